@@ -149,3 +149,34 @@ int cubiomes_map_block_height(float *y,
     }
     return 0;
 }
+
+// ============================================================================
+// Climate noise sampling for MC 1.18+
+// ============================================================================
+
+// Sample the raw climate noise parameters at block coordinates (bx, bz).
+// Writes 5 floats into out: temperature, humidity, continentalness, erosion
+// and weirdness, each roughly in [-1, 1]. Uses the same 1:4 coordinates and
+// shift as sampleBiomeNoise at y=0, so the values vary smoothly.
+// Returns 0 on success, 1 if not supported (e.g. wrong MC version or dim).
+int cubiomes_sample_climate(float *out, const Generator *g, int bx, int bz)
+{
+    if (g->dim != DIM_OVERWORLD)
+        return 1;
+    if (g->mc < MC_1_18)
+        return 1;
+
+    const BiomeNoise *bn = &g->bn;
+    double x = bx / 4.0;
+    double z = bz / 4.0;
+
+    double px = x + sampleDoublePerlin(&bn->climate[NP_SHIFT], x, 0, z) * 4.0;
+    double pz = z + sampleDoublePerlin(&bn->climate[NP_SHIFT], z, x, 0) * 4.0;
+
+    out[0] = sampleDoublePerlin(&bn->climate[NP_TEMPERATURE], px, 0, pz);
+    out[1] = sampleDoublePerlin(&bn->climate[NP_HUMIDITY], px, 0, pz);
+    out[2] = sampleDoublePerlin(&bn->climate[NP_CONTINENTALNESS], px, 0, pz);
+    out[3] = sampleDoublePerlin(&bn->climate[NP_EROSION], px, 0, pz);
+    out[4] = sampleDoublePerlin(&bn->climate[NP_WEIRDNESS], px, 0, pz);
+    return 0;
+}
@@ -56,6 +56,24 @@ pub struct Pos {
     pub z: c_int,
 }
 
+/// StructureConfig struct filled in by `getStructureConfig`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StructureConfig {
+    /// Placement salt
+    pub salt: i32,
+    /// Region size in chunks
+    pub region_size: i8,
+    /// Chunk range within a region that attempts can land in
+    pub chunk_range: i8,
+    /// Structure type (`STRUCT_*`)
+    pub struct_type: u8,
+    /// Dimension (`DIM_*`)
+    pub dim: i8,
+    /// Generation chance for structures that can fail placement
+    pub rarity: f32,
+}
+
 // ============================================================================
 // MCVersion enum
 // ============================================================================
@@ -330,6 +348,10 @@ extern "C" {
 
     // --- Structure finding ---
 
+    /// Get the placement config of a structure for a MC version.
+    /// Returns 0 if the structure does not exist in that version.
+    pub fn getStructureConfig(struct_type: c_int, mc: c_int, sconf: *mut StructureConfig) -> c_int;
+
     /// Get structure position for a given region.
     pub fn getStructurePos(
         struct_type: c_int,
@@ -403,6 +425,17 @@ extern "C" {
         w: c_int,
         h: c_int,
     ) -> c_int;
+
+    /// Sample raw climate noise at block coordinates for MC 1.18+.
+    /// Writes 5 floats into `out`: temperature, humidity, continentalness,
+    /// erosion and weirdness, each roughly in [-1, 1].
+    /// Returns 0 on success, 1 if unsupported (wrong MC version or dimension).
+    pub fn cubiomes_sample_climate(
+        out: *mut f32,
+        g: *const Generator,
+        bx: c_int,
+        bz: c_int,
+    ) -> c_int;
 }
 
 // ============================================================================
@@ -435,6 +468,11 @@ pub fn mc_version_name(mc: c_int) -> String {
     }
 }
 
+/// Whether a biome is any kind of ocean.
+pub fn is_oceanic(id: c_int) -> bool {
+    unsafe { isOceanic(id) != 0 }
+}
+
 /// Get default biome colors (256 entries, RGB).
 pub fn default_biome_colors() -> [[u8; 3]; 256] {
     let mut colors = [[0u8; 3]; 256];
@@ -0,0 +1,509 @@
+//! Biome identifiers shared across crates.
+//!
+//! [`Biome`] wraps a cubiomes biome ID so worldgen, rendering and gameplay
+//! agree on one representation, with names and climate helpers in a single
+//! place instead of scattered ID matching.
+
+use serde::{Deserialize, Serialize};
+
+/// A biome, identified by its cubiomes biome ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Biome(i32);
+
+/// Temperature category of an overworld biome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Climate {
+    /// Snow and ice.
+    Frozen,
+    /// Cold but not snow-covered.
+    Cold,
+    /// Mild temperatures.
+    Temperate,
+    /// Hot or dry.
+    Warm,
+}
+
+impl Biome {
+    /// No biome / invalid ID.
+    pub const NONE: Self = Self(-1);
+    /// Ocean.
+    pub const OCEAN: Self = Self(0);
+    /// Plains.
+    pub const PLAINS: Self = Self(1);
+    /// Desert.
+    pub const DESERT: Self = Self(2);
+    /// Mountains.
+    pub const MOUNTAINS: Self = Self(3);
+    /// Forest.
+    pub const FOREST: Self = Self(4);
+    /// Taiga.
+    pub const TAIGA: Self = Self(5);
+    /// Swamp.
+    pub const SWAMP: Self = Self(6);
+    /// River.
+    pub const RIVER: Self = Self(7);
+    /// Nether wastes.
+    pub const NETHER_WASTES: Self = Self(8);
+    /// The end.
+    pub const THE_END: Self = Self(9);
+    /// Frozen ocean.
+    pub const FROZEN_OCEAN: Self = Self(10);
+    /// Frozen river.
+    pub const FROZEN_RIVER: Self = Self(11);
+    /// Snowy tundra.
+    pub const SNOWY_TUNDRA: Self = Self(12);
+    /// Snowy mountains.
+    pub const SNOWY_MOUNTAINS: Self = Self(13);
+    /// Mushroom fields.
+    pub const MUSHROOM_FIELDS: Self = Self(14);
+    /// Mushroom field shore.
+    pub const MUSHROOM_FIELD_SHORE: Self = Self(15);
+    /// Beach.
+    pub const BEACH: Self = Self(16);
+    /// Desert hills.
+    pub const DESERT_HILLS: Self = Self(17);
+    /// Wooded hills.
+    pub const WOODED_HILLS: Self = Self(18);
+    /// Taiga hills.
+    pub const TAIGA_HILLS: Self = Self(19);
+    /// Mountain edge.
+    pub const MOUNTAIN_EDGE: Self = Self(20);
+    /// Jungle.
+    pub const JUNGLE: Self = Self(21);
+    /// Jungle hills.
+    pub const JUNGLE_HILLS: Self = Self(22);
+    /// Jungle edge.
+    pub const JUNGLE_EDGE: Self = Self(23);
+    /// Deep ocean.
+    pub const DEEP_OCEAN: Self = Self(24);
+    /// Stone shore.
+    pub const STONE_SHORE: Self = Self(25);
+    /// Snowy beach.
+    pub const SNOWY_BEACH: Self = Self(26);
+    /// Birch forest.
+    pub const BIRCH_FOREST: Self = Self(27);
+    /// Birch forest hills.
+    pub const BIRCH_FOREST_HILLS: Self = Self(28);
+    /// Dark forest.
+    pub const DARK_FOREST: Self = Self(29);
+    /// Snowy taiga.
+    pub const SNOWY_TAIGA: Self = Self(30);
+    /// Snowy taiga hills.
+    pub const SNOWY_TAIGA_HILLS: Self = Self(31);
+    /// Giant tree taiga.
+    pub const GIANT_TREE_TAIGA: Self = Self(32);
+    /// Giant tree taiga hills.
+    pub const GIANT_TREE_TAIGA_HILLS: Self = Self(33);
+    /// Wooded mountains.
+    pub const WOODED_MOUNTAINS: Self = Self(34);
+    /// Savanna.
+    pub const SAVANNA: Self = Self(35);
+    /// Savanna plateau.
+    pub const SAVANNA_PLATEAU: Self = Self(36);
+    /// Badlands.
+    pub const BADLANDS: Self = Self(37);
+    /// Wooded badlands plateau.
+    pub const WOODED_BADLANDS_PLATEAU: Self = Self(38);
+    /// Badlands plateau.
+    pub const BADLANDS_PLATEAU: Self = Self(39);
+    /// Small end islands.
+    pub const SMALL_END_ISLANDS: Self = Self(40);
+    /// End midlands.
+    pub const END_MIDLANDS: Self = Self(41);
+    /// End highlands.
+    pub const END_HIGHLANDS: Self = Self(42);
+    /// End barrens.
+    pub const END_BARRENS: Self = Self(43);
+    /// Warm ocean.
+    pub const WARM_OCEAN: Self = Self(44);
+    /// Lukewarm ocean.
+    pub const LUKEWARM_OCEAN: Self = Self(45);
+    /// Cold ocean.
+    pub const COLD_OCEAN: Self = Self(46);
+    /// Deep warm ocean.
+    pub const DEEP_WARM_OCEAN: Self = Self(47);
+    /// Deep lukewarm ocean.
+    pub const DEEP_LUKEWARM_OCEAN: Self = Self(48);
+    /// Deep cold ocean.
+    pub const DEEP_COLD_OCEAN: Self = Self(49);
+    /// Deep frozen ocean.
+    pub const DEEP_FROZEN_OCEAN: Self = Self(50);
+    /// Sunflower plains.
+    pub const SUNFLOWER_PLAINS: Self = Self(129);
+    /// Desert lakes.
+    pub const DESERT_LAKES: Self = Self(130);
+    /// Gravelly mountains.
+    pub const GRAVELLY_MOUNTAINS: Self = Self(131);
+    /// Flower forest.
+    pub const FLOWER_FOREST: Self = Self(132);
+    /// Taiga mountains.
+    pub const TAIGA_MOUNTAINS: Self = Self(133);
+    /// Swamp hills.
+    pub const SWAMP_HILLS: Self = Self(134);
+    /// Ice spikes.
+    pub const ICE_SPIKES: Self = Self(140);
+    /// Modified jungle.
+    pub const MODIFIED_JUNGLE: Self = Self(149);
+    /// Modified jungle edge.
+    pub const MODIFIED_JUNGLE_EDGE: Self = Self(151);
+    /// Tall birch forest.
+    pub const TALL_BIRCH_FOREST: Self = Self(155);
+    /// Tall birch hills.
+    pub const TALL_BIRCH_HILLS: Self = Self(156);
+    /// Dark forest hills.
+    pub const DARK_FOREST_HILLS: Self = Self(157);
+    /// Snowy taiga mountains.
+    pub const SNOWY_TAIGA_MOUNTAINS: Self = Self(158);
+    /// Giant spruce taiga.
+    pub const GIANT_SPRUCE_TAIGA: Self = Self(160);
+    /// Giant spruce taiga hills.
+    pub const GIANT_SPRUCE_TAIGA_HILLS: Self = Self(161);
+    /// Modified gravelly mountains.
+    pub const MODIFIED_GRAVELLY_MOUNTAINS: Self = Self(162);
+    /// Shattered savanna.
+    pub const SHATTERED_SAVANNA: Self = Self(163);
+    /// Shattered savanna plateau.
+    pub const SHATTERED_SAVANNA_PLATEAU: Self = Self(164);
+    /// Eroded badlands.
+    pub const ERODED_BADLANDS: Self = Self(165);
+    /// Modified wooded badlands plateau.
+    pub const MODIFIED_WOODED_BADLANDS_PLATEAU: Self = Self(166);
+    /// Modified badlands plateau.
+    pub const MODIFIED_BADLANDS_PLATEAU: Self = Self(167);
+    /// Bamboo jungle.
+    pub const BAMBOO_JUNGLE: Self = Self(168);
+    /// Bamboo jungle hills.
+    pub const BAMBOO_JUNGLE_HILLS: Self = Self(169);
+    /// Soul sand valley.
+    pub const SOUL_SAND_VALLEY: Self = Self(170);
+    /// Crimson forest.
+    pub const CRIMSON_FOREST: Self = Self(171);
+    /// Warped forest.
+    pub const WARPED_FOREST: Self = Self(172);
+    /// Basalt deltas.
+    pub const BASALT_DELTAS: Self = Self(173);
+    /// Dripstone caves.
+    pub const DRIPSTONE_CAVES: Self = Self(174);
+    /// Lush caves.
+    pub const LUSH_CAVES: Self = Self(175);
+    /// Meadow.
+    pub const MEADOW: Self = Self(177);
+    /// Grove.
+    pub const GROVE: Self = Self(178);
+    /// Snowy slopes.
+    pub const SNOWY_SLOPES: Self = Self(179);
+    /// Jagged peaks.
+    pub const JAGGED_PEAKS: Self = Self(180);
+    /// Frozen peaks.
+    pub const FROZEN_PEAKS: Self = Self(181);
+    /// Stony peaks.
+    pub const STONY_PEAKS: Self = Self(182);
+    /// Deep dark.
+    pub const DEEP_DARK: Self = Self(183);
+    /// Mangrove swamp.
+    pub const MANGROVE_SWAMP: Self = Self(184);
+    /// Cherry grove.
+    pub const CHERRY_GROVE: Self = Self(185);
+    /// Pale garden.
+    pub const PALE_GARDEN: Self = Self(186);
+
+    /// Creates a biome from a cubiomes biome ID.
+    #[must_use]
+    pub const fn from_id(id: i32) -> Self {
+        Self(id)
+    }
+
+    /// Returns the cubiomes biome ID.
+    #[must_use]
+    pub const fn id(self) -> i32 {
+        self.0
+    }
+
+    /// Returns every known biome in ID order.
+    pub fn all() -> impl Iterator<Item = Biome> {
+        BIOME_NAMES.iter().map(|&(biome, _)| biome)
+    }
+
+    /// Returns the biome's identifier name (e.g. `"dark_forest"`), or
+    /// `"unknown"` for IDs this crate doesn't know.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        BIOME_NAMES
+            .binary_search_by_key(&self, |&(biome, _)| biome)
+            .map_or("unknown", |i| BIOME_NAMES[i].1)
+    }
+
+    /// Whether this is a known biome ID.
+    #[must_use]
+    pub fn is_known(self) -> bool {
+        self.name() != "unknown"
+    }
+
+    /// Whether the biome generates in the overworld.
+    #[must_use]
+    pub fn is_overworld(self) -> bool {
+        self.is_known() && !self.is_nether() && !self.is_end()
+    }
+
+    /// Whether the biome belongs to the nether.
+    #[must_use]
+    pub fn is_nether(self) -> bool {
+        matches!(
+            self,
+            Self::NETHER_WASTES
+                | Self::SOUL_SAND_VALLEY
+                | Self::CRIMSON_FOREST
+                | Self::WARPED_FOREST
+                | Self::BASALT_DELTAS
+        )
+    }
+
+    /// Whether the biome belongs to the end.
+    #[must_use]
+    pub fn is_end(self) -> bool {
+        matches!(
+            self,
+            Self::THE_END
+                | Self::SMALL_END_ISLANDS
+                | Self::END_MIDLANDS
+                | Self::END_HIGHLANDS
+                | Self::END_BARRENS
+        )
+    }
+
+    /// Whether the biome is an ocean of any depth or temperature.
+    #[must_use]
+    pub fn is_oceanic(self) -> bool {
+        matches!(
+            self,
+            Self::OCEAN
+                | Self::DEEP_OCEAN
+                | Self::FROZEN_OCEAN
+                | Self::DEEP_FROZEN_OCEAN
+                | Self::COLD_OCEAN
+                | Self::DEEP_COLD_OCEAN
+                | Self::LUKEWARM_OCEAN
+                | Self::DEEP_LUKEWARM_OCEAN
+                | Self::WARM_OCEAN
+                | Self::DEEP_WARM_OCEAN
+        )
+    }
+
+    /// Whether the biome is snow-covered.
+    #[must_use]
+    pub fn is_snowy(self) -> bool {
+        self.climate() == Some(Climate::Frozen)
+    }
+
+    /// Temperature category, or `None` for nether, end and unknown biomes.
+    #[must_use]
+    pub fn climate(self) -> Option<Climate> {
+        if !self.is_overworld() {
+            return None;
+        }
+        let climate = match self {
+            Self::FROZEN_OCEAN
+            | Self::DEEP_FROZEN_OCEAN
+            | Self::FROZEN_RIVER
+            | Self::SNOWY_TUNDRA
+            | Self::SNOWY_MOUNTAINS
+            | Self::SNOWY_BEACH
+            | Self::SNOWY_TAIGA
+            | Self::SNOWY_TAIGA_HILLS
+            | Self::SNOWY_TAIGA_MOUNTAINS
+            | Self::ICE_SPIKES
+            | Self::GROVE
+            | Self::SNOWY_SLOPES
+            | Self::JAGGED_PEAKS
+            | Self::FROZEN_PEAKS => Climate::Frozen,
+            Self::TAIGA
+            | Self::TAIGA_HILLS
+            | Self::TAIGA_MOUNTAINS
+            | Self::GIANT_TREE_TAIGA
+            | Self::GIANT_TREE_TAIGA_HILLS
+            | Self::GIANT_SPRUCE_TAIGA
+            | Self::GIANT_SPRUCE_TAIGA_HILLS
+            | Self::MOUNTAINS
+            | Self::MOUNTAIN_EDGE
+            | Self::WOODED_MOUNTAINS
+            | Self::GRAVELLY_MOUNTAINS
+            | Self::MODIFIED_GRAVELLY_MOUNTAINS
+            | Self::STONE_SHORE
+            | Self::COLD_OCEAN
+            | Self::DEEP_COLD_OCEAN => Climate::Cold,
+            Self::DESERT
+            | Self::DESERT_HILLS
+            | Self::DESERT_LAKES
+            | Self::SAVANNA
+            | Self::SAVANNA_PLATEAU
+            | Self::SHATTERED_SAVANNA
+            | Self::SHATTERED_SAVANNA_PLATEAU
+            | Self::BADLANDS
+            | Self::BADLANDS_PLATEAU
+            | Self::WOODED_BADLANDS_PLATEAU
+            | Self::ERODED_BADLANDS
+            | Self::MODIFIED_BADLANDS_PLATEAU
+            | Self::MODIFIED_WOODED_BADLANDS_PLATEAU
+            | Self::WARM_OCEAN
+            | Self::DEEP_WARM_OCEAN => Climate::Warm,
+            _ => Climate::Temperate,
+        };
+        Some(climate)
+    }
+}
+
+impl Default for Biome {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+impl From<i32> for Biome {
+    fn from(id: i32) -> Self {
+        Self(id)
+    }
+}
+
+impl From<Biome> for i32 {
+    fn from(biome: Biome) -> Self {
+        biome.0
+    }
+}
+
+impl std::fmt::Display for Biome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Known biomes and their names, sorted by ID.
+const BIOME_NAMES: &[(Biome, &str)] = &[
+    (Biome::OCEAN, "ocean"),
+    (Biome::PLAINS, "plains"),
+    (Biome::DESERT, "desert"),
+    (Biome::MOUNTAINS, "mountains"),
+    (Biome::FOREST, "forest"),
+    (Biome::TAIGA, "taiga"),
+    (Biome::SWAMP, "swamp"),
+    (Biome::RIVER, "river"),
+    (Biome::NETHER_WASTES, "nether_wastes"),
+    (Biome::THE_END, "the_end"),
+    (Biome::FROZEN_OCEAN, "frozen_ocean"),
+    (Biome::FROZEN_RIVER, "frozen_river"),
+    (Biome::SNOWY_TUNDRA, "snowy_tundra"),
+    (Biome::SNOWY_MOUNTAINS, "snowy_mountains"),
+    (Biome::MUSHROOM_FIELDS, "mushroom_fields"),
+    (Biome::MUSHROOM_FIELD_SHORE, "mushroom_field_shore"),
+    (Biome::BEACH, "beach"),
+    (Biome::DESERT_HILLS, "desert_hills"),
+    (Biome::WOODED_HILLS, "wooded_hills"),
+    (Biome::TAIGA_HILLS, "taiga_hills"),
+    (Biome::MOUNTAIN_EDGE, "mountain_edge"),
+    (Biome::JUNGLE, "jungle"),
+    (Biome::JUNGLE_HILLS, "jungle_hills"),
+    (Biome::JUNGLE_EDGE, "jungle_edge"),
+    (Biome::DEEP_OCEAN, "deep_ocean"),
+    (Biome::STONE_SHORE, "stone_shore"),
+    (Biome::SNOWY_BEACH, "snowy_beach"),
+    (Biome::BIRCH_FOREST, "birch_forest"),
+    (Biome::BIRCH_FOREST_HILLS, "birch_forest_hills"),
+    (Biome::DARK_FOREST, "dark_forest"),
+    (Biome::SNOWY_TAIGA, "snowy_taiga"),
+    (Biome::SNOWY_TAIGA_HILLS, "snowy_taiga_hills"),
+    (Biome::GIANT_TREE_TAIGA, "giant_tree_taiga"),
+    (Biome::GIANT_TREE_TAIGA_HILLS, "giant_tree_taiga_hills"),
+    (Biome::WOODED_MOUNTAINS, "wooded_mountains"),
+    (Biome::SAVANNA, "savanna"),
+    (Biome::SAVANNA_PLATEAU, "savanna_plateau"),
+    (Biome::BADLANDS, "badlands"),
+    (Biome::WOODED_BADLANDS_PLATEAU, "wooded_badlands_plateau"),
+    (Biome::BADLANDS_PLATEAU, "badlands_plateau"),
+    (Biome::SMALL_END_ISLANDS, "small_end_islands"),
+    (Biome::END_MIDLANDS, "end_midlands"),
+    (Biome::END_HIGHLANDS, "end_highlands"),
+    (Biome::END_BARRENS, "end_barrens"),
+    (Biome::WARM_OCEAN, "warm_ocean"),
+    (Biome::LUKEWARM_OCEAN, "lukewarm_ocean"),
+    (Biome::COLD_OCEAN, "cold_ocean"),
+    (Biome::DEEP_WARM_OCEAN, "deep_warm_ocean"),
+    (Biome::DEEP_LUKEWARM_OCEAN, "deep_lukewarm_ocean"),
+    (Biome::DEEP_COLD_OCEAN, "deep_cold_ocean"),
+    (Biome::DEEP_FROZEN_OCEAN, "deep_frozen_ocean"),
+    (Biome::SUNFLOWER_PLAINS, "sunflower_plains"),
+    (Biome::DESERT_LAKES, "desert_lakes"),
+    (Biome::GRAVELLY_MOUNTAINS, "gravelly_mountains"),
+    (Biome::FLOWER_FOREST, "flower_forest"),
+    (Biome::TAIGA_MOUNTAINS, "taiga_mountains"),
+    (Biome::SWAMP_HILLS, "swamp_hills"),
+    (Biome::ICE_SPIKES, "ice_spikes"),
+    (Biome::MODIFIED_JUNGLE, "modified_jungle"),
+    (Biome::MODIFIED_JUNGLE_EDGE, "modified_jungle_edge"),
+    (Biome::TALL_BIRCH_FOREST, "tall_birch_forest"),
+    (Biome::TALL_BIRCH_HILLS, "tall_birch_hills"),
+    (Biome::DARK_FOREST_HILLS, "dark_forest_hills"),
+    (Biome::SNOWY_TAIGA_MOUNTAINS, "snowy_taiga_mountains"),
+    (Biome::GIANT_SPRUCE_TAIGA, "giant_spruce_taiga"),
+    (Biome::GIANT_SPRUCE_TAIGA_HILLS, "giant_spruce_taiga_hills"),
+    (Biome::MODIFIED_GRAVELLY_MOUNTAINS, "modified_gravelly_mountains"),
+    (Biome::SHATTERED_SAVANNA, "shattered_savanna"),
+    (Biome::SHATTERED_SAVANNA_PLATEAU, "shattered_savanna_plateau"),
+    (Biome::ERODED_BADLANDS, "eroded_badlands"),
+    (Biome::MODIFIED_WOODED_BADLANDS_PLATEAU, "modified_wooded_badlands_plateau"),
+    (Biome::MODIFIED_BADLANDS_PLATEAU, "modified_badlands_plateau"),
+    (Biome::BAMBOO_JUNGLE, "bamboo_jungle"),
+    (Biome::BAMBOO_JUNGLE_HILLS, "bamboo_jungle_hills"),
+    (Biome::SOUL_SAND_VALLEY, "soul_sand_valley"),
+    (Biome::CRIMSON_FOREST, "crimson_forest"),
+    (Biome::WARPED_FOREST, "warped_forest"),
+    (Biome::BASALT_DELTAS, "basalt_deltas"),
+    (Biome::DRIPSTONE_CAVES, "dripstone_caves"),
+    (Biome::LUSH_CAVES, "lush_caves"),
+    (Biome::MEADOW, "meadow"),
+    (Biome::GROVE, "grove"),
+    (Biome::SNOWY_SLOPES, "snowy_slopes"),
+    (Biome::JAGGED_PEAKS, "jagged_peaks"),
+    (Biome::FROZEN_PEAKS, "frozen_peaks"),
+    (Biome::STONY_PEAKS, "stony_peaks"),
+    (Biome::DEEP_DARK, "deep_dark"),
+    (Biome::MANGROVE_SWAMP, "mangrove_swamp"),
+    (Biome::CHERRY_GROVE, "cherry_grove"),
+    (Biome::PALE_GARDEN, "pale_garden"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_biome_names_sorted_and_unique() {
+        assert!(BIOME_NAMES.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_biome_i32_roundtrip() {
+        for biome in Biome::all() {
+            let id: i32 = biome.into();
+            assert_eq!(Biome::from(id), biome);
+            assert_eq!(Biome::from_id(id).name(), biome.name());
+        }
+        assert_eq!(i32::from(Biome::NONE), -1);
+    }
+
+    #[test]
+    fn test_biome_names_and_helpers() {
+        assert_eq!(Biome::PLAINS.name(), "plains");
+        assert_eq!(Biome::DARK_FOREST.to_string(), "dark_forest");
+        assert_eq!(Biome::from(12345).name(), "unknown");
+
+        assert!(Biome::MEADOW.is_overworld());
+        assert!(!Biome::CRIMSON_FOREST.is_overworld());
+        assert!(!Biome::END_BARRENS.is_overworld());
+        assert!(Biome::DEEP_LUKEWARM_OCEAN.is_oceanic());
+        assert!(Biome::ICE_SPIKES.is_snowy());
+        assert_eq!(Biome::DESERT.climate(), Some(Climate::Warm));
+        assert_eq!(Biome::TAIGA.climate(), Some(Climate::Cold));
+        assert_eq!(Biome::FOREST.climate(), Some(Climate::Temperate));
+        assert_eq!(Biome::NETHER_WASTES.climate(), None);
+    }
+}
@@ -2,6 +2,109 @@
 
 use bytemuck::{Pod, Zeroable};
 use serde::{Deserialize, Serialize};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// Continuous world position on the horizontal plane.
+///
+/// `z` is the second horizontal axis, matching the X/Z plane of the
+/// generated world (the top-down view's Y).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, Pod, Zeroable)]
+#[repr(C)]
+pub struct WorldPos {
+    /// X position in world space
+    pub x: f32,
+    /// Z position in world space
+    pub z: f32,
+}
+
+impl WorldPos {
+    /// The world origin.
+    pub const ZERO: Self = Self::new(0.0, 0.0);
+
+    /// Creates a new world position.
+    #[must_use]
+    pub const fn new(x: f32, z: f32) -> Self {
+        Self { x, z }
+    }
+
+    /// Converts to the chunk containing this position.
+    ///
+    /// Uses floor division, so `-0.5` lands in chunk `-1`, not `0`.
+    #[must_use]
+    pub fn to_chunk_coord(self, chunk_size: f32) -> ChunkCoord {
+        ChunkCoord {
+            x: (self.x / chunk_size).floor() as i32,
+            y: (self.z / chunk_size).floor() as i32,
+        }
+    }
+
+    /// Converts to the integer world coordinate containing this position.
+    #[must_use]
+    pub fn to_world_coord(self) -> WorldCoord {
+        WorldCoord {
+            x: self.x.floor() as i64,
+            y: self.z.floor() as i64,
+        }
+    }
+}
+
+impl From<(f32, f32)> for WorldPos {
+    fn from((x, z): (f32, f32)) -> Self {
+        Self { x, z }
+    }
+}
+
+impl Add for WorldPos {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.z + rhs.z)
+    }
+}
+
+impl AddAssign for WorldPos {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for WorldPos {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.z - rhs.z)
+    }
+}
+
+impl SubAssign for WorldPos {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<f32> for WorldPos {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Self::new(self.x * rhs, self.z * rhs)
+    }
+}
+
+impl Div<f32> for WorldPos {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self {
+        Self::new(self.x / rhs, self.z / rhs)
+    }
+}
+
+impl Neg for WorldPos {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.z)
+    }
+}
 
 /// World coordinate in pixels (global position).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Pod, Zeroable)]
@@ -68,6 +171,12 @@ impl ChunkCoord {
     }
 }
 
+impl From<ChunkCoord> for (i32, i32) {
+    fn from(coord: ChunkCoord) -> Self {
+        (coord.x, coord.y)
+    }
+}
+
 /// Local coordinate within a chunk (0 to chunk_size-1).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Pod, Zeroable)]
 #[repr(C)]
@@ -1,5 +1,6 @@
 //! Error types for Project Genesis.
 
+use crate::save_compression::CompressionError;
 use thiserror::Error;
 
 /// Top-level error type for Genesis operations.
@@ -17,6 +18,10 @@ pub enum GenesisError {
     #[error("Serialization error: {0}")]
     Serialization(String),
 
+    /// Save data compression errors
+    #[error("Compression error: {0}")]
+    Compression(#[from] CompressionError),
+
     /// IO errors
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
@@ -82,3 +87,52 @@ pub enum WorldError {
 
 /// Result type alias for Genesis operations.
 pub type GenesisResult<T> = Result<T, GenesisError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as _;
+
+    fn decompress_step() -> GenesisResult<Vec<u8>> {
+        Ok(crate::save_compression::decompress(b"not a save")?)
+    }
+
+    #[test]
+    fn test_compression_error_converts() {
+        let err = decompress_step().expect_err("invalid header should fail");
+        assert!(matches!(
+            err,
+            GenesisError::Compression(CompressionError::InvalidHeader)
+        ));
+        assert_eq!(
+            err.to_string(),
+            "Compression error: Missing or invalid compression header"
+        );
+
+        // The subsystem error stays reachable through the source chain
+        let source = err.source().expect("source should be preserved");
+        assert_eq!(
+            source.to_string(),
+            CompressionError::InvalidHeader.to_string()
+        );
+        assert!(source.downcast_ref::<CompressionError>().is_some());
+    }
+
+    #[test]
+    fn test_nested_errors_keep_source_chain() {
+        let err: GenesisError = WorldError::LoadFailed("region 3,4".to_string()).into();
+        assert_eq!(
+            err.to_string(),
+            "World error: Failed to load chunk: region 3,4"
+        );
+        assert!(err.source().is_some());
+
+        let err: GenesisError =
+            std::io::Error::new(std::io::ErrorKind::NotFound, "save.bin").into();
+        assert!(err.to_string().ends_with("save.bin"));
+        assert!(err
+            .source()
+            .and_then(|e| e.downcast_ref::<std::io::Error>())
+            .is_some());
+    }
+}
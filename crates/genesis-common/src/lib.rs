@@ -4,8 +4,11 @@
 //!
 //! This crate provides foundational types used across all Genesis subsystems:
 //! - Coordinate types (world, chunk, local)
+//! - Biome identifiers shared by worldgen, rendering and gameplay
 //! - ID types (EntityId, ChunkId, etc.)
 //! - Version information for schemas
+//! - Seeded random number generation
+//! - Compression for save data and replays
 //! - Common error types
 //! - Prelude for convenient imports
 
@@ -13,16 +16,21 @@
 #![warn(clippy::all)]
 #![deny(clippy::unwrap_used)]
 
+pub mod biome;
 pub mod coords;
 pub mod error;
 pub mod ids;
+pub mod rng;
+pub mod save_compression;
 pub mod version;
 
 /// Prelude module for convenient imports
 pub mod prelude {
+    pub use crate::biome::{Biome, Climate};
     pub use crate::coords::*;
     pub use crate::error::*;
     pub use crate::ids::*;
+    pub use crate::rng::*;
     pub use crate::version::*;
 }
 
@@ -42,6 +50,63 @@ mod tests {
         assert_eq!(local, LocalCoord::new(4, 8));
     }
 
+    #[test]
+    fn test_world_pos_chunk_boundaries() {
+        assert_eq!(
+            WorldPos::new(0.0, 0.0).to_chunk_coord(256.0),
+            ChunkCoord::new(0, 0)
+        );
+        assert_eq!(
+            WorldPos::new(255.9, 255.9).to_chunk_coord(256.0),
+            ChunkCoord::new(0, 0)
+        );
+        assert_eq!(
+            WorldPos::new(256.0, 512.0).to_chunk_coord(256.0),
+            ChunkCoord::new(1, 2)
+        );
+        assert_eq!(
+            WorldPos::new(100.5, 200.25).to_world_coord(),
+            WorldCoord::new(100, 200)
+        );
+    }
+
+    #[test]
+    fn test_world_pos_negative_floors() {
+        // Truncation would put these in chunk 0
+        assert_eq!(
+            WorldPos::new(-0.5, -1.0).to_chunk_coord(256.0),
+            ChunkCoord::new(-1, -1)
+        );
+        assert_eq!(
+            WorldPos::new(-256.0, -256.1).to_chunk_coord(256.0),
+            ChunkCoord::new(-1, -2)
+        );
+        assert_eq!(
+            WorldPos::new(-0.5, -3.2).to_world_coord(),
+            WorldCoord::new(-1, -4)
+        );
+
+        // Agrees with the integer conversion for whole positions
+        let pos = WorldPos::new(-300.0, 40.0);
+        assert_eq!(
+            pos.to_chunk_coord(32.0),
+            pos.to_world_coord().to_chunk_coord(32)
+        );
+    }
+
+    #[test]
+    fn test_world_pos_arithmetic() {
+        let mut pos = WorldPos::new(1.0, 2.0) + WorldPos::new(3.0, -4.0);
+        assert_eq!(pos, WorldPos::new(4.0, -2.0));
+        pos -= WorldPos::new(1.0, 1.0);
+        assert_eq!(pos * 2.0, WorldPos::new(6.0, -6.0));
+        assert_eq!(-pos / 3.0, WorldPos::new(-1.0, 1.0));
+        assert_eq!(
+            WorldPos::from((5.0, 6.0)) - WorldPos::ZERO,
+            WorldPos::new(5.0, 6.0)
+        );
+    }
+
     #[test]
     fn test_entity_id_generation() {
         let id1 = EntityId::new();
@@ -60,4 +125,61 @@ mod tests {
         // Different major versions are incompatible
         assert!(!v1.is_compatible_with(&v3));
     }
+
+    #[test]
+    fn test_content_hash() {
+        let dir = std::env::temp_dir().join(format!("genesis_content_hash_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("create temp dir");
+        let a = dir.join("a.png");
+        let b = dir.join("b.png");
+        std::fs::write(&a, b"tile a").expect("write a");
+        std::fs::write(&b, b"tile b").expect("write b");
+
+        // Identical inputs hash the same, regardless of order
+        let first = content_hash([&a, &b]);
+        assert_eq!(first, content_hash([&a, &b]));
+        assert_eq!(first, content_hash([&b, &a]));
+
+        // Changing a file changes the hash
+        std::fs::write(&b, b"tile B").expect("rewrite b");
+        let changed = content_hash([&a, &b]);
+        assert_ne!(first, changed);
+
+        // So does removing one
+        std::fs::remove_file(&b).expect("remove b");
+        assert_ne!(changed, content_hash([&a, &b]));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_seeded_rng_deterministic() {
+        let mut a = SeededRng::new(42);
+        let mut b = SeededRng::new(42);
+        let mut c = SeededRng::new(43);
+
+        let seq_a: Vec<u32> = (0..16).map(|_| a.next_u32()).collect();
+        let seq_b: Vec<u32> = (0..16).map(|_| b.next_u32()).collect();
+        let seq_c: Vec<u32> = (0..16).map(|_| c.next_u32()).collect();
+        assert_eq!(seq_a, seq_b);
+        assert_ne!(seq_a, seq_c);
+
+        a.reseed(42);
+        assert_eq!(a.next_u32(), seq_a[0]);
+        assert_eq!(a.seed(), 42);
+    }
+
+    #[test]
+    fn test_seeded_rng_ranges() {
+        let mut rng = SeededRng::new(7);
+        for _ in 0..1000 {
+            let f = rng.next_f32();
+            assert!((0.0..1.0).contains(&f));
+            let r = rng.range(-2.0, 3.0);
+            assert!((-2.0..3.0).contains(&r));
+            let u = rng.range_u32(5, 9);
+            assert!((5..=9).contains(&u));
+        }
+        assert_eq!(rng.range_u32(4, 4), 4);
+    }
 }
@@ -0,0 +1,99 @@
+//! Seeded random number generation.
+//!
+//! Systems that must be reproducible (replays, tests, world generation) take a
+//! [`SeededRng`] instead of reading time or thread-local entropy.
+
+use serde::{Deserialize, Serialize};
+
+/// Deterministic pseudo-random number generator.
+///
+/// A 64-bit LCG with an output permutation (PCG-XSH-RR style). Identical seeds
+/// always produce identical sequences on every platform.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeededRng {
+    /// Seed the generator was created with
+    seed: u64,
+    /// Current LCG state
+    state: u64,
+}
+
+impl Default for SeededRng {
+    fn default() -> Self {
+        Self::new(12345)
+    }
+}
+
+impl SeededRng {
+    /// LCG multiplier.
+    const MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+    /// LCG increment.
+    const INCREMENT: u64 = 1_442_695_040_888_963_407;
+
+    /// Creates a new generator from a seed.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        let mut rng = Self { seed, state: 0 };
+        rng.reseed(seed);
+        rng
+    }
+
+    /// Returns the seed this generator was created (or last reseeded) with.
+    #[must_use]
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the current internal state, for checkpointing.
+    #[must_use]
+    pub const fn state(&self) -> u64 {
+        self.state
+    }
+
+    /// Resets the generator to the start of the sequence for `seed`.
+    pub fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.state = seed.wrapping_add(Self::INCREMENT);
+        self.next_u32();
+    }
+
+    /// Returns the next random `u32`.
+    pub fn next_u32(&mut self) -> u32 {
+        let old = self.state;
+        self.state = old
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(Self::INCREMENT);
+        let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+        let rot = (old >> 59) as u32;
+        xorshifted.rotate_right(rot)
+    }
+
+    /// Returns the next random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        (u64::from(self.next_u32()) << 32) | u64::from(self.next_u32())
+    }
+
+    /// Returns a random `f32` in `[0.0, 1.0)`.
+    pub fn next_f32(&mut self) -> f32 {
+        // 24 bits fit exactly in the f32 mantissa
+        (self.next_u32() >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    /// Returns a random `f32` in `[min, max)`.
+    pub fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + self.next_f32() * (max - min)
+    }
+
+    /// Returns a random `u32` in `[min, max]`.
+    pub fn range_u32(&mut self, min: u32, max: u32) -> u32 {
+        if min >= max {
+            return min;
+        }
+        let span = u64::from(max - min) + 1;
+        min + (u64::from(self.next_u32()) % span) as u32
+    }
+
+    /// Returns `true` with the given probability.
+    pub fn chance(&mut self, probability: f32) -> bool {
+        self.next_f32() < probability
+    }
+}
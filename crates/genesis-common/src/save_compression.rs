@@ -0,0 +1,174 @@
+//! Compression for save data.
+//!
+//! A pipeline for persisted blobs such as replays: serialize with
+//! bincode, then LZ4-compress behind a small header. The header carries a
+//! magic tag and the uncompressed length so corrupt or foreign data is
+//! rejected before any decoding happens.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use thiserror::Error;
+
+/// Magic bytes at the start of every compressed payload.
+pub const COMPRESSION_MAGIC: [u8; 4] = *b"GNLZ";
+
+/// Header size: magic plus the uncompressed length.
+const HEADER_LEN: usize = 8;
+
+/// Upper bound on how many bytes LZ4 can expand one compressed byte into.
+const MAX_EXPANSION: usize = 255;
+
+/// Errors from the save compression pipeline.
+#[derive(Debug, Error)]
+pub enum CompressionError {
+    /// Data doesn't start with the compression header
+    #[error("Missing or invalid compression header")]
+    InvalidHeader,
+    /// Payload too large to record its length
+    #[error("Payload too large to compress: {0} bytes")]
+    TooLarge(usize),
+    /// Header claims more data than the payload could decompress to
+    #[error("Header claims {claimed} bytes from a {compressed} byte payload")]
+    InvalidLength {
+        /// Uncompressed length recorded in the header
+        claimed: usize,
+        /// Size of the compressed payload
+        compressed: usize,
+    },
+    /// LZ4 decoding failed
+    #[error("Decompression failed: {0}")]
+    Decompress(String),
+    /// Serialization failed
+    #[error("Serialization failed: {0}")]
+    Serialize(String),
+    /// Deserialization failed
+    #[error("Deserialization failed: {0}")]
+    Deserialize(String),
+}
+
+/// Result type for compression operations.
+pub type CompressionResult<T> = Result<T, CompressionError>;
+
+/// Compresses raw bytes.
+///
+/// # Errors
+///
+/// Returns an error if the input is larger than `u32::MAX` bytes.
+pub fn compress(data: &[u8]) -> CompressionResult<Vec<u8>> {
+    let len = u32::try_from(data.len()).map_err(|_| CompressionError::TooLarge(data.len()))?;
+    let compressed = lz4_flex::compress(data);
+
+    let mut out = Vec::with_capacity(HEADER_LEN + compressed.len());
+    out.extend_from_slice(&COMPRESSION_MAGIC);
+    out.extend_from_slice(&len.to_le_bytes());
+    out.extend_from_slice(&compressed);
+    Ok(out)
+}
+
+/// Decompresses bytes produced by [`compress`].
+///
+/// # Errors
+///
+/// Returns an error if the header is missing, its length is more than the
+/// payload could hold, or the payload is corrupt.
+pub fn decompress(data: &[u8]) -> CompressionResult<Vec<u8>> {
+    if data.len() < HEADER_LEN || data[..4] != COMPRESSION_MAGIC {
+        return Err(CompressionError::InvalidHeader);
+    }
+
+    let mut len_bytes = [0u8; 4];
+    len_bytes.copy_from_slice(&data[4..HEADER_LEN]);
+    let len = u32::from_le_bytes(len_bytes) as usize;
+
+    // Check the length before it sizes the output buffer
+    let payload = &data[HEADER_LEN..];
+    if len > payload.len().saturating_mul(MAX_EXPANSION) {
+        return Err(CompressionError::InvalidLength {
+            claimed: len,
+            compressed: payload.len(),
+        });
+    }
+
+    lz4_flex::decompress(payload, len).map_err(|e| CompressionError::Decompress(e.to_string()))
+}
+
+/// Serializes a value with bincode and compresses it.
+///
+/// # Errors
+///
+/// Returns an error if serialization fails.
+pub fn serialize_compressed<T: Serialize>(value: &T) -> CompressionResult<Vec<u8>> {
+    let bytes =
+        bincode::serialize(value).map_err(|e| CompressionError::Serialize(e.to_string()))?;
+    compress(&bytes)
+}
+
+/// Decompresses and deserializes a value written by
+/// [`serialize_compressed`].
+///
+/// # Errors
+///
+/// Returns an error if decompression or deserialization fails.
+pub fn deserialize_compressed<T: DeserializeOwned>(data: &[u8]) -> CompressionResult<T> {
+    let bytes = decompress(data)?;
+    bincode::deserialize(&bytes).map_err(|e| CompressionError::Deserialize(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let data: Vec<u8> = (0..4096).map(|i| (i % 7) as u8).collect();
+        let compressed = compress(&data).expect("compress");
+        assert!(compressed.len() < data.len());
+        assert_eq!(decompress(&compressed).expect("decompress"), data);
+
+        let empty = compress(&[]).expect("compress empty");
+        assert!(decompress(&empty).expect("decompress empty").is_empty());
+    }
+
+    #[test]
+    fn test_rejects_bad_data() {
+        assert!(matches!(
+            decompress(b"nope"),
+            Err(CompressionError::InvalidHeader)
+        ));
+
+        let mut compressed = compress(&[1, 2, 3, 4, 5, 6, 7, 8]).expect("compress");
+        compressed.truncate(HEADER_LEN + 1);
+        assert!(matches!(
+            decompress(&compressed),
+            Err(CompressionError::Decompress(_))
+        ));
+    }
+
+    #[test]
+    fn test_rejects_oversized_length() {
+        let mut forged = COMPRESSION_MAGIC.to_vec();
+        forged.extend_from_slice(&u32::MAX.to_le_bytes());
+        forged.extend_from_slice(&[0x10, 0x01]);
+        assert!(matches!(
+            decompress(&forged),
+            Err(CompressionError::InvalidLength {
+                claimed,
+                compressed: 2,
+            }) if claimed == u32::MAX as usize
+        ));
+
+        // Highly repetitive data still fits within the bound
+        let zeros = vec![0u8; 1 << 20];
+        let compressed = compress(&zeros).expect("compress");
+        assert_eq!(decompress(&compressed).expect("decompress"), zeros);
+    }
+
+    #[test]
+    fn test_serialize_roundtrip() {
+        let value = (42u64, String::from("genesis"), vec![1.5f32, -2.0]);
+        let bytes = serialize_compressed(&value).expect("serialize");
+        let restored: (u64, String, Vec<f32>) =
+            deserialize_compressed(&bytes).expect("deserialize");
+        assert_eq!(restored, value);
+    }
+}
@@ -1,6 +1,7 @@
-//! Version types for schema compatibility.
+//! Version types for schema compatibility and asset content.
 
 use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 
 /// Schema version using semantic versioning.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -59,6 +60,62 @@ impl std::fmt::Display for SchemaVersion {
     }
 }
 
+/// Version of a set of asset files, derived from their contents.
+///
+/// Caches built from assets (atlases, textures) store the version they were
+/// built from and only rebuild when [`content_hash`] returns a different one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ContentVersion(pub u64);
+
+impl std::fmt::Display for ContentVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for &b in bytes {
+        hash ^= u64::from(b);
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// Hashes a manifest of asset files into a [`ContentVersion`].
+///
+/// Paths are sorted first so the order they are listed in doesn't matter.
+/// Each file contributes its path, length and contents; unreadable files
+/// contribute a marker instead, so adding or removing a file also changes
+/// the version. Uses FNV-1a, which is stable across runs and platforms.
+pub fn content_hash<I, P>(paths: I) -> ContentVersion
+where
+    I: IntoIterator<Item = P>,
+    P: AsRef<Path>,
+{
+    let mut paths: Vec<PathBuf> = paths
+        .into_iter()
+        .map(|p| p.as_ref().to_path_buf())
+        .collect();
+    paths.sort();
+
+    let mut hash = FNV_OFFSET;
+    for path in &paths {
+        hash = fnv1a(hash, path.to_string_lossy().as_bytes());
+        hash = fnv1a(hash, &[0]);
+        hash = match std::fs::read(path) {
+            Ok(bytes) => {
+                let hash = fnv1a(hash, &(bytes.len() as u64).to_le_bytes());
+                fnv1a(hash, &bytes)
+            },
+            Err(_) => fnv1a(hash, b"<missing>"),
+        };
+    }
+    ContentVersion(hash)
+}
+
 /// Magic bytes for file format identification.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MagicBytes(pub [u8; 4]);
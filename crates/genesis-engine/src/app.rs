@@ -3,7 +3,10 @@
 //! Main game loop that integrates all subsystems.
 
 use anyhow::Result;
-use std::time::Instant;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::time::{Duration, Instant};
 use tracing::{debug, error, info, warn};
 use winit::{
     application::ApplicationHandler,
@@ -13,13 +16,36 @@ use winit::{
     window::{Window, WindowId},
 };
 
-use genesis_gameplay::input::KeyCode;
+use genesis_gameplay::ambient::AmbientDirector;
+use genesis_gameplay::combat::{CombatPosition, CombatState};
+use genesis_gameplay::music::{BiomeMusicMap, MusicDirector};
+use genesis_gameplay::needs::Needs;
+use genesis_gameplay::settings::GameplaySettings;
+use genesis_gameplay::biome::BiomeType;
+use genesis_gameplay::input::{KeyCode, MouseButton};
+use genesis_gameplay::sound_triggers::{biome_to_surface, PlayerSoundContext, SoundTriggerSystem};
 use genesis_gameplay::GameState as GameplayState;
+use genesis_common::{Biome, ItemTypeId, WorldPos};
+use genesis_kernel::crafting_grid::RecipeMatcher;
 use genesis_kernel::Camera;
+use genesis_kernel::screenshot::CaptureConfig;
+use genesis_kernel::sim_events::SimEvent;
+use genesis_kernel::{StreamingConfig, TransitionManager};
+use genesis_tools::hot_reload::{HotReloadConfig, HotReloadRegistry};
+use genesis_tools::inspector::Inspector;
+use genesis_gameplay::inventory::Inventory;
+use genesis_tools::inventory_ui::{
+    InventoryAction, InventoryUI, InventoryUIModel, DEFAULT_INVENTORY_COLS,
+    DEFAULT_INVENTORY_ROWS, HOTBAR_SLOTS,
+};
 use genesis_tools::ui::{
-    MainMenu, MainMenuAction,
-    OptionsMenu, OptionsMenuAction,
+    CombatHud, PlayerCombatState,
+    CraftingGrid, CraftingGridAction, CraftingItem, CraftingItemId, CRAFTING_GRID_SLOTS,
+    MainMenu, MainMenuAction, NewGameConfig, NewGameWizard, NewGameWizardAction,
+    OptionsMenu, OptionsMenuAction, OptionsMenuConfig, OptionsSettings, OptionsTab,
+    AUDIO_BUSES,
     PauseMenu, PauseMenuAction,
+    SaveManagement, SaveManagementAction, SaveMenu, SaveMenuAction, SaveSlotId, SaveSlotInfo,
     WorldTools, WorldToolsAction,
 };
 
@@ -28,23 +54,31 @@ use crate::audio_assets::AudioCategory;
 use crate::audio_integration::{AudioIntegration, SoundEvent};
 use crate::autosave::{AutoSaveConfig, AutoSaveManager};
 use crate::automation::{AutomationRequest, AutomationSystem};
-use crate::combat_events::CombatEventHandler;
+use crate::combat_events::{CombatEventHandler, DeathCause, ENTITY_HIT_RADIUS};
 use crate::combat_profile::CombatProfiler;
 use crate::combat_save::CombatPersistence;
 use crate::config::EngineConfig;
 use crate::crafting_events::CraftingEventHandler;
 use crate::crafting_profile::CraftingProfiler;
 use crate::crafting_save::CraftingPersistence;
-use crate::environment::EnvironmentState;
+use crate::environment::{seasonal_biome_color, EnvironmentState};
 use crate::input::InputHandler;
-use crate::perf::PerfMetrics;
-use crate::recipe_loader::RecipeLoader;
+use crate::input_rebind::InputRebindManager;
+use crate::perf::{
+    PerfMetrics, SECTION_EGUI, SECTION_GAMEPLAY, SECTION_NPC_AI, SECTION_RENDER,
+    SECTION_TERRAIN_GEN,
+};
+use crate::recipe_loader::{RecipeLoader, RecipeRegistry};
 use crate::renderer::Renderer;
-use crate::save_manager::{SaveFileBuilder, SaveManager};
+use crate::incremental_save::{IncrementalSaver, SavePriority, SaveRequest};
+use crate::save_manager::{SaveFileBuilder, SaveManager, SaveSlotMetadata, QUICKSAVE_SLOT};
 use crate::timing::{ChunkMetrics, FpsCounter, FrameTiming, NpcMetrics};
 use crate::weapon_loader::WeaponLoader;
 
-use genesis_worldgen::{BiomeTextureMap, WorldGenConfig, WorldGenerator};
+use genesis_worldgen::{
+    BiomeTextureMap, ChunkSource, GeneratedSource, GenFlags, LayeredSource, SavedSource,
+    WorldGenConfig, WorldGenerator,
+};
 
 /// Application mode (menu/playing/paused).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -59,6 +93,17 @@ pub enum AppMode {
     Menu,
 }
 
+/// Assets reloaded when their files change on disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HotReloadTarget {
+    /// Recipe definitions (assets/recipes)
+    Recipes,
+    /// Weapon definitions (assets/weapons)
+    Weapons,
+    /// Player sprite animations
+    PlayerSprite,
+}
+
 /// Application state machine.
 struct GenesisApp {
     /// Engine configuration
@@ -71,6 +116,10 @@ struct GenesisApp {
     // === Game Systems ===
     /// Input handler
     input: InputHandler,
+    /// Rebindable key bindings (edited from the options menu)
+    input_bindings: InputRebindManager,
+    /// Key bindings as last applied, restored when the options are cancelled
+    applied_key_bindings: HashMap<String, String>,
     /// Frame timing
     timing: FrameTiming,
     /// FPS counter for display
@@ -97,8 +146,18 @@ struct GenesisApp {
     // === World Generation ===
     /// World generator (cubiomes-based biome generation)
     world_generator: WorldGenerator,
+    /// Generator warming up the chunks around spawn on a worker thread
+    spawn_warmup: Option<mpsc::Receiver<WorldGenerator>>,
+    /// Set once the warmed-up generator has replaced `world_generator`
+    spawn_ready: Arc<AtomicBool>,
+    /// Screen fade, held while the spawn chunks warm up
+    transition: TransitionManager,
     /// Biome-to-visual mapping (colors or texture paths)
     biome_texture_map: BiomeTextureMap,
+    /// Whether the palette has edits not yet written to disk
+    biome_palette_dirty: bool,
+    /// Saved chunks for the current seed, layered over generation
+    saved_chunks: SavedSource,
     /// Last chunk coordinate that triggered terrain generation
     last_terrain_chunk: (i32, i32),
     /// Whether terrain needs full regeneration
@@ -113,6 +172,20 @@ struct GenesisApp {
     // === Audio ===
     /// Audio integration system
     audio: AudioIntegration,
+    /// Biome ambience that cross-fades as the player moves
+    ambient_director: AmbientDirector,
+    /// Adaptive music stems driven by combat intensity.
+    music_director: MusicDirector,
+    /// Day and night music track for each biome
+    biome_music: BiomeMusicMap,
+    /// Footstep cadence and surface selection
+    sound_triggers: SoundTriggerSystem,
+
+    // === Hot Reload ===
+    /// Shared watcher for asset files
+    hot_reload: HotReloadRegistry,
+    /// Assets whose files changed, sent by hot reload callbacks
+    hot_reload_events: mpsc::Receiver<HotReloadTarget>,
 
     // === Crafting ===
     /// Recipe loader for loading recipes from assets
@@ -125,6 +198,10 @@ struct GenesisApp {
     crafting_profiler: CraftingProfiler,
     /// Whether crafting UI is open
     show_crafting: bool,
+    /// Crafting grid with live recipe preview
+    crafting_grid: CraftingGrid,
+    /// Grid patterns built from the loaded recipes
+    recipe_matcher: RecipeMatcher,
 
     // === Combat ===
     /// Weapon loader for loading weapon definitions
@@ -135,14 +212,18 @@ struct GenesisApp {
     combat_persistence: CombatPersistence,
     /// Combat profiler for performance tracking
     combat_profiler: CombatProfiler,
+    /// Combat HUD (stamina, attack cooldown, low health)
+    combat_hud: CombatHud,
+    /// Player lock-on target used for aiming
+    combat_state: CombatState,
     /// Whether attack input is held (for charge attacks)
     attack_held: bool,
     /// Time attack has been held
     attack_hold_time: f32,
 
     // === Save System ===
-    /// Save file manager
-    save_manager: SaveManager,
+    /// Background save worker, owning the save file manager
+    saver: IncrementalSaver,
     /// Auto-save manager
     autosave_manager: AutoSaveManager,
     /// Current save slot name
@@ -151,14 +232,27 @@ struct GenesisApp {
     // === Gameplay State ===
     /// Gameplay state (player, entities, etc.)
     gameplay: GameplayState,
+    /// Gameplay tuning for the current game (difficulty, spawn and drain rates)
+    gameplay_settings: GameplaySettings,
+    /// Player hunger, thirst and energy
+    player_needs: Needs,
     /// Camera for viewing the world
     camera: Camera,
     /// Application mode
     app_mode: AppMode,
     /// Whether debug overlay is visible
     show_debug: bool,
+    /// Entity inspector for editing gameplay entities
+    inspector: Inspector,
     /// Whether inventory is open
     show_inventory: bool,
+    /// Inventory window with drag-and-drop between slots
+    inventory_ui: InventoryUI,
+    /// Slot data shown in the inventory window, mirrored from
+    /// `player_inventory` every frame
+    inventory_model: InventoryUIModel,
+    /// Items the player carries; the first slots form the hotbar
+    player_inventory: Inventory,
     /// Whether map is open
     show_map: bool,
     /// Currently selected hotbar slot
@@ -167,10 +261,18 @@ struct GenesisApp {
     // === Menu State ===
     /// Main menu UI
     main_menu: MainMenu,
+    /// New game wizard opened from the main menu
+    new_game_wizard: NewGameWizard,
+    /// Options chosen for the most recent new game
+    new_game: NewGameConfig,
     /// Pause menu UI
     pause_menu: PauseMenu,
     /// Options menu UI
     options_menu: OptionsMenu,
+    /// Save/load slot menu UI
+    save_menu: SaveMenu,
+    /// Save management dialogs (delete confirmation, etc.)
+    save_management: SaveManagement,
     /// Whether showing controls help overlay
     show_controls_help: bool,
     /// World tools panel (character generator, sprite builder, etc.)
@@ -219,6 +321,7 @@ impl GenesisApp {
 
         // Initialize audio system
         let mut audio = AudioIntegration::with_default_assets();
+        audio.set_volumes(config.volume_settings());
         if audio.is_available() {
             info!("Audio system initialized");
             // Preload SFX for immediate playback
@@ -227,31 +330,66 @@ impl GenesisApp {
             warn!("Audio system not available - continuing without audio");
         }
 
+        // Restore persisted key bindings over the defaults
+        let mut input_bindings = InputRebindManager::new();
+        input_bindings.import_from_map(&config.key_bindings);
+        let mut input = InputHandler::new();
+        input_bindings.apply_to(input.manager_mut());
+
+        // Start the options menu sliders from the persisted mixer volumes
+        let mut options_settings = OptionsSettings::default();
+        options_settings.graphics.high_contrast_biomes = config.high_contrast;
+        for (bus, _) in AUDIO_BUSES {
+            if let Some(volume) = audio.bus_volume(bus) {
+                *options_settings.audio.bus_volume_mut(bus) = (volume * 100.0).round() as u32;
+            }
+        }
+        // and the controls tab from the restored key bindings
+        for binding in &mut options_settings.controls.key_bindings {
+            if let Some(keys) = input_bindings.get_binding(binding.action.into()) {
+                binding.primary.clone_from(&keys.primary);
+                binding.secondary.clone_from(&keys.secondary);
+            }
+        }
+
         // Initialize crafting system
-        let mut recipe_loader = RecipeLoader::with_default_path();
+        // Starter recipes that all players know (basic tools)
+        let starter_recipes = [1, 2, 3, 4, 5];
+        let mut recipe_loader =
+            RecipeLoader::with_default_path().with_starter_recipes(starter_recipes);
         if let Err(e) = recipe_loader.load_all() {
             warn!("Failed to load recipes: {}", e);
         } else {
             info!("Loaded {} recipes", recipe_loader.registry().len());
         }
+        let recipe_matcher = recipe_loader.registry().build_matcher(CRAFTING_GRID_SLOTS);
+        let tech_tree = recipe_loader.tech_tree();
+        info!(
+            "Recipe tech tree: {} recipes, {} locked",
+            tech_tree.len(),
+            tech_tree.locked().len()
+        );
         let crafting_events = CraftingEventHandler::new();
-        // Starter recipes that all players know (basic tools)
-        let crafting_persistence = CraftingPersistence::with_starter_recipes([1, 2, 3, 4, 5]);
+        let crafting_persistence = CraftingPersistence::with_starter_recipes(starter_recipes);
         let crafting_profiler = CraftingProfiler::new();
 
         // Initialize combat system
         let mut weapon_loader = WeaponLoader::with_default_path();
-        if let Err(e) = weapon_loader.load_all() {
-            warn!("Failed to load weapons: {}", e);
-        } else {
-            info!("Loaded {} weapons", weapon_loader.registry().len());
+        let weapon_warnings = weapon_loader.load_all();
+        if !weapon_warnings.is_empty() {
+            warn!("Skipped {} invalid weapon entries", weapon_warnings.len());
         }
+        info!("Loaded {} weapons", weapon_loader.registry().len());
         let combat_events = CombatEventHandler::new();
         let combat_persistence = CombatPersistence::new();
         let combat_profiler = CombatProfiler::new();
 
+        // Watch asset files for hot reload (debug builds only)
+        let (hot_reload, hot_reload_events) = Self::create_hot_reload();
+
         // Initialize save system
-        let save_manager = SaveManager::new("saves");
+        let mut saver = IncrementalSaver::with_manager(SaveManager::new("saves"));
+        saver.spawn_worker();
         let autosave_config = AutoSaveConfig::default();
         let autosave_manager = AutoSaveManager::new(autosave_config);
         info!("Save system initialized");
@@ -264,29 +402,29 @@ impl GenesisApp {
         let worldgen_config = WorldGenConfig {
             mc_version: genesis_worldgen::MC_1_21,
             seed: seed,
-            flags: 0,
+            flags: GenFlags::NONE,
             scale: 1,
             y_level: 64, // block y=64 (sea level for surface biomes)
+            ..Default::default()
         };
         let world_generator = WorldGenerator::new(worldgen_config);
-        let biome_texture_map = BiomeTextureMap::from_cubiomes_defaults();
+        let biome_texture_map = load_biome_palette(config.high_contrast);
         info!("World generation initialized with cubiomes (seed={}, mc=1.21)", seed);
 
         // Calculate initial player chunk
-        let player_pos = gameplay.player_position();
-        let chunk_size = 256; // Default chunk size
-        let initial_chunk = (
-            (player_pos.0 / chunk_size as f32).floor() as i32,
-            (player_pos.1 / chunk_size as f32).floor() as i32,
-        );
+        let player_pos = WorldPos::from(gameplay.player_position());
+        let chunk_size = 256.0; // Default chunk size
+        let initial_chunk = player_pos.to_chunk_coord(chunk_size).into();
 
         Self {
             show_debug: config.show_debug_overlay,
+            applied_key_bindings: config.key_bindings.clone(),
             config,
             window: None,
             renderer: None,
 
-            input: InputHandler::new(),
+            input,
+            input_bindings,
             timing,
             fps_counter: FpsCounter::new(),
             last_update: Instant::now(),
@@ -296,40 +434,68 @@ impl GenesisApp {
             chunk_metrics: ChunkMetrics::new(),
             npc_metrics: NpcMetrics::new(),
             asset_manager: AssetManager::new(),
+            saved_chunks: SavedSource::new(saved_chunks_dir(seed)),
             world_generator,
+            spawn_warmup: None,
+            spawn_ready: Arc::new(AtomicBool::new(true)),
+            transition: TransitionManager::new(),
             biome_texture_map,
+            biome_palette_dirty: false,
             last_terrain_chunk: (i32::MAX, i32::MAX), // Force initial generation
             terrain_dirty: true,
             npc_spawner,
             last_player_chunk: initial_chunk,
             audio,
+            ambient_director: AmbientDirector::new(),
+            music_director: MusicDirector::new("exploration"),
+            biome_music: BiomeMusicMap::new(),
+            sound_triggers: SoundTriggerSystem::new(),
+            hot_reload,
+            hot_reload_events,
             recipe_loader,
             crafting_events,
             crafting_persistence,
             crafting_profiler,
             show_crafting: false,
+            crafting_grid: CraftingGrid::new(),
+            recipe_matcher,
 
             weapon_loader,
             combat_events,
             combat_persistence,
             combat_profiler,
+            combat_hud: CombatHud::new(),
+            combat_state: CombatState::new(),
             attack_held: false,
             attack_hold_time: 0.0,
 
-            save_manager,
+            saver,
             autosave_manager,
             current_save_slot: None,
 
             gameplay,
+            gameplay_settings: GameplaySettings::default(),
+            player_needs: Needs::new(),
             camera,
             app_mode: AppMode::default(),
+            inspector: Inspector::new(),
             show_inventory: false,
+            inventory_ui: InventoryUI::new(),
+            inventory_model: InventoryUIModel::default_sized(),
+            player_inventory: Inventory::new(
+                (DEFAULT_INVENTORY_ROWS * DEFAULT_INVENTORY_COLS) as u32,
+            )
+            .with_hotbar(HOTBAR_SLOTS as u32),
             show_map: false,
             hotbar_slot: 0,
 
             main_menu: MainMenu::with_defaults(),
+            new_game_wizard: NewGameWizard::with_defaults(),
+            new_game: NewGameConfig::new(),
             pause_menu: PauseMenu::with_defaults(),
-            options_menu: OptionsMenu::with_defaults(),
+            options_menu: OptionsMenu::new(OptionsMenuConfig::default(), options_settings),
+            save_menu: SaveMenu::with_defaults(),
+            save_management: SaveManagement::with_defaults(),
             world_tools: WorldTools::new(),
             show_controls_help: false,
 
@@ -354,6 +520,67 @@ impl GenesisApp {
         }
     }
 
+    /// Creates the asset watcher and the channel its callbacks report to.
+    fn create_hot_reload() -> (HotReloadRegistry, mpsc::Receiver<HotReloadTarget>) {
+        let mut registry = HotReloadRegistry::new(HotReloadConfig {
+            enabled: cfg!(debug_assertions),
+            ..Default::default()
+        });
+        let (sender, receiver) = mpsc::channel();
+
+        let watched = [
+            ("assets/recipes", HotReloadTarget::Recipes),
+            ("assets/weapons", HotReloadTarget::Weapons),
+            ("assets/sprites/characters/player.toml", HotReloadTarget::PlayerSprite),
+        ];
+        for (path, target) in watched {
+            let sender = sender.clone();
+            registry.register(
+                path,
+                Box::new(move || {
+                    let _ = sender.send(target);
+                }),
+            );
+        }
+
+        (registry, receiver)
+    }
+
+    /// Reloads assets whose files changed since the last frame.
+    fn update_hot_reload(&mut self) {
+        self.hot_reload.poll();
+
+        while let Ok(target) = self.hot_reload_events.try_recv() {
+            match target {
+                HotReloadTarget::Recipes => {
+                    if let Err(e) = self.recipe_loader.reload() {
+                        warn!("Failed to reload recipes: {}", e);
+                        continue;
+                    }
+                    info!("Recipes hot-reloaded");
+                    self.recipe_matcher = self
+                        .recipe_loader
+                        .registry()
+                        .build_matcher(CRAFTING_GRID_SLOTS);
+                },
+                HotReloadTarget::Weapons => {
+                    let warnings = self.weapon_loader.reload();
+                    if !warnings.is_empty() {
+                        warn!("Skipped {} invalid weapon entries", warnings.len());
+                    }
+                    info!("Weapons hot-reloaded");
+                },
+                HotReloadTarget::PlayerSprite => {
+                    if let Some(mut renderer) = self.renderer.take() {
+                        self.load_player_sprite(&mut renderer);
+                        self.renderer = Some(renderer);
+                        info!("Player sprite hot-reloaded");
+                    }
+                },
+            }
+        }
+    }
+
     /// Main update and render loop.
     fn update_and_render(&mut self) {
         // Calculate delta time
@@ -366,6 +593,13 @@ impl GenesisApp {
         self.current_fps = fps;
         self.current_frame_time = frame_time;
 
+        // Apply asset changes picked up by the hot reload watcher
+        self.update_hot_reload();
+
+        // Swap in the warmed-up spawn chunks before the fade checks for them
+        self.poll_spawn_warmup();
+        self.transition.update(dt);
+
         // Handle debug toggle (F3)
         if self.input.debug_toggle_pressed() {
             self.show_debug = !self.show_debug;
@@ -435,9 +669,37 @@ impl GenesisApp {
             }
         }
 
-        // Handle screenshot capture (F12 key)
+        // Handle entity inspector toggle (F4 key)
+        if self.input.is_key_just_pressed(KeyCode::F4) {
+            self.inspector.toggle();
+        }
+
+        // Handle cell picking for the inspector (left click outside the UI)
+        if self.inspector.is_visible() && self.input.is_mouse_just_pressed(MouseButton::Left) {
+            if let Some(renderer) = &mut self.renderer {
+                if !renderer.is_pointer_over_ui() {
+                    let picked = renderer.pick_cell(self.input.mouse_position(), &self.camera);
+                    self.inspector.set_picked_cell(picked);
+                }
+            }
+        }
+
+        // Handle cell simulation pause (F6 key) and single step (F7 key)
+        if let Some(renderer) = &mut self.renderer {
+            if self.input.is_key_just_pressed(KeyCode::F6) {
+                let paused = renderer.toggle_simulation_paused();
+                info!("Simulation: {}", if paused { "PAUSED" } else { "RUNNING" });
+            }
+            if self.input.is_key_just_pressed(KeyCode::F7) {
+                renderer.step_simulation_once();
+            }
+        }
+
+        // Handle screenshot capture (F12 key, Shift+F12 bakes in the overlay)
         if self.input.is_key_just_pressed(KeyCode::F12) {
-            self.capture_screenshot();
+            let annotated = self.input.is_key_pressed(KeyCode::LShift)
+                || self.input.is_key_pressed(KeyCode::RShift);
+            self.capture_screenshot(CaptureConfig::full_resolution().with_overlay(annotated));
         }
 
         // Handle pause/menu toggle (Escape)
@@ -450,7 +712,15 @@ impl GenesisApp {
                 },
                 AppMode::Paused => {
                     // Check which dialog is open and close it appropriately
-                    if self.options_menu.is_visible() {
+                    if self.options_menu.is_rebinding() {
+                        // ESC aborts the key capture, not the whole menu
+                        self.options_menu.cancel_rebind();
+                    } else if self.save_management.has_dialog() {
+                        self.save_management.close_dialog();
+                    } else if self.save_menu.is_open() {
+                        // Close save menu, go back to pause menu
+                        self.save_menu.close();
+                    } else if self.options_menu.is_visible() {
                         // Cancel options menu (revert changes), go back to pause menu
                         info!("Cancelling options via ESC");
                         self.options_menu.cancel();
@@ -460,6 +730,7 @@ impl GenesisApp {
                         // Close world tools, go back to pause menu
                         info!("Closing world tools via ESC");
                         self.world_tools.hide();
+                        self.save_biome_palette();
                         self.pause_menu.show();
                     } else if self.pause_menu.is_visible() {
                         // Close pause menu and resume game
@@ -469,7 +740,12 @@ impl GenesisApp {
                     }
                 },
                 AppMode::Menu => {
-                    // ESC in main menu - do nothing (or could quit confirmation)
+                    if self.save_management.has_dialog() {
+                        self.save_management.close_dialog();
+                    } else if self.save_menu.is_open() {
+                        self.save_menu.close();
+                    }
+                    // Otherwise ESC in main menu does nothing (or could quit confirmation)
                 },
             };
         }
@@ -477,6 +753,7 @@ impl GenesisApp {
         // Handle hotbar selection
         if let Some(slot) = self.input.hotbar_selection() {
             self.hotbar_slot = slot;
+            self.player_inventory.select_hotbar(usize::from(slot));
             debug!("Hotbar slot selected: {}", slot + 1);
         }
 
@@ -495,7 +772,17 @@ impl GenesisApp {
         }
 
         // Update environment (time and weather)
+        let season = self.environment.season();
         self.environment.update(dt);
+        if self.environment.season() != season {
+            info!("Season changed to {:?}", self.environment.season());
+            // Terrain colors are seasonal, so cached chunks must be rebuilt
+            self.terrain_dirty = true;
+            self.last_terrain_chunk = (i32::MAX, i32::MAX);
+            if let Some(renderer) = &mut self.renderer {
+                renderer.terrain_renderer_mut().clear_cache();
+            }
+        }
 
         // Update automation system
         let automation_requests = self.automation.update(dt);
@@ -507,7 +794,6 @@ impl GenesisApp {
         }
 
         // Record performance metrics
-        self.perf_metrics.record_frame(dt, dt * 0.3, dt * 0.5); // Approximate update/render split
         if let Some(renderer) = &self.renderer {
             self.perf_metrics.set_world_stats(
                 renderer.visible_chunk_count() as u32,
@@ -524,7 +810,19 @@ impl GenesisApp {
             .set_player((player_pos.x, player_pos.y), (player_vel.x, player_vel.y));
 
         // Render
+        let render_start = Instant::now();
         self.render();
+        let render_elapsed = render_start.elapsed();
+        let ui_elapsed = self
+            .renderer
+            .as_ref()
+            .map_or(Duration::ZERO, Renderer::last_ui_time);
+        self.perf_metrics
+            .record_section(SECTION_RENDER, render_elapsed.saturating_sub(ui_elapsed));
+        self.perf_metrics.record_section(SECTION_EGUI, ui_elapsed);
+
+        // Commit this frame's measured sections
+        self.perf_metrics.record_frame(dt);
 
         // End frame input processing
         self.input.end_frame();
@@ -538,16 +836,25 @@ impl GenesisApp {
         // Get processed input from the engine's input handler
         // This already returns the gameplay Input struct
         let input = self.input.get_input();
+        let gameplay_start = Instant::now();
 
         // Time NPC updates (NPCs are updated inside gameplay.update via fixed_update)
         let npc_start = Instant::now();
 
+        // Terrain slope under the player scales movement speed
+        let player_pos = self.gameplay.player.position();
+        let gradient = self.height_gradient_at(player_pos.x, player_pos.y);
+        self.gameplay.player.set_height_gradient(gradient);
+
         // Update gameplay state (player, entities, etc.)
         self.gameplay.update(dt, &input);
+        self.player_needs.tick(dt * self.gameplay_settings.need_drain_rate);
 
         // Record NPC update timing
         let npc_elapsed = npc_start.elapsed();
         self.npc_metrics.record_ai_time(npc_elapsed);
+        self.perf_metrics
+            .record_section(SECTION_NPC_AI, npc_elapsed);
         self.npc_metrics.set_npc_count(self.gameplay.npc_count());
 
         // Update nearest interactable NPC for UI prompt
@@ -560,7 +867,7 @@ impl GenesisApp {
         // Update audio system
         self.update_audio(dt, player_pos.x, player_pos.y);
 
-        // Update crafting system (check hot-reload, process events)
+        // Update crafting system (process events)
         self.update_crafting(dt);
 
         // Update combat system (process events, update cooldowns)
@@ -573,6 +880,7 @@ impl GenesisApp {
         self.update_npc_chunks();
 
         // Update chunk manager camera position for multi-chunk streaming
+        let mut terrain_elapsed = Duration::ZERO;
         if let Some(renderer) = &mut self.renderer {
             renderer.update_camera_position(&self.camera);
 
@@ -589,6 +897,11 @@ impl GenesisApp {
                 renderer.set_player_action(genesis_kernel::player_sprite::PlayerAnimAction::Jump);
             }
 
+            // Drain animation frame events (footstep, attack_active, ...)
+            for event in renderer.poll_player_sprite_events() {
+                debug!("Player animation event: {}", event);
+            }
+
             // Prepare and step multi-chunk simulation if enabled
             if renderer.is_multi_chunk_enabled() {
                 let start = Instant::now();
@@ -604,7 +917,11 @@ impl GenesisApp {
                     .set_chunk_count(renderer.visible_chunk_count() as u32);
             }
 
+            // Keep the cell simulation around the player
+            renderer.update_simulation_region(player_pos.x, player_pos.y);
+
             // Update streaming terrain with player position (player-centered streaming)
+            let terrain_start = Instant::now();
             if renderer.is_streaming_terrain_enabled() {
                 let start = Instant::now();
                 renderer.update_player_position_streaming(player_pos.x, player_pos.y);
@@ -617,7 +934,7 @@ impl GenesisApp {
                 // Update chunk metrics from streaming terrain
                 if let Some(stats) = renderer.streaming_stats() {
                     self.chunk_metrics
-                        .set_chunk_count(stats.simulating_count as u32);
+                        .set_chunk_count(stats.cached_chunks as u32);
                 }
             }
 
@@ -626,48 +943,70 @@ impl GenesisApp {
             // Coordinate mapping: game X → cubiomes X, game Y → cubiomes Z (top-down view).
             {
                 let terrain = renderer.terrain_renderer_mut();
-                if terrain.is_enabled() {
+                // While spawn chunks warm up, wait for them instead of
+                // generating the same chunks here
+                if terrain.is_enabled() && self.spawn_warmup.is_none() {
+                    // Underwater shading follows the generator's sea level
+                    terrain.set_sea_level(self.world_generator.config().sea_level);
                     let tile_size = terrain.config().tile_size;
                     let render_radius = terrain.config().render_radius;
                     let chunk_cells = 16i32; // biome cells per chunk
 
                     // Current camera chunk in game coordinates
                     // (game Y maps to cubiomes Z for top-down horizontal slice)
-                    let cam_chunk_x = (player_pos.x / (chunk_cells as f32 * tile_size)).floor() as i32;
-                    let cam_chunk_y = (player_pos.y / (chunk_cells as f32 * tile_size)).floor() as i32;
+                    let (cam_chunk_x, cam_chunk_y) = WorldPos::new(player_pos.x, player_pos.y)
+                        .to_chunk_coord(chunk_cells as f32 * tile_size)
+                        .into();
+
+                    terrain.set_streaming_center(cam_chunk_x, cam_chunk_y);
 
                     // Generate new chunks if camera moved or terrain is dirty
                     let camera_moved = cam_chunk_x != self.last_terrain_chunk.0 || cam_chunk_y != self.last_terrain_chunk.1;
                     if camera_moved || self.terrain_dirty {
+                        // Previous center is only meaningful if the cache wasn't just reset
+                        let reset = self.terrain_dirty
+                            || self.last_terrain_chunk == (i32::MAX, i32::MAX);
+                        let previous = (!reset).then_some(self.last_terrain_chunk);
                         self.last_terrain_chunk = (cam_chunk_x, cam_chunk_y);
                         self.terrain_dirty = false;
 
                         let mut generated = 0u32;
 
-                        // Generate any missing chunks within render radius
-                        for cy in (cam_chunk_y - render_radius)..=(cam_chunk_y + render_radius) {
-                            for cx in (cam_chunk_x - render_radius)..=(cam_chunk_x + render_radius) {
-                                if !terrain.is_chunk_cached(cx, cy) {
-                                    // generate_chunk(cx, cy) internally maps game Y → cubiomes Z
-                                    let chunk = self.world_generator.generate_chunk(cx, cy);
-                                    let biome_map = &self.biome_texture_map;
-                                    // Use block-level heights at scale=1, otherwise 1:4 approx heights
-                                    let heights = if self.world_generator.config().scale == 1 {
-                                        self.world_generator.generate_chunk_block_heights(cx, cy)
-                                    } else {
-                                        self.world_generator.generate_chunk_heights(cx, cy)
-                                    };
-                                    terrain.cache_chunk(
-                                        cx, cy,
-                                        &chunk.biomes,
-                                        &heights,
-                                        chunk.width,
-                                        chunk.height,
-                                        &|biome_id| biome_map.get_color(biome_id),
-                                    );
-                                    generated += 1;
-                                }
-                            }
+                        // Saved chunks belong to the seed they were edited in
+                        let saved_dir = saved_chunks_dir(self.world_generator.config().seed);
+                        if self.saved_chunks.dir() != saved_dir {
+                            self.saved_chunks = SavedSource::new(saved_dir);
+                        }
+
+                        // Saved chunks win; GeneratedSource maps game Y → cubiomes Z internally
+                        let mut source = LayeredSource::new(
+                            &mut self.saved_chunks,
+                            GeneratedSource::new(&self.world_generator),
+                        );
+
+                        // Generate any missing chunks within render radius, as
+                        // one batch so large radii are spread over worker threads
+                        let planned = terrain.plan_chunk_generation(
+                            previous,
+                            (cam_chunk_x, cam_chunk_y),
+                            render_radius,
+                        );
+                        let chunks = source.load_chunks(&planned);
+                        for ((cx, cy), chunk) in planned.into_iter().zip(chunks) {
+                            let Some(chunk) = chunk else {
+                                continue;
+                            };
+                            let biome_map = &self.biome_texture_map;
+                            let season = self.environment.season();
+                            terrain.cache_chunk(
+                                cx, cy,
+                                &chunk.biomes.biomes,
+                                &chunk.heights,
+                                chunk.biomes.width,
+                                chunk.biomes.height,
+                                &|biome_id| seasonal_biome_color(season, biome_id, biome_map.get_color(biome_id)),
+                            );
+                            generated += 1;
                         }
 
                         if generated > 0 {
@@ -680,6 +1019,7 @@ impl GenesisApp {
                     }
                 }
             }
+            terrain_elapsed = terrain_start.elapsed();
 
             // Update terrain visible tiles (uses internal borrows)
             renderer.update_terrain_visible_tiles(
@@ -688,19 +1028,30 @@ impl GenesisApp {
             );
         }
 
-        // Update frame timing
-        let _ = self.timing.accumulate(dt);
+        self.perf_metrics
+            .record_section(SECTION_TERRAIN_GEN, terrain_elapsed);
+        self.perf_metrics.record_section(
+            SECTION_GAMEPLAY,
+            gameplay_start
+                .elapsed()
+                .saturating_sub(npc_elapsed + terrain_elapsed),
+        );
+
+        // Update frame timing and run the fixed-rate cell simulation ticks
+        let sim_ticks = self.timing.accumulate(dt);
+        if let Some(renderer) = &mut self.renderer {
+            let start = Instant::now();
+            renderer.step_simulation(sim_ticks);
+            self.chunk_metrics.record_sim_time(start.elapsed());
+        }
+        self.update_sim_events();
     }
 
     /// Updates NPC spawning/despawning based on player chunk position.
     fn update_npc_chunks(&mut self) {
-        let player_pos = self.gameplay.player_position();
+        let player_pos = WorldPos::from(self.gameplay.player_position());
         let chunk_size = self.npc_spawner.config().chunk_size as f32;
-
-        let current_chunk = (
-            (player_pos.0 / chunk_size).floor() as i32,
-            (player_pos.1 / chunk_size).floor() as i32,
-        );
+        let current_chunk: (i32, i32) = player_pos.to_chunk_coord(chunk_size).into();
 
         // Only update if player moved to a different chunk
         if current_chunk == self.last_player_chunk {
@@ -764,12 +1115,8 @@ impl GenesisApp {
     /// Spawns initial NPCs around the player's starting position.
     fn spawn_initial_npcs(&mut self) {
         let chunk_size = self.npc_spawner.config().chunk_size as f32;
-        let player_pos = self.gameplay.player_position();
-
-        let current_chunk = (
-            (player_pos.0 / chunk_size).floor() as i32,
-            (player_pos.1 / chunk_size).floor() as i32,
-        );
+        let player_pos = WorldPos::from(self.gameplay.player_position());
+        let current_chunk: (i32, i32) = player_pos.to_chunk_coord(chunk_size).into();
 
         // Load NPCs in 3x3 grid around player
         let render_distance = 1;
@@ -909,7 +1256,8 @@ impl GenesisApp {
             };
 
             let mut frames = Vec::new();
-            for frame_entry in frames_arr {
+            let mut frame_events = Vec::new();
+            for (index, frame_entry) in frames_arr.iter().enumerate() {
                 let x = frame_entry
                     .get("x")
                     .and_then(|v| v.as_integer())
@@ -933,10 +1281,15 @@ impl GenesisApp {
                     width,
                     height,
                 });
+
+                // Optional gameplay event (e.g. "footstep") fired on this frame
+                if let Some(event) = frame_entry.get("event").and_then(|v| v.as_str()) {
+                    frame_events.push((index, event.to_string()));
+                }
             }
 
             if !frames.is_empty() {
-                anim_set.insert(key, SpriteAnimation { frames, fps, looping });
+                anim_set.insert(key, SpriteAnimation { frames, fps, looping, frame_events });
             }
         }
 
@@ -948,20 +1301,34 @@ impl GenesisApp {
         // Update listener position to player
         self.audio.set_listener_position(player_x, player_y);
 
-        // Update music based on biome
-        self.update_biome_music();
+        // Adaptive stems replace the biome track when their assets exist;
+        // otherwise music follows the biome
+        let biome_id = self.biome_under(player_x, player_y);
+        if self.update_music_stems(dt) {
+            if self.audio.state().music.is_playing() {
+                self.audio.stop_music(Some(2.0));
+            }
+        } else {
+            self.update_biome_music(biome_id);
+        }
 
         // Update ambient based on environment
         self.update_ambient_audio();
+        self.update_biome_ambience(dt, biome_id);
+        self.update_footsteps(dt, biome_id, player_x, player_y);
 
         // Process queued sounds and update fades
         self.audio.update(dt);
     }
 
     /// Updates music track based on current biome.
-    fn update_biome_music(&mut self) {
-        // Use a default biome since terrain service was removed
-        let track_name = "exploration_plains";
+    fn update_biome_music(&mut self, biome_id: i32) {
+        let hour = self.environment.time.hour();
+        let is_night = !(6..20).contains(&hour);
+        let biome = BiomeType::from_world_biome(Biome::from_id(biome_id));
+        let Some(track_name) = self.biome_music.track_for_biome(biome, is_night).asset_name() else {
+            return;
+        };
 
         // Only change if different from current (to avoid resetting)
         if self.audio.state().music.current_track.as_deref() != Some(track_name) {
@@ -974,6 +1341,25 @@ impl GenesisApp {
         }
     }
 
+    /// Fades the adaptive music stems to the director's volumes.
+    ///
+    /// Returns whether the stems are playing.
+    fn update_music_stems(&mut self, dt: f32) -> bool {
+        self.music_director.update(dt);
+
+        if !self.audio.music_stems_playing() {
+            let stems: Vec<&str> =
+                self.music_director.stems().iter().map(|layer| layer.asset.as_str()).collect();
+            if !self.audio.play_music_stems(&stems) {
+                return false;
+            }
+        }
+        for (index, layer) in self.music_director.stems().iter().enumerate() {
+            self.audio.set_music_stem_volume(index, layer.volume);
+        }
+        true
+    }
+
     /// Updates ambient audio based on environment state.
     fn update_ambient_audio(&mut self) {
         let hour = self.environment.time.hour();
@@ -1022,6 +1408,85 @@ impl GenesisApp {
         }
     }
 
+    /// Returns the generated biome ID under a world position.
+    fn biome_under(&self, x: f32, y: f32) -> i32 {
+        let tile_size = self.renderer.as_ref()
+            .map_or(1.0, |r| r.terrain_renderer().config().tile_size);
+        // Game Y maps to cubiomes Z, same as terrain generation
+        let cell = WorldPos::new(x, y).to_chunk_coord(tile_size);
+        self.world_generator.biome_at(cell.x, cell.y)
+    }
+
+    /// Returns the terrain height gradient (rise per unit of run) under a
+    /// world position, from the generated heights of the neighbouring cells.
+    fn height_gradient_at(&self, x: f32, y: f32) -> genesis_gameplay::Vec2 {
+        let tile_size = self.renderer.as_ref()
+            .map_or(1.0, |r| r.terrain_renderer().config().tile_size);
+        let cell = WorldPos::new(x, y).to_chunk_coord(tile_size);
+        let height = |dx: i32, dz: i32| self.world_generator.height_at(cell.x + dx, cell.y + dz);
+        // Neighbours are two 1:4 cells (8 blocks) apart
+        genesis_gameplay::Vec2::new(
+            (height(1, 0) - height(-1, 0)) / 8.0,
+            (height(0, 1) - height(0, -1)) / 8.0,
+        )
+    }
+
+    /// Cross-fades the biome ambient layer as the player changes biome group.
+    fn update_biome_ambience(&mut self, dt: f32, biome_id: i32) {
+        self.ambient_director.update(biome_id, dt);
+
+        // Each biome layer gets its own channel so old and new overlap
+        for fade in self.ambient_director.take_crossfades() {
+            if let Some(layer) = fade.from {
+                self.audio.fade_out_ambient(&format!("biome_{}", layer.name), fade.duration);
+            }
+            if let Some(layer) = fade.to {
+                let channel = format!("biome_{}", layer.name);
+                self.audio.fade_in_ambient(&channel, &layer.name, layer.volume, fade.duration);
+            }
+        }
+    }
+
+    /// Plays a footstep for the surface under the player once per stride.
+    fn update_footsteps(&mut self, dt: f32, biome_id: i32, player_x: f32, player_y: f32) {
+        let player = &self.gameplay.player;
+        let distance = player.velocity().length() * dt;
+        let running = player.state() == genesis_gameplay::player::PlayerState::Running;
+
+        let biome = BiomeType::from_world_biome(Biome::from_id(biome_id));
+        let surface = biome_to_surface(biome);
+        self.sound_triggers.update_player_context(
+            PlayerSoundContext::new().with_surface(surface).with_running(running),
+        );
+        if self.sound_triggers.advance_stride(distance) {
+            self.play_sfx(SoundTriggerSystem::footstep_for(surface), Some((player_x, player_y)));
+        }
+    }
+
+    /// Forwards reactions read back from the cell simulation to gameplay.
+    fn update_sim_events(&mut self) {
+        let Some(renderer) = &mut self.renderer else {
+            return;
+        };
+        let Some(batch) = renderer.poll_simulation_events() else {
+            return;
+        };
+        if batch.dropped > 0 {
+            debug!("Dropped {} simulation events", batch.dropped);
+        }
+
+        // One sound per frame covers a burst of reactions
+        let sound = batch.events.iter().find_map(|event| match *event {
+            SimEvent::ReactionOccurred { x, y, from, to } => {
+                SoundTriggerSystem::reaction_sound(u16::from(from), u16::from(to))
+                    .map(|name| (name, x as f32, y as f32))
+            },
+        });
+        if let Some((name, x, y)) = sound {
+            self.play_sfx(name, Some((x, y)));
+        }
+    }
+
     /// Plays a sound effect for a gameplay event.
     #[allow(dead_code)]
     pub fn play_sfx(&mut self, name: &str, position: Option<(f32, f32)>) {
@@ -1040,12 +1505,16 @@ impl GenesisApp {
     }
 
     /// Captures a screenshot of the current game view.
-    fn capture_screenshot(&mut self) {
-        self.capture_screenshot_with_name(None);
+    fn capture_screenshot(&mut self, capture: CaptureConfig) {
+        self.capture_screenshot_with_name(None, capture);
     }
 
     /// Captures a screenshot with an optional custom filename.
-    fn capture_screenshot_with_name(&mut self, custom_name: Option<String>) -> Option<std::path::PathBuf> {
+    fn capture_screenshot_with_name(
+        &mut self,
+        custom_name: Option<String>,
+        capture: CaptureConfig,
+    ) -> Option<std::path::PathBuf> {
         let screenshots_dir = std::path::PathBuf::from("screenshots");
 
         let filename = custom_name.unwrap_or_else(|| {
@@ -1060,7 +1529,7 @@ impl GenesisApp {
 
         // Capture screenshot using renderer
         if let (Some(renderer), Some(window)) = (&mut self.renderer, &self.window) {
-            match renderer.capture_screenshot(&path, &self.camera, window, self.environment.time.time_of_day(), self.environment.time.sun_intensity()) {
+            match renderer.capture_screenshot(&path, &self.camera, window, self.environment.time.time_of_day(), self.environment.time.sun_intensity(), capture) {
                 Ok(saved_path) => {
                     info!("Screenshot saved: {:?}", saved_path);
                     return Some(saved_path);
@@ -1131,7 +1600,7 @@ impl GenesisApp {
                 }
                 AutomationRequest::CaptureScreenshot { filename, prompt } => {
                     info!("[AUTOMATION] Capturing screenshot");
-                    if let Some(path) = self.capture_screenshot_with_name(filename) {
+                    if let Some(path) = self.capture_screenshot_with_name(filename, CaptureConfig::full_resolution()) {
                         self.automation.record_screenshot(path.clone());
                         if let Some(prompt) = prompt {
                             info!("[AUTOMATION] Screenshot prompt: {}", prompt);
@@ -1251,17 +1720,39 @@ impl GenesisApp {
         let seed = self.config.world_seed.unwrap_or(12345);
         self.gameplay = genesis_gameplay::GameState::with_player_position(seed, (128.0, 100.0));
         self.gameplay.player.set_grounded(true);
+        self.player_needs = Needs::new();
+        self.new_game.apply_to(&mut self.gameplay_settings);
+        self.apply_gameplay_settings(seed);
 
         // Reset camera
         let player_pos = self.gameplay.player_position();
         self.camera.center_on(player_pos.0, player_pos.1);
 
-        // Reconfigure world generator with current seed
-        let worldgen_config = WorldGenConfig {
-            seed,
-            ..self.world_generator.config().clone()
-        };
-        self.world_generator.reconfigure(worldgen_config);
+        // Re-seed world generator with current seed, then precompute the
+        // chunks around spawn on a worker thread while the screen fades
+        // to black and holds until they're ready
+        self.world_generator.set_seed(seed);
+        let tile_size = self
+            .renderer
+            .as_ref()
+            .map_or(1.0, |r| r.terrain_renderer().config().tile_size);
+        let spawn_chunk = WorldPos::new(player_pos.0, player_pos.1)
+            .to_chunk_coord(16.0 * tile_size) // 16 biome cells per chunk
+            .into();
+        let config = self.world_generator.config().clone();
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            let generator = WorldGenerator::new(config);
+            generator.warmup_region(spawn_chunk, 4);
+            // The receiver is gone if another game started meanwhile
+            let _ = tx.send(generator);
+        });
+        self.spawn_warmup = Some(rx);
+        let ready = Arc::new(AtomicBool::new(false));
+        self.spawn_ready = Arc::clone(&ready);
+        self.transition.fade_to_black();
+        self.transition
+            .hold_until(move || ready.load(Ordering::Acquire));
         self.terrain_dirty = true;
         self.last_terrain_chunk = (i32::MAX, i32::MAX);
 
@@ -1272,11 +1763,72 @@ impl GenesisApp {
         }
     }
 
+    /// Replaces the world generator with the one warmed up around spawn
+    /// once its worker thread finishes, releasing the held fade.
+    fn poll_spawn_warmup(&mut self) {
+        let Some(rx) = &self.spawn_warmup else {
+            return;
+        };
+        match rx.try_recv() {
+            Ok(generator) => self.world_generator = generator,
+            Err(mpsc::TryRecvError::Empty) => return,
+            Err(mpsc::TryRecvError::Disconnected) => {
+                warn!("Spawn warmup thread exited early; generating terrain on demand");
+            },
+        }
+        self.spawn_warmup = None;
+        self.spawn_ready.store(true, Ordering::Release);
+        self.terrain_dirty = true;
+    }
+
+    /// Applies the current gameplay settings to the systems they tune.
+    fn apply_gameplay_settings(&mut self, seed: u64) {
+        let settings = &self.gameplay_settings;
+        info!("Difficulty: {:?}", settings.difficulty);
+
+        // Respawn NPCs around the player with the new hostile rate
+        let spawn_config = genesis_gameplay::NPCSpawnConfig::with_seed(seed)
+            .with_hostile_spawn_rate(settings.hostile_spawn_rate);
+        self.npc_spawner = genesis_gameplay::NPCChunkSpawner::new(spawn_config);
+        let chunk_size = self.npc_spawner.config().chunk_size as f32;
+        let (cx, cy) = WorldPos::from(self.gameplay.player_position())
+            .to_chunk_coord(chunk_size)
+            .into();
+        self.last_player_chunk = (cx, cy);
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                self.npc_spawner
+                    .on_chunk_loaded((cx + dx, cy + dy), self.gameplay.npc_manager_mut());
+            }
+        }
+        let settings = &self.gameplay_settings;
+
+        let minutes = settings.auto_save_interval;
+        self.autosave_manager.update_config(
+            self.autosave_manager
+                .config()
+                .clone()
+                .with_interval(f64::from(minutes) * 60.0)
+                .with_enabled(minutes > 0),
+        );
+
+        self.show_controls_help = settings.show_tutorials;
+    }
+
     /// Updates crafting system for the frame.
-    fn update_crafting(&mut self, _dt: f32) {
-        // Check for recipe hot-reload in debug mode
-        if self.recipe_loader.check_hot_reload() {
-            info!("Recipes hot-reloaded");
+    fn update_crafting(&mut self, dt: f32) {
+        // Match the grid against loaded recipes for the live preview
+        self.crafting_grid.open = self.show_crafting;
+        self.crafting_grid.update(dt);
+        self.crafting_grid.update_preview(&self.recipe_matcher);
+        if let Some(preview) = self.crafting_grid.output_preview.as_mut() {
+            let recipe = preview
+                .id
+                .item_id()
+                .and_then(|id| self.recipe_loader.registry().find_by_output(id));
+            if let Some(recipe) = recipe {
+                preview.name.clone_from(&recipe.name);
+            }
         }
 
         // Process pending crafting events
@@ -1305,11 +1857,6 @@ impl GenesisApp {
 
     /// Updates combat system for the frame.
     fn update_combat(&mut self, dt: f32) {
-        // Check for weapon hot-reload in debug mode
-        if self.weapon_loader.check_hot_reload().unwrap_or(false) {
-            info!("Weapons hot-reloaded");
-        }
-
         // Handle attack input
         let attack_pressed = self.input.is_action_just_pressed(genesis_gameplay::input::Action::Punch);
         let attack_held_input = self.input.is_action_pressed(genesis_gameplay::input::Action::Punch);
@@ -1330,14 +1877,48 @@ impl GenesisApp {
             self.attack_hold_time = 0.0;
         }
 
+        // Lock-on: cycle through living NPCs in range, nearest first
+        let player_pos = self.gameplay.player.position();
+        let origin = CombatPosition::new(player_pos.x, player_pos.y);
+        if self.input.is_action_just_pressed(genesis_gameplay::input::Action::LockOn) {
+            let lock_range = self.combat_state.lock_range();
+            let mut nearby: Vec<(genesis_common::EntityId, f32)> = self
+                .gameplay
+                .npc_manager()
+                .iter()
+                .filter(|(_, npc)| !npc.health.is_dead())
+                .map(|(id, npc)| {
+                    let pos = CombatPosition::new(npc.position.0, npc.position.1);
+                    (id, origin.distance_to(&pos))
+                })
+                .filter(|&(_, distance)| distance <= lock_range)
+                .collect();
+            nearby.sort_by(|a, b| a.1.total_cmp(&b.1));
+            let candidates: Vec<genesis_common::EntityId> =
+                nearby.into_iter().map(|(id, _)| id).collect();
+            let target = self.combat_state.cycle_target(&candidates);
+            debug!("Lock-on target: {:?}", target);
+        }
+        let locked_pos = self.combat_state.locked_target().and_then(|target| {
+            self.gameplay
+                .npc_manager()
+                .iter()
+                .find(|(id, npc)| *id == target && !npc.health.is_dead())
+                .map(|(_, npc)| CombatPosition::new(npc.position.0, npc.position.1))
+        });
+        self.combat_state.validate_target(&origin, locked_pos.as_ref());
+        let locked_pos = locked_pos.filter(|_| self.combat_state.locked_target().is_some());
+
         // Process attack if button was just pressed
         if attack_pressed {
-            // Get player position and direction for attack
-            let player_pos = self.gameplay.player.position();
+            // Get player direction for attack
             let player_vel = self.gameplay.player.velocity();
 
-            // Determine attack direction from movement or facing
-            let direction = if player_vel.x.abs() > 0.1 || player_vel.y.abs() > 0.1 {
+            // Aim at the locked target, else use movement or facing
+            let aim_target = locked_pos.filter(|t| origin.distance_to(t) > 0.1);
+            let direction = if let Some(target) = aim_target {
+                origin.direction_to(&target)
+            } else if player_vel.x.abs() > 0.1 || player_vel.y.abs() > 0.1 {
                 let len = (player_vel.x * player_vel.x + player_vel.y * player_vel.y).sqrt();
                 (player_vel.x / len, player_vel.y / len)
             } else {
@@ -1405,9 +1986,25 @@ impl GenesisApp {
             }
         }
 
+        // Step projectiles fired by ranged attacks against living NPCs
+        let projectile_targets: Vec<_> = self
+            .gameplay
+            .npc_manager()
+            .iter()
+            .filter(|(_, npc)| !npc.health.is_dead())
+            .map(|(id, npc)| (id, npc.position, ENTITY_HIT_RADIUS))
+            .collect();
+        self.combat_events
+            .update_projectiles(dt, &projectile_targets);
+
         // Start profiling event processing
         self.combat_profiler.start_event_processing();
 
+        // Dash i-frames make the player ignore hits
+        let player_id = genesis_common::EntityId::from_raw(1);
+        self.combat_events
+            .set_invulnerable(player_id, self.gameplay.player.is_invulnerable());
+
         // Process pending combat events
         let result = self.combat_events.process_events(Some(&mut self.audio));
 
@@ -1416,6 +2013,53 @@ impl GenesisApp {
             result.attacks.len() + result.hits.len() + result.deaths.len()
         );
 
+        // Any exchange of blows brings in the combat stem
+        if !result.attacks.is_empty() || !result.hits.is_empty() {
+            self.music_director.notify_combat();
+        }
+
+        // Apply hits on the player; a lethal one queues the player's death
+        let enemy_damage = self.gameplay_settings.enemy_damage_multiplier;
+        for hit in result.hits.iter().filter(|hit| hit.target == player_id) {
+            if self.combat_persistence.player().is_dead {
+                break;
+            }
+            let damage = hit.damage * enemy_damage;
+            let health = self.combat_persistence.player().health - damage;
+            self.combat_persistence.player_mut().set_health(health);
+            self.combat_persistence.record_damage_taken(damage);
+            if self.combat_persistence.player().is_dead {
+                self.combat_events.queue_event(CombatEventHandler::make_death_event(
+                    player_id,
+                    Some(hit.attacker),
+                    hit.position,
+                    DeathCause::Combat,
+                    0,
+                ));
+            }
+        }
+
+        // Apply the player's hits to NPCs; a lethal one queues the NPC's death
+        let player_damage = self.gameplay_settings.player_damage_multiplier;
+        for hit in result.hits.iter().filter(|hit| hit.attacker == player_id) {
+            let Some(npc) = self.gameplay.npc_manager_mut().get_mut(hit.target) else {
+                continue;
+            };
+            if npc.health.is_dead() {
+                continue;
+            }
+            npc.health.damage((hit.damage * player_damage).round() as i32);
+            if npc.health.is_dead() {
+                self.combat_events.queue_event(CombatEventHandler::make_death_event(
+                    hit.target,
+                    Some(player_id),
+                    hit.position,
+                    DeathCause::Combat,
+                    0,
+                ));
+            }
+        }
+
         // Handle deaths
         for death in &result.deaths {
             if death.entity.raw() == 1 {
@@ -1440,10 +2084,21 @@ impl GenesisApp {
             self.combat_persistence.player_mut().stamina = new_stamina;
         }
 
+        // Feed the combat HUD from the player's state
+        let player = self.combat_persistence.player();
+        let player_state = PlayerCombatState {
+            health: player.health,
+            max_health: player.max_health,
+            stamina: player.stamina,
+            max_stamina: player.max_stamina,
+            attack_cooldown: player.attack_cooldown,
+        };
+        self.combat_hud.update(dt, &player_state);
+
         // Update combat memory usage for profiling
         self.combat_profiler.update_memory(
             1, // Player entity
-            0, // Active projectiles (would come from a projectile system)
+            self.combat_events.active_projectiles(),
             self.combat_persistence.player().status_effects.len(),
             self.weapon_loader.registry().len(),
         );
@@ -1471,11 +2126,13 @@ impl GenesisApp {
         // Process auto-save if conditions are met
         if self.app_mode == AppMode::Playing {
             let save_data = self.build_save_data("autosave");
-            if self.autosave_manager.check_and_save(&mut self.save_manager, &save_data) {
-                debug!("Auto-save completed");
+            if self.autosave_manager.check_and_submit(&self.saver, &save_data) {
+                debug!("Auto-save queued");
             }
         }
 
+        self.process_save_responses();
+
         // Check for auto-save pause conditions
         // (Combat pause is handled by combat_events integration)
     }
@@ -1484,8 +2141,11 @@ impl GenesisApp {
     fn build_save_data(&self, slot_name: &str) -> crate::save_manager::SaveFileData {
         let player_pos = self.gameplay.player.position();
 
+        let display_name = SaveSlotId::from_slot_name(slot_name)
+            .map_or_else(|| format!("Slot {slot_name}"), |id| id.to_string());
+
         SaveFileBuilder::new(slot_name)
-            .display_name(format!("Slot {}", slot_name))
+            .display_name(display_name)
             .player_position(player_pos.x, player_pos.y)
             .world_seed(self.config.world_seed.unwrap_or(12345))
             .game_time(self.gameplay.game_time() as f64)
@@ -1497,27 +2157,42 @@ impl GenesisApp {
             .build()
     }
 
-    /// Performs a quicksave operation.
-    fn quicksave(&mut self) {
-        info!("Quicksave requested");
-        let save_data = self.build_save_data("quicksave");
-
-        match self.save_manager.quicksave(&save_data) {
-            Ok(()) => {
-                self.current_save_slot = Some("quicksave".to_string());
-                info!("Quicksave successful");
+    /// Handles saves finished by the background worker.
+    fn process_save_responses(&mut self) {
+        let mut refresh_menu = false;
+        for response in self.saver.poll_responses() {
+            refresh_menu = true;
+            if self.autosave_manager.on_save_response(&response) {
+                continue;
             }
-            Err(e) => {
-                warn!("Quicksave failed: {}", e);
+            if response.success {
+                info!("Game saved to slot: {}", response.slot_name);
+                self.current_save_slot = Some(response.slot_name);
+            } else {
+                let error = response.error.unwrap_or_default();
+                warn!("Save to {} failed: {}", response.slot_name, error);
+                if response.priority == SavePriority::Manual {
+                    self.save_management.show_error("Save Failed", error);
+                }
             }
         }
+        if refresh_menu && self.save_menu.is_open() {
+            self.refresh_save_menu();
+        }
+    }
+
+    /// Performs a quicksave operation.
+    fn quicksave(&mut self) {
+        info!("Quicksave requested");
+        self.save_game(QUICKSAVE_SLOT);
     }
 
     /// Performs a quickload operation.
     fn quickload(&mut self) {
         info!("Quickload requested");
 
-        match self.save_manager.quickload() {
+        let loaded = self.saver.manager().quickload();
+        match loaded {
             Ok(save_data) => {
                 self.apply_save_data(&save_data);
                 self.current_save_slot = Some("quicksave".to_string());
@@ -1529,26 +2204,20 @@ impl GenesisApp {
         }
     }
 
-    /// Saves the game to a specific slot.
-    #[allow(dead_code)]
-    fn save_game(&mut self, slot_name: &str) -> Result<()> {
+    /// Queues a save of the game to a specific slot on the save worker.
+    ///
+    /// The result is picked up by `process_save_responses`.
+    fn save_game(&mut self, slot_name: &str) {
         info!("Saving game to slot: {}", slot_name);
         let save_data = self.build_save_data(slot_name);
-
-        self.save_manager.save(slot_name, &save_data)
-            .map_err(|e| anyhow::anyhow!("Save failed: {}", e))?;
-
-        self.current_save_slot = Some(slot_name.to_string());
-        info!("Game saved to slot: {}", slot_name);
-        Ok(())
+        self.saver.submit(SaveRequest::new(slot_name, save_data, SavePriority::Manual));
     }
 
     /// Loads the game from a specific slot.
-    #[allow(dead_code)]
     fn load_game(&mut self, slot_name: &str) -> Result<()> {
         info!("Loading game from slot: {}", slot_name);
 
-        let save_data = self.save_manager.load(slot_name)
+        let save_data = self.saver.manager().load(slot_name)
             .map_err(|e| anyhow::anyhow!("Load failed: {}", e))?;
 
         self.apply_save_data(&save_data);
@@ -1557,6 +2226,75 @@ impl GenesisApp {
         Ok(())
     }
 
+    /// Persists the edited biome palette so it survives a restart.
+    ///
+    /// Edits only mark the palette dirty; this runs once editing is done
+    /// (world tools closed or the app exiting).
+    fn save_biome_palette(&mut self) {
+        if !self.biome_palette_dirty {
+            return;
+        }
+        self.biome_palette_dirty = false;
+        if let Err(e) = self.biome_texture_map.save_toml(&EngineConfig::biome_colors_path()) {
+            warn!("Failed to save biome colors: {e}");
+        }
+    }
+
+    /// Rebuilds the biome palette from the accessibility setting, dropping
+    /// any per-biome edits, and redraws the terrain with it.
+    fn reset_biome_palette(&mut self) {
+        self.biome_texture_map = biome_palette(self.config.high_contrast);
+        self.biome_palette_dirty = false;
+        let path = EngineConfig::biome_colors_path();
+        if path.exists() {
+            if let Err(e) = std::fs::remove_file(&path) {
+                warn!("Failed to remove saved biome colors: {e}");
+            }
+        }
+        self.terrain_dirty = true;
+        self.last_terrain_chunk = (i32::MAX, i32::MAX);
+        if let Some(renderer) = &mut self.renderer {
+            renderer.terrain_renderer_mut().clear_cache();
+        }
+        // Re-sync biome entries to UI
+        let entries: Vec<genesis_tools::ui::BiomeUiEntry> = self.biome_texture_map.sorted_entries()
+            .iter()
+            .map(|e| genesis_tools::ui::BiomeUiEntry {
+                id: e.id,
+                name: e.name.clone(),
+                color: self.biome_texture_map.get_color(e.id),
+                texture_path: match &e.visual {
+                    genesis_worldgen::BiomeVisual::Texture(p) => Some(p.clone()),
+                    genesis_worldgen::BiomeVisual::Color(_) => None,
+                },
+            })
+            .collect();
+        self.world_tools.world_gen_panel_mut().set_biome_entries(entries);
+    }
+
+    /// Refreshes the save menu slots from the save metadata index.
+    fn refresh_save_menu(&mut self) {
+        let slot_count = self.save_menu.config().slot_count;
+        let slots = self.saver.manager().numbered_slots(slot_count);
+        match slots {
+            Ok(slots) => {
+                let slots = save_slot_infos(slots);
+                let has_recent = slots.iter().any(|s| s.is_most_recent);
+                self.save_menu.set_slots(slots);
+                self.save_menu.set_has_recent_save(has_recent);
+            }
+            Err(e) => {
+                warn!("Failed to list save slots: {}", e);
+            }
+        }
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        self.save_menu.set_current_time(now);
+        self.save_menu.set_can_save(self.app_mode != AppMode::Menu);
+    }
+
     /// Applies loaded save data to the game state.
     fn apply_save_data(&mut self, save_data: &crate::save_manager::SaveFileData) {
         // Restore player position using Vec2
@@ -1612,6 +2350,14 @@ impl GenesisApp {
         let show_map = self.show_map;
         let hotbar_slot = self.hotbar_slot;
         let app_mode = self.app_mode;
+        // The fade covers the screen completely while it's held
+        let transition_alpha = if self.transition.is_holding() {
+            1.0
+        } else if self.transition.is_active() {
+            self.transition.alpha()
+        } else {
+            0.0
+        };
         // Extract terrain renderer stats before borrowing renderer for rendering
         let (terrain_enabled, terrain_cached_chunks, terrain_instance_count) =
             if let Some(renderer) = &self.renderer {
@@ -1656,14 +2402,43 @@ impl GenesisApp {
 
         // Get mutable refs to menus for the closure
         let main_menu = &mut self.main_menu;
+        let new_game_wizard = &mut self.new_game_wizard;
         let pause_menu = &mut self.pause_menu;
+        let inventory_ui = &mut self.inventory_ui;
+        let recipes = self.recipe_loader.registry();
+        let inventory_model = &mut self.inventory_model;
+        inventory_model.sync_from_inventory(&self.player_inventory, |id| item_name(recipes, id));
+        inventory_model.is_open = show_inventory;
+        let mut inventory_actions = Vec::new();
+        // What the grid held before this frame, so items it drops (Clear,
+        // output click) can go back to the inventory
+        let grid_before = CraftingGridSnapshot::take(&self.crafting_grid);
+        let crafting_grid = &mut self.crafting_grid;
+        let combat_hud = &self.combat_hud;
+        let mut crafting_actions = Vec::new();
         let options_menu = &mut self.options_menu;
+        let save_menu = &mut self.save_menu;
+        let save_management = &mut self.save_management;
         let world_tools = &mut self.world_tools;
         let show_controls_help = self.show_controls_help;
+        let inspector = &mut self.inspector;
+        let gameplay = &mut self.gameplay;
 
         if let (Some(renderer), Some(window)) = (&mut self.renderer, &self.window) {
+            // Rain and storms darken the scene and fog in the edges
+            let weather = &self.environment.weather;
+            renderer.terrain_renderer_mut().set_weather(weather.color_tint(), weather.fog_density());
+
             // Use render_with_ui to draw world + egui overlay
             let result = renderer.render_with_ui(window, &self.camera, self.environment.time.time_of_day(), self.environment.time.sun_intensity(), |ctx| {
+                // Screen fade goes on its own layer above every menu
+                if transition_alpha > 0.0 {
+                    let layer =
+                        egui::LayerId::new(egui::Order::Foreground, egui::Id::new("transition"));
+                    let color = egui::Color32::from_black_alpha((transition_alpha * 255.0) as u8);
+                    ctx.layer_painter(layer).rect_filled(ctx.screen_rect(), 0.0, color);
+                }
+
                 // Render options menu on top if visible (works from any mode)
                 if options_menu.is_visible() {
                     egui::CentralPanel::default()
@@ -1674,6 +2449,27 @@ impl GenesisApp {
                     return; // Don't render underlying menu when options is open
                 }
 
+                // Render save/load menu on top if open (main menu or paused)
+                if save_menu.is_open() {
+                    egui::CentralPanel::default()
+                        .frame(egui::Frame::none().fill(egui::Color32::from_rgba_unmultiplied(0, 0, 0, 200)))
+                        .show(ctx, |ui| {
+                            save_menu.show(ui);
+                            save_management.render(ui);
+                        });
+                    return; // Don't render underlying menu when save menu is open
+                }
+
+                // Render the new game wizard in place of the main menu
+                if new_game_wizard.is_visible() {
+                    egui::CentralPanel::default()
+                        .frame(egui::Frame::none().fill(egui::Color32::from_rgb(20, 20, 30)))
+                        .show(ctx, |ui| {
+                            new_game_wizard.render(ui);
+                        });
+                    return;
+                }
+
                 // Render world tools on top if visible (works from paused mode)
                 if world_tools.is_visible() {
                     egui::CentralPanel::default()
@@ -1713,19 +2509,19 @@ impl GenesisApp {
                         if show_debug {
                             render_debug_overlay(ctx, &debug_data);
                         }
+                        inspector.render_entities(ctx, gameplay);
                     }
                     AppMode::Playing => {
                         // Normal gameplay UI
                         if show_debug {
                             render_debug_overlay(ctx, &debug_data);
                         }
+                        inspector.render_entities(ctx, gameplay);
 
-                        if show_inventory {
-                            render_inventory(ctx, hotbar_slot);
-                        }
+                        inventory_actions = inventory_ui.show(ctx, inventory_model);
 
                         if show_crafting {
-                            render_crafting(ctx);
+                            crafting_actions = render_crafting(ctx, crafting_grid);
                         }
 
                         if show_map {
@@ -1734,6 +2530,7 @@ impl GenesisApp {
 
                         // Always show HUD elements (hotbar, vitals, minimap)
                         render_hud(ctx, hotbar_slot, &environment_time, &environment_weather);
+                        render_combat_hud(ctx, combat_hud);
 
                         // Show interaction prompt if near an NPC
                         render_interaction_prompt(ctx, &interaction_data);
@@ -1747,9 +2544,144 @@ impl GenesisApp {
         }
 
         // Process menu actions after rendering
+        self.process_inventory_actions(inventory_actions);
+        self.process_crafting_actions(crafting_actions, &grid_before);
         self.process_menu_actions();
     }
 
+    /// Applies inventory window actions to the player's inventory.
+    ///
+    /// The window mirrors the inventory, so anything rejected here (like a
+    /// split stack, which the one-slot-per-item inventory can't hold) snaps
+    /// back on the next sync.
+    fn process_inventory_actions(&mut self, actions: Vec<InventoryAction>) {
+        for action in actions {
+            match action {
+                InventoryAction::Move { from, to } => {
+                    if let Err(e) = self.player_inventory.move_slot(from, to) {
+                        debug!("Inventory move {from} -> {to} rejected: {e}");
+                    }
+                },
+                InventoryAction::SelectHotbar(slot) => {
+                    self.player_inventory.select_hotbar(slot);
+                    self.hotbar_slot = u8::try_from(self.player_inventory.selected_hotbar())
+                        .unwrap_or(self.hotbar_slot);
+                },
+                // Clicking a slot with the crafting grid open stages one item
+                InventoryAction::Select(slot) if self.show_crafting => {
+                    self.stage_for_crafting(slot);
+                },
+                _ => {},
+            }
+        }
+    }
+
+    /// Moves one item from an inventory slot into the crafting grid,
+    /// stacking onto a grid slot that already holds it if possible.
+    fn stage_for_crafting(&mut self, slot: usize) {
+        let Some(stack) = self.player_inventory.slot(slot) else {
+            return;
+        };
+        let id = CraftingItemId::from_item_id(stack.item_type.raw());
+        let grid = &mut self.crafting_grid;
+        let target = grid
+            .slots
+            .iter()
+            .position(|s| s.item.as_ref().is_some_and(|i| i.id == id && i.can_stack(1)))
+            .or_else(|| grid.slots.iter().position(|s| s.is_empty() && !s.locked));
+        let Some(target) = target else {
+            return;
+        };
+        if self.player_inventory.remove(stack.item_type, 1).is_err() {
+            return;
+        }
+        if let Some(item) = grid.slots[target].item.as_mut() {
+            item.add_to_stack(1);
+        } else {
+            let name = item_name(self.recipe_loader.registry(), stack.item_type);
+            grid.set_item(target, CraftingItem::new(id.0, name));
+        }
+    }
+
+    /// Adds a crafting item to the player's inventory, returning it if it
+    /// doesn't fit.
+    fn return_to_inventory(&mut self, item: CraftingItem) -> Option<CraftingItem> {
+        let Some(item_id) = item.id.item_id() else {
+            return Some(item);
+        };
+        match self.player_inventory.add(ItemTypeId::new(item_id), item.count) {
+            Ok(()) => None,
+            Err(e) => {
+                warn!("Inventory can't take {} {}: {e}", item.count, item.name);
+                Some(item)
+            },
+        }
+    }
+
+    /// Handles crafting grid actions from the last frame.
+    ///
+    /// Items only enter the grid from the player's inventory, and go back
+    /// to it when removed, cleared or crafted into something new.
+    fn process_crafting_actions(
+        &mut self,
+        actions: Vec<CraftingGridAction>,
+        before: &CraftingGridSnapshot,
+    ) {
+        for action in actions {
+            match action {
+                CraftingGridAction::CraftClicked => self.craft_into_inventory(),
+                CraftingGridAction::SlotClicked { slot } => {
+                    if let Some(item) = self.crafting_grid.remove_item(slot) {
+                        if let Some(item) = self.return_to_inventory(item) {
+                            self.crafting_grid.slots[slot].set_item(item);
+                        }
+                    }
+                },
+                CraftingGridAction::ClearGrid => {
+                    for (slot, item) in before.slots.iter().enumerate() {
+                        let Some(item) = item.clone() else {
+                            continue;
+                        };
+                        // Keep anything that doesn't fit where it was
+                        if let Some(item) = self.return_to_inventory(item) {
+                            self.crafting_grid.slots[slot].set_item(item);
+                        }
+                    }
+                },
+                CraftingGridAction::OutputCollected { .. } => {
+                    if let Some(item) = before.output.clone() {
+                        if let Some(item) = self.return_to_inventory(item) {
+                            self.crafting_grid.output.item = Some(item);
+                        }
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+
+    /// Crafts the matched recipe and puts the result in the inventory.
+    fn craft_into_inventory(&mut self) {
+        let Some(output) = self.crafting_grid.craft_matched() else {
+            return;
+        };
+        let Some(item_id) = output.id.item_id() else {
+            return;
+        };
+
+        if let Some(recipe) = self.recipe_loader.registry().find_by_output(item_id) {
+            self.crafting_events.queue_event(CraftingEventHandler::make_complete_event(
+                genesis_common::EntityId::from_raw(1), // Player entity ID
+                recipe,
+            ));
+        }
+
+        if let Some(leftover) = self.return_to_inventory(output) {
+            // Keep what didn't fit in the output slot rather than losing it
+            self.crafting_grid.output.item = Some(leftover);
+        }
+    }
+
     /// Process any pending menu actions
     fn process_menu_actions(&mut self) {
         // Process main menu actions
@@ -1761,9 +2693,9 @@ impl GenesisApp {
             info!("Main menu action: {:?}", action);
             match action {
                 MainMenuAction::NewGame => {
-                    info!("Starting new game...");
-                    self.app_mode = AppMode::Playing;
+                    info!("Opening new game wizard...");
                     self.main_menu.hide();
+                    self.new_game_wizard.show();
                 }
                 MainMenuAction::Continue => {
                     info!("Continuing game...");
@@ -1773,7 +2705,8 @@ impl GenesisApp {
                 }
                 MainMenuAction::OpenLoadMenu => {
                     info!("Opening load menu...");
-                    // TODO: Show load game menu
+                    self.refresh_save_menu();
+                    self.save_menu.open_load();
                 }
                 MainMenuAction::OpenOptions => {
                     info!("Opening options menu...");
@@ -1788,6 +2721,22 @@ impl GenesisApp {
             }
         }
 
+        // Process new game wizard actions
+        for action in self.new_game_wizard.drain_actions() {
+            match action {
+                NewGameWizardAction::Create(config) => {
+                    info!("Creating world '{}'", config.world_name);
+                    self.config.world_seed = Some(config.seed_value);
+                    self.new_game = config;
+                    self.start_new_game();
+                }
+                NewGameWizardAction::Cancel | NewGameWizardAction::Close => {
+                    self.main_menu.show();
+                }
+                _ => {}
+            }
+        }
+
         // Process pause menu actions
         for action in self.pause_menu.drain_actions() {
             match action {
@@ -1798,11 +2747,15 @@ impl GenesisApp {
                 }
                 PauseMenuAction::OpenSaveMenu => {
                     info!("Opening save menu...");
-                    // TODO: Show save menu
+                    self.refresh_save_menu();
+                    self.save_menu.open_save();
+                    self.pause_menu.hide();
                 }
                 PauseMenuAction::OpenLoadMenu => {
                     info!("Opening load menu...");
-                    // TODO: Show load menu
+                    self.refresh_save_menu();
+                    self.save_menu.open_load();
+                    self.pause_menu.hide();
                 }
                 PauseMenuAction::OpenOptions => {
                     info!("Opening options menu...");
@@ -1838,29 +2791,118 @@ impl GenesisApp {
                     let camera_zoom = self.options_menu.settings().graphics.camera_zoom;
                     self.camera.set_zoom(camera_zoom);
                     info!("Camera zoom set to: {}", camera_zoom);
+                    let high_contrast = self.options_menu.settings().graphics.high_contrast_biomes;
+                    if high_contrast != self.config.high_contrast {
+                        self.config.high_contrast = high_contrast;
+                        self.reset_biome_palette();
+                    }
+                    self.applied_key_bindings.clone_from(&self.config.key_bindings);
+                    if let Err(e) = self.config.save() {
+                        warn!("Failed to save config: {e}");
+                    }
                     self.options_menu.hide();
                 }
                 OptionsMenuAction::Cancel => {
                     info!("Cancelling options...");
+                    // Undo rebinds made since the options were last applied
+                    self.input_bindings = InputRebindManager::new();
+                    self.input_bindings.import_from_map(&self.applied_key_bindings);
+                    self.input_bindings.apply_to(self.input.manager_mut());
+                    self.config.key_bindings.clone_from(&self.applied_key_bindings);
                     self.options_menu.hide();
                 }
-                OptionsMenuAction::ResetToDefaults => {
+                OptionsMenuAction::ResetToDefaults
+                | OptionsMenuAction::ResetTab(OptionsTab::Controls) => {
                     info!("Resetting options to defaults...");
+                    self.input_bindings.reset_to_defaults();
+                    self.config.key_bindings = self.input_bindings.export_to_map();
+                    self.input_bindings.apply_to(self.input.manager_mut());
                 }
                 OptionsMenuAction::CameraZoomChanged(zoom) => {
                     // Apply zoom immediately for live preview
                     self.camera.set_zoom(zoom);
                 }
+                OptionsMenuAction::BusVolumeChanged(bus, volume) => {
+                    // Apply live; persisted when the options are applied
+                    self.audio.set_bus_volume(bus, volume);
+                    self.config.set_bus_volume(bus, volume);
+                }
+                OptionsMenuAction::KeyRebound(action, key) => {
+                    // The menu already warned about duplicates, so keep both
+                    let other = self.input_bindings.force_rebind_primary(action.into(), &key);
+                    if let Some(other) = other {
+                        warn!("Key {} is bound to both {:?} and {:?}", key, action, other);
+                    }
+                    self.config.key_bindings = self.input_bindings.export_to_map();
+                    self.input_bindings.apply_to(self.input.manager_mut());
+                }
                 _ => {}
             }
         }
 
+        // Process save menu actions
+        for action in self.save_menu.drain_actions() {
+            match action {
+                SaveMenuAction::SaveToSlot(id) => self.save_game(&id.slot_name()),
+                SaveMenuAction::LoadFromSlot(id) => {
+                    match self.load_game(&id.slot_name()) {
+                        Ok(()) => {
+                            self.save_menu.close();
+                            self.main_menu.hide();
+                            self.pause_menu.hide();
+                            self.app_mode = AppMode::Playing;
+                        }
+                        Err(e) => {
+                            warn!("{}", e);
+                            self.save_management.show_error("Load Failed", e.to_string());
+                        }
+                    }
+                }
+                SaveMenuAction::DeleteSlot(id) => {
+                    let name = self
+                        .save_menu
+                        .get_slot(id)
+                        .map_or_else(|| id.to_string(), SaveSlotInfo::display_title);
+                    self.save_management.open_delete(id.0, name);
+                }
+                SaveMenuAction::Close => {
+                    self.save_management.close_dialog();
+                    if self.app_mode == AppMode::Paused {
+                        self.pause_menu.show();
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Process save management dialog actions
+        for action in self.save_management.drain_actions() {
+            if let SaveManagementAction::ConfirmDelete(slot) = action {
+                let slot_name = SaveSlotId::new(slot).slot_name();
+                let deleted = self.saver.manager().delete_slot(&slot_name);
+                match deleted {
+                    Ok(()) => {
+                        if self.current_save_slot.as_deref() == Some(slot_name.as_str()) {
+                            self.current_save_slot = None;
+                        }
+                        self.save_menu.deselect_slot();
+                        self.refresh_save_menu();
+                    }
+                    Err(e) => {
+                        warn!("Failed to delete {}: {}", slot_name, e);
+                        self.save_management.show_error("Delete Failed", e.to_string());
+                    }
+                }
+            }
+        }
+
         // Process world tools actions
         for action in self.world_tools.drain_actions() {
             match action {
                 WorldToolsAction::Close => {
                     info!("Closing world tools...");
                     self.world_tools.hide();
+                    self.save_biome_palette();
                     self.pause_menu.show();
                 }
             }
@@ -1870,7 +2912,7 @@ impl GenesisApp {
         for action in self.world_tools.drain_world_gen_actions() {
             match action {
                 genesis_tools::ui::WorldGenAction::Regenerate { mc_version, seed, flags, scale, y_level, tile_size } => {
-                    info!("Regenerating world: mc={}, seed={}, flags={}, scale={}, y={}, tile_size={}",
+                    info!("Regenerating world: mc={}, seed={}, flags={:?}, scale={}, y={}, tile_size={}",
                         mc_version, seed, flags, scale, y_level, tile_size);
                     let config = WorldGenConfig {
                         mc_version,
@@ -1878,6 +2920,7 @@ impl GenesisApp {
                         flags,
                         scale,
                         y_level,
+                        ..self.world_generator.config().clone()
                     };
                     self.world_generator.reconfigure(config);
                     self.terrain_dirty = true;
@@ -1889,12 +2932,15 @@ impl GenesisApp {
                             tile_size,
                             biome_scale: scale,
                             render_radius: if tile_size <= 1.0 { 80 } else if tile_size <= 2.0 { 48 } else if tile_size <= 4.0 { 16 } else { 8 },
+                            sea_level: self.world_generator.config().sea_level,
+                            ..Default::default()
                         });
                         terrain.enable();
                     }
                 }
                 genesis_tools::ui::WorldGenAction::SetBiomeColor { biome_id, color } => {
                     self.biome_texture_map.set_color(biome_id, color);
+                    self.biome_palette_dirty = true;
                     self.terrain_dirty = true;
                     self.last_terrain_chunk = (i32::MAX, i32::MAX);
                     if let Some(renderer) = &mut self.renderer {
@@ -1903,29 +2949,11 @@ impl GenesisApp {
                 }
                 genesis_tools::ui::WorldGenAction::SetBiomeTexture { biome_id, path } => {
                     self.biome_texture_map.set_texture(biome_id, path);
+                    self.biome_palette_dirty = true;
                     // Texture rendering not yet implemented, but record the mapping
                 }
                 genesis_tools::ui::WorldGenAction::ResetBiomeColors => {
-                    self.biome_texture_map = BiomeTextureMap::from_cubiomes_defaults();
-                    self.terrain_dirty = true;
-                    self.last_terrain_chunk = (i32::MAX, i32::MAX);
-                    if let Some(renderer) = &mut self.renderer {
-                        renderer.terrain_renderer_mut().clear_cache();
-                    }
-                    // Re-sync biome entries to UI
-                    let entries: Vec<genesis_tools::ui::BiomeUiEntry> = self.biome_texture_map.sorted_entries()
-                        .iter()
-                        .map(|e| genesis_tools::ui::BiomeUiEntry {
-                            id: e.id,
-                            name: e.name.clone(),
-                            color: self.biome_texture_map.get_color(e.id),
-                            texture_path: match &e.visual {
-                                genesis_worldgen::BiomeVisual::Texture(p) => Some(p.clone()),
-                                _ => None,
-                            },
-                        })
-                        .collect();
-                    self.world_tools.world_gen_panel_mut().set_biome_entries(entries);
+                    self.reset_biome_palette();
                 }
             }
         }
@@ -1985,6 +3013,9 @@ fn render_debug_overlay(ctx: &egui::Context, data: &DebugOverlayData) {
                 "Frame: {:.1}ms (Update: {:.1}ms, Render: {:.1}ms)",
                 data.perf.frame_time_ms, data.perf.update_time_ms, data.perf.render_time_ms
             ));
+            for section in &data.perf.sections {
+                ui.label(format!("  {}: {:.2}ms", section.name, section.avg_ms));
+            }
             ui.separator();
 
             ui.label(format!(
@@ -2070,88 +3101,58 @@ fn render_debug_overlay(ctx: &egui::Context, data: &DebugOverlayData) {
         });
 }
 
-/// Renders the inventory panel.
-fn render_inventory(ctx: &egui::Context, hotbar_slot: u8) {
-    egui::Window::new("Inventory")
-        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
-        .resizable(false)
-        .collapsible(false)
-        .show(ctx, |ui| {
-            ui.label("Inventory (Tab/I to close)");
-            ui.separator();
+/// Items held by the crafting grid at the start of a frame.
+struct CraftingGridSnapshot {
+    /// Item in each grid slot
+    slots: Vec<Option<CraftingItem>>,
+    /// Item waiting in the output slot
+    output: Option<CraftingItem>,
+}
 
-            // Display inventory slots in a grid
-            egui::Grid::new("inventory_grid")
-                .num_columns(10)
-                .spacing([4.0, 4.0])
-                .show(ui, |ui| {
-                    for row in 0..4 {
-                        for col in 0..10 {
-                            let slot = row * 10 + col;
-                            let is_selected = slot == hotbar_slot as usize && row == 0;
-                            let (rect, _response) = ui
-                                .allocate_exact_size(egui::vec2(40.0, 40.0), egui::Sense::click());
-                            let color = if is_selected {
-                                egui::Color32::from_rgb(100, 150, 200)
-                            } else {
-                                egui::Color32::from_rgb(60, 60, 60)
-                            };
-                            ui.painter()
-                                .rect_filled(rect, egui::Rounding::same(4.0), color);
-                            ui.painter().rect_stroke(
-                                rect,
-                                egui::Rounding::same(4.0),
-                                egui::Stroke::new(1.0, egui::Color32::GRAY),
-                            );
-                        }
-                        ui.end_row();
-                    }
-                });
-        });
+impl CraftingGridSnapshot {
+    fn take(grid: &CraftingGrid) -> Self {
+        Self {
+            slots: grid.slots.iter().map(|slot| slot.item.clone()).collect(),
+            output: grid.output.item.clone(),
+        }
+    }
+}
+
+/// Display name for an item type: the name of a recipe producing it, or
+/// its numeric ID.
+fn item_name(recipes: &RecipeRegistry, id: ItemTypeId) -> String {
+    recipes
+        .find_by_output(id.raw())
+        .map_or_else(|| format!("Item #{}", id.raw()), |recipe| recipe.name.clone())
 }
 
 /// Renders the crafting panel.
-fn render_crafting(ctx: &egui::Context) {
+fn render_crafting(
+    ctx: &egui::Context,
+    crafting_grid: &mut CraftingGrid,
+) -> Vec<CraftingGridAction> {
     egui::Window::new("Crafting")
         .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
         .resizable(false)
         .collapsible(false)
-        .min_width(400.0)
         .show(ctx, |ui| {
             ui.label("Crafting (C to close)");
             ui.separator();
+            crafting_grid.show(ui)
+        })
+        .and_then(|response| response.inner)
+        .unwrap_or_default()
+}
 
-            ui.horizontal(|ui| {
-                // Left side: Recipe categories
-                ui.vertical(|ui| {
-                    ui.set_min_width(120.0);
-                    ui.heading("Categories");
-                    ui.separator();
-                    for category in &["All", "Tools", "Weapons", "Armor", "Materials", "Food"] {
-                        if ui.selectable_label(false, *category).clicked() {
-                            // Category selection would be handled here
-                        }
-                    }
-                });
-
-                ui.separator();
-
-                // Right side: Recipe list
-                ui.vertical(|ui| {
-                    ui.set_min_width(250.0);
-                    ui.heading("Recipes");
-                    ui.separator();
-
-                    egui::ScrollArea::vertical()
-                        .max_height(300.0)
-                        .show(ui, |ui| {
-                            ui.label("No recipes available yet.");
-                            ui.label("");
-                            ui.label("Gather materials and discover");
-                            ui.label("new crafting recipes!");
-                        });
-                });
-            });
+/// Renders the combat HUD overlay (stamina, attack cooldown, warnings).
+fn render_combat_hud(ctx: &egui::Context, combat_hud: &CombatHud) {
+    let screen_size = ctx.screen_rect().size();
+    egui::Area::new(egui::Id::new("combat_hud"))
+        .fixed_pos(egui::Pos2::ZERO)
+        .order(egui::Order::Background)
+        .interactable(false)
+        .show(ctx, |ui| {
+            combat_hud.show(ui, screen_size);
         });
 }
 
@@ -2358,6 +3359,10 @@ impl ApplicationHandler for GenesisApp {
                         renderer.set_scale_factor(scale_factor);
                         // Enable streaming terrain with world seed from config
                         renderer.enable_streaming_terrain(self.config.world_seed.unwrap_or(12345));
+                        renderer.terrain_renderer_mut().set_streaming_config(StreamingConfig {
+                            deterministic: self.config.deterministic_streaming,
+                            ..StreamingConfig::default()
+                        });
 
                         // Always load the player sprite
                         self.load_player_sprite(&mut renderer);
@@ -2436,6 +3441,7 @@ impl ApplicationHandler for GenesisApp {
                 if let Err(e) = self.config.save() {
                     warn!("Failed to save config: {e}");
                 }
+                self.save_biome_palette();
                 event_loop.exit();
             },
             WindowEvent::Resized(new_size) => {
@@ -2483,6 +3489,7 @@ pub fn run() -> Result<()> {
     let mut auto_start = false;
     let mut use_debug_atlas = false;
     let mut use_pure_colors = false;
+    let mut deterministic_streaming = false;
 
     let mut i = 1;
     while i < args.len() {
@@ -2508,6 +3515,9 @@ pub fn run() -> Result<()> {
             "--pure-colors" | "-p" => {
                 use_pure_colors = true;
             }
+            "--deterministic" | "-D" => {
+                deterministic_streaming = true;
+            }
             "--help" | "-h" => {
                 println!("Genesis Engine - Automation Options");
                 println!("");
@@ -2516,6 +3526,7 @@ pub fn run() -> Result<()> {
                 println!("  --auto-start, -a          Auto-start game (skip main menu)");
                 println!("  --debug-atlas, -d         Use debug autotile atlas for testing");
                 println!("  --pure-colors, -p         Use pure colors (no textures) for testing");
+                println!("  --deterministic, -D       Stream terrain in a fixed, repeatable order");
                 println!("");
                 println!("Macro command format: \"action1; action2; action3\"");
                 println!("Available actions:");
@@ -2547,6 +3558,10 @@ pub fn run() -> Result<()> {
         config.use_pure_colors = true;
         info!("Pure color mode enabled via CLI");
     }
+    if deterministic_streaming {
+        config.deterministic_streaming = true;
+        info!("Deterministic terrain streaming enabled via CLI");
+    }
 
     info!("Configuration loaded:");
     info!("  Window: {}x{}", config.window_width, config.window_height);
@@ -2619,6 +3634,71 @@ pub fn run() -> Result<()> {
     Ok(())
 }
 
+/// Directory holding the saved chunk region files for a world seed.
+fn saved_chunks_dir(seed: u64) -> std::path::PathBuf {
+    std::path::Path::new("saves")
+        .join("regions")
+        .join(seed.to_string())
+}
+
+/// Biome palette for the accessibility setting.
+fn biome_palette(high_contrast: bool) -> BiomeTextureMap {
+    if high_contrast {
+        BiomeTextureMap::high_contrast()
+    } else {
+        BiomeTextureMap::from_cubiomes_defaults()
+    }
+}
+
+/// Saved biome colors if the user has edited any, otherwise the palette
+/// for the accessibility setting.
+fn load_biome_palette(high_contrast: bool) -> BiomeTextureMap {
+    let path = EngineConfig::biome_colors_path();
+    if !path.exists() {
+        return biome_palette(high_contrast);
+    }
+    match BiomeTextureMap::load_toml(&path) {
+        Ok(map) => {
+            info!("Loaded biome colors from {}", path.display());
+            map
+        },
+        Err(e) => {
+            warn!("Failed to load biome colors: {e}");
+            biome_palette(high_contrast)
+        },
+    }
+}
+
+/// Converts numbered save slot metadata into save menu grid entries.
+fn save_slot_infos(slots: Vec<Option<SaveSlotMetadata>>) -> Vec<SaveSlotInfo> {
+    let most_recent = slots
+        .iter()
+        .enumerate()
+        .filter_map(|(i, meta)| Some((i, meta.as_ref()?.timestamp)))
+        .max_by_key(|&(_, timestamp)| timestamp)
+        .map(|(i, _)| i);
+
+    slots
+        .into_iter()
+        .enumerate()
+        .map(|(i, meta)| {
+            let id = SaveSlotId::new(i as u32);
+            let Some(meta) = meta else {
+                return SaveSlotInfo::empty(id);
+            };
+            let name = if meta.display_name.is_empty() {
+                id.to_string()
+            } else {
+                meta.display_name
+            };
+            SaveSlotInfo::occupied(id, name, meta.player_level, meta.playtime_seconds as u64)
+                .with_world_name(meta.location)
+                .with_last_saved(meta.timestamp)
+                .with_most_recent(most_recent == Some(i))
+        })
+        .collect()
+}
+
 /// Formats a cell count with commas for readability.
 fn format_cells(n: u64) -> String {
     let s = n.to_string();
@@ -2675,6 +3755,10 @@ fn render_controls_help(ctx: &egui::Context) {
                     ui.label("Sprint");
                     ui.end_row();
 
+                    ui.label("Alt");
+                    ui.label("Dash");
+                    ui.end_row();
+
                     ui.label("");
                     ui.label("");
                     ui.end_row();
@@ -2696,6 +3780,10 @@ fn render_controls_help(ctx: &egui::Context) {
                     ui.label("Secondary Action");
                     ui.end_row();
 
+                    ui.label("Q");
+                    ui.label("Lock On / Cycle Target");
+                    ui.end_row();
+
                     ui.label("");
                     ui.label("");
                     ui.end_row();
@@ -2742,13 +3830,33 @@ fn render_controls_help(ctx: &egui::Context) {
                     ui.label("Toggle Debug Overlay");
                     ui.end_row();
 
+                    ui.label("F4");
+                    ui.label("Toggle Entity Inspector");
+                    ui.end_row();
+
                     ui.label("F5");
                     ui.label("Quick Save");
                     ui.end_row();
 
+                    ui.label("F6");
+                    ui.label("Pause Simulation");
+                    ui.end_row();
+
+                    ui.label("F7");
+                    ui.label("Step Simulation (paused)");
+                    ui.end_row();
+
                     ui.label("F9");
                     ui.label("Quick Load");
                     ui.end_row();
+
+                    ui.label("F12");
+                    ui.label("Screenshot");
+                    ui.end_row();
+
+                    ui.label("Shift+F12");
+                    ui.label("Screenshot with Overlay");
+                    ui.end_row();
                 });
 
             ui.add_space(15.0);
@@ -2763,6 +3871,7 @@ fn render_controls_help(ctx: &egui::Context) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use genesis_tools::ui::SlotState;
 
     #[test]
     fn test_app_mode_default_is_menu() {
@@ -2777,6 +3886,72 @@ mod tests {
         assert_ne!(AppMode::Playing, AppMode::Paused);
     }
 
+    fn save_menu_test_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join("genesis_test_saves").join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_save_menu_emits_save_for_chosen_slot() {
+        let dir = save_menu_test_dir("test_menu_save");
+
+        let mut manager = SaveManager::new(&dir);
+        let mut menu = SaveMenu::with_defaults();
+        menu.set_slots(save_slot_infos(manager.numbered_slots(8).expect("List failed")));
+
+        menu.open_save();
+        menu.select_slot(SaveSlotId::new(3));
+        menu.drain_actions();
+        menu.confirm_action();
+
+        let actions = menu.drain_actions();
+        assert_eq!(actions, vec![SaveMenuAction::SaveToSlot(SaveSlotId::new(3))]);
+        assert_eq!(SaveSlotId::new(3).slot_name(), "slot_4");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_save_menu_load_resolves_to_metadata() {
+        let dir = save_menu_test_dir("test_menu_load");
+
+        let mut manager = SaveManager::new(&dir);
+        let id = SaveSlotId::new(1);
+        let data = SaveFileBuilder::new(id.slot_name())
+            .display_name("Hero")
+            .player_level(7)
+            .location("Forest")
+            .build();
+        manager.save(&id.slot_name(), &data).expect("Save failed");
+        manager.quicksave(&data).expect("Quicksave failed");
+
+        let slots = save_slot_infos(manager.numbered_slots(8).expect("List failed"));
+        assert_eq!(slots[1].state, SlotState::Occupied);
+        assert_eq!(slots[1].player_name.as_deref(), Some("Hero"));
+        assert!(slots[1].is_most_recent);
+        assert_eq!(slots.iter().filter(|s| s.state == SlotState::Occupied).count(), 1);
+
+        let mut menu = SaveMenu::with_defaults();
+        menu.set_slots(slots);
+        menu.open_load();
+        menu.select_slot(id);
+        menu.drain_actions();
+        menu.confirm_action();
+
+        let actions = menu.drain_actions();
+        let Some(SaveMenuAction::LoadFromSlot(load_id)) = actions.first() else {
+            panic!("expected a load action, got {actions:?}");
+        };
+        let meta = manager
+            .get_slot_metadata(&load_id.slot_name())
+            .expect("Metadata missing");
+        assert_eq!(meta.display_name, "Hero");
+        assert_eq!(meta.player_level, 7);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn test_format_cells_with_commas() {
         assert_eq!(format_cells(0), "0");
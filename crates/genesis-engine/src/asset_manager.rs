@@ -5,13 +5,18 @@
 
 #![allow(dead_code)]
 
+use genesis_common::version::{content_hash, ContentVersion};
+use genesis_kernel::terrain_assets::{ManifestIssue, TerrainAssetManifest};
 use std::path::{Path, PathBuf};
 use wgpu::{Device, Queue};
-use tracing::info;
+use tracing::{debug, info, warn};
 
 /// Default path for terrain assets (unused - terrain removed)
 pub const DEFAULT_TERRAIN_ASSETS_PATH: &str = "";
 
+/// Manifest file name inside the terrain asset directory
+pub const TERRAIN_MANIFEST_FILE: &str = "terrain.manifest";
+
 /// Default path for autotile atlas (unused - terrain removed)
 pub const DEFAULT_AUTOTILE_PATH: &str = "";
 
@@ -98,6 +103,8 @@ pub struct AssetManager {
     status: AssetLoadStatus,
     /// Statistics
     stats: AssetStats,
+    /// Content version the current atlas was built from
+    atlas_version: Option<ContentVersion>,
 }
 
 impl AssetManager {
@@ -114,6 +121,7 @@ impl AssetManager {
             config,
             status: AssetLoadStatus::NotLoaded,
             stats: AssetStats::default(),
+            atlas_version: None,
         }
     }
 
@@ -129,18 +137,81 @@ impl AssetManager {
         &self.stats
     }
 
-    /// Load autotile atlas (stub - always fails)
+    /// Content version the current atlas was built from, if any
+    #[must_use]
+    pub fn atlas_version(&self) -> Option<ContentVersion> {
+        self.atlas_version
+    }
+
+    /// Check whether the atlas must be rebuilt for the given asset version
+    #[must_use]
+    pub fn atlas_needs_rebuild(&self, version: ContentVersion) -> bool {
+        self.atlas_version != Some(version)
+    }
+
+    /// Load autotile atlas (stub - fails on every rebuild)
+    ///
+    /// The atlas files' contents are hashed and the result recorded as the
+    /// atlas version; the rebuild is skipped while that version still matches.
     pub fn load_autotile_atlas(&mut self) -> Result<(), String> {
+        let version = content_hash(self.atlas_files());
+        if !self.atlas_needs_rebuild(version) {
+            debug!("Autotile atlas unchanged ({version}), skipping rebuild");
+            return Ok(());
+        }
+        self.atlas_version = Some(version);
         info!("Autotile atlas loading skipped (terrain system removed)");
         Err("Terrain system removed".to_string())
     }
 
+    /// Files making up the autotile atlas: the files directly inside the
+    /// autotile directory, or the path itself if it names a single file
+    fn atlas_files(&self) -> Vec<PathBuf> {
+        let path = &self.config.autotile_path;
+        if !path.is_dir() {
+            return vec![path.clone()];
+        }
+        match std::fs::read_dir(path) {
+            Ok(entries) => entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| path.is_file())
+                .collect(),
+            Err(e) => {
+                warn!("Failed to list autotile atlas {}: {e}", path.display());
+                Vec::new()
+            },
+        }
+    }
+
     /// Load terrain assets (stub - always fails)
+    ///
+    /// The tile manifest is still read and validated so broken hot-swaps
+    /// are logged.
     pub fn load_terrain_assets(&mut self) -> Result<usize, String> {
+        for issue in self.validate_terrain_manifest() {
+            warn!("Terrain manifest: {issue}");
+        }
         info!("Terrain asset loading skipped (terrain system removed)");
         Err("Terrain system removed".to_string())
     }
 
+    /// Validate the terrain tile manifest, if one exists
+    #[must_use]
+    pub fn validate_terrain_manifest(&self) -> Vec<ManifestIssue> {
+        let path = self.config.terrain_path.join(TERRAIN_MANIFEST_FILE);
+        if !path.is_file() {
+            return Vec::new();
+        }
+        match TerrainAssetManifest::load(&path) {
+            Ok(manifest) => manifest.validate(),
+            Err(e) => {
+                warn!("Failed to read terrain manifest {}: {e}", path.display());
+                Vec::new()
+            },
+        }
+    }
+
     /// Upload terrain atlas to GPU (stub - no-op)
     pub fn upload_terrain_to_gpu(&mut self, _device: &Device, _queue: &Queue) {
         info!("Terrain GPU upload skipped (terrain system removed)");
@@ -203,6 +274,45 @@ mod tests {
         let manager = AssetManager::new();
         assert_eq!(manager.status(), AssetLoadStatus::NotLoaded);
         assert_eq!(manager.terrain_tile_count(), 0);
+        assert_eq!(manager.atlas_version(), None);
+    }
+
+    #[test]
+    fn test_terrain_manifest_issues_surface() {
+        let dir = tempfile::tempdir().expect("should create temp dir");
+        std::fs::write(
+            dir.path().join(TERRAIN_MANIFEST_FILE),
+            "grass = grass.png\n",
+        )
+        .expect("should write manifest");
+
+        let mut manager = AssetManager::new();
+        assert!(manager.validate_terrain_manifest().is_empty());
+        manager.set_terrain_path(dir.path());
+        let issues = manager.validate_terrain_manifest();
+        assert_eq!(issues.len(), 1);
+        assert!(matches!(issues[0], ManifestIssue::MissingTile { .. }));
+    }
+
+    #[test]
+    fn test_atlas_version_tracks_file_contents() {
+        let dir = tempfile::tempdir().expect("should create temp dir");
+        let atlas = dir.path().join("atlas.png");
+        std::fs::write(&atlas, b"first").expect("should write atlas");
+
+        let mut manager = AssetManager::new();
+        manager.set_autotile_path(dir.path());
+        assert!(manager.load_autotile_atlas().is_err());
+        let first = manager.atlas_version().expect("version recorded");
+        assert_eq!(first, content_hash([&atlas]));
+
+        // Unchanged files skip the rebuild
+        assert!(manager.load_autotile_atlas().is_ok());
+        assert_eq!(manager.atlas_version(), Some(first));
+
+        std::fs::write(&atlas, b"second").expect("should rewrite atlas");
+        assert!(manager.load_autotile_atlas().is_err());
+        assert_ne!(manager.atlas_version(), Some(first));
     }
 
     #[test]
@@ -105,6 +105,8 @@ pub enum AudioCategory {
     Sfx,
     /// UI sounds (cached).
     Ui,
+    /// Spoken dialogue (cached).
+    Voice,
 }
 
 impl AudioCategory {
@@ -116,6 +118,7 @@ impl AudioCategory {
             Self::Ambient => "ambient",
             Self::Sfx => "sfx",
             Self::Ui => "ui",
+            Self::Voice => "voice",
         }
     }
 
@@ -123,8 +126,8 @@ impl AudioCategory {
     #[must_use]
     pub const fn should_cache(&self) -> bool {
         match self {
-            Self::Music | Self::Ambient => false, // Stream these
-            Self::Sfx | Self::Ui => true,         // Cache these
+            Self::Music | Self::Ambient => false,       // Stream these
+            Self::Sfx | Self::Ui | Self::Voice => true, // Cache these
         }
     }
 }
@@ -424,6 +427,7 @@ impl AudioAssetLoader {
                         "ambient" => AudioCategory::Ambient,
                         "sfx" => AudioCategory::Sfx,
                         "ui" => AudioCategory::Ui,
+                        "voice" => AudioCategory::Voice,
                         _ => continue,
                     };
 
@@ -9,7 +9,15 @@
 
 use std::collections::{HashMap, VecDeque};
 use std::io::Cursor;
-
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use genesis_kernel::audio::AudioCategory as AudioBus;
+use genesis_kernel::audio::{
+    AudioDeviceProvider, AudioResult as DeviceResult, DeviceChange, DeviceMonitor,
+    SystemDeviceProvider,
+};
 use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source};
 use thiserror::Error;
 use tracing::{debug, error, info, warn};
@@ -23,6 +31,12 @@ pub const MAX_QUEUED_EVENTS: usize = 32;
 /// Maximum simultaneous sound effects.
 pub const MAX_ACTIVE_SFX: usize = 64;
 
+/// How often the stem mixer picks up new stem volumes.
+const STEM_GAIN_PERIOD: Duration = Duration::from_millis(10);
+
+/// Seconds between checks for output device changes.
+const DEVICE_POLL_INTERVAL: f32 = 1.0;
+
 /// Errors that can occur in audio integration.
 #[derive(Debug, Error)]
 pub enum AudioIntegrationError {
@@ -183,6 +197,26 @@ impl AudioListener {
     }
 }
 
+/// Device provider that only tracks names; rodio opens the actual stream.
+#[derive(Debug, Default, Clone, Copy)]
+struct DeviceNames;
+
+impl AudioDeviceProvider for DeviceNames {
+    type Device = ();
+
+    fn output_devices(&self) -> Vec<String> {
+        SystemDeviceProvider.output_devices()
+    }
+
+    fn default_output(&self) -> Option<String> {
+        SystemDeviceProvider.default_output()
+    }
+
+    fn open(&self, _name: &str) -> DeviceResult<()> {
+        Ok(())
+    }
+}
+
 /// Main audio integration system.
 pub struct AudioIntegration {
     /// Audio output stream (must stay alive - dropping this stops all audio).
@@ -205,6 +239,10 @@ pub struct AudioIntegration {
     next_music_sink: Option<Sink>,
     /// Ambient layer sinks.
     ambient_sinks: HashMap<String, Sink>,
+    /// Sink playing the mixed music stems.
+    stem_sink: Option<Sink>,
+    /// Per-stem gains read by the stem mixer, stored as `f32` bits.
+    stem_gains: Vec<Arc<AtomicU32>>,
 
     /// Event queue for deferred processing.
     event_queue: VecDeque<SoundEvent>,
@@ -212,6 +250,11 @@ pub struct AudioIntegration {
     /// Next sound handle ID.
     next_handle_id: u64,
 
+    /// Watches for the output device being unplugged or replaced.
+    device_monitor: Option<DeviceMonitor<DeviceNames>>,
+    /// Seconds since the last device check.
+    device_poll_timer: f32,
+
     /// Frame counter for hot-reload checks (debug builds only).
     #[cfg(debug_assertions)]
     hot_reload_counter: u32,
@@ -266,8 +309,12 @@ impl AudioIntegration {
             music_sink: None,
             next_music_sink: None,
             ambient_sinks: HashMap::new(),
+            stem_sink: None,
+            stem_gains: Vec::new(),
             event_queue: VecDeque::with_capacity(MAX_QUEUED_EVENTS),
             next_handle_id: 1,
+            device_monitor: None,
+            device_poll_timer: 0.0,
             #[cfg(debug_assertions)]
             hot_reload_counter: 0,
         }
@@ -332,6 +379,44 @@ impl AudioIntegration {
         self.update_ambient_volumes();
     }
 
+    /// Sets UI sound volume.
+    pub fn set_ui_volume(&mut self, volume: f32) {
+        self.state.volumes.ui = volume.clamp(0.0, 1.0);
+        self.update_sfx_volumes();
+    }
+
+    /// Sets voice volume.
+    pub fn set_voice_volume(&mut self, volume: f32) {
+        self.state.volumes.voice = volume.clamp(0.0, 1.0);
+        self.update_sfx_volumes();
+    }
+
+    /// Sets the volume of a mixer bus and applies it to playing sounds.
+    pub fn set_bus_volume(&mut self, category: AudioBus, volume: f32) {
+        match category {
+            AudioBus::Master => self.set_master_volume(volume),
+            AudioBus::Music => self.set_music_volume(volume),
+            AudioBus::Sfx => self.set_sfx_volume(volume),
+            AudioBus::Ambient => self.set_ambient_volume(volume),
+            AudioBus::Ui => self.set_ui_volume(volume),
+            AudioBus::Voice => self.set_voice_volume(volume),
+        }
+    }
+
+    /// Returns the stored gain for a mixer bus, or `None` if the mixer has no such bus.
+    #[must_use]
+    pub fn bus_volume(&self, category: AudioBus) -> Option<f32> {
+        let volumes = &self.state.volumes;
+        match category {
+            AudioBus::Master => Some(volumes.master),
+            AudioBus::Music => Some(volumes.music),
+            AudioBus::Sfx => Some(volumes.sfx),
+            AudioBus::Ambient => Some(volumes.ambient),
+            AudioBus::Ui => Some(volumes.ui),
+            AudioBus::Voice => Some(volumes.voice),
+        }
+    }
+
     /// Toggles master mute.
     pub fn toggle_mute(&mut self) {
         self.state.mutes.toggle_master();
@@ -417,6 +502,7 @@ impl AudioIntegration {
             AudioCategory::Ui => self.state.volumes.effective_ui(),
             AudioCategory::Music => self.state.volumes.effective_music(),
             AudioCategory::Ambient => self.state.volumes.effective_ambient(),
+            AudioCategory::Voice => self.state.volumes.effective_voice(),
         };
 
         let spatial_attenuation = if let Some((x, y)) = event.position {
@@ -613,6 +699,112 @@ impl AudioIntegration {
         }
     }
 
+    /// Plays a set of music stems in lockstep, all starting silent.
+    ///
+    /// The stems are mixed into a single source so they start on the same
+    /// sample and never drift; use [`Self::set_music_stem_volume`] to fade
+    /// them. If any stem fails to load none are played. Returns whether the
+    /// stems are playing.
+    pub fn play_music_stems(&mut self, stems: &[&str]) -> bool {
+        self.stop_music_stems();
+        if !self.is_available() || stems.is_empty() {
+            return false;
+        }
+
+        // Skip if this stem set already failed to load
+        let key = stems.join("+");
+        if self.state.failed_tracks.contains(&key) {
+            return false;
+        }
+
+        let stream_handle = match &self.stream_handle {
+            Some(h) => h,
+            None => return false,
+        };
+
+        let mut decoders = Vec::with_capacity(stems.len());
+        for stem in stems {
+            let decoded = self
+                .asset_loader
+                .load(AudioCategory::Music, stem)
+                .map_err(|e| e.to_string())
+                .and_then(|asset| {
+                    Decoder::new(Cursor::new((*asset.data).clone())).map_err(|e| e.to_string())
+                });
+            match decoded {
+                Ok(decoder) => decoders.push(decoder),
+                Err(e) => {
+                    warn!("Failed to load music stem {}: {}", stem, e);
+                    self.state.missing_audio_count += 1;
+                    self.state.failed_tracks.insert(key);
+                    return false;
+                },
+            }
+        }
+
+        let sink = match Sink::try_new(stream_handle) {
+            Ok(sink) => sink,
+            Err(e) => {
+                error!("Failed to create music stem sink: {}", e);
+                return false;
+            },
+        };
+
+        // Sources added before the mixer first plays all start on sample 0
+        let (controller, mixer) =
+            rodio::dynamic_mixer::mixer::<f32>(decoders[0].channels(), decoders[0].sample_rate());
+        for decoder in decoders {
+            let gain = Arc::new(AtomicU32::new(0.0f32.to_bits()));
+            let stem_gain = Arc::clone(&gain);
+            controller.add(
+                decoder
+                    .repeat_infinite()
+                    .convert_samples::<f32>()
+                    .amplify(0.0)
+                    .periodic_access(STEM_GAIN_PERIOD, move |source| {
+                        source.set_factor(f32::from_bits(stem_gain.load(Ordering::Relaxed)));
+                    }),
+            );
+            self.stem_gains.push(gain);
+        }
+        sink.append(mixer);
+        sink.set_volume(self.effective_music_volume());
+        self.stem_sink = Some(sink);
+
+        info!("Playing {} music stems", stems.len());
+        true
+    }
+
+    /// Sets the volume (0.0-1.0) of a stem started by [`Self::play_music_stems`].
+    pub fn set_music_stem_volume(&mut self, index: usize, volume: f32) {
+        if let Some(gain) = self.stem_gains.get(index) {
+            gain.store(volume.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+        }
+    }
+
+    /// Whether music stems are playing.
+    #[must_use]
+    pub fn music_stems_playing(&self) -> bool {
+        self.stem_sink.is_some()
+    }
+
+    /// Stops the music stems.
+    pub fn stop_music_stems(&mut self) {
+        if let Some(sink) = self.stem_sink.take() {
+            sink.stop();
+        }
+        self.stem_gains.clear();
+    }
+
+    /// Music bus volume with mutes applied.
+    fn effective_music_volume(&self) -> f32 {
+        if self.state.mutes.is_music_muted() {
+            0.0
+        } else {
+            self.state.volumes.effective_music()
+        }
+    }
+
     /// Sets the current biome for ambient audio.
     pub fn set_biome(&mut self, biome: &str) {
         self.state.ambient.set_biome(biome);
@@ -687,6 +879,13 @@ impl AudioIntegration {
         // Cleanup finished sounds
         self.cleanup_finished_sounds();
 
+        // Pick up unplugged or newly connected output devices
+        self.device_poll_timer += dt;
+        if self.device_poll_timer >= DEVICE_POLL_INTERVAL {
+            self.device_poll_timer = 0.0;
+            self.poll_device();
+        }
+
         // Check for hot-reload in debug builds
         #[cfg(debug_assertions)]
         {
@@ -704,11 +903,10 @@ impl AudioIntegration {
 
     /// Updates music fade/crossfade.
     fn update_music_fade(&mut self, _dt: f32) {
-        let effective_volume = if self.state.mutes.is_music_muted() {
-            0.0
-        } else {
-            self.state.volumes.effective_music()
-        };
+        let effective_volume = self.effective_music_volume();
+        if let Some(sink) = &self.stem_sink {
+            sink.set_volume(effective_volume);
+        }
 
         match &self.state.music.play_state {
             MusicPlayState::FadingIn {
@@ -835,12 +1033,14 @@ impl AudioIntegration {
     fn update_sfx_volumes(&mut self) {
         let sfx_vol = self.state.volumes.effective_sfx();
         let ui_vol = self.state.volumes.effective_ui();
+        let voice_vol = self.state.volumes.effective_voice();
         let is_muted = self.state.mutes.master;
 
         for sound in self.active_sfx.values_mut() {
             let category_vol = match sound.category {
                 AudioCategory::Sfx => sfx_vol,
                 AudioCategory::Ui => ui_vol,
+                AudioCategory::Voice => voice_vol,
                 _ => 1.0,
             };
 
@@ -879,6 +1079,7 @@ impl AudioIntegration {
         if let Some(sink) = self.next_music_sink.take() {
             sink.stop();
         }
+        self.stop_music_stems();
 
         // Stop ambient
         for (_, sink) in self.ambient_sinks.drain() {
@@ -929,6 +1130,44 @@ impl AudioIntegration {
             },
         }
     }
+
+    /// Checks whether the output device changed and reinitializes if so.
+    ///
+    /// Called periodically from [`update`](Self::update); returns the change
+    /// that was handled, if any.
+    pub fn poll_device(&mut self) -> Option<DeviceChange> {
+        let Some(monitor) = &mut self.device_monitor else {
+            // Start monitoring once a device shows up
+            let monitor = DeviceMonitor::open_default(DeviceNames).ok()?;
+            let current = monitor.current().map(str::to_string);
+            self.device_monitor = Some(monitor);
+            if self.is_available() {
+                return None;
+            }
+            self.reinitialize_device();
+            return current.map(|current| DeviceChange::Switched {
+                lost: None,
+                current,
+            });
+        };
+
+        let change = monitor.poll()?;
+        match &change {
+            DeviceChange::Switched { lost, current } => {
+                info!("Audio output switched from {:?} to {}", lost, current);
+                self.reinitialize_device();
+            },
+            DeviceChange::Lost { lost } => {
+                warn!("Audio output device {} was disconnected", lost);
+                self.stop_all();
+                self.output_stream = None;
+                self.stream_handle = None;
+                self.state.device_available = false;
+                self.device_monitor = None;
+            },
+        }
+        Some(change)
+    }
 }
 
 impl Default for AudioIntegration {
@@ -1021,6 +1260,33 @@ mod tests {
         assert!((pan - 1.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_set_bus_volume_updates_mixer() {
+        // Works without an output device; volumes are stored either way
+        let mut audio = AudioIntegration::new("nonexistent/sounds");
+
+        audio.set_bus_volume(AudioBus::Music, 0.25);
+        audio.set_bus_volume(AudioBus::Ui, 0.5);
+        audio.set_bus_volume(AudioBus::Master, 2.0);
+
+        assert_eq!(audio.bus_volume(AudioBus::Music), Some(0.25));
+        assert_eq!(audio.bus_volume(AudioBus::Ui), Some(0.5));
+        assert_eq!(audio.bus_volume(AudioBus::Master), Some(1.0));
+        assert!((audio.volumes().effective_music() - 0.25).abs() < 0.001);
+        audio.set_bus_volume(AudioBus::Voice, 0.75);
+        assert_eq!(audio.bus_volume(AudioBus::Voice), Some(0.75));
+    }
+
+    #[test]
+    fn test_device_poll_keeps_availability_consistent() {
+        let mut audio = AudioIntegration::new("nonexistent/sounds");
+
+        // Whatever devices the host has, a poll must not leave a stale stream
+        audio.update(DEVICE_POLL_INTERVAL);
+        audio.poll_device();
+        assert_eq!(audio.is_available(), audio.state().device_available);
+    }
+
     // Note: AudioIntegration tests that require actual audio hardware
     // are skipped in unit tests. Integration tests would go separately.
 }
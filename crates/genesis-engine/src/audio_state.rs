@@ -28,6 +28,13 @@ pub struct VolumeSettings {
     pub ambient: f32,
     /// UI sounds volume (0.0 - 1.0).
     pub ui: f32,
+    /// Voice volume (0.0 - 1.0).
+    #[serde(default = "default_voice_volume")]
+    pub voice: f32,
+}
+
+fn default_voice_volume() -> f32 {
+    1.0
 }
 
 impl Default for VolumeSettings {
@@ -38,6 +45,7 @@ impl Default for VolumeSettings {
             sfx: 1.0,
             ambient: 0.6,
             ui: 0.8,
+            voice: default_voice_volume(),
         }
     }
 }
@@ -66,6 +74,12 @@ impl VolumeSettings {
     pub fn effective_ui(&self) -> f32 {
         self.master * self.ui
     }
+
+    /// Returns the effective volume for voice.
+    #[must_use]
+    pub fn effective_voice(&self) -> f32 {
+        self.master * self.voice
+    }
 }
 
 /// Mute state for different audio channels.
@@ -643,9 +657,11 @@ mod tests {
             sfx: 1.0,
             ambient: 0.6,
             ui: 0.8,
+            voice: 0.4,
         };
         assert!((vol.effective_music() - 0.4).abs() < 0.001);
         assert!((vol.effective_sfx() - 0.5).abs() < 0.001);
+        assert!((vol.effective_voice() - 0.2).abs() < 0.001);
     }
 
     #[test]
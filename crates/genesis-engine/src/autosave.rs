@@ -10,6 +10,7 @@ use std::collections::VecDeque;
 use std::time::Instant;
 use tracing::{debug, info, warn};
 
+use crate::incremental_save::{IncrementalSaver, SavePriority, SaveRequest, SaveResponse};
 use crate::save_manager::{SaveFileData, SaveManager, SaveResult, AUTOSAVE_PREFIX};
 
 /// Default auto-save interval in seconds.
@@ -255,6 +256,8 @@ pub struct AutoSaveManager {
     notification_timer: f32,
     /// Whether to show notification.
     show_notification: bool,
+    /// Save handed to a background worker: trigger and slot name.
+    in_flight: Option<(AutoSaveTrigger, String)>,
 }
 
 impl Default for AutoSaveManager {
@@ -285,6 +288,7 @@ impl AutoSaveManager {
             last_save_time: None,
             notification_timer: 0.0,
             show_notification: false,
+            in_flight: None,
         }
     }
 
@@ -466,6 +470,53 @@ impl AutoSaveManager {
         false
     }
 
+    /// Like [`check_and_save`](Self::check_and_save), but hands the save to
+    /// a background worker. Returns true if a save was queued; the outcome
+    /// arrives through [`on_save_response`](Self::on_save_response).
+    pub fn check_and_submit(&mut self, saver: &IncrementalSaver, data: &SaveFileData) -> bool {
+        if !self.config.enabled || self.is_paused() || self.in_flight.is_some() {
+            return false;
+        }
+
+        // Check interval trigger
+        if self.time_since_save >= self.config.interval_seconds {
+            self.pending_triggers.push(AutoSaveTrigger::Interval);
+        }
+
+        let Some(trigger) = self.pending_triggers.pop() else {
+            return false;
+        };
+        let (slot_name, save_data) = self.prepare_save(data, trigger);
+        let priority = if trigger == AutoSaveTrigger::AreaTransition {
+            SavePriority::AreaTransition
+        } else {
+            SavePriority::Autosave
+        };
+        saver.submit(SaveRequest::new(slot_name.clone(), save_data, priority));
+        self.in_flight = Some((trigger, slot_name));
+        true
+    }
+
+    /// Records the result of a save queued by
+    /// [`check_and_submit`](Self::check_and_submit). Returns false if the
+    /// response belongs to some other save.
+    pub fn on_save_response(&mut self, response: &SaveResponse) -> bool {
+        let (trigger, slot_name) = match self.in_flight.take() {
+            Some((trigger, slot)) if slot == response.slot_name => (trigger, slot),
+            other => {
+                self.in_flight = other;
+                return false;
+            },
+        };
+        let result = if response.success {
+            Ok(())
+        } else {
+            Err(response.error.clone().unwrap_or_default())
+        };
+        self.finish_save(trigger, &slot_name, result);
+        true
+    }
+
     /// Performs the actual save operation.
     fn perform_save(
         &mut self,
@@ -473,6 +524,19 @@ impl AutoSaveManager {
         data: &SaveFileData,
         trigger: AutoSaveTrigger,
     ) -> bool {
+        let (slot_name, save_data) = self.prepare_save(data, trigger);
+        let result = save_manager
+            .save(&slot_name, &save_data)
+            .map_err(|e| e.to_string());
+        self.finish_save(trigger, &slot_name, result)
+    }
+
+    /// Marks a save as started and builds the auto-save slot and data.
+    fn prepare_save(
+        &mut self,
+        data: &SaveFileData,
+        trigger: AutoSaveTrigger,
+    ) -> (String, SaveFileData) {
         self.status = AutoSaveStatus::Saving;
         let slot_name = self.next_slot_name();
 
@@ -485,14 +549,22 @@ impl AutoSaveManager {
             save_data.metadata.location
         );
 
-        let result = save_manager.save(&slot_name, &save_data);
+        (slot_name, save_data)
+    }
 
+    /// Records a finished save and advances the rotation on success.
+    fn finish_save(
+        &mut self,
+        trigger: AutoSaveTrigger,
+        slot_name: &str,
+        result: Result<(), String>,
+    ) -> bool {
         let record = AutoSaveRecord {
             trigger,
-            slot_name: slot_name.clone(),
+            slot_name: slot_name.to_string(),
             timestamp: Instant::now(),
             success: result.is_ok(),
-            error: result.as_ref().err().map(|e| e.to_string()),
+            error: result.as_ref().err().cloned(),
         };
 
         // Update history
@@ -893,6 +965,39 @@ mod tests {
         cleanup_test_dir(&dir);
     }
 
+    #[test]
+    fn test_autosave_manager_check_and_submit() {
+        let dir = test_save_dir().join("test_check_submit");
+        cleanup_test_dir(&dir);
+
+        let config = AutoSaveConfig::new().with_interval(MIN_AUTOSAVE_INTERVAL);
+        let mut manager = AutoSaveManager::new(config);
+        let mut saver = IncrementalSaver::new(&dir);
+        saver.spawn_worker();
+
+        let data = SaveFileBuilder::new("autosave_test")
+            .location("Test Area")
+            .build();
+
+        manager.update(MIN_AUTOSAVE_INTERVAL);
+        assert!(manager.check_and_submit(&saver, &data));
+        assert_eq!(manager.status(), AutoSaveStatus::Saving);
+        // Only one save in flight at a time
+        assert!(!manager.check_and_submit(&saver, &data));
+
+        saver.shutdown();
+        let responses = saver.poll_responses();
+        assert_eq!(responses.len(), 1);
+        assert!(manager.on_save_response(&responses[0]));
+        assert!(!manager.on_save_response(&responses[0]));
+
+        assert_eq!(manager.status(), AutoSaveStatus::Idle);
+        assert_eq!(manager.next_slot_name(), format!("{AUTOSAVE_PREFIX}_1"));
+        assert!(saver.manager().slot_exists(&format!("{AUTOSAVE_PREFIX}_0")));
+
+        cleanup_test_dir(&dir);
+    }
+
     #[test]
     fn test_autosave_manager_force_save() {
         let dir = test_save_dir().join("test_force_save");
@@ -5,14 +5,21 @@
 //! - OnHit: apply damage, knockback, effects
 //! - OnDeath: drop loot, play animation
 //! - OnBlock: reduce damage, play sound
+//! - Ranged attacks: spawn and step projectiles, turn impacts into hits
 
 use genesis_common::EntityId;
-use std::collections::VecDeque;
+use genesis_kernel::projectile::{
+    Projectile, ProjectileId, ProjectileManager, ProjectileType as KernelProjectileType,
+};
+use std::collections::{HashMap, HashSet, VecDeque};
 use tracing::{debug, info};
 
 use crate::audio_assets::AudioCategory;
 use crate::audio_integration::{AudioIntegration, SoundEvent};
 
+/// Default collision radius for entities that projectiles can hit.
+pub const ENTITY_HIT_RADIUS: f32 = 8.0;
+
 /// Types of combat events.
 #[derive(Debug, Clone)]
 pub enum CombatEvent {
@@ -94,6 +101,17 @@ impl AttackCategory {
             Self::Unarmed => "combat/punch",
         }
     }
+
+    /// Returns the projectile fired by this attack type, if it is ranged.
+    #[must_use]
+    pub const fn projectile_type(&self) -> Option<ProjectileType> {
+        match self {
+            Self::RangedBow => Some(ProjectileType::Arrow),
+            Self::RangedGun => Some(ProjectileType::Bullet),
+            Self::MagicSpell => Some(ProjectileType::MagicBolt),
+            Self::MeleeSwing | Self::MeleeThrust | Self::Unarmed => None,
+        }
+    }
 }
 
 /// Event for when an attack hits a target.
@@ -337,6 +355,41 @@ impl ProjectileType {
             Self::ThrownWeapon => "combat/throw_impact",
         }
     }
+
+    /// Returns the launch speed in world units per second.
+    #[must_use]
+    pub const fn speed(&self) -> f32 {
+        match self {
+            Self::Arrow => 600.0,
+            Self::Bolt => 750.0,
+            Self::Bullet => 1200.0,
+            Self::MagicBolt => 400.0,
+            Self::ThrownWeapon => 350.0,
+        }
+    }
+
+    /// Returns the damage dealt on impact.
+    #[must_use]
+    pub const fn base_damage(&self) -> f32 {
+        match self {
+            Self::Arrow => 12.0,
+            Self::Bolt => 18.0,
+            Self::Bullet => 20.0,
+            Self::MagicBolt => 15.0,
+            Self::ThrownWeapon => 10.0,
+        }
+    }
+
+    /// Returns the damage category of an impact.
+    #[must_use]
+    pub const fn damage_category(&self) -> DamageCategory {
+        match self {
+            Self::MagicBolt => DamageCategory::True,
+            Self::Arrow | Self::Bolt | Self::Bullet | Self::ThrownWeapon => {
+                DamageCategory::Physical
+            },
+        }
+    }
 }
 
 /// Statistics tracked for combat.
@@ -435,6 +488,12 @@ pub struct CombatEventHandler {
     stats: CombatStats,
     /// Pending loot drops (entity_id, position, items).
     pending_loot: Vec<(EntityId, (f32, f32), Vec<LootItem>)>,
+    /// Projectiles fired by ranged attacks.
+    projectiles: ProjectileManager,
+    /// Owner and type of each projectile in flight.
+    projectile_info: HashMap<ProjectileId, (EntityId, ProjectileType)>,
+    /// Entities that currently ignore hits (e.g. dash i-frames).
+    invulnerable: HashSet<EntityId>,
 }
 
 /// A loot item to drop.
@@ -462,9 +521,34 @@ impl CombatEventHandler {
             event_queue: VecDeque::new(),
             stats: CombatStats::new(),
             pending_loot: Vec::new(),
+            projectiles: Self::new_projectile_manager(),
+            projectile_info: HashMap::new(),
+            invulnerable: HashSet::new(),
+        }
+    }
+
+    /// Creates a projectile manager for the top-down view (no gravity).
+    fn new_projectile_manager() -> ProjectileManager {
+        let mut manager = ProjectileManager::new();
+        manager.set_gravity(0.0);
+        manager
+    }
+
+    /// Marks an entity as ignoring (or no longer ignoring) hits.
+    pub fn set_invulnerable(&mut self, entity: EntityId, invulnerable: bool) {
+        if invulnerable {
+            self.invulnerable.insert(entity);
+        } else {
+            self.invulnerable.remove(&entity);
         }
     }
 
+    /// Returns true if hits on the entity are currently ignored.
+    #[must_use]
+    pub fn is_invulnerable(&self, entity: EntityId) -> bool {
+        self.invulnerable.contains(&entity)
+    }
+
     /// Queues a combat event for processing.
     pub fn queue_event(&mut self, event: CombatEvent) {
         self.event_queue.push_back(event);
@@ -486,6 +570,11 @@ impl CombatEventHandler {
                     );
                     self.stats.record_attack();
 
+                    // Ranged attacks launch a projectile
+                    if let Some(projectile_type) = attack.attack_type.projectile_type() {
+                        self.spawn_projectile(&attack, projectile_type);
+                    }
+
                     // Play attack sound
                     if let Some(audio) = audio.as_deref_mut() {
                         let sound =
@@ -497,6 +586,10 @@ impl CombatEventHandler {
                     result.attacks.push(attack);
                 },
                 CombatEvent::Hit(hit) => {
+                    if self.invulnerable.contains(&hit.target) {
+                        debug!("Hit on {:?} ignored (invulnerable)", hit.target);
+                        continue;
+                    }
                     debug!(
                         "Hit: {:?} dealt {} damage to {:?}",
                         hit.attacker, hit.damage, hit.target
@@ -633,6 +726,90 @@ impl CombatEventHandler {
         result
     }
 
+    /// Spawns a projectile for a ranged attack and queues its spawn event.
+    fn spawn_projectile(&mut self, attack: &AttackEvent, projectile_type: ProjectileType) {
+        let speed = projectile_type.speed();
+        let velocity = (attack.direction.0 * speed, attack.direction.1 * speed);
+
+        let mut projectile = Projectile::new(attack.position, velocity);
+        projectile.projectile_type = KernelProjectileType::Straight;
+        projectile.damage = projectile_type.base_damage();
+        projectile.owner = Some(attack.attacker.raw());
+        let id = self.projectiles.spawn(projectile);
+        self.projectile_info
+            .insert(id, (attack.attacker, projectile_type));
+
+        self.queue_event(CombatEvent::ProjectileSpawned(ProjectileEvent {
+            owner: attack.attacker,
+            projectile_type,
+            position: attack.position,
+            velocity,
+            target: None,
+        }));
+    }
+
+    /// Steps projectiles in flight and queues events for their impacts.
+    ///
+    /// `targets` lists entities that can be hit as (id, position, radius).
+    /// Each entity impact queues a hit and a projectile hit event; terrain
+    /// impacts only queue a projectile hit. Returns the number of impacts.
+    pub fn update_projectiles(
+        &mut self,
+        dt: f32,
+        targets: &[(EntityId, (f32, f32), f32)],
+    ) -> usize {
+        let mut collisions = self.projectiles.update(dt);
+        let targets: Vec<_> = targets
+            .iter()
+            .map(|&(id, position, radius)| (id.raw(), position, radius))
+            .collect();
+        collisions.extend(
+            self.projectiles
+                .check_entity_collisions(&targets, |_, _| true),
+        );
+
+        let impacts = collisions.len();
+        for collision in collisions {
+            let Some(&(owner, projectile_type)) =
+                self.projectile_info.get(&collision.projectile_id)
+            else {
+                continue;
+            };
+            if collision.destroyed {
+                self.projectiles.remove(collision.projectile_id);
+            }
+
+            let target = collision.entity_id.map(EntityId::from_raw);
+            if let Some(target) = target {
+                self.queue_event(Self::make_hit_event(
+                    owner,
+                    target,
+                    collision.damage,
+                    projectile_type.damage_category(),
+                    collision.position,
+                ));
+            }
+            self.queue_event(CombatEvent::ProjectileHit(ProjectileEvent {
+                owner,
+                projectile_type,
+                position: collision.position,
+                velocity: collision.velocity,
+                target,
+            }));
+        }
+
+        let projectiles = &self.projectiles;
+        self.projectile_info
+            .retain(|&id, _| projectiles.get(id).is_some());
+        impacts
+    }
+
+    /// Returns the number of projectiles in flight.
+    #[must_use]
+    pub fn active_projectiles(&self) -> usize {
+        self.projectiles.active_count()
+    }
+
     /// Registers a loot drop for a death event.
     pub fn register_loot_drop(
         &mut self,
@@ -886,6 +1063,97 @@ mod tests {
         assert_eq!(ProjectileType::Arrow.impact_sound(), "combat/arrow_impact");
     }
 
+    #[test]
+    fn test_ranged_attack_spawns_projectile() {
+        let mut handler = CombatEventHandler::new();
+        handler.queue_event(CombatEventHandler::make_attack_event(
+            EntityId::from_raw(1),
+            AttackTarget::Direction(1.0, 0.0),
+            AttackCategory::RangedBow,
+            (0.0, 0.0),
+            (1.0, 0.0),
+        ));
+
+        let result = handler.process_events(None);
+        assert_eq!(result.attacks.len(), 1);
+        assert_eq!(result.projectiles_spawned.len(), 1);
+        assert_eq!(
+            result.projectiles_spawned[0].projectile_type,
+            ProjectileType::Arrow
+        );
+        assert_eq!(handler.active_projectiles(), 1);
+
+        // Melee attacks don't fire anything
+        handler.queue_event(CombatEventHandler::make_attack_event(
+            EntityId::from_raw(1),
+            AttackTarget::Direction(1.0, 0.0),
+            AttackCategory::MeleeSwing,
+            (0.0, 0.0),
+            (1.0, 0.0),
+        ));
+        handler.process_events(None);
+        assert_eq!(handler.active_projectiles(), 1);
+    }
+
+    #[test]
+    fn test_projectile_collision_produces_hit() {
+        let mut handler = CombatEventHandler::new();
+        let player = EntityId::from_raw(1);
+        let npc = EntityId::from_raw(7);
+        handler.queue_event(CombatEventHandler::make_attack_event(
+            player,
+            AttackTarget::Direction(1.0, 0.0),
+            AttackCategory::RangedBow,
+            (0.0, 0.0),
+            (1.0, 0.0),
+        ));
+        handler.process_events(None);
+
+        // The shooter standing on the projectile is never hit
+        let targets = [
+            (player, (0.0, 0.0), ENTITY_HIT_RADIUS),
+            (npc, (60.0, 0.0), ENTITY_HIT_RADIUS),
+        ];
+        assert_eq!(handler.update_projectiles(0.05, &targets[..1]), 0);
+
+        // 0.1s at arrow speed reaches the NPC
+        assert_eq!(handler.update_projectiles(0.05, &targets), 1);
+        let result = handler.process_events(None);
+        assert_eq!(result.hits.len(), 1);
+        assert_eq!(result.hits[0].attacker, player);
+        assert_eq!(result.hits[0].target, npc);
+        assert!(result.hits[0].damage > 0.0);
+        assert_eq!(result.projectiles_hit.len(), 1);
+        assert_eq!(result.projectiles_hit[0].target, Some(npc));
+        assert_eq!(handler.active_projectiles(), 0);
+    }
+
+    #[test]
+    fn test_invulnerable_target_ignores_hits() {
+        let mut handler = CombatEventHandler::new();
+        let player = EntityId::from_raw(1);
+        let npc = EntityId::from_raw(7);
+        let hit = || {
+            CombatEventHandler::make_hit_event(
+                npc,
+                player,
+                10.0,
+                DamageCategory::Physical,
+                (0.0, 0.0),
+            )
+        };
+
+        handler.set_invulnerable(player, true);
+        assert!(handler.is_invulnerable(player));
+        handler.queue_event(hit());
+        assert!(handler.process_events(None).hits.is_empty());
+
+        handler.set_invulnerable(player, false);
+        assert!(!handler.is_invulnerable(player));
+        handler.queue_event(hit());
+        assert_eq!(handler.process_events(None).hits.len(), 1);
+    }
+
     #[test]
     fn test_processed_events_has_events() {
         let empty = ProcessedCombatEvents::default();
@@ -3,15 +3,22 @@
 //! Provides configurable parameters for window, graphics, world, and debug settings.
 //! Configuration can be loaded from and saved to a file.
 
+use genesis_kernel::audio::AudioCategory;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
 use tracing::{info, warn};
 
+use crate::audio_state::VolumeSettings;
+
 /// Configuration file name.
 const CONFIG_FILE: &str = "genesis.toml";
 
+/// File holding biome colors edited in the World Tools panel.
+const BIOME_COLORS_FILE: &str = "biome_colors.toml";
+
 /// Engine configuration parameters.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -55,6 +62,12 @@ pub struct EngineConfig {
     pub music_volume: f32,
     /// Sound effects volume (0.0 - 1.0)
     pub sfx_volume: f32,
+    /// Ambient sounds volume (0.0 - 1.0)
+    pub ambient_volume: f32,
+    /// UI sounds volume (0.0 - 1.0)
+    pub ui_volume: f32,
+    /// Voice volume (0.0 - 1.0)
+    pub voice_volume: f32,
 
     // === Debug Settings ===
     /// Show FPS counter
@@ -67,6 +80,8 @@ pub struct EngineConfig {
     pub enable_profiling: bool,
     /// Use pure colors instead of autotile textures (for development/testing)
     pub use_pure_colors: bool,
+    /// Stream terrain chunks in a fixed order so automated runs match
+    pub deterministic_streaming: bool,
 
     // === Gameplay Settings ===
     /// Mouse sensitivity
@@ -83,6 +98,10 @@ pub struct EngineConfig {
     pub high_contrast: bool,
     /// Enable screen shake
     pub screen_shake: bool,
+
+    // === Controls ===
+    /// Key bindings (action -> keys), as exported by the rebind manager
+    pub key_bindings: HashMap<String, String>,
 }
 
 impl Default for EngineConfig {
@@ -111,6 +130,9 @@ impl Default for EngineConfig {
             master_volume: 1.0,
             music_volume: 0.7,
             sfx_volume: 1.0,
+            ambient_volume: 0.6,
+            ui_volume: 0.8,
+            voice_volume: 1.0,
 
             // Debug
             show_fps: true,
@@ -118,6 +140,7 @@ impl Default for EngineConfig {
             gpu_validation: cfg!(debug_assertions),
             enable_profiling: false,
             use_pure_colors: false, // Set to true to disable autotiles
+            deterministic_streaming: false,
 
             // Gameplay
             mouse_sensitivity: 1.0,
@@ -128,6 +151,9 @@ impl Default for EngineConfig {
             ui_scale: 1.0,
             high_contrast: false,
             screen_shake: true,
+
+            // Controls (empty = defaults)
+            key_bindings: HashMap::new(),
         }
     }
 }
@@ -201,12 +227,22 @@ impl EngineConfig {
 
     /// Get the default configuration file path.
     fn config_path() -> PathBuf {
+        Self::config_file(CONFIG_FILE)
+    }
+
+    /// Path of the saved biome color customizations, next to the config.
+    pub fn biome_colors_path() -> PathBuf {
+        Self::config_file(BIOME_COLORS_FILE)
+    }
+
+    /// Path of `name` in the config directory.
+    fn config_file(name: &str) -> PathBuf {
         // Try to use standard config directory
         if let Some(config_dir) = dirs_config_path() {
-            config_dir.join("genesis").join(CONFIG_FILE)
+            config_dir.join("genesis").join(name)
         } else {
             // Fall back to current directory
-            PathBuf::from(CONFIG_FILE)
+            PathBuf::from(name)
         }
     }
 
@@ -228,6 +264,9 @@ impl EngineConfig {
         self.master_volume = self.master_volume.clamp(0.0, 1.0);
         self.music_volume = self.music_volume.clamp(0.0, 1.0);
         self.sfx_volume = self.sfx_volume.clamp(0.0, 1.0);
+        self.ambient_volume = self.ambient_volume.clamp(0.0, 1.0);
+        self.ui_volume = self.ui_volume.clamp(0.0, 1.0);
+        self.voice_volume = self.voice_volume.clamp(0.0, 1.0);
 
         // Gameplay
         self.mouse_sensitivity = self.mouse_sensitivity.clamp(0.1, 5.0);
@@ -236,6 +275,32 @@ impl EngineConfig {
         self.ui_scale = self.ui_scale.clamp(0.5, 3.0);
     }
 
+    /// Store the volume (0.0 - 1.0) for an audio bus.
+    pub fn set_bus_volume(&mut self, bus: AudioCategory, volume: f32) {
+        let volume = volume.clamp(0.0, 1.0);
+        match bus {
+            AudioCategory::Master => self.master_volume = volume,
+            AudioCategory::Music => self.music_volume = volume,
+            AudioCategory::Sfx => self.sfx_volume = volume,
+            AudioCategory::Ambient => self.ambient_volume = volume,
+            AudioCategory::Ui => self.ui_volume = volume,
+            AudioCategory::Voice => self.voice_volume = volume,
+        }
+    }
+
+    /// Get the mixer volume settings stored in this config.
+    #[must_use]
+    pub fn volume_settings(&self) -> VolumeSettings {
+        VolumeSettings {
+            master: self.master_volume,
+            music: self.music_volume,
+            sfx: self.sfx_volume,
+            ambient: self.ambient_volume,
+            ui: self.ui_volume,
+            voice: self.voice_volume,
+        }
+    }
+
     /// Check if this is a debug build configuration.
     #[must_use]
     #[allow(dead_code)]
@@ -307,6 +372,18 @@ mod tests {
         assert!((config.mouse_sensitivity - 0.1).abs() < 0.001);
     }
 
+    #[test]
+    fn test_config_bus_volume() {
+        let mut config = EngineConfig::default();
+        config.set_bus_volume(AudioCategory::Ambient, 0.25);
+        config.set_bus_volume(AudioCategory::Ui, 3.0);
+
+        let volumes = config.volume_settings();
+        assert!((volumes.ambient - 0.25).abs() < 0.001);
+        assert!((volumes.ui - 1.0).abs() < 0.001);
+        assert!((volumes.music - config.music_volume).abs() < 0.001);
+    }
+
     #[test]
     fn test_config_save_load() {
         let temp_dir = TempDir::new().expect("Failed to create temp dir");
@@ -317,6 +394,9 @@ mod tests {
         config.window_width = 1920;
         config.vsync = false;
         config.world_seed = Some(12345);
+        config
+            .key_bindings
+            .insert("Jump".to_string(), "Space,J".to_string());
 
         config.save_to(&config_path).expect("Failed to save config");
 
@@ -325,6 +405,10 @@ mod tests {
         assert_eq!(loaded.window_width, 1920);
         assert!(!loaded.vsync);
         assert_eq!(loaded.world_seed, Some(12345));
+        assert_eq!(
+            loaded.key_bindings.get("Jump").map(String::as_str),
+            Some("Space,J")
+        );
     }
 
     #[test]
@@ -1,9 +1,15 @@
 //! Environment systems for weather and time simulation.
 //!
-//! Provides game time progression and weather state management.
+//! Provides game time progression, seasons and weather state management.
 
 #![allow(dead_code)]
 
+use genesis_gameplay::world_state_save::Season;
+use genesis_worldgen::{BiomeGroup, BiomeTextureMap};
+
+/// Default number of in-game days in each season.
+pub const DEFAULT_DAYS_PER_SEASON: u32 = 30;
+
 /// Game time manager for day/night cycles.
 #[derive(Debug, Clone)]
 pub struct GameTime {
@@ -193,6 +199,34 @@ impl WeatherType {
         }
     }
 
+    /// Returns the fog density (0.0 = clear air, 1.0 = opaque).
+    #[must_use]
+    pub fn fog_density(self) -> f32 {
+        match self {
+            WeatherType::Clear => 0.0,
+            WeatherType::Cloudy => 0.05,
+            WeatherType::Rain => 0.3,
+            WeatherType::Storm => 0.6,
+            WeatherType::Snow => 0.35,
+            WeatherType::Fog => 0.7,
+        }
+    }
+
+    /// Returns the RGB color tint applied to the scene (1.0 = unchanged).
+    ///
+    /// Wet weather pulls the scene toward a darker, cooler grey.
+    #[must_use]
+    pub fn color_tint(self) -> [f32; 3] {
+        match self {
+            WeatherType::Clear => [1.0, 1.0, 1.0],
+            WeatherType::Cloudy => [0.9, 0.9, 0.93],
+            WeatherType::Rain => [0.72, 0.75, 0.82],
+            WeatherType::Storm => [0.5, 0.53, 0.6],
+            WeatherType::Snow => [0.92, 0.95, 1.0],
+            WeatherType::Fog => [0.82, 0.83, 0.85],
+        }
+    }
+
     /// Returns a display name for the weather.
     #[must_use]
     pub fn display_name(self) -> &'static str {
@@ -375,6 +409,28 @@ impl WeatherSystem {
         current + (target - current) * self.transition_progress
     }
 
+    /// Returns the fog density (0.0-1.0), accounting for transitions.
+    #[must_use]
+    pub fn fog_density(&self) -> f32 {
+        let current = self.current_weather.fog_density();
+        let target = self.target_weather.fog_density();
+        current + (target - current) * self.transition_progress
+    }
+
+    /// Returns how far the player can see (1.0 = full draw distance).
+    #[must_use]
+    pub fn visibility_factor(&self) -> f32 {
+        1.0 - self.fog_density()
+    }
+
+    /// Returns the scene color tint, accounting for transitions.
+    #[must_use]
+    pub fn color_tint(&self) -> [f32; 3] {
+        let current = self.current_weather.color_tint();
+        let target = self.target_weather.color_tint();
+        std::array::from_fn(|i| current[i] + (target[i] - current[i]) * self.transition_progress)
+    }
+
     /// Forces a specific weather type immediately.
     pub fn set_weather(&mut self, weather: WeatherType) {
         self.current_weather = weather;
@@ -391,13 +447,49 @@ impl WeatherSystem {
     }
 }
 
-/// Environment state combining time and weather.
+/// Blends `color` toward `target` by `amount` (0.0-1.0).
+fn blend(color: [u8; 3], target: [u8; 3], amount: f32) -> [u8; 3] {
+    std::array::from_fn(|i| {
+        let (from, to) = (f32::from(color[i]), f32::from(target[i]));
+        (from + (to - from) * amount).round() as u8
+    })
+}
+
+/// Returns a biome's terrain color adjusted for the season.
+///
+/// Autumn turns forests orange and dries out grassland; winter covers
+/// temperate land in snow and frosts rivers. Spring and summer, deserts,
+/// jungles and oceans keep their base color.
+#[must_use]
+pub fn seasonal_biome_color(season: Season, biome_id: i32, color: [u8; 3]) -> [u8; 3] {
+    const AUTUMN_LEAVES: [u8; 3] = [196, 104, 38];
+    const DRY_GRASS: [u8; 3] = [176, 156, 84];
+    const SNOW: [u8; 3] = [236, 240, 246];
+    const ICE: [u8; 3] = [176, 208, 230];
+
+    match (season, BiomeTextureMap::group_of(biome_id)) {
+        (Season::Fall, BiomeGroup::Forest) => blend(color, AUTUMN_LEAVES, 0.55),
+        (Season::Fall, BiomeGroup::Plains | BiomeGroup::Savanna | BiomeGroup::Swamp) => {
+            blend(color, DRY_GRASS, 0.3)
+        },
+        (
+            Season::Winter,
+            BiomeGroup::Plains | BiomeGroup::Forest | BiomeGroup::Mountain | BiomeGroup::Swamp,
+        ) => blend(color, SNOW, 0.65),
+        (Season::Winter, BiomeGroup::River) => blend(color, ICE, 0.5),
+        _ => color,
+    }
+}
+
+/// Environment state combining time, seasons and weather.
 #[derive(Debug, Clone)]
 pub struct EnvironmentState {
     /// Game time
     pub time: GameTime,
     /// Weather system
     pub weather: WeatherSystem,
+    /// In-game days per season
+    days_per_season: u32,
 }
 
 impl Default for EnvironmentState {
@@ -413,9 +505,39 @@ impl EnvironmentState {
         Self {
             time: GameTime::default(),
             weather: WeatherSystem::new(),
+            days_per_season: DEFAULT_DAYS_PER_SEASON,
         }
     }
 
+    /// Sets how many in-game days each season lasts.
+    #[must_use]
+    pub fn with_days_per_season(mut self, days: u32) -> Self {
+        self.days_per_season = days.max(1);
+        self
+    }
+
+    /// Returns the current season. Day 1 is the first day of spring.
+    #[must_use]
+    pub fn season(&self) -> Season {
+        Season::from_day(
+            self.time.day_count().saturating_sub(1),
+            self.days_per_season,
+        )
+    }
+
+    /// Returns the offset applied to the temperature need (negative = colder).
+    #[must_use]
+    pub fn temperature_modifier(&self) -> f32 {
+        self.season().temperature_modifier()
+    }
+
+    /// Returns the plant growth rate multiplier, combining season and weather.
+    #[must_use]
+    pub fn growth_modifier(&self) -> f32 {
+        let rain_bonus = 1.0 + self.weather.rain_intensity() * 0.25;
+        self.season().growth_modifier() * rain_bonus
+    }
+
     /// Updates both time and weather systems.
     pub fn update(&mut self, dt: f32) {
         self.time.update(dt);
@@ -488,4 +610,72 @@ mod tests {
         let ambient = env.ambient_light();
         assert!(ambient >= 0.0 && ambient <= 1.0);
     }
+
+    #[test]
+    fn test_season_advances_every_n_days() {
+        let mut env = EnvironmentState::new().with_days_per_season(3);
+        env.time.day_length_seconds = 10.0;
+        let mut seasons = vec![env.season()];
+        for _ in 0..12 {
+            env.update(10.0);
+            seasons.push(env.season());
+        }
+        // Days 1-3 spring, 4-6 summer, ... then back to spring on day 13
+        let expected = [Season::Spring, Season::Summer, Season::Fall, Season::Winter];
+        for (i, season) in seasons.iter().enumerate() {
+            assert_eq!(*season, expected[(i / 3) % 4], "day {}", i + 1);
+        }
+    }
+
+    #[test]
+    fn test_winter_is_cold_and_tints_terrain() {
+        let mut env = EnvironmentState::new().with_days_per_season(2);
+        let spring_temp = env.temperature_modifier();
+        env.time.day_count = 7; // first day of winter
+        assert_eq!(env.season(), Season::Winter);
+        assert!(env.temperature_modifier() < spring_temp);
+        assert!(env.temperature_modifier() < 0.0);
+        assert!(env.growth_modifier() < Season::Spring.growth_modifier());
+
+        let grass = [90, 160, 60];
+        let plains = genesis_worldgen::BIOME_PLAINS;
+        assert_eq!(seasonal_biome_color(Season::Summer, plains, grass), grass);
+        let snowy = seasonal_biome_color(Season::Winter, plains, grass);
+        assert!(snowy.iter().all(|&c| c > 150));
+        let autumn = seasonal_biome_color(Season::Fall, genesis_worldgen::BIOME_FOREST, grass);
+        assert!(autumn[0] > autumn[1] && autumn[0] > grass[0]);
+        // Deserts never get snow
+        let sand = [220, 200, 140];
+        let desert = genesis_worldgen::BIOME_DESERT;
+        assert_eq!(seasonal_biome_color(Season::Winter, desert, sand), sand);
+    }
+
+    #[test]
+    fn test_storm_increases_fog_and_darkens_tint() {
+        let clear = WeatherSystem::with_weather(WeatherType::Clear);
+        let rain = WeatherSystem::with_weather(WeatherType::Rain);
+        let storm = WeatherSystem::with_weather(WeatherType::Storm);
+
+        assert!(clear.fog_density().abs() < f32::EPSILON);
+        assert!((clear.visibility_factor() - 1.0).abs() < f32::EPSILON);
+        assert!(rain.fog_density() > clear.fog_density());
+        assert!(storm.fog_density() > rain.fog_density());
+        assert!(storm.visibility_factor() < rain.visibility_factor());
+
+        let brightness = |tint: [f32; 3]| tint.iter().sum::<f32>();
+        assert!(brightness(rain.color_tint()) < brightness(clear.color_tint()));
+        assert!(brightness(storm.color_tint()) < brightness(rain.color_tint()));
+    }
+
+    #[test]
+    fn test_weather_fog_scales_during_transition() {
+        let mut weather = WeatherSystem::with_weather(WeatherType::Clear);
+        weather.transition_to(WeatherType::Storm);
+        assert!(weather.fog_density().abs() < f32::EPSILON);
+
+        weather.update(15.0); // halfway through the 30s transition
+        let half = weather.fog_density();
+        assert!(half > 0.0 && half < WeatherType::Storm.fog_density());
+        assert!(weather.color_tint()[0] < 1.0);
+    }
 }
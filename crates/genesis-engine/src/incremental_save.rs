@@ -0,0 +1,345 @@
+//! Background saving off the main thread.
+//!
+//! This module provides:
+//! - SavePriority: manual > area-transition > autosave ordering
+//! - SaveRequest/SaveResponse: work items and their results
+//! - IncrementalSaver: a worker thread that services requests by priority
+//!   and reports back over a channel, so a save never blocks the frame.
+//!   The worker writes through the same [`SaveManager`] the caller loads,
+//!   lists and deletes with, so slot metadata stays in one place.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use tracing::{debug, warn};
+
+use crate::save_manager::{SaveFileData, SaveManager};
+
+/// How urgently a save should be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SavePriority {
+    /// Periodic auto-save.
+    Autosave,
+    /// Save triggered by moving to a new area.
+    AreaTransition,
+    /// Save explicitly requested by the player.
+    Manual,
+}
+
+/// A save to be written by the worker.
+#[derive(Debug, Clone)]
+pub struct SaveRequest {
+    /// Slot to write.
+    pub slot_name: String,
+    /// Snapshot of the game state to save.
+    pub data: SaveFileData,
+    /// Request priority.
+    pub priority: SavePriority,
+}
+
+impl SaveRequest {
+    /// Creates a new save request.
+    #[must_use]
+    pub fn new(slot_name: impl Into<String>, data: SaveFileData, priority: SavePriority) -> Self {
+        Self {
+            slot_name: slot_name.into(),
+            data,
+            priority,
+        }
+    }
+}
+
+/// Result of a serviced save request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveResponse {
+    /// Slot that was written.
+    pub slot_name: String,
+    /// Priority of the original request.
+    pub priority: SavePriority,
+    /// Whether the save succeeded.
+    pub success: bool,
+    /// Error message on failure.
+    pub error: Option<String>,
+}
+
+/// Queue entry: highest priority first, FIFO within a priority.
+struct Queued {
+    request: SaveRequest,
+    sequence: u64,
+}
+
+impl PartialEq for Queued {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Queued {}
+
+impl PartialOrd for Queued {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Queued {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.request
+            .priority
+            .cmp(&other.request.priority)
+            .then_with(|| other.sequence.cmp(&self.sequence))
+    }
+}
+
+#[derive(Default)]
+struct QueueState {
+    pending: BinaryHeap<Queued>,
+    next_sequence: u64,
+    shutdown: bool,
+}
+
+type SharedQueue = Arc<(Mutex<QueueState>, Condvar)>;
+
+/// Writes saves on a background thread in priority order.
+pub struct IncrementalSaver {
+    manager: Arc<Mutex<SaveManager>>,
+    queue: SharedQueue,
+    response_tx: Sender<SaveResponse>,
+    response_rx: Receiver<SaveResponse>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl IncrementalSaver {
+    /// Creates a saver writing into `save_dir`. No thread runs until
+    /// [`spawn_worker`](Self::spawn_worker) is called; requests submitted
+    /// before then are queued.
+    #[must_use]
+    pub fn new(save_dir: impl AsRef<Path>) -> Self {
+        Self::with_manager(SaveManager::new(save_dir))
+    }
+
+    /// Creates a saver that writes through an existing save manager.
+    #[must_use]
+    pub fn with_manager(manager: SaveManager) -> Self {
+        let (response_tx, response_rx) = mpsc::channel();
+        Self {
+            manager: Arc::new(Mutex::new(manager)),
+            queue: Arc::new((Mutex::new(QueueState::default()), Condvar::new())),
+            response_tx,
+            response_rx,
+            worker: None,
+        }
+    }
+
+    /// Starts the worker thread. Does nothing if it is already running.
+    pub fn spawn_worker(&mut self) {
+        if self.worker.is_some() {
+            return;
+        }
+
+        let queue = Arc::clone(&self.queue);
+        let responses = self.response_tx.clone();
+        let manager = Arc::clone(&self.manager);
+
+        self.worker = Some(thread::spawn(move || {
+            while let Some(request) = next_request(&queue) {
+                let result = lock_manager(&manager).save(&request.slot_name, &request.data);
+                if let Err(e) = &result {
+                    warn!("Background save to '{}' failed: {}", request.slot_name, e);
+                } else {
+                    debug!("Background save to '{}' complete", request.slot_name);
+                }
+                let response = SaveResponse {
+                    slot_name: request.slot_name,
+                    priority: request.priority,
+                    success: result.is_ok(),
+                    error: result.err().map(|e| e.to_string()),
+                };
+                if responses.send(response).is_err() {
+                    break;
+                }
+            }
+        }));
+    }
+
+    /// Locks the shared save manager for loading, listing or deleting.
+    ///
+    /// Blocks while the worker is in the middle of writing a save.
+    pub fn manager(&self) -> MutexGuard<'_, SaveManager> {
+        lock_manager(&self.manager)
+    }
+
+    /// Queues a save request for the worker.
+    pub fn submit(&self, request: SaveRequest) {
+        let (lock, cvar) = &*self.queue;
+        let mut state = lock
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let sequence = state.next_sequence;
+        state.next_sequence += 1;
+        state.pending.push(Queued { request, sequence });
+        cvar.notify_one();
+    }
+
+    /// Returns the next finished save, if any, without blocking.
+    #[must_use]
+    pub fn try_recv(&self) -> Option<SaveResponse> {
+        self.response_rx.try_recv().ok()
+    }
+
+    /// Drains all finished saves without blocking.
+    pub fn poll_responses(&self) -> Vec<SaveResponse> {
+        self.response_rx.try_iter().collect()
+    }
+
+    /// Number of requests still waiting to be serviced.
+    #[must_use]
+    pub fn pending_count(&self) -> usize {
+        let (lock, _) = &*self.queue;
+        lock.lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .pending
+            .len()
+    }
+
+    /// Whether the worker thread is running.
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        self.worker.is_some()
+    }
+
+    /// Finishes queued saves and stops the worker.
+    pub fn shutdown(&mut self) {
+        {
+            let (lock, cvar) = &*self.queue;
+            lock.lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .shutdown = true;
+            cvar.notify_all();
+        }
+        if let Some(worker) = self.worker.take() {
+            if worker.join().is_err() {
+                warn!("Background save worker panicked");
+            }
+        }
+    }
+}
+
+impl Drop for IncrementalSaver {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+fn lock_manager(manager: &Mutex<SaveManager>) -> MutexGuard<'_, SaveManager> {
+    manager
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// Blocks until a request is available; `None` once shut down and drained.
+fn next_request(queue: &SharedQueue) -> Option<SaveRequest> {
+    let (lock, cvar) = &**queue;
+    let mut state = lock
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    loop {
+        if let Some(queued) = state.pending.pop() {
+            return Some(queued.request);
+        }
+        if state.shutdown {
+            return None;
+        }
+        state = cvar
+            .wait(state)
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    fn test_save_dir(name: &str) -> PathBuf {
+        env::temp_dir()
+            .join("genesis_test_incremental_saves")
+            .join(name)
+    }
+
+    fn cleanup_test_dir(path: &Path) {
+        if path.exists() {
+            let _ = fs::remove_dir_all(path);
+        }
+    }
+
+    fn recv(saver: &IncrementalSaver) -> SaveResponse {
+        saver
+            .response_rx
+            .recv_timeout(Duration::from_secs(5))
+            .expect("save response")
+    }
+
+    #[test]
+    fn test_requests_serviced_by_priority() {
+        let dir = test_save_dir("incremental_priority");
+        cleanup_test_dir(&dir);
+
+        let mut saver = IncrementalSaver::new(&dir);
+        for (slot, priority) in [
+            ("auto", SavePriority::Autosave),
+            ("manual", SavePriority::Manual),
+            ("area", SavePriority::AreaTransition),
+            ("manual2", SavePriority::Manual),
+        ] {
+            saver.submit(SaveRequest::new(slot, SaveFileData::new(slot), priority));
+        }
+        assert_eq!(saver.pending_count(), 4);
+
+        saver.spawn_worker();
+        let order: Vec<String> = (0..4).map(|_| recv(&saver).slot_name).collect();
+        assert_eq!(order, ["manual", "manual2", "area", "auto"]);
+
+        saver.shutdown();
+        cleanup_test_dir(&dir);
+    }
+
+    #[test]
+    fn test_responses_report_success_and_failure() {
+        let dir = test_save_dir("incremental_result");
+        cleanup_test_dir(&dir);
+
+        let mut saver = IncrementalSaver::new(&dir);
+        saver.spawn_worker();
+
+        saver.submit(SaveRequest::new(
+            "good",
+            SaveFileData::new("good"),
+            SavePriority::Manual,
+        ));
+        let ok = recv(&saver);
+        assert!(ok.success);
+        assert!(ok.error.is_none());
+        assert!(saver.manager().slot_exists("good"));
+        assert_eq!(saver.manager().current_slot(), Some("good"));
+
+        saver.submit(SaveRequest::new(
+            "bad/slot",
+            SaveFileData::new("bad/slot"),
+            SavePriority::Autosave,
+        ));
+        let failed = recv(&saver);
+        assert_eq!(failed.slot_name, "bad/slot");
+        assert!(!failed.success);
+        assert!(failed.error.is_some());
+
+        saver.shutdown();
+        cleanup_test_dir(&dir);
+    }
+}
@@ -7,6 +7,8 @@
 //! - Conflict detection
 //! - Reset to defaults
 
+use genesis_gameplay::input::{Action, InputManager, KeyBinding as InputBinding, KeyCode};
+use genesis_tools::ui::KeyAction;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use thiserror::Error;
@@ -257,6 +259,27 @@ impl GameAction {
         matches!(self, Self::Pause | Self::MoveUp | Self::MoveDown | Self::MoveLeft | Self::MoveRight)
     }
 
+    /// Returns the gameplay input action this drives, if the gameplay
+    /// input layer has one.
+    #[must_use]
+    pub fn input_action(self) -> Option<Action> {
+        Some(match self {
+            Self::MoveUp => Action::MoveUp,
+            Self::MoveDown => Action::MoveDown,
+            Self::MoveLeft => Action::MoveLeft,
+            Self::MoveRight => Action::MoveRight,
+            Self::Sprint => Action::Run,
+            Self::Dodge => Action::Dash,
+            Self::Jump => Action::Jump,
+            Self::LockOn => Action::LockOn,
+            Self::UseItem => Action::UseItem,
+            Self::Interact => Action::Interact,
+            Self::Inventory => Action::Inventory,
+            Self::Pause => Action::Pause,
+            _ => return None,
+        })
+    }
+
     /// Returns all actions in a category.
     #[must_use]
     pub fn actions_in_category(category: ActionCategory) -> Vec<Self> {
@@ -268,6 +291,80 @@ impl GameAction {
     }
 }
 
+impl From<KeyAction> for GameAction {
+    /// Maps an options menu action onto the engine's bindable action.
+    fn from(action: KeyAction) -> Self {
+        match action {
+            KeyAction::MoveForward => Self::MoveUp,
+            KeyAction::MoveBackward => Self::MoveDown,
+            KeyAction::MoveLeft => Self::MoveLeft,
+            KeyAction::MoveRight => Self::MoveRight,
+            KeyAction::Jump => Self::Jump,
+            KeyAction::Crouch => Self::Crouch,
+            KeyAction::Sprint => Self::Sprint,
+            KeyAction::PrimaryAction => Self::Attack,
+            KeyAction::SecondaryAction => Self::AttackSecondary,
+            KeyAction::Interact => Self::Interact,
+            KeyAction::Inventory => Self::Inventory,
+            KeyAction::Map => Self::Map,
+            KeyAction::QuestLog => Self::Journal,
+            KeyAction::Pause => Self::Pause,
+        }
+    }
+}
+
+/// Parses a key name into a gameplay key code.
+///
+/// Accepts the names used by the default bindings ("LShift", "Up", "1")
+/// as well as the ones the options menu records ("Shift", "ArrowUp").
+/// Mouse buttons and scroll directions have no key code.
+#[must_use]
+pub fn parse_key_code(name: &str) -> Option<KeyCode> {
+    use KeyCode as K;
+    #[rustfmt::skip]
+    const LETTERS: [KeyCode; 26] = [
+        K::A, K::B, K::C, K::D, K::E, K::F, K::G, K::H, K::I, K::J, K::K, K::L, K::M,
+        K::N, K::O, K::P, K::Q, K::R, K::S, K::T, K::U, K::V, K::W, K::X, K::Y, K::Z,
+    ];
+    #[rustfmt::skip]
+    const DIGITS: [KeyCode; 10] = [
+        K::Num0, K::Num1, K::Num2, K::Num3, K::Num4, K::Num5, K::Num6, K::Num7, K::Num8, K::Num9,
+    ];
+    #[rustfmt::skip]
+    const FUNCTION_KEYS: [KeyCode; 12] = [
+        K::F1, K::F2, K::F3, K::F4, K::F5, K::F6, K::F7, K::F8, K::F9, K::F10, K::F11, K::F12,
+    ];
+
+    if let [c] = name.as_bytes() {
+        return match c {
+            b'A'..=b'Z' => Some(LETTERS[usize::from(c - b'A')]),
+            b'a'..=b'z' => Some(LETTERS[usize::from(c - b'a')]),
+            b'0'..=b'9' => Some(DIGITS[usize::from(c - b'0')]),
+            _ => None,
+        };
+    }
+    if let Some(n) = name.strip_prefix('F').and_then(|n| n.parse::<usize>().ok()) {
+        return n.checked_sub(1).and_then(|i| FUNCTION_KEYS.get(i)).copied();
+    }
+    Some(match name {
+        "Space" => K::Space,
+        "Enter" => K::Enter,
+        "Escape" => K::Escape,
+        "Tab" => K::Tab,
+        "LShift" | "Shift" => K::LShift,
+        "RShift" => K::RShift,
+        "LCtrl" | "Ctrl" => K::LCtrl,
+        "RCtrl" => K::RCtrl,
+        "LAlt" | "Alt" => K::LAlt,
+        "RAlt" => K::RAlt,
+        "Up" | "ArrowUp" => K::Up,
+        "Down" | "ArrowDown" => K::Down,
+        "Left" | "ArrowLeft" => K::Left,
+        "Right" | "ArrowRight" => K::Right,
+        _ => return None,
+    })
+}
+
 /// Categories for grouping actions in UI.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ActionCategory {
@@ -459,7 +556,7 @@ impl InputRebindManager {
             KeyBinding::new(GameAction::MoveLeft, "A").with_secondary("Left"),
             KeyBinding::new(GameAction::MoveRight, "D").with_secondary("Right"),
             KeyBinding::new(GameAction::Sprint, "LShift"),
-            KeyBinding::new(GameAction::Dodge, "Space"),
+            KeyBinding::new(GameAction::Dodge, "LAlt"),
             KeyBinding::new(GameAction::Jump, "Space"),
             KeyBinding::new(GameAction::Crouch, "LCtrl"),
             // Combat
@@ -565,6 +662,53 @@ impl InputRebindManager {
         self.bindings.get(&action).map(|b| b.primary.as_str())
     }
 
+    /// Returns the actions whose keys differ from the defaults.
+    #[must_use]
+    pub fn customized_actions(&self) -> Vec<GameAction> {
+        let defaults: HashMap<GameAction, KeyBinding> = Self::default_bindings()
+            .into_iter()
+            .map(|b| (b.action, b))
+            .collect();
+        let mut actions: Vec<GameAction> = self
+            .bindings
+            .values()
+            .filter(|b| {
+                defaults.get(&b.action).map_or(true, |d| {
+                    d.primary != b.primary || d.secondary != b.secondary
+                })
+            })
+            .map(|b| b.action)
+            .collect();
+        actions.sort_by_key(|a| ALL_ACTIONS.iter().position(|x| x == a));
+        actions
+    }
+
+    /// Pushes customized bindings into the gameplay input manager.
+    ///
+    /// The manager is reset to its own defaults first, so only actions the
+    /// player actually rebound override them. Keys without a gameplay key
+    /// code (mouse buttons, scroll) leave the action on its default.
+    pub fn apply_to(&self, manager: &mut InputManager) {
+        manager.set_default_bindings();
+        for action in self.customized_actions() {
+            let Some(input_action) = action.input_action() else {
+                continue;
+            };
+            let Some(binding) = self.bindings.get(&action) else {
+                continue;
+            };
+            let Some(primary) = parse_key_code(&binding.primary) else {
+                debug!(
+                    "No key code for {}, keeping {:?} default",
+                    binding.primary, action
+                );
+                continue;
+            };
+            let secondary = binding.secondary.as_deref().and_then(parse_key_code);
+            manager.rebind(input_action, InputBinding { primary, secondary });
+        }
+    }
+
     /// Returns all actions triggered by a key.
     pub fn actions_for_key(&self, key: &str) -> Vec<GameAction> {
         self.bindings
@@ -654,6 +798,35 @@ impl InputRebindManager {
         Ok(())
     }
 
+    /// Rebinds the primary key for an action even if another action uses it.
+    ///
+    /// Returns the action that already had the key, if any. Both actions keep
+    /// the key; lookups by key resolve to the newly bound action.
+    pub fn force_rebind_primary(&mut self, action: GameAction, key: &str) -> Option<GameAction> {
+        let conflicting = self.check_conflict(key, action);
+
+        let binding = self
+            .bindings
+            .entry(action)
+            .or_insert_with(|| KeyBinding::new(action, key));
+        if self.key_to_action.get(&binding.primary) == Some(&action) {
+            self.key_to_action.remove(&binding.primary);
+        }
+        binding.primary = key.to_string();
+        self.key_to_action.insert(key.to_string(), action);
+
+        self.dirty = true;
+        if let Some(other) = conflicting {
+            info!(
+                "Rebound {:?} primary to {} (also bound to {:?})",
+                action, key, other
+            );
+        } else {
+            info!("Rebound {:?} primary to {}", action, key);
+        }
+        conflicting
+    }
+
     /// Rebinds the secondary key for an action.
     pub fn rebind_secondary(&mut self, action: GameAction, key: &str) -> RebindResult<()> {
         // Check for conflicts
@@ -769,19 +942,10 @@ impl InputRebindManager {
     /// Imports bindings from a settings map.
     pub fn import_from_map(&mut self, map: &HashMap<String, String>) {
         for (action_str, keys_str) in map {
-            // Parse action from string (simplified - would use proper parsing)
-            let action = match action_str.as_str() {
-                "MoveUp" => Some(GameAction::MoveUp),
-                "MoveDown" => Some(GameAction::MoveDown),
-                "MoveLeft" => Some(GameAction::MoveLeft),
-                "MoveRight" => Some(GameAction::MoveRight),
-                "Sprint" => Some(GameAction::Sprint),
-                "Attack" => Some(GameAction::Attack),
-                "Interact" => Some(GameAction::Interact),
-                "Inventory" => Some(GameAction::Inventory),
-                "Pause" => Some(GameAction::Pause),
-                _ => None,
-            };
+            let action = ALL_ACTIONS
+                .iter()
+                .copied()
+                .find(|a| format!("{a:?}") == *action_str);
 
             if let Some(action) = action {
                 let parts: Vec<&str> = keys_str.split(',').collect();
@@ -876,6 +1040,33 @@ mod tests {
         assert!(matches!(result, Err(RebindError::Conflict { .. })));
     }
 
+    #[test]
+    fn test_rebind_manager_force_rebind_reports_conflict() {
+        let mut manager = InputRebindManager::new();
+
+        let conflict = manager.force_rebind_primary(GameAction::Sprint, "W");
+        assert_eq!(conflict, Some(GameAction::MoveUp));
+        assert_eq!(manager.get_primary_key(GameAction::Sprint), Some("W"));
+        assert_eq!(manager.get_action_for_key("W"), Some(GameAction::Sprint));
+        // Old key no longer resolves to Sprint
+        assert_ne!(
+            manager.get_action_for_key("LShift"),
+            Some(GameAction::Sprint)
+        );
+
+        assert_eq!(manager.force_rebind_primary(GameAction::Sprint, "X"), None);
+    }
+
+    #[test]
+    fn test_key_action_maps_to_game_action() {
+        assert_eq!(GameAction::from(KeyAction::MoveForward), GameAction::MoveUp);
+        assert_eq!(GameAction::from(KeyAction::QuestLog), GameAction::Journal);
+        assert_eq!(
+            GameAction::from(KeyAction::PrimaryAction),
+            GameAction::Attack
+        );
+    }
+
     #[test]
     fn test_rebind_manager_unbind_required() {
         let mut manager = InputRebindManager::new();
@@ -928,6 +1119,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_key_code() {
+        assert_eq!(parse_key_code("Q"), Some(KeyCode::Q));
+        assert_eq!(parse_key_code("7"), Some(KeyCode::Num7));
+        assert_eq!(parse_key_code("F10"), Some(KeyCode::F10));
+        assert_eq!(parse_key_code("Shift"), Some(KeyCode::LShift));
+        assert_eq!(parse_key_code("ArrowUp"), Some(KeyCode::Up));
+        assert_eq!(parse_key_code("F13"), None);
+        assert_eq!(parse_key_code("MouseLeft"), None);
+    }
+
+    #[test]
+    fn test_apply_to_pushes_rebinds_into_input_manager() {
+        let mut manager = InputRebindManager::new();
+        let mut input = InputManager::new();
+        let primary = |input: &InputManager, action| input.get_binding(action).map(|b| b.primary);
+        manager.apply_to(&mut input);
+        // Untouched actions keep the gameplay defaults
+        assert_eq!(primary(&input, Action::LockOn), Some(KeyCode::Q));
+
+        manager.force_rebind_primary(GameAction::Jump, "K");
+        assert_eq!(manager.customized_actions(), vec![GameAction::Jump]);
+        manager.apply_to(&mut input);
+        assert_eq!(primary(&input, Action::Jump), Some(KeyCode::K));
+
+        // Reverting the binding restores the default on the next apply
+        manager.reset_to_defaults();
+        manager.apply_to(&mut input);
+        assert_eq!(primary(&input, Action::Jump), Some(KeyCode::Space));
+    }
+
     #[test]
     fn test_rebind_manager_export_import() {
         let manager = InputRebindManager::new();
@@ -940,6 +1162,7 @@ mod tests {
 
         // Check some bindings were imported
         assert!(manager2.get_binding(GameAction::MoveUp).is_some());
+        assert_eq!(manager2.get_primary_key(GameAction::Journal), Some("J"));
     }
 
     #[test]
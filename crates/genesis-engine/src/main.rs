@@ -44,6 +44,8 @@ pub mod crafting_save;
 pub mod crash_report;
 /// Recipe asset loading
 pub mod recipe_loader;
+/// Recipe dependency graph and tech tree
+pub mod tech_tree;
 /// Weapon data loading
 pub mod weapon_loader;
 
@@ -56,6 +58,8 @@ pub mod asset_manager;
 pub mod autosave;
 /// Cloud storage abstraction
 pub mod cloud_storage;
+/// Background save worker
+pub mod incremental_save;
 /// Save file manager
 pub mod save_manager;
 /// Save file versioning
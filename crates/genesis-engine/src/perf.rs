@@ -5,17 +5,40 @@
 #![allow(dead_code)]
 
 use std::collections::VecDeque;
-use std::time::Instant;
+use std::time::{Duration, Instant};
+
+/// Section name for gameplay update (player, combat, crafting, audio, saves).
+pub const SECTION_GAMEPLAY: &str = "gameplay";
+/// Section name for terrain generation and streaming.
+pub const SECTION_TERRAIN_GEN: &str = "terrain_gen";
+/// Section name for NPC AI updates.
+pub const SECTION_NPC_AI: &str = "npc_ai";
+/// Section name for world rendering.
+pub const SECTION_RENDER: &str = "render";
+/// Section name for egui UI building.
+pub const SECTION_EGUI: &str = "egui";
+
+/// Sections counted as render time; all others count as update time.
+const RENDER_SECTIONS: &[&str] = &[SECTION_RENDER, SECTION_EGUI];
+
+/// Timing history for a single named frame section.
+#[derive(Debug)]
+struct SectionHistory {
+    /// Section name
+    name: &'static str,
+    /// Per-frame totals in seconds
+    samples: VecDeque<f32>,
+    /// Time accumulated for the frame in progress
+    pending: f32,
+}
 
 /// Performance metrics collector.
 #[derive(Debug)]
 pub struct PerfMetrics {
     /// Frame times (total)
     frame_times: VecDeque<f32>,
-    /// Update times (gameplay/physics)
-    update_times: VecDeque<f32>,
-    /// Render times (GPU)
-    render_times: VecDeque<f32>,
+    /// Measured per-section times, in first-recorded order
+    sections: Vec<SectionHistory>,
     /// History size for averaging
     history_size: usize,
     /// Number of loaded chunks
@@ -47,8 +70,7 @@ impl PerfMetrics {
     pub fn new(history_size: usize) -> Self {
         Self {
             frame_times: VecDeque::with_capacity(history_size),
-            update_times: VecDeque::with_capacity(history_size),
-            render_times: VecDeque::with_capacity(history_size),
+            sections: Vec::new(),
             history_size,
             chunk_count: 0,
             cell_count: 0,
@@ -59,26 +81,45 @@ impl PerfMetrics {
         }
     }
 
-    /// Record frame timing.
+    /// Record measured time for a named section of the current frame.
+    ///
+    /// Multiple recordings of the same section within a frame accumulate.
+    /// The values are committed on the next [`Self::record_frame`].
+    ///
+    /// # Arguments
+    /// * `name` - Section name (see the `SECTION_*` constants)
+    /// * `duration` - Measured time spent in the section
+    pub fn record_section(&mut self, name: &'static str, duration: Duration) {
+        let secs = duration.as_secs_f32();
+        if let Some(section) = self.sections.iter_mut().find(|s| s.name == name) {
+            section.pending += secs;
+        } else {
+            self.sections.push(SectionHistory {
+                name,
+                samples: VecDeque::with_capacity(self.history_size),
+                pending: secs,
+            });
+        }
+    }
+
+    /// Record frame timing and commit the sections recorded this frame.
+    ///
+    /// Sections that were not recorded this frame count as zero.
     ///
     /// # Arguments
     /// * `total` - Total frame time in seconds
-    /// * `update` - Update/gameplay time in seconds
-    /// * `render` - Render time in seconds
-    pub fn record_frame(&mut self, total: f32, update: f32, render: f32) {
+    pub fn record_frame(&mut self, total: f32) {
         self.frame_times.push_back(total);
         if self.frame_times.len() > self.history_size {
             self.frame_times.pop_front();
         }
 
-        self.update_times.push_back(update);
-        if self.update_times.len() > self.history_size {
-            self.update_times.pop_front();
-        }
-
-        self.render_times.push_back(render);
-        if self.render_times.len() > self.history_size {
-            self.render_times.pop_front();
+        for section in &mut self.sections {
+            section.samples.push_back(section.pending);
+            if section.samples.len() > self.history_size {
+                section.samples.pop_front();
+            }
+            section.pending = 0.0;
         }
     }
 
@@ -123,22 +164,33 @@ impl PerfMetrics {
         self.frame_times.iter().sum::<f32>() / self.frame_times.len() as f32
     }
 
-    /// Get average update time in seconds.
+    /// Get average time of a named section in seconds.
+    #[must_use]
+    pub fn avg_section_time(&self, name: &str) -> f32 {
+        self.sections
+            .iter()
+            .find(|s| s.name == name)
+            .map_or(0.0, section_average)
+    }
+
+    /// Get average update time in seconds (sum of all non-render sections).
     #[must_use]
     pub fn avg_update_time(&self) -> f32 {
-        if self.update_times.is_empty() {
-            return 0.0;
-        }
-        self.update_times.iter().sum::<f32>() / self.update_times.len() as f32
+        self.sections
+            .iter()
+            .filter(|s| !RENDER_SECTIONS.contains(&s.name))
+            .map(section_average)
+            .sum()
     }
 
-    /// Get average render time in seconds.
+    /// Get average render time in seconds (world render plus egui).
     #[must_use]
     pub fn avg_render_time(&self) -> f32 {
-        if self.render_times.is_empty() {
-            return 0.0;
-        }
-        self.render_times.iter().sum::<f32>() / self.render_times.len() as f32
+        self.sections
+            .iter()
+            .filter(|s| RENDER_SECTIONS.contains(&s.name))
+            .map(section_average)
+            .sum()
     }
 
     /// Update world statistics.
@@ -168,6 +220,14 @@ impl PerfMetrics {
             frame_time_ms: self.avg_frame_time() * 1000.0,
             update_time_ms: self.avg_update_time() * 1000.0,
             render_time_ms: self.avg_render_time() * 1000.0,
+            sections: self
+                .sections
+                .iter()
+                .map(|s| SectionSummary {
+                    name: s.name,
+                    avg_ms: section_average(s) * 1000.0,
+                })
+                .collect(),
             chunks_loaded: self.chunk_count,
             cells_simulated: self.cell_count,
             camera_position: self.camera_position,
@@ -180,11 +240,27 @@ impl PerfMetrics {
     /// Clear all recorded metrics.
     pub fn clear(&mut self) {
         self.frame_times.clear();
-        self.update_times.clear();
-        self.render_times.clear();
+        self.sections.clear();
     }
 }
 
+/// Average of a section's committed samples in seconds.
+fn section_average(section: &SectionHistory) -> f32 {
+    if section.samples.is_empty() {
+        return 0.0;
+    }
+    section.samples.iter().sum::<f32>() / section.samples.len() as f32
+}
+
+/// Average timing of a single frame section.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SectionSummary {
+    /// Section name
+    pub name: &'static str,
+    /// Average time in milliseconds
+    pub avg_ms: f32,
+}
+
 /// Summary of performance metrics for display.
 #[derive(Debug, Clone, Default)]
 pub struct PerfSummary {
@@ -198,6 +274,8 @@ pub struct PerfSummary {
     pub update_time_ms: f32,
     /// Average render time in milliseconds
     pub render_time_ms: f32,
+    /// Per-section breakdown, in first-recorded order
+    pub sections: Vec<SectionSummary>,
     /// Number of loaded chunks
     pub chunks_loaded: u32,
     /// Number of cells being simulated
@@ -213,10 +291,20 @@ pub struct PerfSummary {
 }
 
 impl PerfSummary {
+    /// Format the per-section breakdown as a single line.
+    #[must_use]
+    pub fn format_sections(&self) -> String {
+        self.sections
+            .iter()
+            .map(|s| format!("{}: {:.1}ms", s.name, s.avg_ms))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
     /// Format as multi-line debug text.
     #[must_use]
     pub fn format_debug(&self) -> String {
-        format!(
+        let mut text = format!(
             "FPS: {:.0} (1% low: {:.0})\n\
              Frame: {:.1}ms (Update: {:.1}ms, Render: {:.1}ms)\n\
              Chunks: {} ({} cells)\n\
@@ -236,7 +324,12 @@ impl PerfSummary {
             self.player_position.1,
             self.player_velocity.0,
             self.player_velocity.1
-        )
+        );
+        if !self.sections.is_empty() {
+            text.push_str("\nSections: ");
+            text.push_str(&self.format_sections());
+        }
+        text
     }
 }
 
@@ -306,7 +399,7 @@ mod tests {
 
         // Record some frames at ~60 FPS (16.67ms)
         for _ in 0..30 {
-            metrics.record_frame(0.0167, 0.002, 0.008);
+            record_split_frame(&mut metrics, 0.0167, 0.002, 0.008);
         }
 
         let fps = metrics.avg_fps();
@@ -319,11 +412,11 @@ mod tests {
 
         // Most frames at 60 FPS
         for _ in 0..90 {
-            metrics.record_frame(0.0167, 0.002, 0.008);
+            record_split_frame(&mut metrics, 0.0167, 0.002, 0.008);
         }
         // 10 slow frames at 30 FPS (10% of frames to ensure they're captured)
         for _ in 0..10 {
-            metrics.record_frame(0.0333, 0.004, 0.016);
+            record_split_frame(&mut metrics, 0.0333, 0.004, 0.016);
         }
 
         let low = metrics.low_fps();
@@ -342,6 +435,7 @@ mod tests {
             frame_time_ms: 16.67,
             update_time_ms: 2.0,
             render_time_ms: 8.0,
+            sections: Vec::new(),
             chunks_loaded: 9,
             cells_simulated: 589824,
             camera_position: (128.5, 100.2),
@@ -384,10 +478,69 @@ mod tests {
     #[test]
     fn test_perf_metrics_clear() {
         let mut metrics = PerfMetrics::new(60);
-        metrics.record_frame(0.016, 0.002, 0.008);
+        record_split_frame(&mut metrics, 0.016, 0.002, 0.008);
         assert!(metrics.avg_fps() > 0.0);
 
         metrics.clear();
         assert_eq!(metrics.avg_fps(), 0.0);
+        assert!(metrics.summary().sections.is_empty());
+    }
+
+    #[test]
+    fn test_perf_metrics_sections_sum() {
+        let mut metrics = PerfMetrics::new(60);
+
+        for _ in 0..10 {
+            metrics.record_section(SECTION_GAMEPLAY, Duration::from_millis(2));
+            metrics.record_section(SECTION_TERRAIN_GEN, Duration::from_millis(1));
+            metrics.record_section(SECTION_NPC_AI, Duration::from_millis(3));
+            metrics.record_section(SECTION_RENDER, Duration::from_millis(6));
+            metrics.record_section(SECTION_EGUI, Duration::from_millis(1));
+            metrics.record_frame(0.016);
+        }
+
+        assert!((metrics.avg_update_time() - 0.006).abs() < 1e-5);
+        assert!((metrics.avg_render_time() - 0.007).abs() < 1e-5);
+        assert!((metrics.avg_section_time(SECTION_NPC_AI) - 0.003).abs() < 1e-5);
+        assert!(metrics.avg_section_time("missing").abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_perf_metrics_section_accumulates_within_frame() {
+        let mut metrics = PerfMetrics::new(60);
+
+        metrics.record_section(SECTION_TERRAIN_GEN, Duration::from_millis(1));
+        metrics.record_section(SECTION_TERRAIN_GEN, Duration::from_millis(2));
+        metrics.record_frame(0.016);
+        // Not recorded this frame: counts as zero
+        metrics.record_frame(0.016);
+
+        assert!((metrics.avg_section_time(SECTION_TERRAIN_GEN) - 0.0015).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_perf_summary_sections() {
+        let mut metrics = PerfMetrics::new(60);
+        metrics.record_section(SECTION_GAMEPLAY, Duration::from_millis(4));
+        metrics.record_section(SECTION_RENDER, Duration::from_millis(8));
+        metrics.record_frame(0.016);
+
+        let summary = metrics.summary();
+        let names: Vec<_> = summary.sections.iter().map(|s| s.name).collect();
+        assert_eq!(names, vec![SECTION_GAMEPLAY, SECTION_RENDER]);
+        assert!((summary.sections[0].avg_ms - 4.0).abs() < 1e-3);
+        assert!((summary.update_time_ms - 4.0).abs() < 1e-3);
+        assert!((summary.render_time_ms - 8.0).abs() < 1e-3);
+
+        let text = summary.format_debug();
+        assert!(text.contains("gameplay: 4.0ms"));
+        assert!(text.contains("render: 8.0ms"));
+    }
+
+    /// Records one frame with a gameplay/render split.
+    fn record_split_frame(metrics: &mut PerfMetrics, total: f32, update: f32, render: f32) {
+        metrics.record_section(SECTION_GAMEPLAY, Duration::from_secs_f32(update));
+        metrics.record_section(SECTION_RENDER, Duration::from_secs_f32(render));
+        metrics.record_frame(total);
     }
 }
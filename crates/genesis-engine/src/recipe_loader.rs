@@ -12,10 +12,13 @@ use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
 use genesis_common::{ItemTypeId, RecipeId};
+use genesis_kernel::crafting_grid::{RecipeMatcher, RecipePattern};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tracing::{debug, info, warn};
 
+use crate::tech_tree::TechTree;
+
 /// Default asset path for recipes.
 pub const DEFAULT_RECIPE_PATH: &str = "assets/recipes";
 
@@ -216,6 +219,28 @@ impl RecipeDefinition {
 
         builder.build()
     }
+
+    /// Converts to a shapeless crafting grid pattern.
+    ///
+    /// Each unit of an ingredient takes one grid slot, so recipes needing
+    /// more than `max_slots` items (or none) can't be grid-crafted.
+    #[must_use]
+    pub fn to_recipe_pattern(&self, max_slots: usize) -> Option<RecipePattern> {
+        let mut items = Vec::new();
+        for ingredient in &self.ingredients {
+            let quantity = ingredient.quantity as usize;
+            if items.len() + quantity > max_slots {
+                return None;
+            }
+            items.extend(std::iter::repeat(ingredient.item_id).take(quantity));
+        }
+        if items.is_empty() {
+            return None;
+        }
+
+        let result_count = u16::try_from(self.output.quantity).unwrap_or(u16::MAX);
+        Some(RecipePattern::shapeless(&items).with_result(self.output.item_id, result_count))
+    }
 }
 
 /// A collection of recipes from a single file.
@@ -345,6 +370,29 @@ impl RecipeRegistry {
             .collect()
     }
 
+    /// Builds a grid recipe matcher from all recipes that fit in
+    /// `max_slots` grid slots, in recipe ID order.
+    #[must_use]
+    pub fn build_matcher(&self, max_slots: usize) -> RecipeMatcher {
+        let mut recipes: Vec<_> = self.by_id.values().collect();
+        recipes.sort_by_key(|r| r.id);
+
+        let mut matcher = RecipeMatcher::new();
+        for pattern in recipes.iter().filter_map(|r| r.to_recipe_pattern(max_slots)) {
+            matcher.register(pattern);
+        }
+        matcher
+    }
+
+    /// Finds the first recipe (by ID) producing the given item.
+    #[must_use]
+    pub fn find_by_output(&self, item_id: u32) -> Option<&RecipeDefinition> {
+        self.by_id
+            .values()
+            .filter(|r| r.output.item_id == item_id)
+            .min_by_key(|r| r.id)
+    }
+
     /// Clears the registry.
     pub fn clear(&mut self) {
         self.by_id.clear();
@@ -364,6 +412,8 @@ pub struct RecipeLoader {
     mod_times: HashMap<PathBuf, SystemTime>,
     /// Whether hot-reload is enabled.
     hot_reload_enabled: bool,
+    /// Recipes every player knows from the start.
+    starter_recipes: Vec<u32>,
     /// Statistics.
     stats: RecipeLoaderStats,
 }
@@ -393,6 +443,7 @@ impl RecipeLoader {
             registry: RecipeRegistry::new(),
             mod_times: HashMap::new(),
             hot_reload_enabled: cfg!(debug_assertions),
+            starter_recipes: Vec::new(),
             stats: RecipeLoaderStats::default(),
         }
     }
@@ -410,6 +461,31 @@ impl RecipeLoader {
         self
     }
 
+    /// Sets the recipes every player knows from the start.
+    #[must_use]
+    pub fn with_starter_recipes(mut self, starter_recipes: impl IntoIterator<Item = u32>) -> Self {
+        self.starter_recipes = starter_recipes.into_iter().collect();
+        self
+    }
+
+    /// Returns the starter recipe IDs.
+    #[must_use]
+    pub fn starter_recipes(&self) -> &[u32] {
+        &self.starter_recipes
+    }
+
+    /// Builds the crafting tech tree for the loaded recipes.
+    ///
+    /// Dependency cycles are logged as warnings.
+    #[must_use]
+    pub fn tech_tree(&self) -> TechTree {
+        let tree = TechTree::build(&self.registry, &self.starter_recipes);
+        for cycle in tree.cycles() {
+            warn!("Recipe dependency cycle: {:?}", cycle);
+        }
+        tree
+    }
+
     /// Returns the base path.
     #[must_use]
     pub fn base_path(&self) -> &Path {
@@ -515,13 +591,7 @@ impl RecipeLoader {
                     if let Some(prev_modified) = self.mod_times.get(&path) {
                         if modified > *prev_modified {
                             info!("Hot-reloading recipe file: {:?}", path);
-                            // Need to reload - for now, clear and reload all
-                            self.registry.clear();
-                            self.stats = RecipeLoaderStats::default();
-                            if self.load_all().is_ok() {
-                                self.stats.hot_reloads += 1;
-                                reloaded = true;
-                            }
+                            reloaded = self.reload().is_ok();
                             break;
                         }
                     }
@@ -532,6 +602,17 @@ impl RecipeLoader {
         reloaded
     }
 
+    /// Clears the registry and reloads every recipe file.
+    pub fn reload(&mut self) -> RecipeLoadResult<()> {
+        let hot_reloads = self.stats.hot_reloads;
+        self.registry.clear();
+        self.mod_times.clear();
+        self.stats = RecipeLoaderStats::default();
+        self.load_all()?;
+        self.stats.hot_reloads = hot_reloads + 1;
+        Ok(())
+    }
+
     /// Registers all loaded recipes with a CraftingSystem.
     pub fn register_with_crafting_system(
         &self,
@@ -632,6 +713,29 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_recipe_to_pattern() {
+        let recipe = sample_recipe();
+        let pattern = recipe.to_recipe_pattern(9).expect("fits in a 3x3 grid");
+        assert!(pattern.is_shapeless());
+        assert_eq!(pattern.sorted_ingredients(), vec![100, 100]);
+        assert_eq!((pattern.result_id, pattern.result_count), (300, 1));
+
+        // Two units need two slots
+        assert!(recipe.to_recipe_pattern(1).is_none());
+    }
+
+    #[test]
+    fn test_registry_build_matcher() {
+        let mut registry = RecipeRegistry::new();
+        registry.register(sample_recipe()).expect("should register");
+
+        let matcher = registry.build_matcher(9);
+        assert_eq!(matcher.recipes().len(), 1);
+        assert_eq!(registry.find_by_output(300).map(|r| r.id), Some(1));
+        assert!(registry.find_by_output(100).is_none());
+    }
+
     #[test]
     fn test_registry_register_and_get() {
         let mut registry = RecipeRegistry::new();
@@ -6,12 +6,22 @@
 #![allow(unsafe_code)]
 #![allow(dead_code)]
 
+use std::time::{Duration, Instant};
+
 use anyhow::{Context, Result};
+use genesis_common::{ChunkCoord, WorldPos};
+use genesis_gameplay::terrain_manipulation::materials;
 use genesis_kernel::{
     Camera,
+    cell_buffer::CellDims,
+    compute::Compute,
     player_sprite::{
         PlayerAnimationSet, PlayerSpriteConfig, PlayerSpriteRenderer, PlayerSpriteState,
     },
+    readback::pick_cell,
+    screenshot::{CaptureConfig, ScreenshotData, compose_capture},
+    sim_events::SimEventBatch,
+    sim_rules::{MaterialRule, RuleTable},
     terrain_tiles::TerrainTileRenderer,
 };
 use genesis_tools::EguiIntegration;
@@ -21,6 +31,49 @@ use winit::{dpi::PhysicalSize, event::WindowEvent, window::Window};
 /// Default chunk size for simulation
 pub const DEFAULT_CHUNK_SIZE: u32 = 256;
 
+/// Side length, in chunks, of the simulated region around the player.
+const SIM_REGION_CHUNKS: u32 = 3;
+
+/// Simulated region: the player's chunk and its neighbors.
+#[allow(clippy::cast_possible_wrap)] // region and chunk sizes are small constants
+fn simulation_region(player_chunk: ChunkCoord) -> CellDims {
+    let half = (SIM_REGION_CHUNKS / 2) as i32;
+    let size = DEFAULT_CHUNK_SIZE as i32;
+    CellDims::new(
+        (player_chunk.x - half) * size,
+        (player_chunk.y - half) * size,
+        SIM_REGION_CHUNKS * DEFAULT_CHUNK_SIZE,
+        SIM_REGION_CHUNKS * DEFAULT_CHUNK_SIZE,
+    )
+}
+
+/// Material behavior used by the cell simulation.
+fn material_rules() -> RuleTable {
+    let id = |material: u16| material as u8;
+    let mut rules = RuleTable::new();
+    for material in [
+        materials::DIRT,
+        materials::STONE,
+        materials::GRASS,
+        materials::WOOD,
+        materials::ORE,
+    ] {
+        rules.set(id(material), MaterialRule::solid(2.0));
+    }
+    rules.set(id(materials::LEAVES), MaterialRule::solid(0.5));
+    rules.set(id(materials::SAND), MaterialRule::powder(1.6));
+    rules.set(id(materials::WATER), MaterialRule::liquid(1.0, 0.9));
+    rules.set(
+        id(materials::LAVA),
+        MaterialRule::liquid(2.8, 0.3).with_reaction(
+            id(materials::WATER),
+            id(materials::STONE),
+            0.5,
+        ),
+    );
+    rules
+}
+
 /// Main renderer that manages GPU resources and rendering.
 pub struct Renderer {
     /// wgpu surface for presenting to the window
@@ -41,6 +94,8 @@ pub struct Renderer {
     player_animations: PlayerAnimationSet,
     /// Terrain tile renderer (biome-based world)
     terrain_renderer: TerrainTileRenderer,
+    /// GPU cell simulation
+    simulation: Compute,
     /// Egui integration for UI overlay
     egui: EguiIntegration,
     /// Frame counter
@@ -53,6 +108,12 @@ pub struct Renderer {
     show_debug_grid: bool,
     /// Chunk size for debug grid (in world units/pixels)
     chunk_size: u32,
+    /// Time spent building and preparing egui in the last frame
+    last_ui_time: Duration,
+    /// Egui paint jobs from the last frame, replayed for annotated captures
+    last_paint_jobs: Vec<egui::ClippedPrimitive>,
+    /// Pixels per point used for the last egui frame
+    last_pixels_per_point: f32,
 }
 
 impl Renderer {
@@ -135,6 +196,14 @@ impl Renderer {
         info!("Initializing terrain tile renderer...");
         let terrain_renderer = TerrainTileRenderer::new(&device, surface_format);
 
+        // Initialize cell simulation
+        info!("Initializing cell simulation...");
+        let simulation = Compute::new(
+            &device,
+            simulation_region(ChunkCoord::new(0, 0)),
+            &material_rules(),
+        );
+
         info!("Renderer initialized successfully");
 
         Ok(Self {
@@ -147,12 +216,16 @@ impl Renderer {
             player_sprite_state,
             player_animations,
             terrain_renderer,
+            simulation,
             egui,
             frame_count: 0,
             skip_player_sprite: false,
             streaming_enabled: false,
             show_debug_grid: true,  // Enable by default
             chunk_size: DEFAULT_CHUNK_SIZE,
+            last_ui_time: Duration::ZERO,
+            last_paint_jobs: Vec::new(),
+            last_pixels_per_point: 1.0,
         })
     }
 
@@ -178,6 +251,53 @@ impl Renderer {
         // Terrain simulation removed
     }
 
+    /// Keeps the simulated region centered on the player's chunk.
+    pub fn update_simulation_region(&mut self, player_x: f32, player_y: f32) {
+        let chunk = WorldPos::new(player_x, player_y).to_chunk_coord(DEFAULT_CHUNK_SIZE as f32);
+        self.simulation
+            .resize(&self.device, &self.queue, simulation_region(chunk));
+    }
+
+    /// Returns the simulated cell under a screen position as `(x, y, cell)`.
+    ///
+    /// Reads the GPU cells back first so the result matches what is shown.
+    pub fn pick_cell(
+        &mut self,
+        screen_pos: (f32, f32),
+        camera: &Camera,
+    ) -> Option<(i32, i32, u32)> {
+        self.simulation.read_back(&self.device, &self.queue);
+        pick_cell(screen_pos, camera, self.simulation.cells())
+    }
+
+    /// Returns whether the pointer is over an egui window.
+    #[must_use]
+    pub fn is_pointer_over_ui(&self) -> bool {
+        self.egui.context().is_pointer_over_area()
+    }
+
+    /// Runs up to `ticks` fixed-rate cell simulation ticks.
+    ///
+    /// While the simulation is paused only queued single steps run.
+    pub fn step_simulation(&mut self, ticks: u32) {
+        self.simulation.run(&self.device, &self.queue, ticks);
+    }
+
+    /// Returns reaction events from the last finished simulation readback.
+    pub fn poll_simulation_events(&mut self) -> Option<SimEventBatch> {
+        self.simulation.poll_events(&self.device)
+    }
+
+    /// Toggles the cell simulation pause and returns the new state.
+    pub fn toggle_simulation_paused(&mut self) -> bool {
+        self.simulation.toggle_paused()
+    }
+
+    /// Queues a single cell simulation tick while paused.
+    pub fn step_simulation_once(&mut self) {
+        self.simulation.step_once();
+    }
+
     /// Regenerates the terrain (placeholder).
     pub fn regenerate_terrain(&mut self, _new_seed: u64) {
         info!("Terrain regeneration requested (terrain system removed)");
@@ -188,10 +308,14 @@ impl Renderer {
         // Debug flags removed with terrain
     }
 
-    /// Returns streaming terrain statistics (placeholder).
+    /// Returns streaming terrain statistics.
     #[must_use]
     pub fn streaming_stats(&self) -> Option<StreamingStats> {
-        None
+        let stats = self.terrain_renderer.streaming_stats();
+        Some(StreamingStats {
+            cached_chunks: stats.cached_chunks,
+            memory_bytes: stats.memory_bytes,
+        })
     }
 
     /// Enable autotile terrain (placeholder).
@@ -233,6 +357,11 @@ impl Renderer {
             .update(dt, velocity, position, &self.player_animations);
     }
 
+    /// Drains animation frame events fired since the last call.
+    pub fn poll_player_sprite_events(&mut self) -> Vec<String> {
+        self.player_sprite_state.poll_events()
+    }
+
     /// Triggers a one-shot animation action (e.g., Use, Punch, Jump).
     pub fn set_player_action(&mut self, action: genesis_kernel::player_sprite::PlayerAnimAction) {
         self.player_sprite_state
@@ -257,6 +386,11 @@ impl Renderer {
         &mut self.terrain_renderer
     }
 
+    /// Returns the time spent building and preparing egui in the last frame.
+    pub fn last_ui_time(&self) -> Duration {
+        self.last_ui_time
+    }
+
     /// Returns a reference to the terrain tile renderer.
     pub fn terrain_renderer(&self) -> &TerrainTileRenderer {
         &self.terrain_renderer
@@ -318,10 +452,10 @@ impl Renderer {
         0
     }
 
-    /// Returns the total number of cells being simulated (always 0 now).
+    /// Returns the total number of cells being simulated.
     #[must_use]
     pub fn total_cell_count(&self) -> u64 {
-        0
+        self.simulation.cell_count()
     }
 
     /// Returns a reference to the GPU device.
@@ -392,12 +526,12 @@ impl Renderer {
         let world_right = cam_x + half_width;
         let world_top = cam_y - half_height;
         let world_bottom = cam_y + half_height;
+        let top_left = WorldPos::new(world_left, world_top);
+        let bottom_right = WorldPos::new(world_right, world_bottom);
 
         // Find chunk boundaries to draw
-        let chunk_x_start = (world_left / chunk_size).floor() as i32;
-        let chunk_x_end = (world_right / chunk_size).ceil() as i32;
-        let chunk_y_start = (world_top / chunk_size).floor() as i32;
-        let chunk_y_end = (world_bottom / chunk_size).ceil() as i32;
+        let ChunkCoord { x: chunk_x_start, y: chunk_y_start } = top_left.to_chunk_coord(chunk_size);
+        let ChunkCoord { x: chunk_x_end, y: chunk_y_end } = bottom_right.to_chunk_coord(chunk_size);
 
         // Grid line style
         let grid_stroke = egui::Stroke::new(1.0, egui::Color32::from_rgba_unmultiplied(100, 100, 100, 128));
@@ -441,10 +575,8 @@ impl Renderer {
 
         // Draw finer grid lines within chunks (every 64 pixels)
         let sub_grid_size = 64.0;
-        let sub_x_start = (world_left / sub_grid_size).floor() as i32;
-        let sub_x_end = (world_right / sub_grid_size).ceil() as i32;
-        let sub_y_start = (world_top / sub_grid_size).floor() as i32;
-        let sub_y_end = (world_bottom / sub_grid_size).ceil() as i32;
+        let ChunkCoord { x: sub_x_start, y: sub_y_start } = top_left.to_chunk_coord(sub_grid_size);
+        let ChunkCoord { x: sub_x_end, y: sub_y_end } = bottom_right.to_chunk_coord(sub_grid_size);
 
         for sx in sub_x_start..=sub_x_end {
             let world_x = sx as f32 * sub_grid_size;
@@ -481,6 +613,7 @@ impl Renderer {
         F: FnOnce(&egui::Context),
     {
         // Begin egui frame
+        let ui_start = Instant::now();
         self.egui.begin_frame(window);
 
         // Draw debug grid if enabled (using egui painter for simplicity)
@@ -522,6 +655,7 @@ impl Renderer {
             &screen_descriptor,
             egui_output,
         );
+        self.last_ui_time = ui_start.elapsed();
 
         // Clear the screen with a sky blue color
         {
@@ -636,12 +770,18 @@ impl Renderer {
         self.queue.submit(std::iter::once(encoder.finish()));
         output.present();
 
+        self.last_paint_jobs = paint_jobs;
+        self.last_pixels_per_point = screen_descriptor.pixels_per_point;
+
         self.frame_count += 1;
 
         Ok(())
     }
 
     /// Captures a screenshot and saves it to the specified path.
+    ///
+    /// With `capture.include_overlay` set, the egui output of the last frame
+    /// (debug overlay, HUD) is composited on top of the world.
     pub fn capture_screenshot<P: AsRef<std::path::Path>>(
         &mut self,
         path: P,
@@ -649,6 +789,7 @@ impl Renderer {
         _window: &Window,
         time_of_day: f32,
         sun_intensity: f32,
+        capture: CaptureConfig,
     ) -> Result<std::path::PathBuf> {
         use std::io::Write;
 
@@ -658,38 +799,10 @@ impl Renderer {
         let height = self.size.height;
 
         // Create a texture to render to
-        let capture_texture = self.device.create_texture(&wgpu::TextureDescriptor {
-            label: Some("Screenshot Capture Texture"),
-            size: wgpu::Extent3d {
-                width,
-                height,
-                depth_or_array_layers: 1,
-            },
-            mip_level_count: 1,
-            sample_count: 1,
-            dimension: wgpu::TextureDimension::D2,
-            format: self.config.format,
-            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
-            view_formats: &[],
-        });
+        let capture_texture = self.create_capture_texture("Screenshot Capture Texture");
 
         let capture_view = capture_texture.create_view(&wgpu::TextureViewDescriptor::default());
 
-        // Calculate buffer size with proper alignment
-        let bytes_per_pixel = 4u32;
-        let unpadded_bytes_per_row = width * bytes_per_pixel;
-        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
-        let padded_bytes_per_row = (unpadded_bytes_per_row + align - 1) / align * align;
-        let buffer_size = (padded_bytes_per_row * height) as u64;
-
-        // Create buffer for reading back
-        let output_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("Screenshot Output Buffer"),
-            size: buffer_size,
-            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
-            mapped_at_creation: false,
-        });
-
         let mut encoder = self.device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
             label: Some("Screenshot Encoder"),
         });
@@ -778,10 +891,126 @@ impl Renderer {
             self.player_sprite_renderer.render(&mut render_pass);
         }
 
+        self.queue.submit(std::iter::once(encoder.finish()));
+
+        let world = ScreenshotData::new(width, height, self.read_texture_rgba(&capture_texture)?);
+        let overlay = if capture.include_overlay {
+            Some(ScreenshotData::new(width, height, self.render_overlay_rgba()?))
+        } else {
+            None
+        };
+        let image = compose_capture(world, overlay.as_ref(), capture);
+
+        // Encode as PNG
+        let mut png_data = Vec::new();
+        {
+            let mut encoder = png::Encoder::new(&mut png_data, width, height);
+            encoder.set_color(png::ColorType::Rgba);
+            encoder.set_depth(png::BitDepth::Eight);
+            let mut writer = encoder.write_header().context("Failed to write PNG header")?;
+            writer.write_image_data(&image.pixels).context("Failed to write PNG data")?;
+        }
+
+        // Save to file
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create screenshot directory")?;
+        }
+        let mut file = std::fs::File::create(path).context("Failed to create screenshot file")?;
+        file.write_all(&png_data).context("Failed to write screenshot data")?;
+
+        info!("Screenshot saved: {:?} ({}x{})", path, width, height);
+
+        Ok(path.to_path_buf())
+    }
+
+    /// Replays the last frame's egui paint jobs onto a transparent texture
+    /// and reads it back as premultiplied RGBA.
+    fn render_overlay_rgba(&self) -> Result<Vec<u8>> {
+        let overlay_texture = self.create_capture_texture("Screenshot Overlay Texture");
+        let overlay_view = overlay_texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let screen_descriptor = egui_wgpu::ScreenDescriptor {
+            size_in_pixels: [self.size.width, self.size.height],
+            pixels_per_point: self.last_pixels_per_point,
+        };
+
+        let mut encoder = self.device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Screenshot Overlay Encoder"),
+        });
+        {
+            let render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Screenshot Overlay Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &overlay_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            // SAFETY: We drop the render pass before using the encoder again
+            self.egui.render(
+                &mut render_pass.forget_lifetime(),
+                &self.last_paint_jobs,
+                &screen_descriptor,
+            );
+        }
+        self.queue.submit(std::iter::once(encoder.finish()));
+
+        self.read_texture_rgba(&overlay_texture)
+    }
+
+    /// Creates an offscreen texture matching the surface for captures.
+    fn create_capture_texture(&self, label: &str) -> wgpu::Texture {
+        self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some(label),
+            size: wgpu::Extent3d {
+                width: self.size.width,
+                height: self.size.height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: self.config.format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        })
+    }
+
+    /// Copies a capture texture back to the CPU as tightly packed RGBA.
+    fn read_texture_rgba(&self, texture: &wgpu::Texture) -> Result<Vec<u8>> {
+        let width = self.size.width;
+        let height = self.size.height;
+
+        // Calculate buffer size with proper alignment
+        let bytes_per_pixel = 4u32;
+        let unpadded_bytes_per_row = width * bytes_per_pixel;
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padded_bytes_per_row = unpadded_bytes_per_row.div_ceil(align) * align;
+        let buffer_size = (padded_bytes_per_row * height) as u64;
+
+        // Create buffer for reading back
+        let output_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Screenshot Output Buffer"),
+            size: buffer_size,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = self.device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Screenshot Readback Encoder"),
+        });
+
         // Copy texture to buffer
         encoder.copy_texture_to_buffer(
             wgpu::ImageCopyTexture {
-                texture: &capture_texture,
+                texture,
                 mip_level: 0,
                 origin: wgpu::Origin3d::ZERO,
                 aspect: wgpu::TextureAspect::All,
@@ -846,32 +1075,14 @@ impl Renderer {
         drop(data);
         output_buffer.unmap();
 
-        // Encode as PNG
-        let mut png_data = Vec::new();
-        {
-            let mut encoder = png::Encoder::new(&mut png_data, width, height);
-            encoder.set_color(png::ColorType::Rgba);
-            encoder.set_depth(png::BitDepth::Eight);
-            let mut writer = encoder.write_header().context("Failed to write PNG header")?;
-            writer.write_image_data(&image_data).context("Failed to write PNG data")?;
-        }
-
-        // Save to file
-        let path = path.as_ref();
-        if let Some(parent) = path.parent() {
-            std::fs::create_dir_all(parent).context("Failed to create screenshot directory")?;
-        }
-        let mut file = std::fs::File::create(path).context("Failed to create screenshot file")?;
-        file.write_all(&png_data).context("Failed to write screenshot data")?;
-
-        info!("Screenshot saved: {:?} ({}x{})", path, width, height);
-
-        Ok(path.to_path_buf())
+        Ok(image_data)
     }
 }
 
-/// Placeholder streaming stats (terrain removed).
+/// Streaming terrain stats.
 pub struct StreamingStats {
-    /// Number of simulating chunks
-    pub simulating_count: usize,
+    /// Number of cached terrain chunks
+    pub cached_chunks: usize,
+    /// Bytes held by cached terrain chunks
+    pub memory_bytes: usize,
 }
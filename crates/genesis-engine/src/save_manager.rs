@@ -6,6 +6,7 @@
 //! - Atomic save operations (temp file + rename)
 //! - Error handling and recovery
 
+use genesis_gameplay::quest::QuestSaveData;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs::{self, File};
@@ -17,7 +18,7 @@ use tracing::{debug, error, info, warn};
 
 use crate::combat_save::CombatSaveData;
 use crate::crafting_save::CraftingSaveData;
-use crate::save_version::CURRENT_SAVE_VERSION;
+use crate::save_version::{MigrationRegistry, SectionVersions, CURRENT_SAVE_VERSION};
 
 /// Default save directory name.
 pub const DEFAULT_SAVE_DIR: &str = "saves";
@@ -31,6 +32,9 @@ pub const AUTOSAVE_PREFIX: &str = "autosave";
 /// Quick-save slot name.
 pub const QUICKSAVE_SLOT: &str = "quicksave";
 
+/// Prefix of the numbered slots shown in the save menu (`slot_1`, `slot_2`, ...).
+pub const NUMBERED_SLOT_PREFIX: &str = "slot_";
+
 /// Errors that can occur during save operations.
 #[derive(Debug, Error)]
 pub enum SaveError {
@@ -74,11 +78,89 @@ pub enum SaveError {
     /// Atomic write failed.
     #[error("Atomic write failed: {0}")]
     AtomicWriteFailed(String),
+
+    /// Section migration failed.
+    #[error("Section migration failed: {0}")]
+    Migration(String),
 }
 
 /// Result type for save operations.
 pub type SaveResult<T> = Result<T, SaveError>;
 
+/// Maps a JSON error to the matching save error.
+///
+/// Syntax and EOF errors mean the file itself is damaged; data errors mean
+/// the document is intact but does not match the save schema.
+fn classify_json_error(e: serde_json::Error) -> SaveError {
+    match e.classify() {
+        serde_json::error::Category::Syntax | serde_json::error::Category::Eof => {
+            SaveError::Corrupted(e.to_string())
+        },
+        serde_json::error::Category::Io => SaveError::Io(e.into()),
+        serde_json::error::Category::Data => SaveError::Deserialization(e.to_string()),
+    }
+}
+
+/// How a save file is damaged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveCorruption {
+    /// The file is unreadable or not a complete save document (e.g. truncated).
+    Header,
+    /// The document parses but its checksum does not match its contents.
+    Checksum,
+    /// The document parses but does not match the save schema.
+    Deserialize,
+}
+
+/// Result of checking a save slot before loading it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaveHealth {
+    /// The save loads and its checksum matches.
+    Healthy,
+    /// No save file exists for the slot.
+    Missing,
+    /// The save was written by a newer version of the game.
+    NewerVersion {
+        /// Version found in the save.
+        found: u32,
+    },
+    /// The save is damaged.
+    Corrupt {
+        /// What kind of damage was found.
+        kind: SaveCorruption,
+        /// Underlying error message.
+        detail: String,
+        /// Whether a `.bak` copy of the previous save exists.
+        backup_available: bool,
+    },
+}
+
+impl SaveHealth {
+    /// Returns true if the save can be loaded as-is.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        matches!(self, Self::Healthy)
+    }
+
+    /// Returns true if the save is damaged.
+    #[must_use]
+    pub fn is_corrupt(&self) -> bool {
+        matches!(self, Self::Corrupt { .. })
+    }
+
+    /// Returns true if the menu should offer loading the backup instead.
+    #[must_use]
+    pub fn can_recover_from_backup(&self) -> bool {
+        matches!(
+            self,
+            Self::Corrupt {
+                backup_available: true,
+                ..
+            }
+        )
+    }
+}
+
 /// Metadata about a save slot.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SaveSlotMetadata {
@@ -187,10 +269,16 @@ impl SaveSlotMetadata {
 pub struct SaveFileData {
     /// Save metadata.
     pub metadata: SaveSlotMetadata,
+    /// Schema version of each section (saves without it predate section versioning).
+    #[serde(default = "SectionVersions::initial")]
+    pub section_versions: SectionVersions,
     /// Crafting state.
     pub crafting: CraftingSaveData,
     /// Combat state.
     pub combat: CombatSaveData,
+    /// Quest state.
+    #[serde(default)]
+    pub quest: QuestSaveData,
     /// Player position.
     pub player_position: (f32, f32),
     /// World seed.
@@ -207,8 +295,10 @@ impl SaveFileData {
     pub fn new(slot_name: impl Into<String>) -> Self {
         Self {
             metadata: SaveSlotMetadata::new(slot_name),
+            section_versions: SectionVersions::current(),
             crafting: CraftingSaveData::default(),
             combat: CombatSaveData::default(),
+            quest: QuestSaveData::default(),
             player_position: (0.0, 0.0),
             world_seed: 0,
             game_time: 0.0,
@@ -263,6 +353,8 @@ pub struct SaveManager {
     slot_cache: HashMap<String, SaveSlotMetadata>,
     /// Last error message.
     last_error: Option<String>,
+    /// Migrations applied to sections on load.
+    migrations: MigrationRegistry,
 }
 
 impl Default for SaveManager {
@@ -281,9 +373,15 @@ impl SaveManager {
             current_slot: None,
             slot_cache: HashMap::new(),
             last_error: None,
+            migrations: MigrationRegistry::with_builtin_migrations(),
         }
     }
 
+    /// Returns the migration registry used when loading, for registering section migrations.
+    pub fn migrations_mut(&mut self) -> &mut MigrationRegistry {
+        &mut self.migrations
+    }
+
     /// Returns the save directory path.
     #[must_use]
     pub fn save_dir(&self) -> &Path {
@@ -327,6 +425,11 @@ impl SaveManager {
         self.save_dir.join(format!("{slot_name}.meta"))
     }
 
+    /// Returns the path of the copy kept from the previous save.
+    fn backup_path(&self, slot_name: &str) -> PathBuf {
+        self.save_dir.join(format!("{slot_name}.bak"))
+    }
+
     /// Returns the temp path for atomic writes.
     fn temp_path(&self, slot_name: &str) -> PathBuf {
         self.save_dir.join(format!("{slot_name}.tmp"))
@@ -408,6 +511,18 @@ impl SaveManager {
             writer.flush()?;
         }
 
+        // Keep the previous save so a corrupt write can be recovered from,
+        // but never replace a good backup with a damaged save
+        match self.diagnose(slot_name) {
+            SaveHealth::Healthy => {
+                if let Err(e) = fs::copy(&final_path, self.backup_path(slot_name)) {
+                    warn!("Failed to back up slot {}: {}", slot_name, e);
+                }
+            },
+            SaveHealth::Missing => {},
+            health => warn!("Not backing up slot {}: {:?}", slot_name, health),
+        }
+
         // Atomic rename
         fs::rename(&temp_path, &final_path).map_err(|e| {
             // Clean up temp file on failure
@@ -473,13 +588,82 @@ impl SaveManager {
         result
     }
 
+    /// Loads the `.bak` copy of a slot, kept from the save before the last.
+    ///
+    /// Offered by the save menu when [`Self::diagnose`] reports corruption.
+    pub fn load_backup(&mut self, slot_name: &str) -> SaveResult<SaveFileData> {
+        Self::validate_slot_name(slot_name)?;
+
+        let path = self.backup_path(slot_name);
+        if !path.exists() {
+            return Err(SaveError::SlotNotFound(format!("{slot_name}.bak")));
+        }
+
+        let data = self.load_file(&path)?;
+        self.current_slot = Some(slot_name.to_string());
+        info!("Loaded backup for slot: {}", slot_name);
+        Ok(data)
+    }
+
+    /// Checks a slot without loading it into the game.
+    ///
+    /// The save menu uses this to warn about damaged saves and offer the
+    /// backup. A checksum mismatch is reported here even though [`Self::load`]
+    /// only warns about it.
+    #[must_use]
+    pub fn diagnose(&self, slot_name: &str) -> SaveHealth {
+        let path = self.slot_path(slot_name);
+        if Self::validate_slot_name(slot_name).is_err() || !path.exists() {
+            return SaveHealth::Missing;
+        }
+        let backup_available = self.backup_path(slot_name).exists();
+        let corrupt = |kind, detail: String| SaveHealth::Corrupt {
+            kind,
+            detail,
+            backup_available,
+        };
+
+        match self.load_file(&path) {
+            Ok(data) if data.verify_checksum() => SaveHealth::Healthy,
+            Ok(data) => corrupt(
+                SaveCorruption::Checksum,
+                format!(
+                    "expected checksum {}, found {}",
+                    data.calculate_checksum(),
+                    data.metadata.checksum
+                ),
+            ),
+            Err(SaveError::VersionMismatch { found, .. }) => SaveHealth::NewerVersion { found },
+            Err(e @ (SaveError::Corrupted(_) | SaveError::Io(_))) => {
+                corrupt(SaveCorruption::Header, e.to_string())
+            },
+            Err(e) => corrupt(SaveCorruption::Deserialize, e.to_string()),
+        }
+    }
+
     /// Loads a save file from path.
     fn load_file(&self, path: &Path) -> SaveResult<SaveFileData> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
 
-        let data: SaveFileData = serde_json::from_reader(reader)
-            .map_err(|e| SaveError::Deserialization(e.to_string()))?;
+        let mut raw: serde_json::Value =
+            serde_json::from_reader(reader).map_err(classify_json_error)?;
+
+        // Bring each section up to date on its own before decoding
+        let migrated = self
+            .migrations
+            .migrate_sections(&mut raw, &SectionVersions::current())
+            .map_err(|e| SaveError::Migration(e.to_string()))?;
+        for (section, record) in &migrated {
+            debug!(
+                "Migrated {} section: {} -> {}",
+                section.key(),
+                record.from_version,
+                record.to_version
+            );
+        }
+
+        let data: SaveFileData = serde_json::from_value(raw).map_err(classify_json_error)?;
 
         // Check version compatibility
         if data.metadata.version > CURRENT_SAVE_VERSION {
@@ -509,6 +693,11 @@ impl SaveManager {
             fs::remove_file(&meta_path)?;
         }
 
+        let backup_path = self.backup_path(slot_name);
+        if backup_path.exists() {
+            fs::remove_file(&backup_path)?;
+        }
+
         self.slot_cache.remove(slot_name);
 
         if self.current_slot.as_deref() == Some(slot_name) {
@@ -634,6 +823,29 @@ impl SaveManager {
     pub fn clear_cache(&mut self) {
         self.slot_cache.clear();
     }
+
+    /// Lists the numbered slots (`slot_1`, `slot_2`, ...) in slot order.
+    ///
+    /// Entry `i` holds the metadata of `slot_{i + 1}`, or `None` if it is
+    /// unused. Quicksaves, autosaves and slots beyond `slot_count` are left out.
+    pub fn numbered_slots(
+        &mut self,
+        slot_count: usize,
+    ) -> SaveResult<Vec<Option<SaveSlotMetadata>>> {
+        let mut slots = vec![None; slot_count];
+        for meta in self.list_slots()? {
+            let index = meta
+                .slot_name
+                .strip_prefix(NUMBERED_SLOT_PREFIX)
+                .and_then(|number| number.parse::<usize>().ok())
+                .and_then(|number| number.checked_sub(1));
+            if let Some(slot) = index.and_then(|index| slots.get_mut(index)) {
+                *slot = Some(meta);
+            }
+        }
+
+        Ok(slots)
+    }
 }
 
 /// Builder for creating save file data from game state.
@@ -713,6 +925,13 @@ impl SaveFileBuilder {
         self
     }
 
+    /// Sets quest data.
+    #[must_use]
+    pub fn quest(mut self, data: QuestSaveData) -> Self {
+        self.data.quest = data;
+        self
+    }
+
     /// Adds custom data.
     #[must_use]
     pub fn custom_data(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
@@ -851,6 +1070,31 @@ mod tests {
         cleanup_test_dir(&dir);
     }
 
+    #[test]
+    fn test_save_manager_quest_roundtrip() {
+        use genesis_gameplay::quest::{QuestId, QuestProgress};
+
+        let dir = test_save_dir().join("test_quest_roundtrip");
+        cleanup_test_dir(&dir);
+
+        let mut manager = SaveManager::new(&dir);
+
+        let mut progress = QuestProgress::new(QuestId::new(7), 3, 120.0);
+        progress.objective_progress = vec![3, 1, 0];
+        let quest = QuestSaveData {
+            active: vec![progress],
+            completed: vec![QuestId::new(2), QuestId::new(4)],
+        };
+
+        let data = SaveFileBuilder::new("quests").quest(quest.clone()).build();
+        manager.save("quests", &data).expect("Save failed");
+
+        let loaded = manager.load("quests").expect("Load failed");
+        assert_eq!(loaded.quest, quest);
+
+        cleanup_test_dir(&dir);
+    }
+
     #[test]
     fn test_save_manager_delete() {
         let dir = test_save_dir().join("test_delete");
@@ -889,6 +1133,134 @@ mod tests {
         cleanup_test_dir(&dir);
     }
 
+    #[test]
+    fn test_numbered_slots_skip_quicksave() {
+        let dir = test_save_dir().join("test_numbered_slots");
+        cleanup_test_dir(&dir);
+
+        let mut manager = SaveManager::new(&dir);
+        let data = SaveFileBuilder::new("slot_2").display_name("Hero").build();
+        manager.save("slot_2", &data).expect("Save failed");
+        manager.save("slot_9", &data).expect("Save failed");
+        manager.quicksave(&data).expect("Quicksave failed");
+
+        let slots = manager.numbered_slots(8).expect("List failed");
+        assert_eq!(slots.len(), 8);
+        assert_eq!(slots.iter().flatten().count(), 1);
+        let meta = slots[1].as_ref().expect("slot_2 missing");
+        assert_eq!(meta.display_name, "Hero");
+
+        cleanup_test_dir(&dir);
+    }
+
+    #[test]
+    fn test_diagnose_truncated_save_as_corrupt() {
+        let dir = test_save_dir().join("test_diagnose");
+        cleanup_test_dir(&dir);
+
+        let mut manager = SaveManager::new(&dir);
+        assert_eq!(manager.diagnose("slot_1"), SaveHealth::Missing);
+
+        let data = SaveFileBuilder::new("slot_1")
+            .player_position(4.0, 8.0)
+            .build();
+        manager.save("slot_1", &data).expect("Save failed");
+        assert_eq!(manager.diagnose("slot_1"), SaveHealth::Healthy);
+
+        // Second save keeps the first as a backup, then gets cut off mid-write
+        manager.save("slot_1", &data).expect("Save failed");
+        let path = dir.join("slot_1.sav");
+        let bytes = fs::read(&path).expect("read save");
+        fs::write(&path, &bytes[..bytes.len() / 2]).expect("truncate save");
+
+        let health = manager.diagnose("slot_1");
+        assert!(health.is_corrupt());
+        assert!(health.can_recover_from_backup());
+        assert!(matches!(
+            health,
+            SaveHealth::Corrupt {
+                kind: SaveCorruption::Header,
+                ..
+            }
+        ));
+        assert!(matches!(
+            manager.load("slot_1"),
+            Err(SaveError::Corrupted(_))
+        ));
+
+        let recovered = manager.load_backup("slot_1").expect("Backup load failed");
+        assert_eq!(recovered.player_position, (4.0, 8.0));
+
+        cleanup_test_dir(&dir);
+    }
+
+    #[test]
+    fn test_corrupt_save_does_not_replace_backup() {
+        let dir = test_save_dir().join("test_corrupt_backup");
+        cleanup_test_dir(&dir);
+
+        let mut manager = SaveManager::new(&dir);
+        let first = SaveFileBuilder::new("slot_3")
+            .player_position(1.0, 2.0)
+            .build();
+        manager.save("slot_3", &first).expect("Save failed");
+        manager.save("slot_3", &first).expect("Save failed");
+
+        // Corrupt the live save, then save over it
+        let path = dir.join("slot_3.sav");
+        let bytes = fs::read(&path).expect("read save");
+        fs::write(&path, &bytes[..bytes.len() / 2]).expect("truncate save");
+        let second = SaveFileBuilder::new("slot_3")
+            .player_position(5.0, 6.0)
+            .build();
+        manager.save("slot_3", &second).expect("Save failed");
+
+        let backup = manager.load_backup("slot_3").expect("Backup load failed");
+        assert_eq!(backup.player_position, (1.0, 2.0));
+        assert_eq!(
+            manager.load("slot_3").expect("Load failed").player_position,
+            (5.0, 6.0)
+        );
+
+        cleanup_test_dir(&dir);
+    }
+
+    #[test]
+    fn test_diagnose_checksum_and_schema_errors() {
+        let dir = test_save_dir().join("test_diagnose_kinds");
+        cleanup_test_dir(&dir);
+
+        let mut manager = SaveManager::new(&dir);
+        let data = SaveFileBuilder::new("slot_2").world_seed(99).build();
+        manager.save("slot_2", &data).expect("Save failed");
+
+        let path = dir.join("slot_2.sav");
+        let mut raw: serde_json::Value =
+            serde_json::from_slice(&fs::read(&path).expect("read save")).expect("parse save");
+        raw["world_seed"] = serde_json::json!(100);
+        fs::write(&path, raw.to_string()).expect("write save");
+        assert!(matches!(
+            manager.diagnose("slot_2"),
+            SaveHealth::Corrupt {
+                kind: SaveCorruption::Checksum,
+                backup_available: false,
+                ..
+            }
+        ));
+
+        raw["world_seed"] = serde_json::json!("not a number");
+        fs::write(&path, raw.to_string()).expect("write save");
+        assert!(matches!(
+            manager.diagnose("slot_2"),
+            SaveHealth::Corrupt {
+                kind: SaveCorruption::Deserialize,
+                ..
+            }
+        ));
+
+        cleanup_test_dir(&dir);
+    }
+
     #[test]
     fn test_save_manager_slot_not_found() {
         let dir = test_save_dir().join("test_not_found");
@@ -6,6 +6,7 @@
 //! - Backward compatibility support
 //! - Version mismatch warnings
 
+use genesis_common::SchemaVersion;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use thiserror::Error;
@@ -20,6 +21,18 @@ pub const MIN_SUPPORTED_VERSION: u32 = 1;
 /// Maximum forward compatibility version.
 pub const MAX_FORWARD_VERSION: u32 = 1;
 
+/// Schema version a section is assumed to have when the save predates section versioning.
+pub const INITIAL_SECTION_VERSION: SchemaVersion = SchemaVersion::new(1, 0, 0);
+
+/// Current combat section version.
+pub const COMBAT_SECTION_VERSION: SchemaVersion = SchemaVersion::new(1, 0, 0);
+
+/// Current crafting section version.
+pub const CRAFTING_SECTION_VERSION: SchemaVersion = SchemaVersion::new(1, 0, 0);
+
+/// Current quest section version.
+pub const QUEST_SECTION_VERSION: SchemaVersion = SchemaVersion::new(1, 0, 0);
+
 /// Errors related to save versioning.
 #[derive(Debug, Error)]
 pub enum VersionError {
@@ -286,10 +299,128 @@ pub trait Migration {
     fn description(&self) -> &str;
 }
 
+/// A save file section that is versioned independently of the file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SaveSection {
+    /// Combat state.
+    Combat,
+    /// Crafting state.
+    Crafting,
+    /// Quest state.
+    Quest,
+}
+
+impl SaveSection {
+    /// All versioned sections.
+    pub const ALL: [Self; 3] = [Self::Combat, Self::Crafting, Self::Quest];
+
+    /// Field name of the section in the serialized save.
+    #[must_use]
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::Combat => "combat",
+            Self::Crafting => "crafting",
+            Self::Quest => "quest",
+        }
+    }
+
+    /// Current schema version of the section.
+    #[must_use]
+    pub const fn current_version(self) -> SchemaVersion {
+        match self {
+            Self::Combat => COMBAT_SECTION_VERSION,
+            Self::Crafting => CRAFTING_SECTION_VERSION,
+            Self::Quest => QUEST_SECTION_VERSION,
+        }
+    }
+}
+
+/// Packs a section schema version into the `u32` form used by [`Migration`].
+///
+/// Uses the same `major << 16 | minor` layout as [`SaveVersion`]; patch
+/// releases never change the layout, so they don't need migrations.
+#[must_use]
+pub const fn section_version_key(version: SchemaVersion) -> u32 {
+    ((version.major as u32) << 16) | (version.minor as u32)
+}
+
+/// Unpacks a [`section_version_key`] back into a schema version.
+#[must_use]
+pub const fn section_version_from_key(key: u32) -> SchemaVersion {
+    SchemaVersion::new((key >> 16) as u16, (key & 0xFFFF) as u16, 0)
+}
+
+fn initial_section_version() -> SchemaVersion {
+    INITIAL_SECTION_VERSION
+}
+
+/// Schema version of each save section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SectionVersions {
+    /// Combat section version.
+    #[serde(default = "initial_section_version")]
+    pub combat: SchemaVersion,
+    /// Crafting section version.
+    #[serde(default = "initial_section_version")]
+    pub crafting: SchemaVersion,
+    /// Quest section version.
+    #[serde(default = "initial_section_version")]
+    pub quest: SchemaVersion,
+}
+
+impl Default for SectionVersions {
+    fn default() -> Self {
+        Self::current()
+    }
+}
+
+impl SectionVersions {
+    /// Versions written by this build.
+    #[must_use]
+    pub const fn current() -> Self {
+        Self {
+            combat: COMBAT_SECTION_VERSION,
+            crafting: CRAFTING_SECTION_VERSION,
+            quest: QUEST_SECTION_VERSION,
+        }
+    }
+
+    /// Versions assumed for saves written before sections were versioned.
+    #[must_use]
+    pub const fn initial() -> Self {
+        Self {
+            combat: INITIAL_SECTION_VERSION,
+            crafting: INITIAL_SECTION_VERSION,
+            quest: INITIAL_SECTION_VERSION,
+        }
+    }
+
+    /// Returns the version of a section.
+    #[must_use]
+    pub const fn get(&self, section: SaveSection) -> SchemaVersion {
+        match section {
+            SaveSection::Combat => self.combat,
+            SaveSection::Crafting => self.crafting,
+            SaveSection::Quest => self.quest,
+        }
+    }
+
+    /// Sets the version of a section.
+    pub fn set(&mut self, section: SaveSection, version: SchemaVersion) {
+        match section {
+            SaveSection::Combat => self.combat = version,
+            SaveSection::Crafting => self.crafting = version,
+            SaveSection::Quest => self.quest = version,
+        }
+    }
+}
+
 /// Registry of available migrations.
 pub struct MigrationRegistry {
     /// Migrations keyed by source version.
     migrations: HashMap<u32, Box<dyn Migration + Send + Sync>>,
+    /// Section migrations keyed by section and packed source version.
+    section_migrations: HashMap<(SaveSection, u32), Box<dyn Migration + Send + Sync>>,
 }
 
 impl Default for MigrationRegistry {
@@ -304,6 +435,7 @@ impl MigrationRegistry {
     pub fn new() -> Self {
         Self {
             migrations: HashMap::new(),
+            section_migrations: HashMap::new(),
         }
     }
 
@@ -377,6 +509,113 @@ impl MigrationRegistry {
         Ok(records)
     }
 
+    /// Registers a migration for a single save section.
+    ///
+    /// Source and target versions are packed with [`section_version_key`].
+    pub fn register_section(
+        &mut self,
+        section: SaveSection,
+        migration: Box<dyn Migration + Send + Sync>,
+    ) {
+        let source = migration.source_version();
+        self.section_migrations.insert((section, source), migration);
+    }
+
+    /// Migrates one section's data from `from` towards `to`.
+    ///
+    /// Minor steps without a registered migration are treated as additive and
+    /// pass through to the next version that has one; a missing migration
+    /// across a major version is an error.
+    pub fn migrate_section(
+        &self,
+        section: SaveSection,
+        data: &mut serde_json::Value,
+        from: SchemaVersion,
+        to: SchemaVersion,
+    ) -> VersionResult<Vec<MigrationRecord>> {
+        let target = section_version_key(to);
+        let mut current = section_version_key(from);
+        let mut records = Vec::new();
+
+        while current < target {
+            let Some(migration) = self.section_migrations.get(&(section, current)) else {
+                let next = self
+                    .section_migrations
+                    .keys()
+                    .filter(|&&(s, source)| s == section && source > current && source < target)
+                    .map(|&(_, source)| source)
+                    .min()
+                    .unwrap_or(target);
+                if next >> 16 != current >> 16 {
+                    return Err(VersionError::MigrationFailed {
+                        from: current,
+                        to: next,
+                        reason: format!("no migration for {} section", section.key()),
+                    });
+                }
+                current = next;
+                continue;
+            };
+
+            info!(
+                "Applying {} section migration: {} ({} -> {})",
+                section.key(),
+                migration.description(),
+                section_version_from_key(current),
+                section_version_from_key(migration.target_version())
+            );
+            migration.migrate(data)?;
+
+            records.push(MigrationRecord {
+                from_version: current,
+                to_version: migration.target_version(),
+                timestamp: std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map_or(0, |d| d.as_secs()),
+            });
+            current = migration.target_version();
+        }
+
+        Ok(records)
+    }
+
+    /// Migrates every versioned section of a serialized save to `target`.
+    ///
+    /// Reads the save's `section_versions` (sections missing from it are
+    /// treated as [`INITIAL_SECTION_VERSION`]), migrates each section's subtree
+    /// on its own, and writes the new versions back.
+    pub fn migrate_sections(
+        &self,
+        save: &mut serde_json::Value,
+        target: &SectionVersions,
+    ) -> VersionResult<Vec<(SaveSection, MigrationRecord)>> {
+        let mut versions = save
+            .get("section_versions")
+            .cloned()
+            .and_then(|v| serde_json::from_value::<SectionVersions>(v).ok())
+            .unwrap_or_else(SectionVersions::initial);
+        let mut applied = Vec::new();
+
+        for section in SaveSection::ALL {
+            let from = versions.get(section);
+            let to = target.get(section);
+            if section_version_key(from) >= section_version_key(to) {
+                continue;
+            }
+            if let Some(data) = save.get_mut(section.key()) {
+                let records = self.migrate_section(section, data, from, to)?;
+                applied.extend(records.into_iter().map(|r| (section, r)));
+            }
+            versions.set(section, to);
+        }
+
+        if let (Some(obj), Ok(encoded)) = (save.as_object_mut(), serde_json::to_value(versions)) {
+            obj.insert("section_versions".to_string(), encoded);
+        }
+
+        Ok(applied)
+    }
+
     /// Lists all available migrations.
     #[must_use]
     pub fn list_migrations(&self) -> Vec<(u32, u32, &str)> {
@@ -407,7 +646,7 @@ pub fn validate_version(version: u32) -> VersionResult<VersionCompatibility> {
                     current: CURRENT_SAVE_VERSION,
                 })
             }
-        }
+        },
         _ => Ok(compat),
     }
 }
@@ -570,4 +809,145 @@ mod tests {
         assert_eq!(record.from_version, 1);
         assert_eq!(record.to_version, 2);
     }
+
+    /// Renames `level` to `combat_level` in the combat section.
+    struct RenameCombatLevel;
+
+    impl Migration for RenameCombatLevel {
+        fn source_version(&self) -> u32 {
+            section_version_key(SchemaVersion::new(1, 0, 0))
+        }
+
+        fn target_version(&self) -> u32 {
+            section_version_key(SchemaVersion::new(2, 0, 0))
+        }
+
+        fn migrate(&self, data: &mut serde_json::Value) -> VersionResult<()> {
+            if let Some(obj) = data.as_object_mut() {
+                if let Some(level) = obj.remove("level") {
+                    obj.insert("combat_level".to_string(), level);
+                }
+            }
+            Ok(())
+        }
+
+        fn description(&self) -> &'static str {
+            "Rename combat level field"
+        }
+    }
+
+    #[test]
+    fn test_old_section_migrated_current_section_untouched() {
+        let mut registry = MigrationRegistry::new();
+        registry.register_section(SaveSection::Combat, Box::new(RenameCombatLevel));
+
+        let mut save = serde_json::json!({
+            "section_versions": {
+                "combat": { "major": 1, "minor": 0, "patch": 0 },
+                "crafting": { "major": 1, "minor": 0, "patch": 0 },
+                "quest": { "major": 1, "minor": 0, "patch": 0 }
+            },
+            "combat": { "level": 7 },
+            "crafting": { "level": 3 },
+            "quest": {}
+        });
+        let target = SectionVersions {
+            combat: SchemaVersion::new(2, 0, 0),
+            ..SectionVersions::initial()
+        };
+
+        let applied = registry
+            .migrate_sections(&mut save, &target)
+            .expect("migration should succeed");
+
+        assert_eq!(applied.len(), 1);
+        assert_eq!(applied[0].0, SaveSection::Combat);
+        assert_eq!(save["combat"], serde_json::json!({ "combat_level": 7 }));
+        assert_eq!(save["crafting"], serde_json::json!({ "level": 3 }));
+
+        let versions: SectionVersions =
+            serde_json::from_value(save["section_versions"].clone()).expect("valid versions");
+        assert_eq!(versions, target);
+    }
+
+    #[test]
+    fn test_section_major_bump_without_migration_fails() {
+        let registry = MigrationRegistry::new();
+        let mut data = serde_json::json!({});
+        let result = registry.migrate_section(
+            SaveSection::Quest,
+            &mut data,
+            SchemaVersion::new(1, 0, 0),
+            SchemaVersion::new(2, 0, 0),
+        );
+        assert!(result.is_err());
+
+        // Minor bumps are additive and pass through
+        let records = registry
+            .migrate_section(
+                SaveSection::Quest,
+                &mut data,
+                SchemaVersion::new(1, 0, 0),
+                SchemaVersion::new(1, 2, 0),
+            )
+            .expect("minor bump passes through");
+        assert!(records.is_empty());
+    }
+
+    /// Bumps the quest section from 1.1 to 1.2, adding a `tracked` list.
+    struct AddTrackedQuests;
+
+    impl Migration for AddTrackedQuests {
+        fn source_version(&self) -> u32 {
+            section_version_key(SchemaVersion::new(1, 1, 0))
+        }
+
+        fn target_version(&self) -> u32 {
+            section_version_key(SchemaVersion::new(1, 2, 0))
+        }
+
+        fn migrate(&self, data: &mut serde_json::Value) -> VersionResult<()> {
+            if let Some(obj) = data.as_object_mut() {
+                obj.insert("tracked".to_string(), serde_json::json!([]));
+            }
+            Ok(())
+        }
+
+        fn description(&self) -> &'static str {
+            "Add tracked quest list"
+        }
+    }
+
+    #[test]
+    fn test_section_skips_to_next_registered_migration() {
+        let mut registry = MigrationRegistry::new();
+        registry.register_section(SaveSection::Quest, Box::new(AddTrackedQuests));
+
+        let mut data = serde_json::json!({});
+        let records = registry
+            .migrate_section(
+                SaveSection::Quest,
+                &mut data,
+                SchemaVersion::new(1, 0, 0),
+                SchemaVersion::new(1, 2, 0),
+            )
+            .expect("1.0 -> 1.1 passes through, 1.1 -> 1.2 migrates");
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(
+            records[0].from_version,
+            section_version_key(SchemaVersion::new(1, 1, 0))
+        );
+        assert_eq!(data, serde_json::json!({ "tracked": [] }));
+    }
+
+    #[test]
+    fn test_unversioned_save_assumes_initial_sections() {
+        let registry = MigrationRegistry::new();
+        let mut save = serde_json::json!({ "combat": {}, "crafting": {}, "quest": {} });
+        registry
+            .migrate_sections(&mut save, &SectionVersions::current())
+            .expect("no-op migration");
+        assert!(save.get("section_versions").is_some());
+    }
 }
@@ -0,0 +1,427 @@
+//! Recipe dependency graph and tech tree.
+//!
+//! This module provides:
+//! - Prerequisite edges between recipes (recipe A needs an item made by recipe B)
+//! - A prerequisite-first crafting order and tier per recipe
+//! - Reachability from the starter recipes, for showing locked recipes
+//! - Detection of dependency cycles
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use crate::recipe_loader::{RecipeDefinition, RecipeRegistry, UnlockRequirement};
+
+/// Crafting dependency graph built from a recipe registry.
+#[derive(Debug, Clone, Default)]
+pub struct TechTree {
+    /// Prerequisite recipe IDs for each recipe, sorted.
+    prerequisites: BTreeMap<u32, Vec<u32>>,
+    /// Recipes ordered so prerequisites come first.
+    order: Vec<u32>,
+    /// Depth of each ordered recipe (0 = no prerequisites).
+    tiers: HashMap<u32, u32>,
+    /// Dependency cycles, each sorted by recipe ID.
+    cycles: Vec<Vec<u32>>,
+    /// Recipes craftable starting from the starter recipes.
+    reachable: HashSet<u32>,
+}
+
+impl TechTree {
+    /// Builds the tech tree for all recipes in a registry.
+    ///
+    /// Items no recipe produces are treated as raw materials that are
+    /// always available, and an item made by several recipes is available
+    /// once any one of them is. A recipe is craftable once all its
+    /// ingredients and tools are available and its required recipes are
+    /// craftable; the order, tiers and reachability all follow this rule.
+    /// Starter recipes are reachable from the start.
+    #[must_use]
+    pub fn build(registry: &RecipeRegistry, starter_recipes: &[u32]) -> Self {
+        let mut recipes: Vec<&RecipeDefinition> = registry.iter().collect();
+        recipes.sort_by_key(|r| r.id);
+
+        // Which recipes produce each item
+        let mut producers: HashMap<u32, Vec<u32>> = HashMap::new();
+        for recipe in &recipes {
+            for output in std::iter::once(&recipe.output).chain(&recipe.byproducts) {
+                producers.entry(output.item_id).or_default().push(recipe.id);
+            }
+        }
+
+        let mut prerequisites = BTreeMap::new();
+        for recipe in &recipes {
+            let mut needed = BTreeSet::new();
+            for item_id in input_items(recipe) {
+                if let Some(ids) = producers.get(&item_id) {
+                    needed.extend(ids.iter().copied());
+                }
+            }
+            needed.extend(required_recipes(recipe).filter(|id| registry.get(*id).is_some()));
+            prerequisites.insert(recipe.id, needed.into_iter().collect());
+        }
+
+        let cycles = find_cycles(&prerequisites);
+        let rounds = unlock_rounds(&recipes, &producers, &[]);
+        let tiers = rounds
+            .iter()
+            .zip(0..)
+            .flat_map(|(round, tier)| round.iter().map(move |&id| (id, tier)))
+            .collect();
+        let order = rounds.into_iter().flatten().collect();
+        let reachable = unlock_rounds(&recipes, &producers, starter_recipes)
+            .into_iter()
+            .flatten()
+            .collect();
+
+        Self {
+            prerequisites,
+            order,
+            tiers,
+            cycles,
+            reachable,
+        }
+    }
+
+    /// Returns the number of recipes in the tree.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.prerequisites.len()
+    }
+
+    /// Returns true if the tree has no recipes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.prerequisites.is_empty()
+    }
+
+    /// Returns the recipes that must be crafted before this one.
+    #[must_use]
+    pub fn prerequisites(&self, recipe_id: u32) -> &[u32] {
+        self.prerequisites
+            .get(&recipe_id)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns recipes ordered so each comes after a producer of every item
+    /// it needs and after the recipes it requires.
+    ///
+    /// Recipes that can never be crafted (stuck in a cycle, or needing a
+    /// missing recipe) are left out.
+    #[must_use]
+    pub fn order(&self) -> &[u32] {
+        &self.order
+    }
+
+    /// Returns a recipe's tier (0 = needs only raw materials), if it is
+    /// ordered.
+    #[must_use]
+    pub fn tier(&self, recipe_id: u32) -> Option<u32> {
+        self.tiers.get(&recipe_id).copied()
+    }
+
+    /// Returns the dependency cycles found.
+    #[must_use]
+    pub fn cycles(&self) -> &[Vec<u32>] {
+        &self.cycles
+    }
+
+    /// Returns true if any dependency cycle was found.
+    #[must_use]
+    pub fn has_cycles(&self) -> bool {
+        !self.cycles.is_empty()
+    }
+
+    /// Returns true if the recipe is reachable from the starter recipes.
+    #[must_use]
+    pub fn is_reachable(&self, recipe_id: u32) -> bool {
+        self.reachable.contains(&recipe_id)
+    }
+
+    /// Returns recipes that can't be reached yet, sorted by ID.
+    #[must_use]
+    pub fn locked(&self) -> Vec<u32> {
+        self.prerequisites
+            .keys()
+            .copied()
+            .filter(|id| !self.reachable.contains(id))
+            .collect()
+    }
+
+    /// Returns the prerequisites of a recipe that aren't reachable.
+    #[must_use]
+    pub fn missing_prerequisites(&self, recipe_id: u32) -> Vec<u32> {
+        self.prerequisites(recipe_id)
+            .iter()
+            .copied()
+            .filter(|id| !self.reachable.contains(id))
+            .collect()
+    }
+}
+
+/// Items a recipe consumes or needs as tools.
+fn input_items(recipe: &RecipeDefinition) -> impl Iterator<Item = u32> + '_ {
+    recipe
+        .ingredients
+        .iter()
+        .map(|i| i.item_id)
+        .chain(recipe.tools.iter().copied())
+}
+
+/// Recipes that must be learned before this one.
+fn required_recipes(recipe: &RecipeDefinition) -> impl Iterator<Item = u32> + '_ {
+    recipe
+        .unlock_requirements
+        .iter()
+        .filter_map(|req| match req {
+            UnlockRequirement::Recipe { recipe_id } => Some(*recipe_id),
+            _ => None,
+        })
+}
+
+/// Finds dependency cycles (strongly connected components with a loop).
+fn find_cycles(prerequisites: &BTreeMap<u32, Vec<u32>>) -> Vec<Vec<u32>> {
+    struct Tarjan<'a> {
+        graph: &'a BTreeMap<u32, Vec<u32>>,
+        index: HashMap<u32, usize>,
+        low: HashMap<u32, usize>,
+        stack: Vec<u32>,
+        on_stack: HashSet<u32>,
+        cycles: Vec<Vec<u32>>,
+    }
+
+    impl Tarjan<'_> {
+        fn visit(&mut self, id: u32) {
+            let index = self.index.len();
+            self.index.insert(id, index);
+            self.low.insert(id, index);
+            self.stack.push(id);
+            self.on_stack.insert(id);
+
+            for &next in self.graph.get(&id).into_iter().flatten() {
+                if !self.index.contains_key(&next) {
+                    self.visit(next);
+                    let low = self.low[&id].min(self.low[&next]);
+                    self.low.insert(id, low);
+                } else if self.on_stack.contains(&next) {
+                    let low = self.low[&id].min(self.index[&next]);
+                    self.low.insert(id, low);
+                }
+            }
+
+            if self.low[&id] == self.index[&id] {
+                let mut component = Vec::new();
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack.remove(&member);
+                    component.push(member);
+                    if member == id {
+                        break;
+                    }
+                }
+                let self_loop = self.graph.get(&id).is_some_and(|p| p.contains(&id));
+                if component.len() > 1 || self_loop {
+                    component.sort_unstable();
+                    self.cycles.push(component);
+                }
+            }
+        }
+    }
+
+    let mut tarjan = Tarjan {
+        graph: prerequisites,
+        index: HashMap::new(),
+        low: HashMap::new(),
+        stack: Vec::new(),
+        on_stack: HashSet::new(),
+        cycles: Vec::new(),
+    };
+    for &id in prerequisites.keys() {
+        if !tarjan.index.contains_key(&id) {
+            tarjan.visit(id);
+        }
+    }
+
+    let mut cycles = tarjan.cycles;
+    cycles.sort();
+    cycles
+}
+
+/// Groups recipes by the round in which they become craftable.
+///
+/// A recipe joins the round after one producer of each of its input items
+/// and every recipe it requires have joined; `starters` join round 0
+/// regardless. Rounds are sorted by ID, and recipes that never become
+/// craftable are left out.
+fn unlock_rounds(
+    recipes: &[&RecipeDefinition],
+    producers: &HashMap<u32, Vec<u32>>,
+    starters: &[u32],
+) -> Vec<Vec<u32>> {
+    let mut unlocked = HashSet::new();
+    let mut obtainable = HashSet::new();
+    let mut rounds = Vec::new();
+
+    loop {
+        let round: Vec<&RecipeDefinition> = recipes
+            .iter()
+            .copied()
+            .filter(|recipe| !unlocked.contains(&recipe.id))
+            .filter(|recipe| {
+                (rounds.is_empty() && starters.contains(&recipe.id))
+                    || (input_items(recipe)
+                        .all(|item| !producers.contains_key(&item) || obtainable.contains(&item))
+                        && required_recipes(recipe).all(|id| unlocked.contains(&id)))
+            })
+            .collect();
+        if round.is_empty() {
+            return rounds;
+        }
+
+        for recipe in &round {
+            unlocked.insert(recipe.id);
+            obtainable.insert(recipe.output.item_id);
+            obtainable.extend(recipe.byproducts.iter().map(|b| b.item_id));
+        }
+        rounds.push(round.iter().map(|recipe| recipe.id).collect());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recipe_loader::{RecipeIngredient, RecipeOutput};
+
+    fn recipe(id: u32, ingredients: &[u32], output: u32) -> RecipeDefinition {
+        RecipeDefinition {
+            id,
+            name: format!("Recipe {id}"),
+            description: String::new(),
+            category: "test".to_string(),
+            ingredients: ingredients
+                .iter()
+                .map(|&item_id| RecipeIngredient {
+                    item_id,
+                    quantity: 1,
+                })
+                .collect(),
+            tools: vec![],
+            workstation: None,
+            skill_required: 0,
+            skill_type: "crafting".to_string(),
+            output: RecipeOutput {
+                item_id: output,
+                quantity: 1,
+                quality_variance: 0.0,
+            },
+            byproducts: vec![],
+            craft_time_ticks: 60,
+            skill_gain: 0,
+            unlock_requirements: vec![],
+            mod_id: None,
+        }
+    }
+
+    fn registry(recipes: Vec<RecipeDefinition>) -> RecipeRegistry {
+        let mut registry = RecipeRegistry::new();
+        for recipe in recipes {
+            registry.register(recipe).expect("should register");
+        }
+        registry
+    }
+
+    #[test]
+    fn test_prerequisite_ordering() {
+        // Wood (item 1) and ore (item 2) are raw materials
+        let mut sword = recipe(4, &[11, 10], 12);
+        sword.tools = vec![13];
+        // Planks (1) and ingots (2) feed a hammer (3); the sword (4)
+        // needs all three
+        let registry = registry(vec![
+            recipe(1, &[1], 10),
+            recipe(2, &[2], 11),
+            recipe(3, &[11, 10], 13),
+            sword,
+        ]);
+
+        let tree = TechTree::build(&registry, &[1]);
+        assert!(!tree.has_cycles());
+        assert_eq!(tree.prerequisites(4), &[1, 2, 3]);
+        assert_eq!(tree.prerequisites(3), &[1, 2]);
+        assert_eq!(tree.order(), &[1, 2, 3, 4]);
+        assert_eq!(tree.tier(1), Some(0));
+        assert_eq!(tree.tier(3), Some(1));
+        assert_eq!(tree.tier(4), Some(2));
+        assert!(tree.locked().is_empty());
+    }
+
+    #[test]
+    fn test_reachability_from_starters() {
+        let mut gated = recipe(3, &[1], 12);
+        gated.unlock_requirements = vec![UnlockRequirement::Recipe { recipe_id: 2 }];
+        let registry = registry(vec![
+            recipe(1, &[1], 10),
+            // Needs its own output, so only starters can craft it
+            recipe(2, &[11], 11),
+            gated,
+        ]);
+
+        let tree = TechTree::build(&registry, &[1]);
+        assert!(tree.is_reachable(1));
+        assert!(!tree.is_reachable(2));
+        assert!(!tree.is_reachable(3));
+        assert_eq!(tree.locked(), vec![2, 3]);
+        assert_eq!(tree.missing_prerequisites(3), vec![2]);
+
+        let tree = TechTree::build(&registry, &[1, 2]);
+        assert!(tree.is_reachable(3));
+        assert!(tree.locked().is_empty());
+    }
+
+    #[test]
+    fn test_cycle_detection() {
+        let registry = registry(vec![
+            recipe(1, &[1], 10),
+            // 2 and 3 need each other's output
+            recipe(2, &[10, 12], 11),
+            recipe(3, &[11], 12),
+            // Depends on the cycle but isn't part of it
+            recipe(4, &[12], 13),
+            // Consumes its own output
+            recipe(5, &[14], 14),
+        ]);
+
+        let tree = TechTree::build(&registry, &[]);
+        assert_eq!(tree.cycles(), &[vec![2, 3], vec![5]]);
+
+        // Cycle members and their dependents are left out of the order
+        assert_eq!(tree.order(), &[1]);
+        assert_eq!(tree.tier(4), None);
+        assert_eq!(tree.locked(), vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_any_producer_satisfies_an_item() {
+        let registry = registry(vec![
+            // Item 10 comes from raw material (1) or from item 11 (2)
+            recipe(1, &[1], 10),
+            recipe(2, &[11], 10),
+            recipe(3, &[10], 12),
+            // Item 11 needs item 10, so 2 only unlocks after 1
+            recipe(4, &[10], 11),
+        ]);
+
+        let tree = TechTree::build(&registry, &[]);
+        assert_eq!(tree.prerequisites(3), &[1, 2]);
+        assert_eq!(tree.order(), &[1, 3, 4, 2]);
+        assert_eq!(tree.tier(3), Some(1));
+        assert_eq!(tree.tier(2), Some(2));
+
+        // Ordering and reachability agree on what can be crafted
+        for id in 1..=4 {
+            assert_eq!(
+                tree.is_reachable(id),
+                tree.tier(id).is_some(),
+                "recipe {id}"
+            );
+        }
+        assert!(tree.missing_prerequisites(3).is_empty());
+    }
+}
@@ -11,6 +11,7 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
+use genesis_tools::ui::{WeaponDamageType as UiDamageType, WeaponStats};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tracing::{debug, info, warn};
@@ -40,6 +41,49 @@ pub enum WeaponLoadError {
     /// Duplicate weapon ID.
     #[error("Duplicate weapon ID: {0}")]
     DuplicateId(u32),
+
+    /// A weapon is missing a required field.
+    #[error("Weapon {} is missing required field `{field}`", weapon_label(*weapon_id))]
+    MissingField {
+        /// Weapon ID, if it could be read.
+        weapon_id: Option<u32>,
+        /// Name of the missing field.
+        field: &'static str,
+    },
+
+    /// A weapon field is outside its valid range.
+    #[error("Weapon {weapon_id} has out-of-range {field}: {value}")]
+    OutOfRange {
+        /// Weapon ID.
+        weapon_id: u32,
+        /// Name of the offending field.
+        field: String,
+        /// The rejected value.
+        value: f32,
+    },
+
+    /// A weapon names a category that doesn't exist.
+    #[error("Weapon {} has unknown category: {category}", weapon_label(*weapon_id))]
+    UnknownCategory {
+        /// Weapon ID, if it could be read.
+        weapon_id: Option<u32>,
+        /// The unrecognized category value.
+        category: String,
+    },
+
+    /// A weapon entry couldn't be deserialized.
+    #[error("Weapon {} is invalid: {message}", weapon_label(*weapon_id))]
+    InvalidWeapon {
+        /// Weapon ID, if it could be read.
+        weapon_id: Option<u32>,
+        /// Deserialization error message.
+        message: String,
+    },
+}
+
+/// Formats a possibly unknown weapon ID for error messages.
+fn weapon_label(weapon_id: Option<u32>) -> String {
+    weapon_id.map_or_else(|| "<unknown id>".to_string(), |id| id.to_string())
 }
 
 /// Result type for weapon loading operations.
@@ -322,7 +366,41 @@ fn default_rarity() -> String {
     "common".to_string()
 }
 
+/// Fields every weapon entry must define.
+const REQUIRED_WEAPON_FIELDS: [&str; 3] = ["id", "name", "base_damage"];
+
 impl WeaponDefinition {
+    /// Parses one `[[weapons]]` entry, reporting which field is wrong.
+    pub fn from_toml(value: &toml::Value) -> WeaponLoadResult<Self> {
+        let weapon_id = value
+            .get("id")
+            .and_then(toml::Value::as_integer)
+            .and_then(|id| u32::try_from(id).ok());
+
+        for field in REQUIRED_WEAPON_FIELDS {
+            if value.get(field).is_none() {
+                return Err(WeaponLoadError::MissingField { weapon_id, field });
+            }
+        }
+
+        if let Some(category) = value.get("category") {
+            if WeaponCategory::deserialize(category.clone()).is_err() {
+                return Err(WeaponLoadError::UnknownCategory {
+                    weapon_id,
+                    category: category.to_string(),
+                });
+            }
+        }
+
+        value
+            .clone()
+            .try_into()
+            .map_err(|e: toml::de::Error| WeaponLoadError::InvalidWeapon {
+                weapon_id,
+                message: e.to_string(),
+            })
+    }
+
     /// Validates the weapon definition.
     pub fn validate(&self) -> WeaponLoadResult<()> {
         if self.name.is_empty() {
@@ -332,40 +410,34 @@ impl WeaponDefinition {
             )));
         }
 
+        let out_of_range = |field: &str, value: f32| WeaponLoadError::OutOfRange {
+            weapon_id: self.id,
+            field: field.to_string(),
+            value,
+        };
+
         if self.base_damage < 0.0 {
-            return Err(WeaponLoadError::ValidationError(format!(
-                "Weapon {} has negative base damage: {}",
-                self.id, self.base_damage
-            )));
+            return Err(out_of_range("base_damage", self.base_damage));
         }
 
         if self.attack_speed <= 0.0 {
-            return Err(WeaponLoadError::ValidationError(format!(
-                "Weapon {} has invalid attack speed: {}",
-                self.id, self.attack_speed
-            )));
+            return Err(out_of_range("attack_speed", self.attack_speed));
         }
 
-        if self.crit_chance < 0.0 || self.crit_chance > 1.0 {
-            return Err(WeaponLoadError::ValidationError(format!(
-                "Weapon {} has invalid crit_chance: {} (must be 0.0-1.0)",
-                self.id, self.crit_chance
-            )));
+        if !(0.0..=1.0).contains(&self.crit_chance) {
+            return Err(out_of_range("crit_chance", self.crit_chance));
         }
 
         if self.crit_multiplier < 1.0 {
-            return Err(WeaponLoadError::ValidationError(format!(
-                "Weapon {} has invalid crit_multiplier: {} (must be >= 1.0)",
-                self.id, self.crit_multiplier
-            )));
+            return Err(out_of_range("crit_multiplier", self.crit_multiplier));
         }
 
         for (i, effect) in self.status_effects.iter().enumerate() {
-            if effect.chance < 0.0 || effect.chance > 1.0 {
-                return Err(WeaponLoadError::ValidationError(format!(
-                    "Weapon {} status effect {} has invalid chance: {}",
-                    self.id, i, effect.chance
-                )));
+            if !(0.0..=1.0).contains(&effect.chance) {
+                return Err(out_of_range(
+                    &format!("status_effects[{i}].chance"),
+                    effect.chance,
+                ));
             }
         }
 
@@ -395,6 +467,29 @@ impl WeaponDefinition {
     pub fn roll_critical(&self, roll: f32, bonus: f32) -> bool {
         roll < (self.crit_chance + bonus)
     }
+
+    /// Converts to equipment UI stats for tooltips and comparisons.
+    #[must_use]
+    pub fn to_weapon_stats(&self) -> WeaponStats {
+        let variance = self.damage_variance * self.base_damage;
+        WeaponStats {
+            min_damage: self.base_damage - variance,
+            max_damage: self.base_damage + variance,
+            attack_speed: self.attack_speed,
+            crit_chance: self.crit_chance,
+            crit_multiplier: self.crit_multiplier,
+            damage_type: match self.damage_type {
+                WeaponDamageType::Physical => UiDamageType::Physical,
+                WeaponDamageType::Fire => UiDamageType::Fire,
+                WeaponDamageType::Ice => UiDamageType::Ice,
+                WeaponDamageType::Electric => UiDamageType::Lightning,
+                WeaponDamageType::Poison => UiDamageType::Poison,
+            },
+            armor_pen: self.armor_penetration,
+            range: self.reach,
+            stamina_cost: self.stamina_cost,
+        }
+    }
 }
 
 /// A collection of weapons from a single file.
@@ -608,41 +703,52 @@ impl WeaponLoader {
     }
 
     /// Loads all weapons from the base path.
-    pub fn load_all(&mut self) -> WeaponLoadResult<usize> {
+    ///
+    /// Invalid weapons are skipped so the valid ones still load. Each
+    /// problem is logged and returned as a warning.
+    pub fn load_all(&mut self) -> Vec<WeaponLoadError> {
+        let mut warnings = Vec::new();
         let path = &self.base_path;
         if !path.exists() {
             info!("Weapon path does not exist, creating: {:?}", path);
-            fs::create_dir_all(path)?;
-            return Ok(0);
+            if let Err(e) = fs::create_dir_all(path) {
+                warnings.push(e.into());
+            }
+            return warnings;
         }
 
-        let mut count = 0;
-
-        for entry in fs::read_dir(path)? {
-            let entry = entry?;
-            let file_path = entry.path();
-
-            if file_path.extension().is_some_and(|ext| ext == "toml") {
-                match self.load_file(&file_path) {
-                    Ok(n) => {
-                        count += n;
-                        debug!("Loaded {} weapons from {:?}", n, file_path);
-                    },
-                    Err(e) => {
-                        warn!("Failed to load weapon file {:?}: {}", file_path, e);
-                    },
-                }
+        let entries = match fs::read_dir(path) {
+            Ok(entries) => entries,
+            Err(e) => return vec![e.into()],
+        };
+        let mut files = Vec::new();
+        for entry in entries {
+            match entry {
+                Ok(entry) => files.push(entry.path()),
+                Err(e) => warnings.push(e.into()),
+            }
+        }
+        files.retain(|file| file.extension().is_some_and(|ext| ext == "toml"));
+        files.sort();
+
+        let before = self.registry.len();
+        for file_path in files {
+            for warning in self.load_file(&file_path) {
+                warn!("Failed to load weapon from {:?}: {}", file_path, warning);
+                warnings.push(warning);
             }
         }
 
-        info!("Loaded {} weapons total", count);
-        Ok(count)
+        info!("Loaded {} weapons total", self.registry.len() - before);
+        warnings
     }
 
-    /// Loads weapons from a single file.
-    pub fn load_file(&mut self, path: &Path) -> WeaponLoadResult<usize> {
-        let content = fs::read_to_string(path)?;
-        let weapon_file: WeaponFile = toml::from_str(&content)?;
+    /// Loads weapons from a single file, skipping invalid entries.
+    pub fn load_file(&mut self, path: &Path) -> Vec<WeaponLoadError> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) => return vec![e.into()],
+        };
 
         // Track file modification time
         if let Ok(metadata) = fs::metadata(path) {
@@ -651,20 +757,47 @@ impl WeaponLoader {
             }
         }
 
-        let mut count = 0;
-        for weapon in weapon_file.weapons {
-            weapon.validate()?;
-            self.registry.register(weapon)?;
-            count += 1;
-        }
+        let before = self.registry.len();
+        let warnings = self.load_str(&content);
+        debug!(
+            "Loaded {} weapons from {:?}",
+            self.registry.len() - before,
+            path
+        );
+        warnings
+    }
 
-        Ok(count)
+    /// Loads weapons from TOML source, skipping invalid entries.
+    pub fn load_str(&mut self, content: &str) -> Vec<WeaponLoadError> {
+        let file: toml::Table = match toml::from_str(content) {
+            Ok(file) => file,
+            Err(e) => return vec![e.into()],
+        };
+        let Some(weapons) = file.get("weapons").and_then(toml::Value::as_array) else {
+            return vec![WeaponLoadError::ValidationError(
+                "Weapon file has no [[weapons]] entries".to_string(),
+            )];
+        };
+
+        let mut warnings = Vec::new();
+        for value in weapons {
+            let result = WeaponDefinition::from_toml(value).and_then(|weapon| {
+                weapon.validate()?;
+                self.registry.register(weapon)
+            });
+            if let Err(e) = result {
+                warnings.push(e);
+            }
+        }
+        warnings
     }
 
     /// Checks for file changes and reloads if necessary.
-    pub fn check_hot_reload(&mut self) -> WeaponLoadResult<bool> {
+    ///
+    /// Returns true if weapons were reloaded.
+    pub fn check_hot_reload(&mut self) -> bool {
         if !self.hot_reload_enabled {
-            return Ok(false);
+            return false;
         }
 
         let mut needs_reload = false;
@@ -684,15 +817,15 @@ impl WeaponLoader {
             info!("Weapon files changed, reloading...");
             self.registry.clear();
             self.file_times.clear();
-            self.load_all()?;
-            return Ok(true);
+            self.load_all();
+            return true;
         }
 
-        Ok(false)
+        false
     }
 
-    /// Reloads all weapons.
-    pub fn reload(&mut self) -> WeaponLoadResult<usize> {
+    /// Reloads all weapons, returning any warnings.
+    pub fn reload(&mut self) -> Vec<WeaponLoadError> {
         self.registry.clear();
         self.file_times.clear();
         self.load_all()
@@ -823,7 +956,7 @@ mod tests {
 
         assert!(matches!(
             weapon.validate(),
-            Err(WeaponLoadError::ValidationError(_))
+            Err(WeaponLoadError::OutOfRange { ref field, .. }) if field == "crit_chance"
         ));
     }
 
@@ -1052,6 +1185,12 @@ mod tests {
 
         // Roll 1.0 = maximum damage
         assert!((weapon.calculate_damage(1.0) - 110.0).abs() < 0.01);
+
+        // UI stats span the same damage range
+        let stats = weapon.to_weapon_stats();
+        assert!((stats.min_damage - 90.0).abs() < 0.01);
+        assert!((stats.max_damage - 110.0).abs() < 0.01);
+        assert!((stats.stamina_cost - 10.0).abs() < 0.01);
     }
 
     #[test]
@@ -1134,4 +1273,112 @@ mod tests {
             Err(WeaponLoadError::DuplicateId(1))
         ));
     }
+
+    fn write_weapons(dir: &Path, file: &str, content: &str) {
+        fs::write(dir.join(file), content).expect("write weapon file");
+    }
+
+    #[test]
+    fn test_load_all_skips_invalid_weapons() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_weapons(
+            dir.path(),
+            "mixed.toml",
+            r#"
+            [[weapons]]
+            id = 1
+            name = "Iron Sword"
+            category = "sword"
+            base_damage = 10.0
+
+            [[weapons]]
+            id = 2
+            category = "axe"
+            base_damage = 12.0
+
+            [[weapons]]
+            id = 3
+            name = "Cursed Blade"
+            base_damage = -5.0
+
+            [[weapons]]
+            id = 4
+            name = "Laser Whip"
+            category = "whip"
+            base_damage = 8.0
+
+            [[weapons]]
+            id = 5
+            name = "Oak Bow"
+            category = "bow"
+            base_damage = 7.0
+            "#,
+        );
+        write_weapons(dir.path(), "broken.toml", "[[weapons]\nid = ");
+
+        let mut loader = WeaponLoader::new(dir.path());
+        let warnings = loader.load_all();
+
+        // Valid weapons still load
+        assert_eq!(loader.registry().len(), 2);
+        assert!(loader.registry().get(1).is_some());
+        assert!(loader.registry().get(5).is_some());
+
+        assert_eq!(warnings.len(), 4);
+        assert!(matches!(warnings[0], WeaponLoadError::ParseError(_)));
+        assert!(warnings.iter().any(|w| matches!(
+            w,
+            WeaponLoadError::MissingField { weapon_id: Some(2), field: "name" }
+        )));
+        assert!(warnings.iter().any(|w| matches!(
+            w,
+            WeaponLoadError::OutOfRange { weapon_id: 3, field, .. } if field == "base_damage"
+        )));
+        assert!(warnings.iter().any(|w| matches!(
+            w,
+            WeaponLoadError::UnknownCategory { weapon_id: Some(4), category } if category.contains("whip")
+        )));
+    }
+
+    #[test]
+    fn test_load_str_reports_field_errors() {
+        let mut loader = WeaponLoader::new("unused");
+        let warnings = loader.load_str(
+            r#"
+            [[weapons]]
+            name = "Nameless"
+            base_damage = 3.0
+
+            [[weapons]]
+            id = 7
+            name = "Feather"
+            base_damage = "heavy"
+
+            [[weapons]]
+            id = 8
+            name = "Glass Dagger"
+            category = "dagger"
+            base_damage = 4.0
+            crit_chance = 2.0
+            "#,
+        );
+
+        assert!(loader.registry().is_empty());
+        assert!(matches!(
+            warnings[0],
+            WeaponLoadError::MissingField { weapon_id: None, field: "id" }
+        ));
+        assert!(matches!(
+            warnings[1],
+            WeaponLoadError::InvalidWeapon { weapon_id: Some(7), .. }
+        ));
+        assert!(matches!(
+            &warnings[2],
+            WeaponLoadError::OutOfRange { weapon_id: 8, field, .. } if field == "crit_chance"
+        ));
+        assert_eq!(
+            warnings[0].to_string(),
+            "Weapon <unknown id> is missing required field `id`"
+        );
+    }
 }
@@ -5,9 +5,11 @@
 //! - Multi-layer mixing with smooth transitions
 //! - Weather sound integration
 //! - Distance-based volume falloff
+//! - Biome-group ambience that cross-fades as the player moves
 
 use crate::biome::BiomeType;
 use crate::weather::WeatherState;
+use genesis_common::Biome;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -129,6 +131,13 @@ impl AmbientSound {
         }
     }
 
+    /// Get the asset name (file stem) used by the audio backend.
+    #[must_use]
+    pub fn asset_name(self) -> &'static str {
+        let file = self.asset_path().rsplit('/').next().unwrap_or_default();
+        file.strip_suffix(".ogg").unwrap_or(file)
+    }
+
     /// Get default volume for this sound.
     #[must_use]
     pub fn default_volume(self) -> f32 {
@@ -701,6 +710,225 @@ pub struct AmbientPlaybackInfo {
     pub loops: bool,
 }
 
+/// Broad biome family sharing one ambient bed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AmbientBiomeGroup {
+    /// Temperate forests.
+    Forest,
+    /// Jungles and bamboo jungles.
+    Jungle,
+    /// Deserts, savannas and badlands.
+    Desert,
+    /// Open grassland.
+    Plains,
+    /// Oceans of every temperature.
+    Ocean,
+    /// Rivers.
+    River,
+    /// Swamps and mangroves.
+    Swamp,
+    /// Mountains and peaks.
+    Mountain,
+    /// Snow-covered biomes.
+    Snowy,
+}
+
+impl AmbientBiomeGroup {
+    /// Get the group a generated world biome belongs to.
+    #[must_use]
+    pub fn from_biome(biome: Biome) -> Self {
+        match biome {
+            _ if biome.is_oceanic() => Self::Ocean,
+            Biome::RIVER | Biome::FROZEN_RIVER => Self::River,
+            Biome::JUNGLE
+            | Biome::JUNGLE_HILLS
+            | Biome::JUNGLE_EDGE
+            | Biome::MODIFIED_JUNGLE
+            | Biome::MODIFIED_JUNGLE_EDGE
+            | Biome::BAMBOO_JUNGLE
+            | Biome::BAMBOO_JUNGLE_HILLS => Self::Jungle,
+            _ if biome.is_snowy() => Self::Snowy,
+            _ => match BiomeType::from_world_biome(biome) {
+                BiomeType::Forest => Self::Forest,
+                BiomeType::Desert => Self::Desert,
+                BiomeType::Lake => Self::River,
+                BiomeType::Plains => Self::Plains,
+                BiomeType::Mountain => Self::Mountain,
+                BiomeType::Swamp => Self::Swamp,
+            },
+        }
+    }
+}
+
+/// Ambient layer played for a biome group.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BiomeAmbientLayer {
+    /// Asset name passed to the audio backend.
+    pub name: String,
+    /// Target volume (0.0-1.0).
+    pub volume: f32,
+}
+
+impl BiomeAmbientLayer {
+    /// Create a layer for an ambient sound at its default volume.
+    #[must_use]
+    pub fn from_sound(sound: AmbientSound) -> Self {
+        Self {
+            name: sound.asset_name().to_string(),
+            volume: sound.default_volume(),
+        }
+    }
+}
+
+/// A cross-fade between two biome ambient layers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AmbientCrossfade {
+    /// Layer to fade out (`None` if nothing was playing).
+    pub from: Option<BiomeAmbientLayer>,
+    /// Layer to fade in (`None` if the new group is silent).
+    pub to: Option<BiomeAmbientLayer>,
+    /// Fade duration in seconds.
+    pub duration: f32,
+}
+
+/// Picks the biome ambience for the player's current biome.
+///
+/// Feed it the biome under the player every frame; when the player crosses
+/// into a different biome group it schedules a cross-fade to that group's
+/// layer. Moving between biomes of the same group changes nothing.
+#[derive(Debug, Clone)]
+pub struct AmbientDirector {
+    /// Layer per biome group; unmapped groups are silent.
+    layers: HashMap<AmbientBiomeGroup, BiomeAmbientLayer>,
+    /// Cross-fade duration in seconds.
+    crossfade_duration: f32,
+    /// Group the player is currently in.
+    current_group: Option<AmbientBiomeGroup>,
+    /// Time since the last cross-fade started.
+    fade_elapsed: f32,
+    /// Cross-fades not yet handed to the audio backend.
+    pending: Vec<AmbientCrossfade>,
+}
+
+impl AmbientDirector {
+    /// Create a director with the default biome group layers.
+    #[must_use]
+    pub fn new() -> Self {
+        let mut layers = HashMap::new();
+        for (group, sound) in [
+            (AmbientBiomeGroup::Forest, AmbientSound::Leaves),
+            (AmbientBiomeGroup::Jungle, AmbientSound::Cicadas),
+            (AmbientBiomeGroup::Desert, AmbientSound::SandWind),
+            (AmbientBiomeGroup::Ocean, AmbientSound::Ocean),
+            (AmbientBiomeGroup::River, AmbientSound::River),
+            (AmbientBiomeGroup::Swamp, AmbientSound::Frogs),
+            (AmbientBiomeGroup::Mountain, AmbientSound::WindStrong),
+            (AmbientBiomeGroup::Snowy, AmbientSound::SnowWind),
+        ] {
+            layers.insert(group, BiomeAmbientLayer::from_sound(sound));
+        }
+
+        Self {
+            layers,
+            crossfade_duration: 4.0,
+            current_group: None,
+            fade_elapsed: 0.0,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Set the cross-fade duration in seconds.
+    #[must_use]
+    pub fn with_crossfade_duration(mut self, seconds: f32) -> Self {
+        self.crossfade_duration = seconds.max(0.0);
+        self
+    }
+
+    /// Map a biome group to a layer, replacing any previous mapping.
+    pub fn set_layer(&mut self, group: AmbientBiomeGroup, layer: BiomeAmbientLayer) {
+        self.layers.insert(group, layer);
+    }
+
+    /// Make a biome group silent.
+    pub fn clear_layer(&mut self, group: AmbientBiomeGroup) {
+        self.layers.remove(&group);
+    }
+
+    /// Get the layer mapped to a biome group.
+    #[must_use]
+    pub fn layer(&self, group: AmbientBiomeGroup) -> Option<&BiomeAmbientLayer> {
+        self.layers.get(&group)
+    }
+
+    /// Get the group the player is currently in.
+    #[must_use]
+    pub fn current_group(&self) -> Option<AmbientBiomeGroup> {
+        self.current_group
+    }
+
+    /// Get the layer that is playing (or fading in).
+    #[must_use]
+    pub fn current_layer(&self) -> Option<&BiomeAmbientLayer> {
+        self.current_group.and_then(|group| self.layers.get(&group))
+    }
+
+    /// Update with the biome ID under the player.
+    ///
+    /// Unknown biome IDs are ignored so a failed lookup does not fade the
+    /// ambience out.
+    pub fn update(&mut self, biome_id: i32, dt: f32) {
+        self.fade_elapsed = (self.fade_elapsed + dt).min(self.crossfade_duration);
+
+        let biome = Biome::from_id(biome_id);
+        if !biome.is_known() {
+            return;
+        }
+        let group = AmbientBiomeGroup::from_biome(biome);
+        if self.current_group == Some(group) {
+            return;
+        }
+
+        let from = self.current_layer().cloned();
+        let to = self.layers.get(&group).cloned();
+        self.current_group = Some(group);
+        if from != to {
+            self.pending.push(AmbientCrossfade {
+                from,
+                to,
+                duration: self.crossfade_duration,
+            });
+            self.fade_elapsed = 0.0;
+        }
+    }
+
+    /// Whether a cross-fade is still in progress.
+    #[must_use]
+    pub fn is_crossfading(&self) -> bool {
+        self.fade_elapsed < self.crossfade_duration
+    }
+
+    /// Progress of the latest cross-fade (0.0-1.0).
+    #[must_use]
+    pub fn crossfade_progress(&self) -> f32 {
+        if self.crossfade_duration <= 0.0 {
+            1.0
+        } else {
+            self.fade_elapsed / self.crossfade_duration
+        }
+    }
+
+    /// Take the cross-fades scheduled since the last call.
+    pub fn take_crossfades(&mut self) -> Vec<AmbientCrossfade> {
+        std::mem::take(&mut self.pending)
+    }
+}
+
+impl Default for AmbientDirector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -866,4 +1094,88 @@ mod tests {
         assert_eq!(info.sound, AmbientSound::Birds);
         assert!(info.loops);
     }
+
+    #[test]
+    fn test_ambient_sound_asset_name() {
+        assert_eq!(AmbientSound::SandWind.asset_name(), "sand_wind");
+        assert_eq!(AmbientSound::Ocean.asset_name(), "ocean");
+    }
+
+    #[test]
+    fn test_biome_groups() {
+        assert_eq!(
+            AmbientBiomeGroup::from_biome(Biome::BAMBOO_JUNGLE),
+            AmbientBiomeGroup::Jungle
+        );
+        assert_eq!(
+            AmbientBiomeGroup::from_biome(Biome::DEEP_COLD_OCEAN),
+            AmbientBiomeGroup::Ocean
+        );
+        assert_eq!(
+            AmbientBiomeGroup::from_biome(Biome::DESERT_HILLS),
+            AmbientBiomeGroup::Desert
+        );
+        assert_eq!(
+            AmbientBiomeGroup::from_biome(Biome::ICE_SPIKES),
+            AmbientBiomeGroup::Snowy
+        );
+        assert_eq!(
+            AmbientBiomeGroup::from_biome(Biome::BIRCH_FOREST),
+            AmbientBiomeGroup::Forest
+        );
+    }
+
+    #[test]
+    fn test_director_crossfades_on_new_biome_group() {
+        let mut director = AmbientDirector::new().with_crossfade_duration(2.0);
+
+        director.update(Biome::FOREST.id(), 0.1);
+        let fades = director.take_crossfades();
+        assert_eq!(fades.len(), 1);
+        assert_eq!(fades[0].from, None);
+        assert_eq!(
+            fades[0].to.as_ref().map(|l| l.name.as_str()),
+            Some("leaves")
+        );
+
+        // Same group: no new fade
+        director.update(Biome::DARK_FOREST.id(), 0.1);
+        assert!(director.take_crossfades().is_empty());
+
+        director.update(Biome::JUNGLE.id(), 0.1);
+        let fades = director.take_crossfades();
+        assert_eq!(fades.len(), 1);
+        assert_eq!(
+            fades[0].from.as_ref().map(|l| l.name.as_str()),
+            Some("leaves")
+        );
+        assert_eq!(
+            fades[0].to.as_ref().map(|l| l.name.as_str()),
+            Some("cicadas")
+        );
+        assert!((fades[0].duration - 2.0).abs() < f32::EPSILON);
+        assert_eq!(director.current_group(), Some(AmbientBiomeGroup::Jungle));
+        assert!(director.is_crossfading());
+
+        director.update(Biome::JUNGLE.id(), 2.0);
+        assert!(!director.is_crossfading());
+    }
+
+    #[test]
+    fn test_director_fades_out_into_silent_group() {
+        let mut director = AmbientDirector::new();
+        director.update(Biome::DESERT.id(), 0.0);
+        director.take_crossfades();
+
+        // Plains have no default layer
+        director.update(Biome::PLAINS.id(), 0.0);
+        let fades = director.take_crossfades();
+        assert_eq!(fades.len(), 1);
+        assert_eq!(fades[0].to, None);
+
+        // Unknown IDs keep the current ambience
+        director.update(-1, 0.0);
+        assert!(director.take_crossfades().is_empty());
+        assert_eq!(director.current_group(), Some(AmbientBiomeGroup::Plains));
+    }
 }
@@ -6,7 +6,7 @@
 //! - Resource distribution rules per biome
 //! - Biome-specific cell material variants
 
-use genesis_common::WorldCoord;
+use genesis_common::{Biome, Climate, WorldCoord};
 use serde::{Deserialize, Serialize};
 
 // ============================================================================
@@ -131,6 +131,32 @@ impl BiomeType {
             Self::Swamp => 0.6,
         }
     }
+
+    /// Get the gameplay biome type closest to a generated world biome.
+    #[must_use]
+    pub fn from_world_biome(biome: Biome) -> Self {
+        match biome {
+            _ if biome.is_oceanic() => Self::Lake,
+            Biome::RIVER | Biome::FROZEN_RIVER => Self::Lake,
+            Biome::SWAMP | Biome::SWAMP_HILLS | Biome::MANGROVE_SWAMP => Self::Swamp,
+            Biome::PLAINS | Biome::SUNFLOWER_PLAINS | Biome::MEADOW | Biome::SNOWY_TUNDRA => {
+                Self::Plains
+            },
+            Biome::MOUNTAINS
+            | Biome::MOUNTAIN_EDGE
+            | Biome::WOODED_MOUNTAINS
+            | Biome::GRAVELLY_MOUNTAINS
+            | Biome::MODIFIED_GRAVELLY_MOUNTAINS
+            | Biome::SNOWY_MOUNTAINS
+            | Biome::SNOWY_SLOPES
+            | Biome::JAGGED_PEAKS
+            | Biome::FROZEN_PEAKS
+            | Biome::STONY_PEAKS
+            | Biome::STONE_SHORE => Self::Mountain,
+            _ if biome.climate() == Some(Climate::Warm) => Self::Desert,
+            _ => Self::Forest,
+        }
+    }
 }
 
 /// Environmental properties of a biome.
@@ -1131,6 +1157,17 @@ mod tests {
         assert_eq!(BiomeType::Swamp.display_name(), "Swamp");
     }
 
+    #[test]
+    fn test_biome_type_from_world_biome() {
+        assert_eq!(BiomeType::from_world_biome(Biome::DEEP_OCEAN), BiomeType::Lake);
+        assert_eq!(BiomeType::from_world_biome(Biome::RIVER), BiomeType::Lake);
+        assert_eq!(BiomeType::from_world_biome(Biome::MANGROVE_SWAMP), BiomeType::Swamp);
+        assert_eq!(BiomeType::from_world_biome(Biome::MEADOW), BiomeType::Plains);
+        assert_eq!(BiomeType::from_world_biome(Biome::STONY_PEAKS), BiomeType::Mountain);
+        assert_eq!(BiomeType::from_world_biome(Biome::BADLANDS), BiomeType::Desert);
+        assert_eq!(BiomeType::from_world_biome(Biome::DARK_FOREST), BiomeType::Forest);
+    }
+
     #[test]
     fn test_biome_type_all() {
         let all = BiomeType::all();
@@ -1,10 +1,15 @@
 //! Collision response system for smooth movement.
 //!
 //! This module provides collision response handling for player movement,
-//! including sliding along walls and terrain detection.
+//! including sliding along walls and terrain detection, separation of
+//! overlapping NPCs after they move, and non-solid trigger zones that report
+//! entities entering and leaving them.
 
+use crate::entity::{Entity, EntityType};
 use crate::physics::{CollisionQuery, AABB};
+use genesis_common::{EntityId, WorldCoord};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 
 /// Collision response behavior.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -487,6 +492,271 @@ pub fn find_nearest_valid_position<C: CollisionQuery>(
     None
 }
 
+/// Entity-vs-entity separation applied after movement.
+///
+/// Each active NPC occupies a square of `entity_size` cells anchored at its
+/// position. Overlapping NPCs are pushed apart along the axis of least
+/// penetration, each taking half of the push.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct CollisionResponse {
+    /// Side length of an entity's AABB in cells
+    pub entity_size: i64,
+    /// Maximum number of separation passes per call
+    pub max_iterations: u32,
+}
+
+impl Default for CollisionResponse {
+    fn default() -> Self {
+        Self {
+            entity_size: 12,
+            max_iterations: 4,
+        }
+    }
+}
+
+impl CollisionResponse {
+    /// Creates a collision response with default settings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pushes overlapping NPCs apart.
+    ///
+    /// Neighbors are found through a uniform grid with buckets one entity
+    /// wide, so each entity is only compared against the adjacent buckets.
+    /// Inactive entities and non-NPCs are left alone. Returns the number of
+    /// pushes applied; 0 means nothing overlapped.
+    pub fn separate_entities(&self, entities: &mut [Entity]) -> usize {
+        let size = self.entity_size.max(1);
+        let bucket = |pos: WorldCoord| (pos.x.div_euclid(size), pos.y.div_euclid(size));
+        let mut pushes = 0;
+
+        for _ in 0..self.max_iterations {
+            let mut grid: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+            for (i, entity) in entities.iter().enumerate() {
+                if Self::participates(entity) {
+                    grid.entry(bucket(entity.position())).or_default().push(i);
+                }
+            }
+
+            let mut pass_pushes = 0;
+            for i in 0..entities.len() {
+                if !Self::participates(&entities[i]) {
+                    continue;
+                }
+                let (cx, cy) = bucket(entities[i].position());
+                for dy in -1..=1 {
+                    for dx in -1..=1 {
+                        let Some(neighbors) = grid.get(&(cx + dx, cy + dy)) else {
+                            continue;
+                        };
+                        for &j in neighbors {
+                            if j > i && Self::push_apart(entities, i, j, size) {
+                                pass_pushes += 1;
+                            }
+                        }
+                    }
+                }
+            }
+
+            pushes += pass_pushes;
+            if pass_pushes == 0 {
+                break;
+            }
+        }
+        pushes
+    }
+
+    /// Whether an entity takes part in separation.
+    fn participates(entity: &Entity) -> bool {
+        entity.is_active() && entity.entity_type() == EntityType::Npc
+    }
+
+    /// Separates two entities if their AABBs overlap.
+    fn push_apart(entities: &mut [Entity], a: usize, b: usize, size: i64) -> bool {
+        let pa = entities[a].position();
+        let pb = entities[b].position();
+        let (dx, dy) = (pb.x - pa.x, pb.y - pa.y);
+        let (overlap_x, overlap_y) = (size - dx.abs(), size - dy.abs());
+        if overlap_x <= 0 || overlap_y <= 0 {
+            return false;
+        }
+
+        // Push along the shallower axis; coincident entities split along X
+        let (new_a, new_b) = if overlap_x <= overlap_y {
+            let dir = if dx >= 0 { 1 } else { -1 };
+            (
+                WorldCoord::new(pa.x - dir * (overlap_x / 2), pa.y),
+                WorldCoord::new(pb.x + dir * (overlap_x - overlap_x / 2), pb.y),
+            )
+        } else {
+            let dir = if dy >= 0 { 1 } else { -1 };
+            (
+                WorldCoord::new(pa.x, pa.y - dir * (overlap_y / 2)),
+                WorldCoord::new(pb.x, pb.y + dir * (overlap_y - overlap_y / 2)),
+            )
+        };
+        entities[a].set_position(new_a);
+        entities[b].set_position(new_b);
+        true
+    }
+}
+
+/// Non-solid area that reports entities crossing its boundary.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TriggerZone {
+    /// Caller-chosen zone ID (quest area, trap, ...)
+    pub id: u32,
+    /// Area covered by the zone
+    pub bounds: AABB,
+}
+
+impl TriggerZone {
+    /// Creates a trigger zone.
+    #[must_use]
+    pub const fn new(id: u32, bounds: AABB) -> Self {
+        Self { id, bounds }
+    }
+}
+
+/// Entity crossed a trigger zone boundary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TriggerEvent {
+    /// Entity moved into the zone
+    Enter {
+        /// Zone ID
+        zone: u32,
+        /// Entity that entered
+        entity: EntityId,
+    },
+    /// Entity left the zone, or despawned while inside
+    Exit {
+        /// Zone ID
+        zone: u32,
+        /// Entity that left
+        entity: EntityId,
+    },
+}
+
+/// Registry of trigger zones and which entities are inside them.
+///
+/// An entity enters when its position is inside a zone's bounds but only
+/// exits once it is further than `exit_margin` outside them, so an entity
+/// straddling the edge does not flicker between enter and exit.
+#[derive(Debug, Clone)]
+pub struct TriggerRegistry {
+    zones: Vec<TriggerZone>,
+    exit_margin: f32,
+    inside: HashSet<(u32, EntityId)>,
+}
+
+impl Default for TriggerRegistry {
+    fn default() -> Self {
+        Self {
+            zones: Vec::new(),
+            exit_margin: 2.0,
+            inside: HashSet::new(),
+        }
+    }
+}
+
+impl TriggerRegistry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how far outside a zone an entity must move to exit it.
+    #[must_use]
+    pub fn with_exit_margin(mut self, margin: f32) -> Self {
+        self.exit_margin = margin.max(0.0);
+        self
+    }
+
+    /// Adds a zone, replacing any zone with the same ID.
+    pub fn add_zone(&mut self, zone: TriggerZone) {
+        self.remove_zone(zone.id);
+        self.zones.push(zone);
+    }
+
+    /// Removes a zone without emitting exit events. Returns whether it existed.
+    pub fn remove_zone(&mut self, id: u32) -> bool {
+        let before = self.zones.len();
+        self.zones.retain(|z| z.id != id);
+        self.inside.retain(|&(zone, _)| zone != id);
+        self.zones.len() != before
+    }
+
+    /// Registered zones.
+    #[must_use]
+    pub fn zones(&self) -> &[TriggerZone] {
+        &self.zones
+    }
+
+    /// Whether an entity is currently inside a zone.
+    #[must_use]
+    pub fn is_inside(&self, zone: u32, entity: EntityId) -> bool {
+        self.inside.contains(&(zone, entity))
+    }
+
+    /// Updates zone occupancy from this frame's entity positions.
+    ///
+    /// Entities that were inside a zone but are missing from
+    /// `entity_positions` get an exit event. Events are ordered by zone,
+    /// then by the order of `entity_positions`.
+    pub fn update_triggers(
+        &mut self,
+        entity_positions: &[(EntityId, (f32, f32))],
+    ) -> Vec<TriggerEvent> {
+        let mut events = Vec::new();
+        for zone in &self.zones {
+            let exit_bounds = zone.bounds.expanded(self.exit_margin);
+            for &(entity, pos) in entity_positions {
+                let key = (zone.id, entity);
+                let was_inside = self.inside.contains(&key);
+                if !was_inside && contains_point(zone.bounds, pos) {
+                    self.inside.insert(key);
+                    events.push(TriggerEvent::Enter {
+                        zone: zone.id,
+                        entity,
+                    });
+                } else if was_inside && !contains_point(exit_bounds, pos) {
+                    self.inside.remove(&key);
+                    events.push(TriggerEvent::Exit {
+                        zone: zone.id,
+                        entity,
+                    });
+                }
+            }
+
+            let mut gone: Vec<EntityId> = self
+                .inside
+                .iter()
+                .filter(|&&(id, entity)| {
+                    id == zone.id && !entity_positions.iter().any(|&(e, _)| e == entity)
+                })
+                .map(|&(_, entity)| entity)
+                .collect();
+            gone.sort_by_key(|e| e.raw());
+            for entity in gone {
+                self.inside.remove(&(zone.id, entity));
+                events.push(TriggerEvent::Exit {
+                    zone: zone.id,
+                    entity,
+                });
+            }
+        }
+        events
+    }
+}
+
+/// Whether a point lies inside (or on the edge of) an AABB.
+fn contains_point(bounds: AABB, (x, y): (f32, f32)) -> bool {
+    x >= bounds.min_x && x <= bounds.max_x && y >= bounds.min_y && y <= bounds.max_y
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -706,4 +976,109 @@ mod tests {
         assert_eq!(config.half_width, 6.0);
         assert_eq!(config.half_height, 6.0);
     }
+
+    fn npc_at(x: i64, y: i64) -> Entity {
+        let mut npc = Entity::new(EntityType::Npc);
+        npc.set_position(WorldCoord::new(x, y));
+        npc
+    }
+
+    fn overlaps(a: &Entity, b: &Entity, size: i64) -> bool {
+        let (pa, pb) = (a.position(), b.position());
+        (pb.x - pa.x).abs() < size && (pb.y - pa.y).abs() < size
+    }
+
+    #[test]
+    fn test_trigger_enter_and_exit() {
+        let mut triggers = TriggerRegistry::new().with_exit_margin(1.0);
+        triggers.add_zone(TriggerZone::new(7, AABB::new(0.0, 0.0, 10.0, 10.0)));
+        let player = EntityId::from_raw(1);
+        let npc = EntityId::from_raw(2);
+
+        let events = triggers.update_triggers(&[(player, (-5.0, 5.0)), (npc, (5.0, 5.0))]);
+        assert_eq!(
+            events,
+            vec![TriggerEvent::Enter {
+                zone: 7,
+                entity: npc
+            }]
+        );
+
+        let events = triggers.update_triggers(&[(player, (2.0, 5.0)), (npc, (20.0, 5.0))]);
+        assert_eq!(
+            events,
+            vec![
+                TriggerEvent::Enter {
+                    zone: 7,
+                    entity: player
+                },
+                TriggerEvent::Exit {
+                    zone: 7,
+                    entity: npc
+                },
+            ]
+        );
+        assert!(triggers.is_inside(7, player));
+        assert!(!triggers.is_inside(7, npc));
+
+        // Despawning while inside counts as leaving
+        let events = triggers.update_triggers(&[(npc, (20.0, 5.0))]);
+        assert_eq!(
+            events,
+            vec![TriggerEvent::Exit {
+                zone: 7,
+                entity: player
+            }]
+        );
+    }
+
+    #[test]
+    fn test_trigger_no_repeat_events_while_inside_or_straddling() {
+        let mut triggers = TriggerRegistry::new().with_exit_margin(2.0);
+        triggers.add_zone(TriggerZone::new(1, AABB::new(0.0, 0.0, 10.0, 10.0)));
+        let player = EntityId::from_raw(1);
+
+        assert_eq!(triggers.update_triggers(&[(player, (5.0, 5.0))]).len(), 1);
+        for x in [6.0, 9.0, 10.0, 10.5, 9.8, 11.9, 10.1] {
+            assert!(
+                triggers.update_triggers(&[(player, (x, 5.0))]).is_empty(),
+                "x = {x}"
+            );
+        }
+        assert_eq!(
+            triggers.update_triggers(&[(player, (12.5, 5.0))]),
+            vec![TriggerEvent::Exit {
+                zone: 1,
+                entity: player
+            }]
+        );
+        // Just outside the bounds, within the margin: no re-entry yet
+        assert!(triggers
+            .update_triggers(&[(player, (11.0, 5.0))])
+            .is_empty());
+    }
+
+    #[test]
+    fn test_separate_entities_pushes_overlapping_apart() {
+        let response = CollisionResponse::new();
+        let mut npcs = vec![npc_at(0, 0), npc_at(5, 2)];
+        assert!(response.separate_entities(&mut npcs) > 0);
+        assert!(!overlaps(&npcs[0], &npcs[1], response.entity_size));
+
+        // Entities on the same spot are split apart too
+        let mut stacked = vec![npc_at(100, 100), npc_at(100, 100)];
+        assert!(response.separate_entities(&mut stacked) > 0);
+        assert!(!overlaps(&stacked[0], &stacked[1], response.entity_size));
+    }
+
+    #[test]
+    fn test_separate_entities_leaves_separated_untouched() {
+        let response = CollisionResponse::new();
+        let mut npcs = vec![npc_at(0, 0), npc_at(12, 0), npc_at(-40, 30)];
+        let before: Vec<_> = npcs.iter().map(Entity::position).collect();
+
+        assert_eq!(response.separate_entities(&mut npcs), 0);
+        let after: Vec<_> = npcs.iter().map(Entity::position).collect();
+        assert_eq!(before, after);
+    }
 }
@@ -6,12 +6,16 @@
 //! - Hit detection via collision queries
 //! - Knockback physics
 //! - Death handling
+//! - Target lock-on for aiming
 
 use genesis_common::EntityId;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
 use thiserror::Error;
 
+use crate::damage_calc::{
+    DamageCalculator, DamageCategory, DamageInstance, DamageResult, DefenseStats, Resistances,
+};
 use crate::physics::{CollisionQuery, AABB};
 
 /// Combat system error types.
@@ -488,6 +492,162 @@ impl WeaponStats {
     }
 }
 
+impl From<DamageType> for DamageCategory {
+    fn from(damage_type: DamageType) -> Self {
+        match damage_type {
+            DamageType::Physical => Self::Physical,
+            DamageType::Fire => Self::Fire,
+            DamageType::Ice => Self::Ice,
+            DamageType::Electric => Self::Lightning,
+            DamageType::Poison => Self::Poison,
+            DamageType::True => Self::True,
+        }
+    }
+}
+
+/// Offensive inputs to [`compute_damage`], supplied by the attacker and weapon.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttackInfo {
+    /// Base weapon damage
+    pub damage: f32,
+    /// Attacker power multiplier
+    pub power: f32,
+    /// Type of damage dealt
+    pub damage_type: DamageType,
+    /// Whether the attack rolled a critical hit
+    pub critical: bool,
+    /// Damage multiplier applied on a critical hit
+    pub crit_multiplier: f32,
+    /// Fraction of armor and resistance ignored (0.0 to 1.0)
+    pub penetration: f32,
+}
+
+impl AttackInfo {
+    /// Creates attack info with the given base damage and type.
+    #[must_use]
+    pub fn new(damage: f32, damage_type: DamageType) -> Self {
+        Self {
+            damage,
+            power: 1.0,
+            damage_type,
+            critical: false,
+            crit_multiplier: 2.0,
+            penetration: 0.0,
+        }
+    }
+
+    /// Builds attack info from attacker stats and weapon stats.
+    #[must_use]
+    pub fn from_stats(attacker: &CombatStats, weapon: &WeaponStats, critical: bool) -> Self {
+        Self {
+            damage: weapon.damage,
+            power: attacker.damage_multiplier,
+            damage_type: weapon.damage_type,
+            critical,
+            crit_multiplier: attacker.crit_multiplier,
+            penetration: 0.0,
+        }
+    }
+
+    /// Sets the attacker power multiplier.
+    #[must_use]
+    pub fn with_power(mut self, power: f32) -> Self {
+        self.power = power.max(0.0);
+        self
+    }
+
+    /// Marks the attack as a critical hit with the given multiplier.
+    #[must_use]
+    pub fn as_critical(mut self, multiplier: f32) -> Self {
+        self.critical = true;
+        self.crit_multiplier = multiplier;
+        self
+    }
+
+    /// Sets armor and resistance penetration.
+    #[must_use]
+    pub fn with_penetration(mut self, penetration: f32) -> Self {
+        self.penetration = penetration.clamp(0.0, 1.0);
+        self
+    }
+}
+
+/// Defensive inputs to [`compute_damage`], supplied by the defender and armor.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DefenseInfo {
+    /// Armor value (reduces physical damage)
+    pub armor: f32,
+    /// Resistances to damage types (0.0 = none, 1.0 = immune)
+    pub resistances: HashMap<DamageType, f32>,
+    /// Flat damage reduction applied after armor and resistance
+    pub flat_reduction: f32,
+}
+
+impl DefenseInfo {
+    /// Creates defense info with no mitigation.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds defense info from the defender's combat stats.
+    #[must_use]
+    pub fn from_stats(defender: &CombatStats) -> Self {
+        Self {
+            armor: defender.armor,
+            resistances: defender.resistances.clone(),
+            flat_reduction: 0.0,
+        }
+    }
+
+    /// Sets the armor value.
+    #[must_use]
+    pub fn with_armor(mut self, armor: f32) -> Self {
+        self.armor = armor.max(0.0);
+        self
+    }
+
+    /// Adds a resistance.
+    #[must_use]
+    pub fn with_resistance(mut self, damage_type: DamageType, value: f32) -> Self {
+        self.resistances.insert(damage_type, value.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Sets the flat damage reduction.
+    #[must_use]
+    pub fn with_flat_reduction(mut self, reduction: f32) -> Self {
+        self.flat_reduction = reduction.max(0.0);
+        self
+    }
+}
+
+/// Computes final damage for an attack against a defender.
+///
+/// Power and crits scale the base damage, armor mitigates physical damage with
+/// diminishing returns, and per-type resistances mitigate the remainder. Any hit
+/// that isn't fully negated deals at least the calculator's minimum damage.
+#[must_use]
+pub fn compute_damage(attack: &AttackInfo, defender: &DefenseInfo) -> DamageResult {
+    let mut instance = DamageInstance::new(attack.damage * attack.power, attack.damage_type.into())
+        .with_crit_multiplier(attack.crit_multiplier)
+        .with_penetration(attack.penetration);
+    if attack.critical {
+        instance = instance.as_crit();
+    }
+
+    let mut resistances = Resistances::new();
+    for (&damage_type, &value) in &defender.resistances {
+        resistances.set(damage_type.into(), value);
+    }
+    let defense = DefenseStats::new()
+        .with_armor(defender.armor)
+        .with_resistances(resistances)
+        .with_flat_reduction(defender.flat_reduction);
+
+    DamageCalculator::new().calculate(&instance, &defense)
+}
+
 /// Position data for an entity in combat.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct CombatPosition {
@@ -554,6 +714,94 @@ impl CombatPosition {
     }
 }
 
+/// Default distance at which a locked target is released.
+pub const DEFAULT_LOCK_RANGE: f32 = 256.0;
+
+/// Per-player combat state that persists between attacks.
+///
+/// Tracks the lock-on target so attacks and projectiles aim at it instead
+/// of following movement direction.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CombatState {
+    /// Currently locked target
+    locked_target: Option<EntityId>,
+    /// Distance beyond which the lock is released
+    lock_range: f32,
+}
+
+impl Default for CombatState {
+    fn default() -> Self {
+        Self {
+            locked_target: None,
+            lock_range: DEFAULT_LOCK_RANGE,
+        }
+    }
+}
+
+impl CombatState {
+    /// Creates a combat state with no target locked.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the distance beyond which the lock is released.
+    #[must_use]
+    pub fn with_lock_range(mut self, range: f32) -> Self {
+        self.lock_range = range.max(0.0);
+        self
+    }
+
+    /// Distance beyond which the lock is released.
+    #[must_use]
+    pub fn lock_range(&self) -> f32 {
+        self.lock_range
+    }
+
+    /// Currently locked target, if any.
+    #[must_use]
+    pub fn locked_target(&self) -> Option<EntityId> {
+        self.locked_target
+    }
+
+    /// Locks the candidate after the current target, wrapping around.
+    ///
+    /// `nearby` is the list of lockable targets, usually sorted nearest
+    /// first. With nothing locked (or the locked target no longer in the
+    /// list) the first candidate is chosen; an empty list clears the lock.
+    pub fn cycle_target(&mut self, nearby: &[EntityId]) -> Option<EntityId> {
+        let current = self
+            .locked_target
+            .and_then(|target| nearby.iter().position(|&id| id == target));
+        self.locked_target = match current {
+            Some(index) => Some(nearby[(index + 1) % nearby.len()]),
+            None => nearby.first().copied(),
+        };
+        self.locked_target
+    }
+
+    /// Releases the lock.
+    pub fn clear_target(&mut self) {
+        self.locked_target = None;
+    }
+
+    /// Drops the lock if the target is gone or out of range.
+    ///
+    /// `target` is the locked target's position, or `None` if it died or
+    /// despawned. Returns the target that remains locked.
+    pub fn validate_target(
+        &mut self,
+        origin: &CombatPosition,
+        target: Option<&CombatPosition>,
+    ) -> Option<EntityId> {
+        match target {
+            Some(pos) if origin.distance_to(pos) <= self.lock_range => {},
+            _ => self.locked_target = None,
+        }
+        self.locked_target
+    }
+}
+
 /// Storage for entity combat data.
 pub trait CombatStorage {
     /// Gets an entity's combat stats.
@@ -568,6 +816,10 @@ pub trait CombatStorage {
     fn apply_knockback(&mut self, entity: EntityId, knockback: (f32, f32));
     /// Marks an entity as dead.
     fn on_death(&mut self, entity: EntityId);
+    /// Whether an entity currently ignores damage (e.g. dash i-frames).
+    fn is_invulnerable(&self, _entity: EntityId) -> bool {
+        false
+    }
 }
 
 /// Combat system managing attacks and damage.
@@ -975,33 +1227,14 @@ impl CombatSystem {
         weapon_stats: &WeaponStats,
         target_pos: CombatPosition,
     ) -> DamageEvent {
-        // Base damage
-        let base_damage = weapon_stats.damage * attacker_stats.damage_multiplier;
-
         // Check for critical hit
         let crit_chance = attacker_stats.crit_chance + weapon_stats.crit_bonus;
         let is_crit = self.next_random() < crit_chance;
-        let crit_mult = if is_crit {
-            attacker_stats.crit_multiplier
-        } else {
-            1.0
-        };
-
-        let raw_damage = base_damage * crit_mult;
-
-        // Calculate armor reduction (only for physical damage)
-        let blocked = if weapon_stats.damage_type == DamageType::Physical {
-            raw_damage * target_stats.armor_reduction()
-        } else {
-            0.0
-        };
-
-        // Calculate resistance reduction
-        let resistance = target_stats.get_resistance(weapon_stats.damage_type);
-        let after_armor = raw_damage - blocked;
-        let resisted = after_armor * resistance;
 
-        let final_damage = (after_armor - resisted).max(0.0);
+        let result = compute_damage(
+            &AttackInfo::from_stats(attacker_stats, weapon_stats, is_crit),
+            &DefenseInfo::from_stats(target_stats),
+        );
 
         // Calculate knockback direction
         let knockback = if weapon_stats.knockback > 0.0 {
@@ -1011,13 +1244,13 @@ impl CombatSystem {
             None
         };
 
-        let mut event = DamageEvent::new(target, final_damage, weapon_stats.damage_type)
+        let mut event = DamageEvent::new(target, result.final_damage, weapon_stats.damage_type)
             .with_source(attacker)
             .with_position(target_pos.x, target_pos.y);
 
-        event.blocked = blocked;
-        event.resisted = resisted;
-        event.critical = is_crit;
+        event.blocked = result.armor_blocked;
+        event.resisted = result.resistance_blocked + result.flat_blocked;
+        event.critical = result.was_crit;
 
         if let Some(kb) = knockback {
             event = event.with_knockback(kb.0, kb.1);
@@ -1073,6 +1306,11 @@ impl CombatSystem {
 
     /// Applies damage event to storage and handles death.
     pub fn apply_damage_event<S: CombatStorage>(&mut self, event: &DamageEvent, storage: &mut S) {
+        // Invulnerable targets take neither damage nor knockback
+        if storage.is_invulnerable(event.target) {
+            return;
+        }
+
         // First check if entity exists and apply damage
         let (is_dead, knockback) = {
             if let Some(stats) = storage.get_stats_mut(event.target) {
@@ -1103,6 +1341,7 @@ pub struct MockCombatStorage {
     weapons: HashMap<ItemId, WeaponStats>,
     knockbacks: Vec<(EntityId, (f32, f32))>,
     deaths: Vec<EntityId>,
+    invulnerable: Vec<EntityId>,
 }
 
 #[cfg(test)]
@@ -1114,9 +1353,15 @@ impl MockCombatStorage {
             weapons: HashMap::new(),
             knockbacks: Vec::new(),
             deaths: Vec::new(),
+            invulnerable: Vec::new(),
         }
     }
 
+    /// Marks an entity as ignoring damage.
+    pub fn set_invulnerable(&mut self, id: EntityId) {
+        self.invulnerable.push(id);
+    }
+
     pub fn add_entity(&mut self, id: EntityId, stats: CombatStats, pos: CombatPosition) {
         self.stats.insert(id, stats);
         self.positions.insert(id, pos);
@@ -1152,6 +1397,10 @@ impl CombatStorage for MockCombatStorage {
     fn on_death(&mut self, entity: EntityId) {
         self.deaths.push(entity);
     }
+
+    fn is_invulnerable(&self, entity: EntityId) -> bool {
+        self.invulnerable.contains(&entity)
+    }
 }
 
 #[cfg(test)]
@@ -1689,4 +1938,111 @@ mod tests {
         assert!((aabb.max_x - 5.2).abs() < 0.01);
         assert!((aabb.max_y - 5.2).abs() < 0.01);
     }
+
+    #[test]
+    fn test_invulnerable_target_takes_no_damage() {
+        let mut combat = CombatSystem::new();
+        let mut storage = MockCombatStorage::new();
+        let target = EntityId::from_raw(7);
+        storage.add_entity(target, CombatStats::new(), CombatPosition::new(0.0, 0.0));
+        storage.set_invulnerable(target);
+
+        let event = DamageEvent::new(target, 50.0, DamageType::Physical).with_knockback(5.0, 0.0);
+        combat.apply_damage_event(&event, &mut storage);
+
+        let stats = storage.get_stats(target).expect("target exists");
+        assert!((stats.health - stats.max_health).abs() < f32::EPSILON);
+        assert!(storage.knockbacks.is_empty());
+    }
+
+    #[test]
+    fn test_compute_damage_armor_reduces_physical() {
+        let attack = AttackInfo::new(100.0, DamageType::Physical);
+        let bare = compute_damage(&attack, &DefenseInfo::new());
+        let armored = compute_damage(&attack, &DefenseInfo::new().with_armor(100.0));
+
+        assert!((bare.final_damage - 100.0).abs() < 0.01);
+        assert!((armored.final_damage - 50.0).abs() < 0.01);
+        assert!((armored.armor_blocked - 50.0).abs() < 0.01);
+
+        // Armor doesn't apply to elemental damage
+        let fire = AttackInfo::new(100.0, DamageType::Fire);
+        let result = compute_damage(&fire, &DefenseInfo::new().with_armor(100.0));
+        assert!((result.final_damage - 100.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_compute_damage_resistance_mitigates_its_type() {
+        let defense = DefenseInfo::new().with_resistance(DamageType::Fire, 0.5);
+
+        let fire = compute_damage(&AttackInfo::new(40.0, DamageType::Fire), &defense);
+        assert!((fire.final_damage - 20.0).abs() < 0.01);
+        assert!((fire.resistance_blocked - 20.0).abs() < 0.01);
+
+        let ice = compute_damage(&AttackInfo::new(40.0, DamageType::Ice), &defense);
+        assert!((ice.final_damage - 40.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_compute_damage_power_and_crit() {
+        let attack = AttackInfo::new(10.0, DamageType::Physical)
+            .with_power(1.5)
+            .as_critical(2.0);
+        let result = compute_damage(&attack, &DefenseInfo::new());
+
+        assert!(result.was_crit);
+        assert!((result.final_damage - 30.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_cycle_target_advances_and_wraps() {
+        let [a, b, c] = [1, 2, 3].map(EntityId::from_raw);
+        let mut state = CombatState::new();
+        assert_eq!(state.locked_target(), None);
+
+        assert_eq!(state.cycle_target(&[a, b, c]), Some(a));
+        assert_eq!(state.cycle_target(&[a, b, c]), Some(b));
+        assert_eq!(state.cycle_target(&[a, b, c]), Some(c));
+        assert_eq!(state.cycle_target(&[a, b, c]), Some(a));
+
+        // Locked target left the candidate list: start over from the nearest
+        assert_eq!(state.cycle_target(&[c, b]), Some(c));
+        assert_eq!(state.cycle_target(&[]), None);
+        assert_eq!(state.locked_target(), None);
+    }
+
+    #[test]
+    fn test_lock_clears_when_target_out_of_range_or_dead() {
+        let target = EntityId::from_raw(9);
+        let origin = CombatPosition::new(0.0, 0.0);
+        let mut state = CombatState::new().with_lock_range(100.0);
+
+        state.cycle_target(&[target]);
+        let near = CombatPosition::new(60.0, 80.0);
+        assert_eq!(state.validate_target(&origin, Some(&near)), Some(target));
+
+        let far = CombatPosition::new(60.0, 81.0);
+        assert_eq!(state.validate_target(&origin, Some(&far)), None);
+
+        state.cycle_target(&[target]);
+        assert_eq!(state.validate_target(&origin, None), None);
+        assert_eq!(state.locked_target(), None);
+    }
+
+    #[test]
+    fn test_compute_damage_minimum_floor() {
+        let defense = DefenseInfo::new()
+            .with_armor(10_000.0)
+            .with_resistance(DamageType::Physical, 0.9);
+        let result = compute_damage(&AttackInfo::new(5.0, DamageType::Physical), &defense);
+
+        assert!((result.final_damage - 1.0).abs() < f32::EPSILON);
+        assert!(!result.was_blocked);
+
+        // Full immunity still negates the hit entirely
+        let immune = DefenseInfo::new().with_resistance(DamageType::Poison, 1.0);
+        let result = compute_damage(&AttackInfo::new(5.0, DamageType::Poison), &immune);
+        assert!(result.final_damage.abs() < f32::EPSILON);
+        assert!(result.was_blocked);
+    }
 }
@@ -1,6 +1,10 @@
 //! Entity system with arena-based storage.
 
-use genesis_common::{EntityId, WorldCoord};
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::fmt;
+
+use genesis_common::{EntityId, FactionId, WorldCoord};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -49,8 +53,14 @@ pub struct Entity {
     position: WorldCoord,
     /// Health (if applicable)
     health: Option<Health>,
+    /// Faction allegiance (if any)
+    #[serde(default)]
+    faction: Option<FactionId>,
     /// Whether entity is active
     active: bool,
+    /// Extension components attached by systems or mods (not persisted)
+    #[serde(skip)]
+    components: ComponentMap,
 }
 
 impl Entity {
@@ -62,7 +72,9 @@ impl Entity {
             entity_type,
             position: WorldCoord::new(0, 0),
             health: Some(Health::new(100)),
+            faction: None,
             active: true,
+            components: ComponentMap::default(),
         }
     }
 
@@ -95,6 +107,22 @@ impl Entity {
         self.health.as_ref()
     }
 
+    /// Returns the entity's health mutably, if any.
+    pub fn health_mut(&mut self) -> Option<&mut Health> {
+        self.health.as_mut()
+    }
+
+    /// Returns the entity's faction, if any.
+    #[must_use]
+    pub const fn faction(&self) -> Option<FactionId> {
+        self.faction
+    }
+
+    /// Sets the entity's faction.
+    pub fn set_faction(&mut self, faction: Option<FactionId>) {
+        self.faction = faction;
+    }
+
     /// Returns whether the entity is active.
     #[must_use]
     pub const fn is_active(&self) -> bool {
@@ -105,6 +133,141 @@ impl Entity {
     pub fn deactivate(&mut self) {
         self.active = false;
     }
+
+    /// Attaches a component, returning the previous value of that type if any.
+    pub fn set_component<T: Component>(&mut self, value: T) -> Option<T> {
+        self.components.insert(value)
+    }
+
+    /// Returns the component of type `T`, if attached.
+    #[must_use]
+    pub fn get_component<T: Component>(&self) -> Option<&T> {
+        self.components.get::<T>()
+    }
+
+    /// Returns the component of type `T` mutably, if attached.
+    pub fn get_component_mut<T: Component>(&mut self) -> Option<&mut T> {
+        self.components.get_mut::<T>()
+    }
+
+    /// Detaches and returns the component of type `T`, if attached.
+    pub fn remove_component<T: Component>(&mut self) -> Option<T> {
+        self.components.remove::<T>()
+    }
+
+    /// Returns whether a component of type `T` is attached.
+    #[must_use]
+    pub fn has_component<T: Component>(&self) -> bool {
+        self.components.contains::<T>()
+    }
+
+    /// Returns the attached extension components.
+    #[must_use]
+    pub const fn components(&self) -> &ComponentMap {
+        &self.components
+    }
+}
+
+/// Data that can be attached to an [`Entity`] as an extension component.
+///
+/// Implemented automatically for any `'static` type that is
+/// `Clone + Debug + Send + Sync`.
+pub trait Component: Any + fmt::Debug + Send + Sync {
+    /// Clones the component into a new box.
+    fn clone_box(&self) -> Box<dyn Component>;
+    /// Upcasts to `Any` for downcasting.
+    fn as_any(&self) -> &dyn Any;
+    /// Upcasts to mutable `Any` for downcasting.
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+    /// Upcasts the box to `Any` for by-value downcasting.
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl<T: Any + Clone + fmt::Debug + Send + Sync> Component for T {
+    fn clone_box(&self) -> Box<dyn Component> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+/// Typed map holding at most one component per type.
+#[derive(Debug, Default)]
+pub struct ComponentMap {
+    entries: HashMap<TypeId, Box<dyn Component>>,
+}
+
+impl Clone for ComponentMap {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self
+                .entries
+                .iter()
+                .map(|(id, c)| (*id, (**c).clone_box()))
+                .collect(),
+        }
+    }
+}
+
+impl ComponentMap {
+    /// Inserts a component, returning the previous value of that type if any.
+    pub fn insert<T: Component>(&mut self, value: T) -> Option<T> {
+        self.entries
+            .insert(TypeId::of::<T>(), Box::new(value))
+            .and_then(|old| old.into_any().downcast::<T>().ok())
+            .map(|old| *old)
+    }
+
+    /// Returns the component of type `T`, if present.
+    #[must_use]
+    pub fn get<T: Component>(&self) -> Option<&T> {
+        self.entries
+            .get(&TypeId::of::<T>())
+            .and_then(|c| (**c).as_any().downcast_ref::<T>())
+    }
+
+    /// Returns the component of type `T` mutably, if present.
+    pub fn get_mut<T: Component>(&mut self) -> Option<&mut T> {
+        self.entries
+            .get_mut(&TypeId::of::<T>())
+            .and_then(|c| (**c).as_any_mut().downcast_mut::<T>())
+    }
+
+    /// Removes and returns the component of type `T`, if present.
+    pub fn remove<T: Component>(&mut self) -> Option<T> {
+        self.entries
+            .remove(&TypeId::of::<T>())
+            .and_then(|c| c.into_any().downcast::<T>().ok())
+            .map(|c| *c)
+    }
+
+    /// Returns whether a component of type `T` is present.
+    #[must_use]
+    pub fn contains<T: Component>(&self) -> bool {
+        self.entries.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Returns the number of components.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether no components are attached.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
 }
 
 /// Health component for entities.
@@ -135,6 +298,11 @@ impl Health {
         self.max
     }
 
+    /// Sets current health, clamped to `0..=max`.
+    pub fn set_current(&mut self, current: i32) {
+        self.current = current.clamp(0, self.max);
+    }
+
     /// Applies damage.
     pub fn damage(&mut self, amount: i32) {
         self.current = (self.current - amount).max(0);
@@ -152,6 +320,12 @@ impl Health {
     }
 }
 
+impl Default for Health {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
 /// Arena-based entity storage for efficient allocation and lookup.
 ///
 /// Uses a free list for O(1) allocation and deallocation.
@@ -483,4 +657,54 @@ mod tests {
         let retrieved = arena.get(id).expect("should exist");
         assert_eq!(retrieved.position(), WorldCoord::new(50, 75));
     }
+
+    #[test]
+    fn test_health_set_current_clamps() {
+        let mut health = Health::new(50);
+        health.set_current(20);
+        assert_eq!(health.current(), 20);
+        health.set_current(80);
+        assert_eq!(health.current(), 50);
+        health.set_current(-5);
+        assert!(health.is_dead());
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Tameable;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct LootTable(Vec<(u32, u32)>);
+
+    #[test]
+    fn test_components_of_different_types_coexist() {
+        let mut entity = Entity::new(EntityType::Npc);
+        assert!(!entity.has_component::<Tameable>());
+
+        assert!(entity.set_component(Tameable).is_none());
+        assert!(entity.set_component(LootTable(vec![(1, 2)])).is_none());
+        assert!(entity.set_component(7u32).is_none());
+
+        assert_eq!(entity.get_component::<Tameable>(), Some(&Tameable));
+        assert_eq!(
+            entity.get_component::<LootTable>(),
+            Some(&LootTable(vec![(1, 2)]))
+        );
+        assert_eq!(entity.get_component::<u32>(), Some(&7));
+        assert!(entity.get_component::<i32>().is_none());
+        assert_eq!(entity.components().len(), 3);
+
+        if let Some(loot) = entity.get_component_mut::<LootTable>() {
+            loot.0.push((3, 1));
+        }
+        let cloned = entity.clone();
+        assert_eq!(
+            cloned.get_component::<LootTable>(),
+            Some(&LootTable(vec![(1, 2), (3, 1)]))
+        );
+
+        assert_eq!(entity.set_component(9u32), Some(7));
+        assert_eq!(entity.remove_component::<Tameable>(), Some(Tameable));
+        assert!(!entity.has_component::<Tameable>());
+        assert!(cloned.has_component::<Tameable>());
+    }
 }
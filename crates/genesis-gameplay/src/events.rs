@@ -1,6 +1,14 @@
 //! Event bus for inter-system communication.
+//!
+//! Besides the [`GameEvent`] queue, systems can subscribe to a single event
+//! type with [`EventBus::subscribe`] and only receive events of that type.
+//! Dropping the returned receiver unsubscribes.
 
-use crossbeam_channel::{bounded, Receiver, Sender};
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+use crossbeam_channel::{bounded, Receiver, Sender, TrySendError};
+use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 
 use genesis_common::EntityId;
@@ -90,6 +98,8 @@ pub struct EventBus {
     receiver: Receiver<GameEvent>,
     /// Channel capacity
     capacity: usize,
+    /// Typed subscriber senders (`Sender<T>`) keyed by event type
+    subscribers: Mutex<HashMap<TypeId, Vec<Box<dyn Any + Send>>>>,
 }
 
 impl Default for EventBus {
@@ -107,13 +117,67 @@ impl EventBus {
             sender,
             receiver,
             capacity,
+            subscribers: Mutex::new(HashMap::new()),
         }
     }
 
+    /// Subscribes to events of type `T`.
+    ///
+    /// The receiver gets a copy of every `T` published after this call.
+    /// Dropping the receiver unsubscribes.
+    #[must_use]
+    pub fn subscribe<T: Clone + Send + 'static>(&self) -> Receiver<T> {
+        let (sender, receiver) = bounded::<T>(self.capacity);
+        self.subscribers
+            .lock()
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push(Box::new(sender));
+        receiver
+    }
+
     /// Publishes an event to the bus.
-    pub fn publish(&self, event: GameEvent) {
-        // Non-blocking send - if full, event is dropped
-        let _ = self.sender.try_send(event);
+    ///
+    /// The event is delivered to every subscriber of its type. A
+    /// [`GameEvent`] is also queued for [`Self::drain`].
+    pub fn publish<T: Clone + Send + 'static>(&self, event: T) {
+        self.dispatch(&event);
+
+        let event: Box<dyn Any> = Box::new(event);
+        if let Ok(event) = event.downcast::<GameEvent>() {
+            // Non-blocking send - if full, event is dropped
+            let _ = self.sender.try_send(*event);
+        }
+    }
+
+    /// Sends an event to the typed subscribers, pruning dropped ones.
+    fn dispatch<T: Clone + Send + 'static>(&self, event: &T) {
+        let mut subscribers = self.subscribers.lock();
+        let Some(senders) = subscribers.get_mut(&TypeId::of::<T>()) else {
+            return;
+        };
+
+        senders.retain(|sender| {
+            let Some(sender) = sender.downcast_ref::<Sender<T>>() else {
+                return false;
+            };
+            // Full subscribers miss the event, disconnected ones are removed
+            !matches!(
+                sender.try_send(event.clone()),
+                Err(TrySendError::Disconnected(_))
+            )
+        });
+    }
+
+    /// Returns the number of live subscribers for event type `T`.
+    ///
+    /// Dropped subscribers are counted until the next publish of `T`.
+    #[must_use]
+    pub fn subscriber_count<T: 'static>(&self) -> usize {
+        self.subscribers
+            .lock()
+            .get(&TypeId::of::<T>())
+            .map_or(0, Vec::len)
     }
 
     /// Drains all pending events.
@@ -149,3 +213,61 @@ pub trait EventHandler: Send + Sync {
     /// Handles an event.
     fn handle(&self, event: &GameEvent);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct QuestUpdated(u32);
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct SoundRequested(&'static str);
+
+    #[test]
+    fn test_subscriber_receives_only_its_type() {
+        let bus = EventBus::default();
+        let quests = bus.subscribe::<QuestUpdated>();
+        let sounds = bus.subscribe::<SoundRequested>();
+
+        bus.publish(QuestUpdated(7));
+        bus.publish(SoundRequested("door_open"));
+        bus.publish(QuestUpdated(8));
+
+        let received: Vec<_> = quests.try_iter().collect();
+        assert_eq!(received, vec![QuestUpdated(7), QuestUpdated(8)]);
+
+        let received: Vec<_> = sounds.try_iter().collect();
+        assert_eq!(received, vec![SoundRequested("door_open")]);
+
+        // Typed events don't enter the GameEvent queue
+        assert_eq!(bus.pending_count(), 0);
+    }
+
+    #[test]
+    fn test_dropped_subscriber_is_removed() {
+        let bus = EventBus::default();
+        let kept = bus.subscribe::<QuestUpdated>();
+        let dropped = bus.subscribe::<QuestUpdated>();
+        assert_eq!(bus.subscriber_count::<QuestUpdated>(), 2);
+
+        drop(dropped);
+        bus.publish(QuestUpdated(1));
+
+        assert_eq!(bus.subscriber_count::<QuestUpdated>(), 1);
+        assert_eq!(kept.try_recv(), Ok(QuestUpdated(1)));
+    }
+
+    #[test]
+    fn test_game_event_reaches_queue_and_subscribers() {
+        let bus = EventBus::default();
+        let receiver = bus.subscribe::<GameEvent>();
+
+        bus.publish(GameEvent::EntitySpawned {
+            entity_id: EntityId::new(),
+        });
+
+        assert_eq!(receiver.try_iter().count(), 1);
+        assert_eq!(bus.drain().len(), 1);
+    }
+}
@@ -1,6 +1,6 @@
 //! Faction and reputation system with relationships and membership.
 
-use genesis_common::FactionId;
+use genesis_common::{ChunkCoord, FactionId};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use thiserror::Error;
@@ -391,6 +391,166 @@ impl FactionRegistry {
     }
 }
 
+/// Orthogonal neighbor offsets used for territory adjacency.
+const CHUNK_NEIGHBORS: [(i32, i32); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+
+/// Faction ownership of world chunks.
+///
+/// Each chunk belongs to at most one faction. Regions are groups of
+/// orthogonally connected chunks owned by the same faction.
+#[derive(Debug, Clone, Default)]
+pub struct FactionTerritory {
+    /// Owner of each claimed chunk
+    owners: HashMap<ChunkCoord, FactionId>,
+}
+
+impl FactionTerritory {
+    /// Creates empty territory.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Assigns a chunk to a faction, returning the previous owner.
+    pub fn claim(&mut self, chunk: ChunkCoord, faction: FactionId) -> Option<FactionId> {
+        self.owners.insert(chunk, faction)
+    }
+
+    /// Assigns every chunk in an inclusive rectangle to a faction.
+    pub fn claim_rect(&mut self, min: ChunkCoord, max: ChunkCoord, faction: FactionId) {
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                self.owners.insert(ChunkCoord::new(x, y), faction);
+            }
+        }
+    }
+
+    /// Removes a chunk from its owner, returning the previous owner.
+    pub fn release(&mut self, chunk: ChunkCoord) -> Option<FactionId> {
+        self.owners.remove(&chunk)
+    }
+
+    /// Returns the faction owning a chunk.
+    #[must_use]
+    pub fn faction_at(&self, chunk: ChunkCoord) -> Option<FactionId> {
+        self.owners.get(&chunk).copied()
+    }
+
+    /// Returns all chunks owned by a faction.
+    pub fn chunks_of(&self, faction: FactionId) -> impl Iterator<Item = ChunkCoord> + '_ {
+        self.owners
+            .iter()
+            .filter(move |(_, owner)| **owner == faction)
+            .map(|(chunk, _)| *chunk)
+    }
+
+    /// Returns the number of chunks owned by a faction.
+    #[must_use]
+    pub fn chunk_count(&self, faction: FactionId) -> usize {
+        self.chunks_of(faction).count()
+    }
+
+    /// Returns the number of claimed chunks.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.owners.len()
+    }
+
+    /// Checks if no chunks are claimed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.owners.is_empty()
+    }
+
+    /// Returns the contiguous region containing a chunk (empty if unclaimed).
+    #[must_use]
+    pub fn region_at(&self, chunk: ChunkCoord) -> HashSet<ChunkCoord> {
+        let mut region = HashSet::new();
+        let Some(faction) = self.faction_at(chunk) else {
+            return region;
+        };
+        let mut stack = vec![chunk];
+        region.insert(chunk);
+        while let Some(current) = stack.pop() {
+            for next in neighbors(current) {
+                if self.faction_at(next) == Some(faction) && region.insert(next) {
+                    stack.push(next);
+                }
+            }
+        }
+        region
+    }
+
+    /// Returns every contiguous region owned by a faction.
+    #[must_use]
+    pub fn regions_of(&self, faction: FactionId) -> Vec<HashSet<ChunkCoord>> {
+        let mut seen = HashSet::new();
+        let mut regions = Vec::new();
+        for chunk in self.chunks_of(faction) {
+            if seen.contains(&chunk) {
+                continue;
+            }
+            let region = self.region_at(chunk);
+            seen.extend(region.iter().copied());
+            regions.push(region);
+        }
+        regions
+    }
+
+    /// Returns chunks of other factions that border a faction's territory.
+    ///
+    /// Each bordering chunk is listed once with its owner.
+    #[must_use]
+    pub fn border_chunks(&self, faction: FactionId) -> Vec<(ChunkCoord, FactionId)> {
+        let mut border: Vec<(ChunkCoord, FactionId)> = self
+            .chunks_of(faction)
+            .flat_map(neighbors)
+            .filter_map(|next| match self.faction_at(next) {
+                Some(owner) if owner != faction => Some((next, owner)),
+                _ => None,
+            })
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        border.sort_by_key(|(chunk, _)| (chunk.y, chunk.x));
+        border
+    }
+
+    /// Returns the factions whose territory touches a faction's territory.
+    #[must_use]
+    pub fn bordering_factions(&self, faction: FactionId) -> HashSet<FactionId> {
+        self.border_chunks(faction)
+            .into_iter()
+            .map(|(_, owner)| owner)
+            .collect()
+    }
+
+    /// Checks whether moving between two chunks enters territory of a
+    /// faction the player is hostile with, returning that faction.
+    ///
+    /// Moving within one faction's territory never warns.
+    #[must_use]
+    pub fn entering_hostile(
+        &self,
+        from: ChunkCoord,
+        to: ChunkCoord,
+        reputation: &ReputationTracker,
+    ) -> Option<FactionId> {
+        let owner = self.faction_at(to)?;
+        if self.faction_at(from) == Some(owner) {
+            return None;
+        }
+        (reputation.standing(owner) == ReputationStanding::Hostile).then_some(owner)
+    }
+}
+
+/// Orthogonal neighbors of a chunk.
+fn neighbors(chunk: ChunkCoord) -> impl Iterator<Item = ChunkCoord> {
+    CHUNK_NEIGHBORS
+        .into_iter()
+        .map(move |(dx, dy)| ChunkCoord::new(chunk.x + dx, chunk.y + dy))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -600,4 +760,74 @@ mod tests {
         let result = tracker.join(&faction);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_territory_lookup() {
+        let mut territory = FactionTerritory::new();
+        let (a, b) = (FactionId::new(1), FactionId::new(2));
+        territory.claim_rect(ChunkCoord::new(0, 0), ChunkCoord::new(2, 1), a);
+        assert_eq!(territory.claim(ChunkCoord::new(-4, 7), b), None);
+
+        assert_eq!(territory.faction_at(ChunkCoord::new(2, 1)), Some(a));
+        assert_eq!(territory.faction_at(ChunkCoord::new(-4, 7)), Some(b));
+        assert_eq!(territory.faction_at(ChunkCoord::new(3, 0)), None);
+        assert_eq!(territory.chunk_count(a), 6);
+
+        // Reclaiming hands the chunk over
+        assert_eq!(territory.claim(ChunkCoord::new(0, 0), b), Some(a));
+        assert_eq!(territory.faction_at(ChunkCoord::new(0, 0)), Some(b));
+        assert_eq!(territory.release(ChunkCoord::new(0, 0)), Some(b));
+        assert_eq!(territory.faction_at(ChunkCoord::new(0, 0)), None);
+    }
+
+    #[test]
+    fn test_territory_regions_and_borders() {
+        let mut territory = FactionTerritory::new();
+        let (a, b, c) = (FactionId::new(1), FactionId::new(2), FactionId::new(3));
+        // Two separate regions for A
+        territory.claim_rect(ChunkCoord::new(0, 0), ChunkCoord::new(1, 1), a);
+        territory.claim(ChunkCoord::new(5, 5), a);
+        // B touches A's left edge; C only touches diagonally
+        territory.claim(ChunkCoord::new(-1, 0), b);
+        territory.claim(ChunkCoord::new(-1, 1), b);
+        territory.claim(ChunkCoord::new(2, 2), c);
+
+        assert_eq!(territory.region_at(ChunkCoord::new(1, 1)).len(), 4);
+        assert_eq!(territory.regions_of(a).len(), 2);
+        assert!(territory.region_at(ChunkCoord::new(9, 9)).is_empty());
+
+        assert_eq!(
+            territory.border_chunks(a),
+            vec![(ChunkCoord::new(-1, 0), b), (ChunkCoord::new(-1, 1), b)]
+        );
+        assert_eq!(territory.bordering_factions(b), HashSet::from([a]));
+        assert!(territory.bordering_factions(c).is_empty());
+    }
+
+    #[test]
+    fn test_entering_hostile_territory() {
+        let mut territory = FactionTerritory::new();
+        let (a, b) = (FactionId::new(1), FactionId::new(2));
+        territory.claim_rect(ChunkCoord::new(0, 0), ChunkCoord::new(1, 0), a);
+        territory.claim(ChunkCoord::new(2, 0), b);
+
+        let mut reputation = ReputationTracker::new();
+        reputation.set(a, -80);
+
+        let wild = ChunkCoord::new(-1, 0);
+        assert_eq!(
+            territory.entering_hostile(wild, ChunkCoord::new(0, 0), &reputation),
+            Some(a)
+        );
+        // Already inside A's territory
+        assert_eq!(
+            territory.entering_hostile(ChunkCoord::new(0, 0), ChunkCoord::new(1, 0), &reputation),
+            None
+        );
+        // B is neutral
+        assert_eq!(
+            territory.entering_hostile(ChunkCoord::new(1, 0), ChunkCoord::new(2, 0), &reputation),
+            None
+        );
+    }
 }
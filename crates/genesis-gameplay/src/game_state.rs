@@ -3,11 +3,12 @@
 //! This module provides the `GameState` struct which serves as the single source
 //! of truth for all game state, coordinating player, entities, and game systems.
 
-use genesis_common::EntityId;
+use genesis_common::{EntityId, WorldCoord, WorldPos};
 use serde::{Deserialize, Serialize};
 
+use crate::collision_response::CollisionResponse;
 use crate::combat::CombatSystem;
-use crate::entity::EntityArena;
+use crate::entity::{Entity, EntityArena, EntityType};
 use crate::input::Input;
 use crate::npc::{NPCManager, NPCStorage, NPCType, NPCWorld};
 use crate::player::Player;
@@ -26,6 +27,8 @@ pub struct GameState {
     pub npc_manager: NPCManager,
     /// Combat system for attacks and damage
     pub combat_system: CombatSystem,
+    /// Entity separation applied after movement
+    pub collision_response: CollisionResponse,
     /// Current NPC interaction state
     pub npc_interaction: NPCInteractionState,
     /// Current game time in seconds
@@ -75,6 +78,7 @@ impl GameState {
             entities: EntityArena::new(),
             npc_manager: NPCManager::new(),
             combat_system: CombatSystem::default(),
+            collision_response: CollisionResponse::default(),
             npc_interaction: NPCInteractionState::default(),
             game_time: 0.0,
             paused: false,
@@ -91,6 +95,7 @@ impl GameState {
             entities: EntityArena::new(),
             npc_manager: NPCManager::new(),
             combat_system: CombatSystem::default(),
+            collision_response: CollisionResponse::default(),
             npc_interaction: NPCInteractionState::default(),
             game_time: 0.0,
             paused: false,
@@ -148,6 +153,47 @@ impl GameState {
             &mut storage,
             &mut self.combat_system,
         );
+
+        self.separate_npcs();
+    }
+
+    /// Pushes overlapping living NPCs apart after movement.
+    ///
+    /// Separation works on whole cells, so each NPC is moved by the cell
+    /// offset it was pushed, keeping its sub-cell position.
+    fn separate_npcs(&mut self) {
+        let mut ids: Vec<EntityId> = self
+            .npc_manager
+            .iter()
+            .filter(|(_, npc)| !npc.health.is_dead())
+            .map(|(id, _)| id)
+            .collect();
+        // Stable order so separation is deterministic
+        ids.sort_by_key(|id| id.raw());
+
+        let mut npcs: Vec<Entity> = ids
+            .iter()
+            .filter_map(|&id| self.npc_manager.get(id))
+            .map(|npc| {
+                let mut entity = Entity::new(EntityType::Npc);
+                entity.set_position(WorldPos::from(npc.position).to_world_coord());
+                entity
+            })
+            .collect();
+        let before: Vec<WorldCoord> = npcs.iter().map(Entity::position).collect();
+        if self.collision_response.separate_entities(&mut npcs) == 0 {
+            return;
+        }
+        for ((&id, entity), start) in ids.iter().zip(&npcs).zip(before) {
+            let end = entity.position();
+            if end == start {
+                continue;
+            }
+            if let Some(npc) = self.npc_manager.get_mut(id) {
+                npc.position.0 += (end.x - start.x) as f32;
+                npc.position.1 += (end.y - start.y) as f32;
+            }
+        }
     }
 
     /// Toggles the pause state of the game.
@@ -616,6 +662,38 @@ mod tests {
         assert!(state.game_time() > 0.0);
     }
 
+    #[test]
+    fn test_update_separates_overlapping_npcs() {
+        let mut state = GameState::new(0);
+        let size = state.collision_response.entity_size as f32;
+        let a = state
+            .npc_manager
+            .spawn_npc(NPCType::Passive, (500.0, 500.0));
+        let b = state
+            .npc_manager
+            .spawn_npc(NPCType::Passive, (503.5, 501.25));
+        let dead = state
+            .npc_manager
+            .spawn_npc(NPCType::Passive, (500.0, 500.0));
+        state
+            .npc_manager
+            .get_mut(dead)
+            .expect("dead npc")
+            .health
+            .set_current(0);
+
+        state.update(FIXED_TIMESTEP as f32, &Input::new());
+
+        let pos = |id| state.npc_manager.get(id).expect("npc").position;
+        let (pa, pb) = (pos(a), pos(b));
+        assert!(
+            (pa.0 - pb.0).abs() >= size - 1.0 || (pa.1 - pb.1).abs() >= size - 1.0,
+            "{pa:?} and {pb:?} still overlap"
+        );
+        // Corpses stay where they fell
+        assert_eq!(pos(dead), (500.0, 500.0));
+    }
+
     #[test]
     fn test_game_state_player_velocity() {
         let state = GameState::new(0);
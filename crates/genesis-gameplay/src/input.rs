@@ -362,6 +362,10 @@ pub enum Action {
     Inventory,
     /// Pause/menu (Escape by default)
     Pause,
+    /// Dash/roll (Left Alt by default)
+    Dash,
+    /// Lock on to / cycle combat target (Q by default)
+    LockOn,
 }
 
 /// Processed input state for gameplay use.
@@ -379,6 +383,8 @@ pub struct Input {
     pub interact_just_pressed: bool,
     /// Whether running (sprint) is pressed
     pub running: bool,
+    /// Whether dash was just pressed this frame
+    pub dash_just_pressed: bool,
     /// Primary action (left click / dig)
     pub primary_action: bool,
     /// Primary action was just pressed
@@ -551,6 +557,10 @@ impl InputManager {
             .insert(Action::Inventory, KeyBinding::new(KeyCode::I));
         self.bindings
             .insert(Action::Pause, KeyBinding::new(KeyCode::Escape));
+        self.bindings
+            .insert(Action::Dash, KeyBinding::new(KeyCode::LAlt));
+        self.bindings
+            .insert(Action::LockOn, KeyBinding::new(KeyCode::Q));
     }
 
     /// Rebind an action to a new key.
@@ -691,6 +701,7 @@ impl InputManager {
             interact: self.is_action_pressed(Action::Interact),
             interact_just_pressed: self.is_action_just_pressed(Action::Interact),
             running: self.is_action_pressed(Action::Run),
+            dash_just_pressed: self.is_action_just_pressed(Action::Dash),
             primary_action: self.is_mouse_pressed(MouseButton::Left),
             primary_action_just_pressed: self.is_mouse_just_pressed(MouseButton::Left),
             secondary_action: self.is_mouse_pressed(MouseButton::Right),
@@ -762,6 +773,10 @@ mod tests {
             manager.get_binding(Action::MoveUp).map(|b| b.primary),
             Some(KeyCode::W)
         );
+        assert_ne!(
+            manager.get_binding(Action::Dash).map(|b| b.primary),
+            manager.get_binding(Action::Jump).map(|b| b.primary)
+        );
     }
 
     #[test]
@@ -8,6 +8,8 @@ use thiserror::Error;
 
 use crate::input::Vec2;
 use crate::inventory::Inventory;
+use crate::inventory_ui::ItemMetadata;
+use crate::needs::{ConsumeResult, Needs};
 use crate::player::Player;
 
 /// Errors that can occur during world interaction.
@@ -56,6 +58,10 @@ pub enum InteractionError {
     /// Inventory is full
     #[error("inventory full, cannot pick up item")]
     InventoryFull,
+
+    /// Item cannot be eaten or drunk
+    #[error("item cannot be consumed: {0:?}")]
+    NotConsumable(ItemTypeId),
 }
 
 /// Result type for interaction operations.
@@ -622,6 +628,29 @@ impl InteractionManager {
         Ok(intent)
     }
 
+    /// Try to consume (eat or drink) an item from the inventory.
+    ///
+    /// Called when the player uses an item. One item is removed from the
+    /// inventory only if it has nutrition or hydration.
+    pub fn try_consume<M: ItemMetadata + ?Sized>(
+        item: ItemTypeId,
+        metadata: &M,
+        inventory: &mut Inventory,
+        needs: &mut Needs,
+    ) -> InteractionResult<ConsumeResult> {
+        if inventory.count(item) == 0 {
+            return Err(InteractionError::NoItem(item));
+        }
+        if metadata.get_nutrition(item) <= 0.0 && metadata.get_hydration(item) <= 0.0 {
+            return Err(InteractionError::NotConsumable(item));
+        }
+        if inventory.remove(item, 1).is_err() {
+            return Err(InteractionError::NoItem(item));
+        }
+
+        Ok(needs.consume(item, metadata))
+    }
+
     /// Process a full interaction tick.
     /// Call this each frame with the current input state.
     pub fn update<W: WorldQuery>(
@@ -1454,6 +1483,38 @@ mod tests {
         assert_eq!(inventory.count(ItemTypeId::new(1)), 4); // Used one
     }
 
+    #[test]
+    fn test_consume_item_from_inventory() {
+        let mut metadata = crate::inventory_ui::SimpleItemMetadata::new();
+        metadata.set_consumable(ItemTypeId::new(20), 30.0, 0.0);
+        let mut inventory = create_test_inventory();
+        let _ = inventory.add(ItemTypeId::new(20), 2);
+        let _ = inventory.add(ItemTypeId::new(1), 1);
+        let mut needs = Needs::new();
+        needs.hunger.set(40.0);
+
+        let result = InteractionManager::try_consume(
+            ItemTypeId::new(20),
+            &metadata,
+            &mut inventory,
+            &mut needs,
+        )
+        .expect("food should be consumable");
+        assert!(result.is_consumed());
+        assert_eq!(inventory.count(ItemTypeId::new(20)), 1);
+        assert!((needs.hunger.current() - 70.0).abs() < f32::EPSILON);
+
+        // Dirt is not food and stays in the inventory
+        let result = InteractionManager::try_consume(
+            ItemTypeId::new(1),
+            &metadata,
+            &mut inventory,
+            &mut needs,
+        );
+        assert!(matches!(result, Err(InteractionError::NotConsumable(_))));
+        assert_eq!(inventory.count(ItemTypeId::new(1)), 1);
+    }
+
     #[test]
     fn test_place_cell_blocked() {
         let mut manager = InteractionManager::new();
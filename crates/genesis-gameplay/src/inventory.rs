@@ -1,4 +1,8 @@
 //! Inventory system with stacking and atomic transfers.
+//!
+//! Each item type occupies one slot. The first `hotbar_size` slots form the
+//! hotbar and the rest the main inventory; `quick_move` shifts a stack
+//! between the two.
 
 use genesis_common::ItemTypeId;
 use serde::{Deserialize, Serialize};
@@ -8,6 +12,9 @@ use thiserror::Error;
 /// Default maximum stack size for items.
 pub const DEFAULT_MAX_STACK: u32 = 999;
 
+/// Default number of hotbar slots.
+pub const DEFAULT_HOTBAR_SIZE: u32 = 10;
+
 /// Inventory error types.
 #[derive(Debug, Error, PartialEq, Eq)]
 pub enum InventoryError {
@@ -48,7 +55,7 @@ pub enum InventoryError {
 pub type InventoryResult<T> = Result<T, InventoryError>;
 
 /// A stack of items in an inventory slot.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ItemStack {
     /// The item type
     pub item_type: ItemTypeId,
@@ -69,6 +76,7 @@ impl ItemStack {
 
 /// An inventory container with stacking support.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "InventoryData")]
 pub struct Inventory {
     /// Items and their quantities
     items: HashMap<ItemTypeId, u32>,
@@ -76,17 +84,49 @@ pub struct Inventory {
     capacity: u32,
     /// Maximum stack size per item type
     max_stack: u32,
+    /// Item type held in each slot (hotbar slots first)
+    slots: Vec<Option<ItemTypeId>>,
+    /// Number of leading slots that form the hotbar
+    hotbar_size: u32,
+    /// Selected hotbar slot (drives the equipped item)
+    selected_hotbar: u32,
+}
+
+/// Serialized form of [`Inventory`]; saves from before the slot layout
+/// existed get one rebuilt on load.
+#[derive(Deserialize)]
+struct InventoryData {
+    items: HashMap<ItemTypeId, u32>,
+    capacity: u32,
+    max_stack: u32,
+    #[serde(default)]
+    slots: Vec<Option<ItemTypeId>>,
+    #[serde(default)]
+    hotbar_size: u32,
+    #[serde(default)]
+    selected_hotbar: u32,
+}
+
+impl From<InventoryData> for Inventory {
+    fn from(data: InventoryData) -> Self {
+        let mut inventory = Self {
+            items: data.items,
+            capacity: data.capacity,
+            max_stack: data.max_stack,
+            slots: data.slots,
+            hotbar_size: data.hotbar_size.min(data.capacity),
+            selected_hotbar: data.selected_hotbar,
+        };
+        inventory.repair_slots();
+        inventory
+    }
 }
 
 impl Inventory {
     /// Creates a new inventory with the given slot capacity.
     #[must_use]
     pub fn new(capacity: u32) -> Self {
-        Self {
-            items: HashMap::new(),
-            capacity,
-            max_stack: DEFAULT_MAX_STACK,
-        }
+        Self::with_stack_limit(capacity, DEFAULT_MAX_STACK)
     }
 
     /// Creates a new inventory with custom stack limit.
@@ -96,6 +136,145 @@ impl Inventory {
             items: HashMap::new(),
             capacity,
             max_stack,
+            slots: vec![None; capacity as usize],
+            hotbar_size: 0,
+            selected_hotbar: 0,
+        }
+    }
+
+    /// Sets how many leading slots form the hotbar (clamped to capacity).
+    #[must_use]
+    pub fn with_hotbar(mut self, hotbar_size: u32) -> Self {
+        self.hotbar_size = hotbar_size.min(self.capacity);
+        self.selected_hotbar = self.selected_hotbar.min(self.hotbar_size.saturating_sub(1));
+        self
+    }
+
+    /// Returns the number of hotbar slots.
+    #[must_use]
+    pub const fn hotbar_size(&self) -> u32 {
+        self.hotbar_size
+    }
+
+    /// Checks whether a slot index is part of the hotbar.
+    #[must_use]
+    pub const fn is_hotbar_slot(&self, slot: usize) -> bool {
+        slot < self.hotbar_size as usize
+    }
+
+    /// Returns the stack in a slot, if any.
+    #[must_use]
+    pub fn slot(&self, slot: usize) -> Option<ItemStack> {
+        let item = (*self.slots.get(slot)?)?;
+        Some(ItemStack::new(item, self.count(item)))
+    }
+
+    /// Returns the slot holding an item type, if any.
+    #[must_use]
+    pub fn slot_of(&self, item: ItemTypeId) -> Option<usize> {
+        self.slots.iter().position(|s| *s == Some(item))
+    }
+
+    /// Returns the stack in a hotbar slot, if any.
+    #[must_use]
+    pub fn hotbar_item(&self, slot: usize) -> Option<ItemStack> {
+        if self.is_hotbar_slot(slot) {
+            self.slot(slot)
+        } else {
+            None
+        }
+    }
+
+    /// Selects the hotbar slot whose item is equipped.
+    ///
+    /// Out-of-range slots are ignored.
+    pub fn select_hotbar(&mut self, slot: usize) {
+        if self.is_hotbar_slot(slot) {
+            self.selected_hotbar = slot as u32;
+        }
+    }
+
+    /// Returns the selected hotbar slot.
+    #[must_use]
+    pub const fn selected_hotbar(&self) -> usize {
+        self.selected_hotbar as usize
+    }
+
+    /// Returns the equipped item (the stack in the selected hotbar slot).
+    #[must_use]
+    pub fn equipped_item(&self) -> Option<ItemStack> {
+        self.hotbar_item(self.selected_hotbar())
+    }
+
+    /// Moves the stack in `slot` to the first free slot of the other
+    /// segment (hotbar to main inventory or back), like a shift-click.
+    ///
+    /// Returns the slot the stack moved to.
+    pub fn quick_move(&mut self, slot: usize) -> InventoryResult<usize> {
+        let item = self
+            .slots
+            .get(slot)
+            .copied()
+            .flatten()
+            .ok_or(InventoryError::NotFound)?;
+        let hotbar = self.hotbar_size as usize;
+        let target = if self.is_hotbar_slot(slot) {
+            hotbar..self.slots.len()
+        } else {
+            0..hotbar
+        };
+        let dest = target
+            .into_iter()
+            .find(|&i| self.slots[i].is_none())
+            .ok_or(InventoryError::Full {
+                capacity: self.capacity,
+            })?;
+        self.slots[slot] = None;
+        self.slots[dest] = Some(item);
+        Ok(dest)
+    }
+
+    /// Moves the stack in `from` onto slot `to`, like a drag and drop.
+    ///
+    /// An empty target takes the stack; an occupied one swaps with it,
+    /// since each item type lives in a single slot.
+    pub fn move_slot(&mut self, from: usize, to: usize) -> InventoryResult<()> {
+        if self.slots.get(from).copied().flatten().is_none() || to >= self.slots.len() {
+            return Err(InventoryError::NotFound);
+        }
+        self.slots.swap(from, to);
+        Ok(())
+    }
+
+    /// Puts a newly added item type in the first free slot.
+    fn assign_slot(&mut self, item: ItemTypeId) {
+        if let Some(free) = self.slots.iter_mut().find(|s| s.is_none()) {
+            *free = Some(item);
+        }
+    }
+
+    /// Makes the slot layout match the stored items.
+    fn repair_slots(&mut self) {
+        let capacity = self.capacity as usize;
+        self.slots.resize(capacity, None);
+        let mut seen = Vec::new();
+        for slot in &mut self.slots {
+            match *slot {
+                Some(item) if self.items.contains_key(&item) && !seen.contains(&item) => {
+                    seen.push(item);
+                },
+                _ => *slot = None,
+            }
+        }
+        let mut missing: Vec<ItemTypeId> = self
+            .items
+            .keys()
+            .copied()
+            .filter(|item| !seen.contains(item))
+            .collect();
+        missing.sort_by_key(|item| item.raw());
+        for item in missing {
+            self.assign_slot(item);
         }
     }
 
@@ -146,6 +325,9 @@ impl Inventory {
         }
 
         self.items.insert(item, new_total);
+        if current == 0 {
+            self.assign_slot(item);
+        }
         Ok(())
     }
 
@@ -179,6 +361,9 @@ impl Inventory {
         }
         if current == amount {
             self.items.remove(&item);
+            if let Some(slot) = self.slot_of(item) {
+                self.slots[slot] = None;
+            }
         } else {
             self.items.insert(item, current - amount);
         }
@@ -218,6 +403,7 @@ impl Inventory {
     /// Clears all items from the inventory.
     pub fn clear(&mut self) {
         self.items.clear();
+        self.slots.fill(None);
     }
 
     /// Returns free slots available.
@@ -627,4 +813,85 @@ mod tests {
         assert!(inv.add(item2, 10).is_ok());
         assert_eq!(inv.free_slots(), 3);
     }
+
+    #[test]
+    fn test_move_slot_moves_and_swaps() {
+        let mut inv = Inventory::new(4).with_hotbar(2);
+        assert!(inv.add(ItemTypeId::new(1), 5).is_ok());
+        assert!(inv.add(ItemTypeId::new(2), 3).is_ok());
+
+        assert_eq!(inv.move_slot(0, 3), Ok(()));
+        assert!(inv.slot(0).is_none());
+        assert_eq!(inv.slot(3), Some(ItemStack::new(ItemTypeId::new(1), 5)));
+
+        assert_eq!(inv.move_slot(1, 3), Ok(()));
+        assert_eq!(inv.slot(1), Some(ItemStack::new(ItemTypeId::new(1), 5)));
+        assert_eq!(inv.slot(3), Some(ItemStack::new(ItemTypeId::new(2), 3)));
+
+        assert_eq!(inv.move_slot(0, 2), Err(InventoryError::NotFound));
+        assert_eq!(inv.move_slot(1, 4), Err(InventoryError::NotFound));
+        assert_eq!(inv.total_items(), 8);
+    }
+
+    #[test]
+    fn test_quick_move_between_segments() {
+        let mut inv = Inventory::new(6).with_hotbar(2);
+        assert!(inv.add(ItemTypeId::new(1), 5).is_ok());
+        assert!(inv.add(ItemTypeId::new(2), 3).is_ok());
+        assert!(inv.add(ItemTypeId::new(3), 7).is_ok());
+        assert_eq!(inv.slot_of(ItemTypeId::new(3)), Some(2));
+
+        // Hotbar -> first free main slot
+        assert_eq!(inv.quick_move(0), Ok(3));
+        assert!(inv.slot(0).is_none());
+        assert_eq!(inv.slot(3), Some(ItemStack::new(ItemTypeId::new(1), 5)));
+
+        // Main -> the freed hotbar slot
+        assert_eq!(inv.quick_move(2), Ok(0));
+        assert_eq!(
+            inv.hotbar_item(0),
+            Some(ItemStack::new(ItemTypeId::new(3), 7))
+        );
+
+        // Hotbar is full now
+        assert_eq!(inv.quick_move(3), Err(InventoryError::Full { capacity: 6 }));
+        assert_eq!(inv.quick_move(5), Err(InventoryError::NotFound));
+        assert_eq!(inv.count(ItemTypeId::new(1)), 5);
+    }
+
+    #[test]
+    fn test_hotbar_selection_drives_equipped_item() {
+        let mut inv = Inventory::new(4).with_hotbar(2);
+        assert!(inv.add(ItemTypeId::new(10), 1).is_ok());
+        assert!(inv.add(ItemTypeId::new(20), 4).is_ok());
+        assert!(inv.add(ItemTypeId::new(30), 2).is_ok());
+
+        assert_eq!(
+            inv.equipped_item(),
+            Some(ItemStack::new(ItemTypeId::new(10), 1))
+        );
+        inv.select_hotbar(1);
+        assert_eq!(inv.selected_hotbar(), 1);
+        assert_eq!(
+            inv.equipped_item(),
+            Some(ItemStack::new(ItemTypeId::new(20), 4))
+        );
+
+        // Slot 2 is in the main inventory, not the hotbar
+        inv.select_hotbar(2);
+        assert_eq!(inv.selected_hotbar(), 1);
+        assert!(inv.hotbar_item(2).is_none());
+
+        assert!(inv.remove(ItemTypeId::new(20), 4).is_ok());
+        assert!(inv.equipped_item().is_none());
+    }
+
+    #[test]
+    fn test_legacy_inventory_rebuilds_slots() {
+        let json = r#"{"items":{"7":2,"3":1},"capacity":4,"max_stack":99}"#;
+        let inv: Inventory = serde_json::from_str(json).expect("legacy inventory");
+        assert_eq!(inv.slot_of(ItemTypeId::new(3)), Some(0));
+        assert_eq!(inv.slot_of(ItemTypeId::new(7)), Some(1));
+        assert_eq!(inv.slot(1), Some(ItemStack::new(ItemTypeId::new(7), 2)));
+    }
 }
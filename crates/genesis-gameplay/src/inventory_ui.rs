@@ -194,6 +194,21 @@ pub trait ItemMetadata {
 
     /// Get the rarity for an item type.
     fn get_rarity(&self, item: ItemTypeId) -> Option<String>;
+
+    /// Get how much hunger eating the item restores (0 if not food).
+    fn get_nutrition(&self, _item: ItemTypeId) -> f32 {
+        0.0
+    }
+
+    /// Get how much thirst drinking the item restores (0 if not a drink).
+    fn get_hydration(&self, _item: ItemTypeId) -> f32 {
+        0.0
+    }
+
+    /// Get the weight of a single item, if it has one.
+    fn get_weight(&self, _item: ItemTypeId) -> Option<f32> {
+        None
+    }
 }
 
 /// Simple item metadata implementation using HashMaps.
@@ -203,6 +218,8 @@ pub struct SimpleItemMetadata {
     descriptions: HashMap<ItemTypeId, String>,
     stats: HashMap<ItemTypeId, Vec<(String, String)>>,
     rarities: HashMap<ItemTypeId, String>,
+    consumables: HashMap<ItemTypeId, (f32, f32)>,
+    weights: HashMap<ItemTypeId, f32>,
 }
 
 impl SimpleItemMetadata {
@@ -232,6 +249,16 @@ impl SimpleItemMetadata {
     pub fn set_rarity(&mut self, item: ItemTypeId, rarity: impl Into<String>) {
         self.rarities.insert(item, rarity.into());
     }
+
+    /// Sets nutrition and hydration for a consumable item.
+    pub fn set_consumable(&mut self, item: ItemTypeId, nutrition: f32, hydration: f32) {
+        self.consumables.insert(item, (nutrition, hydration));
+    }
+
+    /// Sets the weight of a single item.
+    pub fn set_weight(&mut self, item: ItemTypeId, weight: f32) {
+        self.weights.insert(item, weight);
+    }
 }
 
 impl ItemMetadata for SimpleItemMetadata {
@@ -256,6 +283,22 @@ impl ItemMetadata for SimpleItemMetadata {
     fn get_rarity(&self, item: ItemTypeId) -> Option<String> {
         self.rarities.get(&item).cloned()
     }
+
+    fn get_nutrition(&self, item: ItemTypeId) -> f32 {
+        self.consumables
+            .get(&item)
+            .map_or(0.0, |&(nutrition, _)| nutrition)
+    }
+
+    fn get_hydration(&self, item: ItemTypeId) -> f32 {
+        self.consumables
+            .get(&item)
+            .map_or(0.0, |&(_, hydration)| hydration)
+    }
+
+    fn get_weight(&self, item: ItemTypeId) -> Option<f32> {
+        self.weights.get(&item).copied()
+    }
 }
 
 /// Inventory UI model for rendering.
@@ -286,23 +329,14 @@ impl InventoryUIModel {
         let capacity = inv.capacity() as usize;
         let mut slots = Vec::with_capacity(capacity);
 
-        // Create slot UI data for each slot
+        // Create slot UI data for each slot, mirroring the inventory layout
         for i in 0..capacity {
             let mut slot = SlotUIData::new(i);
             slot.is_hotbar = i < hotbar_size;
+            slot.item = inv.slot(i);
             slots.push(slot);
         }
 
-        // Fill in items from inventory
-        // Note: Inventory uses HashMap<ItemTypeId, u32>, so we need to convert
-        let mut slot_idx = 0;
-        for (item_type, quantity) in inv.iter() {
-            if slot_idx < capacity {
-                slots[slot_idx].item = Some(ItemStack::new(item_type, quantity));
-                slot_idx += 1;
-            }
-        }
-
         Self {
             slots,
             selected_slot: None,
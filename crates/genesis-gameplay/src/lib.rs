@@ -11,6 +11,7 @@
 //! - Item crafting system
 //! - Building crafting system
 //! - Economy (prices, wallet, trade)
+//! - Loot tables (weighted drops)
 //! - Factions and reputation
 //! - Needs (food, water)
 //! - Event bus for inter-system communication
@@ -39,6 +40,7 @@ pub mod input;
 pub mod interaction;
 pub mod inventory;
 pub mod inventory_ui;
+pub mod loot;
 pub mod melee_combat;
 pub mod music;
 pub mod needs;
@@ -90,6 +92,7 @@ pub mod prelude {
     pub use crate::interaction::*;
     pub use crate::inventory::*;
     pub use crate::inventory_ui::*;
+    pub use crate::loot::*;
     pub use crate::melee_combat::*;
     pub use crate::music::*;
     pub use crate::needs::*;
@@ -0,0 +1,510 @@
+//! Loot tables with weighted drops.
+//!
+//! A [`LootTable`] makes a number of weighted picks from its entries. Each
+//! picked entry then passes its own drop chance and yields a quantity from
+//! its range. Rolls take a [`SeededRng`] so drops are reproducible;
+//! [`structure_loot`] derives that RNG from the world seed and a structure's
+//! position so a chest holds the same items in every session.
+//!
+//! Tables are defined in TOML and referenced by [`LootTableId`] from NPCs
+//! and plants:
+//!
+//! ```toml
+//! [[tables]]
+//! id = 1
+//! name = "slime"
+//! rolls = 2
+//!
+//! [[tables.entries]]
+//! item = 10
+//! weight = 3
+//! min = 1
+//! max = 3
+//! chance = 0.5
+//! ```
+
+use genesis_common::{ItemTypeId, SeededRng};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use thiserror::Error;
+
+/// Errors from loading loot tables.
+#[derive(Debug, Error)]
+pub enum LootError {
+    /// Failed to read a loot file
+    #[error("Failed to read loot file: {0}")]
+    Io(#[from] std::io::Error),
+    /// Failed to parse loot TOML
+    #[error("Failed to parse loot TOML: {0}")]
+    Parse(#[from] toml::de::Error),
+    /// Two tables share an ID
+    #[error("Duplicate loot table ID: {0:?}")]
+    DuplicateId(LootTableId),
+    /// An entry has an empty quantity range
+    #[error("Loot table {table:?} has an entry with min {min} > max {max}")]
+    InvalidRange {
+        /// Table containing the entry
+        table: LootTableId,
+        /// Minimum quantity
+        min: u32,
+        /// Maximum quantity
+        max: u32,
+    },
+}
+
+/// Result type for loot operations.
+pub type LootResult<T> = Result<T, LootError>;
+
+/// Unique identifier for a loot table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LootTableId(pub u32);
+
+impl LootTableId {
+    /// Create a new loot table ID.
+    #[must_use]
+    pub const fn new(id: u32) -> Self {
+        Self(id)
+    }
+
+    /// Get the raw ID value.
+    #[must_use]
+    pub const fn raw(self) -> u32 {
+        self.0
+    }
+}
+
+/// A single possible drop in a loot table.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LootEntry {
+    /// Item dropped
+    pub item: ItemTypeId,
+    /// Relative weight when picking an entry
+    #[serde(default = "default_weight")]
+    pub weight: u32,
+    /// Minimum quantity (inclusive)
+    #[serde(default = "default_quantity")]
+    pub min: u32,
+    /// Maximum quantity (inclusive)
+    #[serde(default = "default_quantity")]
+    pub max: u32,
+    /// Chance the picked entry actually drops (0.0 to 1.0)
+    #[serde(default = "default_chance")]
+    pub chance: f32,
+}
+
+fn default_weight() -> u32 {
+    1
+}
+
+fn default_quantity() -> u32 {
+    1
+}
+
+fn default_chance() -> f32 {
+    1.0
+}
+
+fn default_rolls() -> u32 {
+    1
+}
+
+impl LootEntry {
+    /// Creates an entry that always drops exactly one item when picked.
+    #[must_use]
+    pub fn new(item: ItemTypeId, weight: u32) -> Self {
+        Self {
+            item,
+            weight,
+            min: 1,
+            max: 1,
+            chance: 1.0,
+        }
+    }
+
+    /// Sets the quantity range (inclusive).
+    #[must_use]
+    pub fn with_quantity(mut self, min: u32, max: u32) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Sets the drop chance.
+    #[must_use]
+    pub fn with_chance(mut self, chance: f32) -> Self {
+        self.chance = chance.clamp(0.0, 1.0);
+        self
+    }
+}
+
+/// A weighted set of drops.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LootTable {
+    /// Unique identifier
+    pub id: LootTableId,
+    /// Display name
+    #[serde(default)]
+    pub name: String,
+    /// Number of weighted picks per roll
+    #[serde(default = "default_rolls")]
+    pub rolls: u32,
+    /// Possible drops
+    #[serde(default)]
+    pub entries: Vec<LootEntry>,
+}
+
+impl LootTable {
+    /// Creates an empty table making one pick per roll.
+    #[must_use]
+    pub fn new(id: LootTableId, name: &str) -> Self {
+        Self {
+            id,
+            name: name.to_string(),
+            rolls: 1,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Sets the number of picks per roll.
+    #[must_use]
+    pub fn with_rolls(mut self, rolls: u32) -> Self {
+        self.rolls = rolls;
+        self
+    }
+
+    /// Adds an entry.
+    #[must_use]
+    pub fn with_entry(mut self, entry: LootEntry) -> Self {
+        self.entries.push(entry);
+        self
+    }
+
+    /// Sum of all entry weights.
+    #[must_use]
+    pub fn total_weight(&self) -> u32 {
+        self.entries.iter().map(|e| e.weight).sum()
+    }
+
+    /// Rolls the table, returning the dropped items.
+    ///
+    /// Each item appears at most once, in the order it was first dropped.
+    pub fn roll(&self, rng: &mut SeededRng) -> Vec<(ItemTypeId, u32)> {
+        let mut drops: Vec<(ItemTypeId, u32)> = Vec::new();
+        for _ in 0..self.rolls {
+            let Some(entry) = self.pick(rng) else {
+                break;
+            };
+            if !rng.chance(entry.chance) {
+                continue;
+            }
+            let quantity = rng.range_u32(entry.min, entry.max);
+            if quantity == 0 {
+                continue;
+            }
+            match drops.iter_mut().find(|(item, _)| *item == entry.item) {
+                Some((_, total)) => *total += quantity,
+                None => drops.push((entry.item, quantity)),
+            }
+        }
+        drops
+    }
+
+    /// Picks one entry by weight.
+    fn pick(&self, rng: &mut SeededRng) -> Option<&LootEntry> {
+        let total = self.total_weight();
+        if total == 0 {
+            return None;
+        }
+        let mut target = rng.range_u32(0, total - 1);
+        self.entries.iter().find(|entry| {
+            if target < entry.weight {
+                true
+            } else {
+                target -= entry.weight;
+                false
+            }
+        })
+    }
+
+    fn validate(&self) -> LootResult<()> {
+        match self.entries.iter().find(|e| e.min > e.max) {
+            Some(entry) => Err(LootError::InvalidRange {
+                table: self.id,
+                min: entry.min,
+                max: entry.max,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// RNG seed for the loot of the structure at block position `struct_pos`.
+///
+/// Mixes the world seed with both coordinates through SplitMix64 so nearby
+/// structures get unrelated rolls.
+#[must_use]
+pub fn structure_loot_seed(seed: u64, struct_pos: (i32, i32)) -> u64 {
+    fn mix(mut z: u64) -> u64 {
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+    let (x, z) = struct_pos;
+    let h = mix(seed.wrapping_add(0x9E37_79B9_7F4A_7C15));
+    let h = mix(h ^ u64::from(x as u32));
+    mix(h ^ (u64::from(z as u32) << 32))
+}
+
+/// Rolls `table` for the structure at `struct_pos` in the world with `seed`.
+///
+/// The result depends only on the three inputs, so the same chest yields
+/// the same loot across calls, sessions and replays.
+#[must_use]
+pub fn structure_loot(
+    seed: u64,
+    struct_pos: (i32, i32),
+    table: &LootTable,
+) -> Vec<(ItemTypeId, u32)> {
+    table.roll(&mut SeededRng::new(structure_loot_seed(seed, struct_pos)))
+}
+
+/// TOML file layout for loot tables.
+#[derive(Debug, Deserialize)]
+struct LootFile {
+    #[serde(default)]
+    tables: Vec<LootTable>,
+}
+
+/// Registry of loot tables by ID.
+#[derive(Debug, Clone, Default)]
+pub struct LootRegistry {
+    tables: HashMap<LootTableId, LootTable>,
+}
+
+impl LootRegistry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses tables from TOML.
+    pub fn from_toml(source: &str) -> LootResult<Self> {
+        let mut registry = Self::new();
+        registry.load_toml(source)?;
+        Ok(registry)
+    }
+
+    /// Loads tables from a TOML file.
+    pub fn load_file(&mut self, path: &Path) -> LootResult<()> {
+        let source = std::fs::read_to_string(path)?;
+        self.load_toml(&source)
+    }
+
+    /// Parses tables from TOML and adds them to the registry.
+    ///
+    /// Nothing is added if any table is invalid or reuses an existing ID.
+    pub fn load_toml(&mut self, source: &str) -> LootResult<()> {
+        let file: LootFile = toml::from_str(source)?;
+        for (i, table) in file.tables.iter().enumerate() {
+            table.validate()?;
+            if self.tables.contains_key(&table.id)
+                || file.tables[..i].iter().any(|t| t.id == table.id)
+            {
+                return Err(LootError::DuplicateId(table.id));
+            }
+        }
+        for table in file.tables {
+            self.tables.insert(table.id, table);
+        }
+        Ok(())
+    }
+
+    /// Registers a table, replacing any with the same ID.
+    pub fn register(&mut self, table: LootTable) {
+        self.tables.insert(table.id, table);
+    }
+
+    /// Gets a table by ID.
+    #[must_use]
+    pub fn get(&self, id: LootTableId) -> Option<&LootTable> {
+        self.tables.get(&id)
+    }
+
+    /// Rolls the table with the given ID; unknown IDs drop nothing.
+    pub fn roll(&self, id: LootTableId, rng: &mut SeededRng) -> Vec<(ItemTypeId, u32)> {
+        self.get(id).map(|t| t.roll(rng)).unwrap_or_default()
+    }
+
+    /// Returns the number of tables.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.tables.len()
+    }
+
+    /// Returns whether the registry is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+[[tables]]
+id = 1
+name = "slime"
+rolls = 2
+
+[[tables.entries]]
+item = 10
+weight = 3
+min = 1
+max = 3
+
+[[tables.entries]]
+item = 11
+chance = 0.25
+
+[[tables]]
+id = 2
+name = "berry bush"
+
+[[tables.entries]]
+item = 20
+min = 2
+max = 5
+"#;
+
+    #[test]
+    fn test_roll_respects_weights() {
+        let table = LootTable::new(LootTableId::new(1), "weighted")
+            .with_entry(LootEntry::new(ItemTypeId::new(1), 1))
+            .with_entry(LootEntry::new(ItemTypeId::new(2), 3))
+            .with_entry(LootEntry::new(ItemTypeId::new(3), 0));
+        let mut rng = SeededRng::new(42);
+
+        let mut counts = [0u32; 4];
+        let samples = 20_000;
+        for _ in 0..samples {
+            for (item, quantity) in table.roll(&mut rng) {
+                counts[item.raw() as usize] += quantity;
+            }
+        }
+        assert_eq!(counts[1] + counts[2], samples);
+        assert_eq!(counts[3], 0);
+        // Expect 25% / 75%
+        let share = f64::from(counts[1]) / f64::from(samples);
+        assert!((share - 0.25).abs() < 0.02, "share {share}");
+    }
+
+    #[test]
+    fn test_quantities_and_chance_stay_in_bounds() {
+        let table = LootTable::new(LootTableId::new(1), "bounded")
+            .with_rolls(3)
+            .with_entry(LootEntry::new(ItemTypeId::new(1), 1).with_quantity(2, 5))
+            .with_entry(
+                LootEntry::new(ItemTypeId::new(2), 1)
+                    .with_quantity(1, 1)
+                    .with_chance(0.0),
+            );
+        let single = table.clone().with_rolls(1);
+        let mut rng = SeededRng::new(7);
+        let mut seen = [false; 6];
+        for _ in 0..1_000 {
+            for (item, quantity) in table.roll(&mut rng) {
+                assert_eq!(item, ItemTypeId::new(1));
+                // Up to three picks of 2..=5 each
+                assert!((2..=15).contains(&quantity), "quantity {quantity}");
+            }
+            for (_, quantity) in single.roll(&mut rng) {
+                seen[quantity as usize] = true;
+            }
+        }
+        assert_eq!(seen, [false, false, true, true, true, true]);
+    }
+
+    #[test]
+    fn test_roll_is_deterministic() {
+        let registry = LootRegistry::from_toml(SAMPLE).expect("sample loot");
+        let id = LootTableId::new(1);
+        let a: Vec<_> = {
+            let mut rng = SeededRng::new(99);
+            (0..50).map(|_| registry.roll(id, &mut rng)).collect()
+        };
+        let mut rng = SeededRng::new(99);
+        for drops in a {
+            assert_eq!(registry.roll(id, &mut rng), drops);
+        }
+        assert!(registry.roll(LootTableId::new(404), &mut rng).is_empty());
+    }
+
+    #[test]
+    fn test_structure_loot_is_stable_per_position() {
+        let table = LootTable::new(LootTableId::new(5), "chest")
+            .with_rolls(4)
+            .with_entry(LootEntry::new(ItemTypeId::new(1), 2).with_quantity(1, 8))
+            .with_entry(LootEntry::new(ItemTypeId::new(2), 1).with_quantity(1, 3))
+            .with_entry(LootEntry::new(ItemTypeId::new(3), 1).with_chance(0.5));
+        let seed = 0xDEAD_BEEF;
+        let pos = (-1_184, 2_256);
+
+        let first = structure_loot(seed, pos, &table);
+        assert!(!first.is_empty());
+        for _ in 0..10 {
+            assert_eq!(structure_loot(seed, pos, &table), first);
+        }
+
+        // Other chests and other worlds don't all share one roll
+        let others: Vec<_> = (1..20)
+            .map(|i| structure_loot(seed, (pos.0 + i * 16, pos.1), &table))
+            .chain((1..20).map(|i| structure_loot(seed + i as u64, pos, &table)))
+            .collect();
+        assert!(others.iter().any(|drops| *drops != first));
+        assert_ne!(
+            structure_loot_seed(seed, (1, 0)),
+            structure_loot_seed(seed, (0, 1))
+        );
+    }
+
+    #[test]
+    fn test_registry_loads_toml() {
+        let registry = LootRegistry::from_toml(SAMPLE).expect("sample loot");
+        assert_eq!(registry.len(), 2);
+
+        let slime = registry.get(LootTableId::new(1)).expect("slime table");
+        assert_eq!(slime.rolls, 2);
+        assert_eq!(slime.total_weight(), 4);
+        assert!((slime.entries[1].chance - 0.25).abs() < f32::EPSILON);
+        assert_eq!((slime.entries[1].min, slime.entries[1].max), (1, 1));
+
+        let bush = registry.get(LootTableId::new(2)).expect("bush table");
+        assert_eq!(bush.rolls, 1);
+        assert_eq!((bush.entries[0].min, bush.entries[0].max), (2, 5));
+    }
+
+    #[test]
+    fn test_registry_rejects_bad_tables() {
+        let mut registry = LootRegistry::from_toml(SAMPLE).expect("sample loot");
+        let duplicate = "[[tables]]\nid = 2\n";
+        assert!(matches!(
+            registry.load_toml(duplicate),
+            Err(LootError::DuplicateId(LootTableId(2)))
+        ));
+
+        let bad_range = "[[tables]]\nid = 3\n[[tables.entries]]\nitem = 1\nmin = 4\nmax = 2\n";
+        assert!(matches!(
+            registry.load_toml(bad_range),
+            Err(LootError::InvalidRange { min: 4, max: 2, .. })
+        ));
+        assert!(matches!(
+            registry.load_toml("tables = 5"),
+            Err(LootError::Parse(_))
+        ));
+        assert_eq!(registry.len(), 2);
+    }
+}
@@ -6,6 +6,7 @@
 //! - Crossfade transitions
 //! - Combat music triggers
 //! - Day/night music variants
+//! - Adaptive stems that fade with game intensity
 
 use crate::biome::BiomeType;
 use serde::{Deserialize, Serialize};
@@ -80,6 +81,13 @@ impl MusicTrack {
         }
     }
 
+    /// Get the asset name (file stem) used by the audio backend.
+    #[must_use]
+    pub fn asset_name(self) -> Option<&'static str> {
+        let file = self.asset_path()?.rsplit('/').next()?;
+        Some(file.strip_suffix(".mp3").unwrap_or(file))
+    }
+
     /// Get the display name for this track.
     #[must_use]
     pub fn display_name(self) -> &'static str {
@@ -793,6 +801,206 @@ pub struct MusicPlaybackInfo {
     pub loops: bool,
 }
 
+/// A layer of an adaptive music track.
+///
+/// All stems of a track are the same length and play together; intensity
+/// only changes which of them are audible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MusicStem {
+    /// Always-on foundation.
+    Base,
+    /// Added when danger is nearby.
+    Tension,
+    /// Added during combat.
+    Combat,
+}
+
+impl MusicStem {
+    /// All stems, quietest layer first.
+    pub const ALL: [Self; 3] = [Self::Base, Self::Tension, Self::Combat];
+
+    /// Intensity at which this stem fades in.
+    #[must_use]
+    pub fn threshold(self) -> f32 {
+        match self {
+            Self::Base => 0.0,
+            Self::Tension => 0.35,
+            Self::Combat => 0.7,
+        }
+    }
+
+    /// Suffix appended to the track name to form the stem's asset name.
+    #[must_use]
+    pub fn suffix(self) -> &'static str {
+        match self {
+            Self::Base => "base",
+            Self::Tension => "tension",
+            Self::Combat => "combat",
+        }
+    }
+}
+
+/// Playback state of one stem.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StemLayer {
+    /// Which stem this is.
+    pub stem: MusicStem,
+    /// Asset name passed to the audio backend.
+    pub asset: String,
+    /// Current volume (0.0-1.0).
+    pub volume: f32,
+    /// Volume the stem is fading towards.
+    pub target_volume: f32,
+}
+
+/// Drives adaptive music made of synchronized stems.
+///
+/// The audio backend starts every stem at once and keeps them running for
+/// the life of the track, so they stay sample-aligned; the director only
+/// moves their volumes. Raising the intensity fades in the stems whose
+/// threshold it reaches, lowering it fades them back out.
+#[derive(Debug, Clone)]
+pub struct MusicDirector {
+    /// Stems in [`MusicStem::ALL`] order.
+    stems: Vec<StemLayer>,
+    /// Intensity requested by the game (0.0-1.0).
+    intensity: f32,
+    /// Seconds for a stem to fade fully in or out.
+    fade_time: f32,
+    /// How long combat keeps the intensity raised.
+    combat_hold: f32,
+    /// Remaining combat hold time.
+    combat_timer: f32,
+}
+
+impl MusicDirector {
+    /// Create a director for a track whose stems are named `{track}_{suffix}`.
+    #[must_use]
+    pub fn new(track: &str) -> Self {
+        let stems = MusicStem::ALL
+            .iter()
+            .map(|&stem| StemLayer {
+                stem,
+                asset: format!("{track}_{}", stem.suffix()),
+                volume: 0.0,
+                target_volume: 0.0,
+            })
+            .collect();
+        let mut director = Self {
+            stems,
+            intensity: 0.0,
+            fade_time: 2.0,
+            combat_hold: 8.0,
+            combat_timer: 0.0,
+        };
+        director.retarget();
+        director
+    }
+
+    /// Set how long a stem takes to fade fully in or out.
+    #[must_use]
+    pub fn with_fade_time(mut self, seconds: f32) -> Self {
+        self.fade_time = seconds.max(0.0);
+        self
+    }
+
+    /// Set how long combat keeps the combat stem up after the last hit.
+    #[must_use]
+    pub fn with_combat_hold(mut self, seconds: f32) -> Self {
+        self.combat_hold = seconds.max(0.0);
+        self
+    }
+
+    /// Set the game intensity (0.0 = calm, 1.0 = full combat).
+    pub fn set_intensity(&mut self, level: f32) {
+        self.intensity = level.clamp(0.0, 1.0);
+        self.retarget();
+    }
+
+    /// Get the requested intensity.
+    #[must_use]
+    pub fn intensity(&self) -> f32 {
+        self.intensity
+    }
+
+    /// Intensity after combat is taken into account.
+    #[must_use]
+    pub fn effective_intensity(&self) -> f32 {
+        if self.combat_timer > 0.0 {
+            self.intensity.max(MusicStem::Combat.threshold())
+        } else {
+            self.intensity
+        }
+    }
+
+    /// Raise the intensity to combat level for the combat hold time.
+    pub fn notify_combat(&mut self) {
+        self.combat_timer = self.combat_hold;
+        self.retarget();
+    }
+
+    /// Whether combat is currently holding the intensity up.
+    #[must_use]
+    pub fn in_combat(&self) -> bool {
+        self.combat_timer > 0.0
+    }
+
+    /// Advance fades and the combat hold.
+    pub fn update(&mut self, delta: f32) {
+        if self.combat_timer > 0.0 {
+            self.combat_timer = (self.combat_timer - delta).max(0.0);
+            self.retarget();
+        }
+
+        let step = if self.fade_time > 0.0 {
+            delta / self.fade_time
+        } else {
+            1.0
+        };
+        for layer in &mut self.stems {
+            if layer.volume < layer.target_volume {
+                layer.volume = (layer.volume + step).min(layer.target_volume);
+            } else {
+                layer.volume = (layer.volume - step).max(layer.target_volume);
+            }
+        }
+    }
+
+    /// Get all stems in [`MusicStem::ALL`] order.
+    #[must_use]
+    pub fn stems(&self) -> &[StemLayer] {
+        &self.stems
+    }
+
+    /// Get the current volume of a stem.
+    #[must_use]
+    pub fn stem_volume(&self, stem: MusicStem) -> f32 {
+        self.stems
+            .iter()
+            .find(|layer| layer.stem == stem)
+            .map_or(0.0, |layer| layer.volume)
+    }
+
+    /// Whether a stem is fading in or fully audible.
+    #[must_use]
+    pub fn is_stem_active(&self, stem: MusicStem) -> bool {
+        self.stems
+            .iter()
+            .any(|layer| layer.stem == stem && layer.target_volume > 0.0)
+    }
+
+    fn retarget(&mut self) {
+        let intensity = self.effective_intensity();
+        for layer in &mut self.stems {
+            layer.target_volume = if intensity >= layer.stem.threshold() {
+                1.0
+            } else {
+                0.0
+            };
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -813,6 +1021,9 @@ mod tests {
     #[test]
     fn test_music_track_none_has_no_path() {
         assert!(MusicTrack::None.asset_path().is_none());
+        assert!(MusicTrack::None.asset_name().is_none());
+        assert_eq!(MusicTrack::Forest.asset_name(), Some("forest"));
+        assert_eq!(MusicTrack::Menu.asset_name(), Some("menu_theme"));
     }
 
     #[test]
@@ -970,4 +1181,63 @@ mod tests {
         let effective = manager.effective_volume();
         assert!(effective <= 0.5);
     }
+
+    #[test]
+    fn test_music_director_raising_intensity_fades_in_stems() {
+        let mut director = MusicDirector::new("exploration").with_fade_time(1.0);
+        assert_eq!(director.stems()[1].asset, "exploration_tension");
+        assert!(director.is_stem_active(MusicStem::Base));
+        assert!(!director.is_stem_active(MusicStem::Tension));
+
+        director.update(0.5);
+        assert!((director.stem_volume(MusicStem::Base) - 0.5).abs() < 0.01);
+        assert!(director.stem_volume(MusicStem::Tension).abs() < f32::EPSILON);
+
+        director.set_intensity(0.5);
+        assert!(director.is_stem_active(MusicStem::Tension));
+        assert!(!director.is_stem_active(MusicStem::Combat));
+        director.update(0.5);
+        assert!((director.stem_volume(MusicStem::Base) - 1.0).abs() < 0.01);
+        assert!((director.stem_volume(MusicStem::Tension) - 0.5).abs() < 0.01);
+
+        director.set_intensity(1.0);
+        director.update(1.0);
+        for stem in MusicStem::ALL {
+            assert!((director.stem_volume(stem) - 1.0).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_music_director_lowering_intensity_fades_out_stems() {
+        let mut director = MusicDirector::new("forest").with_fade_time(2.0);
+        director.set_intensity(1.0);
+        director.update(2.0);
+
+        director.set_intensity(0.0);
+        director.update(1.0);
+        assert!((director.stem_volume(MusicStem::Combat) - 0.5).abs() < 0.01);
+        assert!((director.stem_volume(MusicStem::Tension) - 0.5).abs() < 0.01);
+        // The base stem never drops out
+        assert!((director.stem_volume(MusicStem::Base) - 1.0).abs() < 0.01);
+
+        director.update(1.0);
+        assert!(director.stem_volume(MusicStem::Combat).abs() < f32::EPSILON);
+        assert!(director.stem_volume(MusicStem::Tension).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_music_director_combat_hold() {
+        let mut director = MusicDirector::new("forest")
+            .with_fade_time(0.0)
+            .with_combat_hold(3.0);
+        director.notify_combat();
+        director.update(0.1);
+        assert!(director.in_combat());
+        assert!((director.stem_volume(MusicStem::Combat) - 1.0).abs() < f32::EPSILON);
+        assert!(director.intensity().abs() < f32::EPSILON);
+
+        director.update(3.0);
+        assert!(!director.in_combat());
+        assert!(director.stem_volume(MusicStem::Combat).abs() < f32::EPSILON);
+    }
 }
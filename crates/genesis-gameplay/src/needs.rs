@@ -1,7 +1,10 @@
 //! Survival needs system (hunger, thirst, energy).
 
+use genesis_common::ItemTypeId;
 use serde::{Deserialize, Serialize};
 
+use crate::inventory_ui::ItemMetadata;
+
 /// Effects that can be applied when needs are critical.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum NeedEffect {
@@ -162,6 +165,28 @@ impl Need {
     }
 }
 
+/// Outcome of consuming an item.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConsumeResult {
+    /// The item was eaten or drunk
+    Consumed {
+        /// Hunger actually restored (after capping at max)
+        hunger_restored: f32,
+        /// Thirst actually restored (after capping at max)
+        thirst_restored: f32,
+    },
+    /// The item has no nutrition or hydration
+    NotConsumable,
+}
+
+impl ConsumeResult {
+    /// Returns true if the item was consumed.
+    #[must_use]
+    pub fn is_consumed(&self) -> bool {
+        matches!(self, Self::Consumed { .. })
+    }
+}
+
 /// Collection of survival needs.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Needs {
@@ -220,6 +245,32 @@ impl Needs {
         self.thirst.restore(hydration);
     }
 
+    /// Consumes an item, restoring hunger and thirst from its metadata.
+    ///
+    /// Overeating or overdrinking is capped at each need's maximum; the
+    /// result reports how much was actually restored.
+    pub fn consume<M: ItemMetadata + ?Sized>(
+        &mut self,
+        item: ItemTypeId,
+        metadata: &M,
+    ) -> ConsumeResult {
+        let nutrition = metadata.get_nutrition(item).max(0.0);
+        let hydration = metadata.get_hydration(item).max(0.0);
+        if nutrition <= 0.0 && hydration <= 0.0 {
+            return ConsumeResult::NotConsumable;
+        }
+
+        let hunger_before = self.hunger.current();
+        let thirst_before = self.thirst.current();
+        self.eat(nutrition);
+        self.drink(hydration);
+
+        ConsumeResult::Consumed {
+            hunger_restored: self.hunger.current() - hunger_before,
+            thirst_restored: self.thirst.current() - thirst_before,
+        }
+    }
+
     /// Rests (restores energy).
     pub fn rest(&mut self, rest_amount: f32) {
         self.energy.restore(rest_amount);
@@ -533,4 +584,64 @@ mod tests {
         assert!(NeedStatus::Critical.is_problematic());
         assert!(NeedStatus::Depleted.is_problematic());
     }
+
+    fn food_metadata() -> crate::inventory_ui::SimpleItemMetadata {
+        let mut metadata = crate::inventory_ui::SimpleItemMetadata::new();
+        metadata.set_consumable(ItemTypeId::new(10), 25.0, 0.0);
+        metadata.set_consumable(ItemTypeId::new(11), 5.0, 30.0);
+        metadata
+    }
+
+    #[test]
+    fn test_consume_food_restores_hunger() {
+        let metadata = food_metadata();
+        let mut needs = Needs::new();
+        needs.hunger.set(50.0);
+
+        let result = needs.consume(ItemTypeId::new(10), &metadata);
+        assert_eq!(
+            result,
+            ConsumeResult::Consumed {
+                hunger_restored: 25.0,
+                thirst_restored: 0.0
+            }
+        );
+        assert!((needs.hunger.current() - 75.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_consume_caps_at_max() {
+        let metadata = food_metadata();
+        let mut needs = Needs::new();
+        needs.hunger.set(90.0);
+        needs.thirst.set(80.0);
+
+        let result = needs.consume(ItemTypeId::new(11), &metadata);
+        assert_eq!(
+            result,
+            ConsumeResult::Consumed {
+                hunger_restored: 5.0,
+                thirst_restored: 20.0
+            }
+        );
+        assert!(needs.hunger.is_full());
+        assert!(needs.thirst.is_full());
+
+        // Eating at full still counts as consumed but restores nothing
+        let result = needs.consume(ItemTypeId::new(10), &metadata);
+        assert!(result.is_consumed());
+        assert!(needs.hunger.is_full());
+    }
+
+    #[test]
+    fn test_consume_non_food() {
+        let metadata = food_metadata();
+        let mut needs = Needs::new();
+        needs.hunger.set(50.0);
+        assert_eq!(
+            needs.consume(ItemTypeId::new(1), &metadata),
+            ConsumeResult::NotConsumable
+        );
+        assert!((needs.hunger.current() - 50.0).abs() < f32::EPSILON);
+    }
 }
@@ -1,7 +1,9 @@
 //! NPC AI system with behavior trees.
 
 use crate::combat::{AttackIntent, AttackTarget, AttackType, CombatSystem};
-use genesis_common::EntityId;
+use crate::entity::Health;
+use crate::loot::LootTableId;
+use genesis_common::{EntityId, FactionId};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use thiserror::Error;
@@ -137,6 +139,15 @@ pub struct NPCState {
     pub behavior_time: f32,
     /// De-aggro timer
     pub deaggro_timer: f32,
+    /// Current and maximum health
+    #[serde(default)]
+    pub health: Health,
+    /// Faction allegiance (if any)
+    #[serde(default)]
+    pub faction: Option<FactionId>,
+    /// Loot table rolled when the NPC dies (if any)
+    #[serde(default)]
+    pub loot_table: Option<LootTableId>,
 }
 
 impl NPCState {
@@ -160,6 +171,9 @@ impl NPCState {
             patrol_index: 0,
             behavior_time: 0.0,
             deaggro_timer: 0.0,
+            health: Health::default(),
+            faction: None,
+            loot_table: None,
         }
     }
 
@@ -183,6 +197,13 @@ impl NPCState {
         self
     }
 
+    /// Sets the loot table rolled on death.
+    #[must_use]
+    pub const fn with_loot_table(mut self, table: LootTableId) -> Self {
+        self.loot_table = Some(table);
+        self
+    }
+
     /// Returns whether NPC is at home.
     #[must_use]
     pub fn is_at_home(&self) -> bool {
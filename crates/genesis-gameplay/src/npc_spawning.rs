@@ -22,6 +22,8 @@ pub struct NPCSpawnConfig {
     pub max_npcs_per_chunk: u32,
     /// Spawn chance (0.0-1.0) for each potential spawn point
     pub spawn_chance: f32,
+    /// Multiplier on the spawn chance of hostile NPCs (0.0 = no hostiles)
+    pub hostile_spawn_rate: f32,
 }
 
 impl Default for NPCSpawnConfig {
@@ -31,6 +33,7 @@ impl Default for NPCSpawnConfig {
             chunk_size: DEFAULT_CHUNK_SIZE,
             max_npcs_per_chunk: 5,
             spawn_chance: 0.3,
+            hostile_spawn_rate: 1.0,
         }
     }
 }
@@ -44,6 +47,13 @@ impl NPCSpawnConfig {
             ..Default::default()
         }
     }
+
+    /// Sets the hostile spawn rate multiplier.
+    #[must_use]
+    pub fn with_hostile_spawn_rate(mut self, rate: f32) -> Self {
+        self.hostile_spawn_rate = rate.max(0.0);
+        self
+    }
 }
 
 /// Data about a potential NPC spawn.
@@ -112,13 +122,17 @@ impl NPCChunkSpawner {
         // Determine number of potential spawn points
         let num_spawn_points = self.config.max_npcs_per_chunk as usize;
 
+        // Hostiles may roll above the base chance when their rate is raised
+        let hostile_chance = self.config.spawn_chance * self.config.hostile_spawn_rate;
+        let max_chance = self.config.spawn_chance.max(hostile_chance);
+
         for i in 0..num_spawn_points {
             // Advance RNG
             rng_state = Self::next_rng(rng_state);
 
             // Check spawn chance
             let roll = (rng_state % 1000) as f32 / 1000.0;
-            if roll > self.config.spawn_chance {
+            if roll > max_chance {
                 continue;
             }
 
@@ -134,6 +148,14 @@ impl NPCChunkSpawner {
             // Determine NPC type based on position and RNG
             rng_state = Self::next_rng(rng_state);
             let npc_type = Self::pick_npc_type(rng_state, chunk_pos, i);
+            let chance = if npc_type == NPCType::Hostile {
+                hostile_chance
+            } else {
+                self.config.spawn_chance
+            };
+            if roll > chance {
+                continue;
+            }
 
             spawns.push(NPCSpawnData {
                 npc_type,
@@ -336,4 +358,44 @@ mod tests {
         assert_eq!(count2, 0); // Second load should not spawn more
         assert_eq!(npc_manager.len(), count1);
     }
+
+    #[test]
+    fn test_hostile_spawn_rate() {
+        let hostiles = |rate: f32| -> usize {
+            let spawner =
+                NPCChunkSpawner::new(NPCSpawnConfig::with_seed(7).with_hostile_spawn_rate(rate));
+            (0..32)
+                .flat_map(|x| spawner.generate_spawn_data((x, 0)))
+                .filter(|spawn| spawn.npc_type == NPCType::Hostile)
+                .count()
+        };
+
+        assert_eq!(hostiles(0.0), 0);
+        assert!(hostiles(1.0) > 0);
+        assert!(hostiles(2.0) > hostiles(1.0));
+
+        // The default rate keeps the spawn layout from before hostile rates existed
+        let spawner = NPCChunkSpawner::new(NPCSpawnConfig::with_seed(7));
+        let layout = |chunk_pos| -> Vec<(NPCType, (f32, f32))> {
+            spawner
+                .generate_spawn_data(chunk_pos)
+                .into_iter()
+                .map(|spawn| (spawn.npc_type, spawn.position))
+                .collect()
+        };
+        assert_eq!(
+            layout((0, 0)),
+            vec![
+                (NPCType::Passive, (180.0, 175.0)),
+                (NPCType::Passive, (248.0, 163.0)),
+            ]
+        );
+        assert_eq!(
+            layout((-2, 5)),
+            vec![
+                (NPCType::Hostile, (-329.0, 1526.0)),
+                (NPCType::Passive, (-469.0, 1370.0)),
+            ]
+        );
+    }
 }
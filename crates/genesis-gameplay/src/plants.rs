@@ -6,6 +6,7 @@
 //! - Integration with grass cutting (grass regrowth)
 //! - Crop farming system
 
+use crate::loot::LootTableId;
 use genesis_common::{ItemTypeId, WorldCoord};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -142,6 +143,9 @@ pub struct PlantDefinition {
     pub seed_item: ItemTypeId,
     /// Whether this plant regrows after harvest.
     pub regrows_after_harvest: bool,
+    /// Extra drops rolled on a mature harvest (if any).
+    #[serde(default)]
+    pub loot_table: Option<LootTableId>,
 }
 
 impl PlantDefinition {
@@ -186,6 +190,7 @@ impl PlantDefinitionBuilder {
                 wilted_harvest_amount: 1,
                 seed_item: ItemTypeId::new(1),
                 regrows_after_harvest: false,
+                loot_table: None,
             },
         }
     }
@@ -246,6 +251,13 @@ impl PlantDefinitionBuilder {
         self
     }
 
+    /// Set the loot table rolled on a mature harvest.
+    #[must_use]
+    pub fn loot_table(mut self, table: LootTableId) -> Self {
+        self.def.loot_table = Some(table);
+        self
+    }
+
     /// Build the plant definition.
     #[must_use]
     pub fn build(self) -> PlantDefinition {
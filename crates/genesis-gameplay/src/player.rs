@@ -191,6 +191,34 @@ pub struct PlayerConfig {
     pub air_control: f32,
     /// Maximum interaction distance
     pub interaction_range: f32,
+    /// Dash burst speed in units per second
+    #[serde(default = "default_dash_speed")]
+    pub dash_speed: f32,
+    /// How long the dash burst lasts in seconds
+    #[serde(default = "default_dash_duration")]
+    pub dash_duration: f32,
+    /// Time before the player can dash again in seconds
+    #[serde(default = "default_dash_cooldown")]
+    pub dash_cooldown: f32,
+    /// Invulnerability window from the start of a dash in seconds
+    #[serde(default = "default_dash_iframes")]
+    pub dash_iframes: f32,
+}
+
+fn default_dash_speed() -> f32 {
+    450.0
+}
+
+fn default_dash_duration() -> f32 {
+    0.15
+}
+
+fn default_dash_cooldown() -> f32 {
+    0.8
+}
+
+fn default_dash_iframes() -> f32 {
+    0.25
 }
 
 impl Default for PlayerConfig {
@@ -205,6 +233,10 @@ impl Default for PlayerConfig {
             terminal_velocity: 500.0,
             air_control: 0.3,
             interaction_range: 64.0,
+            dash_speed: default_dash_speed(),
+            dash_duration: default_dash_duration(),
+            dash_cooldown: default_dash_cooldown(),
+            dash_iframes: default_dash_iframes(),
         }
     }
 }
@@ -233,6 +265,18 @@ pub struct TopDownPlayerConfig {
     pub collision_half_width: f32,
     /// Collision half-height
     pub collision_half_height: f32,
+    /// How strongly terrain slope changes speed (0 = ignore slope)
+    #[serde(default = "default_slope_factor")]
+    pub slope_factor: f32,
+}
+
+/// Slowest speed multiplier when walking uphill.
+const MIN_SLOPE_MULTIPLIER: f32 = 0.25;
+/// Fastest speed multiplier when walking downhill.
+const MAX_SLOPE_MULTIPLIER: f32 = 1.75;
+
+fn default_slope_factor() -> f32 {
+    1.0
 }
 
 impl Default for TopDownPlayerConfig {
@@ -247,6 +291,7 @@ impl Default for TopDownPlayerConfig {
             place_radius: 1.5,
             collision_half_width: 6.0,
             collision_half_height: 6.0,
+            slope_factor: default_slope_factor(),
         }
     }
 }
@@ -290,6 +335,31 @@ impl TopDownPlayerConfig {
         self.collision_half_height = half_height;
         self
     }
+
+    /// Set the slope factor.
+    #[must_use]
+    pub fn with_slope_factor(mut self, slope_factor: f32) -> Self {
+        self.slope_factor = slope_factor.max(0.0);
+        self
+    }
+
+    /// Scale a velocity by the terrain slope along its direction.
+    ///
+    /// `height_gradient` is the rise in height per world unit along X and Y
+    /// at the player's position. Moving uphill slows the player down and
+    /// moving downhill speeds them up; sideways movement is unaffected.
+    #[must_use]
+    pub fn apply_slope(&self, vel: Vec2, height_gradient: Vec2) -> Vec2 {
+        let speed = vel.length();
+        if speed <= f32::EPSILON {
+            return vel;
+        }
+        // Rise per unit moved along the direction of travel
+        let slope = vel.scale(1.0 / speed).dot(height_gradient);
+        let multiplier =
+            (1.0 - slope * self.slope_factor).clamp(MIN_SLOPE_MULTIPLIER, MAX_SLOPE_MULTIPLIER);
+        vel.scale(multiplier)
+    }
 }
 
 /// The player entity.
@@ -317,6 +387,21 @@ pub struct Player {
     jump_buffer: f32,
     /// Coyote time (allows jumping shortly after leaving ground)
     coyote_time: f32,
+    /// Remaining dash burst time
+    #[serde(default)]
+    dash_time: f32,
+    /// Remaining time until the next dash
+    #[serde(default)]
+    dash_cooldown: f32,
+    /// Remaining invulnerability time
+    #[serde(default)]
+    iframes: f32,
+    /// Terrain height gradient under the player (rise per unit along X/Y)
+    #[serde(skip)]
+    height_gradient: Vec2,
+    /// Top-down movement configuration used by [`Player::update`]
+    #[serde(skip)]
+    topdown_config: TopDownPlayerConfig,
 }
 
 #[allow(dead_code)]
@@ -343,6 +428,11 @@ impl Player {
             on_climbable: false,
             jump_buffer: 0.0,
             coyote_time: 0.0,
+            dash_time: 0.0,
+            dash_cooldown: 0.0,
+            iframes: 0.0,
+            height_gradient: Vec2::ZERO,
+            topdown_config: TopDownPlayerConfig::default(),
         }
     }
 
@@ -361,6 +451,11 @@ impl Player {
             on_climbable: false,
             jump_buffer: 0.0,
             coyote_time: 0.0,
+            dash_time: 0.0,
+            dash_cooldown: 0.0,
+            iframes: 0.0,
+            height_gradient: Vec2::ZERO,
+            topdown_config: TopDownPlayerConfig::default(),
         }
     }
 
@@ -415,6 +510,17 @@ impl Player {
         &mut self.config
     }
 
+    /// Get the top-down movement configuration.
+    #[must_use]
+    pub fn topdown_config(&self) -> &TopDownPlayerConfig {
+        &self.topdown_config
+    }
+
+    /// Set the top-down movement configuration.
+    pub fn set_topdown_config(&mut self, config: TopDownPlayerConfig) {
+        self.topdown_config = config;
+    }
+
     /// Check if the player is grounded.
     #[must_use]
     pub fn is_grounded(&self) -> bool {
@@ -451,12 +557,25 @@ impl Player {
     /// Update the player based on input.
     /// Uses pure top-down movement (no gravity or jumping).
     pub fn update(&mut self, input: &Input, dt: f32) {
+        self.tick_dash(dt);
+        if input.dash_just_pressed {
+            self.dash(input.movement);
+        }
+
+        // Dash burst overrides normal movement until it ends
+        if self.is_dashing() {
+            self.position = self.position.plus(self.velocity.scale(dt));
+            return;
+        }
+
         // Get movement speed based on running state
         let speed = if input.running { 200.0 } else { 120.0 };
 
-        // Direct 8-direction movement
+        // Direct 8-direction movement, slowed uphill and sped up downhill
         let input_dir = input.movement.normalized();
-        let target_velocity = input_dir.scale(speed);
+        let target_velocity = self
+            .topdown_config
+            .apply_slope(input_dir.scale(speed), self.height_gradient);
 
         // Smooth acceleration
         let accel = 12.0 * dt;
@@ -497,6 +616,71 @@ impl Player {
         };
     }
 
+    /// Start a dash in `dir` (or the facing direction if `dir` is zero).
+    ///
+    /// Sets the velocity to a burst of `dash_speed`, starts the cooldown and
+    /// grants `dash_iframes` seconds of invulnerability. Returns false if the
+    /// dash is still on cooldown.
+    pub fn dash(&mut self, dir: Vec2) -> bool {
+        if self.dash_cooldown > 0.0 {
+            return false;
+        }
+
+        let dir = if dir.length() > 0.0 {
+            dir.normalized()
+        } else {
+            self.facing.to_vec2()
+        };
+        if let Some(facing) = Direction::from_vec2(dir) {
+            self.facing = facing;
+        }
+
+        self.velocity = dir.scale(self.config.dash_speed);
+        self.dash_time = self.config.dash_duration;
+        self.dash_cooldown = self.config.dash_cooldown;
+        self.iframes = self.config.dash_iframes;
+        true
+    }
+
+    /// Whether a dash burst is in progress.
+    #[must_use]
+    pub fn is_dashing(&self) -> bool {
+        self.dash_time > 0.0
+    }
+
+    /// Set the terrain height gradient under the player.
+    ///
+    /// Movement is slowed uphill and sped up downhill along it, see
+    /// [`TopDownPlayerConfig::apply_slope`].
+    pub fn set_height_gradient(&mut self, gradient: Vec2) {
+        self.height_gradient = gradient;
+    }
+
+    /// Get the terrain height gradient under the player.
+    #[must_use]
+    pub fn height_gradient(&self) -> Vec2 {
+        self.height_gradient
+    }
+
+    /// Whether the player is currently immune to damage (dash i-frames).
+    #[must_use]
+    pub fn is_invulnerable(&self) -> bool {
+        self.iframes > 0.0
+    }
+
+    /// Seconds until the player can dash again.
+    #[must_use]
+    pub fn dash_cooldown_remaining(&self) -> f32 {
+        self.dash_cooldown
+    }
+
+    /// Count down dash, cooldown and i-frame timers.
+    fn tick_dash(&mut self, dt: f32) {
+        self.dash_time = (self.dash_time - dt).max(0.0);
+        self.dash_cooldown = (self.dash_cooldown - dt).max(0.0);
+        self.iframes = (self.iframes - dt).max(0.0);
+    }
+
     /// Update player state based on input and environment.
     /// NOTE: This is for platformer-style movement, currently unused.
     #[allow(dead_code)]
@@ -778,9 +962,15 @@ impl Player {
         topdown_config: &TopDownPlayerConfig,
         dt: f32,
     ) {
+        self.tick_dash(dt);
+        if input.dash_just_pressed {
+            self.dash(input.movement);
+        }
+        let dashing = self.is_dashing();
+
         // 1. Get input direction
         let input_dir = input.movement.normalized();
-        let has_input = input.has_movement();
+        let has_input = input.has_movement() && !dashing;
 
         // 2. Determine target speed
         let target_speed = if input.running {
@@ -791,18 +981,20 @@ impl Player {
 
         // 3. Calculate target velocity
         let target_velocity = if has_input {
-            input_dir.scale(target_speed)
+            topdown_config.apply_slope(input_dir.scale(target_speed), self.height_gradient)
         } else {
             Vec2::ZERO
         };
 
-        // 4. Apply acceleration towards target velocity
-        let accel = topdown_config.acceleration * dt;
-        self.velocity.x += (target_velocity.x - self.velocity.x) * accel;
-        self.velocity.y += (target_velocity.y - self.velocity.y) * accel;
+        // 4. Apply acceleration towards target velocity (the dash burst is kept as-is)
+        if !dashing {
+            let accel = topdown_config.acceleration * dt;
+            self.velocity.x += (target_velocity.x - self.velocity.x) * accel;
+            self.velocity.y += (target_velocity.y - self.velocity.y) * accel;
+        }
 
         // 5. Apply friction when no input
-        if !has_input {
+        if !has_input && !dashing {
             self.velocity.x *= topdown_config.friction;
             self.velocity.y *= topdown_config.friction;
 
@@ -1306,4 +1498,105 @@ mod tests {
         player.config_mut().walk_speed = 250.0;
         assert_eq!(player.config().walk_speed, 250.0);
     }
+
+    #[test]
+    fn test_topdown_slope_speed() {
+        let config = TopDownPlayerConfig::default();
+        let vel = Vec2::new(100.0, 0.0);
+        let flat = config.apply_slope(vel, Vec2::ZERO);
+        let uphill = config.apply_slope(vel, Vec2::new(0.3, 0.0));
+        let downhill = config.apply_slope(vel, Vec2::new(-0.3, 0.0));
+        let sideways = config.apply_slope(vel, Vec2::new(0.0, 0.3));
+
+        assert_eq!(flat, vel);
+        assert!(uphill.length() < flat.length());
+        assert!(downhill.length() > flat.length());
+        assert!((sideways.length() - flat.length()).abs() < 0.001);
+
+        // Very steep slopes are clamped rather than reversing direction
+        let cliff = config.apply_slope(vel, Vec2::new(10.0, 0.0));
+        assert!(cliff.x > 0.0);
+
+        let ignore = config.with_slope_factor(0.0);
+        assert_eq!(ignore.apply_slope(vel, Vec2::new(0.3, 0.0)), vel);
+    }
+
+    #[test]
+    fn test_update_applies_slope() {
+        let input = Input {
+            movement: Vec2::new(1.0, 0.0),
+            ..Default::default()
+        };
+        let run = |gradient: Vec2, config: TopDownPlayerConfig| {
+            let mut player = Player::new(Vec2::ZERO);
+            player.set_topdown_config(config);
+            player.set_height_gradient(gradient);
+            for _ in 0..60 {
+                player.update(&input, 1.0 / 60.0);
+            }
+            player.position().x
+        };
+
+        let flat = run(Vec2::ZERO, TopDownPlayerConfig::default());
+        assert!(run(Vec2::new(0.3, 0.0), TopDownPlayerConfig::default()) < flat);
+        assert!(run(Vec2::new(-0.3, 0.0), TopDownPlayerConfig::default()) > flat);
+
+        // The player's configured slope factor is used
+        let ignore_slope = TopDownPlayerConfig::default().with_slope_factor(0.0);
+        assert!((run(Vec2::new(0.3, 0.0), ignore_slope) - flat).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_dash_applies_burst_velocity() {
+        let mut player = Player::new(Vec2::ZERO);
+        let speed = player.config().dash_speed;
+
+        assert!(player.dash(Vec2::new(3.0, 4.0)));
+        assert!(player.is_dashing());
+        assert!((player.velocity().length() - speed).abs() < 0.01);
+        assert!((player.velocity().x - speed * 0.6).abs() < 0.01);
+
+        // No direction dashes the way the player is facing
+        let mut idle = Player::new(Vec2::ZERO);
+        assert!(idle.dash(Vec2::ZERO));
+        assert!((idle.velocity().y - speed).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_dash_cooldown() {
+        let mut player = Player::new(Vec2::ZERO);
+        let cooldown = player.config().dash_cooldown;
+        let input = Input::default();
+
+        assert!(player.dash(Vec2::RIGHT));
+        assert!(!player.dash(Vec2::RIGHT));
+
+        player.update(&input, cooldown * 0.5);
+        assert!(!player.dash(Vec2::RIGHT));
+        player.update(&input, cooldown * 0.5 + 0.01);
+        assert!(player.dash_cooldown_remaining() <= 0.0);
+        assert!(player.dash(Vec2::RIGHT));
+    }
+
+    #[test]
+    fn test_dash_grants_iframes() {
+        let mut player = Player::new(Vec2::ZERO);
+        let iframes = player.config().dash_iframes;
+        let input = Input::default();
+        assert!(!player.is_invulnerable());
+
+        let dash = Input {
+            movement: Vec2::LEFT,
+            dash_just_pressed: true,
+            ..Default::default()
+        };
+        player.update(&dash, 0.0);
+        assert!(player.is_invulnerable());
+        assert!(player.velocity().x < 0.0);
+
+        player.update(&input, iframes * 0.9);
+        assert!(player.is_invulnerable());
+        player.update(&input, iframes * 0.2);
+        assert!(!player.is_invulnerable());
+    }
 }
@@ -302,7 +302,7 @@ impl QuestTemplate {
 }
 
 /// Progress tracking for an active quest.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct QuestProgress {
     /// Quest ID being tracked
     pub quest_id: QuestId,
@@ -363,6 +363,17 @@ impl QuestProgress {
     }
 }
 
+/// Persisted quest state.
+///
+/// Templates are not saved; they are registered from game content on startup.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct QuestSaveData {
+    /// Progress of active quests, ordered by quest ID
+    pub active: Vec<QuestProgress>,
+    /// Completed quest IDs, in ascending order
+    pub completed: Vec<QuestId>,
+}
+
 /// Quest manager handling all quest operations.
 #[derive(Debug, Default)]
 pub struct QuestManager {
@@ -748,6 +759,30 @@ impl QuestManager {
     pub fn iter_active(&self) -> impl Iterator<Item = (&QuestId, &QuestProgress)> {
         self.active_quests.iter()
     }
+
+    /// Captures active and completed quests for saving.
+    #[must_use]
+    pub fn to_save_data(&self) -> QuestSaveData {
+        let mut active: Vec<QuestProgress> = self.active_quests.values().cloned().collect();
+        active.sort_by_key(|progress| progress.quest_id.0);
+
+        let mut completed: Vec<QuestId> = self.completed_quests.iter().copied().collect();
+        completed.sort_by_key(|id| id.0);
+
+        QuestSaveData { active, completed }
+    }
+
+    /// Restores active and completed quests from save data.
+    ///
+    /// Registered templates are kept; any current progress is replaced.
+    pub fn load_save_data(&mut self, data: &QuestSaveData) {
+        self.active_quests = data
+            .active
+            .iter()
+            .map(|progress| (progress.quest_id, progress.clone()))
+            .collect();
+        self.completed_quests = data.completed.iter().copied().collect();
+    }
 }
 
 /// Quest data formatted for UI display.
@@ -1390,4 +1425,41 @@ mod tests {
             .expect("Progress should exist");
         assert_eq!(progress.objective_progress[0], 3); // Capped at required
     }
+
+    #[test]
+    fn test_quest_save_data_roundtrip() {
+        let mut manager = QuestManager::new();
+        manager.register_quest(QuestTemplate::new(QuestId::new(1), "Intro", "Intro"));
+        manager.register_quest(
+            QuestTemplate::new(QuestId::new(2), "Hunt", "Hunt")
+                .with_objective(QuestObjective::kill(NPCType::Hostile, 5))
+                .with_objective(QuestObjective::collect(ItemTypeId::new(3), 10)),
+        );
+        manager
+            .start_quest(QuestId::new(1))
+            .expect("Start should succeed");
+        manager
+            .complete_quest(QuestId::new(1))
+            .expect("Complete should succeed");
+        manager
+            .start_quest(QuestId::new(2))
+            .expect("Start should succeed");
+        manager.on_enemy_killed(NPCType::Hostile);
+        manager.on_enemy_killed(NPCType::Hostile);
+        manager.on_item_collected(ItemTypeId::new(3), 4);
+
+        let data = manager.to_save_data();
+        assert_eq!(data.completed, vec![QuestId::new(1)]);
+        assert_eq!(data.active.len(), 1);
+
+        let mut restored = QuestManager::new();
+        restored.load_save_data(&data);
+
+        assert!(restored.is_completed(QuestId::new(1)));
+        let progress = restored
+            .get_progress(QuestId::new(2))
+            .expect("Progress should exist");
+        assert_eq!(progress.objective_progress, vec![2, 4]);
+        assert_eq!(restored.to_save_data(), data);
+    }
 }
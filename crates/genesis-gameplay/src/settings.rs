@@ -745,6 +745,80 @@ impl GameDifficulty {
     }
 }
 
+/// Difficulty preset chosen when starting a new game.
+///
+/// Each preset bundles concrete tuning values that [`Self::apply_to`]
+/// writes into [`GameplaySettings`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum DifficultyPreset {
+    /// No hostiles, slow needs, frequent saves.
+    Peaceful,
+    /// Baseline tuning.
+    #[default]
+    Normal,
+    /// More hostiles, faster needs, harder hits.
+    Hard,
+}
+
+impl DifficultyPreset {
+    /// Get all presets.
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        &[Self::Peaceful, Self::Normal, Self::Hard]
+    }
+
+    /// Get the matching difficulty level.
+    #[must_use]
+    pub const fn difficulty(&self) -> GameDifficulty {
+        match self {
+            Self::Peaceful => GameDifficulty::Peaceful,
+            Self::Normal => GameDifficulty::Normal,
+            Self::Hard => GameDifficulty::Hard,
+        }
+    }
+
+    /// Get hostile spawn rate multiplier.
+    #[must_use]
+    pub const fn hostile_spawn_rate(&self) -> f32 {
+        match self {
+            Self::Peaceful => 0.0,
+            Self::Normal => 1.0,
+            Self::Hard => 1.5,
+        }
+    }
+
+    /// Get need drain rate multiplier (hunger, thirst, energy).
+    #[must_use]
+    pub const fn need_drain_rate(&self) -> f32 {
+        match self {
+            Self::Peaceful => 0.5,
+            Self::Normal => 1.0,
+            Self::Hard => 1.5,
+        }
+    }
+
+    /// Get auto-save interval in minutes.
+    #[must_use]
+    pub const fn auto_save_interval(&self) -> u32 {
+        match self {
+            Self::Peaceful => 2,
+            Self::Normal => 5,
+            Self::Hard => 10,
+        }
+    }
+
+    /// Write this preset's parameters into gameplay settings.
+    pub fn apply_to(&self, config: &mut GameplaySettings) {
+        let difficulty = self.difficulty();
+        config.difficulty = difficulty;
+        config.hostile_spawn_rate = self.hostile_spawn_rate();
+        config.need_drain_rate = self.need_drain_rate();
+        config.player_damage_multiplier = difficulty.player_damage_multiplier();
+        config.enemy_damage_multiplier = difficulty.enemy_damage_multiplier();
+        config.auto_save_interval = self.auto_save_interval();
+    }
+}
+
 /// HUD visibility preset.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub enum HudVisibility {
@@ -761,9 +835,18 @@ pub enum HudVisibility {
 
 /// Gameplay settings.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct GameplaySettings {
     /// Game difficulty.
     pub difficulty: GameDifficulty,
+    /// Hostile spawn rate multiplier (0.0 = no hostiles).
+    pub hostile_spawn_rate: f32,
+    /// Need drain rate multiplier.
+    pub need_drain_rate: f32,
+    /// Damage multiplier for the player's attacks.
+    pub player_damage_multiplier: f32,
+    /// Damage multiplier for enemy attacks.
+    pub enemy_damage_multiplier: f32,
     /// Auto-save interval in minutes (0 = disabled).
     pub auto_save_interval: u32,
     /// Show tutorial prompts.
@@ -802,6 +885,10 @@ impl Default for GameplaySettings {
     fn default() -> Self {
         Self {
             difficulty: GameDifficulty::default(),
+            hostile_spawn_rate: 1.0,
+            need_drain_rate: 1.0,
+            player_damage_multiplier: 1.0,
+            enemy_damage_multiplier: 1.0,
             auto_save_interval: 5,
             show_tutorials: true,
             show_damage_numbers: true,
@@ -1062,6 +1149,42 @@ mod tests {
         assert!(!GameDifficulty::Hard.is_permadeath());
     }
 
+    #[test]
+    fn test_difficulty_presets_distinct() {
+        let applied: Vec<GameplaySettings> = DifficultyPreset::all()
+            .iter()
+            .map(|preset| {
+                let mut settings = GameplaySettings::default();
+                preset.apply_to(&mut settings);
+                settings
+            })
+            .collect();
+
+        for (i, a) in applied.iter().enumerate() {
+            for b in &applied[i + 1..] {
+                assert_ne!(a.difficulty, b.difficulty);
+                assert!((a.hostile_spawn_rate - b.hostile_spawn_rate).abs() > f32::EPSILON);
+                assert!((a.need_drain_rate - b.need_drain_rate).abs() > f32::EPSILON);
+                assert!(
+                    (a.player_damage_multiplier - b.player_damage_multiplier).abs() > f32::EPSILON
+                );
+                assert!(
+                    (a.enemy_damage_multiplier - b.enemy_damage_multiplier).abs() > f32::EPSILON
+                );
+                assert_ne!(a.auto_save_interval, b.auto_save_interval);
+            }
+        }
+
+        let mut normal = GameplaySettings::default();
+        DifficultyPreset::Normal.apply_to(&mut normal);
+        assert_eq!(normal, GameplaySettings::default());
+
+        let mut peaceful = GameplaySettings::default();
+        DifficultyPreset::Peaceful.apply_to(&mut peaceful);
+        assert!(peaceful.hostile_spawn_rate.abs() < f32::EPSILON);
+        assert!(!peaceful.difficulty.enemies_spawn());
+    }
+
     #[test]
     fn test_gameplay_settings_default() {
         let settings = GameplaySettings::default();
@@ -9,10 +9,12 @@
 
 use crate::ai::{AnimalType, MonsterType};
 use crate::biome::BiomeType;
+use crate::interaction::CellType;
 use crate::sound_events::{
     BlockSoundType, HitType, ItemSoundType, MonsterSoundType, QueuedSound, SoundEvent,
     SoundEventQueue, SurfaceType, WeaponSoundType,
 };
+use crate::terrain_manipulation::materials;
 use genesis_common::EntityId;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -342,6 +344,27 @@ pub fn biome_to_surface(biome: BiomeType) -> SurfaceType {
     }
 }
 
+/// Convert a cell material to the surface walked on (`None` for air).
+#[must_use]
+pub fn cell_to_surface(cell: CellType) -> Option<SurfaceType> {
+    match cell {
+        CellType::Dirt | CellType::Grass | CellType::CutGrass | CellType::Leaves => {
+            Some(SurfaceType::Grass)
+        },
+        CellType::Stone | CellType::Ore => Some(SurfaceType::Stone),
+        CellType::Sand => Some(SurfaceType::Sand),
+        CellType::Water => Some(SurfaceType::Water),
+        CellType::Wood => Some(SurfaceType::Wood),
+        CellType::Air | CellType::Custom(_) => None,
+    }
+}
+
+/// Distance walked per footstep, in world units (~2.5 steps/s at walk speed).
+pub const WALK_STRIDE_LENGTH: f32 = 40.0;
+
+/// Stride length multiplier while running.
+pub const RUN_STRIDE_MULTIPLIER: f32 = 1.4;
+
 /// Convert MonsterType from ai module to MonsterSoundType.
 #[must_use]
 pub fn monster_to_sound_type(monster: MonsterType) -> MonsterSoundType {
@@ -376,6 +399,10 @@ pub struct SoundTriggerSystem {
     entity_positions: HashMap<EntityId, [f32; 3]>,
     /// Whether sound triggers are enabled.
     enabled: bool,
+    /// Walking stride length in world units.
+    stride_length: f32,
+    /// Distance walked since the last footstep.
+    stride_progress: f32,
 }
 
 impl SoundTriggerSystem {
@@ -386,6 +413,8 @@ impl SoundTriggerSystem {
             player_context: PlayerSoundContext::new(),
             entity_positions: HashMap::new(),
             enabled: true,
+            stride_length: WALK_STRIDE_LENGTH,
+            stride_progress: 0.0,
         }
     }
 
@@ -436,6 +465,69 @@ impl SoundTriggerSystem {
         self.entity_positions.get(&entity).copied()
     }
 
+    /// Get the footstep sound key for a surface.
+    #[must_use]
+    pub fn footstep_for(surface: SurfaceType) -> &'static str {
+        match surface {
+            SurfaceType::Grass => "footstep_grass",
+            SurfaceType::Stone => "footstep_stone",
+            SurfaceType::Sand => "footstep_sand",
+            SurfaceType::Wood => "footstep_wood",
+            SurfaceType::Water => "footstep_water",
+            SurfaceType::Snow => "footstep_snow",
+            SurfaceType::Metal => "footstep_metal",
+        }
+    }
+
+    /// Get the sound key for a simulated material reaction, if it has one.
+    #[must_use]
+    pub fn reaction_sound(from: u16, to: u16) -> Option<&'static str> {
+        match (from, to) {
+            (materials::LAVA, materials::STONE) => Some("lava_hiss"),
+            _ => None,
+        }
+    }
+
+    /// Set the walking stride length in world units.
+    pub fn set_stride_length(&mut self, length: f32) {
+        self.stride_length = length.max(1.0);
+    }
+
+    /// Advance the stride by the distance the player moved.
+    ///
+    /// Returns true when a foot lands. Faster movement covers a stride in
+    /// less time, so the cadence follows movement speed; running uses a
+    /// longer stride. Large jumps (teleports) produce a single step.
+    pub fn advance_stride(&mut self, distance: f32) -> bool {
+        if !self.enabled || distance <= 0.0 {
+            return false;
+        }
+
+        let stride = if self.player_context.is_running {
+            self.stride_length * RUN_STRIDE_MULTIPLIER
+        } else {
+            self.stride_length
+        };
+        self.stride_progress += distance;
+        if self.stride_progress < stride {
+            return false;
+        }
+        self.stride_progress %= stride;
+        true
+    }
+
+    /// Queue a footstep for the current surface each time a stride completes.
+    pub fn update_footsteps(&mut self, queue: &mut SoundEventQueue, distance: f32) {
+        if self.advance_stride(distance) {
+            let action = if self.player_context.is_running {
+                PlayerAction::Run
+            } else {
+                PlayerAction::Walk
+            };
+            self.trigger_player(queue, action);
+        }
+    }
+
     /// Trigger player action sound.
     pub fn trigger_player(&self, queue: &mut SoundEventQueue, action: PlayerAction) {
         if !self.enabled {
@@ -954,4 +1046,62 @@ mod tests {
         assert_eq!(trigger.player_context.surface, Some(SurfaceType::Wood));
         assert_eq!(trigger.player_context.position, Some([5.0, 0.0, 5.0]));
     }
+
+    #[test]
+    fn test_footstep_for_surface() {
+        assert_eq!(
+            SoundTriggerSystem::footstep_for(SurfaceType::Grass),
+            "footstep_grass"
+        );
+        assert_eq!(
+            SoundTriggerSystem::footstep_for(SurfaceType::Sand),
+            "footstep_sand"
+        );
+        assert_eq!(
+            SoundTriggerSystem::footstep_for(SurfaceType::Stone),
+            "footstep_stone"
+        );
+        assert_eq!(
+            SoundTriggerSystem::footstep_for(SurfaceType::Water),
+            "footstep_water"
+        );
+
+        let surface = biome_to_surface(BiomeType::Desert);
+        assert_eq!(SoundTriggerSystem::footstep_for(surface), "footstep_sand");
+        let surface = cell_to_surface(CellType::Ore).expect("ore is walkable");
+        assert_eq!(SoundTriggerSystem::footstep_for(surface), "footstep_stone");
+        assert_eq!(cell_to_surface(CellType::Air), None);
+    }
+
+    #[test]
+    fn test_reaction_sound() {
+        assert_eq!(
+            SoundTriggerSystem::reaction_sound(materials::LAVA, materials::STONE),
+            Some("lava_hiss")
+        );
+        assert_eq!(
+            SoundTriggerSystem::reaction_sound(materials::SAND, materials::STONE),
+            None
+        );
+    }
+
+    #[test]
+    fn test_footsteps_follow_stride() {
+        let mut system = SoundTriggerSystem::new();
+        system.set_player_surface(SurfaceType::Sand);
+        let mut queue = SoundEventQueue::new();
+
+        // 2.5 strides at walking pace
+        for frame in 0..10 {
+            queue.set_time(f64::from(frame) * 0.1);
+            system.update_footsteps(&mut queue, WALK_STRIDE_LENGTH / 4.0);
+        }
+        assert_eq!(queue.len(), 2);
+
+        // Running stretches the stride
+        let mut runner = SoundTriggerSystem::new();
+        runner.update_player_context(PlayerSoundContext::new().with_running(true));
+        assert!(!runner.advance_stride(WALK_STRIDE_LENGTH));
+        assert!(runner.advance_stride(WALK_STRIDE_LENGTH));
+    }
 }
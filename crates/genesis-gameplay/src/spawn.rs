@@ -4,7 +4,7 @@
 //! and respawning after death.
 
 use crate::input::Vec2;
-use crate::inventory::Inventory;
+use crate::inventory::{Inventory, DEFAULT_HOTBAR_SIZE};
 use crate::player::Player;
 use serde::{Deserialize, Serialize};
 
@@ -137,7 +137,7 @@ impl SpawnSystem {
     #[must_use]
     pub fn create_default_inventory(&self) -> Inventory {
         #[allow(clippy::cast_possible_truncation)]
-        Inventory::new(self.inventory_size as u32)
+        Inventory::new(self.inventory_size as u32).with_hotbar(DEFAULT_HOTBAR_SIZE)
     }
 
     /// Respawns the player after death.
@@ -1,9 +1,11 @@
 //! Terrain manipulation system for dig/place operations.
 //!
 //! This module provides terrain modification capabilities including digging,
-//! placing, and filling cells with different materials.
+//! placing, and filling cells with different materials. Per-cell intents for
+//! the GPU kernel are queued in a bounded [`IntentBuffer`].
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Actions the player can perform on terrain.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -127,6 +129,8 @@ pub mod materials {
     pub const LEAVES: u16 = 7;
     /// Ore
     pub const ORE: u16 = 8;
+    /// Lava
+    pub const LAVA: u16 = 9;
 }
 
 /// Chunk manager trait for terrain access.
@@ -505,7 +509,7 @@ impl TerrainManipulator {
 }
 
 /// Intent for GPU kernel to apply terrain change.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct TerrainIntent {
     /// Action type
     pub action: IntentAction,
@@ -583,6 +587,127 @@ pub fn create_terrain_intents(action: TerrainAction, center: (i32, i32)) -> Vec<
     intents
 }
 
+/// Default number of intents an [`IntentBuffer`] holds.
+pub const DEFAULT_INTENT_CAPACITY: usize = 4096;
+
+/// Outcome of [`IntentBuffer::submit_bulk`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BulkSubmitResult {
+    /// Intents that took a new queue slot
+    pub queued: usize,
+    /// Intents that replaced an earlier intent for the same cell
+    pub coalesced: usize,
+    /// Intents rejected because the queue was full
+    pub dropped: usize,
+}
+
+impl BulkSubmitResult {
+    /// Whether every intent made it into the queue.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.dropped == 0
+    }
+}
+
+/// Bounded queue of terrain intents waiting for the GPU kernel.
+///
+/// Holds at most one intent per cell: a later intent for a queued cell
+/// replaces it (last wins) without taking another slot.
+#[derive(Debug, Clone)]
+pub struct IntentBuffer {
+    /// Queued intents in submission order
+    intents: Vec<TerrainIntent>,
+    /// Index into `intents` for each queued cell
+    by_cell: HashMap<(i32, i32), usize>,
+    /// Maximum number of queued intents
+    capacity: usize,
+}
+
+impl Default for IntentBuffer {
+    fn default() -> Self {
+        Self::new(DEFAULT_INTENT_CAPACITY)
+    }
+}
+
+impl IntentBuffer {
+    /// Create an empty buffer holding at most `capacity` intents.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            intents: Vec::new(),
+            by_cell: HashMap::new(),
+            capacity,
+        }
+    }
+
+    /// Maximum number of queued intents.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of queued intents.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.intents.len()
+    }
+
+    /// Whether the buffer is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.intents.is_empty()
+    }
+
+    /// Free slots left.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.capacity.saturating_sub(self.intents.len())
+    }
+
+    /// Queue one intent. Returns false if the queue was full.
+    pub fn submit(&mut self, intent: TerrainIntent) -> bool {
+        self.submit_bulk(std::slice::from_ref(&intent))
+            .is_complete()
+    }
+
+    /// Queue many intents, coalescing those for the same cell.
+    ///
+    /// Intents for cells already queued (or repeated within `intents`)
+    /// overwrite the earlier one in place. New cells are queued until the
+    /// buffer is full; the rest are counted in
+    /// [`BulkSubmitResult::dropped`] so the caller can retry them after the
+    /// next [`Self::drain`].
+    pub fn submit_bulk(&mut self, intents: &[TerrainIntent]) -> BulkSubmitResult {
+        let mut result = BulkSubmitResult::default();
+        for intent in intents {
+            let cell = (intent.x, intent.y);
+            if let Some(&index) = self.by_cell.get(&cell) {
+                self.intents[index] = *intent;
+                result.coalesced += 1;
+            } else if self.intents.len() < self.capacity {
+                self.by_cell.insert(cell, self.intents.len());
+                self.intents.push(*intent);
+                result.queued += 1;
+            } else {
+                result.dropped += 1;
+            }
+        }
+        result
+    }
+
+    /// Queued intents, in the order their cells were first submitted.
+    #[must_use]
+    pub fn intents(&self) -> &[TerrainIntent] {
+        &self.intents
+    }
+
+    /// Take all queued intents, emptying the buffer.
+    pub fn drain(&mut self) -> Vec<TerrainIntent> {
+        self.by_cell.clear();
+        std::mem::take(&mut self.intents)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -746,6 +871,68 @@ mod tests {
         assert_eq!(place.material, materials::STONE);
     }
 
+    #[test]
+    fn test_intent_buffer_coalesces_same_cell() {
+        let mut buffer = IntentBuffer::new(16);
+        let result = buffer.submit_bulk(&[
+            TerrainIntent::dig(1, 1),
+            TerrainIntent::place(2, 1, materials::STONE),
+            TerrainIntent::place(1, 1, materials::SAND),
+            TerrainIntent::dig(2, 1),
+        ]);
+        assert_eq!(
+            result,
+            BulkSubmitResult {
+                queued: 2,
+                coalesced: 2,
+                dropped: 0,
+            }
+        );
+        // Last write wins, first-seen order kept
+        assert_eq!(
+            buffer.intents(),
+            &[
+                TerrainIntent::place(1, 1, materials::SAND),
+                TerrainIntent::dig(2, 1)
+            ]
+        );
+
+        // Coalescing against already-queued cells takes no new slots
+        let brush = create_terrain_intents(TerrainAction::dig(2.0), (1, 1));
+        let result = buffer.submit_bulk(&brush);
+        assert_eq!(result.queued + result.coalesced, brush.len());
+        assert_eq!(result.coalesced, 2);
+        assert_eq!(buffer.len(), brush.len());
+    }
+
+    #[test]
+    fn test_intent_buffer_reports_overflow() {
+        let mut buffer = IntentBuffer::new(5);
+        assert!(buffer.submit(TerrainIntent::dig(0, 0)));
+
+        let batch: Vec<_> = (0..8).map(|x| TerrainIntent::dig(x, 0)).collect();
+        let result = buffer.submit_bulk(&batch);
+        assert_eq!(
+            result,
+            BulkSubmitResult {
+                queued: 4,
+                coalesced: 1,
+                dropped: 3,
+            }
+        );
+        assert!(!result.is_complete());
+        assert_eq!(buffer.remaining(), 0);
+        // A full queue still accepts updates to queued cells
+        assert!(buffer.submit(TerrainIntent::place(4, 0, materials::STONE)));
+        assert!(!buffer.submit(TerrainIntent::dig(9, 9)));
+
+        // Retrying the dropped tail after a drain succeeds
+        assert_eq!(buffer.drain().len(), 5);
+        assert!(buffer.is_empty());
+        assert!(buffer.submit_bulk(&batch[5..]).is_complete());
+        assert_eq!(buffer.len(), 3);
+    }
+
     #[test]
     fn test_modified_cell() {
         let old_cell = Cell::new(materials::STONE);
@@ -777,6 +777,17 @@ impl Season {
         }
     }
 
+    /// Get plant growth rate multiplier.
+    #[must_use]
+    pub fn growth_modifier(self) -> f32 {
+        match self {
+            Self::Spring => 1.25,
+            Self::Summer => 1.0,
+            Self::Fall => 0.6,
+            Self::Winter => 0.15,
+        }
+    }
+
     /// Get daylight hours.
     #[must_use]
     pub fn daylight_hours(self) -> u32 {
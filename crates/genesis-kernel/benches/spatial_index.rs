@@ -0,0 +1,112 @@
+//! Benchmarks comparing the quadtree and spatial hash broad phases.
+//!
+//! Run with `cargo bench -p genesis-kernel --bench spatial_index`.
+
+#![allow(missing_docs)]
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use genesis_kernel::quadtree::{QuadTree, Rect};
+use genesis_kernel::spatial_hash::SpatialHash;
+
+/// World extent used for all distributions.
+const WORLD_SIZE: f32 = 4096.0;
+/// Object size (roughly an NPC body).
+const OBJECT_SIZE: f32 = 24.0;
+/// Query extent (roughly an interaction radius).
+const QUERY_SIZE: f32 = 96.0;
+
+/// Small deterministic LCG so runs are comparable.
+struct Lcg(u32);
+
+impl Lcg {
+    fn next_f32(&mut self) -> f32 {
+        self.0 = self.0.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        (self.0 >> 8) as f32 / (1u32 << 24) as f32
+    }
+}
+
+/// Objects spread evenly over the whole world.
+fn uniform(count: usize) -> Vec<Rect> {
+    let mut rng = Lcg(1);
+    (0..count)
+        .map(|_| {
+            let x = rng.next_f32() * (WORLD_SIZE - OBJECT_SIZE);
+            let y = rng.next_f32() * (WORLD_SIZE - OBJECT_SIZE);
+            Rect::new(x, y, OBJECT_SIZE, OBJECT_SIZE)
+        })
+        .collect()
+}
+
+/// Objects packed into a handful of tight clusters.
+fn clustered(count: usize) -> Vec<Rect> {
+    let mut rng = Lcg(2);
+    let centers: Vec<(f32, f32)> = (0..8)
+        .map(|_| {
+            (
+                rng.next_f32() * 3500.0 + 256.0,
+                rng.next_f32() * 3500.0 + 256.0,
+            )
+        })
+        .collect();
+    (0..count)
+        .map(|i| {
+            let (cx, cy) = centers[i % centers.len()];
+            let x = cx + (rng.next_f32() - 0.5) * 256.0;
+            let y = cy + (rng.next_f32() - 0.5) * 256.0;
+            Rect::new(x, y, OBJECT_SIZE, OBJECT_SIZE)
+        })
+        .collect()
+}
+
+fn bench_build_and_query(c: &mut Criterion) {
+    let mut group = c.benchmark_group("spatial_index");
+
+    for (name, objects) in [
+        ("uniform", uniform(10_000)),
+        ("clustered", clustered(10_000)),
+    ] {
+        group.bench_with_input(
+            BenchmarkId::new("quadtree", name),
+            &objects,
+            |b, objects| {
+                b.iter(|| {
+                    let mut tree =
+                        QuadTree::new(Rect::new(0.0, 0.0, WORLD_SIZE, WORLD_SIZE), 16, 10);
+                    for (i, rect) in objects.iter().enumerate() {
+                        tree.insert(*rect, i);
+                    }
+                    let mut hits = 0;
+                    for rect in objects {
+                        let query = Rect::from_center(rect.x, rect.y, QUERY_SIZE, QUERY_SIZE);
+                        hits += tree.query(query).len();
+                    }
+                    black_box(hits)
+                });
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("spatial_hash", name),
+            &objects,
+            |b, objects| {
+                b.iter(|| {
+                    let mut hash = SpatialHash::new(OBJECT_SIZE * 2.0);
+                    for (i, rect) in objects.iter().enumerate() {
+                        hash.insert(*rect, i);
+                    }
+                    let mut hits = 0;
+                    for rect in objects {
+                        let query = Rect::from_center(rect.x, rect.y, QUERY_SIZE, QUERY_SIZE);
+                        hits += hash.query_range(query).len();
+                    }
+                    black_box(hits)
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_build_and_query);
+criterion_main!(benches);
@@ -80,23 +80,26 @@
 
 // Re-export the backend module's public API
 pub use crate::audio_backend::{
-    AudioConfig, AudioDevice, AudioEngine, AudioError, AudioResult, AudioSinkPool, CachedBuffer,
-    DistanceModel, Environment, Listener, SoundBufferId, SoundCategory, SoundHandle,
-    DEFAULT_CHANNELS, DEFAULT_SAMPLE_RATE, MAX_CACHED_BUFFERS, MAX_SINKS,
+    AudioConfig, AudioDevice, AudioDeviceProvider, AudioEngine, AudioError, AudioResult,
+    AudioSinkPool, CachedBuffer, DeviceChange, DeviceMonitor, DistanceModel, Environment, Listener,
+    SoundBufferId, SoundCategory, SoundHandle, SystemDeviceProvider, DEFAULT_CHANNELS,
+    DEFAULT_SAMPLE_RATE, MAX_CACHED_BUFFERS, MAX_SINKS,
 };
 
 // Re-export spatial audio types
 pub use crate::audio_spatial::{
-    AttenuationModel, AudioEnvironment, EnvironmentParams, ListenerData, SoundSourceData,
-    SpatialAudioProcessor, SpatialParams, DEFAULT_MAX_DISTANCE, DEFAULT_REFERENCE_DISTANCE,
-    MIN_AUDIBLE_VOLUME, SPEED_OF_SOUND,
+    AttenuationModel, AudioEnvironment, DistanceFilter, EnvironmentParams, ListenerData,
+    LowPassCutoff, LowPassSource, SoundSourceData, SpatialAudioProcessor, SpatialParams,
+    DEFAULT_MAX_DISTANCE, DEFAULT_REFERENCE_DISTANCE, FULL_RANGE_CUTOFF, MIN_AUDIBLE_VOLUME,
+    SPEED_OF_SOUND,
 };
 
 // Re-export resource types
 pub use crate::audio_resource::{
-    AudioBuffer, AudioBufferCache, AudioCategory, AudioControls, AudioHandle, AudioSource,
-    AudioSourceType, BufferId, HandleGenerator, PlaybackState, PlayingSound, VolumeSettings,
-    DEFAULT_CROSSFADE_DURATION, MAX_CACHEABLE_SIZE,
+    AudioBuffer, AudioBufferCache, AudioCategory, AudioControls, AudioHandle, AudioMemoryUsage,
+    AudioResource, AudioSource, AudioSourceType, BufferId, HandleGenerator, PlaybackState,
+    PlayingSound, StreamStats, StreamingSource, VolumeSettings, DEFAULT_CROSSFADE_DURATION,
+    MAX_CACHEABLE_SIZE, STREAM_CHUNK_SAMPLES,
 };
 
 // Legacy compatibility: re-export the old module for existing code
@@ -4,6 +4,7 @@
 //! It includes:
 //!
 //! - `AudioDevice`: Wrapper around rodio's output stream
+//! - `DeviceMonitor`: Output device hot-swap detection with fallback
 //! - `AudioSinkPool`: Pool of sinks for managing multiple simultaneous sounds
 //! - Streaming support for MP3/WAV/FLAC audio files
 //! - Sample caching for frequently-used sound effects
@@ -47,19 +48,22 @@ use std::collections::HashMap;
 use std::io::{BufReader, Cursor};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 
 use parking_lot::{Mutex, RwLock};
-use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source};
+use rodio::cpal::traits::HostTrait;
+use rodio::{Decoder, DeviceTrait, OutputStream, OutputStreamHandle, Sink, Source};
 use thiserror::Error;
 use tracing::{debug, error, info, warn};
 
 use crate::audio_resource::{
-    AudioBufferCache, AudioCategory, AudioControls, AudioHandle, AudioSource, BufferId,
-    HandleGenerator, VolumeSettings,
+    AudioBufferCache, AudioCategory, AudioControls, AudioHandle, AudioMemoryUsage, AudioSource,
+    AudioSourceType, BufferId, HandleGenerator, StreamStats, StreamingSource, VolumeSettings,
 };
 use crate::audio_spatial::{
-    AudioEnvironment, EnvironmentParams, SoundSourceData, SpatialAudioProcessor, SpatialParams,
+    AudioEnvironment, DistanceFilter, EnvironmentParams, LowPassCutoff, LowPassSource,
+    SoundSourceData, SpatialAudioProcessor, SpatialParams, FULL_RANGE_CUTOFF,
 };
 
 // Re-export commonly used types from submodules
@@ -202,11 +206,14 @@ pub struct AudioDevice {
     handle: OutputStreamHandle,
     /// Whether the device is active.
     active: AtomicBool,
+    /// Name of the output device, if known.
+    name: Option<String>,
 }
 
 impl std::fmt::Debug for AudioDevice {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("AudioDevice")
+            .field("name", &self.name)
             .field("active", &self.active.load(Ordering::Relaxed))
             .finish_non_exhaustive()
     }
@@ -217,6 +224,9 @@ impl AudioDevice {
     pub fn new() -> AudioResult<Self> {
         let (stream, handle) =
             OutputStream::try_default().map_err(|e| AudioError::DeviceInitFailed(e.to_string()))?;
+        let name = rodio::cpal::default_host()
+            .default_output_device()
+            .and_then(|d| d.name().ok());
 
         info!("Audio device initialized");
 
@@ -224,9 +234,32 @@ impl AudioDevice {
             _stream: stream,
             handle,
             active: AtomicBool::new(true),
+            name,
         })
     }
 
+    /// Create an audio device on a specific cpal output device.
+    pub fn from_cpal(device: &rodio::Device) -> AudioResult<Self> {
+        let (stream, handle) = OutputStream::try_from_device(device)
+            .map_err(|e| AudioError::DeviceInitFailed(e.to_string()))?;
+        let name = device.name().ok();
+
+        info!("Audio device initialized: {:?}", name);
+
+        Ok(Self {
+            _stream: stream,
+            handle,
+            active: AtomicBool::new(true),
+            name,
+        })
+    }
+
+    /// Name of the output device, if known.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
     /// Get a reference to the output stream handle.
     #[must_use]
     pub fn handle(&self) -> &OutputStreamHandle {
@@ -245,6 +278,164 @@ impl AudioDevice {
     }
 }
 
+/// Source of output devices, abstracted so hot-swap handling can be tested.
+pub trait AudioDeviceProvider {
+    /// Opened device type.
+    type Device;
+
+    /// Names of the currently connected output devices.
+    fn output_devices(&self) -> Vec<String>;
+
+    /// Name of the system default output device, if any.
+    fn default_output(&self) -> Option<String>;
+
+    /// Open the output device with the given name.
+    fn open(&self, name: &str) -> AudioResult<Self::Device>;
+}
+
+/// Device provider backed by the system audio host.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemDeviceProvider;
+
+impl AudioDeviceProvider for SystemDeviceProvider {
+    type Device = AudioDevice;
+
+    fn output_devices(&self) -> Vec<String> {
+        rodio::cpal::default_host()
+            .output_devices()
+            .map(|devices| devices.filter_map(|d| d.name().ok()).collect())
+            .unwrap_or_default()
+    }
+
+    fn default_output(&self) -> Option<String> {
+        rodio::cpal::default_host()
+            .default_output_device()
+            .and_then(|d| d.name().ok())
+    }
+
+    fn open(&self, name: &str) -> AudioResult<AudioDevice> {
+        let device = rodio::cpal::default_host()
+            .output_devices()
+            .map_err(|e| AudioError::DeviceInitFailed(e.to_string()))?
+            .find(|d| d.name().ok().as_deref() == Some(name))
+            .ok_or(AudioError::NoDevice)?;
+        AudioDevice::from_cpal(&device)
+    }
+}
+
+/// A change in the active output device detected by [`DeviceMonitor::poll`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeviceChange {
+    /// Output moved to a new device (after a disconnect, or once one appeared).
+    Switched {
+        /// Device that was lost, if there was one.
+        lost: Option<String>,
+        /// Device now in use.
+        current: String,
+    },
+    /// The active device disappeared and no fallback could be opened.
+    Lost {
+        /// Device that was lost.
+        lost: String,
+    },
+}
+
+/// Tracks the active output device and reopens a fallback when it disappears.
+#[derive(Debug)]
+pub struct DeviceMonitor<P: AudioDeviceProvider> {
+    /// Device source.
+    provider: P,
+    /// Name of the active device.
+    current: Option<String>,
+    /// The opened active device.
+    device: Option<P::Device>,
+}
+
+impl<P: AudioDeviceProvider> DeviceMonitor<P> {
+    /// Open the default device (or the first one that works) and start monitoring it.
+    pub fn open_default(provider: P) -> AudioResult<Self> {
+        let mut monitor = Self {
+            provider,
+            current: None,
+            device: None,
+        };
+        if monitor.open_fallback() {
+            Ok(monitor)
+        } else {
+            Err(AudioError::NoDevice)
+        }
+    }
+
+    /// Name of the active device.
+    #[must_use]
+    pub fn current(&self) -> Option<&str> {
+        self.current.as_deref()
+    }
+
+    /// The opened active device.
+    #[must_use]
+    pub fn device(&self) -> Option<&P::Device> {
+        self.device.as_ref()
+    }
+
+    /// The device provider.
+    #[must_use]
+    pub fn provider(&self) -> &P {
+        &self.provider
+    }
+
+    /// Check whether the active device is still connected.
+    ///
+    /// If it was disconnected (or there was none), tries the default device
+    /// first and then any other connected device.
+    pub fn poll(&mut self) -> Option<DeviceChange> {
+        let connected = self.provider.output_devices();
+        if let Some(current) = &self.current {
+            if connected.contains(current) {
+                return None;
+            }
+        } else if connected.is_empty() {
+            return None;
+        }
+
+        let lost = self.current.take();
+        self.device = None;
+        if let Some(name) = &lost {
+            warn!("Audio output device disconnected: {}", name);
+        }
+
+        if self.open_fallback() {
+            let current = self.current.clone().unwrap_or_default();
+            Some(DeviceChange::Switched { lost, current })
+        } else {
+            lost.map(|lost| DeviceChange::Lost { lost })
+        }
+    }
+
+    /// Open the default device, falling back to any connected device.
+    fn open_fallback(&mut self) -> bool {
+        let mut candidates: Vec<String> = self.provider.default_output().into_iter().collect();
+        for name in self.provider.output_devices() {
+            if !candidates.contains(&name) {
+                candidates.push(name);
+            }
+        }
+
+        for name in candidates {
+            match self.provider.open(&name) {
+                Ok(device) => {
+                    info!("Audio output device: {}", name);
+                    self.device = Some(device);
+                    self.current = Some(name);
+                    return true;
+                },
+                Err(e) => warn!("Failed to open audio device {}: {}", name, e),
+            }
+        }
+        false
+    }
+}
+
 /// State of an audio sink in the pool.
 pub(crate) struct SinkState {
     /// The rodio sink.
@@ -487,6 +678,10 @@ struct PlayingSoundData {
     world_position: Option<(f32, f32)>,
     /// Calculated spatial parameters.
     spatial_params: Option<SpatialParams>,
+    /// Statistics of the stream feeding the sink (if streamed).
+    stream_stats: Option<Arc<StreamStats>>,
+    /// Distance low-pass cutoff of the sink (if spatial).
+    lowpass: Option<LowPassCutoff>,
 }
 
 /// The main audio engine.
@@ -498,9 +693,8 @@ struct PlayingSoundData {
 /// - Volume mixing by category
 #[derive(Debug)]
 pub struct AudioEngine {
-    /// Audio device (must be kept alive for audio to work).
-    #[allow(dead_code)]
-    device: AudioDevice,
+    /// Audio device (must be kept alive for audio to work) and hot-swap monitor.
+    devices: DeviceMonitor<SystemDeviceProvider>,
     /// Sink pool.
     pool: AudioSinkPool,
     /// Buffer cache.
@@ -527,8 +721,9 @@ impl AudioEngine {
 
     /// Create a new audio engine with the given configuration.
     pub fn new(config: AudioConfig) -> AudioResult<Self> {
-        let device = AudioDevice::new()?;
-        let pool = AudioSinkPool::new(&device, config.max_sounds)?;
+        let devices = DeviceMonitor::open_default(SystemDeviceProvider)?;
+        let device = devices.device().ok_or(AudioError::NoDevice)?;
+        let pool = AudioSinkPool::new(device, config.max_sounds)?;
 
         let mut spatial = SpatialAudioProcessor::new();
         spatial.set_doppler_enabled(config.doppler_enabled);
@@ -537,7 +732,7 @@ impl AudioEngine {
         info!("Audio engine initialized with {} sinks", pool.capacity());
 
         Ok(Self {
-            device,
+            devices,
             pool,
             cache: RwLock::new(AudioBufferCache::new()),
             handle_gen: HandleGenerator::new(),
@@ -561,6 +756,123 @@ impl AudioEngine {
         &self.config
     }
 
+    /// Name of the output device in use.
+    #[must_use]
+    pub fn device_name(&self) -> Option<&str> {
+        self.devices.current()
+    }
+
+    /// Detect output device disconnection and move playback to a fallback device.
+    ///
+    /// Enumerates devices, so call it periodically (e.g. once a second) rather
+    /// than every frame. When the device changes, the sink pool is rebuilt on the
+    /// new device and playing sounds are restarted with their volume, speed and
+    /// pause state; playback position is not preserved.
+    pub fn poll_device_changes(&mut self) -> Option<DeviceChange> {
+        let change = self.devices.poll()?;
+
+        match &change {
+            DeviceChange::Switched { current, .. } => {
+                let pool = self
+                    .devices
+                    .device()
+                    .map(|device| AudioSinkPool::new(device, self.config.max_sounds));
+                match pool {
+                    Some(Ok(pool)) => {
+                        let old_pool = std::mem::replace(&mut self.pool, pool);
+                        self.restore_playing(&old_pool);
+                        self.initialized.store(true, Ordering::Relaxed);
+                        info!("Audio moved to device: {}", current);
+                    },
+                    Some(Err(e)) => {
+                        error!("Failed to rebuild audio sinks on {}: {}", current, e);
+                        self.initialized.store(false, Ordering::Relaxed);
+                    },
+                    None => self.initialized.store(false, Ordering::Relaxed),
+                }
+            },
+            DeviceChange::Lost { lost } => {
+                warn!("Audio device {} lost with no fallback available", lost);
+                self.initialized.store(false, Ordering::Relaxed);
+            },
+        }
+
+        Some(change)
+    }
+
+    /// Restart tracked sounds on the current pool after a device switch.
+    fn restore_playing(&self, old_pool: &AudioSinkPool) {
+        let cache = self.cache.read();
+        let mut playing = self.playing.write();
+        let mut dropped = Vec::new();
+
+        for (handle, data) in playing.iter_mut() {
+            let (volume, speed, paused) = old_pool
+                .with_sink_idx(data.sink_idx, |state| {
+                    (state.volume, state.speed, state.sink.is_paused())
+                })
+                .unwrap_or((
+                    data.source.controls.volume,
+                    data.source.controls.speed,
+                    false,
+                ));
+            let category = data.source.category;
+            let looping = data.source.controls.looping;
+
+            let Some(sink_idx) = self.pool.acquire(*handle, category) else {
+                dropped.push(*handle);
+                continue;
+            };
+            let effective_vol = self.effective_volume(category) * volume;
+
+            let restarted = match &data.source.source_type {
+                AudioSourceType::Buffer(buffer_id) => cache.get(*buffer_id).map(|buffer| {
+                    let source = rodio::buffer::SamplesBuffer::new(
+                        buffer.channels,
+                        buffer.sample_rate,
+                        (*buffer.samples).clone(),
+                    );
+                    self.pool.with_sink_idx(sink_idx, |state| {
+                        append_samples(&state.sink, source, looping, data.lowpass.as_ref());
+                    });
+                }),
+                AudioSourceType::Stream { path, .. } => {
+                    StreamingSource::open(path).ok().map(|source| {
+                        let source = source.with_looping(looping);
+                        data.stream_stats = Some(source.stats());
+                        self.pool
+                            .with_sink_idx(sink_idx, |state| state.sink.append(source));
+                    })
+                },
+            };
+
+            if restarted.is_none() {
+                self.pool.release(*handle);
+                dropped.push(*handle);
+                continue;
+            }
+
+            self.pool.with_sink_idx(sink_idx, |state| {
+                state.volume = volume;
+                state.speed = speed;
+                state.category = category;
+                state.sink.set_volume(effective_vol);
+                state.sink.set_speed(speed);
+                if paused {
+                    state.sink.pause();
+                } else {
+                    state.sink.play();
+                }
+            });
+            data.sink_idx = sink_idx;
+        }
+
+        for handle in dropped {
+            debug!("Dropped sound {:?} during device switch", handle);
+            playing.remove(&handle);
+        }
+    }
+
     // ============================================
     // Buffer/Sound Loading
     // ============================================
@@ -651,6 +963,20 @@ impl AudioEngine {
         (cache.count(), cache.total_size())
     }
 
+    /// Get memory usage, split into cached buffers and active streams.
+    #[must_use]
+    pub fn memory_usage(&self) -> AudioMemoryUsage {
+        let resident = AudioMemoryUsage {
+            resident_bytes: self.cache.read().total_size(),
+            streamed_bytes: 0,
+        };
+        self.playing
+            .read()
+            .values()
+            .filter_map(|data| data.stream_stats.as_ref())
+            .fold(resident, |usage, stats| usage + stats.memory_usage())
+    }
+
     // ============================================
     // Playback
     // ============================================
@@ -720,6 +1046,8 @@ impl AudioEngine {
                 is_spatial: false,
                 world_position: None,
                 spatial_params: None,
+                stream_stats: None,
+                lowpass: None,
             },
         );
 
@@ -778,6 +1106,9 @@ impl AudioEngine {
             (*buffer.samples).clone(),
         );
 
+        let lowpass =
+            LowPassCutoff::new(spatial_params.lowpass_cutoff.unwrap_or(FULL_RANGE_CUTOFF));
+
         // Apply spatial parameters
         self.pool.with_sink_idx(sink_idx, |state| {
             state.volume = spatial_params.mono_volume;
@@ -788,11 +1119,7 @@ impl AudioEngine {
             state.sink.set_volume(effective_vol);
             state.sink.set_speed(spatial_params.pitch * controls.speed);
 
-            if controls.looping {
-                state.sink.append(source.repeat_infinite());
-            } else {
-                state.sink.append(source);
-            }
+            append_samples(&state.sink, source, controls.looping, Some(&lowpass));
             state.sink.play();
         });
 
@@ -809,6 +1136,8 @@ impl AudioEngine {
                 is_spatial: true,
                 world_position: Some((x, y)),
                 spatial_params: Some(spatial_params),
+                stream_stats: None,
+                lowpass: Some(lowpass),
             },
         );
 
@@ -822,23 +1151,17 @@ impl AudioEngine {
     }
 
     /// Stream music with controls.
+    ///
+    /// Looping tracks reopen the file at the end, so only one decoded chunk
+    /// is ever held in memory.
     pub fn play_music_with_controls(
         &self,
         path: impl AsRef<Path>,
         controls: AudioControls,
     ) -> AudioResult<AudioHandle> {
         let path = path.as_ref();
-
-        let file = std::fs::File::open(path).map_err(|e| AudioError::LoadFailed {
-            path: path.to_path_buf(),
-            message: e.to_string(),
-        })?;
-
-        let reader = BufReader::new(file);
-        let source = Decoder::new(reader).map_err(|e| AudioError::LoadFailed {
-            path: path.to_path_buf(),
-            message: e.to_string(),
-        })?;
+        let source = StreamingSource::open(path)?.with_looping(controls.looping);
+        let stream_stats = source.stats();
 
         let handle = self.handle_gen.next();
         let category = AudioCategory::Music;
@@ -859,13 +1182,7 @@ impl AudioEngine {
             state.sink.set_volume(effective_vol);
             state.sink.set_speed(controls.speed);
 
-            if controls.looping {
-                // For streaming, we need to reload - for now just play once
-                // TODO: Implement proper music looping with file reload
-                state.sink.append(source.convert_samples::<f32>());
-            } else {
-                state.sink.append(source.convert_samples::<f32>());
-            }
+            state.sink.append(source);
             state.sink.play();
         });
 
@@ -881,6 +1198,8 @@ impl AudioEngine {
                 is_spatial: false,
                 world_position: None,
                 spatial_params: None,
+                stream_stats: Some(stream_stats),
+                lowpass: None,
             },
         );
 
@@ -1048,6 +1367,11 @@ impl AudioEngine {
         self.spatial.read().environment_params()
     }
 
+    /// Set the distance low-pass filter (`None` disables it).
+    pub fn set_distance_filter(&self, filter: Option<DistanceFilter>) {
+        self.spatial.write().set_distance_filter(filter);
+    }
+
     /// Update spatial audio for all playing sounds.
     ///
     /// Call this each frame to update volume and panning for spatial sounds.
@@ -1083,6 +1407,9 @@ impl AudioEngine {
                     state.sink.set_volume(effective);
                     state.sink.set_speed(params.pitch * state.speed);
                 });
+                if let Some(lowpass) = &data.lowpass {
+                    lowpass.set(params.lowpass_cutoff.unwrap_or(FULL_RANGE_CUTOFF));
+                }
 
                 data.spatial_params = Some(params);
             }
@@ -1145,6 +1472,23 @@ impl AudioEngine {
     }
 }
 
+/// Queue decoded samples on a sink, looped and low-pass filtered as asked.
+fn append_samples(
+    sink: &Sink,
+    source: rodio::buffer::SamplesBuffer<f32>,
+    looping: bool,
+    lowpass: Option<&LowPassCutoff>,
+) {
+    match (looping, lowpass) {
+        (true, Some(cutoff)) => {
+            sink.append(LowPassSource::new(source.repeat_infinite(), cutoff.clone()));
+        },
+        (false, Some(cutoff)) => sink.append(LowPassSource::new(source, cutoff.clone())),
+        (true, None) => sink.append(source.repeat_infinite()),
+        (false, None) => sink.append(source),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1189,6 +1533,104 @@ mod tests {
         assert_ne!(h1.id(), h2.id());
     }
 
-    // Integration tests would require mocking the audio device
-    // or running on a system with audio hardware
+    /// Device provider with a scriptable device list.
+    #[derive(Default)]
+    struct MockDeviceProvider {
+        connected: Mutex<Vec<String>>,
+        default: Mutex<Option<String>>,
+        opened: Mutex<Vec<String>>,
+    }
+
+    impl MockDeviceProvider {
+        fn with_devices(devices: &[&str], default: &str) -> Self {
+            let provider = Self::default();
+            provider.set_devices(devices, Some(default));
+            provider
+        }
+
+        fn set_devices(&self, devices: &[&str], default: Option<&str>) {
+            *self.connected.lock() = devices.iter().map(|d| (*d).to_string()).collect();
+            *self.default.lock() = default.map(str::to_string);
+        }
+    }
+
+    impl AudioDeviceProvider for MockDeviceProvider {
+        type Device = String;
+
+        fn output_devices(&self) -> Vec<String> {
+            self.connected.lock().clone()
+        }
+
+        fn default_output(&self) -> Option<String> {
+            self.default.lock().clone()
+        }
+
+        fn open(&self, name: &str) -> AudioResult<String> {
+            if !self.connected.lock().iter().any(|d| d == name) {
+                return Err(AudioError::NoDevice);
+            }
+            self.opened.lock().push(name.to_string());
+            Ok(name.to_string())
+        }
+    }
+
+    #[test]
+    fn test_disconnect_reinitializes_on_fallback_device() {
+        let provider = MockDeviceProvider::with_devices(&["Headphones", "Speakers"], "Headphones");
+        let mut monitor = DeviceMonitor::open_default(provider).expect("device opens");
+        assert_eq!(monitor.current(), Some("Headphones"));
+
+        // Still connected: nothing to do
+        assert_eq!(monitor.poll(), None);
+
+        // Headphones unplugged; system default moves to speakers
+        monitor
+            .provider()
+            .set_devices(&["Speakers"], Some("Speakers"));
+        assert_eq!(
+            monitor.poll(),
+            Some(DeviceChange::Switched {
+                lost: Some("Headphones".to_string()),
+                current: "Speakers".to_string(),
+            })
+        );
+        assert_eq!(monitor.current(), Some("Speakers"));
+        assert_eq!(monitor.device().map(String::as_str), Some("Speakers"));
+        assert_eq!(
+            *monitor.provider().opened.lock(),
+            vec!["Headphones", "Speakers"]
+        );
+    }
+
+    #[test]
+    fn test_device_lost_then_reconnected() {
+        let provider = MockDeviceProvider::with_devices(&["Speakers"], "Speakers");
+        let mut monitor = DeviceMonitor::open_default(provider).expect("device opens");
+
+        monitor.provider().set_devices(&[], None);
+        assert_eq!(
+            monitor.poll(),
+            Some(DeviceChange::Lost {
+                lost: "Speakers".to_string()
+            })
+        );
+        assert!(monitor.device().is_none());
+        assert_eq!(monitor.poll(), None);
+
+        // Falls back to any connected device when there is no default
+        monitor.provider().set_devices(&["USB DAC"], None);
+        assert_eq!(
+            monitor.poll(),
+            Some(DeviceChange::Switched {
+                lost: None,
+                current: "USB DAC".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_open_default_without_devices_fails() {
+        let result = DeviceMonitor::open_default(MockDeviceProvider::default());
+        assert!(matches!(result, Err(AudioError::NoDevice)));
+    }
 }
@@ -4,6 +4,7 @@
 //! - `AudioHandle`: Unique identifier for playing sounds
 //! - `AudioBuffer`: Cached audio samples for quick playback
 //! - `AudioSource`: Streaming or buffered audio data
+//! - `StreamingSource`: Chunked decoding from disk for long tracks
 //! - Per-handle volume, pan, speed controls
 //!
 //! # Architecture
@@ -21,14 +22,19 @@
 //! Long-form audio (music, ambience) streams from disk via `AudioSource`.
 
 use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
 use parking_lot::RwLock;
+use rodio::{Decoder, Source};
 use tracing::{debug, warn};
 
+use crate::audio_backend::{AudioError, AudioResult};
+
 /// Maximum number of cached audio buffers.
 pub const MAX_CACHED_BUFFERS: usize = 256;
 
@@ -38,6 +44,9 @@ pub const MAX_CACHEABLE_SIZE: usize = 5 * 1024 * 1024; // 5MB
 /// Default crossfade duration for music transitions.
 pub const DEFAULT_CROSSFADE_DURATION: Duration = Duration::from_millis(500);
 
+/// Samples decoded per chunk when streaming from disk.
+pub const STREAM_CHUNK_SAMPLES: usize = 8192;
+
 /// Unique identifier for a playing sound.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct AudioHandle {
@@ -383,6 +392,336 @@ impl AudioSource {
     }
 }
 
+/// Streaming audio decoded from disk in small chunks.
+///
+/// Only one chunk of decoded samples is held in memory at a time; the next
+/// chunk is pulled from the file when the mixer consumes the current one.
+/// A looping stream reopens the file at the end instead of finishing.
+pub struct StreamingSource {
+    /// Decoder reading from the file.
+    decoder: Decoder<BufReader<File>>,
+    /// Path the stream was opened from.
+    path: PathBuf,
+    /// Decoded samples of the current chunk.
+    chunk: Vec<f32>,
+    /// Read position within the current chunk.
+    cursor: usize,
+    /// Maximum samples decoded per chunk.
+    chunk_samples: usize,
+    /// Channel count of the current chunk.
+    channels: u16,
+    /// Sample rate of the current chunk.
+    sample_rate: u32,
+    /// Total duration reported by the decoder.
+    total_duration: Option<Duration>,
+    /// Statistics shared with the owner after the source is handed to a sink.
+    stats: Arc<StreamStats>,
+    /// Whether to restart from the beginning at the end of the file.
+    looping: bool,
+}
+
+impl StreamingSource {
+    /// Open a file for streaming playback.
+    pub fn open(path: impl AsRef<Path>) -> AudioResult<Self> {
+        Self::open_with_chunk_size(path, STREAM_CHUNK_SAMPLES)
+    }
+
+    /// Open a file for streaming with a specific chunk size (in samples).
+    pub fn open_with_chunk_size(path: impl AsRef<Path>, chunk_samples: usize) -> AudioResult<Self> {
+        let path = path.as_ref();
+        let decoder = Self::open_decoder(path)?;
+
+        let channels = decoder.channels();
+        let sample_rate = decoder.sample_rate();
+        let total_duration = decoder.total_duration();
+
+        debug!("Opened audio stream: {:?}", path);
+        Ok(Self {
+            decoder,
+            path: path.to_path_buf(),
+            chunk: Vec::new(),
+            cursor: 0,
+            chunk_samples: chunk_samples.max(1),
+            channels,
+            sample_rate,
+            total_duration,
+            stats: Arc::new(StreamStats::default()),
+            looping: false,
+        })
+    }
+
+    /// Set whether the stream restarts from the beginning when it ends.
+    #[must_use]
+    pub const fn with_looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Check if the stream restarts from the beginning when it ends.
+    #[must_use]
+    pub const fn is_looping(&self) -> bool {
+        self.looping
+    }
+
+    /// Open a decoder reading from the start of a file.
+    fn open_decoder(path: &Path) -> AudioResult<Decoder<BufReader<File>>> {
+        let file = File::open(path).map_err(|e| AudioError::LoadFailed {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+
+        Decoder::new(BufReader::new(file)).map_err(|e| AudioError::LoadFailed {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })
+    }
+
+    /// Get the path the stream was opened from.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Get a handle to the stream statistics.
+    ///
+    /// The handle stays valid after the source is moved into a sink.
+    #[must_use]
+    pub fn stats(&self) -> Arc<StreamStats> {
+        Arc::clone(&self.stats)
+    }
+
+    /// Get the memory usage of this stream.
+    #[must_use]
+    pub fn memory_usage(&self) -> AudioMemoryUsage {
+        self.stats.memory_usage()
+    }
+
+    /// Decode the next chunk, reopening the file at the end if looping.
+    fn refill(&mut self) -> bool {
+        if self.decode_chunk() {
+            return true;
+        }
+        if self.looping && self.rewind() && self.decode_chunk() {
+            return true;
+        }
+        self.stats.finished.store(true, Ordering::Relaxed);
+        false
+    }
+
+    /// Restart decoding from the beginning of the file.
+    fn rewind(&mut self) -> bool {
+        match Self::open_decoder(&self.path) {
+            Ok(decoder) => {
+                self.decoder = decoder;
+                true
+            },
+            Err(e) => {
+                warn!("Failed to reopen looping stream: {}", e);
+                false
+            },
+        }
+    }
+
+    /// Decode the next chunk from the file.
+    ///
+    /// A chunk never crosses a decoder frame boundary, so the channel count
+    /// and sample rate are constant within it.
+    fn decode_chunk(&mut self) -> bool {
+        self.channels = self.decoder.channels();
+        self.sample_rate = self.decoder.sample_rate();
+
+        let limit = self
+            .decoder
+            .current_frame_len()
+            .map_or(self.chunk_samples, |len| len.clamp(1, self.chunk_samples));
+
+        self.chunk.clear();
+        self.chunk.extend(
+            self.decoder
+                .by_ref()
+                .take(limit)
+                .map(|sample| f32::from(sample) / 32768.0),
+        );
+        self.cursor = 0;
+
+        self.stats.resident_bytes.store(
+            self.chunk.capacity() * std::mem::size_of::<f32>(),
+            Ordering::Relaxed,
+        );
+        self.stats
+            .samples_decoded
+            .fetch_add(self.chunk.len() as u64, Ordering::Relaxed);
+
+        !self.chunk.is_empty()
+    }
+
+    /// Number of decoded samples not yet consumed.
+    fn remaining(&self) -> usize {
+        self.chunk.len() - self.cursor
+    }
+}
+
+impl std::fmt::Debug for StreamingSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamingSource")
+            .field("path", &self.path)
+            .field("chunk_samples", &self.chunk_samples)
+            .field("channels", &self.channels)
+            .field("sample_rate", &self.sample_rate)
+            .field("stats", &self.stats)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Iterator for StreamingSource {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        if self.remaining() == 0 && !self.refill() {
+            return None;
+        }
+        let sample = self.chunk[self.cursor];
+        self.cursor += 1;
+        Some(sample)
+    }
+}
+
+impl Source for StreamingSource {
+    fn current_frame_len(&self) -> Option<usize> {
+        match self.remaining() {
+            0 => self.decoder.current_frame_len(),
+            n => Some(n),
+        }
+    }
+
+    fn channels(&self) -> u16 {
+        match self.remaining() {
+            0 => self.decoder.channels(),
+            _ => self.channels,
+        }
+    }
+
+    fn sample_rate(&self) -> u32 {
+        match self.remaining() {
+            0 => self.decoder.sample_rate(),
+            _ => self.sample_rate,
+        }
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        if self.looping {
+            None
+        } else {
+            self.total_duration
+        }
+    }
+}
+
+/// Live statistics for a streaming source.
+#[derive(Debug, Default)]
+pub struct StreamStats {
+    /// Bytes of decoded samples currently held in memory.
+    resident_bytes: AtomicUsize,
+    /// Total samples decoded so far.
+    samples_decoded: AtomicU64,
+    /// Whether the end of the file was reached.
+    finished: AtomicBool,
+}
+
+impl StreamStats {
+    /// Get the bytes of decoded samples currently held in memory.
+    #[must_use]
+    pub fn resident_bytes(&self) -> usize {
+        self.resident_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Get the total number of samples decoded so far.
+    #[must_use]
+    pub fn samples_decoded(&self) -> u64 {
+        self.samples_decoded.load(Ordering::Relaxed)
+    }
+
+    /// Check if the stream reached the end of the file.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.finished.load(Ordering::Relaxed)
+    }
+
+    /// Get the memory usage of the stream.
+    #[must_use]
+    pub fn memory_usage(&self) -> AudioMemoryUsage {
+        AudioMemoryUsage {
+            resident_bytes: 0,
+            streamed_bytes: self.resident_bytes(),
+        }
+    }
+}
+
+/// Audio memory usage, split by how the audio is held.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AudioMemoryUsage {
+    /// Bytes held by fully loaded buffers.
+    pub resident_bytes: usize,
+    /// Bytes held by the chunk buffers of active streams.
+    pub streamed_bytes: usize,
+}
+
+impl AudioMemoryUsage {
+    /// Get the total bytes in use.
+    #[must_use]
+    pub const fn total_bytes(&self) -> usize {
+        self.resident_bytes + self.streamed_bytes
+    }
+}
+
+impl std::ops::Add for AudioMemoryUsage {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            resident_bytes: self.resident_bytes + rhs.resident_bytes,
+            streamed_bytes: self.streamed_bytes + rhs.streamed_bytes,
+        }
+    }
+}
+
+/// A loaded audio resource.
+///
+/// Short sound effects are held fully decoded; long music and ambience
+/// tracks are streamed from disk.
+#[derive(Debug)]
+pub enum AudioResource {
+    /// Fully decoded in memory.
+    Resident(AudioBuffer),
+    /// Decoded on demand from disk.
+    Streamed(Box<StreamingSource>),
+}
+
+impl AudioResource {
+    /// Open a file for streaming playback.
+    pub fn stream(path: impl AsRef<Path>) -> AudioResult<Self> {
+        StreamingSource::open(path).map(|source| Self::Streamed(Box::new(source)))
+    }
+
+    /// Check if this resource is streamed.
+    #[must_use]
+    pub const fn is_streamed(&self) -> bool {
+        matches!(self, Self::Streamed(_))
+    }
+
+    /// Get the memory usage of this resource.
+    #[must_use]
+    pub fn memory_usage(&self) -> AudioMemoryUsage {
+        match self {
+            Self::Resident(buffer) => AudioMemoryUsage {
+                resident_bytes: buffer.size_bytes(),
+                streamed_bytes: 0,
+            },
+            Self::Streamed(source) => source.memory_usage(),
+        }
+    }
+}
+
 /// Audio category for volume mixing.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum AudioCategory {
@@ -1115,4 +1454,81 @@ mod tests {
         let vol = manager.effective_volume(AudioCategory::Music);
         assert!((vol - 0.4).abs() < f32::EPSILON); // 0.8 * 0.5
     }
+
+    /// Write a mono 16-bit PCM WAV file of silence.
+    fn write_wav(path: &Path, sample_rate: u32, samples: u32) {
+        let data_len = samples * 2;
+        let mut bytes = Vec::with_capacity(44 + data_len as usize);
+        bytes.extend_from_slice(b"RIFF");
+        bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+        bytes.extend_from_slice(b"WAVEfmt ");
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes()); // PCM
+        bytes.extend_from_slice(&1u16.to_le_bytes()); // Mono
+        bytes.extend_from_slice(&sample_rate.to_le_bytes());
+        bytes.extend_from_slice(&(sample_rate * 2).to_le_bytes());
+        bytes.extend_from_slice(&2u16.to_le_bytes());
+        bytes.extend_from_slice(&16u16.to_le_bytes());
+        bytes.extend_from_slice(b"data");
+        bytes.extend_from_slice(&data_len.to_le_bytes());
+        bytes.resize(44 + data_len as usize, 0);
+        std::fs::write(path, bytes).expect("should write wav");
+    }
+
+    #[test]
+    fn test_streamed_resource_low_resident_memory() {
+        let dir = tempfile::tempdir().expect("should create temp dir");
+        let path = dir.path().join("ambience.wav");
+        // 10 seconds of mono audio
+        write_wav(&path, 44100, 441_000);
+        let full_size = 441_000 * std::mem::size_of::<f32>();
+
+        let resource = AudioResource::stream(&path).expect("should open stream");
+        assert!(resource.is_streamed());
+
+        let AudioResource::Streamed(mut source) = resource else {
+            panic!("expected streamed resource");
+        };
+        let stats = source.stats();
+
+        // Pull one second of samples, as the mixer would while playing
+        assert_eq!(source.by_ref().take(44100).count(), 44100);
+        assert!(!stats.is_finished());
+
+        let usage = source.memory_usage();
+        assert_eq!(usage.resident_bytes, 0);
+        assert!(usage.streamed_bytes > 0);
+        assert!(usage.streamed_bytes <= STREAM_CHUNK_SAMPLES * std::mem::size_of::<f32>());
+        assert!(usage.total_bytes() < full_size / 10);
+        assert!(stats.samples_decoded() < 441_000);
+    }
+
+    #[test]
+    fn test_looping_stream_restarts_without_buffering() {
+        let dir = tempfile::tempdir().expect("should create temp dir");
+        let path = dir.path().join("loop.wav");
+        write_wav(&path, 8000, 1000);
+
+        let mut source = StreamingSource::open_with_chunk_size(&path, 256)
+            .expect("should open stream")
+            .with_looping(true);
+        let stats = source.stats();
+
+        // Play through the file three times
+        assert_eq!(source.by_ref().take(3000).count(), 3000);
+        assert!(!stats.is_finished());
+        assert!(source.total_duration().is_none());
+        assert!(source.memory_usage().streamed_bytes <= 256 * std::mem::size_of::<f32>());
+    }
+
+    #[test]
+    fn test_resident_resource_memory() {
+        let buffer = AudioBuffer::new(BufferId::new(0), vec![0.0; 1000], 44100, 1);
+        let resource = AudioResource::Resident(buffer);
+
+        assert!(!resource.is_streamed());
+        let usage = resource.memory_usage();
+        assert_eq!(usage.resident_bytes, 4000);
+        assert_eq!(usage.streamed_bytes, 0);
+    }
 }
@@ -10,6 +10,7 @@
 //! - **Stereo panning**: Sounds pan left/right based on relative position
 //! - **Doppler effect**: Pitch shifts based on relative velocity
 //! - **Environment effects**: Reverb and filtering based on location
+//! - **Distance filtering**: Optional low-pass that muffles far-off sounds
 //!
 //! # Example
 //!
@@ -43,7 +44,12 @@
 //! assert!(params.audible);
 //! ```
 
-use std::f32::consts::FRAC_PI_4;
+use std::f32::consts::{FRAC_PI_4, TAU};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rodio::Source;
 
 use tracing::debug;
 
@@ -59,6 +65,9 @@ pub const DEFAULT_MAX_DISTANCE: f32 = 1000.0;
 /// Minimum volume threshold for audibility.
 pub const MIN_AUDIBLE_VOLUME: f32 = 0.001;
 
+/// Low-pass cutoff in Hz that leaves the audible range untouched.
+pub const FULL_RANGE_CUTOFF: f32 = 20000.0;
+
 /// Distance attenuation models for 2D spatial audio.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum AttenuationModel {
@@ -274,6 +283,160 @@ impl From<AudioEnvironment> for EnvironmentParams {
     }
 }
 
+/// Distance-driven low-pass filter curve.
+///
+/// The cutoff moves from `near_cutoff` at the reference distance to
+/// `far_cutoff` at the maximum distance. The curve shapes the transition and
+/// the interpolation is done in log-frequency space so it sounds even.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DistanceFilter {
+    /// Cutoff frequency in Hz for nearby sounds.
+    pub near_cutoff: f32,
+    /// Cutoff frequency in Hz at maximum distance.
+    pub far_cutoff: f32,
+    /// Curve from near to far cutoff.
+    pub curve: AttenuationModel,
+}
+
+impl Default for DistanceFilter {
+    fn default() -> Self {
+        Self {
+            near_cutoff: FULL_RANGE_CUTOFF,
+            far_cutoff: 1000.0,
+            curve: AttenuationModel::Linear,
+        }
+    }
+}
+
+impl DistanceFilter {
+    /// Create a filter with the given cutoff range.
+    #[must_use]
+    pub fn new(near_cutoff: f32, far_cutoff: f32) -> Self {
+        Self {
+            near_cutoff: near_cutoff.max(1.0),
+            far_cutoff: far_cutoff.max(1.0),
+            ..Self::default()
+        }
+    }
+
+    /// Set the curve.
+    #[must_use]
+    pub const fn with_curve(mut self, curve: AttenuationModel) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    /// Calculate the cutoff frequency in Hz for a given distance.
+    #[must_use]
+    pub fn cutoff(&self, distance: f32, ref_distance: f32, max_distance: f32) -> f32 {
+        let near = self.curve.calculate(distance, ref_distance, max_distance);
+        self.far_cutoff * (self.near_cutoff / self.far_cutoff).powf(near)
+    }
+}
+
+/// Cutoff of a playing [`LowPassSource`], shared so it can follow the
+/// sound's distance.
+#[derive(Debug, Clone)]
+pub struct LowPassCutoff(Arc<AtomicU32>);
+
+impl LowPassCutoff {
+    /// Create a cutoff in Hz.
+    #[must_use]
+    pub fn new(cutoff: f32) -> Self {
+        Self(Arc::new(AtomicU32::new(cutoff.to_bits())))
+    }
+
+    /// Change the cutoff in Hz; playback picks it up on the next sample.
+    pub fn set(&self, cutoff: f32) {
+        self.0.store(cutoff.to_bits(), Ordering::Relaxed);
+    }
+
+    /// Get the cutoff in Hz.
+    #[must_use]
+    pub fn get(&self) -> f32 {
+        f32::from_bits(self.0.load(Ordering::Relaxed))
+    }
+}
+
+/// One-pole low-pass filter over a source, with an adjustable cutoff.
+///
+/// At [`FULL_RANGE_CUTOFF`] and above samples pass through untouched.
+pub struct LowPassSource<S> {
+    inner: S,
+    cutoff: LowPassCutoff,
+    /// Last output per channel.
+    state: Vec<f32>,
+    /// Channel of the next sample.
+    channel: usize,
+    /// `(cutoff bits, sample rate, smoothing factor)` of the last sample.
+    factor: (u32, u32, f32),
+}
+
+impl<S: Source<Item = f32>> LowPassSource<S> {
+    /// Filter `inner` at the cutoff held by `cutoff`.
+    pub fn new(inner: S, cutoff: LowPassCutoff) -> Self {
+        Self {
+            inner,
+            cutoff,
+            state: Vec::new(),
+            channel: 0,
+            factor: (u32::MAX, 0, 1.0),
+        }
+    }
+
+    /// Smoothing factor for the current cutoff and sample rate.
+    fn factor(&mut self) -> f32 {
+        let bits = self.cutoff.0.load(Ordering::Relaxed);
+        let sample_rate = self.inner.sample_rate();
+        if (bits, sample_rate) != (self.factor.0, self.factor.1) {
+            let cutoff = f32::from_bits(bits);
+            let factor = if cutoff >= FULL_RANGE_CUTOFF || sample_rate == 0 {
+                1.0
+            } else {
+                1.0 - (-TAU * cutoff.max(0.0) / sample_rate as f32).exp()
+            };
+            self.factor = (bits, sample_rate, factor);
+        }
+        self.factor.2
+    }
+}
+
+impl<S: Source<Item = f32>> Iterator for LowPassSource<S> {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        let channels = usize::from(self.inner.channels().max(1));
+        let factor = self.factor();
+        let sample = self.inner.next()?;
+        if self.state.len() != channels {
+            self.state = vec![sample; channels];
+            self.channel = 0;
+        }
+        let output = &mut self.state[self.channel];
+        *output += factor * (sample - *output);
+        self.channel = (self.channel + 1) % channels;
+        Some(*output)
+    }
+}
+
+impl<S: Source<Item = f32>> Source for LowPassSource<S> {
+    fn current_frame_len(&self) -> Option<usize> {
+        self.inner.current_frame_len()
+    }
+
+    fn channels(&self) -> u16 {
+        self.inner.channels()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.inner.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        self.inner.total_duration()
+    }
+}
+
 /// Listener position and orientation for spatial calculations.
 #[derive(Debug, Clone, Copy)]
 pub struct ListenerData {
@@ -429,6 +592,8 @@ pub struct SpatialParams {
     pub mono_volume: f32,
     /// Whether the sound is audible at all.
     pub audible: bool,
+    /// Distance low-pass cutoff in Hz (if distance filtering is enabled).
+    pub lowpass_cutoff: Option<f32>,
 }
 
 impl SpatialParams {
@@ -443,6 +608,7 @@ impl SpatialParams {
             pan: 0.0,
             mono_volume: 0.0,
             audible: false,
+            lowpass_cutoff: None,
         }
     }
 
@@ -469,6 +635,8 @@ pub struct SpatialAudioProcessor {
     doppler_factor: f32,
     /// Speed of sound for Doppler calculations.
     speed_of_sound: f32,
+    /// Distance low-pass filter (if enabled).
+    distance_filter: Option<DistanceFilter>,
 }
 
 impl Default for SpatialAudioProcessor {
@@ -488,6 +656,7 @@ impl SpatialAudioProcessor {
             doppler_enabled: true,
             doppler_factor: 1.0,
             speed_of_sound: SPEED_OF_SOUND,
+            distance_filter: None,
         }
     }
 
@@ -551,6 +720,17 @@ impl SpatialAudioProcessor {
         self.speed_of_sound = speed.max(1.0);
     }
 
+    /// Set the distance low-pass filter (`None` disables it).
+    pub fn set_distance_filter(&mut self, filter: Option<DistanceFilter>) {
+        self.distance_filter = filter;
+    }
+
+    /// Get the distance low-pass filter.
+    #[must_use]
+    pub const fn distance_filter(&self) -> Option<DistanceFilter> {
+        self.distance_filter
+    }
+
     /// Get environment effect parameters.
     #[must_use]
     pub fn environment_params(&self) -> EnvironmentParams {
@@ -620,6 +800,10 @@ impl SpatialAudioProcessor {
             source.pitch
         };
 
+        let lowpass_cutoff = self
+            .distance_filter
+            .map(|filter| filter.cutoff(distance, source.ref_distance, source.max_distance));
+
         SpatialParams {
             left_volume,
             right_volume,
@@ -628,6 +812,7 @@ impl SpatialAudioProcessor {
             pan,
             mono_volume,
             audible: true,
+            lowpass_cutoff,
         }
     }
 
@@ -945,4 +1130,54 @@ mod tests {
         let v_mid = lerp_volume(1.0, 1.0, 0.5);
         assert!(v_mid > 0.9 && v_mid < 1.5); // Roughly preserves energy
     }
+
+    #[test]
+    fn test_distance_filter_cutoff_decreases() {
+        let filter = DistanceFilter::new(20000.0, 500.0);
+
+        // Full range within reference distance
+        assert!((filter.cutoff(50.0, 100.0, 1000.0) - 20000.0).abs() < 1.0);
+
+        let mut previous = f32::MAX;
+        for distance in [100.0, 250.0, 400.0, 550.0, 700.0, 850.0, 1000.0] {
+            let cutoff = filter.cutoff(distance, 100.0, 1000.0);
+            assert!(cutoff <= previous);
+            previous = cutoff;
+        }
+
+        // Heavily filtered at max distance
+        assert!((previous - 500.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_processor_distance_filter() {
+        let mut processor = SpatialAudioProcessor::new();
+        let near = SoundSourceData::new(150.0, 0.0);
+        let far = SoundSourceData::new(800.0, 0.0);
+
+        // Disabled by default
+        assert!(processor.calculate(&near).lowpass_cutoff.is_none());
+
+        processor.set_distance_filter(Some(DistanceFilter::default()));
+        let near_cutoff = processor.calculate(&near).lowpass_cutoff;
+        let far_cutoff = processor.calculate(&far).lowpass_cutoff;
+
+        assert!(near_cutoff.is_some());
+        assert!(far_cutoff < near_cutoff);
+    }
+
+    #[test]
+    fn test_low_pass_source_follows_cutoff() {
+        // Alternating samples are the highest frequency a signal can hold
+        let signal = || rodio::buffer::SamplesBuffer::new(1, 44100, [1.0f32, -1.0].repeat(64));
+        let peak = |source: LowPassSource<_>| source.skip(64).fold(0.0f32, |m, s| m.max(s.abs()));
+
+        let cutoff = LowPassCutoff::new(FULL_RANGE_CUTOFF);
+        let unfiltered: Vec<f32> = LowPassSource::new(signal(), cutoff.clone()).collect();
+        assert_eq!(unfiltered, signal().collect::<Vec<_>>());
+
+        cutoff.set(500.0);
+        assert!((cutoff.get() - 500.0).abs() < f32::EPSILON);
+        assert!(peak(LowPassSource::new(signal(), cutoff.clone())) < 0.2);
+    }
 }
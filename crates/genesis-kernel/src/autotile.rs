@@ -0,0 +1,216 @@
+//! Neighbor masks for autotiling.
+//!
+//! A [`NeighborMask`] records which of a tile's eight neighbors share its
+//! terrain. It maps onto the common 47-tile "blob" tileset layout via
+//! [`NeighborMask::to_blob_index`].
+//!
+//! Bit weights follow the usual blob convention, clockwise from north:
+//!
+//! ```text
+//! NW(128) N(1)  NE(2)
+//! W(64)   .     E(4)
+//! SW(32)  S(16) SE(8)
+//! ```
+
+/// Number of tiles in a blob tileset.
+pub const BLOB_TILE_COUNT: usize = 47;
+
+/// Reduced masks in ascending order; a mask's position is its blob index.
+const BLOB_MASKS: [u8; BLOB_TILE_COUNT] = build_blob_masks();
+
+/// Collects every distinct reduced mask in ascending order.
+const fn build_blob_masks() -> [u8; BLOB_TILE_COUNT] {
+    let mut masks = [0u8; BLOB_TILE_COUNT];
+    let mut count = 0;
+    let mut bits: u16 = 0;
+    while bits <= 0xFF {
+        let mask = NeighborMask(bits as u8);
+        if mask.reduced().0 == mask.0 {
+            masks[count] = mask.0;
+            count += 1;
+        }
+        bits += 1;
+    }
+    masks
+}
+
+/// Which of a tile's eight neighbors match it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NeighborMask(pub u8);
+
+impl NeighborMask {
+    /// North neighbor.
+    pub const N: u8 = 1;
+    /// North-east neighbor.
+    pub const NE: u8 = 2;
+    /// East neighbor.
+    pub const E: u8 = 4;
+    /// South-east neighbor.
+    pub const SE: u8 = 8;
+    /// South neighbor.
+    pub const S: u8 = 16;
+    /// South-west neighbor.
+    pub const SW: u8 = 32;
+    /// West neighbor.
+    pub const W: u8 = 64;
+    /// North-west neighbor.
+    pub const NW: u8 = 128;
+
+    /// No matching neighbors.
+    pub const NONE: Self = Self(0);
+    /// All neighbors match.
+    pub const ALL: Self = Self(0xFF);
+
+    /// Creates a mask from raw bits.
+    #[must_use]
+    pub const fn new(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    /// Builds a mask by testing each neighbor offset.
+    ///
+    /// `matches(dx, dy)` is called for the eight offsets, with `dy` negative
+    /// towards north.
+    pub fn from_neighbors(mut matches: impl FnMut(i32, i32) -> bool) -> Self {
+        const OFFSETS: [(i32, i32, u8); 8] = [
+            (0, -1, NeighborMask::N),
+            (1, -1, NeighborMask::NE),
+            (1, 0, NeighborMask::E),
+            (1, 1, NeighborMask::SE),
+            (0, 1, NeighborMask::S),
+            (-1, 1, NeighborMask::SW),
+            (-1, 0, NeighborMask::W),
+            (-1, -1, NeighborMask::NW),
+        ];
+
+        let mut bits = 0;
+        for (dx, dy, bit) in OFFSETS {
+            if matches(dx, dy) {
+                bits |= bit;
+            }
+        }
+        Self(bits)
+    }
+
+    /// Returns the raw bits.
+    #[must_use]
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Checks whether all the given neighbor bits are set.
+    #[must_use]
+    pub const fn contains(self, bits: u8) -> bool {
+        self.0 & bits == bits
+    }
+
+    /// Drops corners whose two adjacent edges don't both match.
+    ///
+    /// A corner only changes the tile's look when both edges beside it
+    /// connect, so this collapses the 256 masks to 47 distinct tiles.
+    #[must_use]
+    pub const fn reduced(self) -> Self {
+        let mut bits = self.0 & (Self::N | Self::E | Self::S | Self::W);
+        if self.contains(Self::NE | Self::N | Self::E) {
+            bits |= Self::NE;
+        }
+        if self.contains(Self::SE | Self::S | Self::E) {
+            bits |= Self::SE;
+        }
+        if self.contains(Self::SW | Self::S | Self::W) {
+            bits |= Self::SW;
+        }
+        if self.contains(Self::NW | Self::N | Self::W) {
+            bits |= Self::NW;
+        }
+        Self(bits)
+    }
+
+    /// Returns the tile index (0..47) in a blob tileset.
+    ///
+    /// Tiles are ordered by ascending reduced mask, so index 0 is the
+    /// isolated tile and index 46 the fully surrounded one.
+    #[must_use]
+    pub fn to_blob_index(&self) -> usize {
+        let reduced = self.reduced().0;
+        BLOB_MASKS.binary_search(&reduced).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blob_masks_are_distinct_and_sorted() {
+        assert_eq!(BLOB_MASKS.len(), BLOB_TILE_COUNT);
+        assert!(BLOB_MASKS.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(BLOB_MASKS[0], 0);
+        assert_eq!(BLOB_MASKS[BLOB_TILE_COUNT - 1], 0xFF);
+    }
+
+    #[test]
+    fn test_blob_index_known_masks() {
+        let n = NeighborMask::N;
+        let e = NeighborMask::E;
+        let s = NeighborMask::S;
+        let w = NeighborMask::W;
+        let ne = NeighborMask::NE;
+
+        assert_eq!(NeighborMask::NONE.to_blob_index(), 0);
+        assert_eq!(NeighborMask::new(n).to_blob_index(), 1);
+        assert_eq!(NeighborMask::new(e).to_blob_index(), 2);
+        assert_eq!(NeighborMask::new(n | e).to_blob_index(), 3);
+        assert_eq!(NeighborMask::new(n | ne | e).to_blob_index(), 4);
+        assert_eq!(NeighborMask::new(s).to_blob_index(), 5);
+        assert_eq!(NeighborMask::new(n | e | s | w).to_blob_index(), 21);
+        assert_eq!(NeighborMask::ALL.to_blob_index(), 46);
+    }
+
+    #[test]
+    fn test_blob_index_ignores_unsupported_corners() {
+        let n = NeighborMask::N;
+        let e = NeighborMask::E;
+
+        // Lone corners have no effect
+        assert_eq!(NeighborMask::new(NeighborMask::NE).to_blob_index(), 0);
+        assert_eq!(
+            NeighborMask::new(NeighborMask::NE | NeighborMask::SW).to_blob_index(),
+            0
+        );
+
+        // A corner with only one adjacent edge is dropped
+        assert_eq!(
+            NeighborMask::new(n | NeighborMask::NE).to_blob_index(),
+            NeighborMask::new(n).to_blob_index()
+        );
+
+        // Every corner but NE set: NE bit missing changes the tile
+        let without_ne = NeighborMask::new(!NeighborMask::NE);
+        assert_ne!(without_ne.to_blob_index(), NeighborMask::ALL.to_blob_index());
+        assert_ne!(
+            NeighborMask::new(n | e).to_blob_index(),
+            NeighborMask::new(n | e | NeighborMask::NE).to_blob_index()
+        );
+    }
+
+    #[test]
+    fn test_from_neighbors() {
+        // Horizontal strip: only east and west match
+        let mask = NeighborMask::from_neighbors(|_, dy| dy == 0);
+        assert_eq!(mask.bits(), NeighborMask::E | NeighborMask::W);
+
+        let all = NeighborMask::from_neighbors(|_, _| true);
+        assert_eq!(all, NeighborMask::ALL);
+    }
+
+    #[test]
+    fn test_every_mask_maps_to_valid_index() {
+        for bits in 0..=u8::MAX {
+            let mask = NeighborMask::new(bits);
+            let index = mask.to_blob_index();
+            assert!(index < BLOB_TILE_COUNT);
+            assert_eq!(BLOB_MASKS[index], mask.reduced().bits());
+        }
+    }
+}
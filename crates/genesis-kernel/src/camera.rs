@@ -23,6 +23,8 @@ pub struct Camera {
     pub zoom: f32,
     /// Viewport size in pixels (width, height).
     pub viewport_size: (u32, u32),
+    /// World rectangle the view is kept inside ((min_x, min_y), (max_x, max_y)).
+    bounds: Option<((f32, f32), (f32, f32))>,
 }
 
 impl Default for Camera {
@@ -31,6 +33,7 @@ impl Default for Camera {
             position: (0.0, 0.0),
             zoom: DEFAULT_ZOOM,
             viewport_size: (1280, 720),
+            bounds: None,
         }
     }
 }
@@ -43,13 +46,38 @@ impl Camera {
             position: (0.0, 0.0),
             zoom: DEFAULT_ZOOM,
             viewport_size: (viewport_width, viewport_height),
+            bounds: None,
         }
     }
 
+    /// Restrict the view to a world rectangle (e.g. the loaded region).
+    ///
+    /// The camera center is clamped so the visible area stays inside the
+    /// rectangle; on an axis where the view is larger than the rectangle,
+    /// the view is centered on it instead.
+    pub fn set_bounds(&mut self, min: (f32, f32), max: (f32, f32)) {
+        let lo = (min.0.min(max.0), min.1.min(max.1));
+        let hi = (min.0.max(max.0), min.1.max(max.1));
+        self.bounds = Some((lo, hi));
+        self.clamp_to_bounds();
+    }
+
+    /// Remove the view bounds.
+    pub fn clear_bounds(&mut self) {
+        self.bounds = None;
+    }
+
+    /// Get the view bounds, if set.
+    #[must_use]
+    pub const fn bounds(&self) -> Option<((f32, f32), (f32, f32))> {
+        self.bounds
+    }
+
     /// Move camera by delta in world units.
     pub fn translate(&mut self, dx: f32, dy: f32) {
         self.position.0 += dx;
         self.position.1 += dy;
+        self.clamp_to_bounds();
     }
 
     /// Zoom in/out by factor (clamped to reasonable range).
@@ -57,21 +85,46 @@ impl Camera {
     /// Factor > 1.0 zooms in, < 1.0 zooms out.
     pub fn zoom_by(&mut self, factor: f32) {
         self.zoom = (self.zoom * factor).clamp(MIN_ZOOM, MAX_ZOOM);
+        self.clamp_to_bounds();
     }
 
     /// Set absolute zoom level (clamped).
     pub fn set_zoom(&mut self, zoom: f32) {
         self.zoom = zoom.clamp(MIN_ZOOM, MAX_ZOOM);
+        self.clamp_to_bounds();
     }
 
     /// Center camera on world position.
     pub fn center_on(&mut self, world_x: f32, world_y: f32) {
         self.position = (world_x, world_y);
+        self.clamp_to_bounds();
     }
 
     /// Set the viewport size.
     pub fn set_viewport(&mut self, width: u32, height: u32) {
         self.viewport_size = (width, height);
+        self.clamp_to_bounds();
+    }
+
+    /// Pull the camera center back inside the view bounds, if any.
+    fn clamp_to_bounds(&mut self) {
+        let Some(((min_x, min_y), (max_x, max_y))) = self.bounds else {
+            return;
+        };
+        let (half_width, half_height) = {
+            let (w, h) = self.visible_size();
+            (w / 2.0, h / 2.0)
+        };
+
+        let clamp_axis = |value: f32, lo: f32, hi: f32, half: f32| {
+            if hi - lo <= half * 2.0 {
+                (lo + hi) / 2.0
+            } else {
+                value.clamp(lo + half, hi - half)
+            }
+        };
+        self.position.0 = clamp_axis(self.position.0, min_x, max_x, half_width);
+        self.position.1 = clamp_axis(self.position.1, min_y, max_y, half_height);
     }
 
     /// Get the viewport size.
@@ -200,6 +253,7 @@ impl Camera {
         let t = t.clamp(0.0, 1.0);
         self.position.0 += (target_x - self.position.0) * t;
         self.position.1 += (target_y - self.position.1) * t;
+        self.clamp_to_bounds();
     }
 
     /// Smoothly interpolate zoom toward target zoom.
@@ -207,6 +261,7 @@ impl Camera {
         let t = t.clamp(0.0, 1.0);
         let target = target_zoom.clamp(MIN_ZOOM, MAX_ZOOM);
         self.zoom += (target - self.zoom) * t;
+        self.clamp_to_bounds();
     }
 }
 
@@ -408,4 +463,44 @@ mod tests {
         assert!((w - 400.0).abs() < f32::EPSILON);
         assert!((h - 300.0).abs() < f32::EPSILON);
     }
+
+    #[test]
+    fn test_center_on_clamped_to_bounds() {
+        let mut camera = Camera::new(800, 600);
+        camera.set_bounds((0.0, 0.0), (2000.0, 1000.0));
+
+        // Beyond the bounds: clamped so the view edge meets the world edge
+        camera.center_on(5000.0, -300.0);
+        assert!((camera.position.0 - 1600.0).abs() < f32::EPSILON);
+        assert!((camera.position.1 - 300.0).abs() < f32::EPSILON);
+        let (min_x, min_y, max_x, max_y) = camera.visible_bounds();
+        assert!(min_x >= 0.0 && min_y >= 0.0 && max_x <= 2000.0 && max_y <= 1000.0);
+
+        // Following via lerp also respects the clamp
+        camera.lerp_to(-1000.0, 500.0, 1.0);
+        assert!((camera.position.0 - 400.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_center_on_within_bounds_unaffected() {
+        let mut camera = Camera::new(800, 600);
+        camera.set_bounds((0.0, 0.0), (2000.0, 1000.0));
+
+        camera.center_on(1000.0, 500.0);
+        assert!((camera.position.0 - 1000.0).abs() < f32::EPSILON);
+        assert!((camera.position.1 - 500.0).abs() < f32::EPSILON);
+
+        camera.clear_bounds();
+        camera.center_on(-5000.0, 5000.0);
+        assert!((camera.position.0 + 5000.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_bounds_smaller_than_view_centers() {
+        let mut camera = Camera::new(800, 600);
+        camera.set_bounds((0.0, 0.0), (400.0, 200.0));
+        camera.center_on(999.0, -999.0);
+        assert!((camera.position.0 - 200.0).abs() < f32::EPSILON);
+        assert!((camera.position.1 - 100.0).abs() < f32::EPSILON);
+    }
 }
@@ -0,0 +1,228 @@
+//! Resizable cell buffers for the simulated region.
+//!
+//! [`CellBuffers`] keeps a CPU copy of the simulated cells along with the
+//! GPU storage buffer they are uploaded to. When streaming grows or moves
+//! the simulated region, [`CellBuffers::resize`] keeps every cell that is
+//! still inside the new extent at the same world coordinates, and the GPU
+//! buffer is only reallocated when its size actually changes.
+
+/// Extent of the simulated region in world cell coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellDims {
+    /// World X of the first column.
+    pub origin_x: i32,
+    /// World Y of the first row.
+    pub origin_y: i32,
+    /// Width in cells.
+    pub width: u32,
+    /// Height in cells.
+    pub height: u32,
+}
+
+impl CellDims {
+    /// Creates dimensions for a region starting at `(origin_x, origin_y)`.
+    #[must_use]
+    pub const fn new(origin_x: i32, origin_y: i32, width: u32, height: u32) -> Self {
+        Self {
+            origin_x,
+            origin_y,
+            width,
+            height,
+        }
+    }
+
+    /// Number of cells in the region.
+    #[must_use]
+    pub fn cell_count(&self) -> usize {
+        self.width as usize * self.height as usize
+    }
+
+    /// Buffer index of a world cell, or `None` if outside the region.
+    #[must_use]
+    pub fn index_of(&self, x: i32, y: i32) -> Option<usize> {
+        let lx = u32::try_from(i64::from(x) - i64::from(self.origin_x)).ok()?;
+        let ly = u32::try_from(i64::from(y) - i64::from(self.origin_y)).ok()?;
+        if lx >= self.width || ly >= self.height {
+            return None;
+        }
+        Some(ly as usize * self.width as usize + lx as usize)
+    }
+}
+
+/// CPU and GPU storage for the simulated cells.
+#[derive(Debug)]
+pub struct CellBuffers {
+    dims: CellDims,
+    cells: Vec<u32>,
+    gpu: Option<wgpu::Buffer>,
+    dirty: bool,
+}
+
+impl CellBuffers {
+    /// Creates empty (all zero) cell buffers for the given region.
+    #[must_use]
+    pub fn new(dims: CellDims) -> Self {
+        Self {
+            dims,
+            cells: vec![0; dims.cell_count()],
+            gpu: None,
+            dirty: true,
+        }
+    }
+
+    /// Current region.
+    #[must_use]
+    pub fn dims(&self) -> CellDims {
+        self.dims
+    }
+
+    /// Raw cell data, row-major.
+    #[must_use]
+    pub fn cells(&self) -> &[u32] {
+        &self.cells
+    }
+
+    /// Gets the cell at world coordinates.
+    #[must_use]
+    pub fn get(&self, x: i32, y: i32) -> Option<u32> {
+        self.dims.index_of(x, y).map(|i| self.cells[i])
+    }
+
+    /// Sets the cell at world coordinates. Returns `false` if outside.
+    pub fn set(&mut self, x: i32, y: i32, cell: u32) -> bool {
+        match self.dims.index_of(x, y) {
+            Some(i) => {
+                self.cells[i] = cell;
+                self.dirty = true;
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Changes the simulated region, keeping overlapping cells in place.
+    ///
+    /// Cells outside the old region start out empty; cells outside the
+    /// new region are dropped.
+    pub fn resize(&mut self, new_dims: CellDims) {
+        if new_dims == self.dims {
+            return;
+        }
+
+        let mut cells = vec![0; new_dims.cell_count()];
+        let old = self.dims;
+        let min_x = old.origin_x.max(new_dims.origin_x);
+        let max_x = (i64::from(old.origin_x) + i64::from(old.width))
+            .min(i64::from(new_dims.origin_x) + i64::from(new_dims.width));
+        let min_y = old.origin_y.max(new_dims.origin_y);
+        let max_y = (i64::from(old.origin_y) + i64::from(old.height))
+            .min(i64::from(new_dims.origin_y) + i64::from(new_dims.height));
+
+        if i64::from(min_x) < max_x {
+            let span = (max_x - i64::from(min_x)) as usize;
+            for y in i64::from(min_y)..max_y {
+                let y = y as i32;
+                if let (Some(src), Some(dst)) =
+                    (old.index_of(min_x, y), new_dims.index_of(min_x, y))
+                {
+                    cells[dst..dst + span].copy_from_slice(&self.cells[src..src + span]);
+                }
+            }
+        }
+
+        if new_dims.cell_count() != old.cell_count() {
+            self.gpu = None;
+        }
+        self.dims = new_dims;
+        self.cells = cells;
+        self.dirty = true;
+    }
+
+    /// Replaces the CPU copy with cells read back from the GPU buffer.
+    ///
+    /// The GPU already holds these cells, so nothing is queued for upload.
+    pub fn store_readback(&mut self, cells: &[u32]) {
+        let count = self.cells.len().min(cells.len());
+        self.cells[..count].copy_from_slice(&cells[..count]);
+    }
+
+    /// Gets the GPU buffer, if it has been created.
+    #[must_use]
+    pub fn gpu_buffer(&self) -> Option<&wgpu::Buffer> {
+        self.gpu.as_ref()
+    }
+
+    /// Uploads changed cells, (re)allocating the GPU buffer if needed.
+    pub fn sync_gpu(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        if !self.dirty && self.gpu.is_some() {
+            return;
+        }
+        let bytes: &[u8] = bytemuck::cast_slice(&self.cells);
+        let buffer = self.gpu.get_or_insert_with(|| {
+            device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Cell Buffer"),
+                size: (bytes.len() as u64).max(4),
+                usage: wgpu::BufferUsages::STORAGE
+                    | wgpu::BufferUsages::COPY_DST
+                    | wgpu::BufferUsages::COPY_SRC,
+                mapped_at_creation: false,
+            })
+        });
+        queue.write_buffer(buffer, 0, bytes);
+        self.dirty = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filled(dims: CellDims) -> CellBuffers {
+        let mut buffers = CellBuffers::new(dims);
+        for y in dims.origin_y..dims.origin_y + dims.height as i32 {
+            for x in dims.origin_x..dims.origin_x + dims.width as i32 {
+                buffers.set(x, y, (x * 100 + y) as u32);
+            }
+        }
+        buffers
+    }
+
+    #[test]
+    fn test_resize_larger_preserves_cells() {
+        let old = CellDims::new(0, 0, 4, 3);
+        let mut buffers = filled(old);
+
+        // Grow in every direction, including negative coordinates
+        buffers.resize(CellDims::new(-2, -1, 8, 6));
+        for y in 0..3 {
+            for x in 0..4 {
+                assert_eq!(buffers.get(x, y), Some((x * 100 + y) as u32));
+            }
+        }
+        assert_eq!(buffers.get(-2, -1), Some(0));
+        assert_eq!(buffers.get(5, 4), Some(0));
+        assert_eq!(buffers.cells().len(), 48);
+    }
+
+    #[test]
+    fn test_resize_shifted_drops_outside_cells() {
+        let mut buffers = filled(CellDims::new(0, 0, 4, 4));
+        buffers.resize(CellDims::new(2, 2, 4, 4));
+
+        assert_eq!(buffers.get(2, 2), Some(202));
+        assert_eq!(buffers.get(3, 3), Some(303));
+        assert_eq!(buffers.get(4, 4), Some(0));
+        assert_eq!(buffers.get(1, 1), None);
+    }
+
+    #[test]
+    fn test_store_readback_replaces_cells_without_upload() {
+        let mut buffers = CellBuffers::new(CellDims::new(0, 0, 2, 2));
+        buffers.dirty = false;
+
+        buffers.store_readback(&[1, 2, 3, 4]);
+        assert_eq!(buffers.cells(), &[1, 2, 3, 4]);
+        assert_eq!(buffers.get(1, 1), Some(4));
+        assert!(!buffers.dirty);
+    }
+}
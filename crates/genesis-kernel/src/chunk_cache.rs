@@ -0,0 +1,464 @@
+//! Memory-budgeted chunk cache for terrain streaming.
+//!
+//! [`ChunkCache`] tracks how many bytes each cached chunk uses and when it
+//! was last used. Once the total goes over
+//! [`StreamingConfig::max_memory_bytes`], the least recently used chunks
+//! are evicted first, furthest from the streaming center on ties, no
+//! matter how far the render radius reaches. This keeps large render distances from
+//! running low-memory machines out of memory.
+//!
+//! With [`StreamingConfig::deterministic`] set, [`ChunkCache::plan_generation`]
+//! generates chunks in an order that depends only on the path the camera
+//! took, not on how often it was sampled, so repeated runs (screenshot
+//! tests, fixed-step automation) end up with identical caches.
+
+use std::collections::{HashMap, HashSet};
+
+/// Chunk coordinate key `(x, y)`.
+pub type ChunkKey = (i32, i32);
+
+/// Default memory budget for cached chunks (256 MiB).
+pub const DEFAULT_MAX_MEMORY_BYTES: usize = 256 * 1024 * 1024;
+
+/// Longest camera jump, in chunks, that deterministic planning walks.
+/// Longer jumps (teleports) only plan the rings around the target.
+pub const MAX_PLANNED_PATH: i32 = 32;
+
+/// Streaming limits for cached chunks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamingConfig {
+    /// Maximum bytes held by cached chunks before eviction kicks in.
+    pub max_memory_bytes: usize,
+    /// Generate chunks in a fixed, sampling-independent order.
+    pub deterministic: bool,
+}
+
+impl Default for StreamingConfig {
+    fn default() -> Self {
+        Self {
+            max_memory_bytes: DEFAULT_MAX_MEMORY_BYTES,
+            deterministic: false,
+        }
+    }
+}
+
+/// Snapshot of chunk cache usage.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StreamingStats {
+    /// Number of cached chunks.
+    pub cached_chunks: usize,
+    /// Bytes currently held by cached chunks.
+    pub memory_bytes: usize,
+    /// Configured memory budget.
+    pub max_memory_bytes: usize,
+    /// Chunks evicted to stay within budget since creation.
+    pub evicted_chunks: u64,
+}
+
+#[derive(Debug)]
+struct Entry<T> {
+    value: T,
+    bytes: usize,
+    last_used: u64,
+}
+
+/// Chunk cache that evicts least recently used chunks over a memory budget.
+#[derive(Debug)]
+pub struct ChunkCache<T> {
+    entries: HashMap<ChunkKey, Entry<T>>,
+    config: StreamingConfig,
+    center: ChunkKey,
+    memory_bytes: usize,
+    clock: u64,
+    evicted: u64,
+}
+
+impl<T> Default for ChunkCache<T> {
+    fn default() -> Self {
+        Self::new(StreamingConfig::default())
+    }
+}
+
+impl<T> ChunkCache<T> {
+    /// Creates an empty cache with the given limits.
+    #[must_use]
+    pub fn new(config: StreamingConfig) -> Self {
+        Self {
+            entries: HashMap::new(),
+            config,
+            center: (0, 0),
+            memory_bytes: 0,
+            clock: 0,
+            evicted: 0,
+        }
+    }
+
+    /// Current limits.
+    #[must_use]
+    pub fn config(&self) -> StreamingConfig {
+        self.config
+    }
+
+    /// Changes the limits. Call [`Self::enforce_budget`] to apply a smaller
+    /// budget right away.
+    pub fn set_config(&mut self, config: StreamingConfig) {
+        self.config = config;
+    }
+
+    /// Sets the chunk the camera is in, which [`Self::insert`] keeps
+    /// nearby chunks around.
+    pub fn set_center(&mut self, center: ChunkKey) {
+        self.center = center;
+    }
+
+    /// Starts a new frame. Chunks touched until the next tick count as used
+    /// at the same time, so distance from the center decides between them.
+    pub fn tick(&mut self) {
+        self.clock += 1;
+    }
+
+    /// Inserts a chunk using `bytes` of memory, replacing any previous one.
+    ///
+    /// The new chunk counts as just used, so the budget check that follows
+    /// evicts it last. Returns the chunks evicted to make room, furthest
+    /// from the center (see [`Self::set_center`]) first on ties.
+    pub fn insert(&mut self, key: ChunkKey, value: T, bytes: usize) -> Vec<ChunkKey> {
+        self.clock += 1;
+        let entry = Entry {
+            value,
+            bytes,
+            last_used: self.clock,
+        };
+        if let Some(old) = self.entries.insert(key, entry) {
+            self.memory_bytes -= old.bytes;
+        }
+        self.memory_bytes += bytes;
+        self.enforce_budget(self.center)
+    }
+
+    /// Gets a chunk without marking it used.
+    #[must_use]
+    pub fn get(&self, key: ChunkKey) -> Option<&T> {
+        self.entries.get(&key).map(|e| &e.value)
+    }
+
+    /// Gets a chunk and marks it as used this frame (see [`Self::tick`]).
+    pub fn touch(&mut self, key: ChunkKey) -> Option<&T> {
+        let clock = self.clock;
+        self.entries.get_mut(&key).map(|e| {
+            e.last_used = clock;
+            &e.value
+        })
+    }
+
+    /// Whether a chunk is cached.
+    #[must_use]
+    pub fn contains(&self, key: ChunkKey) -> bool {
+        self.entries.contains_key(&key)
+    }
+
+    /// Removes a chunk.
+    pub fn remove(&mut self, key: ChunkKey) -> Option<T> {
+        self.entries.remove(&key).map(|e| {
+            self.memory_bytes -= e.bytes;
+            e.value
+        })
+    }
+
+    /// Removes every chunk.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.memory_bytes = 0;
+    }
+
+    /// Number of cached chunks.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the cache is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Coordinates of all cached chunks.
+    pub fn keys(&self) -> impl Iterator<Item = ChunkKey> + '_ {
+        self.entries.keys().copied()
+    }
+
+    /// Bytes currently held by cached chunks.
+    #[must_use]
+    pub fn memory_bytes(&self) -> usize {
+        self.memory_bytes
+    }
+
+    /// Evicts chunks until the cache fits its memory budget.
+    ///
+    /// Least recently used chunks go first; among equally old chunks the
+    /// one furthest from `center` goes first. Returns the evicted keys.
+    pub fn enforce_budget(&mut self, center: ChunkKey) -> Vec<ChunkKey> {
+        let mut evicted = Vec::new();
+        if self.memory_bytes <= self.config.max_memory_bytes {
+            return evicted;
+        }
+
+        let distance = |key: ChunkKey| {
+            let dx = i64::from(key.0) - i64::from(center.0);
+            let dy = i64::from(key.1) - i64::from(center.1);
+            dx * dx + dy * dy
+        };
+        let mut order: Vec<(u64, i64, ChunkKey)> = self
+            .entries
+            .iter()
+            .map(|(&key, e)| (e.last_used, -distance(key), key))
+            .collect();
+        order.sort_unstable();
+
+        for (_, _, key) in order {
+            if self.memory_bytes <= self.config.max_memory_bytes {
+                break;
+            }
+            self.remove(key);
+            evicted.push(key);
+        }
+        self.evicted += evicted.len() as u64;
+        evicted
+    }
+
+    /// Missing chunks within `radius` of the streaming center, in the order
+    /// they should be generated.
+    ///
+    /// Normally only `center` is considered and chunks come in row order.
+    /// In deterministic mode every chunk center on the grid line from
+    /// `previous` (exclusive) to `center` is visited, so skipping chunks
+    /// between frames doesn't change the result, and each ring is ordered
+    /// nearest first with ties broken by `(y, x)`. Jumps longer than
+    /// [`MAX_PLANNED_PATH`] chunks plan only the ring around `center`.
+    #[must_use]
+    pub fn plan_generation(
+        &self,
+        previous: Option<ChunkKey>,
+        center: ChunkKey,
+        radius: i32,
+    ) -> Vec<ChunkKey> {
+        let mut planned = Vec::new();
+        if !self.config.deterministic {
+            for y in (center.1 - radius)..=(center.1 + radius) {
+                for x in (center.0 - radius)..=(center.0 + radius) {
+                    if !self.contains((x, y)) {
+                        planned.push((x, y));
+                    }
+                }
+            }
+            return planned;
+        }
+
+        let centers = match previous {
+            Some(from) if from != center && chunk_distance(from, center) <= MAX_PLANNED_PATH => {
+                chunk_line(from, center)
+            },
+            _ => vec![center],
+        };
+        let mut seen = HashSet::new();
+        for step in centers {
+            let mut ring: Vec<ChunkKey> = ((step.1 - radius)..=(step.1 + radius))
+                .flat_map(|y| ((step.0 - radius)..=(step.0 + radius)).map(move |x| (x, y)))
+                .filter(|&key| !self.contains(key) && !seen.contains(&key))
+                .collect();
+            ring.sort_unstable_by_key(|&(x, y)| {
+                let (dx, dy) = (i64::from(x - step.0), i64::from(y - step.1));
+                (dx * dx + dy * dy, y, x)
+            });
+            seen.extend(ring.iter().copied());
+            planned.extend(ring);
+        }
+        planned
+    }
+
+    /// Current usage statistics.
+    #[must_use]
+    pub fn stats(&self) -> StreamingStats {
+        StreamingStats {
+            cached_chunks: self.entries.len(),
+            memory_bytes: self.memory_bytes,
+            max_memory_bytes: self.config.max_memory_bytes,
+            evicted_chunks: self.evicted,
+        }
+    }
+}
+
+/// Chebyshev distance between two chunks, saturating at `i32::MAX`.
+fn chunk_distance(a: ChunkKey, b: ChunkKey) -> i32 {
+    let dx = (i64::from(a.0) - i64::from(b.0)).abs();
+    let dy = (i64::from(a.1) - i64::from(b.1)).abs();
+    i32::try_from(dx.max(dy)).unwrap_or(i32::MAX)
+}
+
+/// Chunk centers on the grid line from `from` (exclusive) to `to`
+/// (inclusive), one step along the longer axis at a time.
+fn chunk_line(from: ChunkKey, to: ChunkKey) -> Vec<ChunkKey> {
+    let dx = i64::from(to.0) - i64::from(from.0);
+    let dy = i64::from(to.1) - i64::from(from.1);
+    let steps = dx.abs().max(dy.abs());
+    (1..=steps)
+        .map(|i| {
+            // Round to nearest, halves toward +infinity
+            let x = i64::from(from.0) + (2 * dx * i + steps).div_euclid(2 * steps);
+            let y = i64::from(from.1) + (2 * dy * i + steps).div_euclid(2 * steps);
+            (x as i32, y as i32)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache(max_memory_bytes: usize) -> ChunkCache<u32> {
+        ChunkCache::new(StreamingConfig {
+            max_memory_bytes,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_over_budget_evicts_oldest_chunks() {
+        let mut cache = cache(300);
+        assert!(cache.insert((0, 0), 0, 100).is_empty());
+        assert!(cache.insert((1, 0), 1, 100).is_empty());
+        assert!(cache.insert((2, 0), 2, 100).is_empty());
+
+        // Using (0, 0) again makes (1, 0) the oldest
+        cache.tick();
+        cache.touch((0, 0));
+        assert_eq!(cache.insert((3, 0), 3, 100), vec![(1, 0)]);
+        assert_eq!(cache.memory_bytes(), 300);
+        assert!(cache.contains((0, 0)));
+
+        // A large chunk can push out several
+        assert_eq!(cache.insert((4, 0), 4, 200), vec![(2, 0), (0, 0)]);
+
+        let stats = cache.stats();
+        assert_eq!(stats.cached_chunks, 2);
+        assert_eq!(stats.memory_bytes, 300);
+        assert_eq!(stats.evicted_chunks, 3);
+    }
+
+    #[test]
+    fn test_shrinking_budget_evicts_furthest_first() {
+        let mut cache = cache(usize::MAX);
+        for x in -3..=3 {
+            cache.insert((x, 0), 0, 10);
+        }
+        // Mark everything as used in the same frame
+        cache.clock += 1;
+        let frame = cache.clock;
+        for entry in cache.entries.values_mut() {
+            entry.last_used = frame;
+        }
+
+        cache.set_config(StreamingConfig {
+            max_memory_bytes: 30,
+            ..Default::default()
+        });
+        let mut evicted = cache.enforce_budget((0, 0));
+        evicted.sort_unstable();
+        assert_eq!(evicted, vec![(-3, 0), (-2, 0), (2, 0), (3, 0)]);
+        assert_eq!(cache.len(), 3);
+        assert!(cache.contains((0, 0)));
+    }
+
+    #[test]
+    fn test_chunks_touched_in_one_frame_evict_furthest_first() {
+        let mut cache = cache(30);
+        cache.insert((0, 0), 0, 10);
+        cache.insert((3, 0), 3, 10);
+        cache.insert((1, 0), 1, 10);
+
+        // One frame draws all three; the camera has moved to (1, 0)
+        cache.tick();
+        for key in [(0, 0), (3, 0), (1, 0)] {
+            cache.touch(key);
+        }
+        cache.set_center((1, 0));
+        assert_eq!(cache.insert((2, 0), 2, 10), vec![(3, 0)]);
+    }
+
+    #[test]
+    fn test_replacing_chunk_updates_memory() {
+        let mut cache = cache(1000);
+        cache.insert((0, 0), 1, 400);
+        cache.insert((0, 0), 2, 100);
+        assert_eq!(cache.memory_bytes(), 100);
+        assert_eq!(cache.get((0, 0)), Some(&2));
+        assert_eq!(cache.remove((0, 0)), Some(2));
+        assert_eq!(cache.memory_bytes(), 0);
+    }
+
+    /// Streams along `path`, generating chunk data from the key alone.
+    fn stream(path: &[ChunkKey]) -> ChunkCache<u64> {
+        let mut cache = ChunkCache::new(StreamingConfig {
+            max_memory_bytes: 60,
+            deterministic: true,
+        });
+        let mut previous = None;
+        for &center in path {
+            for key in cache.plan_generation(previous, center, 1) {
+                let data = (i64::from(key.0) * 31 + i64::from(key.1)) as u64;
+                cache.insert(key, data, 1);
+            }
+            previous = Some(center);
+        }
+        cache
+    }
+
+    fn snapshot(cache: &ChunkCache<u64>) -> Vec<(ChunkKey, u64)> {
+        let mut chunks: Vec<_> = cache
+            .keys()
+            .map(|key| (key, *cache.get(key).expect("cached")))
+            .collect();
+        chunks.sort_unstable();
+        chunks
+    }
+
+    #[test]
+    fn test_deterministic_runs_match_regardless_of_sampling() {
+        // The same walk sampled every chunk and every few chunks
+        let fine: Vec<ChunkKey> = (0..=12)
+            .map(|x| (x, 0))
+            .chain((1..=9).map(|y| (12, y)))
+            .collect();
+        let coarse = [(0, 0), (5, 0), (12, 0), (12, 4), (12, 9)];
+
+        let a = stream(&fine);
+        let b = stream(&fine);
+        let c = stream(&coarse);
+        assert_eq!(snapshot(&a), snapshot(&b));
+        assert_eq!(snapshot(&a), snapshot(&c));
+        assert_eq!(a.stats(), c.stats());
+        assert!(a.stats().evicted_chunks > 0);
+    }
+
+    #[test]
+    fn test_deterministic_plan_is_nearest_first() {
+        let cache: ChunkCache<u64> = ChunkCache::new(StreamingConfig {
+            deterministic: true,
+            ..Default::default()
+        });
+        let plan = cache.plan_generation(None, (4, -2), 1);
+        assert_eq!(plan.len(), 9);
+        assert_eq!(plan[0], (4, -2));
+        assert_eq!(&plan[1..5], &[(4, -3), (3, -2), (5, -2), (4, -1)]);
+
+        // Jumping two chunks also covers the chunk in between
+        let plan = cache.plan_generation(Some((0, 0)), (2, 0), 0);
+        assert_eq!(plan, vec![(1, 0), (2, 0)]);
+
+        // A teleport only plans the target ring
+        let plan = cache.plan_generation(Some((0, 0)), (1000, 0), 1);
+        assert_eq!(plan.len(), 9);
+        assert_eq!(plan[0], (1000, 0));
+    }
+}
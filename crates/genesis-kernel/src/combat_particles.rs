@@ -6,6 +6,9 @@
 //! - Impact dust for terrain hits
 //! - Particle pooling for performance
 //!
+//! Randomness comes from a [`SeededRng`], so the same seed and the same spawn
+//! calls always produce the same particles (replays, tests).
+//!
 //! # Example
 //!
 //! ```
@@ -24,6 +27,8 @@
 
 use std::collections::VecDeque;
 
+use genesis_common::SeededRng;
+
 /// Type of combat effect.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[repr(u8)]
@@ -119,7 +124,7 @@ impl CombatEffectType {
 }
 
 /// A single combat particle.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct CombatParticle {
     /// Position (x, y).
     pub position: (f32, f32),
@@ -484,7 +489,7 @@ impl ParticlePool {
 pub struct CombatParticleManager {
     pool: ParticlePool,
     gravity: f32,
-    seed: u32,
+    rng: SeededRng,
     hit_spark_config: HitSparkEffect,
     blood_config: BloodSplatterEffect,
     dust_config: ImpactDustEffect,
@@ -497,13 +502,30 @@ impl CombatParticleManager {
         Self {
             pool: ParticlePool::with_capacity(512),
             gravity: 400.0,
-            seed: 12345,
+            rng: SeededRng::default(),
             hit_spark_config: HitSparkEffect::default(),
             blood_config: BloodSplatterEffect::default(),
             dust_config: ImpactDustEffect::default(),
         }
     }
 
+    /// Create with a seeded random number generator.
+    ///
+    /// Two managers with the same seed produce identical particles for the
+    /// same sequence of spawn calls.
+    #[must_use]
+    pub fn with_rng(seed: u64) -> Self {
+        Self {
+            rng: SeededRng::new(seed),
+            ..Self::new()
+        }
+    }
+
+    /// Reseed the random number generator (e.g. at the start of a replay).
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng.reseed(seed);
+    }
+
     /// Create with custom capacity.
     #[must_use]
     pub fn with_capacity(capacity: usize) -> Self {
@@ -533,15 +555,14 @@ impl CombatParticleManager {
         self.dust_config = config;
     }
 
-    /// Simple random number generator.
+    /// Random value in `[0, 1)`.
     fn random(&mut self) -> f32 {
-        self.seed = self.seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
-        self.seed as f32 / u32::MAX as f32
+        self.rng.next_f32()
     }
 
     /// Random in range.
     fn random_range(&mut self, min: f32, max: f32) -> f32 {
-        min + self.random() * (max - min)
+        self.rng.range(min, max)
     }
 
     /// Spawn hit spark effect.
@@ -816,6 +837,11 @@ impl CombatParticleManager {
         self.pool.active_count()
     }
 
+    /// Iterate over active particles.
+    pub fn active_particles(&self) -> impl Iterator<Item = &CombatParticle> {
+        self.pool.active_particles()
+    }
+
     /// Clear all particles.
     pub fn clear(&mut self) {
         self.pool.clear();
@@ -988,4 +1014,52 @@ mod tests {
         assert!(blood_color[0] > 0.7);
         assert!(blood_color[1] < 0.2);
     }
+
+    #[test]
+    fn test_manager_same_seed_identical_particles() {
+        let spawn = |manager: &mut CombatParticleManager| {
+            manager.spawn_hit_spark((10.0, 20.0), (1.0, 0.0), 1.0);
+            manager.spawn_blood_splatter((5.0, 5.0), (0.0, 1.0), 0.8);
+            manager.spawn_impact_dust((0.0, 0.0), 1.0);
+            manager.update(1.0 / 60.0);
+            manager.spawn_effect(CombatEffectType::MagicImpact, (3.0, 4.0), (1.0, 1.0), 1.0);
+            manager.update(1.0 / 60.0);
+        };
+
+        let mut a = CombatParticleManager::with_rng(99);
+        let mut b = CombatParticleManager::with_rng(99);
+        spawn(&mut a);
+        spawn(&mut b);
+
+        let particles_a: Vec<CombatParticle> = a.active_particles().copied().collect();
+        let particles_b: Vec<CombatParticle> = b.active_particles().copied().collect();
+        assert!(!particles_a.is_empty());
+        assert_eq!(particles_a, particles_b);
+    }
+
+    #[test]
+    fn test_manager_different_seed_differs() {
+        let mut a = CombatParticleManager::with_rng(1);
+        let mut b = CombatParticleManager::with_rng(2);
+        a.spawn_hit_spark((0.0, 0.0), (1.0, 0.0), 1.0);
+        b.spawn_hit_spark((0.0, 0.0), (1.0, 0.0), 1.0);
+
+        let particles_a: Vec<CombatParticle> = a.active_particles().copied().collect();
+        let particles_b: Vec<CombatParticle> = b.active_particles().copied().collect();
+        assert_ne!(particles_a, particles_b);
+    }
+
+    #[test]
+    fn test_manager_reseed_replays() {
+        let mut manager = CombatParticleManager::with_rng(5);
+        manager.spawn_impact_dust((0.0, 0.0), 1.0);
+        let first: Vec<CombatParticle> = manager.active_particles().copied().collect();
+
+        manager.clear();
+        manager.reseed(5);
+        manager.spawn_impact_dust((0.0, 0.0), 1.0);
+        let replayed: Vec<CombatParticle> = manager.active_particles().copied().collect();
+
+        assert_eq!(first, replayed);
+    }
 }
@@ -0,0 +1,619 @@
+//! Cell simulation compute kernel.
+//!
+//! [`Compute`] steps a grid of material cells on the GPU. How each material
+//! behaves (falling, flowing, reacting) comes from a [`RuleTable`] bound as a
+//! storage buffer, so a new material behavior is a table entry rather than a
+//! shader change.
+//!
+//! Ticks alternate between a vertical pass, where falling cells sink through
+//! lighter ones, and a horizontal pass, where liquids spread sideways. Cells
+//! are paired for each pass so both cells of a pair make the same swap
+//! decision from the same input.
+//!
+//! A [`SimulationControl`] gates how many ticks [`Compute::run`] performs so
+//! the simulation can be paused and single-stepped. Reactions are appended to
+//! a [`SimEventBuffer`] that is read back asynchronously and collected with
+//! [`Compute::poll_events`].
+
+use std::sync::mpsc::{Receiver, TryRecvError};
+
+use bytemuck::{Pod, Zeroable};
+use tracing::{debug, warn};
+use wgpu::util::DeviceExt;
+
+use crate::cell_buffer::{CellBuffers, CellDims};
+use crate::sim_control::SimulationControl;
+use crate::sim_events::{
+    decode_events, sim_events_wgsl, SimEventBatch, SimEventBuffer, DEFAULT_EVENT_CAPACITY,
+};
+use crate::sim_rules::RuleTable;
+
+/// Workgroup edge length of the simulation shader.
+const WORKGROUP_SIZE: u32 = 16;
+
+/// Binding of the event buffer in the simulation bind group.
+pub const EVENT_BINDING: u32 = 4;
+
+/// Cell simulation compute shader in WGSL.
+///
+/// Reactions are reported with `emit_reaction`, so the shader is compiled
+/// with [`sim_events_wgsl`] for group 0, [`EVENT_BINDING`] prepended (see
+/// [`cell_sim_shader`]).
+pub const CELL_SIM_SHADER: &str = r"
+// Parameters (32 bytes)
+struct Params {
+    origin_x: i32,
+    origin_y: i32,
+    width: u32,
+    height: u32,
+    tick: u32,
+    seed: u32,
+    _pad0: u32,
+    _pad1: u32,
+}
+
+// Material rule (32 bytes), see GpuMaterialRule
+struct Rule {
+    density: f32,
+    flow_rate: f32,
+    flags: u32,
+    reacts_with: u32,
+    reaction_product: u32,
+    reaction_chance: f32,
+    _pad0: u32,
+    _pad1: u32,
+}
+
+@group(0) @binding(0) var<uniform> params: Params;
+@group(0) @binding(1) var<storage, read> rules: array<Rule>;
+@group(0) @binding(2) var<storage, read> cells_in: array<u32>;
+@group(0) @binding(3) var<storage, read_write> cells_out: array<u32>;
+
+const RULE_FALLS: u32 = 1u;
+const RULE_FLOWS: u32 = 2u;
+const RULE_REACTS: u32 = 4u;
+
+// Marks positions outside the simulated region
+const NO_CELL: u32 = 0xffffffffu;
+
+fn cell_at(x: i32, y: i32) -> u32 {
+    if x < 0 || y < 0 || u32(x) >= params.width || u32(y) >= params.height {
+        return NO_CELL;
+    }
+    return cells_in[u32(y) * params.width + u32(x)];
+}
+
+fn material(cell: u32) -> u32 {
+    return cell & 0xffu;
+}
+
+fn is_material(x: i32, y: i32, id: u32) -> bool {
+    let cell = cell_at(x, y);
+    return cell != NO_CELL && material(cell) == id;
+}
+
+// Uniform random value in [0, 1) for a world cell, tick and purpose
+fn random(x: i32, y: i32, salt: u32) -> f32 {
+    var h = u32(x + params.origin_x) * 374761393u
+        + u32(y + params.origin_y) * 668265263u
+        + (params.tick ^ params.seed) * 2246822519u
+        + salt * 3266489917u;
+    h = (h ^ (h >> 13u)) * 1274126177u;
+    h = h ^ (h >> 16u);
+    return f32(h & 0xffffffu) / 16777216.0;
+}
+
+// The cell a reaction turns (x, y) into this tick, or NO_CELL
+fn reaction_at(x: i32, y: i32) -> u32 {
+    let cell = cell_at(x, y);
+    if cell == NO_CELL {
+        return NO_CELL;
+    }
+    let rule = rules[material(cell)];
+    if (rule.flags & RULE_REACTS) == 0u {
+        return NO_CELL;
+    }
+    let touching = is_material(x - 1, y, rule.reacts_with)
+        || is_material(x + 1, y, rule.reacts_with)
+        || is_material(x, y - 1, rule.reacts_with)
+        || is_material(x, y + 1, rule.reacts_with);
+    if touching && random(x, y, 1u) < rule.reaction_chance {
+        return (cell & ~0xffu) | (rule.reaction_product & 0xffu);
+    }
+    return NO_CELL;
+}
+
+// Whether a cell can be pushed aside: empty space or something loose
+fn displaceable(rule: Rule) -> bool {
+    return (rule.flags & RULE_FALLS) != 0u || rule.density <= 0.0;
+}
+
+// Whether cell a (above or left) swaps with its pair b this tick
+fn swaps(ax: i32, ay: i32, bx: i32, by: i32, vertical: bool) -> bool {
+    let a = cell_at(ax, ay);
+    let b = cell_at(bx, by);
+    if a == NO_CELL || b == NO_CELL {
+        return false;
+    }
+    if reaction_at(ax, ay) != NO_CELL || reaction_at(bx, by) != NO_CELL {
+        return false;
+    }
+    let ra = rules[material(a)];
+    let rb = rules[material(b)];
+    if vertical {
+        return (ra.flags & RULE_FALLS) != 0u && ra.density > rb.density && displaceable(rb);
+    }
+    let a_flows = (ra.flags & RULE_FLOWS) != 0u && ra.density > rb.density && displaceable(rb);
+    let b_flows = (rb.flags & RULE_FLOWS) != 0u && rb.density > ra.density && displaceable(ra);
+    let rate = select(rb.flow_rate, ra.flow_rate, a_flows);
+    return (a_flows || b_flows) && random(ax, ay, 2u) < rate;
+}
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    if id.x >= params.width || id.y >= params.height {
+        return;
+    }
+    let x = i32(id.x);
+    let y = i32(id.y);
+    let index = id.y * params.width + id.x;
+
+    let reacted = reaction_at(x, y);
+    if reacted != NO_CELL {
+        cells_out[index] = reacted;
+        emit_reaction(
+            x + params.origin_x,
+            y + params.origin_y,
+            material(cells_in[index]),
+            material(reacted),
+        );
+        return;
+    }
+
+    // Even ticks pair cells vertically, odd ticks horizontally; the pair
+    // offset alternates so cells are not always paired the same way
+    let vertical = (params.tick & 1u) == 0u;
+    let offset = i32((params.tick >> 1u) & 1u);
+    var cell = cells_in[index];
+    if vertical {
+        if ((y + offset) & 1) == 0 {
+            if swaps(x, y, x, y + 1, true) {
+                cell = cell_at(x, y + 1);
+            }
+        } else if swaps(x, y - 1, x, y, true) {
+            cell = cell_at(x, y - 1);
+        }
+    } else {
+        if ((x + offset) & 1) == 0 {
+            if swaps(x, y, x + 1, y, false) {
+                cell = cell_at(x + 1, y);
+            }
+        } else if swaps(x - 1, y, x, y, false) {
+            cell = cell_at(x - 1, y);
+        }
+    }
+    cells_out[index] = cell;
+}
+";
+
+/// Returns the complete simulation shader source, including the event
+/// buffer declarations.
+#[must_use]
+pub fn cell_sim_shader() -> String {
+    sim_events_wgsl(0, EVENT_BINDING) + CELL_SIM_SHADER
+}
+
+/// Parameters for one simulation tick.
+/// Layout: 32 bytes total.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Pod, Zeroable)]
+#[repr(C)]
+pub struct SimParams {
+    /// World X of the first column (4 bytes).
+    pub origin_x: i32,
+    /// World Y of the first row (4 bytes).
+    pub origin_y: i32,
+    /// Width in cells (4 bytes).
+    pub width: u32,
+    /// Height in cells (4 bytes).
+    pub height: u32,
+    /// Tick number, selects the pass and pairing (4 bytes).
+    pub tick: u32,
+    /// Random seed (4 bytes).
+    pub seed: u32,
+    /// Padding for alignment.
+    padding: [u32; 2],
+}
+
+/// GPU cell simulation.
+///
+/// Cells are `u32`s whose low byte is the material ID used to look up the
+/// [`RuleTable`]. They live in [`CellBuffers`], whose GPU buffer holds the
+/// current state; the CPU copy is refreshed with [`Compute::read_back`].
+pub struct Compute {
+    /// Compute pipeline.
+    pipeline: wgpu::ComputePipeline,
+    /// Bind group layout, kept to rebuild the bind group on resize.
+    bind_group_layout: wgpu::BindGroupLayout,
+    /// Bind group, rebuilt when the cell buffers are reallocated.
+    bind_group: Option<wgpu::BindGroup>,
+    /// Tick parameters buffer.
+    params_buffer: wgpu::Buffer,
+    /// Rule table buffer.
+    rule_buffer: wgpu::Buffer,
+    /// Current cells.
+    cells: CellBuffers,
+    /// Cells written by the running tick, copied back into `cells`.
+    next_cells: Option<wgpu::Buffer>,
+    /// Rules waiting to be uploaded.
+    pending_rules: Option<RuleTable>,
+    /// Current parameters.
+    params: SimParams,
+    /// Pause and single-step state.
+    control: SimulationControl,
+    /// Reaction events emitted by the shader.
+    events: SimEventBuffer,
+    /// Mappable copy of the event buffer.
+    event_staging: wgpu::Buffer,
+    /// Completion of the pending event readback, if one is in flight.
+    event_readback: Option<Receiver<Result<(), wgpu::BufferAsyncError>>>,
+}
+
+impl Compute {
+    /// Creates a simulation of empty cells covering `dims`.
+    #[must_use]
+    pub fn new(device: &wgpu::Device, dims: CellDims, rules: &RuleTable) -> Self {
+        let params = SimParams {
+            origin_x: dims.origin_x,
+            origin_y: dims.origin_y,
+            width: dims.width,
+            height: dims.height,
+            ..Default::default()
+        };
+        let params_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Cell Sim Params Buffer"),
+            contents: bytemuck::bytes_of(&params),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+        let rule_buffer = rules.create_buffer(device);
+        let events = SimEventBuffer::new(device, DEFAULT_EVENT_CAPACITY);
+        let event_staging = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Cell Sim Event Staging Buffer"),
+            size: SimEventBuffer::size_bytes(DEFAULT_EVENT_CAPACITY),
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let storage = |binding, read_only| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Cell Sim Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                storage(1, true),
+                storage(2, true),
+                storage(3, false),
+                storage(EVENT_BINDING, false),
+            ],
+        });
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Cell Sim Shader"),
+            source: wgpu::ShaderSource::Wgsl(cell_sim_shader().into()),
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Cell Sim Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Cell Sim Compute Pipeline"),
+            layout: Some(&pipeline_layout),
+            module: &shader,
+            entry_point: Some("main"),
+            compilation_options: wgpu::PipelineCompilationOptions::default(),
+            cache: None,
+        });
+
+        debug!(
+            "Created cell simulation with {}x{} cells",
+            dims.width, dims.height
+        );
+
+        Self {
+            pipeline,
+            bind_group_layout,
+            bind_group: None,
+            params_buffer,
+            rule_buffer,
+            cells: CellBuffers::new(dims),
+            next_cells: None,
+            pending_rules: None,
+            params,
+            control: SimulationControl::new(),
+            events,
+            event_staging,
+            event_readback: None,
+        }
+    }
+
+    /// Replaces the material rules; they are uploaded before the next tick.
+    pub fn set_rule_table(&mut self, rules: &RuleTable) {
+        self.pending_rules = Some(rules.clone());
+    }
+
+    /// Returns the cell buffers.
+    ///
+    /// The CPU copy reflects the last [`Self::read_back`], not later ticks.
+    #[must_use]
+    pub fn cells(&self) -> &CellBuffers {
+        &self.cells
+    }
+
+    /// Returns the number of simulated cells.
+    #[must_use]
+    pub fn cell_count(&self) -> u64 {
+        self.cells.dims().cell_count() as u64
+    }
+
+    /// Moves or resizes the simulated region.
+    ///
+    /// Cells still inside the new region keep their current (GPU) state.
+    pub fn resize(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, dims: CellDims) {
+        if dims == self.cells.dims() {
+            return;
+        }
+        self.read_back(device, queue);
+        if dims.cell_count() != self.cells.dims().cell_count() {
+            self.bind_group = None;
+            self.next_cells = None;
+        }
+        self.cells.resize(dims);
+        self.params.origin_x = dims.origin_x;
+        self.params.origin_y = dims.origin_y;
+        self.params.width = dims.width;
+        self.params.height = dims.height;
+        debug!(
+            "Resized cell simulation to {}x{} at ({}, {})",
+            dims.width, dims.height, dims.origin_x, dims.origin_y
+        );
+    }
+
+    /// Copies the GPU cells into the CPU copy, blocking until done.
+    pub fn read_back(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        let Some(gpu) = self.cells.gpu_buffer() else {
+            return;
+        };
+        let staging = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Cell Sim Readback Buffer"),
+            size: gpu.size(),
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Cell Sim Readback Encoder"),
+        });
+        encoder.copy_buffer_to_buffer(gpu, 0, &staging, 0, gpu.size());
+        queue.submit(std::iter::once(encoder.finish()));
+
+        let slice = staging.slice(..);
+        let (tx, rx) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = tx.send(result);
+        });
+        device.poll(wgpu::Maintain::Wait);
+
+        match rx.recv() {
+            Ok(Ok(())) => {
+                let data = slice.get_mapped_range();
+                self.cells.store_readback(bytemuck::cast_slice(&data));
+                drop(data);
+                staging.unmap();
+            },
+            Ok(Err(e)) => warn!("Failed to map cell readback buffer: {}", e),
+            Err(e) => warn!("Failed to receive cell readback: {}", e),
+        }
+    }
+
+    /// Returns the number of ticks run so far.
+    #[must_use]
+    pub fn tick(&self) -> u32 {
+        self.params.tick
+    }
+
+    /// Pauses or resumes the simulation.
+    pub fn set_paused(&mut self, paused: bool) {
+        self.control.set_paused(paused);
+    }
+
+    /// Toggles pause and returns the new state.
+    pub fn toggle_paused(&mut self) -> bool {
+        self.control.toggle_paused()
+    }
+
+    /// Whether the simulation is paused.
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.control.is_paused()
+    }
+
+    /// Queues a single tick while paused.
+    pub fn step_once(&mut self) {
+        self.control.step_once();
+    }
+
+    /// Runs the ticks allowed this frame out of `requested`.
+    ///
+    /// While paused only ticks queued with [`Self::step_once`] run. Events
+    /// from the ticks are read back without blocking; collect them with
+    /// [`Self::poll_events`].
+    pub fn run(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, requested: u32) {
+        let ticks = self.control.ticks_to_run(requested);
+        for _ in 0..ticks {
+            self.step(device, queue);
+        }
+        if ticks > 0 {
+            self.start_event_readback(device, queue);
+        }
+    }
+
+    /// Copies the event buffer for reading and clears it for the next ticks.
+    ///
+    /// While a readback is in flight, events keep accumulating on the GPU
+    /// and are picked up by the next one.
+    fn start_event_readback(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        if self.event_readback.is_some() {
+            return;
+        }
+        let size = self.event_staging.size();
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Cell Sim Event Readback Encoder"),
+        });
+        encoder.copy_buffer_to_buffer(self.events.buffer(), 0, &self.event_staging, 0, size);
+        queue.submit(std::iter::once(encoder.finish()));
+        self.events.reset(queue);
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        self.event_staging
+            .slice(..)
+            .map_async(wgpu::MapMode::Read, move |result| {
+                let _ = tx.send(result);
+            });
+        self.event_readback = Some(rx);
+    }
+
+    /// Returns the events from the last finished readback, if any.
+    pub fn poll_events(&mut self, device: &wgpu::Device) -> Option<SimEventBatch> {
+        let rx = self.event_readback.as_ref()?;
+        device.poll(wgpu::Maintain::Poll);
+        let result = match rx.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => return None,
+            Err(TryRecvError::Disconnected) => Err(wgpu::BufferAsyncError),
+        };
+        self.event_readback = None;
+
+        if let Err(e) = result {
+            warn!("Failed to map simulation event buffer: {}", e);
+            return None;
+        }
+        let slice = self.event_staging.slice(..);
+        let batch = decode_events(&slice.get_mapped_range());
+        self.event_staging.unmap();
+        Some(batch)
+    }
+
+    /// Runs one simulation tick, ignoring pause.
+    pub fn step(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        if let Some(rules) = self.pending_rules.take() {
+            rules.upload(queue, &self.rule_buffer);
+        }
+        self.cells.sync_gpu(device, queue);
+        let Some(cells) = self.cells.gpu_buffer() else {
+            return;
+        };
+        let next_cells = self.next_cells.get_or_insert_with(|| {
+            device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Cell Sim Next Cells"),
+                size: cells.size(),
+                usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+                mapped_at_creation: false,
+            })
+        });
+        let bind_group = self.bind_group.get_or_insert_with(|| {
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Cell Sim Bind Group"),
+                layout: &self.bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: self.params_buffer.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: self.rule_buffer.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: cells.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 3,
+                        resource: next_cells.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: EVENT_BINDING,
+                        resource: self.events.buffer().as_entire_binding(),
+                    },
+                ],
+            })
+        });
+        queue.write_buffer(&self.params_buffer, 0, bytemuck::bytes_of(&self.params));
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Cell Sim Encoder"),
+        });
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("Cell Sim Pass"),
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &*bind_group, &[]);
+            pass.dispatch_workgroups(
+                self.params.width.div_ceil(WORKGROUP_SIZE),
+                self.params.height.div_ceil(WORKGROUP_SIZE),
+                1,
+            );
+        }
+        encoder.copy_buffer_to_buffer(next_cells, 0, cells, 0, cells.size());
+        queue.submit(std::iter::once(encoder.finish()));
+
+        self.params.tick = self.params.tick.wrapping_add(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim_rules::GpuMaterialRule;
+
+    #[test]
+    fn test_params_layout() {
+        assert_eq!(std::mem::size_of::<SimParams>(), 32);
+    }
+
+    #[test]
+    fn test_shader_declares_event_buffer() {
+        let shader = cell_sim_shader();
+        assert!(shader.contains(&format!("@group(0) @binding({EVENT_BINDING})")));
+        assert!(shader.contains("fn emit_reaction"));
+        assert!(CELL_SIM_SHADER.contains("emit_reaction("));
+    }
+
+    #[test]
+    fn test_shader_matches_rule_layout() {
+        // The WGSL Rule struct mirrors GpuMaterialRule field for field
+        assert_eq!(std::mem::size_of::<GpuMaterialRule>(), 32);
+        assert!(CELL_SIM_SHADER.contains("var<storage, read> rules: array<Rule>"));
+        assert!(CELL_SIM_SHADER.contains(&format!(
+            "@workgroup_size({WORKGROUP_SIZE}, {WORKGROUP_SIZE})"
+        )));
+    }
+}
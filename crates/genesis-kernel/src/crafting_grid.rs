@@ -632,7 +632,8 @@ impl RecipeMatcher {
 
     /// Check if grid matches a shapeless recipe.
     fn matches_shapeless(grid: &CraftingGrid, recipe: &RecipePattern) -> bool {
-        let grid_items = grid.unique_items();
+        // Every occupied slot counts, so repeated ingredients need one slot each
+        let grid_items: Vec<_> = grid.slots().iter().filter_map(ItemSlot::item).collect();
         let recipe_items = recipe.sorted_ingredients();
 
         // Count occurrences
@@ -857,6 +858,21 @@ mod tests {
         assert_eq!(matches.len(), 1);
     }
 
+    #[test]
+    fn test_recipe_matcher_shapeless_repeated_items() {
+        let mut matcher = RecipeMatcher::new();
+        matcher.register(RecipePattern::shapeless(&[1, 1, 2]).with_result(3, 1));
+
+        let mut grid = CraftingGrid::new(3, 3);
+        grid.set_slot(0, 0, Some(1));
+        grid.set_slot(1, 1, Some(2));
+        grid.set_slot(2, 2, Some(2));
+        assert!(matcher.find_first_match(&grid).is_none());
+
+        grid.set_slot(2, 2, Some(1));
+        assert!(matcher.find_first_match(&grid).is_some());
+    }
+
     #[test]
     fn test_recipe_matcher_mirror() {
         let mut matcher = RecipeMatcher::new();
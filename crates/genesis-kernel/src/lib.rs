@@ -47,14 +47,27 @@ pub mod npc_render;
 // Particles
 pub mod particles;
 
+// Cellular simulation
+pub mod cell_buffer;
+pub mod compute;
+pub mod readback;
+pub mod sim_control;
+pub mod sim_events;
+pub mod sim_rules;
+
 // Player sprite
 pub mod player_sprite;
 
 // Terrain tile rendering
+pub mod autotile;
+pub mod chunk_cache;
+pub mod lighting;
+pub mod terrain_assets;
 pub mod terrain_tiles;
 
 // Spatial indexing
 pub mod quadtree;
+pub mod spatial_hash;
 
 // Resolution management
 pub mod resolution;
@@ -71,7 +84,11 @@ pub mod menu_backdrop;
 /// Prelude for convenient imports
 pub mod prelude {
     pub use crate::audio::*;
+    pub use crate::autotile::*;
     pub use crate::camera::*;
+    pub use crate::cell_buffer::{CellBuffers, CellDims};
+    pub use crate::chunk_cache::{ChunkCache, StreamingConfig, StreamingStats};
+    pub use crate::lighting::LightingSystem;
     // Combat modules use explicit imports to avoid conflicts
     pub use crate::combat_collision::{
         CollisionResult, CombatBoxManager, CombatCollider, FrameRange, Hitbox,
@@ -81,6 +98,7 @@ pub mod prelude {
         BloodSplatterEffect, CombatEffectType, CombatParticle, CombatParticleInstance,
         CombatParticleManager, HitSparkEffect, ImpactDustEffect,
     };
+    pub use crate::compute::{cell_sim_shader, Compute, SimParams, CELL_SIM_SHADER, EVENT_BINDING};
     // Crafting modules use explicit imports to avoid conflicts
     pub use crate::crafting_anim::{
         AnimationPreset, AnimationState, CraftingProgress, SoundTrigger,
@@ -109,6 +127,7 @@ pub mod prelude {
         ProjectileType, TrajectoryPredictor,
     };
     pub use crate::quadtree::*;
+    pub use crate::spatial_hash::*;
     pub use crate::workbench::{
         CraftingStation, CraftingStationBuilder, StationRegistry, WorkbenchType, WorkbenchZone,
     };
@@ -117,14 +136,21 @@ pub mod prelude {
         AmbientParticle, BackdropMode, BackdropState, BackdropUniforms, CloudParticle,
         DayNightCycle, ParallaxLayer, StaticBackdrop, TimeOfDay,
     };
+    pub use crate::readback::pick_cell;
     pub use crate::resolution::{
-        DisplayMode, OrthoProjection, Resolution, ResolutionChangeRequest, ResolutionManager,
-        ResolutionUniforms, ScalingMode, VSyncMode, Viewport,
+        AdaptiveResolution, DisplayMode, OrthoProjection, Resolution, ResolutionChangeRequest,
+        ResolutionManager, ResolutionUniforms, ScalingMode, VSyncMode, Viewport,
     };
     pub use crate::screenshot::{
         CaptureConfig, CaptureRequest, CaptureStatus, ScreenshotData, ScreenshotFormat,
         ScreenshotManager, ScreenshotQuality,
     };
+    pub use crate::sim_control::SimulationControl;
+    pub use crate::sim_events::{
+        decode_events, sim_events_wgsl, GpuSimEvent, GpuSimEventHeader, SimEvent, SimEventBatch,
+        SimEventBuffer,
+    };
+    pub use crate::sim_rules::{GpuMaterialRule, MaterialRule, Reaction, RuleTable};
     pub use crate::transitions::{
         TransitionConfig, TransitionEasing, TransitionManager, TransitionState, TransitionType,
         TransitionUniforms,
@@ -0,0 +1,116 @@
+//! Terrain lighting from the height grid.
+//!
+//! [`LightingSystem::compute_ao`] gives a cheap ambient occlusion term per
+//! cell: cells sitting below taller neighbors are darkened in proportion
+//! to how far those neighbors rise above them, so cliffs and valley floors
+//! read clearly in the flat top-down view.
+
+/// Neighbor offsets with their occlusion weights; diagonals count less.
+const NEIGHBORS: [(i32, i32, f32); 8] = [
+    (0, -1, 1.0),
+    (1, 0, 1.0),
+    (0, 1, 1.0),
+    (-1, 0, 1.0),
+    (-1, -1, std::f32::consts::FRAC_1_SQRT_2),
+    (1, -1, std::f32::consts::FRAC_1_SQRT_2),
+    (1, 1, std::f32::consts::FRAC_1_SQRT_2),
+    (-1, 1, std::f32::consts::FRAC_1_SQRT_2),
+];
+
+/// Ambient occlusion settings for terrain cells.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LightingSystem {
+    /// Maximum darkening for a fully occluded cell (0 = off, 1 = black).
+    pub ao_strength: f32,
+    /// Weighted height rise around a cell that counts as fully occluded.
+    pub ao_height_range: f32,
+}
+
+impl Default for LightingSystem {
+    fn default() -> Self {
+        Self {
+            ao_strength: 0.6,
+            ao_height_range: 32.0,
+        }
+    }
+}
+
+impl LightingSystem {
+    /// Creates a lighting system with default settings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the AO strength and the height range for full occlusion.
+    #[must_use]
+    pub fn with_ao(mut self, strength: f32, height_range: f32) -> Self {
+        self.ao_strength = strength.clamp(0.0, 1.0);
+        self.ao_height_range = height_range.max(f32::EPSILON);
+        self
+    }
+
+    /// Computes an ambient occlusion factor per cell of a `w × h` grid.
+    ///
+    /// Returns one brightness multiplier per cell in `[1 - ao_strength, 1]`,
+    /// where 1 means fully open. Cells outside the grid are treated as the
+    /// same height as their neighbor, so edges are never darkened.
+    #[must_use]
+    pub fn compute_ao(&self, heights: &[f32], w: usize, h: usize) -> Vec<f32> {
+        if heights.len() < w * h {
+            return vec![1.0; heights.len()];
+        }
+
+        let mut ao = Vec::with_capacity(w * h);
+        for y in 0..h {
+            for x in 0..w {
+                let center = heights[y * w + x];
+                let mut occlusion = 0.0;
+                for (dx, dy, weight) in NEIGHBORS {
+                    let (Some(nx), Some(ny)) =
+                        (x.checked_add_signed(dx as isize), y.checked_add_signed(dy as isize))
+                    else {
+                        continue;
+                    };
+                    if nx >= w || ny >= h {
+                        continue;
+                    }
+                    occlusion += (heights[ny * w + nx] - center).max(0.0) * weight;
+                }
+                let amount = (occlusion / self.ao_height_range).min(1.0);
+                ao.push(1.0 - self.ao_strength * amount);
+            }
+        }
+        ao
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ao_darkens_cells_next_to_tall_neighbors() {
+        let lighting = LightingSystem::new();
+        // 4x3 flat grid with one tall column in the middle of the top row
+        let mut heights = vec![64.0; 12];
+        heights[1] = 80.0;
+        let ao = lighting.compute_ao(&heights, 4, 3);
+
+        let open = ao[11];
+        assert!((open - 1.0).abs() < f32::EPSILON);
+        assert!(ao[0] < open);
+        assert!(ao[5] < open);
+        // The tall cell itself is not occluded
+        assert!((ao[1] - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_ao_steeper_drops_shadow_more() {
+        let lighting = LightingSystem::new();
+        let gentle = lighting.compute_ao(&[64.0, 68.0], 2, 1);
+        let steep = lighting.compute_ao(&[64.0, 90.0], 2, 1);
+        assert!(steep[0] < gentle[0]);
+        assert!(steep[0] >= 1.0 - lighting.ao_strength);
+    }
+}
@@ -6,6 +6,11 @@
 //! - NPCs and world geometry
 //!
 //! Uses circle collision for NPC bodies with separate interaction radius.
+//! NPC-to-NPC checks go through a broad phase backed by either a quadtree or
+//! a spatial hash, chosen per check from NPC density.
+
+use crate::quadtree::{QuadTree, Rect};
+use crate::spatial_hash::SpatialHash;
 
 /// A 2D vector for collision operations.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
@@ -258,6 +263,140 @@ pub fn check_npc_to_npc_collisions(npcs: &[NpcPosition]) -> Vec<(u32, u32, f32,
     collisions
 }
 
+/// Average NPCs per broad-phase cell at which `Auto` switches to the spatial hash.
+pub const SPATIAL_HASH_DENSITY_THRESHOLD: f32 = 0.25;
+
+/// Broad-phase spatial index used for NPC-to-NPC collision checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NpcBroadPhase {
+    /// Choose a backend from NPC density on every check.
+    #[default]
+    Auto,
+    /// Quadtree (adapts to clustered NPCs).
+    QuadTree,
+    /// Spatial hash grid (best for uniformly dense NPCs).
+    SpatialHash,
+}
+
+impl NpcBroadPhase {
+    /// Resolves `Auto` to a concrete backend based on NPC density.
+    ///
+    /// Density is measured as the average number of NPCs per broad-phase cell
+    /// across the NPCs' bounding box. Dense fields favor the spatial hash;
+    /// sparse or clustered ones favor the quadtree.
+    #[must_use]
+    pub fn resolve(self, npcs: &[NpcPosition]) -> Self {
+        if self != Self::Auto {
+            return self;
+        }
+        let Some(bounds) = npc_bounds(npcs) else {
+            return Self::QuadTree;
+        };
+        let cell_size = broad_phase_cell_size(npcs);
+        let cells = (bounds.width / cell_size).max(1.0) * (bounds.height / cell_size).max(1.0);
+        if npcs.len() as f32 / cells >= SPATIAL_HASH_DENSITY_THRESHOLD {
+            Self::SpatialHash
+        } else {
+            Self::QuadTree
+        }
+    }
+}
+
+/// Bounding rect of an NPC's collision circle.
+fn npc_rect(npc: &NpcPosition) -> Rect {
+    let size = npc.collision_radius * 2.0;
+    Rect::from_center(npc.position.x, npc.position.y, size, size)
+}
+
+/// Bounding rect enclosing every NPC's collision circle.
+fn npc_bounds(npcs: &[NpcPosition]) -> Option<Rect> {
+    let first = npc_rect(npcs.first()?);
+    let (mut min_x, mut min_y, mut max_x, mut max_y) =
+        (first.x, first.y, first.right(), first.bottom());
+    for rect in npcs.iter().skip(1).map(npc_rect) {
+        min_x = min_x.min(rect.x);
+        min_y = min_y.min(rect.y);
+        max_x = max_x.max(rect.right());
+        max_y = max_y.max(rect.bottom());
+    }
+    Some(Rect::new(min_x, min_y, max_x - min_x, max_y - min_y))
+}
+
+/// Cell size for the spatial hash: the largest NPC diameter.
+fn broad_phase_cell_size(npcs: &[NpcPosition]) -> f32 {
+    npcs.iter()
+        .map(|n| n.collision_radius * 2.0)
+        .fold(1.0, f32::max)
+}
+
+/// Checks collisions between all NPCs using a broad-phase spatial index.
+///
+/// Produces the same pairs, in the same order, as
+/// [`check_npc_to_npc_collisions`].
+#[must_use]
+pub fn check_npc_to_npc_collisions_with(
+    npcs: &[NpcPosition],
+    broad_phase: NpcBroadPhase,
+) -> Vec<(u32, u32, f32, Vec2)> {
+    let Some(bounds) = npc_bounds(npcs) else {
+        return Vec::new();
+    };
+
+    let mut candidates: Vec<(usize, usize)> = Vec::new();
+    match broad_phase.resolve(npcs) {
+        NpcBroadPhase::SpatialHash | NpcBroadPhase::Auto => {
+            let mut hash = SpatialHash::new(broad_phase_cell_size(npcs));
+            for (i, npc) in npcs.iter().enumerate().filter(|(_, n)| n.is_solid) {
+                hash.insert(npc_rect(npc), i);
+            }
+            for (i, npc) in npcs.iter().enumerate().filter(|(_, n)| n.is_solid) {
+                candidates.extend(
+                    hash.query_range(npc_rect(npc))
+                        .into_iter()
+                        .filter(|&&j| j > i)
+                        .map(|&j| (i, j)),
+                );
+            }
+        },
+        NpcBroadPhase::QuadTree => {
+            // Pad so circles on the outer edge still fit inside the root node
+            let root = Rect::new(
+                bounds.x - 1.0,
+                bounds.y - 1.0,
+                bounds.width + 2.0,
+                bounds.height + 2.0,
+            );
+            let mut tree = QuadTree::new(root, 8, 8);
+            for (i, npc) in npcs.iter().enumerate().filter(|(_, n)| n.is_solid) {
+                tree.insert(npc_rect(npc), i);
+            }
+            for (i, npc) in npcs.iter().enumerate().filter(|(_, n)| n.is_solid) {
+                candidates.extend(
+                    tree.query(npc_rect(npc))
+                        .into_iter()
+                        .filter(|&&j| j > i)
+                        .map(|&j| (i, j)),
+                );
+            }
+        },
+    }
+    candidates.sort_unstable();
+
+    candidates
+        .into_iter()
+        .filter_map(|(i, j)| {
+            let (npc1, npc2) = (&npcs[i], &npcs[j]);
+            circles_collide(
+                npc1.position,
+                npc1.collision_radius,
+                npc2.position,
+                npc2.collision_radius,
+            )
+            .map(|(penetration, normal)| (npc1.id, npc2.id, penetration, normal))
+        })
+        .collect()
+}
+
 /// Manager for tracking NPC positions and handling collisions.
 pub struct NpcCollisionManager {
     /// Active NPC positions
@@ -266,6 +405,8 @@ pub struct NpcCollisionManager {
     default_collision_radius: f32,
     /// Default interaction radius for new NPCs
     default_interaction_radius: f32,
+    /// Broad phase used for NPC-to-NPC checks
+    broad_phase: NpcBroadPhase,
 }
 
 impl Default for NpcCollisionManager {
@@ -282,9 +423,21 @@ impl NpcCollisionManager {
             npcs: Vec::new(),
             default_collision_radius: 12.0,
             default_interaction_radius: 32.0,
+            broad_phase: NpcBroadPhase::default(),
         }
     }
 
+    /// Sets the broad phase used for NPC-to-NPC checks.
+    pub fn set_broad_phase(&mut self, broad_phase: NpcBroadPhase) {
+        self.broad_phase = broad_phase;
+    }
+
+    /// Returns the configured broad phase.
+    #[must_use]
+    pub fn broad_phase(&self) -> NpcBroadPhase {
+        self.broad_phase
+    }
+
     /// Sets the default collision radius for new NPCs.
     pub fn set_default_collision_radius(&mut self, radius: f32) {
         self.default_collision_radius = radius;
@@ -359,7 +512,7 @@ impl NpcCollisionManager {
     /// Checks NPC-to-NPC collisions.
     #[must_use]
     pub fn check_npc_collisions(&self) -> Vec<(u32, u32, f32, Vec2)> {
-        check_npc_to_npc_collisions(&self.npcs)
+        check_npc_to_npc_collisions_with(&self.npcs, self.broad_phase)
     }
 
     /// Returns all registered NPCs.
@@ -469,6 +622,65 @@ mod tests {
         assert_eq!(collisions[0].1, 2);
     }
 
+    #[test]
+    fn test_broad_phase_matches_brute_force() {
+        let mut npcs = Vec::new();
+        let mut state = 7u32;
+        for id in 0..400u32 {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let x = (state >> 8) as f32 % 600.0;
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let y = (state >> 8) as f32 % 600.0;
+            npcs.push(NpcPosition::new(id, x, y).with_solid(id % 7 != 0));
+        }
+
+        let expected: Vec<(u32, u32)> = check_npc_to_npc_collisions(&npcs)
+            .iter()
+            .map(|c| (c.0, c.1))
+            .collect();
+        assert!(!expected.is_empty());
+
+        for broad_phase in [NpcBroadPhase::QuadTree, NpcBroadPhase::SpatialHash] {
+            let found: Vec<(u32, u32)> = check_npc_to_npc_collisions_with(&npcs, broad_phase)
+                .iter()
+                .map(|c| (c.0, c.1))
+                .collect();
+            assert_eq!(
+                found, expected,
+                "{broad_phase:?} disagrees with brute force"
+            );
+        }
+    }
+
+    #[test]
+    fn test_broad_phase_auto_by_density() {
+        // Uniform grid, one NPC per diameter-sized cell
+        let dense: Vec<_> = (0..100u32)
+            .map(|i| NpcPosition::new(i, (i % 10) as f32 * 24.0, (i / 10) as f32 * 24.0))
+            .collect();
+        assert_eq!(
+            NpcBroadPhase::Auto.resolve(&dense),
+            NpcBroadPhase::SpatialHash
+        );
+
+        // Two small clusters far apart
+        let clustered: Vec<_> = (0..100u32)
+            .map(|i| {
+                let offset = if i % 2 == 0 { 0.0 } else { 10_000.0 };
+                NpcPosition::new(i, offset + (i % 5) as f32, offset + (i / 20) as f32)
+            })
+            .collect();
+        assert_eq!(
+            NpcBroadPhase::Auto.resolve(&clustered),
+            NpcBroadPhase::QuadTree
+        );
+
+        assert_eq!(
+            NpcBroadPhase::SpatialHash.resolve(&clustered),
+            NpcBroadPhase::SpatialHash
+        );
+    }
+
     #[test]
     fn test_resolve_collision() {
         let collision = NpcCollision {
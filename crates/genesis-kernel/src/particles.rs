@@ -2,10 +2,14 @@
 //!
 //! This module provides a compute-based particle system that simulates particle
 //! physics on the GPU. Supports multiple emitter types and particle effects.
+//!
+//! Emission randomness comes from a [`SeededRng`] and emitters are visited in
+//! ID order, so emission is reproducible for a given seed.
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use bytemuck::{Pod, Zeroable};
+use genesis_common::SeededRng;
 use tracing::debug;
 use wgpu::{util::DeviceExt, Device, Queue};
 
@@ -16,7 +20,7 @@ pub const MAX_PARTICLES: usize = 65536;
 pub const MAX_EMITTERS: usize = 256;
 
 /// Unique identifier for a particle emitter.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct EmitterId(u32);
 
 impl EmitterId {
@@ -438,7 +442,7 @@ pub struct ParticleSystem {
     /// Bind group.
     bind_group: wgpu::BindGroup,
     /// Active emitters.
-    emitters: HashMap<EmitterId, ParticleEmitter>,
+    emitters: BTreeMap<EmitterId, ParticleEmitter>,
     /// Next emitter ID.
     next_emitter_id: u32,
     /// Particle data (CPU side for emission).
@@ -449,8 +453,8 @@ pub struct ParticleSystem {
     params: ParticleParams,
     /// Whether particles need upload.
     dirty: bool,
-    /// Random number generator seed.
-    rng_seed: u32,
+    /// Random number generator for emission.
+    rng: SeededRng,
 }
 
 impl ParticleSystem {
@@ -547,13 +551,13 @@ impl ParticleSystem {
             params_buffer,
             compute_pipeline,
             bind_group,
-            emitters: HashMap::new(),
+            emitters: BTreeMap::new(),
             next_emitter_id: 0,
             particles: vec![GpuParticle::default(); MAX_PARTICLES],
             particle_count: 0,
             params,
             dirty: false,
-            rng_seed: 12345,
+            rng: SeededRng::default(),
         }
     }
 
@@ -616,21 +620,15 @@ impl ParticleSystem {
         self.params.gravity = gravity;
     }
 
-    /// Simple pseudo-random number generator (uses local seed).
-    fn next_random_with_seed(seed: &mut u32) -> f32 {
-        *seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
-        ((*seed >> 16) & 0x7FFF) as f32 / 32768.0
-    }
-
-    /// Generates a random value in a range.
-    fn random_range_with_seed(seed: &mut u32, min: f32, max: f32) -> f32 {
-        min + Self::next_random_with_seed(seed) * (max - min)
+    /// Reseeds the emission random number generator.
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng.reseed(seed);
     }
 
     /// Emits particles from all active emitters.
     pub fn emit(&mut self, delta_time: f32) {
         let mut new_particles = Vec::new();
-        let mut seed = self.rng_seed;
+        let rng = &mut self.rng;
 
         for emitter in self.emitters.values_mut() {
             if !emitter.enabled {
@@ -647,13 +645,11 @@ impl ParticleSystem {
                 }
 
                 // Calculate velocity with spread
-                let angle_offset =
-                    Self::random_range_with_seed(&mut seed, -emitter.spread, emitter.spread);
+                let angle_offset = rng.range(-emitter.spread, emitter.spread);
                 let base_angle = emitter.direction.1.atan2(emitter.direction.0);
                 let angle = base_angle + angle_offset;
 
-                let speed = Self::random_range_with_seed(
-                    &mut seed,
+                let speed = rng.range(
                     (emitter.velocity_range.0 .0.powi(2) + emitter.velocity_range.0 .1.powi(2))
                         .sqrt(),
                     (emitter.velocity_range.1 .0.powi(2) + emitter.velocity_range.1 .1.powi(2))
@@ -664,18 +660,14 @@ impl ParticleSystem {
                 let vy = angle.sin() * speed;
 
                 // Random position offset within a small radius
-                let offset_x = Self::random_range_with_seed(&mut seed, -2.0, 2.0);
-                let offset_y = Self::random_range_with_seed(&mut seed, -2.0, 2.0);
+                let offset_x = rng.range(-2.0, 2.0);
+                let offset_y = rng.range(-2.0, 2.0);
 
                 let particle = GpuParticle {
                     position: [emitter.position.0 + offset_x, emitter.position.1 + offset_y],
                     velocity: [vx, vy],
                     color: emitter.color,
-                    size: Self::random_range_with_seed(
-                        &mut seed,
-                        emitter.size_range.0,
-                        emitter.size_range.1,
-                    ),
+                    size: rng.range(emitter.size_range.0, emitter.size_range.1),
                     lifetime: emitter.lifetime,
                     max_lifetime: emitter.lifetime,
                     gravity: emitter.gravity,
@@ -685,9 +677,6 @@ impl ParticleSystem {
             }
         }
 
-        // Store updated seed
-        self.rng_seed = seed;
-
         // Add new particles to the system
         for particle in new_particles {
             if self.particle_count < MAX_PARTICLES {
@@ -701,22 +690,20 @@ impl ParticleSystem {
 
     /// Spawns a burst of particles at a position.
     pub fn burst(&mut self, position: (f32, f32), effect: ParticleEffect, count: u32) {
-        let mut seed = self.rng_seed;
-
         for _ in 0..count {
             if self.particle_count >= MAX_PARTICLES {
                 break;
             }
 
             // Random direction
-            let angle = Self::random_range_with_seed(&mut seed, 0.0, std::f32::consts::TAU);
-            let speed = Self::random_range_with_seed(&mut seed, 20.0, 80.0);
+            let angle = self.rng.range(0.0, std::f32::consts::TAU);
+            let speed = self.rng.range(20.0, 80.0);
 
             let particle = GpuParticle {
                 position: [position.0, position.1],
                 velocity: [angle.cos() * speed, angle.sin() * speed],
                 color: effect.default_color(),
-                size: Self::random_range_with_seed(&mut seed, 2.0, 5.0),
+                size: self.rng.range(2.0, 5.0),
                 lifetime: effect.default_lifetime(),
                 max_lifetime: effect.default_lifetime(),
                 gravity: effect.default_gravity(),
@@ -726,7 +713,6 @@ impl ParticleSystem {
             self.particle_count += 1;
         }
 
-        self.rng_seed = seed;
         self.dirty = true;
     }
 
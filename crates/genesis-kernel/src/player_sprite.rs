@@ -118,6 +118,19 @@ pub struct SpriteAnimation {
     pub fps: f32,
     /// Whether the animation loops
     pub looping: bool,
+    /// Gameplay events fired when playback enters a frame, as
+    /// `(frame index, event name)` (e.g. `(2, "footstep")`)
+    pub frame_events: Vec<(usize, String)>,
+}
+
+impl SpriteAnimation {
+    /// Returns the events tagged on the given frame.
+    pub fn events_at(&self, frame: usize) -> impl Iterator<Item = &str> {
+        self.frame_events
+            .iter()
+            .filter(move |(f, _)| *f == frame)
+            .map(|(_, name)| name.as_str())
+    }
 }
 
 /// Animation key combining action + direction.
@@ -264,6 +277,8 @@ pub struct PlayerSpriteState {
     action_override: Option<PlayerAnimAction>,
     /// Remaining time for the action override animation
     action_override_timer: f32,
+    /// Frame events fired since the last `poll_events`
+    pending_events: Vec<String>,
 }
 
 impl Default for PlayerSpriteState {
@@ -276,6 +291,7 @@ impl Default for PlayerSpriteState {
             position: (0.0, 0.0),
             action_override: None,
             action_override_timer: 0.0,
+            pending_events: Vec::new(),
         }
     }
 }
@@ -295,6 +311,40 @@ impl PlayerSpriteState {
         self.frame = 0;
         self.frame_time = 0.0;
         self.action = action;
+        self.emit_frame_events(animations);
+    }
+
+    /// Drains the frame events fired since the last call, in order.
+    pub fn poll_events(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.pending_events)
+    }
+
+    /// Queues the events tagged on the current frame of the current animation.
+    fn emit_frame_events(&mut self, animations: &PlayerAnimationSet) {
+        let key = AnimKey::new(self.action, self.direction);
+        if let Some(anim) = animations.get(&key) {
+            self.pending_events
+                .extend(anim.events_at(self.frame as usize).map(str::to_owned));
+        }
+    }
+
+    /// Advances the frame timer, firing events for any frame entered.
+    fn advance_frame(&mut self, dt: f32, animations: &PlayerAnimationSet) {
+        let key = AnimKey::new(self.action, self.direction);
+        let (fps, frame_count) = if let Some(anim) = animations.get(&key) {
+            (anim.fps, anim.frames.len() as u32)
+        } else {
+            (8.0, 6) // fallback
+        };
+        if frame_count > 0 {
+            self.frame_time += dt;
+            let frame_duration = 1.0 / fps;
+            if self.frame_time >= frame_duration {
+                self.frame_time -= frame_duration;
+                self.frame = (self.frame + 1) % frame_count;
+                self.emit_frame_events(animations);
+            }
+        }
     }
 
     /// Updates the animation state based on velocity and available animations.
@@ -332,21 +382,7 @@ impl PlayerSpriteState {
                 }
                 self.action = override_action;
 
-                // Advance frame timer
-                let key = AnimKey::new(self.action, self.direction);
-                let (fps, frame_count) = if let Some(anim) = animations.get(&key) {
-                    (anim.fps, anim.frames.len() as u32)
-                } else {
-                    (8.0, 6)
-                };
-                if frame_count > 0 {
-                    self.frame_time += dt;
-                    let frame_duration = 1.0 / fps;
-                    if self.frame_time >= frame_duration {
-                        self.frame_time -= frame_duration;
-                        self.frame = (self.frame + 1) % frame_count;
-                    }
-                }
+                self.advance_frame(dt, animations);
                 return;
             }
         }
@@ -366,31 +402,19 @@ impl PlayerSpriteState {
         };
 
         // Reset frame if action or direction changed
-        if new_action != self.action || new_direction != self.direction {
+        let restarted = new_action != self.action || new_direction != self.direction;
+        if restarted {
             self.frame = 0;
             self.frame_time = 0.0;
         }
 
         self.action = new_action;
         self.direction = new_direction;
-
-        // Get fps from the current animation (or default to 8)
-        let key = AnimKey::new(self.action, self.direction);
-        let (fps, frame_count) = if let Some(anim) = animations.get(&key) {
-            (anim.fps, anim.frames.len() as u32)
-        } else {
-            (8.0, 6) // fallback
-        };
-
-        // Advance frame timer
-        if frame_count > 0 {
-            self.frame_time += dt;
-            let frame_duration = 1.0 / fps;
-            if self.frame_time >= frame_duration {
-                self.frame_time -= frame_duration;
-                self.frame = (self.frame + 1) % frame_count;
-            }
+        if restarted {
+            self.emit_frame_events(animations);
         }
+
+        self.advance_frame(dt, animations);
     }
 
     /// Returns the current sprite frame from the animation set, or None.
@@ -951,6 +975,7 @@ mod tests {
                 ],
                 fps: 8.0,
                 looping: true,
+                frame_events: Vec::new(),
             },
         );
         anims.insert(
@@ -959,6 +984,7 @@ mod tests {
                 frames: vec![SpriteFrame { x: 0, y: 100, width: 48, height: 74 }],
                 fps: 8.0,
                 looping: true,
+                frame_events: Vec::new(),
             },
         );
 
@@ -985,6 +1011,7 @@ mod tests {
                 frames: vec![SpriteFrame { x: 0, y: 0, width: 48, height: 74 }],
                 fps: 8.0,
                 looping: true,
+                frame_events: Vec::new(),
             },
         );
 
@@ -993,4 +1020,65 @@ mod tests {
         let anim = anims.get(&key);
         assert!(anim.is_some()); // Falls back to IdleDown
     }
+
+    #[test]
+    fn test_frame_events_fire_once_per_loop() {
+        let frame = SpriteFrame { x: 0, y: 0, width: 48, height: 74 };
+        let mut anims = PlayerAnimationSet::new();
+        anims.insert(
+            AnimKey::new(PlayerAnimAction::Walk, PlayerDirection::Right),
+            SpriteAnimation {
+                frames: vec![frame; 4],
+                fps: 10.0,
+                looping: true,
+                frame_events: vec![(1, "footstep".to_string()), (3, "footstep".to_string())],
+            },
+        );
+
+        let mut state = PlayerSpriteState::default();
+        let mut fired = Vec::new();
+        // 0.1s per frame at 10 fps; 8 updates play two full loops
+        for _ in 0..8 {
+            state.update(0.1, (100.0, 0.0), (0.0, 0.0), &anims);
+            fired.push((state.frame, state.poll_events()));
+        }
+
+        let steps: Vec<u32> = fired
+            .iter()
+            .filter(|(_, events)| !events.is_empty())
+            .map(|(frame, events)| {
+                assert_eq!(events, &["footstep"]);
+                *frame
+            })
+            .collect();
+        assert_eq!(steps, [1, 3, 1, 3]);
+        assert!(state.poll_events().is_empty());
+    }
+
+    #[test]
+    fn test_action_override_fires_first_frame_event() {
+        let frame = SpriteFrame { x: 0, y: 0, width: 48, height: 74 };
+        let mut anims = PlayerAnimationSet::new();
+        anims.insert(
+            AnimKey::new(PlayerAnimAction::Punch, PlayerDirection::Down),
+            SpriteAnimation {
+                frames: vec![frame; 3],
+                fps: 10.0,
+                looping: false,
+                frame_events: vec![
+                    (0, "attack_windup".to_string()),
+                    (2, "attack_active".to_string()),
+                ],
+            },
+        );
+
+        let mut state = PlayerSpriteState::default();
+        state.set_action_override(PlayerAnimAction::Punch, &anims);
+        assert_eq!(state.poll_events(), ["attack_windup"]);
+
+        state.update(0.1, (0.0, 0.0), (0.0, 0.0), &anims);
+        assert!(state.poll_events().is_empty());
+        state.update(0.1, (0.0, 0.0), (0.0, 0.0), &anims);
+        assert_eq!(state.poll_events(), ["attack_active"]);
+    }
 }
@@ -125,6 +125,17 @@ impl<T> QuadTree<T> {
         }
     }
 
+    /// Creates a new quadtree with explicit split parameters.
+    ///
+    /// `max_depth` caps how many times a node may subdivide and
+    /// `bucket_size` is how many objects a node holds before it splits.
+    /// Dense scenes want a deeper tree with small buckets; sparse scenes
+    /// stay shallow with larger ones.
+    #[must_use]
+    pub fn with_params(bounds: Rect, max_depth: usize, bucket_size: usize) -> Self {
+        Self::new(bounds, bucket_size, max_depth)
+    }
+
     /// Creates a child node at the specified level.
     fn new_child(bounds: Rect, max_objects: usize, max_levels: usize, level: usize) -> Self {
         Self {
@@ -137,6 +148,18 @@ impl<T> QuadTree<T> {
         }
     }
 
+    /// Returns the maximum depth this tree may subdivide to.
+    #[must_use]
+    pub const fn max_depth(&self) -> usize {
+        self.max_levels
+    }
+
+    /// Returns how many objects a node holds before splitting.
+    #[must_use]
+    pub const fn bucket_size(&self) -> usize {
+        self.max_objects
+    }
+
     /// Returns the bounds of this node.
     #[must_use]
     pub const fn bounds(&self) -> &Rect {
@@ -332,6 +355,23 @@ impl<T> QuadTree<T> {
         stats
     }
 
+    /// Returns the current depth of the tree (0 when the root is a leaf).
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.children
+            .as_ref()
+            .map_or(0, |children| 1 + children.iter().map(Self::depth).max().unwrap_or(0))
+    }
+
+    /// Returns the total number of nodes, including this one.
+    #[must_use]
+    pub fn node_count(&self) -> usize {
+        1 + self
+            .children
+            .as_ref()
+            .map_or(0, |children| children.iter().map(Self::node_count).sum())
+    }
+
     fn collect_stats(&self, stats: &mut QuadTreeStats, depth: usize) {
         stats.node_count += 1;
         stats.object_count += self.objects.len();
@@ -617,4 +657,36 @@ mod tests {
         let results = tree.query(Rect::new(45.0, 45.0, 10.0, 10.0));
         assert!(results.contains(&&"spanning"));
     }
+
+    #[test]
+    fn test_quadtree_small_bucket_splits_to_max_depth() {
+        let mut tree = QuadTree::with_params(Rect::new(0.0, 0.0, 1024.0, 1024.0), 5, 1);
+        assert_eq!(tree.max_depth(), 5);
+        assert_eq!(tree.bucket_size(), 1);
+
+        // Points packed into the top-left corner force repeated splits there
+        for i in 0..64 {
+            tree.insert(Rect::new(i as f32 * 0.25, 0.0, 0.1, 0.1), i);
+        }
+
+        assert_eq!(tree.depth(), 5);
+        assert_eq!(tree.node_count(), 1 + 4 * 5);
+        assert_eq!(tree.stats().max_depth, tree.depth());
+        assert_eq!(tree.stats().node_count, tree.node_count());
+    }
+
+    #[test]
+    fn test_quadtree_large_bucket_stays_shallow() {
+        let mut tree = QuadTree::with_params(Rect::new(0.0, 0.0, 1024.0, 1024.0), 8, 128);
+
+        for i in 0..100 {
+            let x = (i % 10) as f32 * 100.0;
+            let y = (i / 10) as f32 * 100.0;
+            tree.insert(Rect::new(x, y, 5.0, 5.0), i);
+        }
+
+        assert_eq!(tree.depth(), 0);
+        assert_eq!(tree.node_count(), 1);
+        assert_eq!(tree.len(), 100);
+    }
 }
@@ -0,0 +1,57 @@
+//! CPU-side readback helpers for debugging tools.
+//!
+//! These read from the CPU copy of the simulation state, so tools like the
+//! cell inspector can answer "what is under the cursor" without mapping a
+//! GPU buffer per query. Refresh the copy with
+//! [`Compute::read_back`](crate::compute::Compute::read_back) first.
+
+use crate::camera::Camera;
+use crate::cell_buffer::CellBuffers;
+
+/// Finds the simulated cell under a screen position.
+///
+/// The click is converted to world coordinates with the camera, then
+/// floored to the containing cell (one world unit per cell). Returns the
+/// cell's world coordinates and value, or `None` if the click falls
+/// outside the simulated region.
+#[must_use]
+pub fn pick_cell(
+    screen_pos: (f32, f32),
+    camera: &Camera,
+    cells: &CellBuffers,
+) -> Option<(i32, i32, u32)> {
+    let (world_x, world_y) = camera.screen_to_world(screen_pos.0, screen_pos.1);
+    let x = world_x.floor() as i32;
+    let y = world_y.floor() as i32;
+    cells.get(x, y).map(|cell| (x, y, cell))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cell_buffer::CellDims;
+
+    #[test]
+    fn test_pick_cell_at_camera_center() {
+        let mut cells = CellBuffers::new(CellDims::new(-8, -8, 32, 32));
+        cells.set(10, 20, 7);
+        cells.set(-3, -2, 9);
+
+        let mut camera = Camera::new(100, 80);
+        camera.center_on(10.5, 20.5);
+        camera.set_zoom(2.0);
+        assert_eq!(pick_cell((50.0, 40.0), &camera, &cells), Some((10, 20, 7)));
+
+        // Negative world coordinates floor to the containing cell
+        camera.center_on(-2.5, -1.5);
+        assert_eq!(pick_cell((50.0, 40.0), &camera, &cells), Some((-3, -2, 9)));
+    }
+
+    #[test]
+    fn test_pick_cell_outside_region() {
+        let cells = CellBuffers::new(CellDims::new(0, 0, 4, 4));
+        let mut camera = Camera::new(100, 80);
+        camera.center_on(100.0, 100.0);
+        assert_eq!(pick_cell((50.0, 40.0), &camera, &cells), None);
+    }
+}
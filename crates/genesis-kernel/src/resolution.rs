@@ -7,6 +7,8 @@
 //! - Update projection matrices
 //! - Fullscreen toggle support
 
+use std::time::Duration;
+
 use bytemuck::{Pod, Zeroable};
 
 /// Minimum supported resolution width.
@@ -823,6 +825,166 @@ impl ResolutionManager {
     }
 }
 
+/// Default lower bound for the adaptive render scale.
+pub const DEFAULT_ADAPTIVE_MIN_SCALE: f32 = 0.5;
+
+/// Default upper bound for the adaptive render scale.
+pub const DEFAULT_ADAPTIVE_MAX_SCALE: f32 = 1.0;
+
+/// Default scale change applied per adjustment.
+pub const DEFAULT_ADAPTIVE_STEP: f32 = 0.05;
+
+/// Adaptive render resolution scaler.
+///
+/// Trades internal render resolution for frame rate: sustained over-budget
+/// frames lower the render scale, sustained headroom raises it again.
+/// Raising requires a longer streak than lowering so the scale settles
+/// instead of oscillating around the budget.
+#[derive(Debug, Clone)]
+pub struct AdaptiveResolution {
+    /// Resolution at a scale of 1.0.
+    base_resolution: Resolution,
+    /// Current render scale.
+    scale: f32,
+    /// Minimum render scale.
+    min_scale: f32,
+    /// Maximum render scale.
+    max_scale: f32,
+    /// Scale change per adjustment.
+    step: f32,
+    /// Fraction of the target frame time below which there is headroom.
+    headroom: f32,
+    /// Consecutive over-budget frames required before lowering.
+    frames_to_lower: u32,
+    /// Consecutive headroom frames required before raising.
+    frames_to_raise: u32,
+    /// Current over-budget streak.
+    over_budget_frames: u32,
+    /// Current headroom streak.
+    headroom_frames: u32,
+}
+
+impl Default for AdaptiveResolution {
+    fn default() -> Self {
+        Self::new(Resolution::default())
+    }
+}
+
+impl AdaptiveResolution {
+    /// Creates an adaptive scaler for the given base resolution.
+    #[must_use]
+    pub fn new(base_resolution: Resolution) -> Self {
+        Self {
+            base_resolution,
+            scale: DEFAULT_ADAPTIVE_MAX_SCALE,
+            min_scale: DEFAULT_ADAPTIVE_MIN_SCALE,
+            max_scale: DEFAULT_ADAPTIVE_MAX_SCALE,
+            step: DEFAULT_ADAPTIVE_STEP,
+            headroom: 0.8,
+            frames_to_lower: 10,
+            frames_to_raise: 60,
+            over_budget_frames: 0,
+            headroom_frames: 0,
+        }
+    }
+
+    /// Sets the allowed scale range.
+    #[must_use]
+    pub fn with_scale_range(mut self, min_scale: f32, max_scale: f32) -> Self {
+        let min_scale = min_scale.clamp(0.1, 1.0);
+        self.min_scale = min_scale;
+        self.max_scale = max_scale.max(min_scale);
+        self.scale = self.scale.clamp(self.min_scale, self.max_scale);
+        self
+    }
+
+    /// Sets the scale change per adjustment.
+    #[must_use]
+    pub fn with_step(mut self, step: f32) -> Self {
+        self.step = step.max(0.01);
+        self
+    }
+
+    /// Sets the streak lengths required to lower and raise the scale.
+    #[must_use]
+    pub fn with_hysteresis(mut self, frames_to_lower: u32, frames_to_raise: u32) -> Self {
+        self.frames_to_lower = frames_to_lower.max(1);
+        self.frames_to_raise = frames_to_raise.max(1);
+        self
+    }
+
+    /// Gets the base resolution.
+    #[must_use]
+    pub fn base_resolution(&self) -> Resolution {
+        self.base_resolution
+    }
+
+    /// Sets the base resolution (e.g. after a window resize).
+    pub fn set_base_resolution(&mut self, resolution: Resolution) {
+        self.base_resolution = resolution;
+    }
+
+    /// Gets the current render scale.
+    #[must_use]
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Gets the minimum render scale.
+    #[must_use]
+    pub fn min_scale(&self) -> f32 {
+        self.min_scale
+    }
+
+    /// Gets the maximum render scale.
+    #[must_use]
+    pub fn max_scale(&self) -> f32 {
+        self.max_scale
+    }
+
+    /// Gets the render resolution for the current scale.
+    #[must_use]
+    pub fn render_resolution(&self) -> Resolution {
+        self.base_resolution.scaled(self.scale)
+    }
+
+    /// Feeds a frame time and returns the render resolution to use.
+    pub fn update(&mut self, frame_time: Duration, target: Duration) -> Resolution {
+        if frame_time > target {
+            self.headroom_frames = 0;
+            self.over_budget_frames += 1;
+            if self.over_budget_frames >= self.frames_to_lower {
+                self.over_budget_frames = 0;
+                self.scale = (self.scale - self.step).max(self.min_scale);
+            }
+        } else if frame_time.as_secs_f32() < target.as_secs_f32() * self.headroom {
+            self.over_budget_frames = 0;
+            self.headroom_frames += 1;
+            if self.headroom_frames >= self.frames_to_raise {
+                self.headroom_frames = 0;
+                self.scale = (self.scale + self.step).min(self.max_scale);
+            }
+        } else {
+            self.over_budget_frames = 0;
+            self.headroom_frames = 0;
+        }
+
+        self.render_resolution()
+    }
+
+    /// Applies the current render resolution to a resolution manager.
+    pub fn apply(&self, manager: &mut ResolutionManager) {
+        manager.set_render_resolution(self.render_resolution());
+    }
+
+    /// Resets the scale to maximum and clears streaks.
+    pub fn reset(&mut self) {
+        self.scale = self.max_scale;
+        self.over_budget_frames = 0;
+        self.headroom_frames = 0;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1098,4 +1260,83 @@ mod tests {
         assert!(!RESOLUTION_PRESETS.is_empty());
         assert!(RESOLUTION_PRESETS.len() >= 5);
     }
+
+    #[test]
+    fn test_adaptive_resolution_lowers_when_over_budget() {
+        let mut adaptive = AdaptiveResolution::new(Resolution::hd1080());
+        let target = Duration::from_micros(16_667);
+        let slow = Duration::from_millis(25);
+
+        for _ in 0..100 {
+            adaptive.update(slow, target);
+        }
+
+        assert!(adaptive.scale() < 1.0);
+        assert!(adaptive.render_resolution().width < 1920);
+    }
+
+    #[test]
+    fn test_adaptive_resolution_clamps_to_min_scale() {
+        let mut adaptive =
+            AdaptiveResolution::new(Resolution::hd1080()).with_scale_range(0.75, 1.0);
+        let target = Duration::from_micros(16_667);
+
+        for _ in 0..1000 {
+            adaptive.update(Duration::from_millis(50), target);
+        }
+
+        assert!((adaptive.scale() - 0.75).abs() < f32::EPSILON);
+        assert_eq!(
+            adaptive.render_resolution(),
+            Resolution::hd1080().scaled(0.75)
+        );
+    }
+
+    #[test]
+    fn test_adaptive_resolution_recovers_with_headroom() {
+        let mut adaptive = AdaptiveResolution::new(Resolution::hd1080());
+        let target = Duration::from_micros(16_667);
+
+        for _ in 0..200 {
+            adaptive.update(Duration::from_millis(30), target);
+        }
+        let lowered = adaptive.scale();
+        assert!(lowered < 1.0);
+
+        for _ in 0..2000 {
+            adaptive.update(Duration::from_millis(8), target);
+        }
+        assert!(adaptive.scale() > lowered);
+        assert!((adaptive.scale() - adaptive.max_scale()).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_adaptive_resolution_ignores_brief_spikes() {
+        let mut adaptive = AdaptiveResolution::new(Resolution::hd1080());
+        let target = Duration::from_micros(16_667);
+
+        for _ in 0..50 {
+            for _ in 0..3 {
+                adaptive.update(Duration::from_millis(30), target);
+            }
+            adaptive.update(Duration::from_millis(15), target);
+        }
+
+        assert!((adaptive.scale() - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_adaptive_resolution_apply() {
+        let mut adaptive = AdaptiveResolution::new(Resolution::hd1080()).with_step(0.25);
+        let mut manager = ResolutionManager::with_resolution(Resolution::hd1080());
+        let target = Duration::from_micros(16_667);
+
+        for _ in 0..10 {
+            adaptive.update(Duration::from_millis(30), target);
+        }
+        adaptive.apply(&mut manager);
+
+        assert_eq!(manager.render_resolution(), Resolution::new(1440, 810));
+        assert!(manager.targets_dirty());
+    }
 }
@@ -125,6 +125,9 @@ pub struct CaptureConfig {
     pub quality: ScreenshotQuality,
     /// Whether to include UI in the capture.
     pub include_ui: bool,
+    /// Whether to composite the egui overlay (debug overlay, HUD) into the
+    /// capture. Off by default so captures show the clean scene.
+    pub include_overlay: bool,
     /// Whether to flip vertically (for OpenGL).
     pub flip_vertical: bool,
 }
@@ -138,6 +141,7 @@ impl Default for CaptureConfig {
             format: ScreenshotFormat::RawRgba,
             quality: ScreenshotQuality::Medium,
             include_ui: true,
+            include_overlay: false,
             flip_vertical: false,
         }
     }
@@ -191,6 +195,13 @@ impl CaptureConfig {
         self
     }
 
+    /// Sets whether to composite the egui overlay into the capture.
+    #[must_use]
+    pub fn with_overlay(mut self, include: bool) -> Self {
+        self.include_overlay = include;
+        self
+    }
+
     /// Calculates the expected buffer size for full resolution.
     #[must_use]
     pub fn full_buffer_size(&self, width: u32, height: u32) -> usize {
@@ -220,7 +231,7 @@ pub struct CaptureRequest {
     pub dst_width: u32,
     /// Destination height.
     pub dst_height: u32,
-    /// Flags (bit 0: flip vertical, bit 1: include UI).
+    /// Flags (bit 0: flip vertical, bit 1: include UI, bit 2: include overlay).
     pub flags: u32,
     /// Padding.
     _pad: u32,
@@ -309,6 +320,17 @@ impl CaptureRequest {
         self
     }
 
+    /// Sets the include overlay flag.
+    #[must_use]
+    pub fn with_overlay(mut self, include: bool) -> Self {
+        if include {
+            self.flags |= 4;
+        } else {
+            self.flags &= !4;
+        }
+        self
+    }
+
     /// Whether vertical flip is enabled.
     #[must_use]
     pub fn is_flipped(&self) -> bool {
@@ -321,6 +343,12 @@ impl CaptureRequest {
         self.flags & 2 != 0
     }
 
+    /// Whether overlay compositing is enabled.
+    #[must_use]
+    pub fn includes_overlay(&self) -> bool {
+        self.flags & 4 != 0
+    }
+
     /// Calculates the output buffer size.
     #[must_use]
     pub fn output_size(&self) -> usize {
@@ -437,6 +465,51 @@ impl ScreenshotData {
             }
         }
     }
+
+    /// Blends an overlay of the same size on top of this image.
+    ///
+    /// The overlay is expected in premultiplied alpha, as egui renders it
+    /// onto a transparent target. Returns `false` and leaves the image
+    /// untouched if the sizes don't match.
+    pub fn composite_overlay(&mut self, overlay: &ScreenshotData) -> bool {
+        if overlay.width != self.width
+            || overlay.height != self.height
+            || !overlay.is_valid()
+            || !self.is_valid()
+        {
+            return false;
+        }
+
+        for (dst, src) in self
+            .pixels
+            .chunks_exact_mut(BYTES_PER_PIXEL)
+            .zip(overlay.pixels.chunks_exact(BYTES_PER_PIXEL))
+        {
+            let inv_alpha = 255 - u32::from(src[3]);
+            for (d, s) in dst.iter_mut().zip(src) {
+                let blended = u32::from(*s) + (u32::from(*d) * inv_alpha + 127) / 255;
+                *d = blended.min(255) as u8;
+            }
+        }
+        true
+    }
+}
+
+/// Builds the final capture from the rendered world and the egui overlay.
+///
+/// The overlay is only composited when `config.include_overlay` is set.
+#[must_use]
+pub fn compose_capture(
+    mut world: ScreenshotData,
+    overlay: Option<&ScreenshotData>,
+    config: CaptureConfig,
+) -> ScreenshotData {
+    if config.include_overlay {
+        if let Some(overlay) = overlay {
+            world.composite_overlay(overlay);
+        }
+    }
+    world
 }
 
 /// Screenshot manager for async capture operations.
@@ -498,10 +571,12 @@ impl ScreenshotManager {
             CaptureRequest::thumbnail(screen_width, screen_height)
                 .with_flip(config.flip_vertical)
                 .with_ui(config.include_ui)
+                .with_overlay(config.include_overlay)
         } else {
             CaptureRequest::full_framebuffer(screen_width, screen_height)
                 .with_flip(config.flip_vertical)
                 .with_ui(config.include_ui)
+                .with_overlay(config.include_overlay)
         };
 
         self.request = Some(request);
@@ -832,6 +907,8 @@ mod tests {
         let request2 = request.with_flip(false);
         assert!(!request2.is_flipped());
         assert!(request2.includes_ui());
+        assert!(!request2.includes_overlay());
+        assert!(request2.with_overlay(true).includes_overlay());
     }
 
     #[test]
@@ -1017,4 +1094,31 @@ mod tests {
         manager.capture_full(1920, 1080); // Should be ignored
         assert_eq!(manager.status(), status_before);
     }
+
+    #[test]
+    fn test_compose_capture_include_overlay() {
+        // Blue world with an opaque red overlay pixel in the corner
+        let world = ScreenshotData::new(4, 4, [0, 0, 255, 255].repeat(16));
+        let mut overlay = ScreenshotData::with_capacity(4, 4);
+        overlay.set_pixel(0, 0, [255, 0, 0, 255]);
+        overlay.set_pixel(1, 0, [128, 0, 0, 128]);
+
+        let clean = compose_capture(world.clone(), Some(&overlay), CaptureConfig::default());
+        assert_eq!(clean.pixels, world.pixels);
+
+        let config = CaptureConfig::default().with_overlay(true);
+        let annotated = compose_capture(world.clone(), Some(&overlay), config);
+        assert_eq!(annotated.get_pixel(0, 0), Some([255, 0, 0, 255]));
+        assert_eq!(annotated.get_pixel(1, 0), Some([128, 0, 127, 255]));
+        // Transparent overlay pixels leave the world as is
+        assert_eq!(annotated.get_pixel(3, 3), Some([0, 0, 255, 255]));
+    }
+
+    #[test]
+    fn test_composite_overlay_size_mismatch() {
+        let mut world = ScreenshotData::new(2, 2, vec![10; 16]);
+        let overlay = ScreenshotData::new(1, 1, vec![255; 4]);
+        assert!(!world.composite_overlay(&overlay));
+        assert_eq!(world.pixels, vec![10; 16]);
+    }
 }
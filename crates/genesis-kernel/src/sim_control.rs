@@ -0,0 +1,125 @@
+//! Pause and single-step control for the cell simulation.
+//!
+//! [`SimulationControl`] decides how many simulation ticks run each frame.
+//! While paused no ticks run unless [`SimulationControl::step_once`] was
+//! called, which queues exactly one tick for the next frame. This lets
+//! debugging tools watch a reaction frame by frame.
+
+/// Pause/step state for a fixed-tick simulation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SimulationControl {
+    /// Whether the simulation is paused.
+    paused: bool,
+    /// Ticks queued with `step_once` while paused.
+    pending_steps: u32,
+    /// Total ticks run since creation.
+    tick: u64,
+}
+
+impl SimulationControl {
+    /// Creates a running simulation control.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pauses or resumes the simulation.
+    ///
+    /// Resuming drops any queued single steps.
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+        if !paused {
+            self.pending_steps = 0;
+        }
+    }
+
+    /// Toggles pause and returns the new state.
+    pub fn toggle_paused(&mut self) -> bool {
+        self.set_paused(!self.paused);
+        self.paused
+    }
+
+    /// Whether the simulation is paused.
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Queues a single tick while paused. Has no effect while running.
+    pub fn step_once(&mut self) {
+        if self.paused {
+            self.pending_steps += 1;
+        }
+    }
+
+    /// Total ticks run so far.
+    #[must_use]
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Returns how many ticks to run this frame and records them.
+    ///
+    /// `requested` is the number of ticks the frame would run unpaused.
+    pub fn ticks_to_run(&mut self, requested: u32) -> u32 {
+        let ticks = if self.paused {
+            std::mem::take(&mut self.pending_steps)
+        } else {
+            requested
+        };
+        self.tick += u64::from(ticks);
+        ticks
+    }
+
+    /// Runs `step` for each tick allowed this frame.
+    pub fn advance(&mut self, requested: u32, mut step: impl FnMut()) {
+        for _ in 0..self.ticks_to_run(requested) {
+            step();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Toy cell buffer: one sand grain falling down a column.
+    fn fall(cells: &mut [u8]) {
+        if let Some(i) = cells.windows(2).position(|w| w == [1, 0]) {
+            cells.swap(i, i + 1);
+        }
+    }
+
+    #[test]
+    fn test_step_while_paused_advances_exactly_one_tick() {
+        let mut control = SimulationControl::new();
+        let mut cells = vec![1, 0, 0, 0, 0];
+
+        control.set_paused(true);
+        control.advance(3, || fall(&mut cells));
+        assert_eq!(cells, vec![1, 0, 0, 0, 0]);
+
+        control.step_once();
+        control.advance(3, || fall(&mut cells));
+        assert_eq!(cells, vec![0, 1, 0, 0, 0]);
+        assert_eq!(control.tick(), 1);
+
+        // The step is consumed
+        control.advance(3, || fall(&mut cells));
+        assert_eq!(cells, vec![0, 1, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_running_ignores_steps() {
+        let mut control = SimulationControl::new();
+        control.step_once();
+        assert_eq!(control.ticks_to_run(2), 2);
+
+        control.set_paused(true);
+        control.step_once();
+        control.step_once();
+        assert!(!control.toggle_paused());
+        assert_eq!(control.ticks_to_run(1), 1);
+        assert_eq!(control.tick(), 3);
+    }
+}
@@ -0,0 +1,311 @@
+//! Typed events emitted by the GPU simulation.
+//!
+//! Simulation shaders append fixed-size records to an event buffer with an
+//! atomic counter; after readback the CPU decodes them into [`SimEvent`]s
+//! for audio and particles. The buffer starts with a 16-byte
+//! [`GpuSimEventHeader`] followed by `capacity` [`GpuSimEvent`] slots.
+//! Events past capacity are counted but not written.
+
+use bytemuck::{Pod, Zeroable};
+use wgpu::util::DeviceExt;
+
+/// Default number of event slots per simulation step.
+pub const DEFAULT_EVENT_CAPACITY: u32 = 4096;
+
+/// Event kind: a material reaction changed a cell.
+pub const SIM_EVENT_REACTION: u32 = 1;
+
+/// WGSL declarations for emitting events, with `{GROUP}` and `{BINDING}`
+/// placeholders filled in by [`sim_events_wgsl`].
+const SIM_EVENTS_WGSL_TEMPLATE: &str = r"
+struct SimEvent {
+    kind: u32,
+    x: i32,
+    y: i32,
+    payload: u32,
+}
+
+struct SimEventBuffer {
+    count: atomic<u32>,
+    capacity: u32,
+    _pad0: u32,
+    _pad1: u32,
+    events: array<SimEvent>,
+}
+
+@group({GROUP}) @binding({BINDING}) var<storage, read_write> sim_events: SimEventBuffer;
+
+const SIM_EVENT_REACTION: u32 = 1u;
+
+fn emit_sim_event(kind: u32, x: i32, y: i32, payload: u32) {
+    let slot = atomicAdd(&sim_events.count, 1u);
+    if (slot < sim_events.capacity) {
+        sim_events.events[slot] = SimEvent(kind, x, y, payload);
+    }
+}
+
+// Material IDs are packed as from | (to << 8).
+fn emit_reaction(x: i32, y: i32, from_material: u32, to_material: u32) {
+    let payload = (from_material & 0xffu) | ((to_material & 0xffu) << 8u);
+    emit_sim_event(SIM_EVENT_REACTION, x, y, payload);
+}
+";
+
+/// Returns WGSL declaring the event buffer at the given bind slot along with
+/// `emit_sim_event` and `emit_reaction`, for prepending to a simulation
+/// shader.
+#[must_use]
+pub fn sim_events_wgsl(group: u32, binding: u32) -> String {
+    SIM_EVENTS_WGSL_TEMPLATE
+        .replace("{GROUP}", &group.to_string())
+        .replace("{BINDING}", &binding.to_string())
+}
+
+/// Simulation event decoded on the CPU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SimEvent {
+    /// A cell turned from one material into another (e.g. water + lava).
+    ReactionOccurred {
+        /// Cell X in world coordinates.
+        x: i32,
+        /// Cell Y in world coordinates.
+        y: i32,
+        /// Material before the reaction.
+        from: u8,
+        /// Material after the reaction.
+        to: u8,
+    },
+}
+
+/// Header at the start of the event buffer.
+/// Layout: 16 bytes total.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[repr(C)]
+pub struct GpuSimEventHeader {
+    /// Events emitted this step, including any that did not fit (4 bytes).
+    pub count: u32,
+    /// Number of event slots (4 bytes).
+    pub capacity: u32,
+    /// Padding for alignment.
+    padding: [u32; 2],
+}
+
+impl GpuSimEventHeader {
+    /// Creates an empty header for a buffer with `capacity` slots.
+    #[must_use]
+    pub fn new(capacity: u32) -> Self {
+        Self {
+            count: 0,
+            capacity,
+            padding: [0; 2],
+        }
+    }
+}
+
+/// GPU-compatible event record.
+/// Layout: 16 bytes total.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[repr(C)]
+pub struct GpuSimEvent {
+    /// `SIM_EVENT_*` kind (4 bytes).
+    pub kind: u32,
+    /// Cell X (4 bytes).
+    pub x: i32,
+    /// Cell Y (4 bytes).
+    pub y: i32,
+    /// Kind-specific data (4 bytes).
+    pub payload: u32,
+}
+
+impl GpuSimEvent {
+    /// Encodes a reaction the same way `emit_reaction` does in WGSL.
+    #[must_use]
+    pub fn reaction(x: i32, y: i32, from: u8, to: u8) -> Self {
+        Self {
+            kind: SIM_EVENT_REACTION,
+            x,
+            y,
+            payload: u32::from(from) | (u32::from(to) << 8),
+        }
+    }
+
+    /// Decodes the record; unknown kinds return `None`.
+    #[must_use]
+    pub fn decode(&self) -> Option<SimEvent> {
+        match self.kind {
+            SIM_EVENT_REACTION => Some(SimEvent::ReactionOccurred {
+                x: self.x,
+                y: self.y,
+                from: (self.payload & 0xff) as u8,
+                to: ((self.payload >> 8) & 0xff) as u8,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Events decoded from one readback of the event buffer.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SimEventBatch {
+    /// Decoded events in emission order.
+    pub events: Vec<SimEvent>,
+    /// Events emitted after the buffer was full.
+    pub dropped: u32,
+}
+
+/// Decodes the bytes of a read-back event buffer.
+///
+/// Truncated buffers decode as many whole records as are present.
+#[must_use]
+pub fn decode_events(bytes: &[u8]) -> SimEventBatch {
+    let header_len = std::mem::size_of::<GpuSimEventHeader>();
+    let event_len = std::mem::size_of::<GpuSimEvent>();
+    if bytes.len() < header_len {
+        return SimEventBatch::default();
+    }
+    let header: GpuSimEventHeader = bytemuck::pod_read_unaligned(&bytes[..header_len]);
+    let written = header.count.min(header.capacity) as usize;
+    let events = bytes[header_len..]
+        .chunks_exact(event_len)
+        .take(written)
+        .filter_map(|chunk| bytemuck::pod_read_unaligned::<GpuSimEvent>(chunk).decode())
+        .collect();
+    SimEventBatch {
+        events,
+        dropped: header.count.saturating_sub(header.capacity),
+    }
+}
+
+/// GPU storage buffer that simulation shaders append events to.
+pub struct SimEventBuffer {
+    buffer: wgpu::Buffer,
+    capacity: u32,
+}
+
+impl SimEventBuffer {
+    /// Creates an empty event buffer with room for `capacity` events.
+    #[must_use]
+    pub fn new(device: &wgpu::Device, capacity: u32) -> Self {
+        let mut contents = bytemuck::bytes_of(&GpuSimEventHeader::new(capacity)).to_vec();
+        contents.resize(Self::size_bytes(capacity) as usize, 0);
+        let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Simulation Event Buffer"),
+            contents: &contents,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_SRC
+                | wgpu::BufferUsages::COPY_DST,
+        });
+        Self { buffer, capacity }
+    }
+
+    /// Size in bytes of a buffer holding `capacity` events.
+    #[must_use]
+    pub fn size_bytes(capacity: u32) -> u64 {
+        (std::mem::size_of::<GpuSimEventHeader>()
+            + std::mem::size_of::<GpuSimEvent>() * capacity as usize) as u64
+    }
+
+    /// Returns the underlying buffer for binding and copying.
+    #[must_use]
+    pub fn buffer(&self) -> &wgpu::Buffer {
+        &self.buffer
+    }
+
+    /// Returns the number of event slots.
+    #[must_use]
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    /// Clears the event count before the next simulation step.
+    pub fn reset(&self, queue: &wgpu::Queue) {
+        queue.write_buffer(
+            &self.buffer,
+            0,
+            bytemuck::bytes_of(&GpuSimEventHeader::new(self.capacity)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buffer_bytes(count: u32, capacity: u32, events: &[GpuSimEvent]) -> Vec<u8> {
+        let mut header = GpuSimEventHeader::new(capacity);
+        header.count = count;
+        let mut bytes = bytemuck::bytes_of(&header).to_vec();
+        bytes.extend_from_slice(bytemuck::cast_slice(events));
+        bytes
+    }
+
+    #[test]
+    fn test_layout_sizes() {
+        assert_eq!(std::mem::size_of::<GpuSimEventHeader>(), 16);
+        assert_eq!(std::mem::size_of::<GpuSimEvent>(), 16);
+        assert_eq!(SimEventBuffer::size_bytes(4), 80);
+    }
+
+    #[test]
+    fn test_reaction_decodes_coordinates_and_materials() {
+        let event = GpuSimEvent::reaction(-120, 48, 3, 250);
+        assert_eq!(event.payload, 3 | (250 << 8));
+        assert_eq!(
+            event.decode(),
+            Some(SimEvent::ReactionOccurred {
+                x: -120,
+                y: 48,
+                from: 3,
+                to: 250,
+            })
+        );
+
+        let unknown = GpuSimEvent { kind: 99, ..event };
+        assert_eq!(unknown.decode(), None);
+    }
+
+    #[test]
+    fn test_decode_events_from_buffer() {
+        let records = [
+            GpuSimEvent::reaction(1, 2, 4, 9),
+            GpuSimEvent {
+                kind: 0,
+                x: 0,
+                y: 0,
+                payload: 0,
+            },
+            GpuSimEvent::reaction(7, -3, 5, 1),
+            GpuSimEvent::reaction(100, 100, 1, 1),
+        ];
+        // Shader emitted 6 events into 3 slots; the 4th record is stale
+        let batch = decode_events(&buffer_bytes(6, 3, &records));
+        assert_eq!(
+            batch.events,
+            vec![
+                SimEvent::ReactionOccurred {
+                    x: 1,
+                    y: 2,
+                    from: 4,
+                    to: 9,
+                },
+                SimEvent::ReactionOccurred {
+                    x: 7,
+                    y: -3,
+                    from: 5,
+                    to: 1,
+                },
+            ]
+        );
+        assert_eq!(batch.dropped, 3);
+
+        assert_eq!(decode_events(&[0; 8]), SimEventBatch::default());
+    }
+
+    #[test]
+    fn test_wgsl_binding_placeholders_filled() {
+        let wgsl = sim_events_wgsl(2, 5);
+        assert!(wgsl.contains("@group(2) @binding(5)"));
+        assert!(!wgsl.contains("{GROUP}") && !wgsl.contains("{BINDING}"));
+        assert!(wgsl.contains("fn emit_reaction"));
+    }
+}
@@ -0,0 +1,244 @@
+//! Data-driven cellular simulation rules.
+//!
+//! A [`RuleTable`] holds per-material behavior (density, flow, reactions)
+//! indexed by material ID. It is uploaded to the GPU as a flat array of
+//! [`GpuMaterialRule`] so a simulation shader can look rules up by ID
+//! instead of hard-coding material behavior.
+
+use bytemuck::{Pod, Zeroable};
+use wgpu::util::DeviceExt;
+
+/// Number of material IDs covered by a rule table (material IDs are `u8`).
+pub const MAX_MATERIALS: usize = 256;
+
+/// Behavior flag: the material falls under gravity.
+pub const RULE_FALLS: u32 = 1;
+/// Behavior flag: the material spreads sideways like a liquid.
+pub const RULE_FLOWS: u32 = 1 << 1;
+/// Behavior flag: the material takes part in a reaction.
+pub const RULE_REACTS: u32 = 1 << 2;
+
+/// A reaction turning this material into another on contact.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Reaction {
+    /// Material that triggers the reaction when adjacent.
+    pub with: u8,
+    /// Material this cell becomes.
+    pub product: u8,
+    /// Chance per simulation step (0-1).
+    pub chance: f32,
+}
+
+/// Simulation behavior for one material.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MaterialRule {
+    /// Relative density; denser materials sink through lighter ones.
+    pub density: f32,
+    /// Whether the material falls under gravity.
+    pub falls: bool,
+    /// Chance per step that a liquid spreads sideways (0-1, 0 = no flow).
+    pub flow_rate: f32,
+    /// Optional reaction with a neighboring material.
+    pub reaction: Option<Reaction>,
+}
+
+impl MaterialRule {
+    /// Creates a static solid with the given density.
+    #[must_use]
+    pub fn solid(density: f32) -> Self {
+        Self {
+            density,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a falling powder (sand, gravel) with the given density.
+    #[must_use]
+    pub fn powder(density: f32) -> Self {
+        Self {
+            density,
+            falls: true,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a liquid with the given density and flow rate.
+    #[must_use]
+    pub fn liquid(density: f32, flow_rate: f32) -> Self {
+        Self {
+            density,
+            falls: true,
+            flow_rate,
+            reaction: None,
+        }
+    }
+
+    /// Adds a reaction to the rule.
+    #[must_use]
+    pub fn with_reaction(mut self, with: u8, product: u8, chance: f32) -> Self {
+        self.reaction = Some(Reaction {
+            with,
+            product,
+            chance: chance.clamp(0.0, 1.0),
+        });
+        self
+    }
+
+    /// Converts the rule to its GPU layout.
+    #[must_use]
+    pub fn to_gpu(&self) -> GpuMaterialRule {
+        let mut flags = 0;
+        if self.falls {
+            flags |= RULE_FALLS;
+        }
+        if self.flow_rate > 0.0 {
+            flags |= RULE_FLOWS;
+        }
+        let (reacts_with, reaction_product, reaction_chance) = match self.reaction {
+            Some(r) => {
+                flags |= RULE_REACTS;
+                (u32::from(r.with), u32::from(r.product), r.chance)
+            },
+            None => (0, 0, 0.0),
+        };
+        GpuMaterialRule {
+            density: self.density,
+            flow_rate: self.flow_rate,
+            flags,
+            reacts_with,
+            reaction_product,
+            reaction_chance,
+            padding: [0; 2],
+        }
+    }
+}
+
+/// GPU-compatible material rule.
+/// Layout: 32 bytes total, one entry per material ID.
+#[derive(Debug, Clone, Copy, PartialEq, Pod, Zeroable)]
+#[repr(C)]
+pub struct GpuMaterialRule {
+    /// Relative density (4 bytes).
+    pub density: f32,
+    /// Sideways flow rate (4 bytes).
+    pub flow_rate: f32,
+    /// `RULE_*` behavior flags (4 bytes).
+    pub flags: u32,
+    /// Material ID that triggers the reaction (4 bytes).
+    pub reacts_with: u32,
+    /// Material ID produced by the reaction (4 bytes).
+    pub reaction_product: u32,
+    /// Reaction chance per step (4 bytes).
+    pub reaction_chance: f32,
+    /// Padding for alignment.
+    padding: [u32; 2],
+}
+
+/// Per-material simulation rules, indexed by material ID.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleTable {
+    rules: Vec<MaterialRule>,
+}
+
+impl Default for RuleTable {
+    fn default() -> Self {
+        Self {
+            rules: vec![MaterialRule::default(); MAX_MATERIALS],
+        }
+    }
+}
+
+impl RuleTable {
+    /// Creates a table where every material is an inert solid.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the rule for a material.
+    pub fn set(&mut self, material: u8, rule: MaterialRule) {
+        self.rules[usize::from(material)] = rule;
+    }
+
+    /// Sets the rule for a material (builder style).
+    #[must_use]
+    pub fn with_rule(mut self, material: u8, rule: MaterialRule) -> Self {
+        self.set(material, rule);
+        self
+    }
+
+    /// Gets the rule for a material.
+    #[must_use]
+    pub fn get(&self, material: u8) -> &MaterialRule {
+        &self.rules[usize::from(material)]
+    }
+
+    /// Converts the table to the array uploaded to the GPU.
+    #[must_use]
+    pub fn to_gpu(&self) -> Vec<GpuMaterialRule> {
+        self.rules.iter().map(MaterialRule::to_gpu).collect()
+    }
+
+    /// Creates a storage buffer holding the table.
+    #[must_use]
+    pub fn create_buffer(&self, device: &wgpu::Device) -> wgpu::Buffer {
+        device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Simulation Rule Table"),
+            contents: bytemuck::cast_slice(&self.to_gpu()),
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+        })
+    }
+
+    /// Writes the table into a buffer created by [`Self::create_buffer`].
+    pub fn upload(&self, queue: &wgpu::Queue, buffer: &wgpu::Buffer) {
+        queue.write_buffer(buffer, 0, bytemuck::cast_slice(&self.to_gpu()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gpu_rule_layout() {
+        assert_eq!(std::mem::size_of::<GpuMaterialRule>(), 32);
+
+        let table = RuleTable::new();
+        let bytes: Vec<u8> = bytemuck::cast_slice(&table.to_gpu()).to_vec();
+        assert_eq!(bytes.len(), MAX_MATERIALS * 32);
+        assert!(bytes.iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_rule_table_serializes_fields_in_order() {
+        let table = RuleTable::new()
+            .with_rule(3, MaterialRule::liquid(1.0, 4.0).with_reaction(7, 9, 0.25))
+            .with_rule(5, MaterialRule::powder(1.6));
+
+        let gpu = table.to_gpu();
+        let bytes: &[u8] = bytemuck::cast_slice(&gpu);
+        let word = |index: usize, field: usize| {
+            let start = index * 32 + field * 4;
+            [
+                bytes[start],
+                bytes[start + 1],
+                bytes[start + 2],
+                bytes[start + 3],
+            ]
+        };
+
+        assert_eq!(word(3, 0), 1.0f32.to_le_bytes());
+        assert_eq!(word(3, 1), 4.0f32.to_le_bytes());
+        assert_eq!(
+            word(3, 2),
+            (RULE_FALLS | RULE_FLOWS | RULE_REACTS).to_le_bytes()
+        );
+        assert_eq!(word(3, 3), 7u32.to_le_bytes());
+        assert_eq!(word(3, 4), 9u32.to_le_bytes());
+        assert_eq!(word(3, 5), 0.25f32.to_le_bytes());
+
+        assert_eq!(word(5, 0), 1.6f32.to_le_bytes());
+        assert_eq!(word(5, 2), RULE_FALLS.to_le_bytes());
+        assert_eq!(word(4, 0), 0u32.to_le_bytes());
+    }
+}
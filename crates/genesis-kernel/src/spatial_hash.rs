@@ -0,0 +1,342 @@
+//! Spatial hash grid for efficient spatial queries.
+//!
+//! This module provides a uniform-grid alternative to the [`QuadTree`] for
+//! organizing objects by their 2D bounding boxes. Objects are bucketed into
+//! fixed-size cells, giving O(1) insertion and queries proportional to the
+//! number of cells a range overlaps. This outperforms the quadtree when objects
+//! are spread uniformly and densely (e.g. large NPC fields), while the quadtree
+//! adapts better to heavily clustered distributions.
+//!
+//! [`QuadTree`]: crate::quadtree::QuadTree
+
+use std::collections::HashMap;
+
+use crate::quadtree::Rect;
+
+/// Default cell size in world units.
+pub const DEFAULT_SPATIAL_HASH_CELL_SIZE: f32 = 64.0;
+
+/// Statistics about a spatial hash.
+#[derive(Debug, Clone, Default)]
+pub struct SpatialHashStats {
+    /// Number of non-empty cells.
+    pub cell_count: usize,
+    /// Total number of objects stored.
+    pub object_count: usize,
+    /// Total number of cell entries (objects spanning cells count once per cell).
+    pub entry_count: usize,
+    /// Largest number of entries in a single cell.
+    pub max_cell_entries: usize,
+}
+
+/// Uniform grid that buckets objects by cell.
+pub struct SpatialHash<T> {
+    /// Size of a grid cell in world units.
+    cell_size: f32,
+    /// Reciprocal of the cell size.
+    inv_cell_size: f32,
+    /// Object indices per occupied cell.
+    cells: HashMap<(i32, i32), Vec<usize>>,
+    /// Stored objects with their bounds.
+    objects: Vec<(Rect, T)>,
+}
+
+impl<T> Default for SpatialHash<T> {
+    fn default() -> Self {
+        Self::new(DEFAULT_SPATIAL_HASH_CELL_SIZE)
+    }
+}
+
+impl<T> SpatialHash<T> {
+    /// Creates a new spatial hash with the given cell size.
+    ///
+    /// The cell size should be close to the typical object size; non-positive
+    /// values fall back to [`DEFAULT_SPATIAL_HASH_CELL_SIZE`].
+    #[must_use]
+    pub fn new(cell_size: f32) -> Self {
+        let cell_size = if cell_size > 0.0 {
+            cell_size
+        } else {
+            DEFAULT_SPATIAL_HASH_CELL_SIZE
+        };
+        Self {
+            cell_size,
+            inv_cell_size: 1.0 / cell_size,
+            cells: HashMap::new(),
+            objects: Vec::new(),
+        }
+    }
+
+    /// Returns the cell size.
+    #[must_use]
+    pub const fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// Returns the cell coordinate containing a point.
+    #[must_use]
+    pub fn cell_of(&self, x: f32, y: f32) -> (i32, i32) {
+        (
+            (x * self.inv_cell_size).floor() as i32,
+            (y * self.inv_cell_size).floor() as i32,
+        )
+    }
+
+    /// Returns the inclusive cell range covered by a rectangle.
+    fn cell_range(&self, bounds: Rect) -> ((i32, i32), (i32, i32)) {
+        let min = self.cell_of(bounds.x, bounds.y);
+        let max = self.cell_of(bounds.right(), bounds.bottom());
+        (min, max)
+    }
+
+    /// Inserts an object with its bounding rect.
+    pub fn insert(&mut self, bounds: Rect, object: T) {
+        let index = self.objects.len();
+        let ((min_x, min_y), (max_x, max_y)) = self.cell_range(bounds);
+        for cy in min_y..=max_y {
+            for cx in min_x..=max_x {
+                self.cells.entry((cx, cy)).or_default().push(index);
+            }
+        }
+        self.objects.push((bounds, object));
+    }
+
+    /// Queries all objects that intersect with the given range.
+    #[must_use]
+    pub fn query_range(&self, range: Rect) -> Vec<&T> {
+        self.query_with_bounds(range)
+            .into_iter()
+            .map(|(_, object)| object)
+            .collect()
+    }
+
+    /// Queries objects with their bounds.
+    #[must_use]
+    pub fn query_with_bounds(&self, range: Rect) -> Vec<(&Rect, &T)> {
+        let mut result = Vec::new();
+        let ((min_x, min_y), (max_x, max_y)) = self.cell_range(range);
+
+        for cy in min_y..=max_y {
+            for cx in min_x..=max_x {
+                let Some(indices) = self.cells.get(&(cx, cy)) else {
+                    continue;
+                };
+                for &index in indices {
+                    let (bounds, object) = &self.objects[index];
+                    if !bounds.intersects(&range) {
+                        continue;
+                    }
+                    // An object spanning several cells is only reported from the
+                    // first cell shared by both the object and the query range.
+                    let first = self.cell_of(bounds.x.max(range.x), bounds.y.max(range.y));
+                    if first == (cx, cy) {
+                        result.push((bounds, object));
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Queries all objects whose bounds intersect a circle.
+    #[must_use]
+    pub fn query_radius(&self, x: f32, y: f32, radius: f32) -> Vec<&T> {
+        let radius = radius.max(0.0);
+        let range = Rect::from_center(x, y, radius * 2.0, radius * 2.0);
+        let radius_sq = radius * radius;
+
+        self.query_with_bounds(range)
+            .into_iter()
+            .filter(|(bounds, _)| {
+                // Distance from the circle center to the closest point on the rect
+                let dx = x - x.clamp(bounds.x, bounds.right());
+                let dy = y - y.clamp(bounds.y, bounds.bottom());
+                dx * dx + dy * dy <= radius_sq
+            })
+            .map(|(_, object)| object)
+            .collect()
+    }
+
+    /// Queries all objects at a point.
+    #[must_use]
+    pub fn query_point(&self, x: f32, y: f32) -> Vec<&T> {
+        self.cells
+            .get(&self.cell_of(x, y))
+            .into_iter()
+            .flatten()
+            .map(|&index| &self.objects[index])
+            .filter(|(bounds, _)| bounds.contains_point(x, y))
+            .map(|(_, object)| object)
+            .collect()
+    }
+
+    /// Clears all objects, keeping allocated buckets for reuse.
+    pub fn clear(&mut self) {
+        for bucket in self.cells.values_mut() {
+            bucket.clear();
+        }
+        self.objects.clear();
+    }
+
+    /// Returns statistics about the grid.
+    #[must_use]
+    pub fn stats(&self) -> SpatialHashStats {
+        let mut stats = SpatialHashStats {
+            object_count: self.objects.len(),
+            ..Default::default()
+        };
+        for bucket in self.cells.values().filter(|b| !b.is_empty()) {
+            stats.cell_count += 1;
+            stats.entry_count += bucket.len();
+            stats.max_cell_entries = stats.max_cell_entries.max(bucket.len());
+        }
+        stats
+    }
+
+    /// Returns the number of stored objects.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.objects.len()
+    }
+
+    /// Returns true if no objects are stored.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.objects.is_empty()
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for SpatialHash<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SpatialHash")
+            .field("cell_size", &self.cell_size)
+            .field("cells", &self.cells.len())
+            .field("objects", &self.objects.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(mut values: Vec<&u32>) -> Vec<u32> {
+        values.sort_unstable();
+        values.into_iter().copied().collect()
+    }
+
+    #[test]
+    fn test_spatial_hash_insert_and_len() {
+        let mut hash = SpatialHash::new(10.0);
+        assert!(hash.is_empty());
+
+        hash.insert(Rect::new(5.0, 5.0, 2.0, 2.0), 1u32);
+        hash.insert(Rect::new(50.0, 50.0, 2.0, 2.0), 2u32);
+
+        assert_eq!(hash.len(), 2);
+        assert_eq!(hash.stats().cell_count, 2);
+    }
+
+    #[test]
+    fn test_spatial_hash_cell_of_negative() {
+        let hash: SpatialHash<u32> = SpatialHash::new(10.0);
+        assert_eq!(hash.cell_of(5.0, 5.0), (0, 0));
+        assert_eq!(hash.cell_of(-0.5, -10.0), (-1, -1));
+        assert_eq!(hash.cell_of(-10.5, 25.0), (-2, 2));
+    }
+
+    #[test]
+    fn test_spatial_hash_query_range() {
+        let mut hash = SpatialHash::new(10.0);
+        hash.insert(Rect::new(5.0, 5.0, 2.0, 2.0), 1u32);
+        hash.insert(Rect::new(25.0, 5.0, 2.0, 2.0), 2u32);
+        hash.insert(Rect::new(-15.0, -15.0, 2.0, 2.0), 3u32);
+
+        let results = sorted(hash.query_range(Rect::new(0.0, 0.0, 30.0, 10.0)));
+        assert_eq!(results, vec![1, 2]);
+
+        let results = sorted(hash.query_range(Rect::new(-20.0, -20.0, 10.0, 10.0)));
+        assert_eq!(results, vec![3]);
+    }
+
+    #[test]
+    fn test_spatial_hash_spanning_object_reported_once() {
+        let mut hash = SpatialHash::new(10.0);
+        // Spans a 4x4 block of cells
+        hash.insert(Rect::new(5.0, 5.0, 30.0, 30.0), 7u32);
+
+        let results = hash.query_range(Rect::new(0.0, 0.0, 50.0, 50.0));
+        assert_eq!(results.len(), 1);
+
+        let results = hash.query_range(Rect::new(22.0, 22.0, 5.0, 5.0));
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn test_spatial_hash_query_radius() {
+        let mut hash = SpatialHash::new(16.0);
+        hash.insert(Rect::from_center(0.0, 0.0, 2.0, 2.0), 1u32);
+        hash.insert(Rect::from_center(10.0, 0.0, 2.0, 2.0), 2u32);
+        // Inside the bounding square of the radius but outside the circle
+        hash.insert(Rect::from_center(14.0, 14.0, 2.0, 2.0), 3u32);
+        hash.insert(Rect::from_center(100.0, 100.0, 2.0, 2.0), 4u32);
+
+        let results = sorted(hash.query_radius(0.0, 0.0, 15.0));
+        assert_eq!(results, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_spatial_hash_query_point() {
+        let mut hash = SpatialHash::new(10.0);
+        hash.insert(Rect::new(0.0, 0.0, 20.0, 20.0), 1u32);
+        hash.insert(Rect::new(15.0, 15.0, 10.0, 10.0), 2u32);
+
+        assert_eq!(sorted(hash.query_point(5.0, 5.0)), vec![1]);
+        assert_eq!(sorted(hash.query_point(17.0, 17.0)), vec![1, 2]);
+        assert!(hash.query_point(50.0, 50.0).is_empty());
+    }
+
+    #[test]
+    fn test_spatial_hash_matches_quadtree() {
+        use crate::quadtree::QuadTree;
+
+        let mut hash = SpatialHash::new(32.0);
+        let mut tree = QuadTree::new(Rect::new(0.0, 0.0, 2000.0, 2000.0), 8, 8);
+        let mut state = 12345u32;
+        for i in 0..2000u32 {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let x = (state >> 8) as f32 % 1990.0;
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let y = (state >> 8) as f32 % 1990.0;
+            let bounds = Rect::new(x, y, 8.0, 8.0);
+            hash.insert(bounds, i);
+            tree.insert(bounds, i);
+        }
+
+        for range in [
+            Rect::new(100.0, 100.0, 300.0, 200.0),
+            Rect::new(0.0, 0.0, 2000.0, 2000.0),
+            Rect::new(1500.0, 20.0, 33.0, 900.0),
+        ] {
+            assert_eq!(sorted(hash.query_range(range)), sorted(tree.query(range)));
+        }
+    }
+
+    #[test]
+    fn test_spatial_hash_clear() {
+        let mut hash = SpatialHash::new(10.0);
+        hash.insert(Rect::new(5.0, 5.0, 2.0, 2.0), 1u32);
+        hash.clear();
+
+        assert!(hash.is_empty());
+        assert!(hash.query_range(Rect::new(0.0, 0.0, 10.0, 10.0)).is_empty());
+        assert_eq!(hash.stats().cell_count, 0);
+    }
+
+    #[test]
+    fn test_spatial_hash_invalid_cell_size() {
+        let hash: SpatialHash<u32> = SpatialHash::new(0.0);
+        assert!((hash.cell_size() - DEFAULT_SPATIAL_HASH_CELL_SIZE).abs() < f32::EPSILON);
+    }
+}
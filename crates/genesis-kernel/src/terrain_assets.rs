@@ -0,0 +1,256 @@
+//! Terrain tile manifest and validation.
+//!
+//! A manifest lists the tile images that make up the terrain atlas, one
+//! `name = path` entry per line with paths relative to the manifest's
+//! directory. Blank lines and lines starting with `#` are ignored.
+//! [`TerrainAssetManifest::validate`] checks every referenced image so a
+//! bad hot-swap shows up as a list of problems instead of a blank atlas.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Width and height in pixels of every terrain tile image.
+pub const PIXELS_PER_TILE: u32 = 16;
+
+/// One tile image referenced by the manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TerrainTile {
+    /// Tile name used to look it up in the atlas.
+    pub name: String,
+    /// Image path relative to the manifest root.
+    pub path: PathBuf,
+}
+
+/// Problem found while validating a manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManifestIssue {
+    /// A line could not be parsed as `name = path`.
+    MalformedLine {
+        /// 1-based line number.
+        line: usize,
+    },
+    /// Two tiles share a name; the later one shadows the earlier.
+    DuplicateName(String),
+    /// The tile image does not exist.
+    MissingTile {
+        /// Tile name.
+        name: String,
+        /// Resolved image path.
+        path: PathBuf,
+    },
+    /// The tile image exists but could not be decoded.
+    Unreadable {
+        /// Tile name.
+        name: String,
+        /// Resolved image path.
+        path: PathBuf,
+        /// Decoder error.
+        reason: String,
+    },
+    /// The tile image is not `PIXELS_PER_TILE` square.
+    WrongSize {
+        /// Tile name.
+        name: String,
+        /// Actual width in pixels.
+        width: u32,
+        /// Actual height in pixels.
+        height: u32,
+    },
+}
+
+impl fmt::Display for ManifestIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MalformedLine { line } => write!(f, "line {line}: expected `name = path`"),
+            Self::DuplicateName(name) => write!(f, "tile '{name}' is listed more than once"),
+            Self::MissingTile { name, path } => {
+                write!(f, "tile '{name}': {} does not exist", path.display())
+            },
+            Self::Unreadable { name, path, reason } => {
+                write!(f, "tile '{name}': cannot read {}: {reason}", path.display())
+            },
+            Self::WrongSize {
+                name,
+                width,
+                height,
+            } => write!(
+                f,
+                "tile '{name}' is {width}x{height}, expected {PIXELS_PER_TILE}x{PIXELS_PER_TILE}"
+            ),
+        }
+    }
+}
+
+/// Parsed terrain tile manifest.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TerrainAssetManifest {
+    /// Directory tile paths are resolved against.
+    pub root: PathBuf,
+    /// Tiles in manifest order.
+    pub tiles: Vec<TerrainTile>,
+    /// Lines that failed to parse (1-based).
+    malformed_lines: Vec<usize>,
+}
+
+impl TerrainAssetManifest {
+    /// Creates an empty manifest rooted at `root`.
+    #[must_use]
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Adds a tile entry.
+    #[must_use]
+    pub fn with_tile(mut self, name: impl Into<String>, path: impl Into<PathBuf>) -> Self {
+        self.tiles.push(TerrainTile {
+            name: name.into(),
+            path: path.into(),
+        });
+        self
+    }
+
+    /// Parses manifest text; tile paths resolve against `root`.
+    ///
+    /// Malformed lines are skipped and reported by [`Self::validate`].
+    #[must_use]
+    pub fn parse(root: impl Into<PathBuf>, text: &str) -> Self {
+        let mut manifest = Self::new(root);
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            match line.split_once('=') {
+                Some((name, path)) if !name.trim().is_empty() && !path.trim().is_empty() => {
+                    manifest = manifest.with_tile(name.trim(), path.trim());
+                },
+                _ => manifest.malformed_lines.push(i + 1),
+            }
+        }
+        manifest
+    }
+
+    /// Reads and parses the manifest file at `path`.
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)?;
+        let root = path.parent().unwrap_or_else(|| Path::new(""));
+        Ok(Self::parse(root, &text))
+    }
+
+    /// Absolute (or root-relative) path of a tile's image.
+    #[must_use]
+    pub fn resolve(&self, tile: &TerrainTile) -> PathBuf {
+        self.root.join(&tile.path)
+    }
+
+    /// Checks every tile image, returning all problems found.
+    ///
+    /// Only image headers are read, so this is cheap enough to run on every
+    /// reload.
+    #[must_use]
+    pub fn validate(&self) -> Vec<ManifestIssue> {
+        let mut issues: Vec<ManifestIssue> = self
+            .malformed_lines
+            .iter()
+            .map(|&line| ManifestIssue::MalformedLine { line })
+            .collect();
+
+        for (i, tile) in self.tiles.iter().enumerate() {
+            if self.tiles[..i].iter().any(|t| t.name == tile.name)
+                && !issues.contains(&ManifestIssue::DuplicateName(tile.name.clone()))
+            {
+                issues.push(ManifestIssue::DuplicateName(tile.name.clone()));
+            }
+
+            let path = self.resolve(tile);
+            if !path.is_file() {
+                issues.push(ManifestIssue::MissingTile {
+                    name: tile.name.clone(),
+                    path,
+                });
+                continue;
+            }
+            match image::image_dimensions(&path) {
+                Ok((width, height)) if width != PIXELS_PER_TILE || height != PIXELS_PER_TILE => {
+                    issues.push(ManifestIssue::WrongSize {
+                        name: tile.name.clone(),
+                        width,
+                        height,
+                    });
+                },
+                Ok(_) => {},
+                Err(e) => issues.push(ManifestIssue::Unreadable {
+                    name: tile.name.clone(),
+                    path,
+                    reason: e.to_string(),
+                }),
+            }
+        }
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_png(path: &Path, width: u32, height: u32) {
+        image::RgbaImage::new(width, height)
+            .save(path)
+            .expect("should write png");
+    }
+
+    #[test]
+    fn test_valid_manifest_has_no_issues() {
+        let dir = tempfile::tempdir().expect("should create temp dir");
+        write_png(&dir.path().join("grass.png"), 16, 16);
+        write_png(&dir.path().join("sand.png"), 16, 16);
+        let manifest_path = dir.path().join("terrain.manifest");
+        std::fs::write(
+            &manifest_path,
+            "# terrain tiles\ngrass = grass.png\n\nsand = sand.png\n",
+        )
+        .expect("should write manifest");
+
+        let manifest = TerrainAssetManifest::load(&manifest_path).expect("should load");
+        assert_eq!(manifest.tiles.len(), 2);
+        assert_eq!(manifest.tiles[1].name, "sand");
+        assert!(manifest.validate().is_empty());
+    }
+
+    #[test]
+    fn test_missing_and_wrong_size_tiles_reported() {
+        let dir = tempfile::tempdir().expect("should create temp dir");
+        write_png(&dir.path().join("grass.png"), 16, 16);
+        write_png(&dir.path().join("stone.png"), 32, 16);
+        std::fs::write(dir.path().join("dirt.png"), b"not a png").expect("should write");
+
+        let manifest = TerrainAssetManifest::parse(
+            dir.path(),
+            "grass = grass.png\nwater = water.png\nstone = stone.png\n\
+             dirt = dirt.png\nno equals sign\ngrass = grass.png\n",
+        );
+        let issues = manifest.validate();
+
+        assert!(issues.contains(&ManifestIssue::MalformedLine { line: 5 }));
+        assert!(issues.contains(&ManifestIssue::DuplicateName("grass".to_string())));
+        assert!(issues.contains(&ManifestIssue::MissingTile {
+            name: "water".to_string(),
+            path: dir.path().join("water.png"),
+        }));
+        assert!(issues.contains(&ManifestIssue::WrongSize {
+            name: "stone".to_string(),
+            width: 32,
+            height: 16,
+        }));
+        assert!(issues
+            .iter()
+            .any(|i| matches!(i, ManifestIssue::Unreadable { name, .. } if name == "dirt")));
+        assert_eq!(issues.len(), 5);
+        assert!(issues[2].to_string().contains("32x16"));
+    }
+}
@@ -3,9 +3,15 @@
 //! Renders a grid of colored tiles based on biome data from cubiomes.
 //! Each tile represents one biome cell, rendered as a solid-color quad
 //! with heightmap-based shading and time-of-day shadow casting.
+//! Newly cached chunks fade in over `TerrainRenderConfig::fade_in_time`
+//! to hide streaming pop-in. Weather tints the scene and adds fog that
+//! thickens toward the screen edges (see `set_weather`).
 
+use std::time::Instant;
+
+use crate::chunk_cache::{ChunkCache, StreamingConfig, StreamingStats};
+use crate::lighting::LightingSystem;
 use bytemuck::{Pod, Zeroable};
-use std::collections::HashMap;
 use tracing::info;
 use wgpu::util::DeviceExt;
 
@@ -17,6 +23,8 @@ struct CameraUniform {
     time_of_day: f32,
     sun_intensity: f32,
     _pad0: f32,
+    // rgb = weather color tint, a = fog density
+    weather: vec4<f32>,
 };
 
 @group(0) @binding(0)
@@ -32,6 +40,7 @@ struct InstanceInput {
     @location(3) tile_color: vec4<f32>,
     @location(4) height: f32,
     @location(5) height_deltas: vec4<f32>,
+    @location(6) alpha: f32,
 };
 
 struct VertexOutput {
@@ -39,6 +48,8 @@ struct VertexOutput {
     @location(0) color: vec4<f32>,
     @location(1) height: f32,
     @location(2) height_deltas: vec4<f32>,
+    @location(3) alpha: f32,
+    @location(4) screen_pos: vec2<f32>,
 };
 
 @vertex
@@ -51,6 +62,8 @@ fn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {
     out.color = instance.tile_color;
     out.height = instance.height;
     out.height_deltas = instance.height_deltas;
+    out.alpha = instance.alpha;
+    out.screen_pos = screen_pos;
     return out;
 }
 
@@ -91,8 +104,17 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
         light = light * 0.5;
     }
 
-    let final_color = clamp(base * light, vec3<f32>(0.0), vec3<f32>(1.0));
-    return vec4<f32>(final_color, in.color.a);
+    var final_color = clamp(base * light, vec3<f32>(0.0), vec3<f32>(1.0));
+
+    // Weather: darken/desaturate, then fog in from the screen edges
+    let tint = camera.weather.rgb;
+    let grey = dot(final_color, vec3<f32>(0.299, 0.587, 0.114));
+    let desaturate = 1.0 - min(tint.r, min(tint.g, tint.b));
+    final_color = mix(final_color, vec3<f32>(grey), desaturate) * tint;
+    let fog = camera.weather.a * smoothstep(0.0, 1.4, length(in.screen_pos));
+    final_color = mix(final_color, vec3<f32>(0.55, 0.58, 0.62) * tint, fog);
+    // Per-chunk fade-in alpha
+    return vec4<f32>(final_color, in.color.a * in.alpha);
 }
 ";
 
@@ -105,10 +127,11 @@ pub struct TerrainTileInstance {
     pub color: [f32; 4],
     pub height: f32,
     pub height_deltas: [f32; 4],
-    pub _pad: [f32; 1],
+    /// Chunk fade-in alpha multiplied into the tile color (0.0 to 1.0).
+    pub alpha: f32,
 }
 
-/// Camera uniform for terrain rendering (32 bytes = 8 x f32).
+/// Camera uniform for terrain rendering (48 bytes = 12 x f32).
 #[repr(C)]
 #[derive(Debug, Clone, Copy, Pod, Zeroable)]
 struct TerrainCameraUniform {
@@ -118,6 +141,8 @@ struct TerrainCameraUniform {
     time_of_day: f32,
     sun_intensity: f32,
     _pad0: f32,
+    /// Weather color tint (rgb) and fog density (a).
+    weather: [f32; 4],
 }
 
 #[allow(dead_code)]
@@ -125,12 +150,50 @@ struct CachedChunk {
     chunk_x: i32,
     chunk_y: i32,
     instances: Vec<TerrainTileInstance>,
+    /// When the chunk was cached, for the fade-in.
+    cached_at: Instant,
+}
+
+impl CachedChunk {
+    /// Fade-in alpha at `now` for a fade lasting `fade_time` seconds.
+    fn fade_alpha(&self, now: Instant, fade_time: f32) -> f32 {
+        chunk_fade_alpha(now.saturating_duration_since(self.cached_at).as_secs_f32(), fade_time)
+    }
+}
+
+/// Color water is blended toward, in 0-255 channel units.
+const WATER_COLOR: [f32; 3] = [30.0, 64.0, 140.0];
+
+/// Blends a tile color toward water for a tile `depth` blocks below the
+/// waterline: half-way at the surface, up to 85% for deep water.
+pub fn water_tint(color: [f32; 3], depth: f32) -> [f32; 3] {
+    let t = (0.5 + depth.max(0.0) / 64.0).min(0.85);
+    [
+        color[0] + (WATER_COLOR[0] - color[0]) * t,
+        color[1] + (WATER_COLOR[1] - color[1]) * t,
+        color[2] + (WATER_COLOR[2] - color[2]) * t,
+    ]
+}
+
+/// Alpha of a chunk `age` seconds after it was cached, ramping linearly
+/// from 0 to 1 over `fade_time` seconds.
+pub fn chunk_fade_alpha(age: f32, fade_time: f32) -> f32 {
+    if fade_time <= 0.0 {
+        1.0
+    } else {
+        (age / fade_time).clamp(0.0, 1.0)
+    }
 }
 
 pub struct TerrainRenderConfig {
     pub tile_size: f32,
     pub biome_scale: i32,
     pub render_radius: i32,
+    /// Seconds a newly cached chunk takes to fade in (0 disables the fade).
+    pub fade_in_time: f32,
+    /// Block height of the waterline; tiles at or below it are shaded as
+    /// water, darker the deeper they are.
+    pub sea_level: f32,
 }
 
 impl Default for TerrainRenderConfig {
@@ -139,6 +202,8 @@ impl Default for TerrainRenderConfig {
             tile_size: 1.0,
             biome_scale: 1,
             render_radius: 80,
+            fade_in_time: 0.3,
+            sea_level: 63.0,
         }
     }
 }
@@ -152,8 +217,11 @@ pub struct TerrainTileRenderer {
     camera_bind_group: wgpu::BindGroup,
     max_instances: usize,
     instance_count: u32,
-    cached_chunks: HashMap<(i32, i32), CachedChunk>,
+    cached_chunks: ChunkCache<CachedChunk>,
     config: TerrainRenderConfig,
+    lighting: LightingSystem,
+    /// Weather color tint (rgb) and fog density (a).
+    weather: [f32; 4],
     enabled: bool,
 }
 
@@ -229,6 +297,11 @@ impl TerrainTileRenderer {
                                 offset: 36,
                                 shader_location: 5,
                             },
+                            wgpu::VertexAttribute {
+                                format: wgpu::VertexFormat::Float32,
+                                offset: 52,
+                                shader_location: 6,
+                            },
                         ],
                     },
                 ],
@@ -301,17 +374,43 @@ impl TerrainTileRenderer {
         Self {
             pipeline, vertex_buffer, index_buffer, instance_buffer,
             camera_buffer, camera_bind_group, max_instances,
-            instance_count: 0, cached_chunks: HashMap::new(),
-            config, enabled: false,
+            instance_count: 0, cached_chunks: ChunkCache::default(),
+            config, lighting: LightingSystem::default(),
+            weather: [1.0, 1.0, 1.0, 0.0], enabled: false,
         }
     }
 
+    /// Starts drawing cached chunks on the next `update_camera`.
     pub fn enable(&mut self) { self.enabled = true; }
+    /// Stops drawing terrain; cached chunks are kept.
     pub fn disable(&mut self) { self.enabled = false; }
     pub fn is_enabled(&self) -> bool { self.enabled }
     pub fn set_config(&mut self, config: TerrainRenderConfig) { self.config = config; }
     pub fn config(&self) -> &TerrainRenderConfig { &self.config }
+    /// Sets the waterline used for chunks cached from now on.
+    pub fn set_sea_level(&mut self, sea_level: f32) { self.config.sea_level = sea_level; }
     pub fn clear_cache(&mut self) { self.cached_chunks.clear(); }
+    /// Sets the ambient occlusion used for chunks cached from now on.
+    pub fn set_lighting(&mut self, lighting: LightingSystem) { self.lighting = lighting; }
+
+    /// Set the weather color tint and fog density (0.0 to 1.0) applied on
+    /// the next `update_camera`.
+    pub fn set_weather(&mut self, tint: [f32; 3], fog_density: f32) {
+        self.weather = [tint[0], tint[1], tint[2], fog_density.clamp(0.0, 1.0)];
+    }
+    /// Ambient occlusion settings applied when caching chunks.
+    pub fn lighting(&self) -> &LightingSystem { &self.lighting }
+    /// Sets the chunk the camera is in; chunks far from it are evicted
+    /// first when the cache goes over budget.
+    pub fn set_streaming_center(&mut self, chunk_x: i32, chunk_y: i32) {
+        self.cached_chunks.set_center((chunk_x, chunk_y));
+    }
+    /// Sets the memory budget and generation order for cached chunks.
+    pub fn set_streaming_config(&mut self, config: StreamingConfig) {
+        self.cached_chunks.set_config(config);
+    }
+    /// Cached chunk count and memory usage.
+    pub fn streaming_stats(&self) -> StreamingStats { self.cached_chunks.stats() }
 
     pub fn cache_chunk(
         &mut self,
@@ -324,15 +423,23 @@ impl TerrainTileRenderer {
         colors: &dyn Fn(i32) -> [u8; 3],
     ) {
         let tile_size = self.config.tile_size;
+        let sea_level = self.config.sea_level;
         let w = width as usize;
         let h = height as usize;
+        let ao = self.lighting.compute_ao(heights, w, h);
         let mut instances = Vec::with_capacity(w * h);
         for by in 0..h {
             for bx in 0..w {
                 let idx = by * w + bx;
                 let biome_id = biomes[idx];
-                let color = colors(biome_id);
                 let self_h = heights[idx];
+                let [r, g, b] = colors(biome_id).map(f32::from);
+                let color = if self_h <= sea_level {
+                    water_tint([r, g, b], sea_level - self_h)
+                } else {
+                    [r, g, b]
+                };
+                let brightness = ao.get(idx).copied().unwrap_or(1.0) / 255.0;
                 let h_north = if by > 0     { heights[(by - 1) * w + bx] } else { self_h };
                 let h_south = if by + 1 < h { heights[(by + 1) * w + bx] } else { self_h };
                 let h_east  = if bx + 1 < w { heights[by * w + bx + 1]  } else { self_h };
@@ -343,9 +450,9 @@ impl TerrainTileRenderer {
                     position: [world_x, world_y],
                     size: [tile_size, tile_size],
                     color: [
-                        color[0] as f32 / 255.0,
-                        color[1] as f32 / 255.0,
-                        color[2] as f32 / 255.0,
+                        color[0] * brightness,
+                        color[1] * brightness,
+                        color[2] * brightness,
                         1.0,
                     ],
                     height: self_h,
@@ -355,14 +462,19 @@ impl TerrainTileRenderer {
                         h_south - self_h,
                         h_west  - self_h,
                     ],
-                    _pad: [0.0],
+                    alpha: 1.0,
                 });
             }
         }
-        self.cached_chunks.insert(
+        let bytes = instances.len() * std::mem::size_of::<TerrainTileInstance>();
+        let evicted = self.cached_chunks.insert(
             (chunk_x, chunk_y),
-            CachedChunk { chunk_x, chunk_y, instances },
+            CachedChunk { chunk_x, chunk_y, instances, cached_at: Instant::now() },
+            bytes,
         );
+        if !evicted.is_empty() {
+            info!("Evicted {} terrain chunks over memory budget", evicted.len());
+        }
     }
 
     pub fn update_camera(
@@ -381,6 +493,7 @@ impl TerrainTileRenderer {
             time_of_day,
             sun_intensity,
             _pad0: 0.0,
+            weather: self.weather,
         };
         queue.write_buffer(&self.camera_buffer, 0, bytemuck::bytes_of(&uniform));
     }
@@ -405,11 +518,21 @@ impl TerrainTileRenderer {
         let chunk_right  = ((camera_pos.0 + half_w) / chunk_span).ceil()  as i32 + 1;
         let chunk_top    = ((camera_pos.1 - half_h) / chunk_span).floor() as i32 - 1;
         let chunk_bottom = ((camera_pos.1 + half_h) / chunk_span).ceil()  as i32 + 1;
+        let now = Instant::now();
+        let fade_time = self.config.fade_in_time;
         let mut all_instances: Vec<TerrainTileInstance> = Vec::new();
+        self.cached_chunks.tick();
         for cy in chunk_top..=chunk_bottom {
             for cx in chunk_left..=chunk_right {
-                if let Some(chunk) = self.cached_chunks.get(&(cx, cy)) {
-                    all_instances.extend_from_slice(&chunk.instances);
+                if let Some(chunk) = self.cached_chunks.touch((cx, cy)) {
+                    let alpha = chunk.fade_alpha(now, fade_time);
+                    if alpha < 1.0 {
+                        all_instances.extend(
+                            chunk.instances.iter().map(|&inst| TerrainTileInstance { alpha, ..inst }),
+                        );
+                    } else {
+                        all_instances.extend_from_slice(&chunk.instances);
+                    }
                 }
             }
         }
@@ -436,9 +559,67 @@ impl TerrainTileRenderer {
     pub fn cached_chunk_count(&self) -> usize { self.cached_chunks.len() }
     pub fn instance_count(&self) -> u32 { self.instance_count }
     pub fn cached_chunk_coords(&self) -> Vec<(i32, i32)> {
-        self.cached_chunks.keys().copied().collect()
+        self.cached_chunks.keys().collect()
     }
     pub fn is_chunk_cached(&self, chunk_x: i32, chunk_y: i32) -> bool {
-        self.cached_chunks.contains_key(&(chunk_x, chunk_y))
+        self.cached_chunks.contains((chunk_x, chunk_y))
+    }
+    /// Missing chunks around `center` in generation order; see
+    /// [`ChunkCache::plan_generation`].
+    pub fn plan_chunk_generation(
+        &self,
+        previous: Option<(i32, i32)>,
+        center: (i32, i32),
+        radius: i32,
+    ) -> Vec<(i32, i32)> {
+        self.cached_chunks.plan_generation(previous, center, radius)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_fresh_chunk_alpha_ramps_over_fade_time() {
+        let chunk = CachedChunk {
+            chunk_x: 0,
+            chunk_y: 0,
+            instances: Vec::new(),
+            cached_at: Instant::now(),
+        };
+        let fade = TerrainRenderConfig::default().fade_in_time;
+        let at = |secs: f32| chunk.fade_alpha(chunk.cached_at + Duration::from_secs_f32(secs), fade);
+
+        assert!(at(0.0).abs() < f32::EPSILON);
+        assert!((at(fade * 0.5) - 0.5).abs() < 0.01);
+        assert!(at(fade * 0.25) < at(fade * 0.75));
+        assert!((at(fade) - 1.0).abs() < f32::EPSILON);
+        assert!((at(fade * 4.0) - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_zero_fade_time_is_opaque() {
+        assert!((chunk_fade_alpha(0.0, 0.0) - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_water_tint_deepens_with_depth() {
+        let grass = [80.0, 160.0, 60.0];
+        let shallow = water_tint(grass, 0.0);
+        let deep = water_tint(grass, 40.0);
+        // Deeper water is closer to the water color on every channel
+        for i in 0..3 {
+            assert!((deep[i] - WATER_COLOR[i]).abs() < (shallow[i] - WATER_COLOR[i]).abs());
+        }
+        // and stops deepening past the cap
+        let capped = water_tint(grass, 500.0)[2] - water_tint(grass, 1000.0)[2];
+        assert!(capped.abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_instance_alpha_fits_former_padding() {
+        assert_eq!(std::mem::size_of::<TerrainTileInstance>(), 56);
     }
 }
@@ -6,8 +6,11 @@
 //! - Crossfade between scenes
 //! - Configurable duration (default 0.3s)
 //! - GPU-based alpha blending
+//! - Holding while covered until a readiness check passes (loading screens)
 
 use bytemuck::{Pod, Zeroable};
+use std::fmt;
+use std::sync::Arc;
 
 /// Default transition duration in seconds.
 pub const DEFAULT_TRANSITION_DURATION: f32 = 0.3;
@@ -114,6 +117,8 @@ pub enum TransitionState {
     FadingOut,
     /// At midpoint (fully covered).
     AtMidpoint,
+    /// Covered and waiting for a hold predicate to pass.
+    Holding,
     /// Transition is fading in (second half).
     FadingIn,
     /// Transition completed.
@@ -345,6 +350,16 @@ impl TransitionConfig {
     }
 }
 
+/// Readiness check gating a held transition.
+#[derive(Clone)]
+struct HoldPredicate(Arc<dyn Fn() -> bool + Send + Sync>);
+
+impl fmt::Debug for HoldPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HoldPredicate")
+    }
+}
+
 /// Active transition state manager.
 #[derive(Debug, Clone)]
 pub struct TransitionManager {
@@ -360,6 +375,8 @@ pub struct TransitionManager {
     midpoint_callback_pending: bool,
     /// Callback ID for completion notification.
     completion_callback_pending: bool,
+    /// Check that must pass before the transition leaves its covered point.
+    hold: Option<HoldPredicate>,
 }
 
 impl Default for TransitionManager {
@@ -371,6 +388,7 @@ impl Default for TransitionManager {
             uniforms: TransitionUniforms::default(),
             midpoint_callback_pending: false,
             completion_callback_pending: false,
+            hold: None,
         }
     }
 }
@@ -408,6 +426,30 @@ impl TransitionManager {
         self.start(TransitionConfig::crossfade());
     }
 
+    /// Holds the transition at its covered point until `predicate` returns
+    /// true, e.g. until the chunks around spawn are streamed in.
+    ///
+    /// Fades to a color hold at the midpoint; every other type holds before
+    /// it starts revealing. The hold is released the first time the
+    /// predicate passes.
+    pub fn hold_until(&mut self, predicate: impl Fn() -> bool + Send + Sync + 'static) {
+        self.hold = Some(HoldPredicate(Arc::new(predicate)));
+    }
+
+    /// Checks if the transition is held waiting for its predicate.
+    #[must_use]
+    pub fn is_holding(&self) -> bool {
+        matches!(self.state, TransitionState::Holding)
+    }
+
+    /// Elapsed time at which a held transition waits.
+    fn hold_point(&self) -> f32 {
+        match self.config.transition_type {
+            TransitionType::FadeToBlack | TransitionType::FadeToWhite => self.config.duration * 0.5,
+            _ => 0.0,
+        }
+    }
+
     /// Updates the transition state.
     pub fn update(&mut self, dt: f32) {
         if !self.state.is_active() {
@@ -415,6 +457,23 @@ impl TransitionManager {
         }
 
         self.elapsed += dt;
+
+        if let Some(hold) = &self.hold {
+            let hold_point = self.hold_point();
+            if self.elapsed >= hold_point {
+                if (hold.0)() {
+                    self.hold = None;
+                } else {
+                    self.elapsed = hold_point;
+                    self.state = TransitionState::Holding;
+                    let progress = self.progress();
+                    self.uniforms.update_progress(progress, self.config.easing);
+                    // Keep the cover drawn even at progress 0.
+                    self.uniforms.active = 1;
+                    return;
+                }
+            }
+        }
         let progress = (self.elapsed / self.config.duration).clamp(0.0, 1.0);
 
         // Determine state based on progress
@@ -533,6 +592,7 @@ impl TransitionManager {
     pub fn cancel(&mut self) {
         self.state = TransitionState::Idle;
         self.elapsed = 0.0;
+        self.hold = None;
         self.midpoint_callback_pending = false;
         self.completion_callback_pending = false;
         self.uniforms.active = 0;
@@ -904,4 +964,52 @@ mod tests {
         let mid_alpha = manager.alpha();
         assert!(mid_alpha < start_alpha);
     }
+
+    #[test]
+    fn test_hold_until_waits_at_midpoint() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let ready = Arc::new(AtomicBool::new(false));
+        let mut manager = TransitionManager::new();
+        manager.start(TransitionConfig::fade_to_black().with_duration(1.0));
+        let flag = Arc::clone(&ready);
+        manager.hold_until(move || flag.load(Ordering::Relaxed));
+
+        manager.update(0.25);
+        assert_eq!(manager.state(), TransitionState::FadingOut);
+
+        // Covered, but terrain isn't ready yet
+        for _ in 0..10 {
+            manager.update(0.5);
+            assert!(manager.is_holding());
+            assert!(manager.is_active());
+            assert!((manager.progress() - 0.5).abs() < f32::EPSILON);
+        }
+
+        ready.store(true, Ordering::Relaxed);
+        manager.update(0.1);
+        assert_eq!(manager.state(), TransitionState::FadingIn);
+        manager.update(1.0);
+        assert!(manager.is_complete());
+    }
+
+    #[test]
+    fn test_hold_until_fade_from_black_stays_covered() {
+        let mut manager = TransitionManager::new();
+        manager.fade_from_black();
+        manager.hold_until(|| false);
+
+        manager.update(1.0);
+        assert!(manager.is_holding());
+        assert!(manager.alpha() > 0.99);
+        assert_eq!(manager.uniforms().active, 1);
+
+        manager.hold_until(|| true);
+        manager.update(0.1);
+        assert_eq!(manager.state(), TransitionState::FadingIn);
+        assert!(manager.alpha() < 1.0);
+
+        manager.cancel();
+        assert!(!manager.is_holding());
+    }
 }
@@ -5,10 +5,15 @@
 //! - Choice selection and branching
 //! - Speaker portraits and names
 //! - Dialogue effects (mood, reputation, items)
+//!
+//! Node and choice text may be a localization key; see
+//! [`DialogueNode::localized_text`].
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::localization::Localization;
+
 /// Unique identifier for dialogue nodes.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub struct DialogueNodeId(u64);
@@ -249,7 +254,7 @@ impl DialogueCondition {
 pub struct DialogueChoice {
     /// Unique ID within the node
     pub id: u32,
-    /// Text displayed to player
+    /// Text displayed to player (or a localization key)
     pub text: String,
     /// Node to go to when selected
     pub next_node: Option<DialogueNodeId>,
@@ -275,6 +280,14 @@ impl DialogueChoice {
         }
     }
 
+    /// Returns the choice text translated into `locale`.
+    ///
+    /// Literal text that is not a localization key is returned unchanged.
+    #[must_use]
+    pub fn localized_text<'a>(&'a self, localization: &'a Localization, locale: &str) -> &'a str {
+        localization.get(&self.text, locale)
+    }
+
     /// Sets the next node.
     #[must_use]
     pub fn with_next(mut self, node: DialogueNodeId) -> Self {
@@ -313,7 +326,7 @@ pub struct DialogueNode {
     pub speaker: String,
     /// Speaker mood/portrait
     pub mood: SpeakerMood,
-    /// Dialogue text
+    /// Dialogue text (or a localization key)
     pub text: String,
     /// Available choices
     pub choices: Vec<DialogueChoice>,
@@ -366,6 +379,14 @@ impl DialogueNode {
         self
     }
 
+    /// Returns the node text translated into `locale`.
+    ///
+    /// Literal text that is not a localization key is returned unchanged.
+    #[must_use]
+    pub fn localized_text<'a>(&'a self, localization: &'a Localization, locale: &str) -> &'a str {
+        localization.get(&self.text, locale)
+    }
+
     /// Returns whether this node has choices.
     #[must_use]
     pub fn has_choices(&self) -> bool {
@@ -1338,6 +1359,35 @@ mod tests {
         assert_eq!(node.auto_advance, Some(2.0));
     }
 
+    #[test]
+    fn test_dialogue_node_localized_text() {
+        let mut loc = Localization::new("en");
+        loc.load_locale_from_toml_str(
+            r#"
+locale = "en"
+name = "English"
+strings = { "npc.hello" = "Hello!" }
+"#,
+        )
+        .expect("load en locale");
+        loc.load_locale_from_toml_str(
+            r#"
+locale = "fr"
+name = "Français"
+strings = { "npc.hello" = "Bonjour !" }
+"#,
+        )
+        .expect("load fr locale");
+
+        let node = DialogueNode::new(DialogueNodeId::new(1), "Guard", "npc.hello")
+            .with_choice(DialogueChoice::new(1, "Goodbye"));
+
+        assert_eq!(node.localized_text(&loc, "en"), "Hello!");
+        assert_eq!(node.localized_text(&loc, "fr"), "Bonjour !");
+        // Literal text passes through
+        assert_eq!(node.choices[0].localized_text(&loc, "fr"), "Goodbye");
+    }
+
     #[test]
     fn test_dialogue_tree_new() {
         let mut tree = DialogueTree::new(
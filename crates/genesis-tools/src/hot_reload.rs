@@ -5,12 +5,14 @@
 //! - Safe resource swapping
 //! - Reload notifications
 //! - Support for materials and shaders
+//! - A shared, debounced file-watch registry with per-path callbacks
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::{mpsc, Arc, RwLock};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{mpsc, Arc, Mutex, RwLock};
+use std::thread::JoinHandle;
 use std::time::{Duration, Instant, SystemTime};
 
 /// Result type for hot reload operations.
@@ -599,11 +601,323 @@ pub struct ReloadNotification {
     pub error: Option<String>,
 }
 
+/// How often the shared watcher thread checks registered paths.
+const WATCH_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Snapshot of a watched path, compared to detect changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PathStamp {
+    /// Newest modification time (of the file, or of a directory's entries).
+    modified: Option<SystemTime>,
+    /// Number of directory entries (1 for files).
+    entries: usize,
+}
+
+impl PathStamp {
+    /// Reads the current stamp, or `None` if the path doesn't exist.
+    fn read(path: &Path) -> Option<Self> {
+        let metadata = std::fs::metadata(path).ok()?;
+        let mut stamp = Self {
+            modified: metadata.modified().ok(),
+            entries: 1,
+        };
+
+        if metadata.is_dir() {
+            stamp.entries = 0;
+            for entry in std::fs::read_dir(path).ok()?.flatten() {
+                stamp.entries += 1;
+                let modified = entry.metadata().ok().and_then(|m| m.modified().ok());
+                stamp.modified = stamp.modified.max(modified);
+            }
+        }
+
+        Some(stamp)
+    }
+}
+
+/// Paths watched by the shared watcher thread, with their last stamp.
+type WatchedPaths = Arc<Mutex<HashMap<PathBuf, Option<PathStamp>>>>;
+
+/// Compares watched paths against their last stamps and sends the ones
+/// that changed. The file system is read without holding the lock.
+///
+/// Returns false once the registry has stopped listening.
+fn scan_paths(watched: &WatchedPaths, sender: &mpsc::Sender<PathBuf>) -> bool {
+    let snapshot: Vec<(PathBuf, Option<PathStamp>)> = match watched.lock() {
+        Ok(watched) => watched
+            .iter()
+            .map(|(path, stamp)| (path.clone(), *stamp))
+            .collect(),
+        Err(_) => return true,
+    };
+
+    let changed: Vec<(PathBuf, Option<PathStamp>)> = snapshot
+        .into_iter()
+        .filter_map(|(path, stamp)| {
+            let current = PathStamp::read(&path);
+            (current != stamp).then_some((path, current))
+        })
+        .collect();
+    if changed.is_empty() {
+        return true;
+    }
+
+    if let Ok(mut watched) = watched.lock() {
+        for (path, current) in &changed {
+            // Paths unregistered during the scan stay removed
+            if let Some(stamp) = watched.get_mut(path) {
+                *stamp = *current;
+            }
+        }
+    }
+
+    changed
+        .into_iter()
+        .all(|(path, _)| sender.send(path).is_ok())
+}
+
+/// Callback invoked when a watched path changes.
+pub type ChangeCallback = Box<dyn FnMut()>;
+
+/// A registered path and its callback.
+struct WatchEntry {
+    /// Handle returned from registration
+    handle: ResourceHandle,
+    /// Watched file or directory
+    path: PathBuf,
+    /// Called once the path settles after a change
+    callback: ChangeCallback,
+    /// When the latest unhandled change was seen
+    pending_since: Option<Instant>,
+}
+
+/// Registry of watched paths sharing one watcher thread.
+///
+/// Any system can register a file or directory with a callback. The
+/// watcher thread only reports changed paths; callbacks run on the
+/// caller's thread from [`HotReloadRegistry::poll`] once a path has been
+/// quiet for the configured debounce, so a burst of writes triggers a
+/// single callback.
+pub struct HotReloadRegistry {
+    /// Configuration
+    config: HotReloadConfig,
+    /// Registered callbacks
+    entries: Vec<WatchEntry>,
+    /// Paths shared with the watcher thread
+    watched: WatchedPaths,
+    /// Changed paths reported by the watcher thread
+    changes: mpsc::Receiver<PathBuf>,
+    /// Sender for changes found by [`Self::check_now`]
+    sender: mpsc::Sender<PathBuf>,
+    /// Signals the watcher thread to exit
+    stop: Arc<AtomicBool>,
+    /// Watcher thread (None when disabled)
+    watcher: Option<JoinHandle<()>>,
+    /// Next handle ID
+    next_id: u64,
+}
+
+impl std::fmt::Debug for HotReloadRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HotReloadRegistry")
+            .field("config", &self.config)
+            .field("entries", &self.entries.len())
+            .field("watching", &self.watcher.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for HotReloadRegistry {
+    fn default() -> Self {
+        Self::new(HotReloadConfig::default())
+    }
+}
+
+impl HotReloadRegistry {
+    /// Creates a registry, starting the watcher thread if enabled.
+    #[must_use]
+    pub fn new(config: HotReloadConfig) -> Self {
+        let watched: WatchedPaths = Arc::default();
+        let stop = Arc::new(AtomicBool::new(false));
+        let (sender, changes) = mpsc::channel();
+
+        let watcher = if config.enabled {
+            Self::spawn_watcher(Arc::clone(&watched), Arc::clone(&stop), sender.clone())
+        } else {
+            None
+        };
+
+        Self {
+            config,
+            entries: Vec::new(),
+            watched,
+            changes,
+            sender,
+            stop,
+            watcher,
+            next_id: 1,
+        }
+    }
+
+    /// Starts the thread that checks watched paths for changes.
+    fn spawn_watcher(
+        watched: WatchedPaths,
+        stop: Arc<AtomicBool>,
+        sender: mpsc::Sender<PathBuf>,
+    ) -> Option<JoinHandle<()>> {
+        std::thread::Builder::new()
+            .name("hot-reload".to_string())
+            .spawn(move || {
+                while !stop.load(Ordering::Relaxed) && scan_paths(&watched, &sender) {
+                    std::thread::sleep(WATCH_INTERVAL);
+                }
+            })
+            .ok()
+    }
+
+    /// Returns the configuration.
+    #[must_use]
+    pub fn config(&self) -> &HotReloadConfig {
+        &self.config
+    }
+
+    /// Returns true if the watcher thread is running.
+    #[must_use]
+    pub fn is_watching(&self) -> bool {
+        self.watcher.is_some()
+    }
+
+    /// Registers a file or directory and the callback to run when it
+    /// changes. Directories report changes to any entry directly inside.
+    pub fn register<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        callback: ChangeCallback,
+    ) -> ResourceHandle {
+        let path = path.as_ref().to_path_buf();
+        let handle = ResourceHandle::new(self.next_id);
+        self.next_id += 1;
+
+        let stamp = PathStamp::read(&path);
+        if let Ok(mut watched) = self.watched.lock() {
+            watched.entry(path.clone()).or_insert(stamp);
+        }
+
+        self.entries.push(WatchEntry {
+            handle,
+            path,
+            callback,
+            pending_since: None,
+        });
+        handle
+    }
+
+    /// Unregisters a callback, stopping the watch once no callbacks
+    /// remain for its path.
+    pub fn unregister(&mut self, handle: ResourceHandle) {
+        let Some(index) = self.entries.iter().position(|e| e.handle == handle) else {
+            return;
+        };
+        let entry = self.entries.remove(index);
+
+        if !self.entries.iter().any(|e| e.path == entry.path) {
+            if let Ok(mut watched) = self.watched.lock() {
+                watched.remove(&entry.path);
+            }
+        }
+    }
+
+    /// Returns the number of registered callbacks.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if nothing is registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Checks the watched paths on the calling thread instead of waiting
+    /// for the watcher. Changes are picked up by the next poll.
+    pub fn check_now(&self) {
+        scan_paths(&self.watched, &self.sender);
+    }
+
+    /// Collects changes from the watcher and runs the callbacks of paths
+    /// that have been quiet for the debounce duration.
+    ///
+    /// Returns the number of callbacks invoked.
+    pub fn poll(&mut self) -> usize {
+        self.poll_at(Instant::now())
+    }
+
+    /// Like [`Self::poll`], treating `now` as the current time.
+    pub fn poll_at(&mut self, now: Instant) -> usize {
+        while let Ok(path) = self.changes.try_recv() {
+            for entry in self.entries.iter_mut().filter(|e| e.path == path) {
+                entry.pending_since = Some(now);
+            }
+        }
+
+        let mut invoked = 0;
+        for entry in &mut self.entries {
+            let settled = entry
+                .pending_since
+                .is_some_and(|since| now.duration_since(since) >= self.config.debounce);
+            if settled {
+                entry.pending_since = None;
+                (entry.callback)();
+                invoked += 1;
+            }
+        }
+        invoked
+    }
+}
+
+impl Drop for HotReloadRegistry {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(watcher) = self.watcher.take() {
+            let _ = watcher.join();
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
     use tempfile::tempdir;
 
+    /// Sets a file's modification time `secs` into the future, so the
+    /// change is visible regardless of timestamp resolution.
+    fn touch(path: &Path, secs: u64) {
+        std::fs::File::options()
+            .write(true)
+            .open(path)
+            .expect("open")
+            .set_modified(SystemTime::now() + Duration::from_secs(secs))
+            .expect("set mtime");
+    }
+
+    /// A registry without a watcher thread, checked with
+    /// [`HotReloadRegistry::check_now`].
+    fn manual_registry(debounce: Duration) -> HotReloadRegistry {
+        HotReloadRegistry::new(HotReloadConfig {
+            enabled: false,
+            debounce,
+            ..Default::default()
+        })
+    }
+
+    fn counting_callback(count: &Rc<Cell<u32>>) -> ChangeCallback {
+        let count = Rc::clone(count);
+        Box::new(move || count.set(count.get() + 1))
+    }
+
     #[test]
     fn test_asset_type_extensions() {
         assert!(AssetType::Material.extensions().contains(&"ron"));
@@ -930,4 +1244,89 @@ mod tests {
         let pending = reloader.take_pending_changes();
         assert!(pending.is_empty());
     }
+
+    #[test]
+    fn test_registry_touch_fires_once_after_debounce() {
+        let dir = tempdir().expect("tempdir");
+        let path = dir.path().join("recipes.toml");
+        std::fs::write(&path, "a").expect("write");
+
+        let debounce = Duration::from_millis(100);
+        let mut registry = manual_registry(debounce);
+        let count = Rc::new(Cell::new(0));
+        registry.register(&path, counting_callback(&count));
+        let start = Instant::now();
+        registry.check_now();
+        assert_eq!(registry.poll_at(start), 0);
+
+        touch(&path, 10);
+        registry.check_now();
+        assert_eq!(registry.poll_at(start), 0);
+        assert_eq!(registry.poll_at(start + debounce / 2), 0);
+        assert_eq!(registry.poll_at(start + debounce), 1);
+        assert_eq!(count.get(), 1);
+
+        // No repeat once handled
+        registry.check_now();
+        assert_eq!(registry.poll_at(start + debounce * 3), 0);
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn test_registry_burst_is_debounced() {
+        let dir = tempdir().expect("tempdir");
+        let path = dir.path().join("weapons.toml");
+        std::fs::write(&path, "a").expect("write");
+
+        let mut registry = manual_registry(Duration::from_millis(150));
+        let count = Rc::new(Cell::new(0));
+        let other = Rc::new(Cell::new(0));
+        registry.register(&path, counting_callback(&count));
+        registry.register(dir.path().join("other.toml"), counting_callback(&other));
+
+        let start = Instant::now();
+        for step in 0..3 {
+            touch(&path, (step + 1) * 10);
+            registry.check_now();
+            let now = start + Duration::from_millis(60 * step);
+            assert_eq!(registry.poll_at(now), 0);
+        }
+        // Quiet for 150ms after the last write at 120ms
+        assert_eq!(registry.poll_at(start + Duration::from_millis(200)), 0);
+        assert_eq!(registry.poll_at(start + Duration::from_millis(270)), 1);
+
+        assert_eq!(count.get(), 1);
+        assert_eq!(other.get(), 0);
+    }
+
+    #[test]
+    fn test_registry_watches_directories() {
+        let dir = tempdir().expect("tempdir");
+        let debounce = Duration::from_millis(20);
+        let mut registry = manual_registry(debounce);
+        let count = Rc::new(Cell::new(0));
+        let handle = registry.register(dir.path(), counting_callback(&count));
+
+        let start = Instant::now();
+        std::fs::write(dir.path().join("new.toml"), "a").expect("write");
+        registry.check_now();
+        assert_eq!(registry.poll_at(start), 0);
+        assert_eq!(registry.poll_at(start + debounce), 1);
+
+        registry.unregister(handle);
+        assert!(registry.is_empty());
+        std::fs::write(dir.path().join("another.toml"), "a").expect("write");
+        registry.check_now();
+        assert_eq!(registry.poll_at(start + debounce * 10), 0);
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn test_registry_disabled_does_not_watch() {
+        let registry = HotReloadRegistry::new(HotReloadConfig {
+            enabled: false,
+            ..Default::default()
+        });
+        assert!(!registry.is_watching());
+    }
 }
@@ -0,0 +1,579 @@
+//! Entity inspector.
+//!
+//! Lists every gameplay entity (the player, NPCs, vehicles and other world
+//! entities) in a browser window. Selecting a row shows the entity's
+//! position, health and faction, and edits are written straight back to
+//! the [`GameState`].
+
+use crate::ui::{ConstrainedWindow, ScreenConstraints};
+use egui::{Color32, Context, Id, RichText, Ui};
+use genesis_common::{EntityId, FactionId, WorldCoord};
+use genesis_gameplay::entity::EntityType;
+use genesis_gameplay::input::Vec2;
+use genesis_gameplay::GameState;
+
+/// Where an inspected entity lives in the game state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EntityRef {
+    /// The player character.
+    Player,
+    /// An NPC owned by the NPC manager.
+    Npc(EntityId),
+    /// An entity in the world entity arena (vehicles, drops, buildings).
+    World(EntityId),
+}
+
+/// A row in the entity browser.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntityRow {
+    /// Entity the row refers to.
+    pub entity: EntityRef,
+    /// Display label.
+    pub label: String,
+    /// Entity kind (Player, NPC type, or world entity type).
+    pub kind: String,
+    /// Position in world units.
+    pub position: (f32, f32),
+}
+
+/// Editable fields of an entity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EntityFields {
+    /// Position in world units.
+    pub position: (f32, f32),
+    /// Current and maximum health, if the entity has health.
+    pub health: Option<(i32, i32)>,
+    /// Faction allegiance.
+    pub faction: Option<FactionId>,
+    /// Whether the faction can be changed.
+    pub faction_editable: bool,
+}
+
+/// A change to one of an entity's fields.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EntityEdit {
+    /// Move the entity.
+    Position(f32, f32),
+    /// Set current health (clamped to the maximum).
+    Health(i32),
+    /// Change or clear the faction.
+    Faction(Option<FactionId>),
+}
+
+/// Configuration for the inspector window.
+#[derive(Debug, Clone)]
+pub struct InspectorConfig {
+    /// Font size.
+    pub font_size: f32,
+    /// Window width.
+    pub panel_width: f32,
+    /// Window height.
+    pub panel_height: f32,
+}
+
+impl Default for InspectorConfig {
+    fn default() -> Self {
+        Self {
+            font_size: 12.0,
+            panel_width: 340.0,
+            panel_height: 480.0,
+        }
+    }
+}
+
+/// Entity browser with field editing.
+#[derive(Debug, Default)]
+pub struct Inspector {
+    /// Configuration.
+    config: InspectorConfig,
+    /// Whether the window is visible.
+    visible: bool,
+    /// Selected entity.
+    selected: Option<EntityRef>,
+    /// Search filter for labels and kinds.
+    filter: String,
+    /// Last picked simulation cell as `(x, y, cell)`.
+    picked_cell: Option<(i32, i32, u32)>,
+}
+
+impl Inspector {
+    /// Creates a new inspector.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates with custom configuration.
+    #[must_use]
+    pub fn with_config(config: InspectorConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    /// Toggles visibility.
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+
+    /// Returns whether the window is visible.
+    #[must_use]
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Sets visibility.
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    /// Returns the selected entity.
+    #[must_use]
+    pub fn selected(&self) -> Option<EntityRef> {
+        self.selected
+    }
+
+    /// Selects an entity (or clears the selection).
+    pub fn select(&mut self, entity: Option<EntityRef>) {
+        self.selected = entity;
+    }
+
+    /// Returns the last picked simulation cell.
+    #[must_use]
+    pub fn picked_cell(&self) -> Option<(i32, i32, u32)> {
+        self.picked_cell
+    }
+
+    /// Sets the picked simulation cell (`None` when the click missed).
+    pub fn set_picked_cell(&mut self, cell: Option<(i32, i32, u32)>) {
+        self.picked_cell = cell;
+    }
+
+    /// Returns the search filter.
+    #[must_use]
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    /// Sets the search filter.
+    pub fn set_filter(&mut self, filter: impl Into<String>) {
+        self.filter = filter.into();
+    }
+
+    /// Lists all entities: the player, then NPCs, then world entities,
+    /// each group ordered by ID.
+    #[must_use]
+    pub fn entity_rows(state: &GameState) -> Vec<EntityRow> {
+        let player = state.player.position();
+        let mut rows = vec![EntityRow {
+            entity: EntityRef::Player,
+            label: "Player".to_string(),
+            kind: "Player".to_string(),
+            position: (player.x, player.y),
+        }];
+
+        let mut npcs: Vec<_> = state.npc_manager.iter().collect();
+        npcs.sort_by_key(|(id, _)| id.raw());
+        rows.extend(npcs.into_iter().map(|(id, npc)| EntityRow {
+            entity: EntityRef::Npc(id),
+            label: format!("NPC #{}", id.raw()),
+            kind: format!("{:?}", npc.npc_type),
+            position: npc.position,
+        }));
+
+        let mut world: Vec<_> = state
+            .entities
+            .iter()
+            .filter(|e| e.entity_type() != EntityType::Player)
+            .collect();
+        world.sort_by_key(|e| e.id().raw());
+        rows.extend(world.into_iter().map(|e| EntityRow {
+            entity: EntityRef::World(e.id()),
+            label: format!("{:?} #{}", e.entity_type(), e.id().raw()),
+            kind: format!("{:?}", e.entity_type()),
+            position: world_to_f32(e.position()),
+        }));
+
+        rows
+    }
+
+    /// Reads an entity's editable fields, or `None` if it no longer exists.
+    #[must_use]
+    pub fn fields(state: &GameState, entity: EntityRef) -> Option<EntityFields> {
+        match entity {
+            EntityRef::Player => {
+                let position = state.player.position();
+                Some(EntityFields {
+                    position: (position.x, position.y),
+                    health: None,
+                    faction: Some(FactionId::PLAYER),
+                    faction_editable: false,
+                })
+            },
+            EntityRef::Npc(id) => state.npc_manager.get(id).map(|npc| EntityFields {
+                position: npc.position,
+                health: Some((npc.health.current(), npc.health.max())),
+                faction: npc.faction,
+                faction_editable: true,
+            }),
+            EntityRef::World(id) => state.entities.get(id).ok().map(|e| EntityFields {
+                position: world_to_f32(e.position()),
+                health: e.health().map(|h| (h.current(), h.max())),
+                faction: e.faction(),
+                faction_editable: true,
+            }),
+        }
+    }
+
+    /// Returns the fields of the selected entity.
+    #[must_use]
+    pub fn selected_fields(&self, state: &GameState) -> Option<EntityFields> {
+        self.selected.and_then(|entity| Self::fields(state, entity))
+    }
+
+    /// Writes an edit back to the game state.
+    ///
+    /// Returns false if the entity no longer exists or doesn't have the
+    /// edited field.
+    pub fn apply_edit(state: &mut GameState, entity: EntityRef, edit: EntityEdit) -> bool {
+        match entity {
+            EntityRef::Player => match edit {
+                EntityEdit::Position(x, y) => {
+                    state.player.set_position(Vec2::new(x, y));
+                    true
+                },
+                EntityEdit::Health(_) | EntityEdit::Faction(_) => false,
+            },
+            EntityRef::Npc(id) => {
+                let Some(npc) = state.npc_manager.get_mut(id) else {
+                    return false;
+                };
+                match edit {
+                    EntityEdit::Position(x, y) => npc.position = (x, y),
+                    EntityEdit::Health(current) => npc.health.set_current(current),
+                    EntityEdit::Faction(faction) => npc.faction = faction,
+                }
+                true
+            },
+            EntityRef::World(id) => {
+                let Ok(e) = state.entities.get_mut(id) else {
+                    return false;
+                };
+                match edit {
+                    EntityEdit::Position(x, y) => {
+                        e.set_position(WorldCoord::new(x.round() as i64, y.round() as i64));
+                    },
+                    EntityEdit::Health(current) => {
+                        let Some(health) = e.health_mut() else {
+                            return false;
+                        };
+                        health.set_current(current);
+                    },
+                    EntityEdit::Faction(faction) => e.set_faction(faction),
+                }
+                true
+            },
+        }
+    }
+
+    /// Renders the entity browser and applies any edits to `state`.
+    pub fn render_entities(&mut self, ctx: &Context, state: &mut GameState) {
+        if !self.visible {
+            return;
+        }
+
+        // Drop the selection if the entity was despawned
+        if let Some(entity) = self.selected {
+            if Self::fields(state, entity).is_none() {
+                self.selected = None;
+            }
+        }
+
+        let constraints = ScreenConstraints::from_context(ctx);
+        let mut visible = self.visible;
+
+        egui::Window::new("Entity Inspector")
+            .id(Id::new("entity_inspector"))
+            .open(&mut visible)
+            .with_constrained_defaults(
+                &constraints,
+                self.config.panel_width,
+                self.config.panel_height,
+            )
+            .resizable(true)
+            .collapsible(true)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Search:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.filter)
+                            .desired_width(140.0)
+                            .hint_text("Name/type..."),
+                    );
+                });
+                ui.separator();
+
+                self.show_picked_cell(ui);
+                ui.separator();
+
+                self.show_rows(ui, state);
+
+                ui.separator();
+                match self.selected {
+                    Some(entity) => Self::show_fields(ui, state, entity),
+                    None => {
+                        ui.label(RichText::new("Select an entity to edit it").color(Color32::GRAY));
+                    },
+                }
+            });
+
+        self.visible = visible;
+    }
+
+    /// Shows the cell picked with a click in the world.
+    fn show_picked_cell(&self, ui: &mut Ui) {
+        match self.picked_cell {
+            Some((x, y, cell)) => {
+                ui.label(format!("Cell ({x}, {y}): material {}", cell & 0xff));
+            },
+            None => {
+                ui.label(RichText::new("Click the world to pick a cell").color(Color32::GRAY));
+            },
+        }
+    }
+
+    /// Shows the selectable entity list.
+    fn show_rows(&mut self, ui: &mut Ui, state: &GameState) {
+        let filter = self.filter.to_lowercase();
+        let rows: Vec<_> = Self::entity_rows(state)
+            .into_iter()
+            .filter(|row| {
+                filter.is_empty()
+                    || row.label.to_lowercase().contains(&filter)
+                    || row.kind.to_lowercase().contains(&filter)
+            })
+            .collect();
+
+        egui::ScrollArea::vertical()
+            .id_salt("entity_inspector_rows")
+            .max_height(self.config.panel_height * 0.5)
+            .show(ui, |ui| {
+                for row in rows {
+                    let text = format!(
+                        "{}  [{}]  ({:.0}, {:.0})",
+                        row.label, row.kind, row.position.0, row.position.1
+                    );
+                    let selected = self.selected == Some(row.entity);
+                    if ui
+                        .selectable_label(selected, RichText::new(text).size(self.config.font_size))
+                        .clicked()
+                    {
+                        self.selected = Some(row.entity);
+                    }
+                }
+            });
+    }
+
+    /// Shows the editable fields of the selected entity.
+    fn show_fields(ui: &mut Ui, state: &mut GameState, entity: EntityRef) {
+        let Some(fields) = Self::fields(state, entity) else {
+            return;
+        };
+        let mut edits = Vec::new();
+
+        egui::Grid::new("entity_inspector_fields")
+            .num_columns(2)
+            .show(ui, |ui| {
+                let (mut x, mut y) = fields.position;
+                ui.label("Position");
+                ui.horizontal(|ui| {
+                    let moved_x = ui.add(egui::DragValue::new(&mut x).prefix("x: ")).changed();
+                    let moved_y = ui.add(egui::DragValue::new(&mut y).prefix("y: ")).changed();
+                    if moved_x || moved_y {
+                        edits.push(EntityEdit::Position(x, y));
+                    }
+                });
+                ui.end_row();
+
+                ui.label("Health");
+                match fields.health {
+                    Some((mut current, max)) => {
+                        let response = ui.add(
+                            egui::DragValue::new(&mut current)
+                                .range(0..=max)
+                                .suffix(format!(" / {max}")),
+                        );
+                        if response.changed() {
+                            edits.push(EntityEdit::Health(current));
+                        }
+                    },
+                    None => {
+                        ui.label(RichText::new("n/a").color(Color32::GRAY));
+                    },
+                }
+                ui.end_row();
+
+                ui.label("Faction");
+                if fields.faction_editable {
+                    ui.horizontal(|ui| {
+                        let mut has_faction = fields.faction.is_some();
+                        let mut raw = fields.faction.map_or(0, FactionId::raw);
+                        let toggled = ui.checkbox(&mut has_faction, "").changed();
+                        let changed = ui
+                            .add_enabled(has_faction, egui::DragValue::new(&mut raw))
+                            .changed();
+                        if toggled || changed {
+                            edits.push(EntityEdit::Faction(
+                                has_faction.then(|| FactionId::new(raw)),
+                            ));
+                        }
+                    });
+                } else {
+                    ui.label(
+                        fields
+                            .faction
+                            .map_or_else(|| "none".to_string(), |f| f.raw().to_string()),
+                    );
+                }
+                ui.end_row();
+            });
+
+        for edit in edits {
+            Self::apply_edit(state, entity, edit);
+        }
+    }
+}
+
+/// Converts an integer world coordinate to world units.
+#[allow(clippy::cast_precision_loss)]
+fn world_to_f32(coord: WorldCoord) -> (f32, f32) {
+    (coord.x as f32, coord.y as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use genesis_gameplay::entity::{Entity, Health};
+    use genesis_gameplay::npc::NPCType;
+
+    fn state_with_entities() -> (GameState, EntityId, EntityId) {
+        let mut state = GameState::with_player_position(1, (10.0, 20.0));
+        let npc = state.npc_manager.spawn_npc(NPCType::Guard, (5.0, 6.0));
+
+        let mut vehicle = Entity::new(EntityType::Vehicle);
+        vehicle.set_position(WorldCoord::new(100, 200));
+        let vehicle = state.entities.spawn_entity(vehicle);
+
+        (state, npc, vehicle)
+    }
+
+    #[test]
+    fn test_entity_rows_list_player_npcs_and_vehicles() {
+        let (state, npc, vehicle) = state_with_entities();
+        let rows = Inspector::entity_rows(&state);
+
+        let entities: Vec<_> = rows.iter().map(|r| r.entity).collect();
+        assert_eq!(
+            entities,
+            vec![
+                EntityRef::Player,
+                EntityRef::Npc(npc),
+                EntityRef::World(vehicle)
+            ]
+        );
+        assert_eq!(rows[1].kind, "Guard");
+        assert_eq!(rows[2].kind, "Vehicle");
+        assert_eq!(rows[2].position, (100.0, 200.0));
+    }
+
+    #[test]
+    fn test_selecting_entity_exposes_fields() {
+        let (state, npc, vehicle) = state_with_entities();
+        let mut inspector = Inspector::new();
+        assert!(inspector.selected_fields(&state).is_none());
+
+        inspector.select(Some(EntityRef::Npc(npc)));
+        let fields = inspector.selected_fields(&state).expect("npc fields");
+        assert_eq!(fields.position, (5.0, 6.0));
+        assert_eq!(fields.health, Some((100, 100)));
+        assert_eq!(fields.faction, None);
+        assert!(fields.faction_editable);
+
+        inspector.select(Some(EntityRef::Player));
+        let fields = inspector.selected_fields(&state).expect("player fields");
+        assert_eq!(fields.position, (10.0, 20.0));
+        assert_eq!(fields.faction, Some(FactionId::PLAYER));
+        assert!(!fields.faction_editable);
+
+        inspector.select(Some(EntityRef::World(vehicle)));
+        let fields = inspector.selected_fields(&state).expect("vehicle fields");
+        assert_eq!(fields.position, (100.0, 200.0));
+    }
+
+    #[test]
+    fn test_edits_mutate_underlying_entity() {
+        let (mut state, npc, vehicle) = state_with_entities();
+        let faction = Some(FactionId::new(7));
+
+        let npc_ref = EntityRef::Npc(npc);
+        assert!(Inspector::apply_edit(
+            &mut state,
+            npc_ref,
+            EntityEdit::Position(1.0, 2.0)
+        ));
+        assert!(Inspector::apply_edit(
+            &mut state,
+            npc_ref,
+            EntityEdit::Health(40)
+        ));
+        assert!(Inspector::apply_edit(
+            &mut state,
+            npc_ref,
+            EntityEdit::Faction(faction)
+        ));
+        let npc_state = state.npc_manager.get(npc).expect("npc");
+        assert_eq!(npc_state.position, (1.0, 2.0));
+        assert_eq!(npc_state.health.current(), 40);
+        assert_eq!(npc_state.faction, faction);
+
+        let vehicle_ref = EntityRef::World(vehicle);
+        assert!(Inspector::apply_edit(
+            &mut state,
+            vehicle_ref,
+            EntityEdit::Position(-3.4, 8.6)
+        ));
+        assert!(Inspector::apply_edit(
+            &mut state,
+            vehicle_ref,
+            EntityEdit::Health(500)
+        ));
+        let entity = state.entities.get(vehicle).expect("vehicle");
+        assert_eq!(entity.position(), WorldCoord::new(-3, 9));
+        assert_eq!(entity.health().map(Health::current), Some(100));
+
+        assert!(Inspector::apply_edit(
+            &mut state,
+            EntityRef::Player,
+            EntityEdit::Position(0.0, 0.0)
+        ));
+        assert_eq!(state.player_position(), (0.0, 0.0));
+        assert!(!Inspector::apply_edit(
+            &mut state,
+            EntityRef::Player,
+            EntityEdit::Health(1)
+        ));
+    }
+
+    #[test]
+    fn test_edit_missing_entity_fails() {
+        let (mut state, npc, _) = state_with_entities();
+        state.npc_manager.despawn_npc(npc).expect("despawn");
+        assert!(Inspector::fields(&state, EntityRef::Npc(npc)).is_none());
+        assert!(!Inspector::apply_edit(
+            &mut state,
+            EntityRef::Npc(npc),
+            EntityEdit::Health(1)
+        ));
+    }
+}
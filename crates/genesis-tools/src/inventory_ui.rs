@@ -5,7 +5,7 @@
 //! - Hotbar always visible at bottom
 //! - Drag and drop between slots
 //! - Right-click context menu
-//! - Tooltip on hover
+//! - Tooltip on hover, with durability and weight from item metadata
 //! - Item count overlay
 
 use egui::{
@@ -13,6 +13,9 @@ use egui::{
     Vec2, Window,
 };
 use genesis_common::ItemTypeId;
+use genesis_gameplay::inventory::Inventory;
+use genesis_gameplay::inventory_ui::ItemMetadata;
+use genesis_kernel::item_stack;
 use serde::{Deserialize, Serialize};
 
 /// Default slot size in pixels.
@@ -89,6 +92,28 @@ impl SlotUIData {
     pub fn is_empty(&self) -> bool {
         self.item_type.is_none() || self.count == 0
     }
+
+    /// Returns whether two slots hold the same item type.
+    #[must_use]
+    pub fn stacks_with(&self, other: &Self) -> bool {
+        !self.is_empty() && !other.is_empty() && self.item_type == other.item_type
+    }
+
+    /// Takes the item from `other` with the given count, keeping this
+    /// slot's index and selection state.
+    fn set_item_from(&mut self, other: &Self, count: u32) {
+        self.item_type = other.item_type;
+        self.item_name.clone_from(&other.item_name);
+        self.count = count;
+        self.max_stack = other.max_stack;
+        self.icon_color = other.icon_color;
+    }
+
+    /// Removes the item, keeping this slot's index and selection state.
+    fn clear_item(&mut self) {
+        let empty = Self::empty(self.slot_index);
+        self.set_item_from(&empty, 0);
+    }
 }
 
 /// Tooltip data for an item.
@@ -102,6 +127,12 @@ pub struct TooltipData {
     pub stats: Vec<(String, String)>,
     /// Rarity color
     pub rarity_color: [u8; 4],
+    /// Current and maximum durability, for items that wear out
+    pub durability: Option<(u16, u16)>,
+    /// Total weight of the stack
+    pub weight: Option<f32>,
+    /// Whether the stack carries custom data (enchantments, tags)
+    pub has_custom_data: bool,
 }
 
 impl TooltipData {
@@ -116,8 +147,75 @@ impl TooltipData {
                 format!("{}/{}", slot.count, slot.max_stack),
             )],
             rarity_color: [255, 255, 255, 255],
+            durability: None,
+            weight: None,
+            has_custom_data: false,
         }
     }
+
+    /// Creates tooltip data for a stack from its item metadata.
+    ///
+    /// Durability is only shown for items that wear out, and weight only for
+    /// items the metadata gives one.
+    #[must_use]
+    pub fn from_stack<M: ItemMetadata>(stack: &item_stack::ItemStack, metadata: &M) -> Self {
+        let item = ItemTypeId::new(stack.item_id());
+        let mut stats = metadata.get_stats(item);
+        stats.push(("Count".to_string(), stack.count().to_string()));
+        let durability = (stack.has_durability() && !stack.is_infinite_durability())
+            .then(|| (stack.durability(), stack.max_durability()));
+        Self {
+            name: metadata.get_name(item),
+            description: metadata.get_description(item),
+            stats,
+            rarity_color: [255, 255, 255, 255],
+            durability,
+            weight: metadata
+                .get_weight(item)
+                .map(|weight| weight * stack.count() as f32),
+            has_custom_data: !stack.metadata().is_empty(),
+        }
+    }
+
+    /// Remaining durability as a fraction (0.0-1.0), if the item wears out.
+    #[must_use]
+    pub fn durability_fraction(&self) -> Option<f32> {
+        self.durability.map(|(current, max)| {
+            if max == 0 {
+                1.0
+            } else {
+                f32::from(current) / f32::from(max)
+            }
+        })
+    }
+}
+
+/// Renders the optional tooltip lines: durability bar, weight and custom
+/// data.
+fn render_tooltip_details(ui: &mut Ui, tooltip: &TooltipData) {
+    if let (Some((current, max)), Some(fraction)) =
+        (tooltip.durability, tooltip.durability_fraction())
+    {
+        let color = if fraction > 0.5 {
+            Color32::from_rgb(80, 200, 80)
+        } else if fraction > 0.25 {
+            Color32::from_rgb(220, 200, 60)
+        } else {
+            Color32::from_rgb(220, 70, 60)
+        };
+        ui.add(
+            egui::ProgressBar::new(fraction)
+                .desired_width(140.0)
+                .fill(color)
+                .text(format!("{current}/{max}")),
+        );
+    }
+    if let Some(weight) = tooltip.weight {
+        ui.label(RichText::new(format!("Weight: {weight:.1}")).weak());
+    }
+    if tooltip.has_custom_data {
+        ui.label(RichText::new("Custom data").italics().weak());
+    }
 }
 
 /// Inventory UI model containing all display data.
@@ -156,6 +254,150 @@ impl InventoryUIModel {
             HOTBAR_SLOTS,
         )
     }
+
+    /// Mirrors a gameplay inventory: slot `i` shows the inventory's slot `i`
+    /// and the hotbar shows its leading slots. `name_of` labels item types.
+    pub fn sync_from_inventory(
+        &mut self,
+        inventory: &Inventory,
+        name_of: impl Fn(ItemTypeId) -> String,
+    ) {
+        let capacity = inventory.capacity() as usize;
+        self.slots.truncate(capacity);
+        let len = self.slots.len();
+        self.slots.extend((len..capacity).map(SlotUIData::empty));
+        for slot in &mut self.slots {
+            match inventory.slot(slot.slot_index) {
+                Some(stack) if slot.item_type == Some(stack.item_type) => {
+                    slot.count = stack.quantity;
+                },
+                Some(stack) => {
+                    let item = SlotUIData::with_item(
+                        slot.slot_index,
+                        stack.item_type,
+                        name_of(stack.item_type),
+                        stack.quantity,
+                    );
+                    slot.set_item_from(&item, stack.quantity);
+                },
+                None => slot.clear_item(),
+            }
+        }
+        for (index, hotbar) in self.hotbar.iter_mut().enumerate() {
+            match self.slots.get(index) {
+                Some(slot) => hotbar.set_item_from(slot, slot.count),
+                None => hotbar.clear_item(),
+            }
+        }
+        self.selected_hotbar = inventory.selected_hotbar();
+    }
+
+    /// Adds items, topping up matching stacks before filling empty slots.
+    ///
+    /// Returns the number of items that didn't fit.
+    pub fn add_item(&mut self, item_type: ItemTypeId, name: &str, mut count: u32) -> u32 {
+        for slot in &mut self.slots {
+            if count == 0 {
+                break;
+            }
+            if !slot.is_empty() && slot.item_type == Some(item_type) {
+                let added = count.min(slot.max_stack.saturating_sub(slot.count));
+                slot.count += added;
+                count -= added;
+            }
+        }
+        for slot in &mut self.slots {
+            if count == 0 {
+                break;
+            }
+            if slot.is_empty() {
+                let item = SlotUIData::with_item(slot.slot_index, item_type, name, 0);
+                let added = count.min(item.max_stack);
+                slot.set_item_from(&item, added);
+                count -= added;
+            }
+        }
+        count
+    }
+
+    /// Moves up to `count` items from one slot onto another.
+    ///
+    /// Dropping onto an empty slot moves the items, onto the same item type
+    /// merges as much as the target stack can hold, and onto a different
+    /// item swaps the two stacks (only when the whole source stack moves).
+    pub fn move_stack(&mut self, from: usize, to: usize, count: u32) -> DropOutcome {
+        if from == to || from >= self.slots.len() || to >= self.slots.len() {
+            return DropOutcome::Rejected;
+        }
+        let source = self.slots[from].clone();
+        let count = count.min(source.count);
+        if source.is_empty() || count == 0 {
+            return DropOutcome::Rejected;
+        }
+
+        let target = &mut self.slots[to];
+        let outcome = if target.is_empty() {
+            target.set_item_from(&source, count);
+            DropOutcome::Moved(count)
+        } else if target.stacks_with(&source) {
+            let moved = count.min(target.max_stack.saturating_sub(target.count));
+            if moved == 0 {
+                return DropOutcome::Rejected;
+            }
+            target.count += moved;
+            DropOutcome::Merged(moved)
+        } else if count == source.count {
+            let displaced = target.clone();
+            target.set_item_from(&source, source.count);
+            self.slots[from].set_item_from(&displaced, displaced.count);
+            return DropOutcome::Swapped;
+        } else {
+            return DropOutcome::Rejected;
+        };
+
+        let moved = outcome.moved();
+        let source = &mut self.slots[from];
+        source.count -= moved;
+        if source.count == 0 {
+            source.clear_item();
+        }
+        outcome
+    }
+}
+
+/// Result of dropping a stack onto a slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropOutcome {
+    /// Items moved into an empty slot
+    Moved(u32),
+    /// Items merged into a stack of the same type
+    Merged(u32),
+    /// The two stacks swapped places
+    Swapped,
+    /// Nothing changed (same slot, full stack, or a partial stack onto a
+    /// different item)
+    Rejected,
+}
+
+impl DropOutcome {
+    /// Returns the number of items that left the source slot for a move
+    /// or merge.
+    #[must_use]
+    pub fn moved(self) -> u32 {
+        match self {
+            Self::Moved(count) | Self::Merged(count) => count,
+            Self::Swapped | Self::Rejected => 0,
+        }
+    }
+}
+
+/// Items picked up from a slot and following the cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeldStack {
+    /// Slot the items came from
+    pub from: usize,
+    /// Number of items held
+    pub count: u32,
 }
 
 /// Actions that can be performed on the inventory.
@@ -605,6 +847,8 @@ impl InventoryPanel {
                     });
                 }
             }
+
+            render_tooltip_details(ui, tooltip);
         });
     }
 
@@ -703,8 +947,8 @@ pub struct InventoryUI {
     pub config: InventoryUIConfig,
     /// Context menu state
     context_menu_slot: Option<usize>,
-    /// Drag source slot
-    drag_source: Option<usize>,
+    /// Stack currently picked up by drag or right-click
+    held: Option<HeldStack>,
 }
 
 impl Default for InventoryUI {
@@ -721,7 +965,7 @@ impl InventoryUI {
             is_open: false,
             config: InventoryUIConfig::default(),
             context_menu_slot: None,
-            drag_source: None,
+            held: None,
         }
     }
 
@@ -732,7 +976,7 @@ impl InventoryUI {
             is_open: false,
             config,
             context_menu_slot: None,
-            drag_source: None,
+            held: None,
         }
     }
 
@@ -754,6 +998,13 @@ impl InventoryUI {
                 self.render_inventory_grid(ui, model, &mut actions);
             });
 
+        // Ghost of the held stack follows the cursor
+        if let Some(held) = self.held {
+            if let Some(slot) = model.slots.get(held.from) {
+                self.render_ghost(ctx, slot, held.count);
+            }
+        }
+
         // Handle context menu
         if let Some(slot_idx) = self.context_menu_slot {
             let menu_id = Id::new("inventory_context_menu");
@@ -847,34 +1098,28 @@ impl InventoryUI {
         egui::Grid::new("inventory_grid")
             .spacing(Vec2::splat(self.config.slot_padding))
             .show(ui, |ui| {
-                for (idx, slot) in model.slots.iter().enumerate() {
-                    let response = self.render_slot(ui, slot);
+                for idx in 0..model.slots.len() {
+                    let response = self.render_slot(ui, &model.slots[idx]);
+                    let was_holding = self.held.is_some();
 
                     // Handle interactions
-                    if response.clicked() {
+                    if response.clicked() && !was_holding {
                         actions.push(InventoryAction::Select(idx));
                     }
 
-                    if response.secondary_clicked() {
+                    // Right-click splits stacks; single items get the context menu
+                    if response.secondary_clicked() && model.slots[idx].count <= 1 {
                         self.context_menu_slot = Some(idx);
                     }
 
                     // Drag and drop
-                    if response.drag_started() {
-                        self.drag_source = Some(idx);
-                        model.dragging = Some(idx);
-                    }
-
-                    if response.drag_stopped() {
-                        if let Some(_from) = self.drag_source.take() {
-                            model.dragging = None;
-                            // Find drop target (simplified - would need proper hit testing)
-                            // For now, just clear drag state
-                        }
+                    if let Some(action) = self.handle_drag(model, idx, &response) {
+                        actions.push(action);
                     }
 
                     // Tooltip on hover
-                    if response.hovered() && !slot.is_empty() {
+                    let slot = &model.slots[idx];
+                    if response.hovered() && !was_holding && !slot.is_empty() {
                         let tooltip = TooltipData::from_slot(slot);
                         response.on_hover_ui(|ui| {
                             self.render_tooltip(ui, &tooltip);
@@ -887,6 +1132,117 @@ impl InventoryUI {
                     }
                 }
             });
+
+        // Releasing a drag outside any slot puts the stack back
+        if ui.input(|i| i.pointer.primary_released()) {
+            self.cancel_drag(model);
+        }
+    }
+
+    /// Handles drag-and-drop for one rendered slot.
+    ///
+    /// Dragging picks up the whole stack and right-clicking picks up half of
+    /// it; releasing or clicking over another slot drops the held items
+    /// there. The move is applied to `inventory` directly and reported as
+    /// [`InventoryAction::Move`].
+    pub fn handle_drag(
+        &mut self,
+        inventory: &mut InventoryUIModel,
+        slot: usize,
+        response: &Response,
+    ) -> Option<InventoryAction> {
+        if response.drag_started() {
+            self.pick_up(inventory, slot);
+        } else if response.secondary_clicked() {
+            self.pick_up_half(inventory, slot);
+        }
+
+        let released = response.ctx.input(|i| i.pointer.primary_released());
+        if released && self.held.is_some() && response.contains_pointer() {
+            let from = self.held.map(|held| held.from)?;
+            return match self.drop_held(inventory, slot) {
+                DropOutcome::Rejected => None,
+                _ => Some(InventoryAction::Move { from, to: slot }),
+            };
+        }
+        None
+    }
+
+    /// Picks up the whole stack in a slot.
+    pub fn pick_up(&mut self, inventory: &mut InventoryUIModel, slot: usize) {
+        if let Some(source) = inventory.slots.get(slot).filter(|s| !s.is_empty()) {
+            self.held = Some(HeldStack {
+                from: slot,
+                count: source.count,
+            });
+            inventory.dragging = Some(slot);
+        }
+    }
+
+    /// Picks up half of a stack (rounded down), leaving the rest in place.
+    pub fn pick_up_half(&mut self, inventory: &mut InventoryUIModel, slot: usize) {
+        if let Some(source) = inventory.slots.get(slot).filter(|s| s.count > 1) {
+            self.held = Some(HeldStack {
+                from: slot,
+                count: source.count / 2,
+            });
+            inventory.dragging = Some(slot);
+        }
+    }
+
+    /// Drops the held stack onto a slot.
+    pub fn drop_held(&mut self, inventory: &mut InventoryUIModel, to: usize) -> DropOutcome {
+        let Some(held) = self.held.take() else {
+            return DropOutcome::Rejected;
+        };
+        inventory.dragging = None;
+        inventory.move_stack(held.from, to, held.count)
+    }
+
+    /// Puts the held stack back without moving anything.
+    pub fn cancel_drag(&mut self, inventory: &mut InventoryUIModel) {
+        self.held = None;
+        inventory.dragging = None;
+    }
+
+    /// Returns the stack currently held, if any.
+    #[must_use]
+    pub fn held(&self) -> Option<HeldStack> {
+        self.held
+    }
+
+    /// Renders the held stack next to the cursor.
+    fn render_ghost(&self, ctx: &Context, slot: &SlotUIData, count: u32) {
+        let Some(pos) = ctx.pointer_hover_pos() else {
+            return;
+        };
+        egui::Area::new(Id::new("inventory_drag_ghost"))
+            .fixed_pos(pos + Vec2::new(8.0, 8.0))
+            .order(egui::Order::Tooltip)
+            .interactable(false)
+            .show(ctx, |ui| {
+                let size = Vec2::splat(self.config.slot_size * 0.75);
+                let (rect, _) = ui.allocate_exact_size(size, Sense::hover());
+
+                let painter = ui.painter();
+                let icon_color = Color32::from_rgba_unmultiplied(
+                    slot.icon_color[0],
+                    slot.icon_color[1],
+                    slot.icon_color[2],
+                    180,
+                );
+                painter.rect_filled(rect, Rounding::same(4.0), icon_color);
+
+                if count > 1 {
+                    painter.text(
+                        rect.right_bottom() - Vec2::new(2.0, 2.0),
+                        Align2::RIGHT_BOTTOM,
+                        count.to_string(),
+                        FontId::proportional(10.0),
+                        Color32::WHITE,
+                    );
+                }
+            });
     }
 
     /// Renders a single inventory slot.
@@ -995,9 +1351,22 @@ impl InventoryUI {
                     });
                 }
             }
+
+            // Durability, weight and custom data
+            render_tooltip_details(ui, tooltip);
         });
     }
 
+    /// Renders a tooltip for an item stack using its item metadata.
+    pub fn item_tooltip<M: ItemMetadata>(
+        &self,
+        ui: &mut Ui,
+        stack: &item_stack::ItemStack,
+        metadata: &M,
+    ) {
+        self.render_tooltip(ui, &TooltipData::from_stack(stack, metadata));
+    }
+
     /// Toggles the inventory open/closed.
     pub fn toggle(&mut self) {
         self.is_open = !self.is_open;
@@ -1012,12 +1381,14 @@ impl InventoryUI {
     pub fn close(&mut self) {
         self.is_open = false;
         self.context_menu_slot = None;
+        self.held = None;
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use genesis_gameplay::inventory_ui::SimpleItemMetadata;
 
     #[test]
     fn test_slot_ui_data_empty() {
@@ -1094,6 +1465,45 @@ mod tests {
         assert!(!tooltip.stats.is_empty());
     }
 
+    #[test]
+    fn test_tooltip_from_stack_metadata() {
+        let mut metadata = SimpleItemMetadata::new();
+        let pick = ItemTypeId::new(7);
+        metadata.register(pick, "Iron Pickaxe", "Breaks stone");
+        metadata.set_stats(pick, vec![("Mining".to_string(), "3".to_string())]);
+        metadata.set_weight(pick, 2.5);
+
+        let mut stack = item_stack::ItemStack::with_durability(7, 2, 200);
+        stack.damage(50);
+        let tooltip = TooltipData::from_stack(&stack, &metadata);
+        assert_eq!(tooltip.name, "Iron Pickaxe");
+        assert_eq!(tooltip.description, "Breaks stone");
+        assert_eq!(tooltip.stats[0], ("Mining".to_string(), "3".to_string()));
+        assert_eq!(tooltip.durability, Some((150, 200)));
+        let fraction = tooltip.durability_fraction().unwrap_or_default();
+        assert!((fraction - 0.75).abs() < f32::EPSILON);
+        assert!((tooltip.weight.unwrap_or_default() - 5.0).abs() < f32::EPSILON);
+        assert!(!tooltip.has_custom_data);
+    }
+
+    #[test]
+    fn test_tooltip_from_stack_without_optional_fields() {
+        let metadata = SimpleItemMetadata::new();
+        let mut stack = item_stack::ItemStack::new(3, 10);
+        stack.metadata_mut().set_tag(b"enchanted");
+        let tooltip = TooltipData::from_stack(&stack, &metadata);
+        assert_eq!(tooltip.name, "Item #3");
+        assert_eq!(tooltip.durability, None);
+        assert_eq!(tooltip.durability_fraction(), None);
+        assert_eq!(tooltip.weight, None);
+        assert!(tooltip.has_custom_data);
+
+        let unbreakable =
+            item_stack::ItemStack::with_durability(3, 1, item_stack::INFINITE_DURABILITY);
+        let tooltip = TooltipData::from_stack(&unbreakable, &metadata);
+        assert_eq!(tooltip.durability, None);
+    }
+
     #[test]
     fn test_inventory_action_equality() {
         assert_eq!(InventoryAction::Select(5), InventoryAction::Select(5));
@@ -1198,6 +1608,117 @@ mod tests {
         assert!(stack.is_none());
     }
 
+    fn drag_model() -> InventoryUIModel {
+        let mut model = InventoryUIModel::new(4, 0);
+        model.slots[0] = SlotUIData::with_item(0, ItemTypeId::new(1), "Stone", 10);
+        model.slots[1] = SlotUIData::with_item(1, ItemTypeId::new(1), "Stone", 995);
+        model.slots[2] = SlotUIData::with_item(2, ItemTypeId::new(2), "Wood", 3);
+        model
+    }
+
+    #[test]
+    fn test_move_stack_to_empty_slot() {
+        let mut model = drag_model();
+        assert_eq!(model.move_stack(0, 3, 10), DropOutcome::Moved(10));
+        assert!(model.slots[0].is_empty());
+        assert_eq!(model.slots[0].slot_index, 0);
+        assert_eq!(model.slots[3].item_type, Some(ItemTypeId::new(1)));
+        assert_eq!(model.slots[3].count, 10);
+        assert_eq!(model.slots[3].slot_index, 3);
+    }
+
+    #[test]
+    fn test_move_stack_merges_up_to_max() {
+        let mut model = drag_model();
+        // Only 4 more fit on top of 995
+        assert_eq!(model.move_stack(0, 1, 10), DropOutcome::Merged(4));
+        assert_eq!(model.slots[1].count, 999);
+        assert_eq!(model.slots[0].count, 6);
+
+        // Target full: nothing moves
+        assert_eq!(model.move_stack(0, 1, 6), DropOutcome::Rejected);
+        assert_eq!(model.slots[0].count, 6);
+    }
+
+    #[test]
+    fn test_move_stack_swaps_different_items() {
+        let mut model = drag_model();
+        assert_eq!(model.move_stack(0, 2, 10), DropOutcome::Swapped);
+        assert_eq!(model.slots[0].item_name, "Wood");
+        assert_eq!(model.slots[0].count, 3);
+        assert_eq!(model.slots[2].item_name, "Stone");
+        assert_eq!(model.slots[2].count, 10);
+
+        // A partial stack can't displace a different item
+        assert_eq!(model.move_stack(2, 0, 5), DropOutcome::Rejected);
+        assert_eq!(model.move_stack(1, 1, 5), DropOutcome::Rejected);
+    }
+
+    #[test]
+    fn test_add_item_merges_then_fills() {
+        let mut model = drag_model();
+        assert_eq!(model.add_item(ItemTypeId::new(1), "Stone", 990), 0);
+        assert_eq!(model.slots[0].count, 999);
+        assert_eq!(model.slots[1].count, 996);
+
+        assert_eq!(model.add_item(ItemTypeId::new(5), "Torch", 2), 0);
+        assert_eq!(model.slots[3].item_name, "Torch");
+        assert_eq!(model.slots[3].count, 2);
+
+        // No room left for a new item type
+        assert_eq!(model.add_item(ItemTypeId::new(6), "Rope", 4), 4);
+    }
+
+    #[test]
+    fn test_sync_from_inventory_mirrors_slots_and_hotbar() {
+        let mut inventory = Inventory::new(6).with_hotbar(2);
+        assert!(inventory.add(ItemTypeId::new(1), 10).is_ok());
+        assert!(inventory.add(ItemTypeId::new(2), 3).is_ok());
+        assert!(inventory.move_slot(0, 4).is_ok());
+        inventory.select_hotbar(1);
+
+        let mut model = InventoryUIModel::new(40, 2);
+        let name_of = |id: ItemTypeId| format!("Item #{}", id.raw());
+        model.sync_from_inventory(&inventory, name_of);
+        assert_eq!(model.slots.len(), 6);
+        assert!(model.slots[0].is_empty());
+        assert_eq!(model.slots[4].item_name, "Item #1");
+        assert_eq!(model.slots[4].count, 10);
+        assert_eq!(model.hotbar[1].item_type, Some(ItemTypeId::new(2)));
+        assert!(model.hotbar[0].is_empty());
+        assert_eq!(model.selected_hotbar, 1);
+
+        // A move made in the model but not the inventory is undone
+        assert_eq!(model.move_stack(4, 5, 5), DropOutcome::Moved(5));
+        assert!(inventory.remove(ItemTypeId::new(1), 4).is_ok());
+        model.sync_from_inventory(&inventory, name_of);
+        assert_eq!(model.slots[4].count, 6);
+        assert!(model.slots[5].is_empty());
+    }
+
+    #[test]
+    fn test_drag_split_and_cancel() {
+        let mut ui = InventoryUI::new();
+        let mut model = drag_model();
+
+        ui.pick_up_half(&mut model, 0);
+        assert_eq!(ui.held(), Some(HeldStack { from: 0, count: 5 }));
+        assert_eq!(model.dragging, Some(0));
+        assert_eq!(ui.drop_held(&mut model, 3), DropOutcome::Moved(5));
+        assert_eq!(model.slots[0].count, 5);
+        assert_eq!(model.slots[3].count, 5);
+        assert!(ui.held().is_none());
+        assert!(model.dragging.is_none());
+
+        // Cancelling leaves the slots untouched
+        ui.pick_up(&mut model, 2);
+        assert_eq!(ui.held(), Some(HeldStack { from: 2, count: 3 }));
+        ui.cancel_drag(&mut model);
+        assert!(ui.held().is_none());
+        assert_eq!(model.slots[2].count, 3);
+        assert_eq!(ui.drop_held(&mut model, 3), DropOutcome::Rejected);
+    }
+
     #[test]
     fn test_inventory_sort_mode_default() {
         assert_eq!(InventorySortMode::default(), InventorySortMode::None);
@@ -4,8 +4,10 @@
 //!
 //! This crate provides:
 //! - Replay/determinism harness
+//! - Chunk serialization fuzzing
 //! - Performance HUD
 //! - Event log viewer
+//! - Entity inspector
 //! - Asset management
 //! - Localization
 //! - UI components
@@ -26,6 +28,7 @@ pub mod event_log;
 pub mod game_hud;
 pub mod hot_reload;
 pub mod hotbar;
+pub mod inspector;
 pub mod inventory_ui;
 pub mod localization;
 pub mod memory_profiler;
@@ -34,6 +37,7 @@ pub mod perf;
 pub mod perf_hud;
 pub mod quest_ui;
 pub mod replay;
+pub mod test_harness;
 pub mod ui;
 
 /// Prelude for convenient imports
@@ -51,8 +55,9 @@ pub mod prelude {
     pub use crate::egui_integration::*;
     pub use crate::event_log::*;
     pub use crate::game_hud::*;
-    pub use crate::hot_reload::HotReloader;
+    pub use crate::hot_reload::{HotReloadRegistry, HotReloader};
     pub use crate::hotbar::*;
+    pub use crate::inspector::*;
     pub use crate::inventory_ui::*;
     pub use crate::localization::*;
     pub use crate::memory_profiler::*;
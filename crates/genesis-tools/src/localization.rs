@@ -1,6 +1,9 @@
 //! Localization System
 //!
 //! Multi-language support with fallback, interpolation, and plural forms.
+//!
+//! Locale files are TOML or JSON with the [`LocaleFile`] layout, one file per
+//! language. Keys missing from a language fall back to the fallback locale.
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -95,35 +98,61 @@ impl Localization {
     /// Load a locale from a file
     ///
     /// # Arguments
-    /// * `path` - Path to the locale JSON file
+    /// * `path` - Path to the locale file (`.toml`, otherwise parsed as JSON)
     pub fn load_locale(&mut self, path: impl AsRef<Path>) -> Result<(), LocaleError> {
+        let path = path.as_ref();
         let data = std::fs::read_to_string(path)?;
-        let locale_file: LocaleFile =
-            serde_json::from_str(&data).map_err(|e| LocaleError::ParseError(e.to_string()))?;
 
-        self.locale_names
-            .insert(locale_file.locale.clone(), locale_file.name.clone());
-        self.strings
-            .insert(locale_file.locale.clone(), locale_file.strings);
-        self.plurals
-            .insert(locale_file.locale.clone(), locale_file.plurals);
+        if path.extension().is_some_and(|ext| ext == "toml") {
+            self.load_locale_from_toml_str(&data)
+        } else {
+            self.load_locale_from_str(&data)
+        }
+    }
 
-        Ok(())
+    /// Load every `.toml` and `.json` locale file in a directory
+    ///
+    /// Returns the number of locales loaded.
+    pub fn load_locales_from_dir(&mut self, dir: impl AsRef<Path>) -> Result<usize, LocaleError> {
+        let mut paths: Vec<_> = std::fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| {
+                path.extension()
+                    .is_some_and(|ext| ext == "toml" || ext == "json")
+            })
+            .collect();
+        paths.sort();
+
+        for path in &paths {
+            self.load_locale(path)?;
+        }
+
+        Ok(paths.len())
     }
 
-    /// Load a locale from a string
+    /// Load a locale from a JSON string
     pub fn load_locale_from_str(&mut self, data: &str) -> Result<(), LocaleError> {
         let locale_file: LocaleFile =
             serde_json::from_str(data).map_err(|e| LocaleError::ParseError(e.to_string()))?;
+        self.insert_locale(locale_file);
+        Ok(())
+    }
+
+    /// Load a locale from a TOML string
+    pub fn load_locale_from_toml_str(&mut self, data: &str) -> Result<(), LocaleError> {
+        let locale_file: LocaleFile =
+            toml::from_str(data).map_err(|e| LocaleError::ParseError(e.to_string()))?;
+        self.insert_locale(locale_file);
+        Ok(())
+    }
 
+    /// Register the contents of a parsed locale file
+    fn insert_locale(&mut self, locale_file: LocaleFile) {
         self.locale_names
-            .insert(locale_file.locale.clone(), locale_file.name.clone());
+            .insert(locale_file.locale.clone(), locale_file.name);
         self.strings
             .insert(locale_file.locale.clone(), locale_file.strings);
-        self.plurals
-            .insert(locale_file.locale.clone(), locale_file.plurals);
-
-        Ok(())
+        self.plurals.insert(locale_file.locale, locale_file.plurals);
     }
 
     /// Set the current locale
@@ -155,13 +184,21 @@ impl Localization {
         self.locale_names.get(locale).map(String::as_str)
     }
 
-    /// Get a translation by key
+    /// Get a translation by key in the current locale
     ///
     /// Falls back to fallback locale if key not found in current locale.
     /// Returns the key itself if not found in any locale.
-    pub fn get<'a>(&'a self, key: &'a str) -> &'a str {
-        // Try current locale
-        if let Some(strings) = self.strings.get(&self.current_locale) {
+    pub fn get_current<'a>(&'a self, key: &'a str) -> &'a str {
+        self.get(key, &self.current_locale)
+    }
+
+    /// Get a translation by key for a specific locale
+    ///
+    /// Falls back to fallback locale if key not found in `locale`.
+    /// Returns the key itself if not found in any locale.
+    pub fn get<'a>(&'a self, key: &'a str, locale: &str) -> &'a str {
+        // Try requested locale
+        if let Some(strings) = self.strings.get(locale) {
             if let Some(value) = strings.get(key) {
                 return value;
             }
@@ -186,7 +223,7 @@ impl Localization {
     ///
     /// Placeholders in the format `{name}` will be replaced.
     pub fn get_formatted(&self, key: &str, args: &[(&str, &str)]) -> String {
-        let template = self.get(key);
+        let template = self.get_current(key);
         let mut result = template.to_string();
 
         for (name, value) in args {
@@ -274,7 +311,7 @@ impl Localization {
 #[macro_export]
 macro_rules! t {
     ($loc:expr, $key:literal) => {
-        $loc.get($key)
+        $loc.get_current($key)
     };
     ($loc:expr, $key:literal, $(($arg_name:expr, $arg_val:expr)),* $(,)?) => {
         $loc.get_formatted($key, &[$(($arg_name, $arg_val)),*])
@@ -329,7 +366,7 @@ mod tests {
     #[test]
     fn test_basic_translation() {
         let loc = create_test_localization();
-        assert_eq!(loc.get("greeting"), "Hello");
+        assert_eq!(loc.get_current("greeting"), "Hello");
     }
 
     #[test]
@@ -342,10 +379,10 @@ mod tests {
     #[test]
     fn test_language_switch() {
         let mut loc = create_test_localization();
-        assert_eq!(loc.get("greeting"), "Hello");
+        assert_eq!(loc.get_current("greeting"), "Hello");
 
         loc.set_locale("es").expect("set locale");
-        assert_eq!(loc.get("greeting"), "Hola");
+        assert_eq!(loc.get_current("greeting"), "Hola");
     }
 
     #[test]
@@ -354,7 +391,7 @@ mod tests {
         loc.set_locale("es").expect("set locale");
 
         // "nonexistent" doesn't exist, should return key
-        assert_eq!(loc.get("nonexistent"), "nonexistent");
+        assert_eq!(loc.get_current("nonexistent"), "nonexistent");
     }
 
     #[test]
@@ -379,6 +416,66 @@ mod tests {
         assert!(locales.contains(&"es"));
     }
 
+    const EN_TOML: &str = r#"
+locale = "en"
+name = "English"
+
+[strings]
+"npc.greeting" = "Hello, traveler."
+"npc.farewell" = "Safe travels."
+"menu.options" = "Options"
+"#;
+
+    const DE_TOML: &str = r#"
+locale = "de"
+name = "Deutsch"
+
+[strings]
+"npc.greeting" = "Hallo, Reisender."
+"#;
+
+    #[test]
+    fn test_get_locale() {
+        let loc = create_test_localization();
+        assert_eq!(loc.get("greeting", "en"), "Hello");
+        assert_eq!(loc.get("greeting", "es"), "Hola");
+        // Current locale is unchanged
+        assert_eq!(loc.get_locale(), "en");
+    }
+
+    #[test]
+    fn test_get_falls_back_to_default_locale() {
+        let mut loc = Localization::new("en");
+        loc.load_locale_from_toml_str(EN_TOML)
+            .expect("load en locale");
+        loc.load_locale_from_toml_str(DE_TOML)
+            .expect("load de locale");
+
+        assert_eq!(loc.get("npc.greeting", "de"), "Hallo, Reisender.");
+        // Missing in German, falls back to English
+        assert_eq!(loc.get("npc.farewell", "de"), "Safe travels.");
+        // Unknown locale also falls back
+        assert_eq!(loc.get("menu.options", "fr"), "Options");
+        // Missing everywhere returns the key
+        assert_eq!(loc.get("npc.unknown", "de"), "npc.unknown");
+    }
+
+    #[test]
+    fn test_load_toml_locales_from_dir() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        std::fs::write(dir.path().join("en.toml"), EN_TOML).expect("write en");
+        std::fs::write(dir.path().join("de.toml"), DE_TOML).expect("write de");
+        std::fs::write(dir.path().join("notes.txt"), "ignored").expect("write txt");
+
+        let mut loc = Localization::new("en");
+        let loaded = loc.load_locales_from_dir(dir.path()).expect("load dir");
+
+        assert_eq!(loaded, 2);
+        assert_eq!(loc.locale_name("de"), Some("Deutsch"));
+        assert_eq!(loc.get("npc.greeting", "en"), "Hello, traveler.");
+        assert_eq!(loc.get("npc.greeting", "de"), "Hallo, Reisender.");
+    }
+
     #[test]
     fn test_t_macro() {
         let loc = create_test_localization();
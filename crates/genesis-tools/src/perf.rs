@@ -25,6 +25,8 @@ pub struct PerfStats {
 pub struct PerfTracker {
     /// Frame time history
     frame_times: VecDeque<Duration>,
+    /// GPU time history, once GPU timing is reported
+    gpu_times: VecDeque<Duration>,
     /// Target FPS for calculations
     target_fps: u32,
     /// Maximum history length
@@ -41,6 +43,7 @@ impl PerfTracker {
     pub fn new(target_fps: u32) -> Self {
         Self {
             frame_times: VecDeque::with_capacity(120),
+            gpu_times: VecDeque::new(),
             target_fps,
             history_length: 120,
             frame_start: None,
@@ -56,15 +59,45 @@ impl PerfTracker {
     /// Marks the end of a frame.
     pub fn frame_end(&mut self) {
         if let Some(start) = self.frame_start.take() {
-            let duration = start.elapsed();
-            if self.frame_times.len() >= self.history_length {
-                self.frame_times.pop_front();
-            }
-            self.frame_times.push_back(duration);
-            self.frame_count += 1;
+            self.record_frame(start.elapsed());
         }
     }
 
+    /// Records a frame that took `duration`.
+    pub fn record_frame(&mut self, duration: Duration) {
+        if self.frame_times.len() >= self.history_length {
+            self.frame_times.pop_front();
+        }
+        self.frame_times.push_back(duration);
+        self.frame_count += 1;
+    }
+
+    /// Records the GPU time of the latest frame.
+    pub fn record_gpu_time(&mut self, duration: Duration) {
+        if self.gpu_times.len() >= self.history_length {
+            self.gpu_times.pop_front();
+        }
+        self.gpu_times.push_back(duration);
+    }
+
+    /// Returns the retained frame times in milliseconds, oldest first.
+    #[must_use]
+    pub fn frame_times_ms(&self) -> Vec<f64> {
+        self.frame_times
+            .iter()
+            .map(|d| d.as_secs_f64() * 1000.0)
+            .collect()
+    }
+
+    /// Returns the retained GPU times in milliseconds, oldest first.
+    #[must_use]
+    pub fn gpu_times_ms(&self) -> Vec<f64> {
+        self.gpu_times
+            .iter()
+            .map(|d| d.as_secs_f64() * 1000.0)
+            .collect()
+    }
+
     /// Returns the average FPS over recent frames.
     #[must_use]
     pub fn average_fps(&self) -> f64 {
@@ -99,6 +132,12 @@ impl PerfTracker {
             .map_or(0.0, |d| d.as_secs_f64() * 1000.0)
     }
 
+    /// Returns the frame time budget for the target FPS.
+    #[must_use]
+    pub fn target_frame_time(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / f64::from(self.target_fps.max(1)))
+    }
+
     /// Returns whether we're hitting target FPS.
     #[must_use]
     pub fn is_hitting_target(&self) -> bool {
@@ -117,7 +156,7 @@ impl PerfTracker {
         PerfStats {
             fps: self.average_fps(),
             frame_time_ms: self.average_frame_time_ms(),
-            gpu_time_ms: None,
+            gpu_time_ms: self.gpu_times.back().map(|d| d.as_secs_f64() * 1000.0),
             loaded_chunks: 0,
             entity_count: 0,
             memory_bytes: 0,
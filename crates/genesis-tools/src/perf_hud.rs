@@ -141,6 +141,63 @@ impl Default for FrameSample {
     }
 }
 
+/// Summary of a window of frame times, as drawn by [`PerfHud::render_graph`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FrameGraph {
+    /// Samples in milliseconds, oldest first
+    pub samples: Vec<f64>,
+    /// Frame budget in milliseconds; samples above it are spikes
+    pub budget_ms: f64,
+    /// Fastest sample
+    pub min_ms: f64,
+    /// Slowest sample
+    pub max_ms: f64,
+    /// Mean of all samples
+    pub avg_ms: f64,
+    /// Mean of the slowest 1% of samples (at least one)
+    pub one_percent_low_ms: f64,
+}
+
+impl FrameGraph {
+    /// Builds a graph from samples in milliseconds.
+    #[must_use]
+    pub fn new(samples: Vec<f64>, budget_ms: f64) -> Self {
+        if samples.is_empty() {
+            return Self {
+                budget_ms,
+                ..Self::default()
+            };
+        }
+
+        let mut sorted = samples.clone();
+        sorted.sort_by(f64::total_cmp);
+        let slowest = (sorted.len() / 100).max(1);
+        let one_percent_low_ms =
+            sorted[sorted.len() - slowest..].iter().sum::<f64>() / slowest as f64;
+
+        Self {
+            budget_ms,
+            min_ms: sorted[0],
+            max_ms: sorted[sorted.len() - 1],
+            avg_ms: samples.iter().sum::<f64>() / samples.len() as f64,
+            one_percent_low_ms,
+            samples,
+        }
+    }
+
+    /// Returns whether a sample is over budget.
+    #[must_use]
+    pub fn is_spike(&self, sample_ms: f64) -> bool {
+        sample_ms > self.budget_ms
+    }
+
+    /// Returns the number of samples over budget.
+    #[must_use]
+    pub fn spike_count(&self) -> usize {
+        self.samples.iter().filter(|&&s| self.is_spike(s)).count()
+    }
+}
+
 /// Performance HUD with egui rendering.
 #[derive(Debug)]
 pub struct PerfHud {
@@ -496,6 +553,111 @@ impl PerfHud {
         );
     }
 
+    /// Builds the frame time graph for a tracker's sample window.
+    #[must_use]
+    pub fn frame_graph(tracker: &PerfTracker) -> FrameGraph {
+        FrameGraph::new(
+            tracker.frame_times_ms(),
+            tracker.target_frame_time().as_secs_f64() * 1000.0,
+        )
+    }
+
+    /// Builds the GPU time graph, if the tracker has GPU samples.
+    #[must_use]
+    pub fn gpu_graph(tracker: &PerfTracker) -> Option<FrameGraph> {
+        let samples = tracker.gpu_times_ms();
+        (!samples.is_empty())
+            .then(|| FrameGraph::new(samples, tracker.target_frame_time().as_secs_f64() * 1000.0))
+    }
+
+    /// Renders a scrolling sparkline of the tracker's frame times.
+    ///
+    /// Segments over the frame budget are drawn red, with the average and
+    /// 1% low marked. GPU time is overlaid once the tracker has samples.
+    pub fn render_graph(&self, ui: &mut Ui, tracker: &PerfTracker) {
+        let frame = Self::frame_graph(tracker);
+        let gpu = Self::gpu_graph(tracker);
+        let height = self.config.graph_height;
+
+        let (rect, _response) = ui.allocate_exact_size(
+            Vec2::new(ui.available_width(), height),
+            egui::Sense::hover(),
+        );
+        let painter = ui.painter_at(rect);
+        painter.rect_filled(rect, 2.0, Color32::from_rgb(30, 30, 30));
+
+        // Scale to the slowest sample, keeping the budget line on screen
+        let gpu_max = gpu.as_ref().map_or(0.0, |g| g.max_ms);
+        let max_ms = (frame.max_ms.max(gpu_max).max(frame.budget_ms) * 1.2).max(1.0);
+        let to_y = |ms: f64| rect.max.y - (ms / max_ms * f64::from(height)) as f32;
+        let step = rect.width() / self.config.graph_samples.max(2).saturating_sub(1) as f32;
+        let to_x = |i: usize, len: usize| rect.max.x - (len.saturating_sub(1) - i) as f32 * step;
+
+        let marker = |ms: f64, color: Color32, label: &str| {
+            let y = to_y(ms);
+            painter.line_segment(
+                [
+                    egui::Pos2::new(rect.min.x, y),
+                    egui::Pos2::new(rect.max.x, y),
+                ],
+                egui::Stroke::new(1.0, color),
+            );
+            painter.text(
+                egui::Pos2::new(rect.min.x + 2.0, y - 1.0),
+                egui::Align2::LEFT_BOTTOM,
+                label,
+                egui::FontId::proportional(9.0),
+                color,
+            );
+        };
+        marker(frame.budget_ms, Color32::from_rgb(100, 100, 100), "budget");
+        if !frame.samples.is_empty() {
+            marker(
+                frame.avg_ms,
+                Color32::from_rgb(120, 180, 255),
+                &format!("avg {:.1}ms", frame.avg_ms),
+            );
+            marker(
+                frame.one_percent_low_ms,
+                Color32::from_rgb(255, 165, 0),
+                &format!("1% low {:.1}ms", frame.one_percent_low_ms),
+            );
+        }
+
+        let sparkline = |graph: &FrameGraph, color: Color32| {
+            let len = graph.samples.len();
+            for (i, pair) in graph.samples.windows(2).enumerate() {
+                let spike = graph.is_spike(pair[0]) || graph.is_spike(pair[1]);
+                painter.line_segment(
+                    [
+                        egui::Pos2::new(to_x(i, len), to_y(pair[0])),
+                        egui::Pos2::new(to_x(i + 1, len), to_y(pair[1])),
+                    ],
+                    egui::Stroke::new(
+                        1.5,
+                        if spike {
+                            Color32::from_rgb(255, 80, 80)
+                        } else {
+                            color
+                        },
+                    ),
+                );
+            }
+        };
+        sparkline(&frame, Color32::from_rgb(100, 255, 100));
+        if let Some(gpu) = &gpu {
+            sparkline(gpu, Color32::from_rgb(200, 120, 255));
+        }
+
+        painter.text(
+            egui::Pos2::new(rect.max.x - 2.0, rect.min.y + 2.0),
+            egui::Align2::RIGHT_TOP,
+            format!("{:.1}-{:.1}ms", frame.min_ms, frame.max_ms),
+            egui::FontId::proportional(9.0),
+            Color32::GRAY,
+        );
+    }
+
     /// Renders the simulation section.
     fn render_sim_section(&self, ui: &mut Ui) {
         ui.label(RichText::new("⚙ Simulation").size(12.0));
@@ -750,6 +912,54 @@ mod tests {
         assert!(elapsed >= 10.0);
     }
 
+    #[test]
+    fn test_frame_graph_matches_sample_window() {
+        let mut tracker = PerfTracker::new(60);
+        for ms in [10, 12, 14, 40, 16, 18, 11, 13] {
+            tracker.record_frame(Duration::from_millis(ms));
+        }
+
+        let graph = PerfHud::frame_graph(&tracker);
+        assert_eq!(graph.samples.len(), 8);
+        assert!((graph.min_ms - 10.0).abs() < 1e-9);
+        assert!((graph.max_ms - 40.0).abs() < 1e-9);
+        assert!((graph.avg_ms - 16.75).abs() < 1e-9);
+        assert!((graph.avg_ms - tracker.average_frame_time_ms()).abs() < 1e-9);
+        // Fewer than 100 samples: the 1% low is the single slowest frame
+        assert!((graph.one_percent_low_ms - 40.0).abs() < 1e-9);
+        // 18ms and 40ms exceed the 16.67ms budget
+        assert_eq!(graph.spike_count(), 2);
+        assert!(PerfHud::gpu_graph(&tracker).is_none());
+    }
+
+    #[test]
+    fn test_frame_graph_window_and_gpu() {
+        let mut tracker = PerfTracker::new(60);
+        for i in 0..200 {
+            tracker.record_frame(Duration::from_millis(if i < 100 { 50 } else { 10 + i % 2 }));
+            tracker.record_gpu_time(Duration::from_millis(5));
+        }
+
+        // Only the last 120 frames are kept
+        let graph = PerfHud::frame_graph(&tracker);
+        assert_eq!(graph.samples.len(), 120);
+        assert!((graph.max_ms - 50.0).abs() < 1e-9);
+        assert!((graph.min_ms - 10.0).abs() < 1e-9);
+        let expected_avg = (20.0 * 50.0 + 50.0 * 10.0 + 50.0 * 11.0) / 120.0;
+        assert!((graph.avg_ms - expected_avg).abs() < 1e-9);
+        assert!((graph.one_percent_low_ms - 50.0).abs() < 1e-9);
+
+        let gpu = PerfHud::gpu_graph(&tracker).expect("gpu samples recorded");
+        assert_eq!(gpu.samples.len(), 120);
+        assert!((gpu.avg_ms - 5.0).abs() < 1e-9);
+        assert_eq!(gpu.spike_count(), 0);
+        assert_eq!(tracker.stats().gpu_time_ms, Some(5.0));
+
+        let empty = FrameGraph::new(Vec::new(), 16.0);
+        assert!(empty.samples.is_empty());
+        assert_eq!(empty.spike_count(), 0);
+    }
+
     #[test]
     fn test_to_perf_stats() {
         let mut hud = PerfHud::new();
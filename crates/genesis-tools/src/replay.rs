@@ -6,18 +6,27 @@
 //! - Frame-by-frame state hashes for verification
 //! - Timing information for accurate playback
 //! - World seed for deterministic world generation
+//! - RNG checkpoints for catching random-number divergence
+//!
+//! Replay files are written through the shared save compression pipeline
+//! so they stay small enough to share.
 
+use genesis_common::save_compression;
 use serde::{Deserialize, Serialize};
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 use std::io::{Read, Write};
+use std::path::Path;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Magic bytes for replay file format.
 pub const REPLAY_MAGIC: &[u8; 4] = b"GRPL";
 
 /// Current replay format version.
-pub const REPLAY_VERSION: u32 = 1;
+///
+/// Version 1 stored a JSON payload; version 2 stores a compressed bincode
+/// payload. Both can be loaded.
+pub const REPLAY_VERSION: u32 = 2;
 
 /// Input action types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -156,6 +165,23 @@ impl StateHash {
     }
 }
 
+/// RNG state captured at a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RngCheckpoint {
+    /// Frame this checkpoint was captured at
+    pub frame: u64,
+    /// Generator state at the start of the frame
+    pub state: u64,
+}
+
+impl RngCheckpoint {
+    /// Creates a new RNG checkpoint.
+    #[must_use]
+    pub const fn new(frame: u64, state: u64) -> Self {
+        Self { frame, state }
+    }
+}
+
 /// Replay metadata.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReplayMetadata {
@@ -201,6 +227,9 @@ pub struct Replay {
     pub state_hashes: Vec<StateHash>,
     /// Hash capture interval (every N frames)
     pub hash_interval: u64,
+    /// RNG checkpoints for verification
+    #[serde(default)]
+    pub rng_checkpoints: Vec<RngCheckpoint>,
 }
 
 impl Replay {
@@ -246,6 +275,12 @@ impl Replay {
             .max_by_key(|h| h.frame)
     }
 
+    /// Finds the RNG checkpoint for a given frame.
+    #[must_use]
+    pub fn find_rng_checkpoint(&self, frame: u64) -> Option<&RngCheckpoint> {
+        self.rng_checkpoints.iter().find(|c| c.frame == frame)
+    }
+
     /// Serializes the replay to JSON bytes.
     ///
     /// # Errors
@@ -264,12 +299,12 @@ impl Replay {
         serde_json::from_slice(data).map_err(|e| ReplayError::Deserialization(e.to_string()))
     }
 
-    /// Saves the replay to a writer.
+    /// Writes the replay to a writer.
     ///
     /// # Errors
     ///
-    /// Returns an error if writing fails.
-    pub fn save<W: Write>(&self, mut writer: W) -> Result<(), ReplayError> {
+    /// Returns an error if serialization or writing fails.
+    pub fn write_to<W: Write>(&self, mut writer: W) -> Result<(), ReplayError> {
         // Write magic bytes
         writer
             .write_all(REPLAY_MAGIC)
@@ -280,25 +315,26 @@ impl Replay {
             .write_all(&REPLAY_VERSION.to_le_bytes())
             .map_err(|e| ReplayError::Io(e.to_string()))?;
 
-        // Write JSON payload
-        let json = self.to_json()?;
-        let len = json.len() as u64;
+        // Write compressed payload
+        let payload = save_compression::serialize_compressed(self)
+            .map_err(|e| ReplayError::Serialization(e.to_string()))?;
+        let len = payload.len() as u64;
         writer
             .write_all(&len.to_le_bytes())
             .map_err(|e| ReplayError::Io(e.to_string()))?;
         writer
-            .write_all(&json)
+            .write_all(&payload)
             .map_err(|e| ReplayError::Io(e.to_string()))?;
 
         Ok(())
     }
 
-    /// Loads a replay from a reader.
+    /// Reads a replay from a reader.
     ///
     /// # Errors
     ///
     /// Returns an error if reading or parsing fails.
-    pub fn load<R: Read>(mut reader: R) -> Result<Self, ReplayError> {
+    pub fn read_from<R: Read>(mut reader: R) -> Result<Self, ReplayError> {
         // Read and verify magic
         let mut magic = [0u8; 4];
         reader
@@ -318,19 +354,45 @@ impl Replay {
             return Err(ReplayError::UnsupportedVersion(version));
         }
 
-        // Read JSON payload
+        // Read payload
         let mut len_bytes = [0u8; 8];
         reader
             .read_exact(&mut len_bytes)
             .map_err(|e| ReplayError::Io(e.to_string()))?;
         let len = u64::from_le_bytes(len_bytes) as usize;
 
-        let mut json = vec![0u8; len];
+        let mut payload = vec![0u8; len];
         reader
-            .read_exact(&mut json)
+            .read_exact(&mut payload)
             .map_err(|e| ReplayError::Io(e.to_string()))?;
 
-        Self::from_json(&json)
+        if version < 2 {
+            return Self::from_json(&payload);
+        }
+        save_compression::deserialize_compressed(&payload)
+            .map_err(|e| ReplayError::Deserialization(e.to_string()))
+    }
+
+    /// Saves the replay to a compressed file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ReplayError> {
+        let file = std::fs::File::create(path).map_err(|e| ReplayError::Io(e.to_string()))?;
+        let mut writer = std::io::BufWriter::new(file);
+        self.write_to(&mut writer)?;
+        writer.flush().map_err(|e| ReplayError::Io(e.to_string()))
+    }
+
+    /// Loads a replay from a file written by [`Replay::save`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or parsed.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ReplayError> {
+        let file = std::fs::File::open(path).map_err(|e| ReplayError::Io(e.to_string()))?;
+        Self::read_from(std::io::BufReader::new(file))
     }
 }
 
@@ -578,6 +640,15 @@ impl ReplayRecorder {
         }
     }
 
+    /// Records the RNG state at the current frame.
+    pub fn record_rng_state(&mut self, state: u64) {
+        if self.is_recording {
+            self.replay
+                .rng_checkpoints
+                .push(RngCheckpoint::new(self.current_frame, state));
+        }
+    }
+
     /// Finishes recording and returns the replay.
     #[must_use]
     pub fn finish(mut self) -> Replay {
@@ -787,6 +858,22 @@ impl ReplayPlayer {
         Ok(())
     }
 
+    /// Verifies an RNG state against the recorded checkpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DeterminismFailure` if the states don't match.
+    pub fn verify_rng(&self, frame: u64, state: u64) -> Result<(), ReplayError> {
+        match self.replay.find_rng_checkpoint(frame) {
+            Some(expected) if expected.state != state => Err(ReplayError::DeterminismFailure {
+                frame,
+                expected: expected.state,
+                actual: state,
+            }),
+            _ => Ok(()),
+        }
+    }
+
     /// Returns a reference to the underlying replay.
     #[must_use]
     pub fn replay(&self) -> &Replay {
@@ -1313,6 +1400,7 @@ impl DeterminismTest {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use genesis_common::SeededRng;
     use std::io::Cursor;
 
     #[test]
@@ -1390,10 +1478,10 @@ mod tests {
         replay.state_hashes.push(StateHash::new(0, 111, 222));
 
         let mut buffer = Vec::new();
-        replay.save(&mut buffer).expect("Failed to save");
+        replay.write_to(&mut buffer).expect("Failed to save");
 
         let cursor = Cursor::new(buffer);
-        let loaded = Replay::load(cursor).expect("Failed to load");
+        let loaded = Replay::read_from(cursor).expect("Failed to load");
 
         assert_eq!(loaded.seed, 99);
         assert_eq!(loaded.frames.len(), 1);
@@ -1405,10 +1493,95 @@ mod tests {
     fn test_replay_invalid_magic() {
         let bad_data = b"BADM\x01\x00\x00\x00";
         let cursor = Cursor::new(bad_data);
-        let result = Replay::load(cursor);
+        let result = Replay::read_from(cursor);
         assert!(matches!(result, Err(ReplayError::InvalidFormat(_))));
     }
 
+    /// Advances a tiny random-driven simulation by one frame.
+    fn simulate_frame(rng: &mut SeededRng, position: &mut (i64, i64), inputs: &[Input]) {
+        for input in inputs {
+            let step = i64::from(rng.range_u32(1, 4));
+            match input {
+                Input::MoveLeft => position.0 -= step,
+                Input::MoveRight => position.0 += step,
+                Input::MoveUp => position.1 -= step,
+                Input::MoveDown => position.1 += step,
+                _ => {},
+            }
+        }
+    }
+
+    #[test]
+    fn test_replay_file_roundtrip_replays_identically() {
+        const MOVES: [Input; 4] = [Input::MoveLeft, Input::MoveRight, Input::MoveUp, Input::MoveDown];
+
+        let mut recorder = ReplayRecorder::with_config(RecordingConfig {
+            hash_interval: 10,
+            capture_timing: false,
+            ..Default::default()
+        });
+        recorder.set_seed(1234);
+        recorder.start();
+
+        let mut rng = SeededRng::new(1234);
+        let mut player_choices = SeededRng::new(7);
+        let mut position = (0, 0);
+        for _ in 0..300 {
+            recorder.record_rng_state(rng.state());
+            let input = MOVES[player_choices.range_u32(0, 3) as usize];
+            recorder.record_input_action(input);
+            simulate_frame(&mut rng, &mut position, &[input]);
+            recorder.record_state_hash(position.0 as u64, position.1 as u64);
+            recorder.end_frame();
+        }
+        let replay = recorder.finish();
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("run.replay");
+        replay.save(&path).expect("save");
+        let file_len = std::fs::metadata(&path).expect("metadata").len();
+        let json_len = replay.to_json().expect("json").len() as u64;
+        assert!(file_len < json_len);
+
+        let loaded = Replay::load(&path).expect("load");
+        assert_eq!(loaded.seed, 1234);
+        assert_eq!(loaded.rng_checkpoints, replay.rng_checkpoints);
+        assert_eq!(
+            DeterminismChecker::new().compare_replays(&replay, &loaded),
+            DeterminismResult::Identical
+        );
+
+        // Re-run the simulation from the loaded inputs alone
+        let mut player = ReplayPlayer::new(loaded);
+        let mut rng = SeededRng::new(u64::from(player.seed()));
+        let mut replayed = (0, 0);
+        while let Some(frame) = player.next_frame().cloned() {
+            player.verify_rng(frame.frame, rng.state()).expect("rng diverged");
+            simulate_frame(&mut rng, &mut replayed, &frame.inputs);
+            player
+                .verify_state(frame.frame, replayed.0 as u64, replayed.1 as u64)
+                .expect("state diverged");
+        }
+        assert_eq!(replayed, position);
+        assert!(player.verify_rng(0, rng.state()).is_err());
+    }
+
+    #[test]
+    fn test_replay_reads_version_1_json() {
+        let replay = Replay::with_seed(5);
+        let json = replay.to_json().expect("json");
+
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(REPLAY_MAGIC);
+        buffer.extend_from_slice(&1u32.to_le_bytes());
+        buffer.extend_from_slice(&(json.len() as u64).to_le_bytes());
+        buffer.extend_from_slice(&json);
+
+        let loaded = Replay::read_from(Cursor::new(buffer)).expect("load v1");
+        assert_eq!(loaded.seed, 5);
+        assert!(loaded.rng_checkpoints.is_empty());
+    }
+
     #[test]
     fn test_recorder_basic() {
         let mut recorder = ReplayRecorder::new();
@@ -1706,9 +1879,9 @@ mod tests {
 
         // Test save/load roundtrip
         let mut buffer = Vec::new();
-        replay.save(&mut buffer).expect("save failed");
+        replay.write_to(&mut buffer).expect("save failed");
 
-        let loaded = Replay::load(Cursor::new(&buffer)).expect("load failed");
+        let loaded = Replay::read_from(Cursor::new(&buffer)).expect("load failed");
         assert_eq!(loaded.frames.len(), 1000);
         assert_eq!(loaded.seed, 42);
 
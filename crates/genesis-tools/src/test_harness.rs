@@ -0,0 +1,357 @@
+//! Deterministic fuzzing for the chunk serialization pipeline.
+//!
+//! [`fuzz_serialization`] generates random terrain chunks from a seed and
+//! pushes each one through serialize → compress → decompress →
+//! deserialize, checking that the chunk comes back bit-for-bit identical.
+//! The first iterations always cover the edge cases (empty, single
+//! material, largest size) before switching to random shapes. Failing
+//! chunks are shrunk before they are reported, and the same seed always
+//! replays the same run.
+
+use genesis_common::rng::SeededRng;
+use genesis_common::save_compression;
+use genesis_worldgen::{BiomeChunk, ChunkData, ChunkSerializer};
+use std::fmt;
+
+/// Side length of the largest chunk the fuzzer generates.
+pub const FUZZ_MAX_CHUNK_SIDE: i32 = 256;
+
+/// Side length limit for randomly shaped chunks.
+const RANDOM_CHUNK_SIDE: u32 = 32;
+
+/// Biome scales a generated chunk can have.
+const SCALES: [i32; 5] = [1, 4, 16, 64, 256];
+
+/// Pipeline stage where a round trip went wrong.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FuzzStage {
+    /// Chunk serialization failed.
+    Serialize,
+    /// Compression failed.
+    Compress,
+    /// Decompression failed.
+    Decompress,
+    /// Chunk deserialization failed.
+    Deserialize,
+    /// The round trip succeeded but produced a different chunk.
+    Mismatch,
+}
+
+impl fmt::Display for FuzzStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Serialize => "serialize",
+            Self::Compress => "compress",
+            Self::Decompress => "decompress",
+            Self::Deserialize => "deserialize",
+            Self::Mismatch => "mismatch",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A chunk that failed to round-trip.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuzzFailure {
+    /// Iteration that produced the original chunk.
+    pub iteration: u32,
+    /// Stage the shrunk chunk fails at.
+    pub stage: FuzzStage,
+    /// Error message, or a description of the mismatch.
+    pub message: String,
+    /// Smallest chunk found that still fails.
+    pub chunk: ChunkData,
+}
+
+/// Outcome of a fuzzing run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuzzReport {
+    /// Seed the run used; pass it again to reproduce.
+    pub seed: u64,
+    /// Chunks generated and checked.
+    pub iterations: u32,
+    /// Chunks that failed, in iteration order.
+    pub failures: Vec<FuzzFailure>,
+}
+
+impl FuzzReport {
+    /// Whether every chunk round-tripped.
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Result of running one chunk through a pipeline.
+pub type RoundTrip = Result<ChunkData, (FuzzStage, String)>;
+
+/// Fuzzes the real chunk pipeline for `iterations` chunks.
+#[must_use]
+pub fn fuzz_serialization(seed: u64, iterations: u32) -> FuzzReport {
+    fuzz_pipeline(seed, iterations, round_trip)
+}
+
+/// Fuzzes an arbitrary pipeline, for testing the harness itself or
+/// alternative encodings.
+pub fn fuzz_pipeline(
+    seed: u64,
+    iterations: u32,
+    pipeline: impl Fn(&ChunkData) -> RoundTrip,
+) -> FuzzReport {
+    let mut rng = SeededRng::new(seed);
+    let failures = (0..iterations)
+        .filter_map(|iteration| {
+            let chunk = random_chunk(&mut rng, iteration);
+            check(&pipeline, &chunk)?;
+            let chunk = shrink(&pipeline, chunk);
+            let (stage, message) = check(&pipeline, &chunk)?;
+            Some(FuzzFailure {
+                iteration,
+                stage,
+                message,
+                chunk,
+            })
+        })
+        .collect();
+    FuzzReport {
+        seed,
+        iterations,
+        failures,
+    }
+}
+
+/// Runs a chunk through serialize → compress → decompress → deserialize.
+pub fn round_trip(chunk: &ChunkData) -> RoundTrip {
+    let bytes =
+        ChunkSerializer::serialize(chunk).map_err(|e| (FuzzStage::Serialize, e.to_string()))?;
+    let compressed =
+        save_compression::compress(&bytes).map_err(|e| (FuzzStage::Compress, e.to_string()))?;
+    let decompressed = save_compression::decompress(&compressed)
+        .map_err(|e| (FuzzStage::Decompress, e.to_string()))?;
+    ChunkSerializer::deserialize(&decompressed).map_err(|e| (FuzzStage::Deserialize, e.to_string()))
+}
+
+/// Whether two chunks are identical, comparing heights bit-for-bit so NaN
+/// and `-0.0` count.
+#[must_use]
+pub fn chunks_identical(a: &ChunkData, b: &ChunkData) -> bool {
+    a.biomes == b.biomes
+        && a.heights.len() == b.heights.len()
+        && a.heights
+            .iter()
+            .zip(&b.heights)
+            .all(|(x, y)| x.to_bits() == y.to_bits())
+}
+
+/// Runs `chunk` through `pipeline`, returning the failure if any.
+fn check(
+    pipeline: &impl Fn(&ChunkData) -> RoundTrip,
+    chunk: &ChunkData,
+) -> Option<(FuzzStage, String)> {
+    match pipeline(chunk) {
+        Ok(out) if chunks_identical(chunk, &out) => None,
+        Ok(out) => Some((
+            FuzzStage::Mismatch,
+            format!(
+                "{}x{} chunk came back as {}x{} with {} heights",
+                chunk.biomes.width,
+                chunk.biomes.height,
+                out.biomes.width,
+                out.biomes.height,
+                out.heights.len()
+            ),
+        )),
+        Err(failure) => Some(failure),
+    }
+}
+
+/// Greedily simplifies a failing chunk while it keeps failing.
+fn shrink(pipeline: &impl Fn(&ChunkData) -> RoundTrip, mut chunk: ChunkData) -> ChunkData {
+    loop {
+        let candidate = simpler_chunks(&chunk)
+            .into_iter()
+            .find(|c| check(pipeline, c).is_some());
+        match candidate {
+            Some(simpler) => chunk = simpler,
+            None => return chunk,
+        }
+    }
+}
+
+/// Chunks strictly simpler than `chunk`: smaller, or with fewer distinct
+/// values.
+fn simpler_chunks(chunk: &ChunkData) -> Vec<ChunkData> {
+    let (w, h) = (chunk.biomes.width, chunk.biomes.height);
+    let mut out = Vec::new();
+    if w > 0 {
+        out.push(cropped(chunk, w / 2, h));
+    }
+    if h > 0 {
+        out.push(cropped(chunk, w, h / 2));
+    }
+    if chunk.biomes.biomes.iter().any(|&b| b != 0) {
+        let mut c = chunk.clone();
+        c.biomes.biomes.fill(0);
+        out.push(c);
+    }
+    if chunk.heights.iter().any(|h| h.to_bits() != 0) {
+        let mut c = chunk.clone();
+        c.heights.fill(0.0);
+        out.push(c);
+    }
+    if (
+        chunk.biomes.origin_x,
+        chunk.biomes.origin_z,
+        chunk.biomes.scale,
+    ) != (0, 0, 1)
+    {
+        let mut c = chunk.clone();
+        c.biomes.origin_x = 0;
+        c.biomes.origin_z = 0;
+        c.biomes.scale = 1;
+        out.push(c);
+    }
+    out
+}
+
+/// Top-left `width`×`height` corner of a chunk.
+fn cropped(chunk: &ChunkData, width: i32, height: i32) -> ChunkData {
+    let old_w = chunk.biomes.width.max(0) as usize;
+    let (w, h) = (width.max(0) as usize, height.max(0) as usize);
+    let crop = |values: &[i32]| -> Vec<i32> {
+        (0..h)
+            .flat_map(|z| values[z * old_w..z * old_w + w].iter().copied())
+            .collect()
+    };
+    let heights = (0..h)
+        .flat_map(|z| chunk.heights[z * old_w..z * old_w + w].iter().copied())
+        .collect();
+    ChunkData {
+        biomes: BiomeChunk {
+            biomes: crop(&chunk.biomes.biomes),
+            width,
+            height,
+            ..chunk.biomes.clone()
+        },
+        heights,
+    }
+}
+
+/// Generates the chunk for one iteration; the first three are the empty,
+/// single-material and largest edge cases.
+#[allow(clippy::cast_possible_wrap)] // origins are meant to cover the whole i32 range
+fn random_chunk(rng: &mut SeededRng, iteration: u32) -> ChunkData {
+    let (width, height) = match iteration {
+        0 => (0, 0),
+        2 => (FUZZ_MAX_CHUNK_SIDE, FUZZ_MAX_CHUNK_SIDE),
+        _ => (
+            rng.range_u32(0, RANDOM_CHUNK_SIDE) as i32,
+            rng.range_u32(0, RANDOM_CHUNK_SIDE) as i32,
+        ),
+    };
+    let cells = (width * height) as usize;
+    let uniform = iteration == 1 || rng.chance(0.1);
+    let material = rng.range_u32(0, 255) as i32;
+    let biomes = (0..cells)
+        .map(|_| {
+            if uniform {
+                material
+            } else {
+                rng.range_u32(0, 255) as i32 - 1
+            }
+        })
+        .collect();
+    let heights = (0..cells)
+        .map(|_| {
+            if rng.chance(0.02) {
+                special_height(rng)
+            } else {
+                rng.range(-64.0, 320.0)
+            }
+        })
+        .collect();
+    ChunkData {
+        biomes: BiomeChunk {
+            biomes,
+            width,
+            height,
+            origin_x: rng.next_u32() as i32,
+            origin_z: rng.next_u32() as i32,
+            scale: SCALES[rng.range_u32(0, SCALES.len() as u32 - 1) as usize],
+        },
+        heights,
+    }
+}
+
+/// Height values that tend to trip encoders.
+fn special_height(rng: &mut SeededRng) -> f32 {
+    const SPECIAL: [f32; 7] = [
+        0.0,
+        -0.0,
+        f32::NAN,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::MAX,
+        f32::MIN_POSITIVE,
+    ];
+    SPECIAL[rng.range_u32(0, SPECIAL.len() as u32 - 1) as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pipeline_round_trips_fuzzed_chunks() {
+        let report = fuzz_serialization(0x5eed, 64);
+        assert_eq!(report.iterations, 64);
+        assert!(report.passed(), "{:?}", report.failures.first());
+    }
+
+    #[test]
+    fn test_edge_cases_generated_and_runs_reproducible() {
+        let mut rng = SeededRng::new(9);
+        let empty = random_chunk(&mut rng, 0);
+        assert!(empty.biomes.biomes.is_empty() && empty.heights.is_empty());
+        let uniform = random_chunk(&mut rng, 1);
+        assert!(uniform.biomes.biomes.windows(2).all(|p| p[0] == p[1]));
+        let max = random_chunk(&mut rng, 2);
+        assert_eq!(
+            max.heights.len(),
+            (FUZZ_MAX_CHUNK_SIDE * FUZZ_MAX_CHUNK_SIDE) as usize
+        );
+
+        let (mut a, mut b) = (SeededRng::new(3), SeededRng::new(3));
+        for i in 0..8 {
+            assert!(chunks_identical(
+                &random_chunk(&mut a, i),
+                &random_chunk(&mut b, i)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_broken_pipeline_reports_shrunk_chunk() {
+        // Drops the last height of any chunk with a cell above 100
+        let lossy = |chunk: &ChunkData| -> RoundTrip {
+            let mut out = round_trip(chunk)?;
+            if out.heights.iter().any(|&h| h > 100.0) {
+                out.heights.pop();
+            }
+            Ok(out)
+        };
+        let report = fuzz_pipeline(11, 16, lossy);
+        assert!(!report.passed());
+        assert_eq!(report, fuzz_pipeline(11, 16, lossy));
+
+        let failure = &report.failures[0];
+        assert_eq!(failure.stage, FuzzStage::Mismatch);
+        // Shrinking leaves a single cell holding the offending height
+        assert_eq!(
+            (failure.chunk.biomes.width, failure.chunk.biomes.height),
+            (1, 1)
+        );
+        assert_eq!(failure.chunk.biomes.biomes, vec![0]);
+        assert!(failure.chunk.heights[0] > 100.0);
+    }
+}
@@ -5,6 +5,7 @@
 //! - Damage flash overlay
 //! - Low health warning
 //! - Status effect icons
+//! - Stamina bar and attack cooldown sweep
 
 use egui::{Color32, Pos2, Rect, Ui, Vec2};
 use serde::{Deserialize, Serialize};
@@ -604,6 +605,153 @@ impl LowHealthWarning {
     }
 }
 
+/// Player combat values the HUD displays each frame.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PlayerCombatState {
+    /// Current health.
+    pub health: f32,
+    /// Maximum health.
+    pub max_health: f32,
+    /// Current stamina.
+    pub stamina: f32,
+    /// Maximum stamina.
+    pub max_stamina: f32,
+    /// Time until the next attack is ready.
+    pub attack_cooldown: f32,
+}
+
+impl Default for PlayerCombatState {
+    fn default() -> Self {
+        Self {
+            health: 100.0,
+            max_health: 100.0,
+            stamina: 100.0,
+            max_stamina: 100.0,
+            attack_cooldown: 0.0,
+        }
+    }
+}
+
+/// Stamina bar state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StaminaBar {
+    /// Current stamina.
+    pub stamina: f32,
+    /// Maximum stamina.
+    pub max_stamina: f32,
+    /// Fraction at or below which the bar flashes.
+    pub low_threshold: f32,
+    /// Flash timer.
+    pub flash_timer: f32,
+    /// Flash speed.
+    pub flash_speed: f32,
+}
+
+impl Default for StaminaBar {
+    fn default() -> Self {
+        Self {
+            stamina: 100.0,
+            max_stamina: 100.0,
+            low_threshold: 0.2,
+            flash_timer: 0.0,
+            flash_speed: 8.0,
+        }
+    }
+}
+
+impl StaminaBar {
+    /// Create new stamina bar.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set current and maximum stamina.
+    pub fn set(&mut self, stamina: f32, max_stamina: f32) {
+        self.stamina = stamina;
+        self.max_stamina = max_stamina;
+    }
+
+    /// Get the filled fraction (0.0 - 1.0).
+    pub fn fraction(&self) -> f32 {
+        if self.max_stamina > 0.0 {
+            (self.stamina / self.max_stamina).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Check if stamina is low enough to flash.
+    pub fn is_low(&self) -> bool {
+        self.fraction() <= self.low_threshold
+    }
+
+    /// Get the bar's fill color, flashing when low.
+    pub fn color(&self) -> Color32 {
+        if self.is_low() {
+            let pulse = (self.flash_timer * self.flash_speed).sin() * 0.5 + 0.5;
+            let alpha = (120.0 + pulse * 135.0) as u8;
+            Color32::from_rgba_unmultiplied(230, 140, 40, alpha)
+        } else {
+            Color32::from_rgb(90, 200, 90)
+        }
+    }
+
+    /// Update the flash timer.
+    pub fn update(&mut self, dt: f32) {
+        if self.is_low() {
+            self.flash_timer += dt;
+        } else {
+            self.flash_timer = 0.0;
+        }
+    }
+}
+
+/// Attack cooldown sweep state.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AttackCooldownIndicator {
+    /// Time until the next attack is ready.
+    pub remaining: f32,
+    /// Full length of the current cooldown.
+    pub duration: f32,
+}
+
+impl AttackCooldownIndicator {
+    /// Create new indicator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the remaining cooldown.
+    ///
+    /// A value above the previous one means a new attack started, so it
+    /// becomes the full duration the sweep is measured against.
+    pub fn set_remaining(&mut self, remaining: f32) {
+        let remaining = remaining.max(0.0);
+        if remaining > self.remaining {
+            self.duration = remaining;
+        }
+        self.remaining = remaining;
+        if remaining <= 0.0 {
+            self.duration = 0.0;
+        }
+    }
+
+    /// Get the fraction of the cooldown still remaining (1.0 just after an
+    /// attack, 0.0 when ready).
+    pub fn fraction(&self) -> f32 {
+        if self.duration > 0.0 {
+            (self.remaining / self.duration).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Check if the next attack is ready.
+    pub fn is_ready(&self) -> bool {
+        self.remaining <= 0.0
+    }
+}
+
 /// Combat HUD configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CombatHudConfig {
@@ -625,6 +773,12 @@ pub struct CombatHudConfig {
     pub combo_position: [f32; 2],
     /// Status effects position.
     pub status_position: [f32; 2],
+    /// Show stamina bar.
+    pub show_stamina: bool,
+    /// Show attack cooldown sweep.
+    pub show_attack_cooldown: bool,
+    /// Stamina bar position (normalized 0-1, bar center).
+    pub stamina_position: [f32; 2],
 }
 
 impl Default for CombatHudConfig {
@@ -639,6 +793,9 @@ impl Default for CombatHudConfig {
             max_damage_indicators: 20,
             combo_position: [0.5, 0.3],
             status_position: [0.02, 0.15],
+            show_stamina: true,
+            show_attack_cooldown: true,
+            stamina_position: [0.5, 0.85],
         }
     }
 }
@@ -658,6 +815,10 @@ pub struct CombatHud {
     pub screen_flash: ScreenFlash,
     /// Low health warning.
     pub low_health_warning: LowHealthWarning,
+    /// Stamina bar.
+    pub stamina_bar: StaminaBar,
+    /// Attack cooldown sweep.
+    pub attack_cooldown: AttackCooldownIndicator,
     /// Whether HUD is visible.
     pub visible: bool,
 }
@@ -678,6 +839,8 @@ impl CombatHud {
             damage_indicators: Vec::new(),
             screen_flash: ScreenFlash::new(),
             low_health_warning: LowHealthWarning::new(),
+            stamina_bar: StaminaBar::new(),
+            attack_cooldown: AttackCooldownIndicator::new(),
             visible: true,
         }
     }
@@ -749,8 +912,15 @@ impl CombatHud {
         self.low_health_warning.set_health(percent);
     }
 
-    /// Update all components.
-    pub fn update(&mut self, dt: f32) {
+    /// Update all components from the player's combat state.
+    pub fn update(&mut self, dt: f32, player: &PlayerCombatState) {
+        if player.max_health > 0.0 {
+            self.set_health_percent(player.health / player.max_health);
+        }
+        self.stamina_bar.set(player.stamina, player.max_stamina);
+        self.attack_cooldown.set_remaining(player.attack_cooldown);
+
+        self.stamina_bar.update(dt);
         self.combo.update(dt);
         self.screen_flash.update(dt);
         self.low_health_warning.update(dt);
@@ -805,6 +975,60 @@ impl CombatHud {
         if self.config.show_damage_numbers {
             self.show_damage_indicators(ui);
         }
+
+        // Stamina and attack cooldown
+        if self.config.show_stamina {
+            self.show_stamina(ui, screen_size);
+        }
+    }
+
+    /// Show stamina bar with the attack cooldown sweep beside it.
+    fn show_stamina(&self, ui: &mut Ui, screen_size: Vec2) {
+        let center = Pos2::new(
+            screen_size.x * self.config.stamina_position[0],
+            screen_size.y * self.config.stamina_position[1],
+        );
+
+        let painter = ui.painter();
+        let bar_width = 200.0;
+        let bar_height = 8.0;
+        let bar_rect = Rect::from_center_size(center, Vec2::new(bar_width, bar_height));
+        painter.rect_filled(bar_rect, 3.0, Color32::from_gray(40));
+
+        let fill_width = bar_width * self.stamina_bar.fraction();
+        let fill_rect = Rect::from_min_size(bar_rect.min, Vec2::new(fill_width, bar_height));
+        painter.rect_filled(fill_rect, 3.0, self.stamina_bar.color());
+        painter.rect_stroke(bar_rect, 3.0, egui::Stroke::new(1.0, Color32::from_gray(100)));
+
+        if self.config.show_attack_cooldown && !self.attack_cooldown.is_ready() {
+            let radius = 10.0;
+            let sweep_center = Pos2::new(bar_rect.max.x + radius + 8.0, center.y);
+            Self::draw_cooldown_sweep(painter, sweep_center, radius, self.attack_cooldown.fraction());
+        }
+    }
+
+    /// Draw a clockwise sweep covering the remaining cooldown fraction.
+    fn draw_cooldown_sweep(painter: &egui::Painter, center: Pos2, radius: f32, fraction: f32) {
+        painter.circle_filled(center, radius, Color32::from_gray(40));
+
+        const SEGMENTS: usize = 32;
+        let steps = (SEGMENTS as f32 * fraction).ceil() as usize;
+        if steps > 0 {
+            let start = -std::f32::consts::FRAC_PI_2;
+            let sweep = std::f32::consts::TAU * fraction;
+            let mut points = vec![center];
+            for i in 0..=steps {
+                let angle = start + sweep * (i as f32 / steps as f32);
+                points.push(center + Vec2::angled(angle) * radius);
+            }
+            painter.add(egui::Shape::convex_polygon(
+                points,
+                Color32::from_rgba_unmultiplied(200, 200, 255, 160),
+                egui::Stroke::NONE,
+            ));
+        }
+
+        painter.circle_stroke(center, radius, egui::Stroke::new(1.0, Color32::from_gray(120)));
     }
 
     /// Show combo counter.
@@ -1292,7 +1516,7 @@ mod tests {
         hud.add_status(StatusEffect::new(StatusEffectType::Poison, 0.5));
         hud.damage_flash(0.5);
 
-        hud.update(1.0);
+        hud.update(1.0, &PlayerCombatState::default());
 
         // Effects should have updated/expired
         assert!(hud.screen_flash.intensity < 0.5);
@@ -1366,4 +1590,69 @@ mod tests {
             StatusEffect::new(StatusEffectType::Poison, 5.0).with_source("Venomous Spider");
         assert_eq!(effect.source, Some("Venomous Spider".to_string()));
     }
+
+    #[test]
+    fn test_stamina_fraction_from_player_state() {
+        let mut hud = CombatHud::new();
+        let mut player = PlayerCombatState {
+            stamina: 75.0,
+            max_stamina: 150.0,
+            ..PlayerCombatState::default()
+        };
+
+        hud.update(0.1, &player);
+        assert!((hud.stamina_bar.fraction() - 0.5).abs() < f32::EPSILON);
+        assert!(!hud.stamina_bar.is_low());
+
+        player.stamina = 15.0;
+        hud.update(0.1, &player);
+        assert!((hud.stamina_bar.fraction() - 0.1).abs() < 1e-6);
+        assert!(hud.stamina_bar.is_low());
+
+        // Out-of-range values clamp instead of overflowing the bar
+        player.stamina = -5.0;
+        hud.update(0.1, &player);
+        assert!(hud.stamina_bar.fraction().abs() < f32::EPSILON);
+        player.max_stamina = 0.0;
+        hud.update(0.1, &player);
+        assert!(hud.stamina_bar.fraction().abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_attack_cooldown_fraction_from_player_state() {
+        let mut hud = CombatHud::new();
+        let mut player = PlayerCombatState::default();
+
+        hud.update(0.0, &player);
+        assert!(hud.attack_cooldown.is_ready());
+        assert!(hud.attack_cooldown.fraction().abs() < f32::EPSILON);
+
+        // Attack starts a 0.8s cooldown
+        player.attack_cooldown = 0.8;
+        hud.update(0.0, &player);
+        assert!((hud.attack_cooldown.fraction() - 1.0).abs() < f32::EPSILON);
+
+        player.attack_cooldown = 0.2;
+        hud.update(0.6, &player);
+        assert!((hud.attack_cooldown.fraction() - 0.25).abs() < 1e-6);
+
+        player.attack_cooldown = 0.0;
+        hud.update(0.2, &player);
+        assert!(hud.attack_cooldown.is_ready());
+        assert!(hud.attack_cooldown.fraction().abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_low_stamina_flashes() {
+        let mut bar = StaminaBar::new();
+        bar.set(10.0, 100.0);
+        bar.update(0.1);
+        let first = bar.color();
+        bar.update(0.2);
+        assert_ne!(first, bar.color());
+
+        bar.set(100.0, 100.0);
+        bar.update(0.1);
+        assert!(bar.flash_timer.abs() < f32::EPSILON);
+    }
 }
@@ -5,8 +5,13 @@
 //! - Output slot with result preview
 //! - Craft button with cooldown visual
 //! - Clear grid button
+//! - Live recipe preview via [`RecipeMatcher`], highlighting the cells
+//!   that make up the matched recipe
 
 use egui::{Color32, Pos2, Rect, Response, Ui, Vec2};
+use genesis_kernel::crafting_grid::{
+    execute_craft, CraftingGrid as MatchGrid, ItemId, ItemSlot, RecipeMatcher, RecipePattern,
+};
 use serde::{Deserialize, Serialize};
 
 /// Grid dimensions for the crafting grid.
@@ -24,6 +29,16 @@ impl CraftingItemId {
     pub fn new(id: impl Into<String>) -> Self {
         Self(id.into())
     }
+
+    /// Create an ID for a numeric item type.
+    pub fn from_item_id(item_id: ItemId) -> Self {
+        Self(item_id.to_string())
+    }
+
+    /// Get the numeric item type, if this ID is one.
+    pub fn item_id(&self) -> Option<ItemId> {
+        self.0.parse().ok()
+    }
 }
 
 impl std::fmt::Display for CraftingItemId {
@@ -438,6 +453,10 @@ pub struct CraftingGrid {
     pub cooldown: CraftCooldown,
     /// Whether the grid is open.
     pub open: bool,
+    /// Recipe matched by the current arrangement.
+    pub matched_recipe: Option<RecipePattern>,
+    /// Slot indices that contribute to the matched recipe.
+    pub matched_cells: Vec<usize>,
     /// Pending actions.
     pending_actions: Vec<CraftingGridAction>,
 }
@@ -459,6 +478,8 @@ impl CraftingGrid {
             drag_state: CraftingDragState::default(),
             cooldown: CraftCooldown::new(0.5),
             open: false,
+            matched_recipe: None,
+            matched_cells: Vec::new(),
             pending_actions: Vec::new(),
         }
     }
@@ -582,6 +603,82 @@ impl CraftingGrid {
         pattern
     }
 
+    /// Convert the grid to the kernel representation used for matching.
+    ///
+    /// Items without a numeric ID can't be part of a recipe and are
+    /// treated as an unknown item so they still block matches.
+    pub fn match_grid(&self) -> MatchGrid {
+        let mut grid = MatchGrid::new(CRAFTING_GRID_SIZE, CRAFTING_GRID_SIZE);
+        for (index, slot) in self.slots.iter().enumerate() {
+            if let Some(item) = &slot.item {
+                let item_id = item.id.item_id().unwrap_or(u32::MAX - 1);
+                let count = u16::try_from(item.count).unwrap_or(u16::MAX);
+                grid.set_full_slot(
+                    index % CRAFTING_GRID_SIZE,
+                    index / CRAFTING_GRID_SIZE,
+                    ItemSlot::new(item_id, count),
+                );
+            }
+        }
+        grid
+    }
+
+    /// Match the current arrangement against `matcher` and update the
+    /// output preview and highlighted cells.
+    pub fn update_preview(&mut self, matcher: &RecipeMatcher) {
+        let grid = self.match_grid();
+        self.matched_recipe = matcher.find_first_match(&grid).cloned();
+        self.matched_cells.clear();
+
+        match &self.matched_recipe {
+            Some(recipe) => {
+                self.matched_cells.extend(
+                    self.slots
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, slot)| slot.has_item())
+                        .map(|(index, _)| index),
+                );
+                let output = CraftingItem::new(
+                    recipe.result_id.to_string(),
+                    format!("Item #{}", recipe.result_id),
+                )
+                .with_count(u32::from(recipe.result_count));
+                // Keep a name the caller already set for the same item
+                if !self
+                    .output_preview
+                    .as_ref()
+                    .is_some_and(|preview| preview.id == output.id)
+                {
+                    self.output_preview = Some(output);
+                }
+            },
+            None => self.output_preview = None,
+        }
+    }
+
+    /// Craft the matched recipe, consuming one item from each contributing
+    /// slot. Returns the crafted item.
+    pub fn craft_matched(&mut self) -> Option<CraftingItem> {
+        let recipe = self.matched_recipe.clone()?;
+        let mut grid = self.match_grid();
+        let result = execute_craft(&mut grid, &recipe)?;
+
+        for (x, y) in result.consumed_slots {
+            let slot = &mut self.slots[y * CRAFTING_GRID_SIZE + x];
+            if let Some(item) = &mut slot.item {
+                item.remove_from_stack(1);
+                if item.count == 0 {
+                    slot.item = None;
+                }
+            }
+        }
+
+        let mut output = self.output_preview.clone()?;
+        output.count = u32::from(result.count);
+        Some(output)
+    }
+
     /// Update the cooldown timer.
     pub fn update(&mut self, delta_time: f32) {
         self.cooldown.update(delta_time);
@@ -738,10 +835,14 @@ impl CraftingGrid {
         let (rect, response) =
             ui.allocate_exact_size(Vec2::splat(size), egui::Sense::click_and_drag());
 
-        // Background
+        // Background, outlined when the slot is part of the matched recipe
+        let stroke = if self.matched_cells.contains(&index) {
+            egui::Stroke::new(2.0, Color32::from_rgb(100, 200, 100))
+        } else {
+            egui::Stroke::new(1.0, Color32::from_gray(60))
+        };
         ui.painter().rect_filled(rect, 4.0, bg_color);
-        ui.painter()
-            .rect_stroke(rect, 4.0, egui::Stroke::new(1.0, Color32::from_gray(60)));
+        ui.painter().rect_stroke(rect, 4.0, stroke);
 
         // Item
         if let Some(item) = &slot.item {
@@ -1116,6 +1217,81 @@ mod tests {
         assert!(!grid.output.has_item());
     }
 
+    /// Matcher with a pickaxe recipe: 3 wood (1) and 2 fiber (3).
+    fn pickaxe_matcher() -> RecipeMatcher {
+        let mut matcher = RecipeMatcher::new();
+        matcher.register(RecipePattern::shapeless(&[1, 1, 1, 3, 3]).with_result(20, 1));
+        matcher
+    }
+
+    fn numeric_item(item_id: ItemId, count: u32) -> CraftingItem {
+        CraftingItem::new(item_id.to_string(), format!("Item {item_id}")).with_count(count)
+    }
+
+    #[test]
+    fn test_crafting_item_id_numeric() {
+        let id = CraftingItemId::from_item_id(42);
+        assert_eq!(id.0, "42");
+        assert_eq!(id.item_id(), Some(42));
+        assert_eq!(CraftingItemId::new("iron_ingot").item_id(), None);
+    }
+
+    #[test]
+    fn test_update_preview_matches_known_pattern() {
+        let matcher = pickaxe_matcher();
+        let mut grid = CraftingGrid::new();
+        for index in [0, 1, 2] {
+            grid.set_item(index, numeric_item(1, 1));
+        }
+        grid.set_item(4, numeric_item(3, 1));
+        grid.set_item(7, numeric_item(3, 2));
+
+        grid.update_preview(&matcher);
+
+        let preview = grid.output_preview.as_ref().map(|item| item.id.item_id());
+        assert_eq!(preview, Some(Some(20)));
+        assert_eq!(grid.matched_cells, vec![0, 1, 2, 4, 7]);
+        assert!(grid.can_craft());
+    }
+
+    #[test]
+    fn test_update_preview_missing_ingredient() {
+        let matcher = pickaxe_matcher();
+        let mut grid = CraftingGrid::new();
+        grid.set_item(0, numeric_item(1, 1));
+        grid.set_item(1, numeric_item(1, 1));
+        grid.set_item(4, numeric_item(3, 1));
+        grid.set_item(7, numeric_item(3, 1));
+
+        grid.update_preview(&matcher);
+
+        assert!(grid.output_preview.is_none());
+        assert!(grid.matched_cells.is_empty());
+        assert!(!grid.can_craft());
+    }
+
+    #[test]
+    fn test_craft_matched_consumes_ingredients() {
+        let matcher = pickaxe_matcher();
+        let mut grid = CraftingGrid::new();
+        for index in [0, 1, 2] {
+            grid.set_item(index, numeric_item(1, 1));
+        }
+        grid.set_item(4, numeric_item(3, 1));
+        grid.set_item(7, numeric_item(3, 2));
+        grid.update_preview(&matcher);
+
+        let output = grid.craft_matched().map(|item| (item.id.item_id(), item.count));
+        assert_eq!(output, Some((Some(20), 1)));
+        assert!(grid.slots[0].is_empty());
+        assert!(grid.slots[4].is_empty());
+        assert_eq!(grid.slots[7].item.as_ref().map(|item| item.count), Some(1));
+
+        // Not enough left for a second craft
+        grid.update_preview(&matcher);
+        assert!(grid.craft_matched().is_none());
+    }
+
     #[test]
     fn test_crafting_grid_config_defaults() {
         let config = CraftingGridConfig::default();
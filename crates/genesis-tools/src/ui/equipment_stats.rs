@@ -4,9 +4,10 @@
 //! - Weapon damage display
 //! - Armor values display
 //! - Stat comparison between items
+//! - Weapon comparison tooltips
 //! - DPS calculation
 
-use egui::{Color32, Ui};
+use egui::{Color32, Response, Ui};
 use serde::{Deserialize, Serialize};
 
 /// Equipment slot types.
@@ -196,6 +197,8 @@ pub struct WeaponStats {
     pub armor_pen: f32,
     /// Range (for ranged weapons).
     pub range: Option<f32>,
+    /// Stamina cost per attack.
+    pub stamina_cost: f32,
 }
 
 impl Default for WeaponStats {
@@ -209,6 +212,7 @@ impl Default for WeaponStats {
             damage_type: WeaponDamageType::Physical,
             armor_pen: 0.0,
             range: None,
+            stamina_cost: 10.0,
         }
     }
 }
@@ -667,6 +671,66 @@ impl StatComparison {
     }
 }
 
+/// Signed change in a single weapon stat.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatDelta {
+    /// Candidate value minus current value.
+    pub delta: f32,
+    /// Whether the change is an improvement for the player.
+    pub result: CompareResult,
+}
+
+impl StatDelta {
+    /// Create a delta for a stat where higher values are better.
+    pub fn higher_is_better(current: f32, candidate: f32) -> Self {
+        Self {
+            delta: candidate - current,
+            result: StatComparison::new("", current, candidate).result,
+        }
+    }
+
+    /// Create a delta for a stat where lower values are better (costs).
+    pub fn lower_is_better(current: f32, candidate: f32) -> Self {
+        Self {
+            delta: candidate - current,
+            result: StatComparison::new("", candidate, current).result,
+        }
+    }
+
+    /// Get formatted delta string.
+    pub fn text(&self) -> String {
+        if self.delta.abs() < 0.01 {
+            "0".to_string()
+        } else if self.delta > 0.0 {
+            format!("+{:.1}", self.delta)
+        } else {
+            format!("{:.1}", self.delta)
+        }
+    }
+}
+
+/// Weapon stat changes from swapping the current weapon for a candidate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatDeltas {
+    /// Average damage per hit.
+    pub damage: StatDelta,
+    /// Attacks per second.
+    pub speed: StatDelta,
+    /// Stamina cost per attack (lower is better).
+    pub stamina_cost: StatDelta,
+}
+
+impl StatDeltas {
+    /// Get labelled deltas in display order.
+    pub fn rows(&self) -> [(&'static str, StatDelta); 3] {
+        [
+            ("Damage", self.damage),
+            ("Speed", self.speed),
+            ("Stamina Cost", self.stamina_cost),
+        ]
+    }
+}
+
 /// Equipment comparison between current and new item.
 #[derive(Debug, Clone)]
 pub struct EquipmentComparison {
@@ -845,6 +909,55 @@ impl EquipmentStatsPanel {
             .map(|new_item| EquipmentComparison::new(&self.selected_item, new_item))
     }
 
+    /// Compare a candidate weapon against the current one.
+    pub fn compare(current: &WeaponStats, candidate: &WeaponStats) -> StatDeltas {
+        StatDeltas {
+            damage: StatDelta::higher_is_better(
+                current.average_damage(),
+                candidate.average_damage(),
+            ),
+            speed: StatDelta::higher_is_better(current.attack_speed, candidate.attack_speed),
+            stamina_cost: StatDelta::lower_is_better(current.stamina_cost, candidate.stamina_cost),
+        }
+    }
+
+    /// Attach a hover tooltip showing `candidate`'s weapon stats against
+    /// the selected (equipped) weapon.
+    pub fn weapon_tooltip(&self, response: Response, candidate: &EquipmentItem) -> Response {
+        let Some(weapon) = &candidate.weapon else {
+            return response;
+        };
+        let current = self.selected_item.as_ref().and_then(|c| c.weapon.as_ref());
+
+        response.on_hover_ui(|ui| {
+            ui.label(
+                egui::RichText::new(&candidate.name)
+                    .color(candidate.rarity.color())
+                    .strong(),
+            );
+            ui.separator();
+
+            let values = [
+                ("Damage", weapon.average_damage()),
+                ("Speed", weapon.attack_speed),
+                ("Stamina Cost", weapon.stamina_cost),
+            ];
+            let deltas = current.map(|current| Self::compare(current, weapon).rows());
+            for (i, (label, value)) in values.into_iter().enumerate() {
+                ui.horizontal(|ui| {
+                    ui.label(format!("{label}: {value:.1}"));
+                    if let Some(deltas) = &deltas {
+                        let delta = deltas[i].1;
+                        ui.label(
+                            egui::RichText::new(format!("({})", delta.text()))
+                                .color(delta.result.color()),
+                        );
+                    }
+                });
+            }
+        })
+    }
+
     /// Show the stats panel.
     pub fn show(&mut self, ui: &mut Ui) {
         if !self.visible {
@@ -1022,6 +1135,18 @@ impl EquipmentStatsPanel {
             });
         }
 
+        // Weapon stat deltas
+        let current_weapon = comparison.current.as_ref().and_then(|c| c.weapon.as_ref());
+        if let (Some(current), Some(new_weapon)) = (current_weapon, &comparison.new_item.weapon) {
+            for (label, delta) in Self::compare(current, new_weapon).rows() {
+                ui.horizontal(|ui| {
+                    ui.label(format!("{label}:"));
+                    ui.label(egui::RichText::new(delta.result.arrow()).color(delta.result.color()));
+                    ui.label(egui::RichText::new(delta.text()).color(delta.result.color()));
+                });
+            }
+        }
+
         // Armor comparison
         if let Some(armor) = &comparison.armor {
             ui.horizontal(|ui| {
@@ -1369,4 +1494,64 @@ mod tests {
         let stats = WeaponStats::new(15.0, 25.0, 1.0);
         assert_eq!(stats.damage_range_text(), "15 - 25");
     }
+
+    #[test]
+    fn test_compare_signs_improvements() {
+        let current = WeaponStats {
+            min_damage: 10.0,
+            max_damage: 20.0,
+            attack_speed: 1.0,
+            stamina_cost: 12.0,
+            ..Default::default()
+        };
+        let candidate = WeaponStats {
+            min_damage: 20.0,
+            max_damage: 30.0,
+            attack_speed: 1.5,
+            stamina_cost: 8.0,
+            ..Default::default()
+        };
+
+        let deltas = EquipmentStatsPanel::compare(&current, &candidate);
+        assert!((deltas.damage.delta - 10.0).abs() < f32::EPSILON);
+        assert_eq!(deltas.damage.result, CompareResult::Better);
+        assert!((deltas.speed.delta - 0.5).abs() < f32::EPSILON);
+        assert_eq!(deltas.speed.result, CompareResult::Better);
+
+        // Cheaper attacks are an improvement even though the delta is negative
+        assert!((deltas.stamina_cost.delta + 4.0).abs() < f32::EPSILON);
+        assert_eq!(deltas.stamina_cost.result, CompareResult::Better);
+        assert_eq!(deltas.stamina_cost.text(), "-4.0");
+    }
+
+    #[test]
+    fn test_compare_signs_regressions() {
+        let current = WeaponStats {
+            stamina_cost: 8.0,
+            ..WeaponStats::new(20.0, 30.0, 1.5)
+        };
+        let candidate = WeaponStats {
+            stamina_cost: 12.0,
+            ..WeaponStats::new(10.0, 20.0, 1.0)
+        };
+
+        let deltas = EquipmentStatsPanel::compare(&current, &candidate);
+        assert!(deltas.damage.delta < 0.0);
+        assert_eq!(deltas.damage.result, CompareResult::Worse);
+        assert!(deltas.speed.delta < 0.0);
+        assert_eq!(deltas.speed.result, CompareResult::Worse);
+        assert!(deltas.stamina_cost.delta > 0.0);
+        assert_eq!(deltas.stamina_cost.result, CompareResult::Worse);
+        assert_eq!(deltas.stamina_cost.text(), "+4.0");
+    }
+
+    #[test]
+    fn test_compare_equal_weapons() {
+        let weapon = WeaponStats::default();
+        let deltas = EquipmentStatsPanel::compare(&weapon, &weapon);
+        for (_, delta) in deltas.rows() {
+            assert_eq!(delta.result, CompareResult::Equal);
+            assert_eq!(delta.text(), "0");
+        }
+    }
 }
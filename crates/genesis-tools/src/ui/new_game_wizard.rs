@@ -4,6 +4,7 @@
 //! seed selection, world size, difficulty, and confirmation.
 
 use egui::{Color32, Ui};
+use genesis_gameplay::settings::{DifficultyPreset, GameDifficulty, GameplaySettings};
 use serde::{Deserialize, Serialize};
 
 /// Wizard steps
@@ -196,6 +197,26 @@ impl NewGameDifficulty {
     pub fn is_warning(&self) -> bool {
         matches!(self, Self::Hard | Self::Hardcore)
     }
+
+    /// Get the gameplay preset this difficulty starts from
+    pub fn preset(&self) -> DifficultyPreset {
+        match self {
+            Self::Peaceful => DifficultyPreset::Peaceful,
+            Self::Easy | Self::Normal => DifficultyPreset::Normal,
+            Self::Hard | Self::Hardcore => DifficultyPreset::Hard,
+        }
+    }
+
+    /// Get the matching gameplay difficulty level
+    pub fn game_difficulty(&self) -> GameDifficulty {
+        match self {
+            Self::Peaceful => GameDifficulty::Peaceful,
+            Self::Easy => GameDifficulty::Easy,
+            Self::Normal => GameDifficulty::Normal,
+            Self::Hard => GameDifficulty::Hard,
+            Self::Hardcore => GameDifficulty::Hardcore,
+        }
+    }
 }
 
 /// Seed input mode
@@ -299,6 +320,18 @@ impl NewGameConfig {
         self.seed_value = rand_seed();
     }
 
+    /// Apply the chosen difficulty and tutorial setting to gameplay settings
+    pub fn apply_to(&self, settings: &mut GameplaySettings) {
+        self.difficulty.preset().apply_to(settings);
+
+        // Easy and Hardcore share a preset but keep their own damage tuning
+        let difficulty = self.difficulty.game_difficulty();
+        settings.difficulty = difficulty;
+        settings.player_damage_multiplier = difficulty.player_damage_multiplier();
+        settings.enemy_damage_multiplier = difficulty.enemy_damage_multiplier();
+        settings.show_tutorials = self.enable_tutorial;
+    }
+
     /// Parse custom seed from string
     pub fn parse_custom_seed(&mut self) {
         if self.custom_seed.is_empty() {
@@ -1091,6 +1124,41 @@ mod tests {
         assert!(NewGameDifficulty::Hardcore.is_warning());
     }
 
+    #[test]
+    fn test_difficulty_preset_mapping() {
+        assert_eq!(
+            NewGameDifficulty::Peaceful.preset(),
+            DifficultyPreset::Peaceful
+        );
+        assert_eq!(NewGameDifficulty::Easy.preset(), DifficultyPreset::Normal);
+        assert_eq!(NewGameDifficulty::Normal.preset(), DifficultyPreset::Normal);
+        assert_eq!(NewGameDifficulty::Hard.preset(), DifficultyPreset::Hard);
+        assert_eq!(NewGameDifficulty::Hardcore.preset(), DifficultyPreset::Hard);
+    }
+
+    #[test]
+    fn test_new_game_config_apply_to() {
+        let mut settings = GameplaySettings::default();
+        NewGameConfig::new()
+            .with_difficulty(NewGameDifficulty::Hard)
+            .apply_to(&mut settings);
+
+        let mut expected = GameplaySettings::default();
+        DifficultyPreset::Hard.apply_to(&mut expected);
+        assert_eq!(settings, expected);
+
+        let mut settings = GameplaySettings::default();
+        let mut config = NewGameConfig::new().with_difficulty(NewGameDifficulty::Hardcore);
+        config.enable_tutorial = false;
+        config.apply_to(&mut settings);
+        assert_eq!(settings.difficulty, GameDifficulty::Hardcore);
+        assert!(
+            (settings.hostile_spawn_rate - DifficultyPreset::Hard.hostile_spawn_rate()).abs()
+                < f32::EPSILON
+        );
+        assert!(!settings.show_tutorials);
+    }
+
     #[test]
     fn test_seed_mode_display_name() {
         assert_eq!(SeedMode::Random.display_name(), "Random");
@@ -4,6 +4,7 @@
 //! with Apply/Cancel/Reset functionality.
 
 use egui::{Color32, Ui};
+use genesis_kernel::audio::AudioCategory;
 use serde::{Deserialize, Serialize};
 
 /// Options menu tabs
@@ -177,6 +178,9 @@ pub struct GraphicsSettings {
     pub gamma: f32,
     /// Camera zoom level (0.25 - 20.0)
     pub camera_zoom: f32,
+    /// Color-blind friendly biome palette for terrain and minimap
+    #[serde(default)]
+    pub high_contrast_biomes: bool,
 }
 
 impl Default for GraphicsSettings {
@@ -196,6 +200,7 @@ impl Default for GraphicsSettings {
             motion_blur: false,
             gamma: 1.0,
             camera_zoom: 1.0,
+            high_contrast_biomes: false,
         }
     }
 }
@@ -274,8 +279,42 @@ impl OptionsAudioSettings {
             (self.master_volume as f32 / 100.0) * (category_volume as f32 / 100.0)
         }
     }
+
+    /// Get the slider value (0-100) for a mixer bus
+    pub fn bus_volume(&self, bus: AudioCategory) -> u32 {
+        match bus {
+            AudioCategory::Master => self.master_volume,
+            AudioCategory::Music => self.music_volume,
+            AudioCategory::Sfx => self.sfx_volume,
+            AudioCategory::Ambient => self.ambient_volume,
+            AudioCategory::Voice => self.voice_volume,
+            AudioCategory::Ui => self.ui_volume,
+        }
+    }
+
+    /// Get mutable slider value (0-100) for a mixer bus
+    pub fn bus_volume_mut(&mut self, bus: AudioCategory) -> &mut u32 {
+        match bus {
+            AudioCategory::Master => &mut self.master_volume,
+            AudioCategory::Music => &mut self.music_volume,
+            AudioCategory::Sfx => &mut self.sfx_volume,
+            AudioCategory::Ambient => &mut self.ambient_volume,
+            AudioCategory::Voice => &mut self.voice_volume,
+            AudioCategory::Ui => &mut self.ui_volume,
+        }
+    }
 }
 
+/// Mixer buses with a volume slider, in display order
+pub const AUDIO_BUSES: [(AudioCategory, &str); 6] = [
+    (AudioCategory::Master, "Master Volume:"),
+    (AudioCategory::Music, "Music Volume:"),
+    (AudioCategory::Sfx, "SFX Volume:"),
+    (AudioCategory::Ambient, "Ambient Volume:"),
+    (AudioCategory::Voice, "Voice Volume:"),
+    (AudioCategory::Ui, "UI Volume:"),
+];
+
 /// Key binding action
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum KeyAction {
@@ -465,6 +504,26 @@ impl ControlSettings {
             binding.primary = primary.into();
         }
     }
+
+    /// Find another action already using a key
+    pub fn find_conflict(&self, action: KeyAction, key: &str) -> Option<KeyAction> {
+        self.key_bindings
+            .iter()
+            .filter(|b| b.action != action)
+            .find(|b| b.primary == key || b.secondary.as_deref() == Some(key))
+            .map(|b| b.action)
+    }
+}
+
+/// A key assigned to more than one action
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RebindConflict {
+    /// The action that was just rebound
+    pub action: KeyAction,
+    /// The action already using the key
+    pub existing: KeyAction,
+    /// The shared key
+    pub key: String,
 }
 
 /// Difficulty level
@@ -600,8 +659,12 @@ pub enum OptionsMenuAction {
     SettingsChanged,
     /// Start key rebinding
     StartRebind(KeyAction),
+    /// A key was bound to an action (primary key)
+    KeyRebound(KeyAction, String),
     /// Camera zoom changed (for live preview)
     CameraZoomChanged(f32),
+    /// Audio bus volume changed (0.0-1.0, applied live)
+    BusVolumeChanged(AudioCategory, f32),
 }
 
 /// Configuration for options menu appearance
@@ -656,6 +719,8 @@ pub struct OptionsMenu {
     actions: Vec<OptionsMenuAction>,
     /// Currently rebinding key action
     rebinding_action: Option<KeyAction>,
+    /// Conflict from the last rebind, if any
+    rebind_conflict: Option<RebindConflict>,
 }
 
 impl OptionsMenu {
@@ -670,6 +735,7 @@ impl OptionsMenu {
             has_changes: false,
             actions: Vec::new(),
             rebinding_action: None,
+            rebind_conflict: None,
         }
     }
 
@@ -762,6 +828,14 @@ impl OptionsMenu {
 
     /// Cancel and revert changes
     pub fn cancel(&mut self) {
+        // Undo live bus volume previews
+        for (bus, _) in AUDIO_BUSES {
+            let original = self.original_settings.audio.bus_volume(bus);
+            if self.current_settings.audio.bus_volume(bus) != original {
+                self.actions
+                    .push(OptionsMenuAction::BusVolumeChanged(bus, original as f32 / 100.0));
+            }
+        }
         self.current_settings = self.original_settings.clone();
         self.has_changes = false;
         self.hide();
@@ -772,6 +846,7 @@ impl OptionsMenu {
     pub fn reset_to_defaults(&mut self) {
         self.current_settings = OptionsSettings::default();
         self.has_changes = true;
+        self.push_bus_volumes();
         self.actions.push(OptionsMenuAction::ResetToDefaults);
     }
 
@@ -783,6 +858,7 @@ impl OptionsMenu {
             },
             OptionsTab::Audio => {
                 self.current_settings.audio = OptionsAudioSettings::default();
+                self.push_bus_volumes();
             },
             OptionsTab::Controls => {
                 self.current_settings.controls = ControlSettings::default();
@@ -796,6 +872,26 @@ impl OptionsMenu {
             .push(OptionsMenuAction::ResetTab(self.active_tab));
     }
 
+    /// Emit the current volume of every bus so the mixer follows a reset
+    fn push_bus_volumes(&mut self) {
+        for (bus, _) in AUDIO_BUSES {
+            let volume = self.current_settings.audio.bus_volume(bus);
+            self.actions.push(OptionsMenuAction::BusVolumeChanged(
+                bus,
+                volume as f32 / 100.0,
+            ));
+        }
+    }
+
+    /// Set an audio bus volume (0-100) and apply it live
+    pub fn set_bus_volume(&mut self, bus: AudioCategory, volume: u32) {
+        let volume = volume.min(100);
+        *self.current_settings.audio.bus_volume_mut(bus) = volume;
+        self.has_changes = true;
+        self.actions
+            .push(OptionsMenuAction::BusVolumeChanged(bus, volume as f32 / 100.0));
+    }
+
     /// Check if currently rebinding
     pub fn is_rebinding(&self) -> bool {
         self.rebinding_action.is_some()
@@ -806,17 +902,36 @@ impl OptionsMenu {
         self.rebinding_action
     }
 
+    /// Get the conflict left by the last rebind, if any
+    pub fn rebind_conflict(&self) -> Option<&RebindConflict> {
+        self.rebind_conflict.as_ref()
+    }
+
     /// Start rebinding a key
     pub fn start_rebind(&mut self, action: KeyAction) {
         self.rebinding_action = Some(action);
+        self.rebind_conflict = None;
         self.actions.push(OptionsMenuAction::StartRebind(action));
     }
 
     /// Complete rebinding with a key
+    ///
+    /// The key is assigned even if another action uses it; the clash is
+    /// kept in [`Self::rebind_conflict`] so the menu can warn about it.
     pub fn complete_rebind(&mut self, key: impl Into<String>) {
         if let Some(action) = self.rebinding_action.take() {
-            self.current_settings.controls.set_binding(action, key);
+            let key = key.into();
+            let controls = &mut self.current_settings.controls;
+            self.rebind_conflict = controls
+                .find_conflict(action, &key)
+                .map(|existing| RebindConflict {
+                    action,
+                    existing,
+                    key: key.clone(),
+                });
+            controls.set_binding(action, key.clone());
             self.has_changes = true;
+            self.actions.push(OptionsMenuAction::KeyRebound(action, key));
         }
     }
 
@@ -1071,6 +1186,16 @@ impl OptionsMenu {
             self.has_changes = true;
         }
 
+        if ui
+            .checkbox(
+                &mut graphics.high_contrast_biomes,
+                "High-Contrast Biome Colors",
+            )
+            .changed()
+        {
+            self.has_changes = true;
+        }
+
         ui.add_space(8.0);
         ui.separator();
         ui.add_space(4.0);
@@ -1097,65 +1222,17 @@ impl OptionsMenu {
 
         ui.add_space(8.0);
 
-        ui.horizontal(|ui| {
-            ui.label("Master Volume:");
-            if ui
-                .add(egui::Slider::new(&mut audio.master_volume, 0..=100))
-                .changed()
-            {
-                self.has_changes = true;
-            }
-        });
-
-        ui.horizontal(|ui| {
-            ui.label("Music Volume:");
-            if ui
-                .add(egui::Slider::new(&mut audio.music_volume, 0..=100))
-                .changed()
-            {
-                self.has_changes = true;
-            }
-        });
-
-        ui.horizontal(|ui| {
-            ui.label("SFX Volume:");
-            if ui
-                .add(egui::Slider::new(&mut audio.sfx_volume, 0..=100))
-                .changed()
-            {
-                self.has_changes = true;
-            }
-        });
-
-        ui.horizontal(|ui| {
-            ui.label("Ambient Volume:");
-            if ui
-                .add(egui::Slider::new(&mut audio.ambient_volume, 0..=100))
-                .changed()
-            {
-                self.has_changes = true;
-            }
-        });
-
-        ui.horizontal(|ui| {
-            ui.label("Voice Volume:");
-            if ui
-                .add(egui::Slider::new(&mut audio.voice_volume, 0..=100))
-                .changed()
-            {
-                self.has_changes = true;
-            }
-        });
-
-        ui.horizontal(|ui| {
-            ui.label("UI Volume:");
-            if ui
-                .add(egui::Slider::new(&mut audio.ui_volume, 0..=100))
-                .changed()
-            {
-                self.has_changes = true;
-            }
-        });
+        for (bus, label) in AUDIO_BUSES {
+            ui.horizontal(|ui| {
+                ui.label(label);
+                let volume = audio.bus_volume_mut(bus);
+                if ui.add(egui::Slider::new(volume, 0..=100)).changed() {
+                    self.has_changes = true;
+                    self.actions
+                        .push(OptionsMenuAction::BusVolumeChanged(bus, *volume as f32 / 100.0));
+                }
+            });
+        }
 
         ui.add_space(8.0);
 
@@ -1224,6 +1301,7 @@ impl OptionsMenu {
 
         // Clone to avoid borrow issues
         let bindings = controls.key_bindings.clone();
+        let mut clicked = None;
         for binding in bindings {
             ui.horizontal(|ui| {
                 ui.label(binding.action.display_name());
@@ -1234,12 +1312,44 @@ impl OptionsMenu {
                                 .color(Color32::from_rgb(200, 200, 100)),
                         );
                     } else if ui.button(&binding.primary).clicked() {
-                        self.rebinding_action = Some(binding.action);
+                        clicked = Some(binding.action);
                     }
                 });
             });
         }
+        if let Some(action) = clicked {
+            self.start_rebind(action);
+        }
+
+        // Capture the next key press for the action being rebound
+        if self.rebinding_action.is_some() {
+            let pressed = ui.input(|i| {
+                i.events.iter().find_map(|event| match event {
+                    egui::Event::Key {
+                        key, pressed: true, ..
+                    } => Some(*key),
+                    _ => None,
+                })
+            });
+            match pressed {
+                Some(egui::Key::Escape) => self.cancel_rebind(),
+                Some(key) => self.complete_rebind(key.name()),
+                None => {},
+            }
+        }
+
+        if let Some(conflict) = &self.rebind_conflict {
+            ui.label(
+                egui::RichText::new(format!(
+                    "⚠ {} is also bound to {}",
+                    conflict.key,
+                    conflict.existing.display_name()
+                ))
+                .color(Color32::from_rgb(220, 160, 60)),
+            );
+        }
 
+        let controls = &mut self.current_settings.controls;
         ui.add_space(16.0);
 
         ui.horizontal(|ui| {
@@ -1646,6 +1756,29 @@ mod tests {
         assert!(!menu.is_visible());
     }
 
+    #[test]
+    fn test_options_menu_bus_volume_live() {
+        let mut menu = OptionsMenu::with_defaults();
+        let original_music = menu.settings().audio.music_volume;
+
+        menu.set_bus_volume(AudioCategory::Music, 40);
+        assert_eq!(menu.settings().audio.music_volume, 40);
+        assert!(menu.has_changes());
+        assert_eq!(
+            menu.drain_actions(),
+            vec![OptionsMenuAction::BusVolumeChanged(AudioCategory::Music, 0.4)]
+        );
+
+        // Cancelling restores the previous live volume
+        menu.cancel();
+        let actions = menu.drain_actions();
+        assert!(actions.contains(&OptionsMenuAction::BusVolumeChanged(
+            AudioCategory::Music,
+            original_music as f32 / 100.0
+        )));
+        assert_eq!(menu.settings().audio.music_volume, original_music);
+    }
+
     #[test]
     fn test_options_menu_reset_to_defaults() {
         let mut menu = OptionsMenu::with_defaults();
@@ -1668,6 +1801,37 @@ mod tests {
         assert_eq!(menu.settings().graphics.render_distance, 12);
     }
 
+    #[test]
+    fn test_options_menu_reset_audio_emits_bus_volumes() {
+        let mut menu = OptionsMenu::with_defaults();
+        menu.set_active_tab(OptionsTab::Audio);
+        menu.set_bus_volume(AudioCategory::Voice, 10);
+        menu.drain_actions();
+
+        menu.reset_current_tab();
+        let actions = menu.drain_actions();
+        let changed: Vec<_> = actions
+            .iter()
+            .filter_map(|a| match a {
+                OptionsMenuAction::BusVolumeChanged(bus, _) => Some(*bus),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(changed, AUDIO_BUSES.map(|(bus, _)| bus));
+        assert!(actions.contains(&OptionsMenuAction::BusVolumeChanged(
+            AudioCategory::Voice,
+            1.0
+        )));
+
+        menu.reset_to_defaults();
+        let resets = menu
+            .drain_actions()
+            .into_iter()
+            .filter(|a| matches!(a, OptionsMenuAction::BusVolumeChanged(..)))
+            .count();
+        assert_eq!(resets, AUDIO_BUSES.len());
+    }
+
     #[test]
     fn test_options_menu_rebinding() {
         let mut menu = OptionsMenu::with_defaults();
@@ -1688,6 +1852,45 @@ mod tests {
         assert_eq!(binding.primary, "X");
     }
 
+    #[test]
+    fn test_options_menu_rebind_writes_binding() {
+        let mut menu = OptionsMenu::with_defaults();
+        menu.start_rebind(KeyAction::Interact);
+        menu.complete_rebind("F");
+
+        let controls = &menu.settings().controls;
+        let primary = controls.get_binding(KeyAction::Interact).map(|b| b.primary.as_str());
+        assert_eq!(primary, Some("F"));
+        assert!(menu.rebind_conflict().is_none());
+        assert!(menu
+            .drain_actions()
+            .contains(&OptionsMenuAction::KeyRebound(KeyAction::Interact, "F".into())));
+    }
+
+    #[test]
+    fn test_options_menu_rebind_flags_duplicate() {
+        let mut menu = OptionsMenu::with_defaults();
+        menu.start_rebind(KeyAction::Jump);
+        menu.complete_rebind("W");
+
+        // Key is still assigned, but the clash is reported
+        let controls = &menu.settings().controls;
+        let primary = controls.get_binding(KeyAction::Jump).map(|b| b.primary.as_str());
+        assert_eq!(primary, Some("W"));
+        assert_eq!(
+            menu.rebind_conflict(),
+            Some(&RebindConflict {
+                action: KeyAction::Jump,
+                existing: KeyAction::MoveForward,
+                key: "W".to_string(),
+            })
+        );
+
+        // Starting a new rebind clears the warning
+        menu.start_rebind(KeyAction::Jump);
+        assert!(menu.rebind_conflict().is_none());
+    }
+
     #[test]
     fn test_options_menu_cancel_rebind() {
         let mut menu = OptionsMenu::with_defaults();
@@ -20,6 +20,17 @@ impl SaveSlotId {
     pub fn slot_number(&self) -> u32 {
         self.0 + 1
     }
+
+    /// Get the save file name used for this slot
+    pub fn slot_name(&self) -> String {
+        format!("slot_{}", self.slot_number())
+    }
+
+    /// Parse a save file name produced by [`Self::slot_name`]
+    pub fn from_slot_name(name: &str) -> Option<Self> {
+        let number: u32 = name.strip_prefix("slot_")?.parse().ok()?;
+        number.checked_sub(1).map(Self)
+    }
 }
 
 impl std::fmt::Display for SaveSlotId {
@@ -277,6 +288,8 @@ pub enum SaveMenuAction {
     LoadFromSlot(SaveSlotId),
     /// Request to show save management for a slot
     ManageSlot(SaveSlotId),
+    /// Request to delete a slot (the engine asks for confirmation)
+    DeleteSlot(SaveSlotId),
     /// Request to close the menu
     Close,
     /// Changed menu mode
@@ -474,6 +487,14 @@ impl SaveMenu {
         self.slots.iter().find(|s| s.is_most_recent)
     }
 
+    /// Get the first slot with nothing saved in it
+    pub fn first_empty_slot(&self) -> Option<SaveSlotId> {
+        self.slots
+            .iter()
+            .find(|s| s.state == SlotState::Empty)
+            .map(|s| s.id)
+    }
+
     /// Set current time for relative timestamps
     pub fn set_current_time(&mut self, time: u64) {
         self.current_time = time;
@@ -527,6 +548,26 @@ impl SaveMenu {
         }
     }
 
+    /// Save to the first empty slot
+    pub fn save_to_new_slot(&mut self) {
+        if let Some(id) = self.first_empty_slot() {
+            self.select_slot(id);
+            self.actions.push(SaveMenuAction::SaveToSlot(id));
+        }
+    }
+
+    /// Request deletion of the selected slot
+    pub fn delete_selected(&mut self) {
+        if let Some(id) = self.selected_slot {
+            if self
+                .get_slot(id)
+                .is_some_and(|slot| slot.state == SlotState::Occupied)
+            {
+                self.actions.push(SaveMenuAction::DeleteSlot(id));
+            }
+        }
+    }
+
     /// Request to start a new game
     pub fn new_game(&mut self) {
         self.actions.push(SaveMenuAction::NewGame);
@@ -779,6 +820,24 @@ impl SaveMenu {
                 }
             });
 
+            if self.mode == MenuMode::Save {
+                ui.add_enabled_ui(self.first_empty_slot().is_some(), |ui| {
+                    if ui.button("New Save").clicked() {
+                        self.save_to_new_slot();
+                    }
+                });
+            }
+
+            let can_delete = self.selected_slot.is_some_and(|id| {
+                self.get_slot(id)
+                    .is_some_and(|slot| slot.state == SlotState::Occupied)
+            });
+            ui.add_enabled_ui(can_delete, |ui| {
+                if ui.button("🗑 Delete").clicked() {
+                    self.delete_selected();
+                }
+            });
+
             ui.separator();
 
             if ui.button("Close").clicked() {
@@ -1030,6 +1089,49 @@ mod tests {
         assert!(actions.contains(&SaveMenuAction::LoadFromSlot(SaveSlotId::new(0))));
     }
 
+    #[test]
+    fn test_save_slot_name_roundtrip() {
+        let id = SaveSlotId::new(2);
+        assert_eq!(id.slot_name(), "slot_3");
+        assert_eq!(SaveSlotId::from_slot_name("slot_3"), Some(id));
+        assert_eq!(SaveSlotId::from_slot_name("slot_0"), None);
+        assert_eq!(SaveSlotId::from_slot_name("quicksave"), None);
+    }
+
+    #[test]
+    fn test_save_menu_save_to_new_slot() {
+        let mut menu = SaveMenu::with_defaults();
+        menu.set_slot(SaveSlotInfo::occupied(SaveSlotId::new(0), "Hero", 3, 100));
+        menu.open_save();
+        menu.drain_actions();
+
+        menu.save_to_new_slot();
+
+        let actions = menu.drain_actions();
+        assert!(actions.contains(&SaveMenuAction::SaveToSlot(SaveSlotId::new(1))));
+        assert_eq!(menu.selected_slot(), Some(SaveSlotId::new(1)));
+    }
+
+    #[test]
+    fn test_save_menu_delete_selected() {
+        let mut menu = SaveMenu::with_defaults();
+        menu.set_slot(SaveSlotInfo::occupied(SaveSlotId::new(0), "Hero", 3, 100));
+
+        // Empty slots have nothing to delete
+        menu.select_slot(SaveSlotId::new(1));
+        menu.drain_actions();
+        menu.delete_selected();
+        assert!(menu.drain_actions().is_empty());
+
+        menu.select_slot(SaveSlotId::new(0));
+        menu.drain_actions();
+        menu.delete_selected();
+        assert_eq!(
+            menu.drain_actions(),
+            vec![SaveMenuAction::DeleteSlot(SaveSlotId::new(0))]
+        );
+    }
+
     #[test]
     fn test_save_menu_set_current_time() {
         let mut menu = SaveMenu::with_defaults();
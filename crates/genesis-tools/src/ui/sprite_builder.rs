@@ -10,6 +10,7 @@
 //! - Export to TOML configuration files
 
 use egui::{Color32, ColorImage, RichText, TextureHandle, TextureOptions, Ui, Vec2};
+use genesis_common::SeededRng;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
@@ -696,6 +697,8 @@ pub struct AnimationPreviewState {
     pub last_frame_time: Instant,
     /// Direction for ping-pong playback (true = forward, false = backward).
     pub ping_pong_forward: bool,
+    /// Random number generator for random playback.
+    pub rng: SeededRng,
 }
 
 impl Default for AnimationPreviewState {
@@ -705,6 +708,7 @@ impl Default for AnimationPreviewState {
             current_frame: 0,
             last_frame_time: Instant::now(),
             ping_pong_forward: true,
+            rng: SeededRng::default(),
         }
     }
 }
@@ -1771,12 +1775,9 @@ impl SpriteBuilder {
                         }
                     }
                     PlaybackMode::Random => {
-                        use std::time::SystemTime;
-                        let seed = SystemTime::now()
-                            .duration_since(SystemTime::UNIX_EPOCH)
-                            .map(|d| d.as_nanos() as usize)
-                            .unwrap_or(0);
-                        self.preview_state.current_frame = seed % frames.len();
+                        let last = u32::try_from(frames.len() - 1).unwrap_or(u32::MAX);
+                        self.preview_state.current_frame =
+                            self.preview_state.rng.range_u32(0, last) as usize;
                     }
                 }
                 self.preview_state.last_frame_time = Instant::now();
@@ -5,6 +5,7 @@
 //! and per-biome color/texture editing.
 
 use egui::{Color32, RichText, Ui};
+use genesis_worldgen::{BiomeScale, GenFlags};
 
 /// Actions produced by the World Generation panel.
 #[derive(Debug, Clone)]
@@ -15,8 +16,8 @@ pub enum WorldGenAction {
         mc_version: i32,
         /// World seed.
         seed: u64,
-        /// Generator flags (e.g. LARGE_BIOMES).
-        flags: u32,
+        /// Generator flags.
+        flags: GenFlags,
         /// Biome generation scale (1, 4, 16, 64, 256).
         scale: i32,
         /// Y level for biome sampling.
@@ -129,10 +130,8 @@ pub struct WorldGenPanel {
     seed_str: String,
     /// Parsed seed value.
     seed: u64,
-    /// Large biomes flag.
-    large_biomes: bool,
-    /// Force ocean variants flag.
-    force_ocean_variants: bool,
+    /// Generator flags (large biomes, ocean variants).
+    flags: GenFlags,
     /// Generation scale (1, 4, 16, 64, 256).
     scale: i32,
     /// Y level for sampling.
@@ -181,8 +180,7 @@ impl WorldGenPanel {
             mc_versions,
             seed_str: "0".to_string(),
             seed: 0,
-            large_biomes: false,
-            force_ocean_variants: false,
+            flags: GenFlags::NONE,
             scale: 1,
             y_level: 64,
             tile_size: 1.0,
@@ -200,15 +198,14 @@ impl WorldGenPanel {
     }
 
     /// Update config from engine state (call when panel is opened or world regenerated).
-    pub fn sync_config(&mut self, mc_version: i32, seed: u64, flags: u32, scale: i32, y_level: i32, tile_size: f32) {
+    pub fn sync_config(&mut self, mc_version: i32, seed: u64, flags: GenFlags, scale: i32, y_level: i32, tile_size: f32) {
         // Find MC version index
         if let Some(idx) = self.mc_versions.iter().position(|v| v.id == mc_version) {
             self.mc_version_idx = idx;
         }
         self.seed = seed;
         self.seed_str = seed.to_string();
-        self.large_biomes = (flags & cubiomes_sys::LARGE_BIOMES) != 0;
-        self.force_ocean_variants = (flags & cubiomes_sys::FORCE_OCEAN_VARIANTS) != 0;
+        self.flags = flags;
         self.scale = scale;
         self.y_level = y_level;
         self.tile_size = tile_size;
@@ -219,14 +216,6 @@ impl WorldGenPanel {
         std::mem::take(&mut self.actions)
     }
 
-    /// Build current flags from UI state.
-    fn current_flags(&self) -> u32 {
-        let mut flags = 0u32;
-        if self.large_biomes { flags |= cubiomes_sys::LARGE_BIOMES; }
-        if self.force_ocean_variants { flags |= cubiomes_sys::FORCE_OCEAN_VARIANTS; }
-        flags
-    }
-
     /// Get current MC version ID.
     fn current_mc_version(&self) -> i32 {
         self.mc_versions.get(self.mc_version_idx)
@@ -286,6 +275,20 @@ impl WorldGenPanel {
 
                 ui.add_space(2.0);
 
+                // Scale presets (set scale and the large-biomes flag together)
+                ui.horizontal_wrapped(|ui| {
+                    ui.label(RichText::new("Preset:").color(Color32::GRAY));
+                    let current = BiomeScale::from_raw(self.scale, self.flags.large_biomes);
+                    for preset in BiomeScale::ALL {
+                        if ui.selectable_label(current == Some(preset), preset.label()).clicked() {
+                            self.scale = preset.raw();
+                            self.flags.large_biomes = preset.large_biomes();
+                        }
+                    }
+                });
+
+                ui.add_space(2.0);
+
                 // Scale selector
                 ui.horizontal(|ui| {
                     ui.label(RichText::new("Scale:").color(Color32::GRAY));
@@ -320,8 +323,8 @@ impl WorldGenPanel {
 
                 // Flags
                 ui.horizontal(|ui| {
-                    ui.checkbox(&mut self.large_biomes, RichText::new("Large Biomes").color(Color32::LIGHT_GRAY));
-                    ui.checkbox(&mut self.force_ocean_variants, RichText::new("Force Ocean Variants").color(Color32::LIGHT_GRAY));
+                    ui.checkbox(&mut self.flags.large_biomes, RichText::new("Large Biomes").color(Color32::LIGHT_GRAY));
+                    ui.checkbox(&mut self.flags.force_ocean_variants, RichText::new("Force Ocean Variants").color(Color32::LIGHT_GRAY));
                 });
 
                 ui.add_space(8.0);
@@ -340,7 +343,7 @@ impl WorldGenPanel {
                     self.actions.push(WorldGenAction::Regenerate {
                         mc_version: self.current_mc_version(),
                         seed: self.seed,
-                        flags: self.current_flags(),
+                        flags: self.flags,
                         scale: self.scale,
                         y_level: self.y_level,
                         tile_size: self.tile_size,
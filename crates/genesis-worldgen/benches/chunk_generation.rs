@@ -0,0 +1,44 @@
+//! Benchmarks serial against parallel chunk generation.
+//!
+//! Both arms clear the generator's chunk caches every iteration, so they
+//! measure generation rather than cache lookups. Run with `cargo bench -p genesis-worldgen --bench chunk_generation`.
+
+#![allow(missing_docs)]
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use genesis_worldgen::{WorldGenConfig, WorldGenerator};
+
+/// Chunks in one batch: a 4x4 block, roughly one row of a large render
+/// radius.
+fn batch() -> Vec<(i32, i32)> {
+    (0..4).flat_map(|y| (0..4).map(move |x| (x, y))).collect()
+}
+
+fn bench_chunk_batch(c: &mut Criterion) {
+    let mut generator = WorldGenerator::new(WorldGenConfig::default());
+    let coords = batch();
+    let mut group = c.benchmark_group("chunk_generation_16");
+
+    group.bench_function("serial", |b| {
+        b.iter(|| {
+            generator.clear_cache();
+            let chunks: Vec<_> = coords
+                .iter()
+                .map(|&(cx, cy)| generator.generate_chunk(cx, cy))
+                .collect();
+            black_box(chunks)
+        });
+    });
+
+    group.bench_function("parallel", |b| {
+        b.iter(|| {
+            generator.clear_cache();
+            black_box(generator.generate_chunks_parallel(&coords))
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_chunk_batch);
+criterion_main!(benches);
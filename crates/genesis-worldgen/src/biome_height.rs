@@ -6,8 +6,17 @@
 //!
 //! - **Elevation shading**: higher terrain is brighter, lower is darker
 //! - **Shadow casting**: taller neighbours cast shadows based on sun angle
+//! - **Water depth**: darker shading for deeper oceans and rivers
 
-use cubiomes_sys::*;
+use genesis_common::Biome;
+
+use crate::biome_map::BiomeTextureMap;
+use crate::generator::{WorldGenConfig, HEIGHT_SEA_LEVEL};
+
+/// The point on the [`biome_height`] scale that maps to
+/// [`HEIGHT_SEA_LEVEL`]: between the river tier and the beach tier, so
+/// every ocean and river biome sits below the default sea level.
+const SEA_TIER: f32 = 0.30;
 
 /// Returns a normalized height (0.0 = deep ocean floor, 1.0 = mountain peak)
 /// for the given biome ID, approximating Minecraft surface elevation.
@@ -22,118 +31,141 @@ use cubiomes_sys::*;
 /// - 0.65–0.80 Mountains, taller hills
 /// - 0.80–1.00 Extreme peaks
 pub fn biome_height(id: i32) -> f32 {
-    match id {
+    match Biome::from_id(id) {
         // Deep oceans — lowest
-        BIOME_DEEP_OCEAN => 0.05,
-        47 /*DEEP_WARM_OCEAN*/ => 0.05,
-        BIOME_DEEP_LUKEWARM_OCEAN => 0.05,
-        BIOME_DEEP_COLD_OCEAN => 0.06,
-        BIOME_DEEP_FROZEN_OCEAN => 0.06,
-        BIOME_DEEP_DARK => 0.02, // underground cavern
+        Biome::DEEP_OCEAN => 0.05,
+        Biome::DEEP_WARM_OCEAN => 0.05,
+        Biome::DEEP_LUKEWARM_OCEAN => 0.05,
+        Biome::DEEP_COLD_OCEAN => 0.06,
+        Biome::DEEP_FROZEN_OCEAN => 0.06,
+        Biome::DEEP_DARK => 0.02, // underground cavern
 
         // Shallow oceans
-        BIOME_OCEAN => 0.18,
-        BIOME_WARM_OCEAN => 0.20,
-        BIOME_LUKEWARM_OCEAN => 0.19,
-        BIOME_COLD_OCEAN => 0.17,
-        BIOME_FROZEN_OCEAN => 0.16,
+        Biome::OCEAN => 0.18,
+        Biome::WARM_OCEAN => 0.20,
+        Biome::LUKEWARM_OCEAN => 0.19,
+        Biome::COLD_OCEAN => 0.17,
+        Biome::FROZEN_OCEAN => 0.16,
 
         // Rivers, beaches, shores
-        BIOME_RIVER => 0.28,
-        BIOME_FROZEN_RIVER => 0.27,
-        BIOME_BEACH => 0.32,
-        BIOME_SNOWY_BEACH => 0.31,
-        BIOME_STONE_SHORE => 0.35,
-        BIOME_MUSHROOM_FIELD_SHORE => 0.33,
+        Biome::RIVER => 0.28,
+        Biome::FROZEN_RIVER => 0.27,
+        Biome::BEACH => 0.32,
+        Biome::SNOWY_BEACH => 0.31,
+        Biome::STONE_SHORE => 0.35,
+        Biome::MUSHROOM_FIELD_SHORE => 0.33,
 
         // Low-lying land
-        BIOME_SWAMP => 0.38,
-        134 /*SWAMP_HILLS*/ => 0.42,
-        BIOME_MANGROVE_SWAMP => 0.37,
-        BIOME_MUSHROOM_FIELDS => 0.40,
+        Biome::SWAMP => 0.38,
+        Biome::SWAMP_HILLS => 0.42,
+        Biome::MANGROVE_SWAMP => 0.37,
+        Biome::MUSHROOM_FIELDS => 0.40,
 
         // Sea-level land (plains, forests, deserts)
-        BIOME_PLAINS => 0.48,
-        BIOME_SUNFLOWER_PLAINS => 0.48,
-        BIOME_FOREST => 0.50,
-        BIOME_FLOWER_FOREST => 0.50,
-        BIOME_BIRCH_FOREST => 0.50,
-        BIOME_DARK_FOREST => 0.52,
-        BIOME_PALE_GARDEN => 0.50,
-        BIOME_TAIGA => 0.50,
-        BIOME_SNOWY_TAIGA => 0.50,
-        BIOME_SNOWY_TUNDRA => 0.47,
-        BIOME_DESERT => 0.48,
-        BIOME_JUNGLE => 0.50,
-        BIOME_JUNGLE_EDGE => 0.48,
-        BIOME_BAMBOO_JUNGLE => 0.50,
-        BIOME_SAVANNA => 0.48,
-        BIOME_CHERRY_GROVE => 0.52,
-        BIOME_MEADOW => 0.55,
-        BIOME_DRIPSTONE_CAVES => 0.30,
-        BIOME_LUSH_CAVES => 0.28,
-        BIOME_ICE_SPIKES => 0.52,
+        Biome::PLAINS => 0.48,
+        Biome::SUNFLOWER_PLAINS => 0.48,
+        Biome::FOREST => 0.50,
+        Biome::FLOWER_FOREST => 0.50,
+        Biome::BIRCH_FOREST => 0.50,
+        Biome::DARK_FOREST => 0.52,
+        Biome::PALE_GARDEN => 0.50,
+        Biome::TAIGA => 0.50,
+        Biome::SNOWY_TAIGA => 0.50,
+        Biome::SNOWY_TUNDRA => 0.47,
+        Biome::DESERT => 0.48,
+        Biome::JUNGLE => 0.50,
+        Biome::JUNGLE_EDGE => 0.48,
+        Biome::BAMBOO_JUNGLE => 0.50,
+        Biome::SAVANNA => 0.48,
+        Biome::CHERRY_GROVE => 0.52,
+        Biome::MEADOW => 0.55,
+        Biome::DRIPSTONE_CAVES => 0.30,
+        Biome::LUSH_CAVES => 0.28,
+        Biome::ICE_SPIKES => 0.52,
 
         // Hills
-        BIOME_DESERT_HILLS => 0.58,
-        BIOME_WOODED_HILLS => 0.58,
-        BIOME_TAIGA_HILLS => 0.57,
-        BIOME_JUNGLE_HILLS => 0.58,
-        BIOME_BIRCH_FOREST_HILLS => 0.57,
-        BIOME_SNOWY_TAIGA_HILLS => 0.57,
-        BIOME_GIANT_TREE_TAIGA => 0.53,
-        BIOME_GIANT_TREE_TAIGA_HILLS => 0.58,
-        BIOME_TALL_BIRCH_FOREST => 0.52,
-        BIOME_TALL_BIRCH_HILLS => 0.58,
-        BIOME_DARK_FOREST_HILLS => 0.58,
-        BIOME_GIANT_SPRUCE_TAIGA => 0.53,
-        BIOME_GIANT_SPRUCE_TAIGA_HILLS => 0.58,
-        BIOME_BAMBOO_JUNGLE_HILLS => 0.58,
-        BIOME_MOUNTAIN_EDGE => 0.60,
+        Biome::DESERT_HILLS => 0.58,
+        Biome::WOODED_HILLS => 0.58,
+        Biome::TAIGA_HILLS => 0.57,
+        Biome::JUNGLE_HILLS => 0.58,
+        Biome::BIRCH_FOREST_HILLS => 0.57,
+        Biome::SNOWY_TAIGA_HILLS => 0.57,
+        Biome::GIANT_TREE_TAIGA => 0.53,
+        Biome::GIANT_TREE_TAIGA_HILLS => 0.58,
+        Biome::TALL_BIRCH_FOREST => 0.52,
+        Biome::TALL_BIRCH_HILLS => 0.58,
+        Biome::DARK_FOREST_HILLS => 0.58,
+        Biome::GIANT_SPRUCE_TAIGA => 0.53,
+        Biome::GIANT_SPRUCE_TAIGA_HILLS => 0.58,
+        Biome::BAMBOO_JUNGLE_HILLS => 0.58,
+        Biome::MOUNTAIN_EDGE => 0.60,
 
         // Plateaus and elevated terrain
-        BIOME_SAVANNA_PLATEAU => 0.62,
-        BIOME_SHATTERED_SAVANNA => 0.65,
-        BIOME_SHATTERED_SAVANNA_PLATEAU => 0.68,
-        BIOME_BADLANDS => 0.60,
-        BIOME_BADLANDS_PLATEAU => 0.65,
-        BIOME_WOODED_BADLANDS_PLATEAU => 0.65,
-        BIOME_ERODED_BADLANDS => 0.62,
-        BIOME_MODIFIED_BADLANDS_PLATEAU => 0.67,
-        BIOME_MODIFIED_WOODED_BADLANDS_PLATEAU => 0.67,
-        BIOME_GROVE => 0.65,
-        BIOME_SNOWY_SLOPES => 0.70,
+        Biome::SAVANNA_PLATEAU => 0.62,
+        Biome::SHATTERED_SAVANNA => 0.65,
+        Biome::SHATTERED_SAVANNA_PLATEAU => 0.68,
+        Biome::BADLANDS => 0.60,
+        Biome::BADLANDS_PLATEAU => 0.65,
+        Biome::WOODED_BADLANDS_PLATEAU => 0.65,
+        Biome::ERODED_BADLANDS => 0.62,
+        Biome::MODIFIED_BADLANDS_PLATEAU => 0.67,
+        Biome::MODIFIED_WOODED_BADLANDS_PLATEAU => 0.67,
+        Biome::GROVE => 0.65,
+        Biome::SNOWY_SLOPES => 0.70,
 
         // Mountains
-        BIOME_MOUNTAINS => 0.72,
-        BIOME_WOODED_MOUNTAINS => 0.73,
-        BIOME_GRAVELLY_MOUNTAINS => 0.72,
-        BIOME_MODIFIED_GRAVELLY_MOUNTAINS => 0.74,
-        BIOME_SNOWY_MOUNTAINS => 0.72,
-        BIOME_TAIGA_MOUNTAINS => 0.68,
-        BIOME_SNOWY_TAIGA_MOUNTAINS => 0.70,
+        Biome::MOUNTAINS => 0.72,
+        Biome::WOODED_MOUNTAINS => 0.73,
+        Biome::GRAVELLY_MOUNTAINS => 0.72,
+        Biome::MODIFIED_GRAVELLY_MOUNTAINS => 0.74,
+        Biome::SNOWY_MOUNTAINS => 0.72,
+        Biome::TAIGA_MOUNTAINS => 0.68,
+        Biome::SNOWY_TAIGA_MOUNTAINS => 0.70,
 
         // Extreme peaks — tallest
-        BIOME_STONY_PEAKS => 0.85,
-        BIOME_JAGGED_PEAKS => 0.92,
-        BIOME_FROZEN_PEAKS => 0.90,
+        Biome::STONY_PEAKS => 0.85,
+        Biome::JAGGED_PEAKS => 0.92,
+        Biome::FROZEN_PEAKS => 0.90,
 
         // Nether / End — treat as mid-height
-        BIOME_NETHER_WASTES | BIOME_SOUL_SAND_VALLEY |
-        BIOME_CRIMSON_FOREST | BIOME_WARPED_FOREST |
-        BIOME_BASALT_DELTAS => 0.45,
-        BIOME_THE_END | BIOME_SMALL_END_ISLANDS |
-        BIOME_END_MIDLANDS | BIOME_END_HIGHLANDS |
-        BIOME_END_BARRENS => 0.50,
+        Biome::NETHER_WASTES
+        | Biome::SOUL_SAND_VALLEY
+        | Biome::CRIMSON_FOREST
+        | Biome::WARPED_FOREST
+        | Biome::BASALT_DELTAS => 0.45,
+        Biome::THE_END
+        | Biome::SMALL_END_ISLANDS
+        | Biome::END_MIDLANDS
+        | Biome::END_HIGHLANDS
+        | Biome::END_BARRENS => 0.50,
 
         // Unknown — default to sea-level land
         _ => 0.48,
     }
 }
 
+/// [`biome_height`] in blocks, on the same scale as the generator's
+/// height maps.
+pub fn biome_surface_height(id: i32) -> f32 {
+    biome_height(id) / SEA_TIER * HEIGHT_SEA_LEVEL
+}
+
+/// Blocks of water above a cell with floor at block height `height`,
+/// measured from `config`'s sea level.
+///
+/// Returns 0.0 for land biomes (even below sea level, e.g. caves) and for
+/// water cells whose floor sits at or above the surface.
+pub fn water_depth(biome_id: i32, height: f32, config: &WorldGenConfig) -> f32 {
+    if !BiomeTextureMap::group_of(biome_id).is_water() {
+        return 0.0;
+    }
+    config.water_depth(height)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use cubiomes_sys::*;
 
     #[test]
     fn test_height_range() {
@@ -152,4 +184,37 @@ mod tests {
             assert!(h >= 0.0 && h <= 1.0, "biome {} height {} out of bounds", id, h);
         }
     }
+
+    fn depth(id: i32) -> f32 {
+        water_depth(id, biome_surface_height(id), &WorldGenConfig::default())
+    }
+
+    #[test]
+    fn test_deep_ocean_deeper_than_shallow() {
+        assert!(depth(BIOME_DEEP_OCEAN) > depth(BIOME_OCEAN));
+        assert!(depth(BIOME_DEEP_COLD_OCEAN) > depth(BIOME_COLD_OCEAN));
+        assert!(depth(BIOME_OCEAN) > depth(BIOME_RIVER));
+        assert!(depth(BIOME_RIVER) > 0.0);
+    }
+
+    #[test]
+    fn test_land_has_no_water_depth() {
+        for id in [BIOME_PLAINS, BIOME_BEACH, BIOME_MOUNTAINS, BIOME_LUSH_CAVES] {
+            assert!(depth(id).abs() < f32::EPSILON, "biome {id}");
+        }
+        // Ocean floor above the surface is dry.
+        let config = WorldGenConfig::default();
+        assert!(water_depth(BIOME_OCEAN, HEIGHT_SEA_LEVEL + 1.0, &config).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_water_depth_follows_config_sea_level() {
+        let river = biome_surface_height(BIOME_RIVER);
+        let default = WorldGenConfig::default();
+        let low = WorldGenConfig::default().with_sea_level(river - 1.0);
+        assert!(water_depth(BIOME_RIVER, river, &default) > 0.0);
+        assert!(water_depth(BIOME_RIVER, river, &low).abs() < f32::EPSILON);
+        let ocean = biome_surface_height(BIOME_OCEAN);
+        assert!(water_depth(BIOME_OCEAN, ocean, &low) < depth(BIOME_OCEAN));
+    }
 }
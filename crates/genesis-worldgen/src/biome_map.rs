@@ -3,12 +3,161 @@
 //! Maps cubiomes BiomeIDs to texture file paths or fallback solid colors.
 //! Configuration is serialized to/from a TOML file for easy editing.
 
-use cubiomes_sys::*;
+use cubiomes_sys::{default_biome_colors, is_oceanic};
+use genesis_common::Biome;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::river_distance::is_river;
+
+/// Overworld biomes registered in every palette.
+const OVERWORLD_BIOMES: &[Biome] = &[
+    Biome::OCEAN,
+    Biome::PLAINS,
+    Biome::DESERT,
+    Biome::MOUNTAINS,
+    Biome::FOREST,
+    Biome::TAIGA,
+    Biome::SWAMP,
+    Biome::RIVER,
+    Biome::FROZEN_OCEAN,
+    Biome::FROZEN_RIVER,
+    Biome::SNOWY_TUNDRA,
+    Biome::SNOWY_MOUNTAINS,
+    Biome::MUSHROOM_FIELDS,
+    Biome::MUSHROOM_FIELD_SHORE,
+    Biome::BEACH,
+    Biome::DESERT_HILLS,
+    Biome::WOODED_HILLS,
+    Biome::TAIGA_HILLS,
+    Biome::MOUNTAIN_EDGE,
+    Biome::JUNGLE,
+    Biome::JUNGLE_HILLS,
+    Biome::JUNGLE_EDGE,
+    Biome::DEEP_OCEAN,
+    Biome::STONE_SHORE,
+    Biome::SNOWY_BEACH,
+    Biome::BIRCH_FOREST,
+    Biome::BIRCH_FOREST_HILLS,
+    Biome::DARK_FOREST,
+    Biome::SNOWY_TAIGA,
+    Biome::SNOWY_TAIGA_HILLS,
+    Biome::GIANT_TREE_TAIGA,
+    Biome::GIANT_TREE_TAIGA_HILLS,
+    Biome::WOODED_MOUNTAINS,
+    Biome::SAVANNA,
+    Biome::SAVANNA_PLATEAU,
+    Biome::BADLANDS,
+    Biome::WOODED_BADLANDS_PLATEAU,
+    Biome::BADLANDS_PLATEAU,
+    Biome::WARM_OCEAN,
+    Biome::LUKEWARM_OCEAN,
+    Biome::COLD_OCEAN,
+    Biome::DEEP_WARM_OCEAN,
+    Biome::DEEP_LUKEWARM_OCEAN,
+    Biome::DEEP_COLD_OCEAN,
+    Biome::DEEP_FROZEN_OCEAN,
+    Biome::BAMBOO_JUNGLE,
+    Biome::BAMBOO_JUNGLE_HILLS,
+    Biome::SOUL_SAND_VALLEY,
+    Biome::CRIMSON_FOREST,
+    Biome::WARPED_FOREST,
+    Biome::BASALT_DELTAS,
+    Biome::DRIPSTONE_CAVES,
+    Biome::LUSH_CAVES,
+    Biome::MEADOW,
+    Biome::GROVE,
+    Biome::SNOWY_SLOPES,
+    Biome::JAGGED_PEAKS,
+    Biome::FROZEN_PEAKS,
+    Biome::STONY_PEAKS,
+    Biome::DEEP_DARK,
+    Biome::MANGROVE_SWAMP,
+    Biome::CHERRY_GROVE,
+    Biome::PALE_GARDEN,
+    // Mutated variants
+    Biome::SUNFLOWER_PLAINS,
+    Biome::DESERT_LAKES,
+    Biome::GRAVELLY_MOUNTAINS,
+    Biome::FLOWER_FOREST,
+    Biome::TAIGA_MOUNTAINS,
+    Biome::SWAMP_HILLS,
+    Biome::ICE_SPIKES,
+    Biome::MODIFIED_JUNGLE,
+    Biome::MODIFIED_JUNGLE_EDGE,
+    Biome::TALL_BIRCH_FOREST,
+    Biome::TALL_BIRCH_HILLS,
+    Biome::DARK_FOREST_HILLS,
+    Biome::SNOWY_TAIGA_MOUNTAINS,
+    Biome::GIANT_SPRUCE_TAIGA,
+    Biome::GIANT_SPRUCE_TAIGA_HILLS,
+    Biome::MODIFIED_GRAVELLY_MOUNTAINS,
+    Biome::SHATTERED_SAVANNA,
+    Biome::SHATTERED_SAVANNA_PLATEAU,
+    Biome::ERODED_BADLANDS,
+    Biome::MODIFIED_WOODED_BADLANDS_PLATEAU,
+    Biome::MODIFIED_BADLANDS_PLATEAU,
+];
+
+/// Biome pairs that commonly border each other in generated worlds.
+///
+/// [`BiomeTextureMap::high_contrast`] keeps these pairs apart, and they are
+/// the pairs to check when judging how readable a palette is.
+pub const NEIGHBOR_BIOME_PAIRS: &[(Biome, Biome)] = &[
+    (Biome::PLAINS, Biome::FOREST),
+    (Biome::PLAINS, Biome::SUNFLOWER_PLAINS),
+    (Biome::PLAINS, Biome::MEADOW),
+    (Biome::FOREST, Biome::DARK_FOREST),
+    (Biome::FOREST, Biome::BIRCH_FOREST),
+    (Biome::FOREST, Biome::FLOWER_FOREST),
+    (Biome::FOREST, Biome::TAIGA),
+    (Biome::TAIGA, Biome::SNOWY_TAIGA),
+    (Biome::TAIGA, Biome::GIANT_TREE_TAIGA),
+    (Biome::SNOWY_TUNDRA, Biome::SNOWY_TAIGA),
+    (Biome::SNOWY_TUNDRA, Biome::ICE_SPIKES),
+    (Biome::OCEAN, Biome::DEEP_OCEAN),
+    (Biome::OCEAN, Biome::BEACH),
+    (Biome::OCEAN, Biome::RIVER),
+    (Biome::WARM_OCEAN, Biome::LUKEWARM_OCEAN),
+    (Biome::LUKEWARM_OCEAN, Biome::OCEAN),
+    (Biome::COLD_OCEAN, Biome::OCEAN),
+    (Biome::COLD_OCEAN, Biome::FROZEN_OCEAN),
+    (Biome::STONE_SHORE, Biome::OCEAN),
+    (Biome::RIVER, Biome::PLAINS),
+    (Biome::RIVER, Biome::FOREST),
+    (Biome::BEACH, Biome::DESERT),
+    (Biome::BEACH, Biome::PLAINS),
+    (Biome::DESERT, Biome::SAVANNA),
+    (Biome::DESERT, Biome::BADLANDS),
+    (Biome::SAVANNA, Biome::PLAINS),
+    (Biome::SAVANNA, Biome::SAVANNA_PLATEAU),
+    (Biome::JUNGLE, Biome::JUNGLE_EDGE),
+    (Biome::JUNGLE, Biome::BAMBOO_JUNGLE),
+    (Biome::JUNGLE, Biome::FOREST),
+    (Biome::SWAMP, Biome::FOREST),
+    (Biome::SWAMP, Biome::PLAINS),
+    (Biome::SWAMP, Biome::MANGROVE_SWAMP),
+    (Biome::BADLANDS, Biome::WOODED_BADLANDS_PLATEAU),
+    (Biome::BADLANDS, Biome::ERODED_BADLANDS),
+    (Biome::MOUNTAINS, Biome::WOODED_MOUNTAINS),
+    (Biome::MOUNTAINS, Biome::STONY_PEAKS),
+    (Biome::MEADOW, Biome::GROVE),
+    (Biome::GROVE, Biome::SNOWY_SLOPES),
+    (Biome::SNOWY_SLOPES, Biome::FROZEN_PEAKS),
+    (Biome::JAGGED_PEAKS, Biome::FROZEN_PEAKS),
+    (Biome::STONY_PEAKS, Biome::JAGGED_PEAKS),
+    (Biome::MUSHROOM_FIELDS, Biome::MUSHROOM_FIELD_SHORE),
+];
+
+/// Shades tried for each biome in the high-contrast palette: positive values
+/// blend toward white, negative toward black.
+const CONTRAST_SHADES: [f32; 5] = [0.0, -0.35, 0.45, -0.6, 0.7];
 
 /// How a biome is visually represented.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum BiomeVisual {
     /// Solid color fill (R, G, B).
     Color([u8; 3]),
@@ -16,8 +165,78 @@ pub enum BiomeVisual {
     Texture(String),
 }
 
+/// Broad category of biomes that share rendering effects.
+///
+/// All oceans share water rendering, all forests share tree density, and
+/// so on, so the renderer can key effects off the group rather than
+/// listing every biome ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BiomeGroup {
+    /// Oceans of every temperature and depth.
+    Ocean,
+    /// Rivers, including frozen ones.
+    River,
+    /// Beaches and shores.
+    Beach,
+    /// Open grassland and meadows.
+    Plains,
+    /// Temperate and coniferous forests.
+    Forest,
+    /// Jungles and bamboo jungles.
+    Jungle,
+    /// Swamps and mangroves.
+    Swamp,
+    /// Savannas.
+    Savanna,
+    /// Sandy deserts.
+    Desert,
+    /// Badlands (mesa).
+    Badlands,
+    /// Mountains, hills and peaks without snow cover.
+    Mountain,
+    /// Snow-covered land and frozen peaks.
+    Snowy,
+    /// Mushroom fields.
+    Mushroom,
+    /// Underground cave biomes.
+    Cave,
+    /// Anything not covered above (nether, end, unknown IDs).
+    Other,
+}
+
+impl BiomeGroup {
+    /// Whether this group is rendered as water.
+    pub fn is_water(self) -> bool {
+        matches!(self, Self::Ocean | Self::River)
+    }
+
+    /// Base color of this group in the high-contrast palette.
+    ///
+    /// Hues follow the Okabe-Ito color-blind safe set where a group's usual
+    /// color allows it.
+    pub fn contrast_color(self) -> [u8; 3] {
+        match self {
+            Self::Ocean => [0, 60, 160],
+            Self::River => [86, 180, 233],
+            Self::Beach => [240, 228, 66],
+            Self::Plains => [150, 210, 90],
+            Self::Forest => [0, 110, 50],
+            Self::Jungle => [0, 158, 115],
+            Self::Swamp => [110, 90, 160],
+            Self::Savanna => [230, 159, 0],
+            Self::Desert => [250, 235, 180],
+            Self::Badlands => [213, 94, 0],
+            Self::Mountain => [140, 130, 115],
+            Self::Snowy => [245, 245, 255],
+            Self::Mushroom => [204, 121, 167],
+            Self::Cave => [60, 40, 30],
+            Self::Other => [128, 128, 128],
+        }
+    }
+}
+
 /// Entry for a single biome in the texture map.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BiomeEntry {
     /// Display name of the biome.
     pub name: String,
@@ -28,12 +247,20 @@ pub struct BiomeEntry {
 }
 
 /// Maps biome IDs to their visual representation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BiomeTextureMap {
     /// Map of biome ID → entry.
     pub entries: HashMap<i32, BiomeEntry>,
 }
 
+/// On-disk layout of a [`BiomeTextureMap`]: TOML tables can't have integer
+/// keys, so entries are stored as a `[[biome]]` array sorted by ID.
+#[derive(Serialize, Deserialize)]
+struct BiomeMapFile {
+    #[serde(default, rename = "biome")]
+    biomes: Vec<BiomeEntry>,
+}
+
 impl Default for BiomeTextureMap {
     fn default() -> Self {
         Self::from_cubiomes_defaults()
@@ -52,116 +279,161 @@ impl BiomeTextureMap {
     /// Each biome gets its cubiomes-default color as a solid fill.
     pub fn from_cubiomes_defaults() -> Self {
         let colors = default_biome_colors();
-        let mut entries = HashMap::new();
-
-        // Register all known overworld biomes with their default colors
-        let biomes = [
-            (BIOME_OCEAN, "ocean"),
-            (BIOME_PLAINS, "plains"),
-            (BIOME_DESERT, "desert"),
-            (BIOME_MOUNTAINS, "mountains"),
-            (BIOME_FOREST, "forest"),
-            (BIOME_TAIGA, "taiga"),
-            (BIOME_SWAMP, "swamp"),
-            (BIOME_RIVER, "river"),
-            (BIOME_FROZEN_OCEAN, "frozen_ocean"),
-            (BIOME_FROZEN_RIVER, "frozen_river"),
-            (BIOME_SNOWY_TUNDRA, "snowy_tundra"),
-            (BIOME_SNOWY_MOUNTAINS, "snowy_mountains"),
-            (BIOME_MUSHROOM_FIELDS, "mushroom_fields"),
-            (BIOME_MUSHROOM_FIELD_SHORE, "mushroom_field_shore"),
-            (BIOME_BEACH, "beach"),
-            (BIOME_DESERT_HILLS, "desert_hills"),
-            (BIOME_WOODED_HILLS, "wooded_hills"),
-            (BIOME_TAIGA_HILLS, "taiga_hills"),
-            (BIOME_MOUNTAIN_EDGE, "mountain_edge"),
-            (BIOME_JUNGLE, "jungle"),
-            (BIOME_JUNGLE_HILLS, "jungle_hills"),
-            (BIOME_JUNGLE_EDGE, "jungle_edge"),
-            (BIOME_DEEP_OCEAN, "deep_ocean"),
-            (BIOME_STONE_SHORE, "stone_shore"),
-            (BIOME_SNOWY_BEACH, "snowy_beach"),
-            (BIOME_BIRCH_FOREST, "birch_forest"),
-            (BIOME_BIRCH_FOREST_HILLS, "birch_forest_hills"),
-            (BIOME_DARK_FOREST, "dark_forest"),
-            (BIOME_SNOWY_TAIGA, "snowy_taiga"),
-            (BIOME_SNOWY_TAIGA_HILLS, "snowy_taiga_hills"),
-            (BIOME_GIANT_TREE_TAIGA, "giant_tree_taiga"),
-            (BIOME_GIANT_TREE_TAIGA_HILLS, "giant_tree_taiga_hills"),
-            (BIOME_WOODED_MOUNTAINS, "wooded_mountains"),
-            (BIOME_SAVANNA, "savanna"),
-            (BIOME_SAVANNA_PLATEAU, "savanna_plateau"),
-            (BIOME_BADLANDS, "badlands"),
-            (BIOME_WOODED_BADLANDS_PLATEAU, "wooded_badlands_plateau"),
-            (BIOME_BADLANDS_PLATEAU, "badlands_plateau"),
-            (BIOME_WARM_OCEAN, "warm_ocean"),
-            (BIOME_LUKEWARM_OCEAN, "lukewarm_ocean"),
-            (BIOME_COLD_OCEAN, "cold_ocean"),
-            (BIOME_DEEP_WARM_OCEAN, "deep_warm_ocean"),
-            (BIOME_DEEP_LUKEWARM_OCEAN, "deep_lukewarm_ocean"),
-            (BIOME_DEEP_COLD_OCEAN, "deep_cold_ocean"),
-            (BIOME_DEEP_FROZEN_OCEAN, "deep_frozen_ocean"),
-            (BIOME_BAMBOO_JUNGLE, "bamboo_jungle"),
-            (BIOME_BAMBOO_JUNGLE_HILLS, "bamboo_jungle_hills"),
-            (BIOME_SOUL_SAND_VALLEY, "soul_sand_valley"),
-            (BIOME_CRIMSON_FOREST, "crimson_forest"),
-            (BIOME_WARPED_FOREST, "warped_forest"),
-            (BIOME_BASALT_DELTAS, "basalt_deltas"),
-            (BIOME_DRIPSTONE_CAVES, "dripstone_caves"),
-            (BIOME_LUSH_CAVES, "lush_caves"),
-            (BIOME_MEADOW, "meadow"),
-            (BIOME_GROVE, "grove"),
-            (BIOME_SNOWY_SLOPES, "snowy_slopes"),
-            (BIOME_JAGGED_PEAKS, "jagged_peaks"),
-            (BIOME_FROZEN_PEAKS, "frozen_peaks"),
-            (BIOME_STONY_PEAKS, "stony_peaks"),
-            (BIOME_DEEP_DARK, "deep_dark"),
-            (BIOME_MANGROVE_SWAMP, "mangrove_swamp"),
-            (BIOME_CHERRY_GROVE, "cherry_grove"),
-            (BIOME_PALE_GARDEN, "pale_garden"),
-            // Mutated variants
-            (BIOME_SUNFLOWER_PLAINS, "sunflower_plains"),
-            (BIOME_DESERT_LAKES, "desert_lakes"),
-            (BIOME_GRAVELLY_MOUNTAINS, "gravelly_mountains"),
-            (BIOME_FLOWER_FOREST, "flower_forest"),
-            (BIOME_TAIGA_MOUNTAINS, "taiga_mountains"),
-            (BIOME_SWAMP_HILLS, "swamp_hills"),
-            (BIOME_ICE_SPIKES, "ice_spikes"),
-            (BIOME_MODIFIED_JUNGLE, "modified_jungle"),
-            (BIOME_MODIFIED_JUNGLE_EDGE, "modified_jungle_edge"),
-            (BIOME_TALL_BIRCH_FOREST, "tall_birch_forest"),
-            (BIOME_TALL_BIRCH_HILLS, "tall_birch_hills"),
-            (BIOME_DARK_FOREST_HILLS, "dark_forest_hills"),
-            (BIOME_SNOWY_TAIGA_MOUNTAINS, "snowy_taiga_mountains"),
-            (BIOME_GIANT_SPRUCE_TAIGA, "giant_spruce_taiga"),
-            (BIOME_GIANT_SPRUCE_TAIGA_HILLS, "giant_spruce_taiga_hills"),
-            (BIOME_MODIFIED_GRAVELLY_MOUNTAINS, "modified_gravelly_mountains"),
-            (BIOME_SHATTERED_SAVANNA, "shattered_savanna"),
-            (BIOME_SHATTERED_SAVANNA_PLATEAU, "shattered_savanna_plateau"),
-            (BIOME_ERODED_BADLANDS, "eroded_badlands"),
-            (BIOME_MODIFIED_WOODED_BADLANDS_PLATEAU, "modified_wooded_badlands_plateau"),
-            (BIOME_MODIFIED_BADLANDS_PLATEAU, "modified_badlands_plateau"),
-        ];
-
-        for (id, name) in biomes {
-            let color = if (id as usize) < 256 {
+        Self::from_colors(|id| {
+            if (id as usize) < 256 {
                 colors[id as usize]
             } else {
                 [128, 128, 128]
-            };
-            entries.insert(
-                id,
-                BiomeEntry {
-                    name: name.to_string(),
-                    id,
-                    visual: BiomeVisual::Color(color),
-                },
-            );
+            }
+        })
+    }
+
+    /// Build a color-blind friendly map that keeps commonly adjacent biomes
+    /// far apart perceptually.
+    ///
+    /// Each biome starts from its group's hue in [`BiomeGroup::contrast_color`]
+    /// and then picks a lighter or darker shade of it, greedily maximizing the
+    /// smallest CIELAB distance to the already-colored biomes it borders in
+    /// [`NEIGHBOR_BIOME_PAIRS`]. Groups keep a recognizable hue (water stays
+    /// blue) while neighbors inside a group differ in lightness.
+    pub fn high_contrast() -> Self {
+        let mut colors: HashMap<Biome, [u8; 3]> = HashMap::new();
+        let neighbors_of = |biome: Biome| {
+            NEIGHBOR_BIOME_PAIRS.iter().filter_map(move |&(a, b)| {
+                if a == biome {
+                    Some(b)
+                } else if b == biome {
+                    Some(a)
+                } else {
+                    None
+                }
+            })
+        };
+
+        for &(a, b) in NEIGHBOR_BIOME_PAIRS {
+            for biome in [a, b] {
+                if colors.contains_key(&biome) {
+                    continue;
+                }
+                let base = Self::group_of(biome.id()).contrast_color();
+                let mut best = (f32::NEG_INFINITY, base);
+                for t in CONTRAST_SHADES {
+                    let candidate = shade(base, t);
+                    let nearest = neighbors_of(biome)
+                        .filter_map(|n| colors.get(&n))
+                        .map(|&c| color_distance(candidate, c))
+                        .fold(f32::INFINITY, f32::min);
+                    if nearest > best.0 {
+                        best = (nearest, candidate);
+                    }
+                }
+                colors.insert(biome, best.1);
+            }
         }
 
+        Self::from_colors(|id| {
+            colors
+                .get(&Biome::from_id(id))
+                .copied()
+                .unwrap_or_else(|| Self::group_of(id).contrast_color())
+        })
+    }
+
+    /// Registers every overworld biome with the color `color_of` picks.
+    fn from_colors(color_of: impl Fn(i32) -> [u8; 3]) -> Self {
+        let entries = OVERWORLD_BIOMES
+            .iter()
+            .map(|&biome| {
+                let id = biome.id();
+                let entry = BiomeEntry {
+                    name: biome.name().to_string(),
+                    id,
+                    visual: BiomeVisual::Color(color_of(id)),
+                };
+                (id, entry)
+            })
+            .collect();
         Self { entries }
     }
 
+    /// Get the render group for a biome ID.
+    pub fn group_of(biome_id: i32) -> BiomeGroup {
+        match Biome::from_id(biome_id) {
+            Biome::OCEAN
+            | Biome::DEEP_OCEAN
+            | Biome::FROZEN_OCEAN
+            | Biome::DEEP_FROZEN_OCEAN
+            | Biome::COLD_OCEAN
+            | Biome::DEEP_COLD_OCEAN
+            | Biome::LUKEWARM_OCEAN
+            | Biome::DEEP_LUKEWARM_OCEAN
+            | Biome::WARM_OCEAN
+            | Biome::DEEP_WARM_OCEAN => BiomeGroup::Ocean,
+            Biome::RIVER | Biome::FROZEN_RIVER => BiomeGroup::River,
+            Biome::BEACH
+            | Biome::SNOWY_BEACH
+            | Biome::STONE_SHORE
+            | Biome::MUSHROOM_FIELD_SHORE => BiomeGroup::Beach,
+            Biome::PLAINS | Biome::SUNFLOWER_PLAINS | Biome::MEADOW => BiomeGroup::Plains,
+            Biome::FOREST
+            | Biome::FLOWER_FOREST
+            | Biome::WOODED_HILLS
+            | Biome::BIRCH_FOREST
+            | Biome::BIRCH_FOREST_HILLS
+            | Biome::TALL_BIRCH_FOREST
+            | Biome::TALL_BIRCH_HILLS
+            | Biome::DARK_FOREST
+            | Biome::DARK_FOREST_HILLS
+            | Biome::PALE_GARDEN
+            | Biome::CHERRY_GROVE
+            | Biome::TAIGA
+            | Biome::TAIGA_HILLS
+            | Biome::TAIGA_MOUNTAINS
+            | Biome::GIANT_TREE_TAIGA
+            | Biome::GIANT_TREE_TAIGA_HILLS
+            | Biome::GIANT_SPRUCE_TAIGA
+            | Biome::GIANT_SPRUCE_TAIGA_HILLS => BiomeGroup::Forest,
+            Biome::JUNGLE
+            | Biome::JUNGLE_HILLS
+            | Biome::JUNGLE_EDGE
+            | Biome::MODIFIED_JUNGLE
+            | Biome::MODIFIED_JUNGLE_EDGE
+            | Biome::BAMBOO_JUNGLE
+            | Biome::BAMBOO_JUNGLE_HILLS => BiomeGroup::Jungle,
+            Biome::SWAMP | Biome::SWAMP_HILLS | Biome::MANGROVE_SWAMP => BiomeGroup::Swamp,
+            Biome::SAVANNA
+            | Biome::SAVANNA_PLATEAU
+            | Biome::SHATTERED_SAVANNA
+            | Biome::SHATTERED_SAVANNA_PLATEAU => BiomeGroup::Savanna,
+            Biome::DESERT | Biome::DESERT_HILLS | Biome::DESERT_LAKES => BiomeGroup::Desert,
+            Biome::BADLANDS
+            | Biome::BADLANDS_PLATEAU
+            | Biome::WOODED_BADLANDS_PLATEAU
+            | Biome::ERODED_BADLANDS
+            | Biome::MODIFIED_BADLANDS_PLATEAU
+            | Biome::MODIFIED_WOODED_BADLANDS_PLATEAU => BiomeGroup::Badlands,
+            Biome::MOUNTAINS
+            | Biome::MOUNTAIN_EDGE
+            | Biome::WOODED_MOUNTAINS
+            | Biome::GRAVELLY_MOUNTAINS
+            | Biome::MODIFIED_GRAVELLY_MOUNTAINS
+            | Biome::STONY_PEAKS => BiomeGroup::Mountain,
+            Biome::SNOWY_TUNDRA
+            | Biome::SNOWY_MOUNTAINS
+            | Biome::SNOWY_TAIGA
+            | Biome::SNOWY_TAIGA_HILLS
+            | Biome::SNOWY_TAIGA_MOUNTAINS
+            | Biome::ICE_SPIKES
+            | Biome::GROVE
+            | Biome::SNOWY_SLOPES
+            | Biome::JAGGED_PEAKS
+            | Biome::FROZEN_PEAKS => BiomeGroup::Snowy,
+            Biome::MUSHROOM_FIELDS => BiomeGroup::Mushroom,
+            Biome::DRIPSTONE_CAVES | Biome::LUSH_CAVES | Biome::DEEP_DARK => BiomeGroup::Cave,
+            _ => BiomeGroup::Other,
+        }
+    }
+
     /// Get the visual for a biome ID. Falls back to gray if not found.
     pub fn get_visual(&self, id: i32) -> &BiomeVisual {
         static FALLBACK: BiomeVisual = BiomeVisual::Color([128, 128, 128]);
@@ -209,6 +481,33 @@ impl BiomeTextureMap {
             .unwrap_or("unknown")
     }
 
+    /// Write every entry to a TOML file, creating parent directories.
+    pub fn save_toml(&self, path: &Path) -> io::Result<()> {
+        let file = BiomeMapFile {
+            biomes: self.sorted_entries().into_iter().cloned().collect(),
+        };
+        let contents = toml::to_string_pretty(&file)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)
+    }
+
+    /// Read a map written by [`Self::save_toml`].
+    ///
+    /// Biomes missing from the file keep their cubiomes default color, so
+    /// the result always covers every overworld biome.
+    pub fn load_toml(path: &Path) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        let file: BiomeMapFile =
+            toml::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let mut map = Self::from_cubiomes_defaults();
+        map.entries
+            .extend(file.biomes.into_iter().map(|entry| (entry.id, entry)));
+        Ok(map)
+    }
+
     /// Get all entries sorted by biome ID.
     pub fn sorted_entries(&self) -> Vec<&BiomeEntry> {
         let mut entries: Vec<_> = self.entries.values().collect();
@@ -245,4 +544,255 @@ impl BiomeTextureMap {
 
         rgba
     }
+
+    /// Whether the land cell at `(x, z)` of a row-major biome grid touches
+    /// an ocean cell on one of its four sides.
+    ///
+    /// Ocean and river cells are never coast. Neighbors outside the grid
+    /// count as land.
+    pub fn is_coast(biomes: &[i32], width: usize, x: usize, z: usize) -> bool {
+        Self::land_next_to(biomes, width, x, z, is_oceanic)
+    }
+
+    /// Whether the land cell at `(x, z)` touches a river cell on one of its
+    /// four sides, like [`Self::is_coast`].
+    pub fn is_riverbank(biomes: &[i32], width: usize, x: usize, z: usize) -> bool {
+        Self::land_next_to(biomes, width, x, z, is_river)
+    }
+
+    fn land_next_to(
+        biomes: &[i32],
+        width: usize,
+        x: usize,
+        z: usize,
+        water: fn(i32) -> bool,
+    ) -> bool {
+        let at = |x: usize, z: usize| (x < width).then(|| biomes.get(z * width + x)).flatten();
+        let Some(&biome) = at(x, z) else {
+            return false;
+        };
+        if is_oceanic(biome) || is_river(biome) {
+            return false;
+        }
+        let neighbors = [
+            x.checked_sub(1).map(|x| (x, z)),
+            Some((x + 1, z)),
+            z.checked_sub(1).map(|z| (x, z)),
+            Some((x, z + 1)),
+        ];
+        neighbors
+            .into_iter()
+            .flatten()
+            .filter_map(|(x, z)| at(x, z))
+            .any(|&b| water(b))
+    }
+}
+
+/// Blends a color toward white (`t > 0`) or black (`t < 0`).
+fn shade(color: [u8; 3], t: f32) -> [u8; 3] {
+    color.map(|c| {
+        let c = f32::from(c);
+        let target = if t >= 0.0 { 255.0 } else { 0.0 };
+        (c + (target - c) * t.abs()).round() as u8
+    })
+}
+
+/// Converts an sRGB color to CIELAB (D65 white point).
+pub fn rgb_to_lab(rgb: [u8; 3]) -> [f32; 3] {
+    let linear = rgb.map(|c| {
+        let c = f32::from(c) / 255.0;
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    });
+    let [r, g, b] = linear;
+    let x = (0.4124 * r + 0.3576 * g + 0.1805 * b) / 0.95047;
+    let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+    let z = (0.0193 * r + 0.1192 * g + 0.9505 * b) / 1.08883;
+    let f = |t: f32| {
+        if t > 216.0 / 24389.0 {
+            t.cbrt()
+        } else {
+            (24389.0 / 27.0 * t + 16.0) / 116.0
+        }
+    };
+    let (fx, fy, fz) = (f(x), f(y), f(z));
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// Perceptual distance between two sRGB colors (CIE76 ΔE).
+///
+/// Around 2.3 is just noticeable; 20 and up reads as clearly different.
+pub fn color_distance(a: [u8; 3], b: [u8; 3]) -> f32 {
+    let (a, b) = (rgb_to_lab(a), rgb_to_lab(b));
+    a.iter()
+        .zip(&b)
+        .map(|(p, q)| (p - q).powi(2))
+        .sum::<f32>()
+        .sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cubiomes_sys::*;
+
+    #[test]
+    fn test_group_of_representative_biomes() {
+        let cases = [
+            (BIOME_OCEAN, BiomeGroup::Ocean),
+            (BIOME_DEEP_FROZEN_OCEAN, BiomeGroup::Ocean),
+            (BIOME_WARM_OCEAN, BiomeGroup::Ocean),
+            (BIOME_RIVER, BiomeGroup::River),
+            (BIOME_FROZEN_RIVER, BiomeGroup::River),
+            (BIOME_BEACH, BiomeGroup::Beach),
+            (BIOME_PLAINS, BiomeGroup::Plains),
+            (BIOME_FOREST, BiomeGroup::Forest),
+            (BIOME_DARK_FOREST, BiomeGroup::Forest),
+            (BIOME_TAIGA, BiomeGroup::Forest),
+            (BIOME_BAMBOO_JUNGLE, BiomeGroup::Jungle),
+            (BIOME_MANGROVE_SWAMP, BiomeGroup::Swamp),
+            (BIOME_SAVANNA, BiomeGroup::Savanna),
+            (BIOME_DESERT, BiomeGroup::Desert),
+            (BIOME_ERODED_BADLANDS, BiomeGroup::Badlands),
+            (BIOME_STONY_PEAKS, BiomeGroup::Mountain),
+            (BIOME_FROZEN_PEAKS, BiomeGroup::Snowy),
+            (BIOME_SNOWY_TAIGA, BiomeGroup::Snowy),
+            (BIOME_MUSHROOM_FIELDS, BiomeGroup::Mushroom),
+            (BIOME_LUSH_CAVES, BiomeGroup::Cave),
+            (BIOME_NETHER_WASTES, BiomeGroup::Other),
+            (BIOME_NONE, BiomeGroup::Other),
+        ];
+        for (id, group) in cases {
+            assert_eq!(BiomeTextureMap::group_of(id), group, "biome {id}");
+        }
+    }
+
+    #[test]
+    fn test_default_names_match_biome() {
+        let map = BiomeTextureMap::from_cubiomes_defaults();
+        for entry in map.sorted_entries() {
+            assert_eq!(entry.name, Biome::from(entry.id).name());
+        }
+        assert_eq!(map.biome_name(BIOME_CHERRY_GROVE), "cherry_grove");
+    }
+
+    #[test]
+    fn test_biome_names_match_cubiomes() {
+        // Pre-1.18 names; later versions renamed some of these biomes
+        for biome in Biome::all().filter(|b| b.id() < BIOME_DRIPSTONE_CAVES) {
+            assert_eq!(biome_name(MC_1_17, biome.id()), biome.name());
+        }
+    }
+
+    #[test]
+    fn test_water_groups() {
+        assert!(BiomeTextureMap::group_of(BIOME_DEEP_OCEAN).is_water());
+        assert!(BiomeTextureMap::group_of(BIOME_RIVER).is_water());
+        assert!(!BiomeTextureMap::group_of(BIOME_BEACH).is_water());
+    }
+
+    /// Smallest and mean distance over the neighbor-prone pairs.
+    fn pair_distances(map: &BiomeTextureMap) -> (f32, f32) {
+        let distances: Vec<f32> = NEIGHBOR_BIOME_PAIRS
+            .iter()
+            .map(|&(a, b)| color_distance(map.get_color(a.id()), map.get_color(b.id())))
+            .collect();
+        let min = distances.iter().copied().fold(f32::INFINITY, f32::min);
+        (min, distances.iter().sum::<f32>() / distances.len() as f32)
+    }
+
+    #[test]
+    fn test_high_contrast_separates_neighbors_more_than_default() {
+        let (default_min, default_mean) =
+            pair_distances(&BiomeTextureMap::from_cubiomes_defaults());
+        let high_contrast = BiomeTextureMap::high_contrast();
+        let (min, mean) = pair_distances(&high_contrast);
+        assert!(min > default_min, "{min} <= {default_min}");
+        assert!(mean > default_mean, "{mean} <= {default_mean}");
+        // Every neighbor pair is clearly distinguishable
+        assert!(min > 30.0, "closest neighbors only {min} apart");
+        assert_eq!(
+            high_contrast.entries.len(),
+            BiomeTextureMap::from_cubiomes_defaults().entries.len()
+        );
+    }
+
+    #[test]
+    fn test_coast_and_riverbank_cells() {
+        const O: i32 = BIOME_OCEAN;
+        const D: i32 = BIOME_DEEP_OCEAN;
+        const R: i32 = BIOME_RIVER;
+        const P: i32 = BIOME_PLAINS;
+        const F: i32 = BIOME_FOREST;
+        // Ocean on the left, a river down column 4
+        #[rustfmt::skip]
+        let grid = [
+            D, O, P, F, R, P,
+            D, O, F, P, R, F,
+            O, P, P, F, R, P,
+            O, O, O, P, R, F,
+        ];
+        let w = 6;
+        let coast: Vec<(usize, usize)> = (0..4)
+            .flat_map(|z| (0..w).map(move |x| (x, z)))
+            .filter(|&(x, z)| BiomeTextureMap::is_coast(&grid, w, x, z))
+            .collect();
+        assert_eq!(coast, vec![(2, 0), (2, 1), (1, 2), (2, 2), (3, 3)]);
+
+        let banks: Vec<(usize, usize)> = (0..4)
+            .flat_map(|z| (0..w).map(move |x| (x, z)))
+            .filter(|&(x, z)| BiomeTextureMap::is_riverbank(&grid, w, x, z))
+            .collect();
+        let expected: Vec<_> = (0..4).flat_map(|z| [(3, z), (5, z)]).collect();
+        assert_eq!(banks, expected);
+
+        // Edge land cells see the outside as dry land; out of range is false
+        assert!(!BiomeTextureMap::is_coast(&grid, w, 5, 0));
+        assert!(!BiomeTextureMap::is_coast(&grid, w, 6, 0));
+        assert!(!BiomeTextureMap::is_coast(&grid, w, 0, 4));
+    }
+
+    #[test]
+    fn test_toml_round_trip_keeps_edits() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("colors").join("biomes.toml");
+
+        let mut map = BiomeTextureMap::from_cubiomes_defaults();
+        map.set_color(BIOME_PLAINS, [1, 2, 3]);
+        map.set_color(BIOME_OCEAN, [0, 40, 200]);
+        map.set_texture(BIOME_DESERT, "textures/biomes/desert.png".to_string());
+        map.save_toml(&path).expect("save");
+        assert_eq!(BiomeTextureMap::load_toml(&path).expect("load"), map);
+
+        // Missing biomes fall back to the defaults
+        std::fs::write(
+            &path,
+            "[[biome]]\nname = \"plains\"\nid = 1\nvisual = { Color = [9, 9, 9] }\n",
+        )
+        .expect("write");
+        let partial = BiomeTextureMap::load_toml(&path).expect("load partial");
+        let defaults = BiomeTextureMap::from_cubiomes_defaults();
+        assert_eq!(partial.entries.len(), defaults.entries.len());
+        assert_eq!(partial.get_color(BIOME_PLAINS), [9, 9, 9]);
+        assert_eq!(
+            partial.entries[&BIOME_FOREST],
+            defaults.entries[&BIOME_FOREST]
+        );
+
+        std::fs::write(&path, "biome = 3").expect("write");
+        let err = BiomeTextureMap::load_toml(&path).expect_err("invalid file");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_color_distance() {
+        assert!(color_distance([10, 20, 30], [10, 20, 30]).abs() < 1e-4);
+        let black_white = color_distance([0, 0, 0], [255, 255, 255]);
+        assert!((black_white - 100.0).abs() < 0.1, "{black_white}");
+        let lab = rgb_to_lab([255, 0, 0]);
+        assert!((lab[0] - 53.24).abs() < 0.1 && (lab[1] - 80.09).abs() < 0.2);
+    }
 }
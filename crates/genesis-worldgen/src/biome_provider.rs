@@ -0,0 +1,166 @@
+//! Abstraction over where biome and height data comes from.
+//!
+//! Streaming terrain only needs biomes and heights for rectangular regions,
+//! so it talks to a [`BiomeProvider`] rather than to [`WorldGenerator`]
+//! directly. That lets tests and alternative worlds (flat worlds, data read
+//! back from saves) feed the same chunk path as cubiomes generation.
+
+use crate::chunk_source::ChunkData;
+use crate::generator::{BiomeChunk, WorldGenerator};
+
+/// Cells along each side of a game chunk.
+pub const CHUNK_CELLS: i32 = 16;
+
+/// Source of biome IDs and surface heights.
+///
+/// Region coordinates are cells at the provider's own scale, matching
+/// [`WorldGenerator::generate_region`].
+pub trait BiomeProvider {
+    /// Biome ID of the cell at `(x, z)`.
+    fn biome_at(&self, x: i32, z: i32) -> i32;
+
+    /// Biomes for a `width × height` region starting at cell `(x, z)`.
+    fn region_biomes(&self, x: i32, z: i32, width: i32, height: i32) -> BiomeChunk;
+
+    /// Surface heights for the same region as [`Self::region_biomes`],
+    /// indexed `[z * width + x]`.
+    fn heights(&self, x: i32, z: i32, width: i32, height: i32) -> Vec<f32>;
+
+    /// Biome and height data for the game chunk at `(chunk_x, chunk_y)`.
+    fn chunk(&self, chunk_x: i32, chunk_y: i32) -> ChunkData {
+        let (x, z) = (chunk_x * CHUNK_CELLS, chunk_y * CHUNK_CELLS);
+        ChunkData {
+            biomes: self.region_biomes(x, z, CHUNK_CELLS, CHUNK_CELLS),
+            heights: self.heights(x, z, CHUNK_CELLS, CHUNK_CELLS),
+        }
+    }
+
+    /// [`Self::chunk`] for many game chunks, in the same order.
+    fn chunks(&self, coords: &[(i32, i32)]) -> Vec<ChunkData> {
+        coords.iter().map(|&(cx, cy)| self.chunk(cx, cy)).collect()
+    }
+}
+
+impl BiomeProvider for WorldGenerator {
+    fn biome_at(&self, x: i32, z: i32) -> i32 {
+        WorldGenerator::biome_at(self, x, z)
+    }
+
+    fn region_biomes(&self, x: i32, z: i32, width: i32, height: i32) -> BiomeChunk {
+        self.generate_region(x, z, width, height)
+    }
+
+    /// Block-level heights at scale 1, otherwise 1:4 approximate heights.
+    fn heights(&self, x: i32, z: i32, width: i32, height: i32) -> Vec<f32> {
+        let heights = if self.config().scale == 1 {
+            self.generate_block_heights(x, z, width, height)
+        } else {
+            self.generate_heights(x, z, width, height)
+        };
+        self.apply_amplified(heights)
+    }
+
+    /// Served from the chunk cache when the chunk was generated or warmed up.
+    fn chunk(&self, chunk_x: i32, chunk_y: i32) -> ChunkData {
+        self.chunk_data(chunk_x, chunk_y)
+    }
+
+    /// Generated on worker threads; see [`WorldGenerator::chunk_data_parallel`].
+    fn chunks(&self, coords: &[(i32, i32)]) -> Vec<ChunkData> {
+        self.chunk_data_parallel(coords)
+    }
+}
+
+/// Provider for a featureless world: one biome at one height everywhere.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlatBiomeProvider {
+    /// Biome ID of every cell.
+    pub biome: i32,
+    /// Surface height of every cell.
+    pub height: f32,
+    /// Blocks per cell reported in generated chunks.
+    pub scale: i32,
+}
+
+impl FlatBiomeProvider {
+    /// Create a flat world of `biome` at surface `height`, at block scale.
+    pub fn new(biome: i32, height: f32) -> Self {
+        Self {
+            biome,
+            height,
+            scale: 1,
+        }
+    }
+
+    /// Set the blocks per cell reported in generated chunks.
+    #[must_use]
+    pub fn with_scale(mut self, scale: i32) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+}
+
+impl BiomeProvider for FlatBiomeProvider {
+    fn biome_at(&self, _x: i32, _z: i32) -> i32 {
+        self.biome
+    }
+
+    fn region_biomes(&self, x: i32, z: i32, width: i32, height: i32) -> BiomeChunk {
+        let cells = (width.max(0) * height.max(0)) as usize;
+        BiomeChunk {
+            biomes: vec![self.biome; cells],
+            width,
+            height,
+            origin_x: x,
+            origin_z: z,
+            scale: self.scale,
+        }
+    }
+
+    fn heights(&self, _x: i32, _z: i32, width: i32, height: i32) -> Vec<f32> {
+        vec![self.height; (width.max(0) * height.max(0)) as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_source::{ChunkSource, GeneratedSource};
+    use cubiomes_sys::BIOME_PLAINS;
+
+    #[test]
+    fn test_flat_provider_region_is_uniform() {
+        let flat = FlatBiomeProvider::new(BIOME_PLAINS, 70.0).with_scale(4);
+        assert_eq!(flat.biome_at(-123, 456), BIOME_PLAINS);
+
+        let region = flat.region_biomes(-8, 3, 5, 7);
+        assert_eq!((region.width, region.height), (5, 7));
+        assert_eq!((region.origin_x, region.origin_z, region.scale), (-8, 3, 4));
+        assert_eq!(region.biomes.len(), 35);
+        assert!(region.biomes.iter().all(|&b| b == BIOME_PLAINS));
+        assert!(flat.heights(0, 0, 0, 4).is_empty());
+    }
+
+    #[test]
+    fn test_generated_source_streams_from_flat_provider() {
+        let flat = FlatBiomeProvider::new(BIOME_PLAINS, 64.0);
+        let mut source = GeneratedSource::new(&flat);
+
+        for (cx, cy) in [(0, 0), (3, -2), (-17, 40)] {
+            let chunk = source
+                .load_chunk(cx, cy)
+                .expect("generated chunks always load");
+            assert_eq!(chunk, flat.chunk(cx, cy));
+            assert_eq!(
+                (chunk.biomes.origin_x, chunk.biomes.origin_z),
+                (cx * CHUNK_CELLS, cy * CHUNK_CELLS)
+            );
+            assert_eq!(chunk.biomes.biomes, vec![BIOME_PLAINS; 256]);
+            assert_eq!(chunk.heights.len(), 256);
+            assert!(chunk
+                .heights
+                .iter()
+                .all(|&h| (h - 64.0).abs() < f32::EPSILON));
+        }
+    }
+}
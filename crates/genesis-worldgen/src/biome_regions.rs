@@ -0,0 +1,196 @@
+//! Connected biome regions within a generated chunk.
+//!
+//! Biome-gated content (a quest that only runs in the desert, a spawn table
+//! for one swamp) needs to know where one contiguous patch of a biome starts
+//! and ends. This labels the biome grid into 4-connected regions so gameplay
+//! can ask which region a cell belongs to.
+
+use crate::generator::BiomeChunk;
+
+/// A contiguous patch of a single biome, in the chunk's local cell coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BiomeRegion {
+    /// Index of this region within the labeling.
+    pub id: usize,
+    /// Biome ID shared by every cell in the region.
+    pub biome: i32,
+    /// Minimum X (column) covered, inclusive.
+    pub min_x: i32,
+    /// Minimum Z (row) covered, inclusive.
+    pub min_z: i32,
+    /// Maximum X (column) covered, inclusive.
+    pub max_x: i32,
+    /// Maximum Z (row) covered, inclusive.
+    pub max_z: i32,
+    /// Number of cells in the region.
+    pub cell_count: usize,
+}
+
+impl BiomeRegion {
+    /// Width of the bounding box in cells.
+    pub fn width(&self) -> i32 {
+        self.max_x - self.min_x + 1
+    }
+
+    /// Height of the bounding box in cells.
+    pub fn height(&self) -> i32 {
+        self.max_z - self.min_z + 1
+    }
+
+    /// Whether `(x, z)` lies inside the bounding box.
+    ///
+    /// Regions can be concave, so use [`BiomeRegionMap::region_at`] for an
+    /// exact membership test.
+    pub fn bounds_contain(&self, x: i32, z: i32) -> bool {
+        x >= self.min_x && x <= self.max_x && z >= self.min_z && z <= self.max_z
+    }
+}
+
+/// Region labeling for a chunk: the regions plus a per-cell region index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BiomeRegionMap {
+    /// Regions in scan order (top-left first).
+    pub regions: Vec<BiomeRegion>,
+    /// Region index per cell, indexed as `[z * width + x]`.
+    labels: Vec<usize>,
+    width: i32,
+    height: i32,
+}
+
+impl BiomeRegionMap {
+    /// Label the chunk's biome grid into 4-connected regions.
+    pub fn from_chunk(chunk: &BiomeChunk) -> Self {
+        let width = chunk.width.max(0);
+        let height = chunk.height.max(0);
+        let len = (width * height) as usize;
+        let mut labels = vec![usize::MAX; len];
+        let mut regions = Vec::new();
+        let mut stack = Vec::new();
+
+        for start in 0..len.min(chunk.biomes.len()) {
+            if labels[start] != usize::MAX {
+                continue;
+            }
+            let id = regions.len();
+            let biome = chunk.biomes[start];
+            let (sx, sz) = ((start as i32) % width, (start as i32) / width);
+            let mut region = BiomeRegion {
+                id,
+                biome,
+                min_x: sx,
+                min_z: sz,
+                max_x: sx,
+                max_z: sz,
+                cell_count: 0,
+            };
+
+            labels[start] = id;
+            stack.push((sx, sz));
+            while let Some((x, z)) = stack.pop() {
+                region.cell_count += 1;
+                region.min_x = region.min_x.min(x);
+                region.min_z = region.min_z.min(z);
+                region.max_x = region.max_x.max(x);
+                region.max_z = region.max_z.max(z);
+
+                for (nx, nz) in [(x - 1, z), (x + 1, z), (x, z - 1), (x, z + 1)] {
+                    if nx < 0 || nx >= width || nz < 0 || nz >= height {
+                        continue;
+                    }
+                    let idx = (nz * width + nx) as usize;
+                    if labels[idx] == usize::MAX && chunk.biomes.get(idx) == Some(&biome) {
+                        labels[idx] = id;
+                        stack.push((nx, nz));
+                    }
+                }
+            }
+            regions.push(region);
+        }
+
+        Self {
+            regions,
+            labels,
+            width,
+            height,
+        }
+    }
+
+    /// Region containing local cell `(x, z)`, if in bounds.
+    pub fn region_at(&self, x: i32, z: i32) -> Option<&BiomeRegion> {
+        if x < 0 || x >= self.width || z < 0 || z >= self.height {
+            return None;
+        }
+        self.labels
+            .get((z * self.width + x) as usize)
+            .and_then(|&id| self.regions.get(id))
+    }
+}
+
+/// Split a chunk's biome grid into connected regions with bounds and cell counts.
+pub fn biome_regions(chunk: &BiomeChunk) -> Vec<BiomeRegion> {
+    BiomeRegionMap::from_chunk(chunk).regions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 6x4 grid with a desert blob on the left and a forest blob on the right.
+    fn two_blob_chunk() -> BiomeChunk {
+        const D: i32 = 2;
+        const F: i32 = 4;
+        #[rustfmt::skip]
+        let biomes = vec![
+            D, D, D, F, F, F,
+            D, D, F, F, F, F,
+            D, D, F, F, F, F,
+            D, F, F, F, F, F,
+        ];
+        BiomeChunk {
+            biomes,
+            width: 6,
+            height: 4,
+            origin_x: 0,
+            origin_z: 0,
+            scale: 4,
+        }
+    }
+
+    #[test]
+    fn test_two_blobs_produce_two_regions() {
+        let regions = biome_regions(&two_blob_chunk());
+        assert_eq!(regions.len(), 2);
+
+        let desert = &regions[0];
+        assert_eq!(desert.biome, 2);
+        assert_eq!((desert.min_x, desert.min_z), (0, 0));
+        assert_eq!((desert.max_x, desert.max_z), (2, 3));
+        assert_eq!(desert.cell_count, 8);
+
+        let forest = &regions[1];
+        assert_eq!(forest.biome, 4);
+        assert_eq!((forest.min_x, forest.min_z), (1, 0));
+        assert_eq!((forest.max_x, forest.max_z), (5, 3));
+        assert_eq!(forest.cell_count, 16);
+    }
+
+    #[test]
+    fn test_disjoint_patches_of_same_biome_are_separate() {
+        let chunk = BiomeChunk {
+            biomes: vec![1, 0, 1],
+            width: 3,
+            height: 1,
+            origin_x: 0,
+            origin_z: 0,
+            scale: 4,
+        };
+        let map = BiomeRegionMap::from_chunk(&chunk);
+        assert_eq!(map.regions.len(), 3);
+        assert_ne!(
+            map.region_at(0, 0).map(|r| r.id),
+            map.region_at(2, 0).map(|r| r.id)
+        );
+        assert_eq!(map.region_at(1, 0).map(|r| r.biome), Some(0));
+        assert!(map.region_at(3, 0).is_none());
+    }
+}
@@ -0,0 +1,132 @@
+//! Deterministic per-chunk feature rolls.
+//!
+//! Uses Minecraft's slime-chunk formula: the world seed and chunk
+//! coordinates are mixed with a per-feature salt and fed to Java's
+//! `java.util.Random`, and the chunk qualifies when `nextInt(10) == 0`.
+//! With [`SLIME_CHUNK_SEED`] the result matches Minecraft's slime chunks;
+//! other salts give independent 1-in-10 layouts for gameplay features.
+
+/// Salt Minecraft uses for slime chunks.
+pub const SLIME_CHUNK_SEED: u64 = 0x3ad8_025f;
+
+/// Minimal port of `java.util.Random` (48-bit LCG).
+struct JavaRandom {
+    state: u64,
+}
+
+impl JavaRandom {
+    const MULTIPLIER: u64 = 0x5_DEEC_E66D;
+    const ADDEND: u64 = 0xB;
+    const MASK: u64 = (1 << 48) - 1;
+
+    fn new(seed: u64) -> Self {
+        Self {
+            state: (seed ^ Self::MULTIPLIER) & Self::MASK,
+        }
+    }
+
+    fn next(&mut self, bits: u32) -> i32 {
+        self.state = self
+            .state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(Self::ADDEND)
+            & Self::MASK;
+        (self.state >> (48 - bits)) as i32
+    }
+
+    /// `nextInt(bound)` for a positive, non-power-of-two `bound`.
+    fn next_int(&mut self, bound: i32) -> i32 {
+        loop {
+            let bits = self.next(31);
+            let val = bits % bound;
+            if bits.wrapping_sub(val).wrapping_add(bound - 1) >= 0 {
+                return val;
+            }
+        }
+    }
+}
+
+/// Whether chunk `(chunk_x, chunk_z)` carries the feature salted by
+/// `feature_seed` in the world with `world_seed`.
+pub fn chunk_has_feature(world_seed: u64, chunk_x: i32, chunk_z: i32, feature_seed: u64) -> bool {
+    // Mirrors the Java expression, including its int overflow and casts.
+    let (x, z) = (chunk_x, chunk_z);
+    let mixed = (world_seed as i64)
+        .wrapping_add(i64::from(x.wrapping_mul(x).wrapping_mul(0x4c_1906)))
+        .wrapping_add(i64::from(x.wrapping_mul(0x5a_c0db)))
+        .wrapping_add(i64::from(z.wrapping_mul(z)).wrapping_mul(0x43_07a7))
+        .wrapping_add(i64::from(z.wrapping_mul(0x5_f24f)));
+    JavaRandom::new(mixed as u64 ^ feature_seed).next_int(10) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Slime chunks in the 10×10 chunks from the origin, from Java.
+    const SEED_12345_SLIME: [(i32, i32); 9] = [
+        (0, 7),
+        (0, 9),
+        (3, 0),
+        (3, 5),
+        (4, 1),
+        (6, 7),
+        (8, 0),
+        (9, 0),
+        (9, 5),
+    ];
+
+    #[test]
+    fn test_matches_minecraft_slime_chunks() {
+        for x in 0..10 {
+            for z in 0..10 {
+                assert_eq!(
+                    chunk_has_feature(12345, x, z, SLIME_CHUNK_SEED),
+                    SEED_12345_SLIME.contains(&(x, z)),
+                    "chunk ({x}, {z})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_negative_seed_and_far_chunks() {
+        let seed = -4_172_144_997_902_289_642_i64 as u64;
+        for (x, z) in [
+            (1, 1),
+            (4, 3),
+            (9, 9),
+            (100_000, -50_006),
+            (100_001, -50_013),
+        ] {
+            assert!(
+                chunk_has_feature(seed, x, z, SLIME_CHUNK_SEED),
+                "chunk ({x}, {z})"
+            );
+        }
+        for (x, z) in [(0, 0), (5, 5), (100_000, -50_000)] {
+            assert!(
+                !chunk_has_feature(seed, x, z, SLIME_CHUNK_SEED),
+                "chunk ({x}, {z})"
+            );
+        }
+        // Same chunk under another seed is a slime chunk
+        assert!(chunk_has_feature(12345, 100_000, -50_000, SLIME_CHUNK_SEED));
+    }
+
+    #[test]
+    fn test_feature_seeds_are_independent() {
+        let roll = |salt| {
+            (0..32)
+                .flat_map(|x| (0..32).map(move |z| (x, z)))
+                .filter(|&(x, z)| chunk_has_feature(7, x, z, salt))
+                .collect::<Vec<_>>()
+        };
+        let slime = roll(SLIME_CHUNK_SEED);
+        let other = roll(0x1234_5678);
+        assert_ne!(slime, other);
+        // Roughly 1 in 10 of 1024 chunks
+        assert!((50..160).contains(&slime.len()));
+        assert!((50..160).contains(&other.len()));
+    }
+}
@@ -0,0 +1,233 @@
+//! Versioned binary format for a single chunk.
+//!
+//! Every serialized chunk starts with a [`ChunkHeader`] carrying a magic
+//! tag, the schema version and the payload encoding. Since version 2 the
+//! payload is a list of tagged, length-prefixed sections, so a reader can
+//! skip sections added by newer versions and still pick out the fields it
+//! knows.
+//!
+//! Version history:
+//! - 1: header followed by the whole [`ChunkData`] as one bincode blob
+//! - 2: header followed by tagged sections (biomes, heights)
+
+use crate::chunk_source::ChunkData;
+use crate::generator::BiomeChunk;
+use std::io;
+
+/// Magic bytes at the start of every serialized chunk.
+pub const CHUNK_MAGIC: [u8; 4] = *b"GNCH";
+
+/// Schema version written by [`ChunkSerializer::serialize`].
+pub const CHUNK_FORMAT_VERSION: u16 = 2;
+
+/// Header size: magic, version, encoding and one reserved byte.
+const HEADER_LEN: usize = 8;
+
+/// Section header size: tag plus payload length.
+const SECTION_HEADER_LEN: usize = 6;
+
+/// Section holding the [`BiomeChunk`].
+const SECTION_BIOMES: u16 = 1;
+
+/// Section holding the surface heights.
+const SECTION_HEIGHTS: u16 = 2;
+
+/// How section payloads are encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkEncoding {
+    /// bincode with default options.
+    Bincode,
+}
+
+impl ChunkEncoding {
+    fn to_byte(self) -> u8 {
+        match self {
+            Self::Bincode => 0,
+        }
+    }
+
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(Self::Bincode),
+            _ => None,
+        }
+    }
+}
+
+/// Fixed-size header at the start of a serialized chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkHeader {
+    /// Schema version the chunk was written with.
+    pub version: u16,
+    /// Payload encoding.
+    pub encoding: ChunkEncoding,
+}
+
+impl ChunkHeader {
+    /// Header for the current schema version.
+    pub fn current() -> Self {
+        Self {
+            version: CHUNK_FORMAT_VERSION,
+            encoding: ChunkEncoding::Bincode,
+        }
+    }
+
+    /// Append the encoded header to `out`.
+    pub fn write(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&CHUNK_MAGIC);
+        out.extend_from_slice(&self.version.to_le_bytes());
+        out.push(self.encoding.to_byte());
+        out.push(0);
+    }
+
+    /// Parse the header from the start of `bytes`.
+    pub fn read(bytes: &[u8]) -> io::Result<Self> {
+        if bytes.len() < HEADER_LEN || bytes[..4] != CHUNK_MAGIC {
+            return Err(invalid("missing chunk header"));
+        }
+        let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+        if version == 0 {
+            return Err(invalid("chunk version 0"));
+        }
+        let encoding = ChunkEncoding::from_byte(bytes[6])
+            .ok_or_else(|| invalid(format!("unknown chunk encoding {}", bytes[6])))?;
+        Ok(Self { version, encoding })
+    }
+}
+
+/// Reads and writes chunks in the versioned format.
+pub struct ChunkSerializer;
+
+impl ChunkSerializer {
+    /// Serialize a chunk at [`CHUNK_FORMAT_VERSION`].
+    pub fn serialize(chunk: &ChunkData) -> io::Result<Vec<u8>> {
+        let mut out = Vec::new();
+        ChunkHeader::current().write(&mut out);
+        write_section(&mut out, SECTION_BIOMES, &chunk.biomes)?;
+        write_section(&mut out, SECTION_HEIGHTS, &chunk.heights)?;
+        Ok(out)
+    }
+
+    /// Deserialize a chunk written at any known version.
+    ///
+    /// Chunks from newer versions are read as long as they still carry the
+    /// sections this version knows; unknown sections are skipped.
+    pub fn deserialize(bytes: &[u8]) -> io::Result<ChunkData> {
+        let header = ChunkHeader::read(bytes)?;
+        let body = &bytes[HEADER_LEN..];
+        match header.version {
+            1 => bincode::deserialize(body).map_err(invalid),
+            _ => read_sections(body),
+        }
+    }
+}
+
+fn write_section<T: serde::Serialize>(out: &mut Vec<u8>, tag: u16, value: &T) -> io::Result<()> {
+    let payload = bincode::serialize(value).map_err(invalid)?;
+    let len = u32::try_from(payload.len()).map_err(|_| invalid("chunk section too large"))?;
+    out.extend_from_slice(&tag.to_le_bytes());
+    out.extend_from_slice(&len.to_le_bytes());
+    out.extend_from_slice(&payload);
+    Ok(())
+}
+
+fn read_sections(mut body: &[u8]) -> io::Result<ChunkData> {
+    let mut biomes: Option<BiomeChunk> = None;
+    let mut heights: Option<Vec<f32>> = None;
+
+    while !body.is_empty() {
+        if body.len() < SECTION_HEADER_LEN {
+            return Err(invalid("truncated chunk section header"));
+        }
+        let tag = u16::from_le_bytes([body[0], body[1]]);
+        let len = u32::from_le_bytes([body[2], body[3], body[4], body[5]]) as usize;
+        let rest = &body[SECTION_HEADER_LEN..];
+        if rest.len() < len {
+            return Err(invalid("truncated chunk section"));
+        }
+        let payload = &rest[..len];
+        match tag {
+            SECTION_BIOMES => biomes = Some(bincode::deserialize(payload).map_err(invalid)?),
+            SECTION_HEIGHTS => heights = Some(bincode::deserialize(payload).map_err(invalid)?),
+            // Added by a newer version; skip it.
+            _ => {},
+        }
+        body = &rest[len..];
+    }
+
+    Ok(ChunkData {
+        biomes: biomes.ok_or_else(|| invalid("chunk has no biome section"))?,
+        heights: heights.ok_or_else(|| invalid("chunk has no height section"))?,
+    })
+}
+
+fn invalid<E: Into<Box<dyn std::error::Error + Send + Sync>>>(error: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_chunk() -> ChunkData {
+        ChunkData {
+            biomes: BiomeChunk {
+                biomes: (0..16).collect(),
+                width: 4,
+                height: 4,
+                origin_x: -4,
+                origin_z: 8,
+                scale: 1,
+            },
+            heights: (0..16).map(|h| h as f32 * 0.5).collect(),
+        }
+    }
+
+    #[test]
+    fn test_round_trip_at_current_version() {
+        let chunk = sample_chunk();
+        let bytes = ChunkSerializer::serialize(&chunk).unwrap();
+        assert_eq!(ChunkHeader::read(&bytes).unwrap(), ChunkHeader::current());
+        assert_eq!(ChunkSerializer::deserialize(&bytes).unwrap(), chunk);
+    }
+
+    #[test]
+    fn test_reads_version_one_blob() {
+        let chunk = sample_chunk();
+        let mut bytes = Vec::new();
+        ChunkHeader {
+            version: 1,
+            encoding: ChunkEncoding::Bincode,
+        }
+        .write(&mut bytes);
+        bytes.extend(bincode::serialize(&chunk).unwrap());
+
+        assert_eq!(ChunkSerializer::deserialize(&bytes).unwrap(), chunk);
+    }
+
+    #[test]
+    fn test_newer_version_skips_unknown_sections() {
+        let chunk = sample_chunk();
+        let mut bytes = Vec::new();
+        ChunkHeader {
+            version: CHUNK_FORMAT_VERSION + 1,
+            encoding: ChunkEncoding::Bincode,
+        }
+        .write(&mut bytes);
+        write_section(&mut bytes, SECTION_BIOMES, &chunk.biomes).unwrap();
+        write_section(&mut bytes, 99, &"lighting data").unwrap();
+        write_section(&mut bytes, SECTION_HEIGHTS, &chunk.heights).unwrap();
+        write_section(&mut bytes, 100, &vec![1u8, 2, 3]).unwrap();
+
+        assert_eq!(ChunkSerializer::deserialize(&bytes).unwrap(), chunk);
+    }
+
+    #[test]
+    fn test_rejects_bad_header_and_truncation() {
+        assert!(ChunkSerializer::deserialize(b"nope").is_err());
+
+        let bytes = ChunkSerializer::serialize(&sample_chunk()).unwrap();
+        let err = ChunkSerializer::deserialize(&bytes[..bytes.len() - 1]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
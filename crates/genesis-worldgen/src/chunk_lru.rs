@@ -0,0 +1,120 @@
+//! Fixed-capacity least-recently-used cache keyed by chunk coordinates.
+//!
+//! Each entry remembers the tick it was last used; inserting past capacity
+//! drops the entry with the oldest tick. Capacities are a few hundred
+//! chunks, so the linear scan on eviction is cheaper than keeping a linked
+//! list in sync.
+
+use std::collections::HashMap;
+
+/// LRU cache of per-chunk values.
+#[derive(Debug)]
+pub(crate) struct ChunkLru<V> {
+    entries: HashMap<(i32, i32), (V, u64)>,
+    capacity: usize,
+    clock: u64,
+}
+
+impl<V> ChunkLru<V> {
+    /// Create an empty cache holding at most `capacity` chunks (0 disables it).
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            capacity,
+            clock: 0,
+        }
+    }
+
+    /// Look up a chunk, marking it as just used.
+    pub(crate) fn get(&mut self, key: (i32, i32)) -> Option<&V> {
+        self.clock += 1;
+        let clock = self.clock;
+        self.entries.get_mut(&key).map(|(value, last_used)| {
+            *last_used = clock;
+            &*value
+        })
+    }
+
+    /// Look up a chunk without marking it as used.
+    pub(crate) fn peek(&self, key: (i32, i32)) -> Option<&V> {
+        self.entries.get(&key).map(|(value, _)| value)
+    }
+
+    /// Insert or replace a chunk, evicting the least recently used one if
+    /// the cache is full.
+    pub(crate) fn insert(&mut self, key: (i32, i32), value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.clock += 1;
+        self.entries.insert(key, (value, self.clock));
+        self.evict_to(self.capacity);
+    }
+
+    /// Change the capacity, evicting the oldest chunks if it shrank.
+    pub(crate) fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.evict_to(capacity);
+    }
+
+    /// Every cached value, without marking any as used.
+    pub(crate) fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.entries.values_mut().map(|(value, _)| value)
+    }
+
+    /// Drop every chunk.
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Number of cached chunks.
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn evict_to(&mut self, capacity: usize) {
+        while self.entries.len() > capacity {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(&key, _)| key);
+            match oldest {
+                Some(key) => self.entries.remove(&key),
+                None => break,
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let mut lru = ChunkLru::new(2);
+        lru.insert((0, 0), "a");
+        lru.insert((1, 0), "b");
+        // Touching (0, 0) makes (1, 0) the oldest; peeking doesn't count
+        assert_eq!(lru.get((0, 0)), Some(&"a"));
+        assert_eq!(lru.peek((1, 0)), Some(&"b"));
+        lru.insert((2, 0), "c");
+        assert_eq!(lru.len(), 2);
+        assert_eq!(lru.get((1, 0)), None);
+        assert_eq!(lru.get((0, 0)), Some(&"a"));
+        assert_eq!(lru.get((2, 0)), Some(&"c"));
+
+        lru.set_capacity(1);
+        assert_eq!(lru.len(), 1);
+        assert_eq!(lru.get((2, 0)), Some(&"c"));
+    }
+
+    #[test]
+    fn test_zero_capacity_stores_nothing() {
+        let mut lru = ChunkLru::new(0);
+        lru.insert((0, 0), 1);
+        assert_eq!(lru.len(), 0);
+        assert_eq!(lru.get((0, 0)), None);
+    }
+}
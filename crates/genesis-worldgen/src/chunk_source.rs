@@ -0,0 +1,319 @@
+//! Chunk sources for streaming terrain.
+//!
+//! A [`ChunkSource`] supplies biome and height data for a game chunk.
+//! New worlds stream straight from a [`BiomeProvider`], usually the
+//! generator ([`GeneratedSource`]), persistent worlds read edited chunks
+//! back from region files ([`SavedSource`]), and [`LayeredSource`]
+//! combines the two so both cases go through the same streaming path.
+
+use crate::biome_provider::BiomeProvider;
+use crate::generator::BiomeChunk;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// Number of chunks along each side of a region file.
+pub const REGION_SIZE: i32 = 32;
+
+/// Biome and height data for a single game chunk.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChunkData {
+    /// Biome IDs for the chunk.
+    pub biomes: BiomeChunk,
+    /// Surface heights, one per biome cell.
+    pub heights: Vec<f32>,
+}
+
+/// Something that can provide terrain chunks by game chunk coordinates.
+pub trait ChunkSource {
+    /// Load the chunk at `(chunk_x, chunk_y)`, or `None` if this source
+    /// has no data for it.
+    fn load_chunk(&mut self, chunk_x: i32, chunk_y: i32) -> Option<ChunkData>;
+
+    /// [`Self::load_chunk`] for many chunks, in the same order. Sources
+    /// that can load in bulk (like the generator) override this.
+    fn load_chunks(&mut self, coords: &[(i32, i32)]) -> Vec<Option<ChunkData>> {
+        coords
+            .iter()
+            .map(|&(cx, cy)| self.load_chunk(cx, cy))
+            .collect()
+    }
+}
+
+impl<T: ChunkSource + ?Sized> ChunkSource for &mut T {
+    fn load_chunk(&mut self, chunk_x: i32, chunk_y: i32) -> Option<ChunkData> {
+        (**self).load_chunk(chunk_x, chunk_y)
+    }
+
+    fn load_chunks(&mut self, coords: &[(i32, i32)]) -> Vec<Option<ChunkData>> {
+        (**self).load_chunks(coords)
+    }
+}
+
+/// Chunk source that generates everything from a biome provider, usually
+/// the world generator.
+pub struct GeneratedSource<'a> {
+    provider: &'a dyn BiomeProvider,
+}
+
+impl<'a> GeneratedSource<'a> {
+    /// Create a source backed by the given provider.
+    pub fn new(provider: &'a dyn BiomeProvider) -> Self {
+        Self { provider }
+    }
+}
+
+impl ChunkSource for GeneratedSource<'_> {
+    fn load_chunk(&mut self, chunk_x: i32, chunk_y: i32) -> Option<ChunkData> {
+        Some(self.provider.chunk(chunk_x, chunk_y))
+    }
+
+    fn load_chunks(&mut self, coords: &[(i32, i32)]) -> Vec<Option<ChunkData>> {
+        self.provider.chunks(coords).into_iter().map(Some).collect()
+    }
+}
+
+/// Chunks stored in one region file, keyed by chunk coordinates.
+type Region = BTreeMap<(i32, i32), ChunkData>;
+
+/// Chunk source that reads saved chunks from region files on disk.
+///
+/// Each region file holds up to `REGION_SIZE × REGION_SIZE` chunks and is
+/// named `r.<rx>.<ry>.bin` inside the save directory. Regions are loaded
+/// lazily and kept in memory once read. A region file that fails to decode
+/// is never cached, so saving into it reports the error instead of
+/// replacing the file.
+pub struct SavedSource {
+    dir: PathBuf,
+    regions: HashMap<(i32, i32), Region>,
+    /// Regions whose files failed to read, so loads skip them quietly.
+    unreadable: HashSet<(i32, i32)>,
+}
+
+impl SavedSource {
+    /// Create a source reading region files from `dir`.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            regions: HashMap::new(),
+            unreadable: HashSet::new(),
+        }
+    }
+
+    /// Get the save directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Get the region coordinates containing a chunk.
+    pub fn region_of(chunk_x: i32, chunk_y: i32) -> (i32, i32) {
+        (
+            chunk_x.div_euclid(REGION_SIZE),
+            chunk_y.div_euclid(REGION_SIZE),
+        )
+    }
+
+    /// Get the path of the region file for the given region coordinates.
+    pub fn region_path(&self, region_x: i32, region_y: i32) -> PathBuf {
+        self.dir.join(format!("r.{region_x}.{region_y}.bin"))
+    }
+
+    /// Store a chunk and write its region file back to disk.
+    ///
+    /// Fails without touching the file if the existing region file can't be
+    /// read or decoded.
+    pub fn save_chunk(&mut self, chunk_x: i32, chunk_y: i32, data: ChunkData) -> io::Result<()> {
+        let key = Self::region_of(chunk_x, chunk_y);
+        self.region(key)?.insert((chunk_x, chunk_y), data);
+
+        let path = self.region_path(key.0, key.1);
+        fs::create_dir_all(&self.dir)?;
+        let bytes = bincode::serialize(&self.regions[&key]).map_err(io::Error::other)?;
+        fs::write(path, bytes)
+    }
+
+    /// Get a loaded region, reading it from disk on first access.
+    fn region(&mut self, key: (i32, i32)) -> io::Result<&mut Region> {
+        if !self.regions.contains_key(&key) {
+            let region = self.read_region(key)?;
+            self.regions.insert(key, region);
+        }
+        Ok(self.regions.get_mut(&key).expect("region was just loaded"))
+    }
+
+    /// Read a region file. A missing file is an empty region; an unreadable
+    /// or corrupt one is an error.
+    fn read_region(&self, (region_x, region_y): (i32, i32)) -> io::Result<Region> {
+        let path = self.region_path(region_x, region_y);
+        let bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Region::new()),
+            Err(e) => return Err(e),
+        };
+        bincode::deserialize(&bytes).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("corrupt region file {}: {e}", path.display()),
+            )
+        })
+    }
+}
+
+impl ChunkSource for SavedSource {
+    fn load_chunk(&mut self, chunk_x: i32, chunk_y: i32) -> Option<ChunkData> {
+        let key = Self::region_of(chunk_x, chunk_y);
+        if self.unreadable.contains(&key) {
+            return None;
+        }
+        match self.region(key) {
+            Ok(region) => region.get(&(chunk_x, chunk_y)).cloned(),
+            Err(e) => {
+                warn!("Skipping saved region {key:?}: {e}");
+                self.unreadable.insert(key);
+                None
+            },
+        }
+    }
+}
+
+/// Chunk source that prefers saved data and falls back to generation.
+pub struct LayeredSource<S, G> {
+    saved: S,
+    generated: G,
+}
+
+impl<S: ChunkSource, G: ChunkSource> LayeredSource<S, G> {
+    /// Layer `saved` over `generated`.
+    pub fn new(saved: S, generated: G) -> Self {
+        Self { saved, generated }
+    }
+
+    /// Get the saved layer.
+    pub fn saved_mut(&mut self) -> &mut S {
+        &mut self.saved
+    }
+
+    /// Get the generated layer.
+    pub fn generated_mut(&mut self) -> &mut G {
+        &mut self.generated
+    }
+}
+
+impl<S: ChunkSource, G: ChunkSource> ChunkSource for LayeredSource<S, G> {
+    fn load_chunk(&mut self, chunk_x: i32, chunk_y: i32) -> Option<ChunkData> {
+        self.saved
+            .load_chunk(chunk_x, chunk_y)
+            .or_else(|| self.generated.load_chunk(chunk_x, chunk_y))
+    }
+
+    /// Loads saved chunks first, then generates the rest in one batch.
+    fn load_chunks(&mut self, coords: &[(i32, i32)]) -> Vec<Option<ChunkData>> {
+        let saved = self.saved.load_chunks(coords);
+        let missing: Vec<(i32, i32)> = coords
+            .iter()
+            .zip(&saved)
+            .filter(|(_, chunk)| chunk.is_none())
+            .map(|(&coord, _)| coord)
+            .collect();
+        let mut generated = self.generated.load_chunks(&missing).into_iter();
+        saved
+            .into_iter()
+            .map(|chunk| chunk.or_else(|| generated.next().flatten()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Source that fills every chunk with a single biome.
+    struct FlatSource(i32);
+
+    impl ChunkSource for FlatSource {
+        fn load_chunk(&mut self, chunk_x: i32, chunk_y: i32) -> Option<ChunkData> {
+            Some(flat_chunk(chunk_x, chunk_y, self.0))
+        }
+    }
+
+    fn flat_chunk(chunk_x: i32, chunk_y: i32, biome: i32) -> ChunkData {
+        ChunkData {
+            biomes: BiomeChunk {
+                biomes: vec![biome; 16 * 16],
+                width: 16,
+                height: 16,
+                origin_x: chunk_x * 16,
+                origin_z: chunk_y * 16,
+                scale: 1,
+            },
+            heights: vec![64.0; 16 * 16],
+        }
+    }
+
+    #[test]
+    fn test_layered_prefers_saved() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut saved = SavedSource::new(dir.path());
+        saved.save_chunk(2, -3, flat_chunk(2, -3, 7)).unwrap();
+
+        let mut layered = LayeredSource::new(saved, FlatSource(1));
+        let chunk = layered.load_chunk(2, -3).unwrap();
+        assert_eq!(chunk, flat_chunk(2, -3, 7));
+    }
+
+    #[test]
+    fn test_layered_falls_back_to_generated() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut saved = SavedSource::new(dir.path());
+        saved.save_chunk(0, 0, flat_chunk(0, 0, 7)).unwrap();
+
+        let mut layered = LayeredSource::new(saved, FlatSource(1));
+        assert_eq!(layered.load_chunk(1, 0).unwrap(), flat_chunk(1, 0, 1));
+        assert_eq!(layered.load_chunk(-40, 5).unwrap(), flat_chunk(-40, 5, 1));
+    }
+
+    #[test]
+    fn test_layered_batch_keeps_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut saved = SavedSource::new(dir.path());
+        saved.save_chunk(1, 0, flat_chunk(1, 0, 7)).unwrap();
+
+        let mut layered = LayeredSource::new(saved, FlatSource(1));
+        let chunks = layered.load_chunks(&[(0, 0), (1, 0), (2, 0)]);
+        let expected = [
+            flat_chunk(0, 0, 1),
+            flat_chunk(1, 0, 7),
+            flat_chunk(2, 0, 1),
+        ];
+        assert_eq!(chunks, expected.map(Some));
+    }
+
+    #[test]
+    fn test_saved_source_reads_region_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut writer = SavedSource::new(dir.path());
+        writer.save_chunk(-1, 33, flat_chunk(-1, 33, 4)).unwrap();
+        assert!(writer.region_path(-1, 1).exists());
+
+        // A fresh source only sees what is on disk
+        let mut reader = SavedSource::new(dir.path());
+        assert_eq!(reader.load_chunk(-1, 33).unwrap(), flat_chunk(-1, 33, 4));
+        assert!(reader.load_chunk(0, 0).is_none());
+    }
+
+    #[test]
+    fn test_corrupt_region_is_not_overwritten() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut source = SavedSource::new(dir.path());
+        let path = source.region_path(0, 0);
+        fs::write(&path, b"not a region").unwrap();
+
+        assert!(source.load_chunk(1, 1).is_none());
+        let err = source.save_chunk(1, 1, flat_chunk(1, 1, 4)).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(fs::read(&path).unwrap(), b"not a region");
+    }
+}
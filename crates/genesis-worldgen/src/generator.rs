@@ -15,10 +15,197 @@
 //! plane and `{y, sy}` for vertical sampling. Our `generate_chunk(cx, cy)`
 //! maps game chunk-Y to cubiomes Z internally.
 
+use crate::biome_map::{BiomeGroup, BiomeTextureMap};
+use crate::chunk_lru::ChunkLru;
+use crate::chunk_source::ChunkData;
+use crate::range::RangeExt;
+use crate::structure::StructureKind;
 use cubiomes_sys::*;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
+use std::ffi::c_uint;
+use std::ops::BitOr;
+use std::thread;
 use tracing::info;
 
+/// Cubiomes generator flags as named booleans.
+///
+/// Serializes as the raw flag word so existing configs keep loading.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(from = "u32", into = "u32")]
+pub struct GenFlags {
+    /// Quarter-scale biome layout (`LARGE_BIOMES`).
+    pub large_biomes: bool,
+    /// Disable beta-era ocean generation (`NO_BETA_OCEAN`).
+    pub no_beta_ocean: bool,
+    /// Generate ocean variants in versions that lack them (`FORCE_OCEAN_VARIANTS`).
+    pub force_ocean_variants: bool,
+}
+
+impl GenFlags {
+    /// No flags set.
+    pub const NONE: Self = Self {
+        large_biomes: false,
+        no_beta_ocean: false,
+        force_ocean_variants: false,
+    };
+
+    /// Decode a raw cubiomes flag word. Unknown bits are dropped.
+    pub const fn from_raw(raw: c_uint) -> Self {
+        Self {
+            large_biomes: raw & LARGE_BIOMES != 0,
+            no_beta_ocean: raw & NO_BETA_OCEAN != 0,
+            force_ocean_variants: raw & FORCE_OCEAN_VARIANTS != 0,
+        }
+    }
+
+    /// Encode as the raw cubiomes flag word.
+    pub const fn to_raw(self) -> c_uint {
+        let mut raw = 0;
+        if self.large_biomes {
+            raw |= LARGE_BIOMES;
+        }
+        if self.no_beta_ocean {
+            raw |= NO_BETA_OCEAN;
+        }
+        if self.force_ocean_variants {
+            raw |= FORCE_OCEAN_VARIANTS;
+        }
+        raw
+    }
+
+    /// Whether no flags are set.
+    pub const fn is_empty(self) -> bool {
+        self.to_raw() == 0
+    }
+}
+
+impl From<c_uint> for GenFlags {
+    fn from(raw: c_uint) -> Self {
+        Self::from_raw(raw)
+    }
+}
+
+impl From<GenFlags> for c_uint {
+    fn from(flags: GenFlags) -> Self {
+        flags.to_raw()
+    }
+}
+
+impl BitOr for GenFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self::from_raw(self.to_raw() | rhs.to_raw())
+    }
+}
+
+/// Block-level sea level of the height maps.
+pub const HEIGHT_SEA_LEVEL: f32 = 63.0;
+
+/// Spacing in blocks between candidates in [`WorldGenerator::find_safe_spawn`].
+pub const SPAWN_SEARCH_STEP: i32 = 16;
+
+/// Amplified mode: how much elevation above sea level is stretched.
+pub const AMPLIFIED_ELEVATION_SCALE: f32 = 2.5;
+
+/// Amplified mode: how much depth below sea level is stretched.
+pub const AMPLIFIED_DEPTH_SCALE: f32 = 1.5;
+
+/// Apply amplified-mode exaggeration to a block-level height.
+///
+/// Heights are stretched away from [`HEIGHT_SEA_LEVEL`], more strongly
+/// above it than below, so the coastline stays put.
+pub fn amplify_height(height: f32) -> f32 {
+    amplify_height_around(height, HEIGHT_SEA_LEVEL)
+}
+
+/// [`amplify_height`] around an arbitrary sea level.
+pub fn amplify_height_around(height: f32, sea_level: f32) -> f32 {
+    let offset = height - sea_level;
+    let scale = if offset > 0.0 {
+        AMPLIFIED_ELEVATION_SCALE
+    } else {
+        AMPLIFIED_DEPTH_SCALE
+    };
+    sea_level + offset * scale
+}
+
+fn default_sea_level() -> f32 {
+    HEIGHT_SEA_LEVEL
+}
+
+/// Default number of biome chunks [`WorldGenerator`] keeps in its LRU cache.
+pub const DEFAULT_CHUNK_CACHE_CAPACITY: usize = 256;
+
+fn default_chunk_cache_capacity() -> usize {
+    DEFAULT_CHUNK_CACHE_CAPACITY
+}
+
+/// Named biome sampling scales.
+///
+/// Each preset pairs a cubiomes scale with the matching generator flags so
+/// callers don't have to remember which raw values are valid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum BiomeScale {
+    /// One sample per block (1:1).
+    Block,
+    /// One sample per 4×4 blocks (1:4), cubiomes' native biome cell.
+    Quad,
+    /// One sample per chunk (1:16).
+    Chunk,
+    /// One sample per 256×256 blocks (1:256), for whole-world overviews.
+    Region,
+    /// 1:4 sampling with the large-biomes flag, so biomes are 4× wider.
+    LargeBiomes,
+}
+
+impl BiomeScale {
+    /// All presets, finest first.
+    pub const ALL: [Self; 5] = [
+        Self::Block,
+        Self::Quad,
+        Self::Chunk,
+        Self::Region,
+        Self::LargeBiomes,
+    ];
+
+    /// Raw cubiomes scale for [`WorldGenConfig::scale`].
+    pub fn raw(self) -> i32 {
+        match self {
+            Self::Block => 1,
+            Self::Quad | Self::LargeBiomes => 4,
+            Self::Chunk => 16,
+            Self::Region => 256,
+        }
+    }
+
+    /// Whether the preset turns on [`GenFlags::large_biomes`].
+    pub fn large_biomes(self) -> bool {
+        self == Self::LargeBiomes
+    }
+
+    /// Display name.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Block => "Block (1:1)",
+            Self::Quad => "Quad (1:4)",
+            Self::Chunk => "Chunk (1:16)",
+            Self::Region => "Region (1:256)",
+            Self::LargeBiomes => "Large Biomes",
+        }
+    }
+
+    /// Preset matching a raw scale and large-biomes flag, if any.
+    pub fn from_raw(scale: i32, large_biomes: bool) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.raw() == scale && preset.large_biomes() == large_biomes)
+    }
+}
+
 /// Configuration for world generation.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct WorldGenConfig {
@@ -26,8 +213,8 @@ pub struct WorldGenConfig {
     pub mc_version: i32,
     /// World seed.
     pub seed: u64,
-    /// Generator flags (e.g. LARGE_BIOMES).
-    pub flags: u32,
+    /// Generator flags (e.g. large biomes).
+    pub flags: GenFlags,
     /// Scale for biome generation (1, 4, 16, 64, 256).
     /// Lower = more detail, higher = faster.
     pub scale: i32,
@@ -35,6 +222,85 @@ pub struct WorldGenConfig {
     /// At scale=4: y=16 → block y=64 (sea level in MC 1.18+).
     /// This samples the surface biome layer for our top-down view.
     pub y_level: i32,
+    /// Biome replacements applied to every generated biome ID.
+    /// Each ID is looked up once, so substitutions don't chain.
+    #[serde(default)]
+    pub biome_substitutions: HashMap<i32, i32>,
+    /// Exaggerate terrain relief: raise peaks and deepen valleys around sea
+    /// level, like Minecraft's amplified worlds.
+    #[serde(default)]
+    pub amplified: bool,
+    /// Block height of the water surface. Cells whose height is at or below
+    /// it are underwater.
+    #[serde(default = "default_sea_level")]
+    pub sea_level: f32,
+    /// Most recently used biome chunks [`WorldGenerator::generate_chunk`]
+    /// keeps around. `0` disables the cache.
+    #[serde(default = "default_chunk_cache_capacity")]
+    pub chunk_cache_capacity: usize,
+}
+
+impl WorldGenConfig {
+    /// Apply a scale preset, setting the raw scale and the large-biomes flag
+    /// together.
+    #[must_use]
+    pub fn with_scale_preset(mut self, preset: BiomeScale) -> Self {
+        self.scale = preset.raw();
+        self.flags.large_biomes = preset.large_biomes();
+        self
+    }
+
+    /// The preset matching the current scale and flags, if any.
+    pub fn scale_preset(&self) -> Option<BiomeScale> {
+        BiomeScale::from_raw(self.scale, self.flags.large_biomes)
+    }
+
+    /// Set the block height of the water surface.
+    #[must_use]
+    pub fn with_sea_level(mut self, sea_level: f32) -> Self {
+        self.sea_level = sea_level;
+        self
+    }
+
+    /// Set how many biome chunks the generator caches.
+    #[must_use]
+    pub fn with_chunk_cache_capacity(mut self, capacity: usize) -> Self {
+        self.chunk_cache_capacity = capacity;
+        self
+    }
+
+    /// Whether both configs generate the same biomes, ignoring fields that
+    /// only affect heights or caching.
+    fn same_biomes(&self, other: &Self) -> bool {
+        self.mc_version == other.mc_version
+            && self.seed == other.seed
+            && self.flags.to_raw() == other.flags.to_raw()
+            && self.scale == other.scale
+            && self.y_level == other.y_level
+            && self.biome_substitutions == other.biome_substitutions
+    }
+
+    /// Whether both configs generate the same biomes and heights.
+    fn same_heights(&self, other: &Self) -> bool {
+        self.same_biomes(other)
+            && self.amplified == other.amplified
+            && self.sea_level.to_bits() == other.sea_level.to_bits()
+    }
+
+    /// Whether a cell at block height `height` is underwater.
+    pub fn is_submerged(&self, height: f32) -> bool {
+        height <= self.sea_level
+    }
+
+    /// Blocks of water above a cell at `height`, or `0.0` on dry land.
+    pub fn water_depth(&self, height: f32) -> f32 {
+        (self.sea_level - height).max(0.0)
+    }
+
+    /// Per-cell underwater flags for a height grid, in the same order.
+    pub fn submerged_mask(&self, heights: &[f32]) -> Vec<bool> {
+        heights.iter().map(|&h| self.is_submerged(h)).collect()
+    }
 }
 
 impl Default for WorldGenConfig {
@@ -42,19 +308,41 @@ impl Default for WorldGenConfig {
         Self {
             mc_version: MC_1_18,
             seed: 0,
-            flags: 0,
+            flags: GenFlags::NONE,
             scale: 1,
             y_level: 64, // block y=64 → sea level (surface biomes)
+            biome_substitutions: HashMap::new(),
+            amplified: false,
+            sea_level: HEIGHT_SEA_LEVEL,
+            chunk_cache_capacity: DEFAULT_CHUNK_CACHE_CAPACITY,
         }
     }
 }
 
+/// Raw climate noise at a point, as used by 1.18+ biome generation.
+///
+/// Values vary continuously across the world, roughly within `[-1, 1]`,
+/// so they can drive smooth gradients where biome IDs change in steps.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ClimateSample {
+    /// Cold (negative) to hot (positive).
+    pub temperature: f32,
+    /// Dry (negative) to wet (positive).
+    pub humidity: f32,
+    /// Ocean (negative) to inland (positive).
+    pub continentalness: f32,
+    /// Mountainous (negative) to flat (positive).
+    pub erosion: f32,
+    /// Drives peaks, valleys and biome variants.
+    pub weirdness: f32,
+}
+
 /// Generated biome data for a rectangular region.
 ///
 /// Biomes are indexed as `[row * width + col]` where:
 /// - `row` = cubiomes Z offset (maps to game Y / screen vertical)
 /// - `col` = cubiomes X offset (maps to game X / screen horizontal)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct BiomeChunk {
     /// Biome IDs, indexed as `[z_offset * width + x_offset]`.
     pub biomes: Vec<i32>,
@@ -78,6 +366,32 @@ impl BiomeChunk {
         }
         self.biomes[(z * self.width + x) as usize]
     }
+
+    /// Number of cells holding each biome ID.
+    pub fn histogram(&self) -> HashMap<i32, u32> {
+        let mut counts = HashMap::new();
+        for &biome in &self.biomes {
+            *counts.entry(biome).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// The most common biome, preferring the lowest ID on ties, or
+    /// `BIOME_NONE` for an empty chunk.
+    pub fn dominant_biome(&self) -> i32 {
+        self.histogram()
+            .into_iter()
+            .max_by_key(|&(biome, count)| (count, std::cmp::Reverse(biome)))
+            .map_or(BIOME_NONE, |(biome, _)| biome)
+    }
+}
+
+/// A chunk in the LRU cache: its biomes, plus its heights once something
+/// asked for them.
+#[derive(Debug, Clone)]
+struct CachedChunk {
+    biomes: BiomeChunk,
+    heights: Option<Vec<f32>>,
 }
 
 /// Safe wrapper around the cubiomes Generator.
@@ -88,6 +402,14 @@ pub struct WorldGenerator {
     sn: *mut SurfaceNoise,
     /// Current configuration.
     config: WorldGenConfig,
+    /// Recently generated or warmed-up chunks.
+    biome_cache: RefCell<ChunkLru<CachedChunk>>,
+    /// Biome chunk lookups answered from / missed by `biome_cache`.
+    biome_cache_hits: Cell<u64>,
+    biome_cache_misses: Cell<u64>,
+    /// Generators for parallel workers, built on first use and kept until
+    /// the configuration changes.
+    workers: RefCell<Vec<WorldGenerator>>,
 }
 
 // SAFETY: The Generator is used single-threaded and the pointer is stable.
@@ -96,13 +418,27 @@ unsafe impl Send for WorldGenerator {}
 impl WorldGenerator {
     /// Create a new world generator with the given configuration.
     pub fn new(config: WorldGenConfig) -> Self {
+        let generator = Self::allocate(config);
+        let config = &generator.config;
+        info!(
+            "WorldGenerator initialized: mc={}, seed={}, scale={}, flags={}",
+            mc_version_name(config.mc_version),
+            config.seed,
+            config.scale,
+            config.flags.to_raw()
+        );
+        generator
+    }
+
+    /// Allocate and initialize the cubiomes structs, without logging.
+    fn allocate(config: WorldGenConfig) -> Self {
         unsafe {
             let gen = cubiomes_generator_new();
             assert!(!gen.is_null(), "Failed to allocate cubiomes Generator");
             cubiomes_generator_init(
                 gen,
                 config.mc_version,
-                config.flags,
+                config.flags.to_raw(),
                 DIM_OVERWORLD,
                 config.seed,
             );
@@ -111,30 +447,45 @@ impl WorldGenerator {
             assert!(!sn.is_null(), "Failed to allocate cubiomes SurfaceNoise");
             cubiomes_surface_noise_init(sn, DIM_OVERWORLD, config.seed);
 
-            info!(
-                "WorldGenerator initialized: mc={}, seed={}, scale={}, flags={}",
-                mc_version_name(config.mc_version),
-                config.seed,
-                config.scale,
-                config.flags
-            );
-            Self { gen, sn, config }
+            Self {
+                gen,
+                sn,
+                biome_cache: RefCell::new(ChunkLru::new(config.chunk_cache_capacity)),
+                config,
+                biome_cache_hits: Cell::new(0),
+                biome_cache_misses: Cell::new(0),
+                workers: RefCell::default(),
+            }
         }
     }
 
     /// Update the generator with new configuration.
+    ///
+    /// Cached biome chunks are kept unless the change affects biomes (seed,
+    /// version, flags, scale, Y level or substitutions); cached heights are
+    /// also dropped when amplification or the sea level change.
     pub fn reconfigure(&mut self, config: WorldGenConfig) {
         unsafe {
             cubiomes_generator_init(
                 self.gen,
                 config.mc_version,
-                config.flags,
+                config.flags.to_raw(),
                 DIM_OVERWORLD,
                 config.seed,
             );
             cubiomes_surface_noise_init(self.sn, DIM_OVERWORLD, config.seed);
         }
+        let biome_cache = self.biome_cache.get_mut();
+        if !self.config.same_biomes(&config) {
+            biome_cache.clear();
+        } else if !self.config.same_heights(&config) {
+            for chunk in biome_cache.values_mut() {
+                chunk.heights = None;
+            }
+        }
+        biome_cache.set_capacity(config.chunk_cache_capacity);
         self.config = config;
+        self.workers.get_mut().clear();
         info!(
             "WorldGenerator reconfigured: mc={}, seed={}, scale={}",
             mc_version_name(self.config.mc_version),
@@ -143,25 +494,322 @@ impl WorldGenerator {
         );
     }
 
+    /// Re-seed the existing generator in place.
+    ///
+    /// Keeps the MC version and flags and skips reallocating the C structs,
+    /// so tools can scrub through many seeds cheaply.
+    pub fn set_seed(&mut self, seed: u64) {
+        unsafe {
+            applySeed(self.gen, DIM_OVERWORLD, seed);
+            cubiomes_surface_noise_init(self.sn, DIM_OVERWORLD, seed);
+        }
+        self.config.seed = seed;
+        for worker in self.workers.get_mut() {
+            worker.set_seed(seed);
+        }
+        self.clear_cache();
+    }
+
+    /// Precompute biomes and heights for every chunk within `radius`
+    /// chunks of `center` (a square) into the chunk cache, so the first
+    /// frames after spawning don't wait on generation. Returns how many
+    /// chunks were newly generated.
+    ///
+    /// Warmed-up chunks share the LRU cache with everything else, so
+    /// [`WorldGenConfig::chunk_cache_capacity`] bounds how many stay.
+    pub fn warmup_region(&self, center: (i32, i32), radius: i32) -> usize {
+        let radius = radius.max(0);
+        let missing: Vec<(i32, i32)> = (center.1 - radius..=center.1 + radius)
+            .flat_map(|cy| (center.0 - radius..=center.0 + radius).map(move |cx| (cx, cy)))
+            .filter(|&(cx, cy)| !self.is_chunk_cached(cx, cy))
+            .collect();
+        let chunks = self.generate_on_workers(&missing, Self::generate_chunk_data);
+        for (&coord, chunk) in missing.iter().zip(&chunks) {
+            self.remember_chunk_data(coord, chunk);
+        }
+        info!(
+            "Warmed up {} chunks around ({}, {})",
+            missing.len(),
+            center.0,
+            center.1
+        );
+        missing.len()
+    }
+
+    /// Whether a chunk's biomes and heights are both cached.
+    pub fn is_chunk_cached(&self, chunk_x: i32, chunk_y: i32) -> bool {
+        self.biome_cache
+            .borrow()
+            .peek((chunk_x, chunk_y))
+            .is_some_and(|chunk| chunk.heights.is_some())
+    }
+
+    /// Number of chunks in the cache.
+    pub fn cached_chunk_count(&self) -> usize {
+        self.biome_cache.borrow().len()
+    }
+
+    /// Drop every biome chunk from the LRU cache.
+    pub fn clear_cache(&mut self) {
+        self.biome_cache.get_mut().clear();
+    }
+
+    /// `(hits, misses)` of the biome chunk LRU cache since the generator
+    /// was created.
+    pub fn cache_stats(&self) -> (u64, u64) {
+        (self.biome_cache_hits.get(), self.biome_cache_misses.get())
+    }
+
+    /// Look up a chunk's biomes in the LRU cache, counting hits and misses.
+    fn cached_biomes(&self, chunk_x: i32, chunk_y: i32) -> Option<BiomeChunk> {
+        let cached = self
+            .biome_cache
+            .borrow_mut()
+            .get((chunk_x, chunk_y))
+            .map(|chunk| chunk.biomes.clone());
+        self.count_lookup(cached.is_some());
+        cached
+    }
+
+    /// Look up a chunk's biomes and heights in the LRU cache, counting hits
+    /// and misses. Entries without heights count as misses.
+    fn cached_chunk_data(&self, chunk_x: i32, chunk_y: i32) -> Option<ChunkData> {
+        let cached = self
+            .biome_cache
+            .borrow_mut()
+            .get((chunk_x, chunk_y))
+            .and_then(|chunk| {
+                Some(ChunkData {
+                    biomes: chunk.biomes.clone(),
+                    heights: chunk.heights.clone()?,
+                })
+            });
+        self.count_lookup(cached.is_some());
+        cached
+    }
+
+    fn count_lookup(&self, hit: bool) {
+        let counter = if hit {
+            &self.biome_cache_hits
+        } else {
+            &self.biome_cache_misses
+        };
+        counter.set(counter.get() + 1);
+    }
+
+    /// Put a generated chunk in the LRU cache.
+    fn remember_chunk_data(&self, coord: (i32, i32), chunk: &ChunkData) {
+        self.biome_cache.borrow_mut().insert(
+            coord,
+            CachedChunk {
+                biomes: chunk.biomes.clone(),
+                heights: Some(chunk.heights.clone()),
+            },
+        );
+    }
+
+    /// Biomes and heights for a game chunk, from the cache if present.
+    pub fn chunk_data(&self, chunk_x: i32, chunk_y: i32) -> ChunkData {
+        self.cached_chunk_data(chunk_x, chunk_y).unwrap_or_else(|| {
+            let chunk = self.generate_chunk_data(chunk_x, chunk_y);
+            self.remember_chunk_data((chunk_x, chunk_y), &chunk);
+            chunk
+        })
+    }
+
+    /// Generate heights for a game chunk, reusing its biomes from the LRU
+    /// cache if an earlier biome-only lookup left them there.
+    ///
+    /// Uses block-level heights at scale 1, otherwise 1:4 approximate
+    /// heights.
+    fn generate_chunk_data(&self, chunk_x: i32, chunk_y: i32) -> ChunkData {
+        let cached = self
+            .biome_cache
+            .borrow_mut()
+            .get((chunk_x, chunk_y))
+            .map(|chunk| chunk.biomes.clone());
+        let biomes = cached.unwrap_or_else(|| self.generate_chunk_uncached(chunk_x, chunk_y));
+        let heights = if self.config.scale == 1 {
+            self.generate_chunk_block_heights(chunk_x, chunk_y)
+        } else {
+            self.generate_chunk_heights(chunk_x, chunk_y)
+        };
+        ChunkData { biomes, heights }
+    }
+
+    /// Generate biomes for many game chunks at once, spread across worker
+    /// threads.
+    ///
+    /// The output is in the same order as `coords` and identical to calling
+    /// [`Self::generate_chunk`] for each. Cached chunks are served from the
+    /// cache; the rest are split between up to one worker per CPU core,
+    /// each with its own cubiomes generator. Worker generators are built on
+    /// first use and reused until the configuration changes.
+    ///
+    /// The `chunk_generation` bench compares this against the serial loop
+    /// for a 16-chunk batch with the cache cleared between iterations.
+    pub fn generate_chunks_parallel(&self, coords: &[(i32, i32)]) -> Vec<BiomeChunk> {
+        let chunks =
+            self.map_chunks_parallel(coords, Self::generate_chunk_uncached, Self::cached_biomes);
+        let mut biome_cache = self.biome_cache.borrow_mut();
+        for (&coord, chunk) in coords.iter().zip(&chunks) {
+            // Don't drop heights an earlier chunk_data call cached
+            if biome_cache.get(coord).is_none() {
+                let chunk = CachedChunk {
+                    biomes: chunk.clone(),
+                    heights: None,
+                };
+                biome_cache.insert(coord, chunk);
+            }
+        }
+        chunks
+    }
+
+    /// Biomes and heights for many game chunks at once, like
+    /// [`Self::generate_chunks_parallel`] but matching [`Self::chunk_data`].
+    pub fn chunk_data_parallel(&self, coords: &[(i32, i32)]) -> Vec<ChunkData> {
+        let chunks =
+            self.map_chunks_parallel(coords, Self::generate_chunk_data, Self::cached_chunk_data);
+        for (&coord, chunk) in coords.iter().zip(&chunks) {
+            self.remember_chunk_data(coord, chunk);
+        }
+        chunks
+    }
+
+    /// Answers each chunk from `lookup` or, failing that, from `generate`
+    /// run on worker threads, keeping the order of `coords`.
+    fn map_chunks_parallel<T: Send>(
+        &self,
+        coords: &[(i32, i32)],
+        generate: fn(&Self, i32, i32) -> T,
+        lookup: fn(&Self, i32, i32) -> Option<T>,
+    ) -> Vec<T> {
+        let cached: Vec<Option<T>> = coords
+            .iter()
+            .map(|&(cx, cy)| lookup(self, cx, cy))
+            .collect();
+        let missing: Vec<(i32, i32)> = coords
+            .iter()
+            .zip(&cached)
+            .filter(|(_, chunk)| chunk.is_none())
+            .map(|(&coord, _)| coord)
+            .collect();
+        let mut generated = self.generate_on_workers(&missing, generate).into_iter();
+        cached
+            .into_iter()
+            .map(|chunk| {
+                chunk.unwrap_or_else(|| generated.next().expect("one generated chunk per miss"))
+            })
+            .collect()
+    }
+
+    /// Runs `generate` for every chunk in `coords`, in order, splitting the
+    /// work across one generator per available core.
+    fn generate_on_workers<T: Send>(
+        &self,
+        coords: &[(i32, i32)],
+        generate: fn(&Self, i32, i32) -> T,
+    ) -> Vec<T> {
+        let workers = thread::available_parallelism()
+            .map_or(1, |n| n.get())
+            .min(coords.len());
+        if workers <= 1 {
+            return coords
+                .iter()
+                .map(|&(cx, cy)| generate(self, cx, cy))
+                .collect();
+        }
+        let per_worker = coords.len().div_ceil(workers);
+
+        // The C generator isn't Sync, so each worker owns one. Workers only
+        // generate, so they don't need caches of their own.
+        let mut pool = self.workers.borrow_mut();
+        while pool.len() < workers {
+            let config = self.config.clone().with_chunk_cache_capacity(0);
+            pool.push(Self::allocate(config));
+        }
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = coords
+                .chunks(per_worker)
+                .zip(pool.iter_mut())
+                .map(|(batch, generator)| {
+                    // Moving the `&mut` in keeps this Send without Sync
+                    scope.spawn(move || {
+                        let generator = &*generator;
+                        batch
+                            .iter()
+                            .map(|&(cx, cy)| generate(generator, cx, cy))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|h| h.join().expect("chunk generation worker panicked"))
+                .collect()
+        })
+    }
+
     /// Get the current configuration.
     pub fn config(&self) -> &WorldGenConfig {
         &self.config
     }
 
+    /// Deterministically classify a game chunk for a feature.
+    ///
+    /// Roughly one chunk in ten qualifies for any given `feature_seed`.
+    /// Passing [`SLIME_CHUNK_SEED`](crate::chunk_features::SLIME_CHUNK_SEED)
+    /// reproduces Minecraft's slime chunks for this world seed.
+    pub fn chunk_has_feature(&self, chunk_coord: (i32, i32), feature_seed: u64) -> bool {
+        crate::chunk_features::chunk_has_feature(
+            self.config.seed,
+            chunk_coord.0,
+            chunk_coord.1,
+            feature_seed,
+        )
+    }
+
     /// Generate biomes for a rectangular region.
     ///
     /// `x` and `z` are in world biome coordinates (at the configured scale).
     /// `width` and `height` are the size of the region.
     pub fn generate_region(&self, x: i32, z: i32, width: i32, height: i32) -> BiomeChunk {
-        let r = Range {
-            scale: self.config.scale,
+        self.generate_region_scaled(self.config.scale, self.config.y_level, x, z, width, height)
+    }
+
+    /// Generate biomes for a region at an explicit scale and Y level.
+    fn generate_region_scaled(
+        &self,
+        scale: i32,
+        y: i32,
+        x: i32,
+        z: i32,
+        width: i32,
+        height: i32,
+    ) -> BiomeChunk {
+        self.generate_range(Range {
+            scale,
             x,
             z,
             sx: width,
             sz: height,
-            y: self.config.y_level,
+            y,
             sy: 1,
-        };
+        })
+    }
+
+    /// Generate biomes for a cubiomes range (single Y layer).
+    fn generate_range(&self, r: Range) -> BiomeChunk {
+        let Range {
+            scale,
+            x,
+            z,
+            sx: width,
+            sz: height,
+            ..
+        } = r;
 
         unsafe {
             let cache = allocCache(self.gen, r);
@@ -171,40 +819,268 @@ impl WorldGenerator {
             assert_eq!(err, 0, "genBiomes failed with error {}", err);
 
             let count = (width * height) as usize;
-            let biomes = std::slice::from_raw_parts(cache, count).to_vec();
+            let mut biomes = std::slice::from_raw_parts(cache, count).to_vec();
 
             // Free the C-allocated cache
             libc_free(cache as *mut std::ffi::c_void);
 
+            for id in &mut biomes {
+                *id = self.substitute(*id);
+            }
+
             BiomeChunk {
                 biomes,
                 width,
                 height,
                 origin_x: x,
                 origin_z: z,
-                scale: self.config.scale,
+                scale,
             }
         }
     }
 
+    /// Legend of the biomes present in a range, most common first.
+    ///
+    /// Each entry is `(biome id, name, color)` with the name and color taken
+    /// from `map`. Ties are broken by biome ID.
+    pub fn legend_for(&self, range: Range, map: &BiomeTextureMap) -> Vec<(i32, String, [u8; 3])> {
+        let mut counts: Vec<(i32, usize)> = unique_biomes(&self.generate_range(range))
+            .into_iter()
+            .collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        counts
+            .into_iter()
+            .map(|(id, _)| (id, map.biome_name(id).to_string(), map.get_color(id)))
+            .collect()
+    }
+
     /// Generate biomes for a chunk at the given game chunk coordinates.
     ///
     /// Each chunk is 16×16 biome cells at the configured scale.
     /// `chunk_x` maps to cubiomes X (east/west).
     /// `chunk_y` maps to cubiomes Z (north/south) for top-down view.
+    /// Chunks precomputed by [`Self::warmup_region`] or generated recently
+    /// (see [`WorldGenConfig::chunk_cache_capacity`]) come from the cache.
     pub fn generate_chunk(&self, chunk_x: i32, chunk_y: i32) -> BiomeChunk {
-        let chunk_size = 16;
-        self.generate_region(
-            chunk_x * chunk_size,
-            chunk_y * chunk_size,
-            chunk_size,
-            chunk_size,
-        )
+        self.cached_biomes(chunk_x, chunk_y).unwrap_or_else(|| {
+            let chunk = self.generate_chunk_uncached(chunk_x, chunk_y);
+            let cached = CachedChunk {
+                biomes: chunk.clone(),
+                heights: None,
+            };
+            self.biome_cache
+                .borrow_mut()
+                .insert((chunk_x, chunk_y), cached);
+            chunk
+        })
+    }
+
+    /// Generate biomes for a game chunk, bypassing the cache.
+    fn generate_chunk_uncached(&self, chunk_x: i32, chunk_y: i32) -> BiomeChunk {
+        self.generate_range(Range::for_chunk(
+            chunk_x,
+            chunk_y,
+            16,
+            self.config.scale,
+            self.config.y_level,
+            1,
+        ))
+    }
+
+    /// Sample the climate noise at block coordinates.
+    ///
+    /// Only 1.18+ overworld generation is driven by climate noise; other
+    /// versions return `None`.
+    pub fn climate_at(&self, x: i32, z: i32) -> Option<ClimateSample> {
+        let mut out = [0.0f32; 5];
+        let ret = unsafe { cubiomes_sample_climate(out.as_mut_ptr(), self.gen, x, z) };
+        if ret != 0 {
+            return None;
+        }
+        let [temperature, humidity, continentalness, erosion, weirdness] = out;
+        Some(ClimateSample {
+            temperature,
+            humidity,
+            continentalness,
+            erosion,
+            weirdness,
+        })
+    }
+
+    /// Biome of a single cell, without generating a chunk.
+    ///
+    /// `world_x` and `world_z` are cell coordinates at the configured scale,
+    /// on the same grid [`Self::generate_chunk`] fills: cell `(i, j)` of
+    /// chunk `(cx, cy)` is `(cx * 16 + i, cy * 16 + j)`, with game Y passed
+    /// as cubiomes Z. Nothing is cached, so this is cheap enough to call
+    /// every frame for the player's position.
+    pub fn biome_at(&self, world_x: i32, world_z: i32) -> i32 {
+        let (scale, y) = (self.config.scale, self.config.y_level);
+        let id = unsafe { getBiomeAt(self.gen, scale, world_x, y, world_z) };
+        self.substitute(id)
+    }
+
+    /// Whether `(x, z)` is a safe place to (re)spawn a player.
+    ///
+    /// The block-scale biome must be a valid overworld land biome (not ocean,
+    /// river, nether or end) and the surface must sit above the configured
+    /// sea level.
+    pub fn is_safe_spawn(&self, x: i32, z: i32) -> bool {
+        let id = self.substitute(unsafe { getBiomeAt(self.gen, 1, x, self.config.y_level, z) });
+        if id < 0 {
+            return false;
+        }
+        let group = BiomeTextureMap::group_of(id);
+        if group.is_water() || group == BiomeGroup::Other {
+            return false;
+        }
+        let surface = self.generate_block_heights(x, z, 1, 1)[0];
+        !self.config.is_submerged(surface)
+    }
+
+    /// Find the safe spawn point nearest to `(x, z)`, searching outward in
+    /// square rings up to `max_radius` blocks.
+    ///
+    /// Candidates are spaced [`SPAWN_SEARCH_STEP`] blocks apart and checked
+    /// nearest first. Returns `(x, z)` itself when it is already safe, or
+    /// `None` if nothing within the radius is.
+    pub fn find_safe_spawn(&self, x: i32, z: i32, max_radius: i32) -> Option<(i32, i32)> {
+        if self.is_safe_spawn(x, z) {
+            return Some((x, z));
+        }
+        let step = SPAWN_SEARCH_STEP;
+        for ring in 1..=max_radius / step {
+            let mut candidates: Vec<(i32, i32)> = (-ring..=ring)
+                .flat_map(|i| [(i, -ring), (i, ring), (-ring, i), (ring, i)])
+                .collect();
+            candidates.sort_by_key(|&(dx, dz)| (dx * dx + dz * dz, dz, dx));
+            candidates.dedup();
+            if let Some((dx, dz)) = candidates
+                .into_iter()
+                .find(|&(dx, dz)| self.is_safe_spawn(x + dx * step, z + dz * step))
+            {
+                return Some((x + dx * step, z + dz * step));
+            }
+        }
+        None
+    }
+
+    /// Check whether a biome occurs within `radius` blocks of `(x, z)`.
+    ///
+    /// Samples at 1:4 scale, which is plenty for seed scanning and much
+    /// cheaper than block-level generation.
+    pub fn biome_within(&self, biome: i32, x: i32, z: i32, radius: i32) -> bool {
+        let radius = radius.max(1);
+        let cells = radius * 2 / 4 + 1;
+        let chunk = self.generate_region_scaled(
+            4,
+            self.config.y_level / 4,
+            (x - radius).div_euclid(4),
+            (z - radius).div_euclid(4),
+            cells,
+            cells,
+        );
+        chunk.biomes.contains(&biome)
+    }
+
+    /// Find viable structures of a kind inside `region`.
+    ///
+    /// `region` is read as a horizontal box at its own `scale` (its `y` and
+    /// `sy` are ignored). Every placement region overlapping the box is
+    /// asked for its structure attempt, which is kept when it lands inside
+    /// the box and its biomes allow it. Uses the configured MC version and
+    /// seed, and returns block positions in region order. Only overworld
+    /// structures can be found, since the generator samples the overworld.
+    pub fn find_structures(&self, kind: StructureKind, region: Range) -> Vec<Pos> {
+        let scale = region.scale.max(1);
+        let (x0, z0) = (region.x * scale, region.z * scale);
+        let (x1, z1) = (
+            (region.x + region.sx) * scale,
+            (region.z + region.sz) * scale,
+        );
+        self.scan_structures(kind, (x0, z0, x1, z1), |pos| {
+            (x0..x1).contains(&pos.x) && (z0..z1).contains(&pos.z)
+        })
+    }
+
+    /// Find viable structures of a kind within `radius` blocks of `(x, z)`.
+    ///
+    /// Returns block positions sorted by distance from `(x, z)`.
+    pub fn find_structures_near(
+        &self,
+        kind: StructureKind,
+        x: i32,
+        z: i32,
+        radius: i32,
+    ) -> Vec<(i32, i32)> {
+        let radius_sq = i64::from(radius) * i64::from(radius);
+        let dist_sq = |(px, pz): (i32, i32)| {
+            let dx = i64::from(px - x);
+            let dz = i64::from(pz - z);
+            dx * dx + dz * dz
+        };
+        let bounds = (x - radius, z - radius, x + radius + 1, z + radius + 1);
+
+        let mut found: Vec<(i32, i32)> = self
+            .scan_structures(kind, bounds, |pos| dist_sq((pos.x, pos.z)) <= radius_sq)
+            .into_iter()
+            .map(|pos| (pos.x, pos.z))
+            .collect();
+        found.sort_by_key(|&p| dist_sq(p));
+        found
+    }
+
+    /// Structure attempts in the regions overlapping the block box
+    /// `[x0, x1) × [z0, z1)` that pass `accept` and the biome check.
+    ///
+    /// `accept` runs first so cheap bounds checks skip the biome sampling.
+    fn scan_structures(
+        &self,
+        kind: StructureKind,
+        (x0, z0, x1, z1): (i32, i32, i32, i32),
+        accept: impl Fn(&Pos) -> bool,
+    ) -> Vec<Pos> {
+        if x1 <= x0 || z1 <= z0 || kind.dimension() != DIM_OVERWORLD {
+            return Vec::new();
+        }
+        let Some(region_chunks) = kind.region_chunks(self.config.mc_version) else {
+            return Vec::new();
+        };
+        let region_blocks = region_chunks * 16;
+        let struct_type = kind.to_raw();
+
+        let mut found = Vec::new();
+        for rz in z0.div_euclid(region_blocks)..=(z1 - 1).div_euclid(region_blocks) {
+            for rx in x0.div_euclid(region_blocks)..=(x1 - 1).div_euclid(region_blocks) {
+                let mut pos = Pos::default();
+                unsafe {
+                    let placed = getStructurePos(
+                        struct_type,
+                        self.config.mc_version,
+                        self.config.seed,
+                        rx,
+                        rz,
+                        &mut pos,
+                    );
+                    if placed == 0 || !accept(&pos) {
+                        continue;
+                    }
+                    if isViableStructurePos(struct_type, self.gen, pos.x, pos.z, 0) != 0 {
+                        found.push(pos);
+                    }
+                }
+            }
+        }
+        found
     }
 
-    /// Get a single biome at world coordinates (block scale).
-    pub fn get_biome_at(&self, x: i32, z: i32) -> i32 {
-        unsafe { getBiomeAt(self.gen, self.config.scale, x, self.config.y_level, z) }
+    /// Apply the configured biome substitution to a generated biome ID.
+    fn substitute(&self, id: i32) -> i32 {
+        self.config
+            .biome_substitutions
+            .get(&id)
+            .copied()
+            .unwrap_or(id)
     }
 
     /// Generate approximate surface heights for a rectangular region at 1:4 scale.
@@ -235,14 +1111,32 @@ impl WorldGenerator {
 
     /// Generate heights for a chunk at game chunk coordinates.
     /// Each chunk is 16×16 cells at 1:4 scale.
+    /// Amplified worlds get [`amplify_height_around`] the configured sea
+    /// level applied.
     pub fn generate_chunk_heights(&self, chunk_x: i32, chunk_y: i32) -> Vec<f32> {
         let chunk_size = 16;
-        self.generate_heights(
+        let heights = self.generate_heights(
             chunk_x * chunk_size,
             chunk_y * chunk_size,
             chunk_size,
             chunk_size,
-        )
+        );
+        self.apply_amplified(heights)
+    }
+
+    /// Surface height at a single 1:4 cell, matching the corresponding entry
+    /// of [`Self::generate_chunk_heights`] (amplification included).
+    ///
+    /// `world_x` and `world_z` are in 1:4 coordinates, so cell `(x, z)` of
+    /// chunk `(cx, cy)` is at `(cx * 16 + x, cy * 16 + z)`. Useful for
+    /// placing a single entity on the ground without generating a chunk.
+    pub fn height_at(&self, world_x: i32, world_z: i32) -> f32 {
+        let height = self.generate_heights(world_x, world_z, 1, 1)[0];
+        if self.config.amplified {
+            amplify_height_around(height, self.config.sea_level)
+        } else {
+            height
+        }
     }
 
     /// Generate surface heights at true block-level (1:1) resolution for MC 1.18+.
@@ -274,12 +1168,23 @@ impl WorldGenerator {
     /// Each chunk is 16×16 blocks at 1:1 scale.
     pub fn generate_chunk_block_heights(&self, chunk_x: i32, chunk_y: i32) -> Vec<f32> {
         let chunk_size = 16;
-        self.generate_block_heights(
+        let heights = self.generate_block_heights(
             chunk_x * chunk_size,
             chunk_y * chunk_size,
             chunk_size,
             chunk_size,
-        )
+        );
+        self.apply_amplified(heights)
+    }
+
+    /// Exaggerate chunk heights when the world is amplified.
+    pub(crate) fn apply_amplified(&self, mut heights: Vec<f32>) -> Vec<f32> {
+        if self.config.amplified {
+            for h in &mut heights {
+                *h = amplify_height_around(*h, self.config.sea_level);
+            }
+        }
+        heights
     }
 
     /// Get the biome name for a given ID.
@@ -326,6 +1231,46 @@ impl WorldGenerator {
 
         (chunk, rgb)
     }
+
+    /// Render a small RGBA biome preview centred on spawn.
+    ///
+    /// Covers `world_radius` blocks around the world origin (where players
+    /// spawn) and returns `size * size * 4` bytes. Biomes are sampled at the
+    /// coarsest scale that still gives one cell per pixel, so this stays
+    /// cheap enough to call from UI code when the seed changes.
+    pub fn thumbnail(&self, size: u32, world_radius: i32) -> Vec<u8> {
+        let size = size.max(1);
+        let span = (world_radius.max(1) * 2) as u32;
+
+        // Coarsest cubiomes scale that still has at least one cell per pixel
+        let scale = [256, 64, 16, 4]
+            .into_iter()
+            .find(|&s| span / s as u32 >= size)
+            .unwrap_or(4);
+        // Vertical range is at 1:4 for every scale above 1:1
+        let y = self.config.y_level / 4;
+
+        let cells = (span as i32 / scale).max(1);
+        let origin = -world_radius.max(1) / scale;
+        let chunk = self.generate_region_scaled(scale, y, origin, origin, cells, cells);
+
+        let colors = default_biome_colors();
+        let mut rgba = Vec::with_capacity((size * size * 4) as usize);
+        for py in 0..size {
+            let cz = (py as u64 * cells as u64 / size as u64) as i32;
+            for px in 0..size {
+                let cx = (px as u64 * cells as u64 / size as u64) as i32;
+                let id = chunk.get(cx, cz);
+                let [r, g, b] = if (0..256).contains(&id) {
+                    colors[id as usize]
+                } else {
+                    [128, 128, 128]
+                };
+                rgba.extend_from_slice(&[r, g, b, 255]);
+            }
+        }
+        rgba
+    }
 }
 
 impl Drop for WorldGenerator {
@@ -353,3 +1298,478 @@ pub fn unique_biomes(chunk: &BiomeChunk) -> HashMap<i32, usize> {
     }
     counts
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gen_flags_round_trip_raw() {
+        for raw in 0..8 {
+            assert_eq!(GenFlags::from_raw(raw).to_raw(), raw);
+        }
+        let flags = GenFlags::from_raw(LARGE_BIOMES | FORCE_OCEAN_VARIANTS);
+        assert!(flags.large_biomes);
+        assert!(!flags.no_beta_ocean);
+        assert!(flags.force_ocean_variants);
+        assert_eq!(c_uint::from(flags), LARGE_BIOMES | FORCE_OCEAN_VARIANTS);
+        assert_eq!(GenFlags::from_raw(0x100), GenFlags::NONE);
+        assert!(GenFlags::default().is_empty());
+    }
+
+    #[test]
+    fn test_gen_flags_combine() {
+        let large = GenFlags {
+            large_biomes: true,
+            ..GenFlags::NONE
+        };
+        let no_beta = GenFlags::from(NO_BETA_OCEAN);
+        let both = large | no_beta;
+        assert!(both.large_biomes && both.no_beta_ocean);
+        assert!(!both.force_ocean_variants);
+        assert_eq!(both.to_raw(), LARGE_BIOMES | NO_BETA_OCEAN);
+        assert_eq!(both | large, both);
+    }
+
+    #[test]
+    fn test_scale_presets_map_to_raw_scale() {
+        let expected = [
+            (BiomeScale::Block, 1, false),
+            (BiomeScale::Quad, 4, false),
+            (BiomeScale::Chunk, 16, false),
+            (BiomeScale::Region, 256, false),
+            (BiomeScale::LargeBiomes, 4, true),
+        ];
+        for (preset, scale, large) in expected {
+            let config = WorldGenConfig {
+                flags: GenFlags::from_raw(LARGE_BIOMES | FORCE_OCEAN_VARIANTS),
+                ..Default::default()
+            }
+            .with_scale_preset(preset);
+            assert_eq!(config.scale, scale, "{preset:?}");
+            assert_eq!(config.flags.large_biomes, large, "{preset:?}");
+            // Other flags are left alone
+            assert!(config.flags.force_ocean_variants);
+            assert_eq!(config.scale_preset(), Some(preset));
+        }
+        assert_eq!(BiomeScale::from_raw(64, false), None);
+        assert_eq!(BiomeScale::from_raw(1, true), None);
+    }
+
+    #[test]
+    fn test_climate_is_continuous() {
+        let gen = WorldGenerator::new(WorldGenConfig {
+            seed: 42,
+            ..Default::default()
+        });
+        let samples: Vec<ClimateSample> = (0..256)
+            .map(|i| {
+                gen.climate_at(i * 4 - 512, 300)
+                    .expect("1.18 has climate noise")
+            })
+            .collect();
+        for pair in samples.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            assert!(
+                (a.temperature - b.temperature).abs() < 0.05,
+                "{a:?} -> {b:?}"
+            );
+            assert!((a.humidity - b.humidity).abs() < 0.05, "{a:?} -> {b:?}");
+            assert!(
+                (a.continentalness - b.continentalness).abs() < 0.05,
+                "{a:?} -> {b:?}"
+            );
+        }
+        // Over a kilometre the climate does change
+        let first = samples[0];
+        assert!(samples
+            .iter()
+            .any(|s| (s.temperature - first.temperature).abs() > 0.01));
+
+        let legacy = WorldGenerator::new(WorldGenConfig {
+            mc_version: MC_1_16,
+            ..Default::default()
+        });
+        assert_eq!(legacy.climate_at(0, 0), None);
+    }
+
+    #[test]
+    fn test_legend_lists_present_biomes() {
+        let gen = WorldGenerator::new(WorldGenConfig {
+            seed: 42,
+            ..Default::default()
+        });
+        let map = BiomeTextureMap::default();
+        let range = Range::for_region(-512, -512, 1024, 1024, 4, 16, 1);
+
+        let legend = gen.legend_for(range, &map);
+        let counts = unique_biomes(&gen.generate_range(range));
+        assert!(legend.len() > 1);
+        assert_eq!(legend.len(), counts.len());
+        for (id, name, color) in &legend {
+            assert!(counts.contains_key(id));
+            assert_eq!(name, map.biome_name(*id));
+            assert_eq!(*color, map.get_color(*id));
+        }
+        for pair in legend.windows(2) {
+            assert!(counts[&pair[0].0] >= counts[&pair[1].0]);
+        }
+    }
+
+    fn variance(values: &[f32]) -> f32 {
+        let mean = values.iter().sum::<f32>() / values.len() as f32;
+        values.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / values.len() as f32
+    }
+
+    #[test]
+    fn test_ocean_spawn_is_unsafe_and_search_finds_land() {
+        let gen = WorldGenerator::new(WorldGenConfig {
+            seed: 42,
+            ..Default::default()
+        });
+        // Pick the first deep-ocean cell in a wide 1:16 scan
+        let region = gen.generate_region_scaled(16, 4, -128, -128, 256, 256);
+        let index = region
+            .biomes
+            .iter()
+            .position(|&id| id == BIOME_DEEP_OCEAN)
+            .expect("seed 42 has deep ocean near the origin");
+        let x = (region.origin_x + index as i32 % region.width) * 16 + 8;
+        let z = (region.origin_z + index as i32 / region.width) * 16 + 8;
+        assert!(!gen.is_safe_spawn(x, z));
+
+        let (sx, sz) = gen.find_safe_spawn(x, z, 4096).expect("land within range");
+        assert!(gen.is_safe_spawn(sx, sz));
+        assert!((sx - x).abs() <= 4096 && (sz - z).abs() <= 4096);
+        assert_eq!((sx - x) % SPAWN_SEARCH_STEP, 0);
+        // A safe point is its own answer
+        assert_eq!(gen.find_safe_spawn(sx, sz, 4096), Some((sx, sz)));
+    }
+
+    #[test]
+    fn test_amplify_height_stretches_around_sea_level() {
+        assert!((amplify_height(HEIGHT_SEA_LEVEL) - HEIGHT_SEA_LEVEL).abs() < f32::EPSILON);
+        assert!((amplify_height(103.0) - 163.0).abs() < 1e-4);
+        assert!((amplify_height(43.0) - 33.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_lower_sea_level_submerges_fewer_cells() {
+        let heights = [40.0, 55.0, 60.0, 63.0, 64.0, 70.0, 90.0, 58.0];
+        let default = WorldGenConfig::default();
+        let low = WorldGenConfig::default().with_sea_level(56.0);
+        assert!((default.sea_level - HEIGHT_SEA_LEVEL).abs() < f32::EPSILON);
+
+        let wet = default.submerged_mask(&heights);
+        let low_wet = low.submerged_mask(&heights);
+        assert_eq!(wet.iter().filter(|&&w| w).count(), 5);
+        assert_eq!(low_wet.iter().filter(|&&w| w).count(), 2);
+        // Everything dry at the default level stays dry
+        assert!(low_wet.iter().zip(&wet).all(|(&l, &d)| !l || d));
+
+        assert!((default.water_depth(40.0) - 23.0).abs() < 1e-4);
+        assert!((low.water_depth(40.0) - 16.0).abs() < 1e-4);
+        assert!(low.water_depth(60.0).abs() < f32::EPSILON);
+        assert!((amplify_height_around(46.0, 56.0) - 41.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_warmup_region_caches_spawn_chunks() {
+        let mut gen = WorldGenerator::new(WorldGenConfig {
+            seed: 42,
+            ..Default::default()
+        });
+        assert_eq!(gen.warmup_region((4, -2), 1), 9);
+        assert_eq!(gen.cached_chunk_count(), 9);
+        assert!(gen.is_chunk_cached(3, -3) && gen.is_chunk_cached(5, -1));
+        assert!(!gen.is_chunk_cached(6, -2));
+        // Already cached chunks are not regenerated
+        assert_eq!(gen.warmup_region((4, -2), 0), 0);
+
+        let (hits, _) = gen.cache_stats();
+        let cached = gen.generate_chunk(4, -2);
+        assert_eq!(gen.cache_stats().0, hits + 1);
+        assert_eq!(cached, gen.generate_chunk_uncached(4, -2));
+        assert_eq!(gen.chunk_data(5, -1), gen.generate_chunk_data(5, -1));
+        assert_eq!(gen.cache_stats().0, hits + 2);
+        gen.generate_chunk(6, -2);
+        assert_eq!(gen.cache_stats().0, hits + 2);
+
+        // A biome-only entry still needs its heights warmed up
+        assert!(!gen.is_chunk_cached(6, -2));
+        assert_eq!(gen.warmup_region((6, -2), 0), 1);
+        assert!(gen.is_chunk_cached(6, -2));
+
+        // A new seed invalidates the cache
+        gen.set_seed(43);
+        assert_eq!(gen.cached_chunk_count(), 0);
+    }
+
+    #[test]
+    fn test_histogram_and_dominant_biome() {
+        let chunk = |biomes: Vec<i32>| BiomeChunk {
+            width: biomes.len() as i32,
+            height: 1,
+            biomes,
+            origin_x: 0,
+            origin_z: 0,
+            scale: 4,
+        };
+        let mixed = chunk(vec![
+            BIOME_FOREST,
+            BIOME_PLAINS,
+            BIOME_PLAINS,
+            BIOME_RIVER,
+            BIOME_PLAINS,
+            BIOME_FOREST,
+        ]);
+        let histogram = mixed.histogram();
+        assert_eq!(histogram.len(), 3);
+        assert_eq!(histogram[&BIOME_PLAINS], 3);
+        assert_eq!(histogram[&BIOME_FOREST], 2);
+        assert_eq!(histogram[&BIOME_RIVER], 1);
+        assert_eq!(histogram.values().sum::<u32>(), 6);
+        assert_eq!(mixed.dominant_biome(), BIOME_PLAINS);
+
+        // Ties go to the lowest ID whatever the cell order
+        let tied = chunk(vec![BIOME_RIVER, BIOME_FOREST, BIOME_FOREST, BIOME_RIVER]);
+        assert_eq!(tied.dominant_biome(), BIOME_FOREST.min(BIOME_RIVER));
+        assert_eq!(chunk(Vec::new()).dominant_biome(), BIOME_NONE);
+    }
+
+    #[test]
+    fn test_height_at_matches_chunk_heights() {
+        for amplified in [false, true] {
+            let gen = WorldGenerator::new(WorldGenConfig {
+                seed: 1234,
+                amplified,
+                ..Default::default()
+            });
+            let (cx, cy) = (3, -2);
+            let heights = gen.generate_chunk_heights(cx, cy);
+            for (x, z) in [(0, 0), (5, 11), (15, 15)] {
+                let expected = heights[(z * 16 + x) as usize];
+                let sampled = gen.height_at(cx * 16 + x, cy * 16 + z);
+                assert!(
+                    (sampled - expected).abs() <= f32::EPSILON * expected.abs().max(1.0),
+                    "({x}, {z}): {sampled} != {expected}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_lru_cache_survives_unrelated_reconfigure() {
+        let config = WorldGenConfig {
+            seed: 42,
+            ..Default::default()
+        }
+        .with_chunk_cache_capacity(2);
+        let mut gen = WorldGenerator::new(config.clone());
+        let first = gen.generate_chunk(0, 0);
+        assert_eq!(gen.cache_stats(), (0, 1));
+        assert_eq!(gen.generate_chunk(0, 0), first);
+        assert_eq!(gen.cache_stats(), (1, 1));
+
+        // Sea level and amplification only affect heights
+        gen.reconfigure(config.clone().with_sea_level(40.0));
+        gen.generate_chunk(0, 0);
+        assert_eq!(gen.cache_stats(), (2, 1));
+
+        gen.reconfigure(WorldGenConfig { seed: 7, ..config });
+        assert_ne!(gen.generate_chunk(0, 0), first);
+        assert_eq!(gen.cache_stats(), (2, 2));
+
+        gen.clear_cache();
+        gen.generate_chunk(0, 0);
+        assert_eq!(gen.cache_stats(), (2, 3));
+    }
+
+    #[test]
+    fn test_chunk_data_goes_through_lru_cache() {
+        let config = WorldGenConfig {
+            seed: 42,
+            ..Default::default()
+        };
+        let mut gen = WorldGenerator::new(config.clone());
+        let first = gen.chunk_data(0, 0);
+        assert_eq!(gen.cache_stats(), (0, 1));
+        assert_eq!(gen.chunk_data(0, 0).heights, first.heights);
+        assert_eq!(gen.cache_stats(), (1, 1));
+
+        // The batch path used by the engine reads and fills the same cache
+        let batch = gen.chunk_data_parallel(&[(0, 0), (1, 0)]);
+        assert_eq!(batch[0].biomes, first.biomes);
+        assert_eq!(gen.cache_stats(), (2, 2));
+        gen.chunk_data(1, 0);
+        assert_eq!(gen.cache_stats(), (3, 2));
+
+        // Biome-only entries are upgraded with heights on the first request
+        gen.generate_chunk(2, 0);
+        gen.chunk_data(2, 0);
+        gen.chunk_data(2, 0);
+        assert_eq!(gen.cache_stats(), (4, 4));
+
+        // Height-only changes keep biomes but regenerate heights
+        gen.reconfigure(config.with_sea_level(40.0));
+        gen.chunk_data(0, 0);
+        assert_eq!(gen.cache_stats(), (4, 5));
+    }
+
+    #[test]
+    fn test_amplified_increases_height_variance() {
+        let config = WorldGenConfig {
+            seed: 42,
+            ..Default::default()
+        };
+        let normal = WorldGenerator::new(config.clone());
+        let amplified = WorldGenerator::new(WorldGenConfig {
+            amplified: true,
+            ..config
+        });
+
+        let mut hilly = 0;
+        for (cx, cy) in [(0, 0), (5, -3), (-12, 8), (40, 40)] {
+            let base = normal.generate_chunk_heights(cx, cy);
+            let tall = amplified.generate_chunk_heights(cx, cy);
+            assert_eq!(base.len(), tall.len());
+            if variance(&base) > 0.0 {
+                hilly += 1;
+                assert!(variance(&tall) > variance(&base), "chunk ({cx}, {cy})");
+            }
+        }
+        assert!(hilly > 0);
+    }
+
+    #[test]
+    fn test_thumbnail_size_and_variety() {
+        let gen = WorldGenerator::new(WorldGenConfig {
+            seed: 42,
+            ..Default::default()
+        });
+        let size = 32;
+        let rgba = gen.thumbnail(size, 4096);
+        assert_eq!(rgba.len(), (size * size * 4) as usize);
+
+        let first = &rgba[0..4];
+        assert!(
+            rgba.chunks_exact(4).any(|px| px != first),
+            "thumbnail should show more than one biome"
+        );
+    }
+
+    #[test]
+    fn test_biome_substitutions() {
+        let plain = WorldGenerator::new(WorldGenConfig {
+            seed: 42,
+            ..Default::default()
+        });
+        let original = plain.generate_chunk(0, 0);
+        let (&common, _) = unique_biomes(&original)
+            .iter()
+            .max_by_key(|(_, &count)| count)
+            .unwrap();
+        let replacement = if common == 1 { 4 } else { 1 };
+
+        let substituted = WorldGenerator::new(WorldGenConfig {
+            seed: 42,
+            biome_substitutions: HashMap::from([(common, replacement)]),
+            ..Default::default()
+        });
+        let chunk = substituted.generate_chunk(0, 0);
+        assert!(!chunk.biomes.contains(&common));
+        for (&before, &after) in original.biomes.iter().zip(&chunk.biomes) {
+            if before == common {
+                assert_eq!(after, replacement);
+            } else {
+                assert_eq!(after, before);
+            }
+        }
+    }
+
+    #[test]
+    fn test_set_seed_matches_fresh_generator() {
+        let mut gen = WorldGenerator::new(WorldGenConfig {
+            seed: 1,
+            ..Default::default()
+        });
+        gen.set_seed(987_654_321);
+        assert_eq!(gen.config().seed, 987_654_321);
+
+        let fresh = WorldGenerator::new(WorldGenConfig {
+            seed: 987_654_321,
+            ..Default::default()
+        });
+        for (cx, cy) in [(0, 0), (3, -2), (-7, 5)] {
+            assert_eq!(gen.generate_chunk(cx, cy), fresh.generate_chunk(cx, cy));
+        }
+    }
+
+    #[test]
+    fn test_find_structures_in_region() {
+        let gen = WorldGenerator::new(WorldGenConfig::default());
+        let region = Range::for_region(-2048, -2048, 4096, 4096, 1, 0, 1);
+        let villages = gen.find_structures(StructureKind::Village, region);
+        assert!(!villages.is_empty());
+        for pos in &villages {
+            assert!((-2048..2048).contains(&pos.x) && (-2048..2048).contains(&pos.z));
+        }
+
+        // The same box at 1:16 finds the same villages
+        let coarse = Range::for_region(-2048, -2048, 4096, 4096, 16, 0, 1);
+        let coarse: Vec<_> = gen
+            .find_structures(StructureKind::Village, coarse)
+            .iter()
+            .map(|p| (p.x, p.z))
+            .collect();
+        assert_eq!(
+            coarse,
+            villages.iter().map(|p| (p.x, p.z)).collect::<Vec<_>>()
+        );
+
+        let near = gen.find_structures_near(StructureKind::Village, 0, 0, 1024);
+        assert!(near.iter().all(|p| coarse.contains(p)));
+        assert!(gen
+            .find_structures(StructureKind::Fortress, region)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_biome_at_matches_generated_chunk() {
+        for scale in [1, 4] {
+            let gen = WorldGenerator::new(WorldGenConfig {
+                scale,
+                ..Default::default()
+            });
+            let (cx, cy) = (3, -2);
+            let chunk = gen.generate_chunk(cx, cy);
+            for (i, j) in [(0, 0), (5, 11), (15, 15), (8, 2)] {
+                assert_eq!(
+                    gen.biome_at(cx * 16 + i, cy * 16 + j),
+                    chunk.get(i, j),
+                    "scale {scale}, cell ({i}, {j})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_parallel_chunks_match_serial_order() {
+        let gen = WorldGenerator::new(WorldGenConfig::default());
+        let coords: Vec<(i32, i32)> = (-2..2).flat_map(|y| (-2..2).map(move |x| (x, y))).collect();
+        let serial: Vec<BiomeChunk> = coords
+            .iter()
+            .map(|&(cx, cy)| gen.generate_chunk(cx, cy))
+            .collect();
+        assert_eq!(gen.generate_chunks_parallel(&coords), serial);
+
+        // Cached chunks are mixed back in at their original positions
+        gen.warmup_region((0, 0), 1);
+        let data = gen.chunk_data_parallel(&coords);
+        assert_eq!(data.len(), coords.len());
+        for (chunk, &(cx, cy)) in data.iter().zip(&coords) {
+            assert_eq!(chunk, &gen.chunk_data(cx, cy));
+        }
+        assert!(gen.generate_chunks_parallel(&[]).is_empty());
+    }
+}
@@ -6,11 +6,41 @@
 
 pub mod biome_height;
 pub mod biome_map;
+pub mod biome_provider;
+pub mod biome_regions;
+pub mod chunk_features;
+mod chunk_lru;
+pub mod chunk_format;
+pub mod chunk_source;
 pub mod generator;
+pub mod range;
+pub mod river_distance;
+pub mod seed_scan;
+pub mod structure;
+pub mod thumbnails;
+pub mod version_diff;
 
-pub use biome_height::biome_height;
-pub use biome_map::{BiomeEntry, BiomeTextureMap, BiomeVisual};
-pub use generator::{BiomeChunk, WorldGenConfig, WorldGenerator};
+pub use biome_height::{biome_height, biome_surface_height, water_depth};
+pub use biome_map::{
+    color_distance, rgb_to_lab, BiomeEntry, BiomeGroup, BiomeTextureMap, BiomeVisual,
+    NEIGHBOR_BIOME_PAIRS,
+};
+pub use biome_provider::{BiomeProvider, FlatBiomeProvider};
+pub use biome_regions::{biome_regions, BiomeRegion, BiomeRegionMap};
+pub use chunk_features::{chunk_has_feature, SLIME_CHUNK_SEED};
+pub use chunk_format::{ChunkEncoding, ChunkHeader, ChunkSerializer, CHUNK_FORMAT_VERSION};
+pub use chunk_source::{ChunkData, ChunkSource, GeneratedSource, LayeredSource, SavedSource};
+pub use generator::{
+    amplify_height, amplify_height_around, BiomeChunk, BiomeScale, ClimateSample, GenFlags,
+    WorldGenConfig, WorldGenerator, DEFAULT_CHUNK_CACHE_CAPACITY,
+};
+pub use genesis_common::{Biome, Climate};
+pub use range::RangeExt;
+pub use river_distance::river_distance_field;
+pub use seed_scan::{BiomeRequirement, SeedCriteria, StructureRequirement, SPAWN_SEARCH_RADIUS};
+pub use structure::StructureKind;
+pub use thumbnails::{SeedThumbnail, ThumbnailHandle};
+pub use version_diff::{BiomeDifference, DiffRegion, VersionDiff};
 
 // Re-export key cubiomes constants for convenience
 pub use cubiomes_sys::{
@@ -24,6 +54,8 @@ pub use cubiomes_sys::{
     BIOME_SNOWY_TUNDRA, BIOME_JUNGLE, BIOME_BIRCH_FOREST, BIOME_DARK_FOREST,
     BIOME_SAVANNA, BIOME_BADLANDS, BIOME_MUSHROOM_FIELDS, BIOME_MEADOW,
     BIOME_CHERRY_GROVE, BIOME_DEEP_OCEAN, BIOME_WARM_OCEAN,
+    // Finder types
+    Pos, Range,
     // Utility
     all_mc_versions, mc_version_name, biome_name,
 };
@@ -0,0 +1,108 @@
+//! Builders for cubiomes [`Range`] requests.
+//!
+//! A `Range` mixes scaled horizontal coordinates with a raw scale factor,
+//! which makes hand-built ranges easy to get wrong. These constructors keep
+//! the chunk and block-to-cell math in one place.
+
+use cubiomes_sys::Range;
+
+/// Chunk- and region-aligned constructors for [`Range`].
+///
+/// Bring the trait into scope to call `Range::for_chunk(..)` and
+/// `Range::for_region(..)`.
+pub trait RangeExt: Sized {
+    /// Range covering one game chunk of `chunk_cells × chunk_cells` cells.
+    ///
+    /// Chunk `(chunk_x, chunk_z)` starts at cell
+    /// `(chunk_x * chunk_cells, chunk_z * chunk_cells)` at the given `scale`.
+    /// `y` and `sy` are passed through unchanged.
+    fn for_chunk(chunk_x: i32, chunk_z: i32, chunk_cells: i32, scale: i32, y: i32, sy: i32)
+        -> Self;
+
+    /// Range of cells at `scale` covering the block-space rectangle
+    /// `[x, x + width) × [z, z + depth)`.
+    ///
+    /// Partially covered cells on either edge are included, so the range
+    /// always spans the whole rectangle. `y` and `sy` are passed through
+    /// unchanged.
+    fn for_region(x: i32, z: i32, width: i32, depth: i32, scale: i32, y: i32, sy: i32) -> Self;
+}
+
+impl RangeExt for Range {
+    fn for_chunk(
+        chunk_x: i32,
+        chunk_z: i32,
+        chunk_cells: i32,
+        scale: i32,
+        y: i32,
+        sy: i32,
+    ) -> Self {
+        Self {
+            scale,
+            x: chunk_x * chunk_cells,
+            z: chunk_z * chunk_cells,
+            sx: chunk_cells,
+            sz: chunk_cells,
+            y,
+            sy,
+        }
+    }
+
+    fn for_region(x: i32, z: i32, width: i32, depth: i32, scale: i32, y: i32, sy: i32) -> Self {
+        let scale = scale.max(1);
+        let x0 = x.div_euclid(scale);
+        let z0 = z.div_euclid(scale);
+        let x1 = (x + width.max(1) - 1).div_euclid(scale);
+        let z1 = (z + depth.max(1) - 1).div_euclid(scale);
+        Self {
+            scale,
+            x: x0,
+            z: z0,
+            sx: x1 - x0 + 1,
+            sz: z1 - z0 + 1,
+            y,
+            sy,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(r: Range) -> [i32; 7] {
+        [r.scale, r.x, r.z, r.sx, r.sz, r.y, r.sy]
+    }
+
+    #[test]
+    fn test_for_chunk_matches_manual_range() {
+        // What WorldGenerator::generate_chunk used to build by hand
+        let (chunk_x, chunk_y, chunk_size) = (-3, 7, 16);
+        let manual = Range {
+            scale: 4,
+            x: chunk_x * chunk_size,
+            z: chunk_y * chunk_size,
+            sx: chunk_size,
+            sz: chunk_size,
+            y: 16,
+            sy: 1,
+        };
+        let built = Range::for_chunk(chunk_x, chunk_y, chunk_size, 4, 16, 1);
+        assert_eq!(fields(built), fields(manual));
+    }
+
+    #[test]
+    fn test_for_region_covers_partial_cells() {
+        // Blocks -5..=9 at 1:4 touch cells -2..=2
+        let r = Range::for_region(-5, 0, 15, 4, 4, 16, 1);
+        assert_eq!(fields(r), [4, -2, 0, 5, 1, 16, 1]);
+
+        // Block scale is a straight copy
+        let r = Range::for_region(10, -20, 32, 8, 1, 64, 1);
+        assert_eq!(fields(r), [1, 10, -20, 32, 8, 64, 1]);
+
+        // Aligned regions don't pick up an extra cell
+        let r = Range::for_region(64, 64, 64, 64, 16, 0, 1);
+        assert_eq!(fields(r), [16, 4, 4, 4, 4, 0, 1]);
+    }
+}
@@ -0,0 +1,121 @@
+//! Distance from each cell of a chunk to the nearest river.
+//!
+//! Gameplay uses this as a moisture gradient: plants grow denser close to
+//! water and NPCs can walk downhill on the field to reach it. Distances are
+//! approximate Euclidean, computed with a two-pass chamfer transform that
+//! weights straight steps 1 and diagonal steps √2.
+
+use crate::generator::BiomeChunk;
+use cubiomes_sys::{BIOME_FROZEN_RIVER, BIOME_RIVER};
+
+/// Whether a biome counts as river water.
+pub(crate) fn is_river(biome: i32) -> bool {
+    biome == BIOME_RIVER || biome == BIOME_FROZEN_RIVER
+}
+
+/// Distance in cells from each cell to the nearest river cell.
+///
+/// Indexed like [`BiomeChunk::biomes`] (`[z * width + x]`). River cells are
+/// `0.0`; if the chunk has no river every cell is `f32::INFINITY`. Multiply
+/// by the chunk's `scale` for block distances. Rivers in neighboring chunks
+/// are not seen, so values near the edges are upper bounds.
+pub fn river_distance_field(chunk: &BiomeChunk) -> Vec<f32> {
+    const DIAGONAL: f32 = std::f32::consts::SQRT_2;
+    let (w, h) = (chunk.width.max(0) as usize, chunk.height.max(0) as usize);
+    let mut dist: Vec<f32> = chunk
+        .biomes
+        .iter()
+        .take(w * h)
+        .map(|&b| if is_river(b) { 0.0 } else { f32::INFINITY })
+        .collect();
+    if dist.len() < w * h {
+        return dist;
+    }
+
+    let relax = |dist: &mut [f32], x: usize, z: usize, dx: isize, dz: isize, cost: f32| {
+        let (nx, nz) = (x as isize + dx, z as isize + dz);
+        if nx < 0 || nz < 0 || nx >= w as isize || nz >= h as isize {
+            return;
+        }
+        let candidate = dist[nz as usize * w + nx as usize] + cost;
+        let cell = &mut dist[z * w + x];
+        if candidate < *cell {
+            *cell = candidate;
+        }
+    };
+
+    // Forward pass: neighbors above and to the left
+    for z in 0..h {
+        for x in 0..w {
+            relax(&mut dist, x, z, -1, 0, 1.0);
+            relax(&mut dist, x, z, 0, -1, 1.0);
+            relax(&mut dist, x, z, -1, -1, DIAGONAL);
+            relax(&mut dist, x, z, 1, -1, DIAGONAL);
+        }
+    }
+    // Backward pass: neighbors below and to the right
+    for z in (0..h).rev() {
+        for x in (0..w).rev() {
+            relax(&mut dist, x, z, 1, 0, 1.0);
+            relax(&mut dist, x, z, 0, 1, 1.0);
+            relax(&mut dist, x, z, 1, 1, DIAGONAL);
+            relax(&mut dist, x, z, -1, 1, DIAGONAL);
+        }
+    }
+    dist
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAINS: i32 = 1;
+
+    fn chunk(width: i32, height: i32, biomes: Vec<i32>) -> BiomeChunk {
+        BiomeChunk {
+            biomes,
+            width,
+            height,
+            origin_x: 0,
+            origin_z: 0,
+            scale: 4,
+        }
+    }
+
+    #[test]
+    fn test_distance_grows_away_from_river() {
+        // Vertical river down column 2 of a 8x5 grid
+        let (w, h) = (8, 5);
+        let biomes = (0..w * h)
+            .map(|i| if i % w == 2 { BIOME_RIVER } else { PLAINS })
+            .collect();
+        let field = river_distance_field(&chunk(w, h, biomes));
+        assert_eq!(field.len(), (w * h) as usize);
+
+        for z in 0..h {
+            let row = &field[(z * w) as usize..((z + 1) * w) as usize];
+            assert_eq!(row[2], 0.0);
+            for (x, &d) in row.iter().enumerate() {
+                let expected = (x as f32 - 2.0).abs();
+                assert!((d - expected).abs() < 1e-5, "({x}, {z}) = {d}");
+            }
+            // Strictly increasing to the right of the river
+            assert!(row[3..].windows(2).all(|p| p[1] > p[0]));
+        }
+    }
+
+    #[test]
+    fn test_single_river_cell_and_no_river() {
+        let mut biomes = vec![PLAINS; 25];
+        biomes[12] = BIOME_FROZEN_RIVER;
+        let field = river_distance_field(&chunk(5, 5, biomes));
+        assert_eq!(field[12], 0.0);
+        assert!((field[7] - 1.0).abs() < 1e-5);
+        assert!((field[6] - std::f32::consts::SQRT_2).abs() < 1e-5);
+        assert!((field[0] - 2.0 * std::f32::consts::SQRT_2).abs() < 1e-5);
+        assert!(field[0] > field[6] && field[6] > field[7]);
+
+        let dry = river_distance_field(&chunk(3, 3, vec![PLAINS; 9]));
+        assert!(dry.iter().all(|d| d.is_infinite()));
+    }
+}
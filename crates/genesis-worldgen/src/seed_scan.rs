@@ -0,0 +1,175 @@
+//! Seed scanning.
+//!
+//! Finds seeds whose spawn area matches a [`SeedCriteria`], e.g. "plains
+//! near spawn and a village within 500 blocks". Each worker thread keeps
+//! one generator and re-seeds it with [`WorldGenerator::set_seed`], so
+//! scanning large seed ranges stays cheap.
+
+use crate::generator::WorldGenerator;
+use crate::structure::StructureKind;
+use std::ops::Range;
+use std::thread;
+
+/// How far from the origin [`SeedCriteria`] looks for the spawn point.
+pub const SPAWN_SEARCH_RADIUS: i32 = 4096;
+
+/// A biome that must appear near spawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BiomeRequirement {
+    /// Biome ID from cubiomes.
+    pub biome: i32,
+    /// Search radius around spawn in blocks.
+    pub radius: i32,
+}
+
+/// A structure that must generate near spawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StructureRequirement {
+    /// Structure that must be present.
+    pub structure: StructureKind,
+    /// Search radius around spawn in blocks.
+    pub radius: i32,
+}
+
+/// Conditions a seed must meet, all checked around the seed's spawn point.
+///
+/// The spawn point is the safe spawn nearest the origin, as found by
+/// [`WorldGenerator::find_safe_spawn`]; seeds without one never match.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SeedCriteria {
+    /// Biomes that must be present near spawn.
+    pub biomes: Vec<BiomeRequirement>,
+    /// Structures that must be present near spawn.
+    pub structures: Vec<StructureRequirement>,
+}
+
+impl SeedCriteria {
+    /// Create criteria that every seed satisfies.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require a biome within `radius` blocks of spawn.
+    #[must_use]
+    pub fn with_biome_near_spawn(mut self, biome: i32, radius: i32) -> Self {
+        self.biomes.push(BiomeRequirement { biome, radius });
+        self
+    }
+
+    /// Require a structure within `radius` blocks of spawn.
+    #[must_use]
+    pub fn with_structure_within(mut self, structure: StructureKind, radius: i32) -> Self {
+        self.structures
+            .push(StructureRequirement { structure, radius });
+        self
+    }
+
+    /// Check the generator's current seed against the criteria.
+    ///
+    /// Biomes are checked first since they are cheaper to rule out.
+    pub fn is_satisfied_by(&self, generator: &WorldGenerator) -> bool {
+        let Some((x, z)) = generator.find_safe_spawn(0, 0, SPAWN_SEARCH_RADIUS) else {
+            return false;
+        };
+        self.biomes
+            .iter()
+            .all(|req| generator.biome_within(req.biome, x, z, req.radius))
+            && self.structures.iter().all(|req| {
+                !generator
+                    .find_structures_near(req.structure, x, z, req.radius)
+                    .is_empty()
+            })
+    }
+}
+
+impl WorldGenerator {
+    /// Find all seeds in `seeds` that satisfy `criteria`.
+    ///
+    /// Uses this generator's MC version, flags and Y level. The range is
+    /// split across the available CPU cores; results are sorted ascending.
+    pub fn scan_seeds(&self, criteria: &SeedCriteria, seeds: Range<u64>) -> Vec<u64> {
+        let total = seeds.end.saturating_sub(seeds.start);
+        if total == 0 {
+            return Vec::new();
+        }
+        let workers = thread::available_parallelism()
+            .map_or(1, |n| n.get() as u64)
+            .min(total);
+        let per_worker = total.div_ceil(workers);
+
+        let mut found: Vec<u64> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..workers)
+                .map(|i| {
+                    let start = seeds.start + i * per_worker;
+                    let end = (start + per_worker).min(seeds.end);
+                    let config = self.config().clone();
+                    scope.spawn(move || {
+                        let mut generator = WorldGenerator::new(config);
+                        (start..end)
+                            .filter(|&seed| {
+                                generator.set_seed(seed);
+                                criteria.is_satisfied_by(&generator)
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|h| h.join().expect("seed scan worker panicked"))
+                .collect()
+        });
+        found.sort_unstable();
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::WorldGenConfig;
+    use cubiomes_sys::BIOME_PLAINS;
+
+    #[test]
+    fn test_criteria_builder() {
+        let criteria = SeedCriteria::new()
+            .with_biome_near_spawn(BIOME_PLAINS, 256)
+            .with_structure_within(StructureKind::Village, 1000);
+        assert_eq!(
+            criteria.biomes,
+            vec![BiomeRequirement {
+                biome: BIOME_PLAINS,
+                radius: 256
+            }]
+        );
+        assert_eq!(criteria.structures.len(), 1);
+        assert_eq!(criteria.structures[0].structure, StructureKind::Village);
+    }
+
+    #[test]
+    fn test_scan_seeds_results_satisfy_criteria() {
+        let base = WorldGenerator::new(WorldGenConfig::default());
+        let criteria = SeedCriteria::new()
+            .with_biome_near_spawn(BIOME_PLAINS, 512)
+            .with_structure_within(StructureKind::Village, 1500);
+
+        let found = base.scan_seeds(&criteria, 0..64);
+        assert!(!found.is_empty(), "expected at least one matching seed");
+        assert!(found.windows(2).all(|w| w[0] < w[1]));
+
+        for seed in found {
+            let fresh = WorldGenerator::new(WorldGenConfig {
+                seed,
+                ..Default::default()
+            });
+            let (x, z) = fresh
+                .find_safe_spawn(0, 0, SPAWN_SEARCH_RADIUS)
+                .expect("matching seed has a spawn point");
+            assert!(fresh.biome_within(BIOME_PLAINS, x, z, 512));
+            assert!(!fresh
+                .find_structures_near(StructureKind::Village, x, z, 1500)
+                .is_empty());
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//! Structure types for the cubiomes finders.
+//!
+//! [`StructureKind`] wraps the `STRUCT_*` constants so callers of
+//! [`WorldGenerator::find_structures`](crate::WorldGenerator::find_structures)
+//! can't pass an arbitrary integer, and carries the per-structure placement
+//! data the finders need.
+
+use cubiomes_sys::*;
+
+/// A structure cubiomes can locate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StructureKind {
+    /// Desert pyramid.
+    DesertPyramid,
+    /// Jungle temple.
+    JungleTemple,
+    /// Swamp hut.
+    SwampHut,
+    /// Igloo.
+    Igloo,
+    /// Village.
+    Village,
+    /// Ocean ruin.
+    OceanRuin,
+    /// Shipwreck.
+    Shipwreck,
+    /// Ocean monument.
+    Monument,
+    /// Woodland mansion.
+    Mansion,
+    /// Pillager outpost.
+    Outpost,
+    /// Overworld ruined portal.
+    RuinedPortal,
+    /// Nether ruined portal.
+    RuinedPortalNether,
+    /// Ancient city.
+    AncientCity,
+    /// Buried treasure.
+    Treasure,
+    /// Mineshaft.
+    Mineshaft,
+    /// Nether fortress.
+    Fortress,
+    /// Bastion remnant.
+    Bastion,
+    /// End city.
+    EndCity,
+    /// End gateway.
+    EndGateway,
+    /// Trail ruins.
+    TrailRuins,
+    /// Trial chambers.
+    TrialChambers,
+}
+
+impl StructureKind {
+    /// Every structure kind, in `STRUCT_*` order.
+    pub const ALL: [Self; 21] = [
+        Self::DesertPyramid,
+        Self::JungleTemple,
+        Self::SwampHut,
+        Self::Igloo,
+        Self::Village,
+        Self::OceanRuin,
+        Self::Shipwreck,
+        Self::Monument,
+        Self::Mansion,
+        Self::Outpost,
+        Self::RuinedPortal,
+        Self::RuinedPortalNether,
+        Self::AncientCity,
+        Self::Treasure,
+        Self::Mineshaft,
+        Self::Fortress,
+        Self::Bastion,
+        Self::EndCity,
+        Self::EndGateway,
+        Self::TrailRuins,
+        Self::TrialChambers,
+    ];
+
+    /// The cubiomes `STRUCT_*` constant.
+    pub fn to_raw(self) -> i32 {
+        match self {
+            Self::DesertPyramid => STRUCT_DESERT_PYRAMID,
+            Self::JungleTemple => STRUCT_JUNGLE_TEMPLE,
+            Self::SwampHut => STRUCT_SWAMP_HUT,
+            Self::Igloo => STRUCT_IGLOO,
+            Self::Village => STRUCT_VILLAGE,
+            Self::OceanRuin => STRUCT_OCEAN_RUIN,
+            Self::Shipwreck => STRUCT_SHIPWRECK,
+            Self::Monument => STRUCT_MONUMENT,
+            Self::Mansion => STRUCT_MANSION,
+            Self::Outpost => STRUCT_OUTPOST,
+            Self::RuinedPortal => STRUCT_RUINED_PORTAL,
+            Self::RuinedPortalNether => STRUCT_RUINED_PORTAL_N,
+            Self::AncientCity => STRUCT_ANCIENT_CITY,
+            Self::Treasure => STRUCT_TREASURE,
+            Self::Mineshaft => STRUCT_MINESHAFT,
+            Self::Fortress => STRUCT_FORTRESS,
+            Self::Bastion => STRUCT_BASTION,
+            Self::EndCity => STRUCT_END_CITY,
+            Self::EndGateway => STRUCT_END_GATEWAY,
+            Self::TrailRuins => STRUCT_TRAIL_RUINS,
+            Self::TrialChambers => STRUCT_TRIAL_CHAMBERS,
+        }
+    }
+
+    /// Look up the kind for a `STRUCT_*` constant.
+    pub fn from_raw(raw: i32) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.to_raw() == raw)
+    }
+
+    /// Size of the structure's placement region in chunks for a MC version.
+    ///
+    /// Cubiomes places at most one attempt per region, so finders step
+    /// through regions of this size. Returns `None` if the structure does
+    /// not generate in `mc`.
+    pub fn region_chunks(self, mc: i32) -> Option<i32> {
+        let mut config = StructureConfig::default();
+        let found = unsafe { getStructureConfig(self.to_raw(), mc, &mut config) };
+        (found != 0).then_some(i32::from(config.region_size))
+    }
+
+    /// Dimension the structure generates in (`DIM_*`).
+    pub fn dimension(self) -> i32 {
+        match self {
+            Self::RuinedPortalNether | Self::Fortress | Self::Bastion => DIM_NETHER,
+            Self::EndCity | Self::EndGateway => DIM_END,
+            _ => DIM_OVERWORLD,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raw_round_trip() {
+        for kind in StructureKind::ALL {
+            assert_eq!(StructureKind::from_raw(kind.to_raw()), Some(kind));
+        }
+        assert_eq!(
+            StructureKind::from_raw(STRUCT_MONUMENT),
+            Some(StructureKind::Monument)
+        );
+        assert_eq!(StructureKind::from_raw(0), None);
+        assert_eq!(StructureKind::from_raw(STRUCT_TRIAL_CHAMBERS + 1), None);
+    }
+
+    #[test]
+    fn test_region_sizes_and_dimensions() {
+        assert_eq!(StructureKind::Village.region_chunks(MC_1_18), Some(34));
+        assert_eq!(StructureKind::Village.region_chunks(MC_1_17), Some(32));
+        assert_eq!(StructureKind::Monument.region_chunks(MC_1_18), Some(32));
+        assert_eq!(StructureKind::Mansion.region_chunks(MC_1_18), Some(80));
+        assert_eq!(StructureKind::AncientCity.region_chunks(MC_1_18), None);
+        assert_eq!(StructureKind::Village.dimension(), DIM_OVERWORLD);
+        assert_eq!(StructureKind::Bastion.dimension(), DIM_NETHER);
+        assert_eq!(StructureKind::EndCity.dimension(), DIM_END);
+    }
+}
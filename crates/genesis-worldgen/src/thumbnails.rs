@@ -0,0 +1,147 @@
+//! Background thumbnail generation for seed pickers.
+//!
+//! [`WorldGenerator::thumbnails_async`] renders spawn previews for many
+//! seeds on worker threads and hands them back through a
+//! [`ThumbnailHandle`] as each one finishes, so a gallery can fill in
+//! without blocking the UI thread.
+
+use crate::generator::WorldGenerator;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+
+/// Blocks around spawn covered by each seed thumbnail.
+pub const THUMBNAIL_WORLD_RADIUS: i32 = 2048;
+
+/// A finished seed preview.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeedThumbnail {
+    /// Seed the preview was generated for.
+    pub seed: u64,
+    /// Width and height in pixels.
+    pub size: u32,
+    /// RGBA pixels, `size * size * 4` bytes.
+    pub rgba: Vec<u8>,
+}
+
+/// Receives thumbnails from [`WorldGenerator::thumbnails_async`].
+///
+/// Dropping the handle stops the workers after their current thumbnail.
+#[derive(Debug)]
+pub struct ThumbnailHandle {
+    receiver: mpsc::Receiver<SeedThumbnail>,
+    pending: usize,
+}
+
+impl ThumbnailHandle {
+    /// Take every thumbnail finished since the last call, without blocking.
+    pub fn poll(&mut self) -> Vec<SeedThumbnail> {
+        let ready: Vec<_> = self.receiver.try_iter().collect();
+        self.pending -= ready.len();
+        ready
+    }
+
+    /// Number of thumbnails not yet received.
+    pub fn pending(&self) -> usize {
+        self.pending
+    }
+
+    /// Whether every requested thumbnail has been received.
+    pub fn is_done(&self) -> bool {
+        self.pending == 0
+    }
+
+    /// Block until the remaining thumbnails are finished and return them.
+    pub fn wait(mut self) -> Vec<SeedThumbnail> {
+        let ready: Vec<_> = self.receiver.iter().collect();
+        self.pending -= ready.len();
+        ready
+    }
+}
+
+impl WorldGenerator {
+    /// Render spawn thumbnails for `seeds` on background threads.
+    ///
+    /// Uses this generator's MC version, flags and Y level. Thumbnails are
+    /// `size` pixels square and cover [`THUMBNAIL_WORLD_RADIUS`] blocks
+    /// around spawn; they arrive in completion order, not seed order.
+    pub fn thumbnails_async(&self, seeds: &[u64], size: u32) -> ThumbnailHandle {
+        let (sender, receiver) = mpsc::channel();
+        let seeds: Arc<[u64]> = seeds.into();
+        let next = Arc::new(AtomicUsize::new(0));
+        let workers = thread::available_parallelism()
+            .map_or(1, |n| n.get())
+            .min(seeds.len());
+
+        for _ in 0..workers {
+            let sender = sender.clone();
+            let seeds = Arc::clone(&seeds);
+            let next = Arc::clone(&next);
+            let config = self.config().clone();
+            thread::spawn(move || {
+                let mut generator = WorldGenerator::new(config);
+                while let Some(&seed) = seeds.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    generator.set_seed(seed);
+                    let rgba = generator.thumbnail(size, THUMBNAIL_WORLD_RADIUS);
+                    let thumbnail = SeedThumbnail {
+                        seed,
+                        size: size.max(1),
+                        rgba,
+                    };
+                    if sender.send(thumbnail).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        ThumbnailHandle {
+            receiver,
+            pending: seeds.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::WorldGenConfig;
+
+    #[test]
+    fn test_all_thumbnails_resolve() {
+        let gen = WorldGenerator::new(WorldGenConfig::default());
+        let seeds = [1, 2, 3, 42, 1234, 99_999];
+        let mut handle = gen.thumbnails_async(&seeds, 24);
+        assert_eq!(handle.pending(), seeds.len());
+
+        let mut thumbnails = handle.poll();
+        thumbnails.extend(handle.wait());
+        assert_eq!(thumbnails.len(), seeds.len());
+
+        let mut received: Vec<u64> = thumbnails.iter().map(|t| t.seed).collect();
+        received.sort_unstable();
+        assert_eq!(received, vec![1, 2, 3, 42, 1234, 99_999]);
+        for thumbnail in &thumbnails {
+            assert_eq!(thumbnail.size, 24);
+            assert_eq!(thumbnail.rgba.len(), 24 * 24 * 4);
+        }
+
+        // Same output as rendering the seed directly
+        let seed_42 = thumbnails.iter().find(|t| t.seed == 42).map(|t| &t.rgba);
+        let direct = WorldGenerator::new(WorldGenConfig {
+            seed: 42,
+            ..Default::default()
+        })
+        .thumbnail(24, THUMBNAIL_WORLD_RADIUS);
+        assert_eq!(seed_42, Some(&direct));
+    }
+
+    #[test]
+    fn test_no_seeds_is_done() {
+        let gen = WorldGenerator::new(WorldGenConfig::default());
+        let mut handle = gen.thumbnails_async(&[], 16);
+        assert!(handle.is_done());
+        assert!(handle.poll().is_empty());
+        assert!(handle.wait().is_empty());
+    }
+}
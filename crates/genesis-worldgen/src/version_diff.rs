@@ -0,0 +1,171 @@
+//! Biome differences between Minecraft versions.
+//!
+//! Content that has to work across versions can compare what two versions
+//! generate for the same seed and region, e.g. to see how much the 1.18
+//! terrain overhaul moved biomes around compared to 1.17.
+
+use crate::generator::{WorldGenConfig, WorldGenerator};
+
+/// Rectangular region in biome coordinates at the generator's scale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiffRegion {
+    /// X origin.
+    pub x: i32,
+    /// Z origin.
+    pub z: i32,
+    /// Width in biome cells.
+    pub width: i32,
+    /// Height in biome cells.
+    pub height: i32,
+}
+
+impl DiffRegion {
+    /// Create a region starting at `(x, z)`.
+    pub fn new(x: i32, z: i32, width: i32, height: i32) -> Self {
+        Self {
+            x,
+            z,
+            width,
+            height,
+        }
+    }
+
+    /// Number of cells in the region.
+    pub fn cell_count(&self) -> usize {
+        (self.width.max(0) * self.height.max(0)) as usize
+    }
+}
+
+/// A cell whose biome differs between two versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BiomeDifference {
+    /// X coordinate of the cell.
+    pub x: i32,
+    /// Z coordinate of the cell.
+    pub z: i32,
+    /// Biome ID in the first version.
+    pub from: i32,
+    /// Biome ID in the second version.
+    pub to: i32,
+}
+
+/// Result of comparing biome output between two versions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionDiff {
+    /// First MC version compared.
+    pub v1: i32,
+    /// Second MC version compared.
+    pub v2: i32,
+    /// Region that was compared.
+    pub region: DiffRegion,
+    /// Cells whose biome differs, in row-major order.
+    pub changed: Vec<BiomeDifference>,
+}
+
+impl VersionDiff {
+    /// Whether both versions generated the same biomes.
+    pub fn is_empty(&self) -> bool {
+        self.changed.is_empty()
+    }
+
+    /// Number of cells that differ.
+    pub fn len(&self) -> usize {
+        self.changed.len()
+    }
+
+    /// Fraction of the region that differs (0-1).
+    pub fn changed_fraction(&self) -> f32 {
+        match self.region.cell_count() {
+            0 => 0.0,
+            total => self.changed.len() as f32 / total as f32,
+        }
+    }
+}
+
+impl WorldGenerator {
+    /// Compare the biomes two MC versions generate for a seed and region.
+    ///
+    /// Uses this generator's flags, scale, Y level and substitutions for
+    /// both versions, so only the version differs between the two runs.
+    pub fn diff_versions(&self, seed: u64, region: DiffRegion, v1: i32, v2: i32) -> VersionDiff {
+        let generate = |mc_version| {
+            WorldGenerator::new(WorldGenConfig {
+                mc_version,
+                seed,
+                ..self.config().clone()
+            })
+            .generate_region(region.x, region.z, region.width, region.height)
+        };
+        let first = generate(v1);
+        let second = if v1 == v2 {
+            first.clone()
+        } else {
+            generate(v2)
+        };
+
+        let changed = first
+            .biomes
+            .iter()
+            .zip(&second.biomes)
+            .enumerate()
+            .filter(|(_, (a, b))| a != b)
+            .map(|(i, (&from, &to))| {
+                let i = i as i32;
+                BiomeDifference {
+                    x: region.x + i % region.width,
+                    z: region.z + i / region.width,
+                    from,
+                    to,
+                }
+            })
+            .collect();
+
+        VersionDiff {
+            v1,
+            v2,
+            region,
+            changed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cubiomes_sys::{MC_1_17, MC_1_18};
+
+    #[test]
+    fn test_same_version_has_no_diff() {
+        let gen = WorldGenerator::new(WorldGenConfig::default());
+        let diff = gen.diff_versions(42, DiffRegion::new(-32, -32, 64, 64), MC_1_18, MC_1_18);
+        assert!(diff.is_empty());
+        assert_eq!(diff.changed_fraction(), 0.0);
+    }
+
+    #[test]
+    fn test_terrain_overhaul_changes_biomes() {
+        let gen = WorldGenerator::new(WorldGenConfig {
+            scale: 4,
+            ..Default::default()
+        });
+        let region = DiffRegion::new(-64, -64, 128, 128);
+        let diff = gen.diff_versions(42, region, MC_1_17, MC_1_18);
+        assert!(
+            !diff.is_empty(),
+            "1.17 and 1.18 should generate differently"
+        );
+
+        // Reported cells really differ between the two versions
+        let old = WorldGenerator::new(WorldGenConfig {
+            mc_version: MC_1_17,
+            seed: 42,
+            scale: 4,
+            ..Default::default()
+        })
+        .generate_region(region.x, region.z, region.width, region.height);
+        let first = diff.changed[0];
+        let (col, row) = (first.x - region.x, first.z - region.z);
+        assert_eq!(old.get(col, row), first.from);
+        assert_ne!(first.from, first.to);
+    }
+}